@@ -0,0 +1,60 @@
+/*!
+    Measure the time spent by the event dispatch on high frequency messages (mouse move, paint, timer).
+    The messages are sent directly to the window procedure, so only the dispatch itself is measured.
+
+    `cargo bench --bench dispatch`
+*/
+extern crate native_windows_gui as nwg;
+
+use std::time::Instant;
+use std::cell::Cell;
+use winapi::um::winuser::{SendMessageW, WM_MOUSEMOVE, WM_PAINT, WM_TIMER};
+use winapi::shared::windef::HWND;
+
+const ITERATIONS: u32 = 200_000;
+
+fn bench_message(name: &str, hwnd: HWND, msg: u32) {
+    // Warm up
+    for _ in 0..1000 {
+        unsafe { SendMessageW(hwnd, msg, 1, 0); }
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        unsafe { SendMessageW(hwnd, msg, 1, 0); }
+    }
+
+    let elapsed = start.elapsed();
+    println!("{:<12} {:>8} ns/message", name, elapsed.as_nanos() / ITERATIONS as u128);
+}
+
+fn main() {
+    nwg::init().expect("Failed to init Native Windows GUI");
+
+    let mut window = Default::default();
+    nwg::Window::builder()
+        .flags(nwg::WindowFlags::WINDOW)
+        .build(&mut window)
+        .expect("Failed to build the window");
+
+    let mut button = Default::default();
+    nwg::Button::builder()
+        .parent(&window)
+        .build(&mut button)
+        .expect("Failed to build the button");
+
+    let events = Cell::new(0u64);
+    let handler = nwg::full_bind_event_handler(&window.handle, move |_evt, _evt_data, _handle| {
+        events.set(events.get() + 1);
+    });
+
+    let window_hwnd = window.handle.hwnd().unwrap();
+    let button_hwnd = button.handle.hwnd().unwrap();
+
+    bench_message("mouse move", window_hwnd, WM_MOUSEMOVE);
+    bench_message("child move", button_hwnd, WM_MOUSEMOVE);
+    bench_message("paint", window_hwnd, WM_PAINT);
+    bench_message("timer", window_hwnd, WM_TIMER);
+
+    nwg::unbind_event_handler(&handler);
+}
@@ -0,0 +1,91 @@
+/*!
+    A small example on how to draw with direct2D using the `D2DCanvas` control.
+    A circle follows the mouse over the canvas.
+
+    To run: `cargo run --example d2d_canvas_d --features "d2d-canvas"`
+*/
+
+extern crate native_windows_gui as nwg;
+extern crate native_windows_derive as nwd;
+
+use nwd::NwgUi;
+use nwg::NativeUi;
+use std::cell::Cell;
+
+
+#[derive(Default, NwgUi)]
+pub struct D2DCanvasExample {
+    mouse: Cell<[f32; 2]>,
+
+    #[nwg_control(size: (500, 400), position: (300, 300), title: "Direct2D canvas")]
+    #[nwg_events( OnWindowClose: [nwg::stop_thread_dispatch()] )]
+    window: nwg::Window,
+
+    #[nwg_layout(parent: window, spacing: 0, margin: [0, 0, 0, 0])]
+    layout: nwg::GridLayout,
+
+    #[nwg_control(parent: window)]
+    #[nwg_events(
+        OnPaint: [D2DCanvasExample::paint],
+        OnMouseMove: [D2DCanvasExample::mouse_move],
+        OnResize: [D2DCanvasExample::resize],
+    )]
+    #[nwg_layout_item(layout: layout, col: 0, row: 0)]
+    canvas: nwg::D2DCanvas,
+}
+
+impl D2DCanvasExample {
+
+    fn paint(&self) {
+        let [mx, my] = self.mouse.get();
+
+        let result = self.canvas.draw(|ctx| {
+            let [width, height] = ctx.size();
+
+            ctx.clear([30, 30, 40, 255]);
+
+            // Background grid
+            let mut x = 0.0;
+            while x < width {
+                ctx.draw_line([x, 0.0], [x, height], [255, 255, 255, 30], 1.0);
+                x += 20.0;
+            }
+
+            let mut y = 0.0;
+            while y < height {
+                ctx.draw_line([0.0, y], [width, y], [255, 255, 255, 30], 1.0);
+                y += 20.0;
+            }
+
+            ctx.fill_rounded_rect([10.0, 10.0, 170.0, 50.0], [8.0, 8.0], [80, 120, 220, 200]);
+            ctx.fill_ellipse([mx, my], [40.0, 40.0], [255, 180, 0, 180]);
+            ctx.draw_ellipse([mx, my], [40.0, 40.0], [255, 255, 255, 255], 2.0);
+        });
+
+        if let Err(e) = result {
+            nwg::error_message("Direct2D", &format!("{}", e));
+            nwg::stop_thread_dispatch();
+        }
+    }
+
+    fn mouse_move(&self) {
+        // The cursor position is in pixels and the canvas coordinates are in device independent pixels
+        let (x, y) = nwg::GlobalCursor::local_position(&self.canvas, None);
+        let scale = unsafe { nwg::dpi() } as f32 / 96.0;
+        self.mouse.set([x as f32 / scale, y as f32 / scale]);
+        self.canvas.invalidate();
+    }
+
+    fn resize(&self) {
+        self.canvas.invalidate();
+    }
+
+}
+
+fn main() {
+    nwg::init().expect("Failed to init Native Windows GUI");
+
+    let _app = D2DCanvasExample::build_ui(Default::default()).expect("Failed to build UI");
+
+    nwg::dispatch_thread_events();
+}
@@ -0,0 +1,87 @@
+/*!
+    Drag and drop files between the explorer and a list.
+
+    Drop files from the explorer into the list. Press the label and drag to copy the files of the list back into the explorer.
+
+    Requires the following features: `cargo run --example drag_drop_d --features "drag-drop listbox"`
+*/
+
+extern crate native_windows_gui as nwg;
+extern crate native_windows_derive as nwd;
+
+use nwd::NwgUi;
+use nwg::NativeUi;
+
+
+#[derive(Default, NwgUi)]
+pub struct DragDropApp {
+    #[nwg_control(size: (400, 300), position: (300, 300), title: "Drag and drop")]
+    #[nwg_events( OnWindowClose: [nwg::stop_thread_dispatch()] )]
+    window: nwg::Window,
+
+    #[nwg_layout(parent: window, spacing: 2)]
+    layout: nwg::GridLayout,
+
+    #[nwg_control(parent: window)]
+    #[nwg_events(
+        OnDragEnter: [DragDropApp::drag_over(SELF, EVT_DATA)],
+        OnDragOver: [DragDropApp::drag_over(SELF, EVT_DATA)],
+        OnDrop: [DragDropApp::drop(SELF, EVT_DATA)]
+    )]
+    #[nwg_layout_item(layout: layout, col: 0, row: 0, row_span: 5)]
+    files: nwg::ListBox<String>,
+
+    #[nwg_control(parent: files)]
+    files_target: nwg::DropTarget,
+
+    #[nwg_control(parent: window, text: "Drag the files from here")]
+    #[nwg_events( OnMousePress: [DragDropApp::drag_files(SELF, EVT)] )]
+    #[nwg_layout_item(layout: layout, col: 0, row: 5)]
+    drag_label: nwg::Label,
+}
+
+impl DragDropApp {
+
+    fn drag_over(&self, data: &nwg::EventData) {
+        let data = data.on_drag_drop();
+        if data.has_files() {
+            data.set_effect(nwg::DropEffect::COPY);
+        }
+    }
+
+    fn drop(&self, data: &nwg::EventData) {
+        for path in data.on_drag_drop().files() {
+            self.files.push(path);
+        }
+    }
+
+    fn drag_files(&self, evt: nwg::Event) {
+        if evt != nwg::Event::OnMousePress(nwg::MousePressEvent::MousePressLeftDown) {
+            return;
+        }
+
+        let collection = self.files.collection();
+        if collection.is_empty() {
+            return;
+        }
+
+        let files: Vec<&str> = collection.iter().map(|f| f.as_str()).collect();
+        let data = nwg::DragData::new()
+            .text(&files.join("\r\n"))
+            .files(&files);
+
+        if let Err(e) = nwg::begin_drag(&data, nwg::DropEffect::COPY) {
+            nwg::error_message("Drag and drop", &format!("{}", e));
+        }
+    }
+
+}
+
+fn main() {
+    nwg::init().expect("Failed to init Native Windows GUI");
+    nwg::Font::set_global_family("Segoe UI").expect("Failed to set default font");
+
+    let _app = DragDropApp::build_ui(Default::default()).expect("Failed to build UI");
+
+    nwg::dispatch_thread_events();
+}
@@ -80,12 +80,11 @@ impl EchoApp {
     }
 
     pub fn scroll_to_top(&self) {
-        self.text.scroll(self.text.linecount() * -1);
+        self.text.scroll_to_line(0);
     }
 
     pub fn scroll_to_mid(&self) {
-        self.scroll_to_top();
-        self.text.scroll(self.text.linecount() / 2);
+        self.text.scroll_to_line(self.text.line_count() / 2);
     }
 
     pub fn scroll_to_bot(&self) {
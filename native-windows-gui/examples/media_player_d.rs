@@ -0,0 +1,113 @@
+/*!
+    A small audio / video player using the `MediaPlayer` control.
+
+    Requires the following features: `cargo run --example media_player_d --features "media-player file-dialog trackbar"`
+*/
+
+extern crate native_windows_gui as nwg;
+extern crate native_windows_derive as nwd;
+
+use nwd::NwgUi;
+use nwg::NativeUi;
+
+
+#[derive(Default, NwgUi)]
+pub struct MediaPlayerApp {
+    #[nwg_control(size: (640, 480), position: (300, 300), title: "Media player")]
+    #[nwg_events( OnWindowClose: [nwg::stop_thread_dispatch()] )]
+    window: nwg::Window,
+
+    #[nwg_layout(parent: window, spacing: 2)]
+    layout: nwg::GridLayout,
+
+    #[nwg_resource(title: "Open media", action: nwg::FileDialogAction::Open, filters: "Media(*.wav;*.mp3;*.avi;*.mp4;*.wmv)|Any (*.*)")]
+    dialog: nwg::FileDialog,
+
+    #[nwg_control(parent: window)]
+    #[nwg_events( OnMediaEnd: [MediaPlayerApp::media_end] )]
+    #[nwg_layout_item(layout: layout, col: 0, row: 0, col_span: 5, row_span: 8)]
+    player: nwg::MediaPlayer,
+
+    #[nwg_control(parent: window, text: "Open")]
+    #[nwg_events( OnButtonClick: [MediaPlayerApp::open] )]
+    #[nwg_layout_item(layout: layout, col: 0, row: 8)]
+    open_btn: nwg::Button,
+
+    #[nwg_control(parent: window, text: "Play")]
+    #[nwg_events( OnButtonClick: [MediaPlayerApp::play] )]
+    #[nwg_layout_item(layout: layout, col: 1, row: 8)]
+    play_btn: nwg::Button,
+
+    #[nwg_control(parent: window, text: "Pause")]
+    #[nwg_events( OnButtonClick: [MediaPlayerApp::pause] )]
+    #[nwg_layout_item(layout: layout, col: 2, row: 8)]
+    pause_btn: nwg::Button,
+
+    #[nwg_control(parent: window, text: "Stop")]
+    #[nwg_events( OnButtonClick: [MediaPlayerApp::stop] )]
+    #[nwg_layout_item(layout: layout, col: 3, row: 8)]
+    stop_btn: nwg::Button,
+
+    #[nwg_control(parent: window, range: Some(0..1000), pos: Some(1000))]
+    #[nwg_events( OnHorizontalScroll: [MediaPlayerApp::volume] )]
+    #[nwg_layout_item(layout: layout, col: 4, row: 8)]
+    volume: nwg::TrackBar,
+
+    #[nwg_control(parent: window, text: "Open a media file")]
+    #[nwg_layout_item(layout: layout, col: 0, row: 9, col_span: 5)]
+    status: nwg::Label,
+}
+
+impl MediaPlayerApp {
+
+    fn open(&self) {
+        if !self.dialog.run(Some(&self.window)) {
+            return;
+        }
+
+        let path = match self.dialog.get_selected_item() {
+            Ok(path) => path.into_string().unwrap_or_default(),
+            Err(_) => return
+        };
+
+        match self.player.open(&path) {
+            Ok(()) => {
+                let length = self.player.length();
+                self.status.set_text(&format!("{} ({}:{:02})", path, length.as_secs() / 60, length.as_secs() % 60));
+                self.player.set_volume(self.volume.pos() as u32);
+                self.player.play();
+            },
+            Err(e) => self.status.set_text(&format!("{}", e))
+        }
+    }
+
+    fn play(&self) {
+        self.player.play();
+    }
+
+    fn pause(&self) {
+        self.player.pause();
+    }
+
+    fn stop(&self) {
+        self.player.stop();
+    }
+
+    fn volume(&self) {
+        self.player.set_volume(self.volume.pos() as u32);
+    }
+
+    fn media_end(&self) {
+        self.status.set_text("End of media");
+    }
+
+}
+
+fn main() {
+    nwg::init().expect("Failed to init Native Windows GUI");
+    nwg::Font::set_global_family("Segoe UI").expect("Failed to set default font");
+
+    let _app = MediaPlayerApp::build_ui(Default::default()).expect("Failed to build UI");
+
+    nwg::dispatch_thread_events();
+}
@@ -0,0 +1,84 @@
+/*!
+    A small monitoring example using the `PlotCanvas` control.
+    A new value is added to each series every 200 ms. Hover a point to display its value.
+
+    To run: `cargo run --example plot_canvas_d --features "plot-canvas animation-timer"`
+*/
+
+extern crate native_windows_gui as nwg;
+extern crate native_windows_derive as nwd;
+
+use nwd::NwgUi;
+use nwg::NativeUi;
+use std::cell::Cell;
+use std::time::Duration;
+
+
+#[derive(Default, NwgUi)]
+pub struct PlotCanvasExample {
+    tick: Cell<u32>,
+
+    #[nwg_control(size: (600, 400), position: (300, 300), title: "Plot canvas")]
+    #[nwg_events( OnInit: [PlotCanvasExample::setup], OnWindowClose: [nwg::stop_thread_dispatch()] )]
+    window: nwg::Window,
+
+    #[nwg_layout(parent: window, spacing: 2)]
+    layout: nwg::GridLayout,
+
+    #[nwg_control(parent: window, max_points: Some(60), y_range: Some([0.0, 100.0]))]
+    #[nwg_events( OnPlotHover: [PlotCanvasExample::hover(SELF, EVT_DATA)] )]
+    #[nwg_layout_item(layout: layout, col: 0, row: 0, row_span: 9)]
+    plot: nwg::PlotCanvas,
+
+    #[nwg_control(parent: window, text: "Hover a point")]
+    #[nwg_layout_item(layout: layout, col: 0, row: 9)]
+    status: nwg::Label,
+
+    #[nwg_control(parent: window, interval: Duration::from_millis(200))]
+    #[nwg_events( OnTimerTick: [PlotCanvasExample::add_values] )]
+    timer: nwg::AnimationTimer,
+}
+
+impl PlotCanvasExample {
+
+    fn setup(&self) {
+        self.plot.add_series("CPU", nwg::PlotKind::Line, [0, 120, 215]);
+        self.plot.add_series("Memory", nwg::PlotKind::Line, [215, 80, 0]);
+        self.plot.add_series("Requests", nwg::PlotKind::Bar, [120, 190, 120]);
+        self.timer.start();
+    }
+
+    fn add_values(&self) {
+        let tick = self.tick.get();
+        self.tick.set(tick + 1);
+
+        // Fake values
+        let t = tick as f64;
+        let cpu = 50.0 + 30.0 * (t / 7.0).sin() + 10.0 * (t / 2.0).cos();
+        let memory = 40.0 + (t / 3.0) % 40.0;
+        let requests = ((tick * 37) % 23) as f64;
+
+        self.plot.push_point(0, [t, cpu]);
+        self.plot.push_point(1, [t, memory]);
+        self.plot.push_point(2, [t, requests]);
+    }
+
+    fn hover(&self, data: &nwg::EventData) {
+        let text = match data.on_plot_hover() {
+            Some(point) => format!("Series {}: x = {:.0}, y = {:.1}", point.series, point.value[0], point.value[1]),
+            None => "Hover a point".to_string()
+        };
+
+        self.status.set_text(&text);
+    }
+
+}
+
+fn main() {
+    nwg::init().expect("Failed to init Native Windows GUI");
+    nwg::Font::set_global_family("Segoe UI").expect("Failed to set default font");
+
+    let _app = PlotCanvasExample::build_ui(Default::default()).expect("Failed to build UI");
+
+    nwg::dispatch_thread_events();
+}
@@ -0,0 +1,190 @@
+/*!
+    A small helper to write applications in the "model, update, view" style. Requires the `app` feature.
+
+    * The model holds the whole state of the application.
+    * The control events are mapped to messages.
+    * `update` applies the messages to the model.
+    * The views read a part of the model and refresh the controls. A view is only refreshed when its part of the model changes.
+
+    Messages sent while a message is processed (for example, by a control refreshed in a view) are queued
+    and processed after it, so `update` is never reentered.
+
+    ```rust
+    use native_windows_gui as nwg;
+    use std::rc::Rc;
+
+    #[derive(Default)]
+    struct Model { clicks: u32 }
+
+    enum Msg { Click, Exit }
+
+    fn update(model: &mut Model, msg: Msg) {
+        match msg {
+            Msg::Click => { model.clicks += 1; },
+            Msg::Exit => nwg::stop_thread_dispatch(),
+        }
+    }
+
+    fn build(window: &nwg::Window, button: &nwg::Button, label: &Rc<nwg::Label>) -> nwg::App<Model, Msg> {
+        let app = nwg::App::new(Model::default(), update);
+
+        let (button_handle, window_handle) = (button.handle, window.handle);
+        app.bind(window, move |evt, _evt_data, handle| match evt {
+            nwg::Event::OnButtonClick if handle == button_handle => Some(Msg::Click),
+            nwg::Event::OnWindowClose if handle == window_handle => Some(Msg::Exit),
+            _ => None
+        });
+
+        let label = label.clone();
+        app.view(|model| model.clicks, move |clicks| label.set_text(&format!("{} clicks", clicks)));
+
+        app
+    }
+    ```
+*/
+use crate::{ControlHandle, Event, EventData, EventHandler, full_bind_event_handler, unbind_event_handler};
+use std::cell::{Cell, Ref, RefCell};
+use std::collections::VecDeque;
+use std::rc::{Rc, Weak};
+
+/// Refresh a view if its part of the model changed
+type View<M> = Box<dyn FnMut(&M) -> ()>;
+
+struct AppInner<M, Msg> {
+    model: RefCell<M>,
+    update: Box<dyn Fn(&mut M, Msg) -> ()>,
+    views: RefCell<Vec<View<M>>>,
+    queue: RefCell<VecDeque<Msg>>,
+    processing: Cell<bool>,
+    handlers: RefCell<Vec<EventHandler>>,
+}
+
+/**
+    An application in the "model, update, view" style. See the module documentation.
+    Cloning an app returns a new reference to the same app.
+*/
+pub struct App<M, Msg> {
+    inner: Rc<AppInner<M, Msg>>
+}
+
+impl<M: 'static, Msg: 'static> App<M, Msg> {
+
+    /// Create an app with the initial `model`. `update` is called with each message.
+    pub fn new<U>(model: M, update: U) -> App<M, Msg>
+        where U: Fn(&mut M, Msg) -> () + 'static
+    {
+        let inner = AppInner {
+            model: RefCell::new(model),
+            update: Box::new(update),
+            views: RefCell::new(Vec::new()),
+            queue: RefCell::new(VecDeque::new()),
+            processing: Cell::new(false),
+            handlers: RefCell::new(Vec::new()),
+        };
+
+        App { inner: Rc::new(inner) }
+    }
+
+    /// Return a reference to the model. The reference must be released before the next message is sent.
+    pub fn model(&self) -> Ref<'_, M> {
+        self.inner.model.borrow()
+    }
+
+    /**
+        Map the events of `control` and its children to messages. The events mapped to `None` are ignored.
+        The events stop being mapped when `unbind` is called.
+    */
+    pub fn bind<C, F>(&self, control: C, map: F)
+        where C: Into<ControlHandle>, F: Fn(Event, &EventData, ControlHandle) -> Option<Msg> + 'static
+    {
+        let app: Weak<AppInner<M, Msg>> = Rc::downgrade(&self.inner);
+        let handler = full_bind_event_handler(&control.into(), move |evt, evt_data, handle| {
+            if let (Some(inner), Some(msg)) = (app.upgrade(), map(evt, &evt_data, handle)) {
+                App { inner }.send(msg);
+            }
+        });
+
+        self.inner.handlers.borrow_mut().push(handler);
+    }
+
+    /**
+        Add a view. `select` reads a part of the model and `refresh` updates the controls with it.
+        `refresh` is called now, and then each time the value returned by `select` changes.
+    */
+    pub fn view<T, S, R>(&self, select: S, refresh: R)
+        where T: PartialEq + 'static, S: Fn(&M) -> T + 'static, R: Fn(&T) -> () + 'static
+    {
+        let current = select(&self.inner.model.borrow());
+        refresh(&current);
+
+        let mut current = current;
+        let view = move |model: &M| {
+            let value = select(model);
+            if value != current {
+                refresh(&value);
+                current = value;
+            }
+        };
+
+        self.inner.views.borrow_mut().push(Box::new(view));
+    }
+
+    /**
+        Send a message to the app. The message is processed immediately, unless another message is being processed.
+        In that case, it is processed once the previous messages are processed.
+    */
+    pub fn send(&self, msg: Msg) {
+        self.inner.queue.borrow_mut().push_back(msg);
+
+        if self.inner.processing.replace(true) {
+            return;
+        }
+
+        // Accept new messages even if `update` or a view panicked
+        struct Processing<'a>(&'a Cell<bool>);
+        impl<'a> Drop for Processing<'a> {
+            fn drop(&mut self) { self.0.set(false); }
+        }
+
+        let _processing = Processing(&self.inner.processing);
+
+        loop {
+            let msg = match self.inner.queue.borrow_mut().pop_front() {
+                Some(msg) => msg,
+                None => break
+            };
+
+            (self.inner.update)(&mut self.inner.model.borrow_mut(), msg);
+            self.refresh();
+        }
+    }
+
+    /// Stop mapping the control events to messages. See `bind`.
+    pub fn unbind(&self) {
+        for handler in self.inner.handlers.borrow_mut().drain(..) {
+            unbind_event_handler(&handler);
+        }
+    }
+
+    /// Refresh the views whose part of the model changed
+    fn refresh(&self) {
+        let model = self.inner.model.borrow();
+
+        // The views are taken out so that a view can add other views
+        let mut views = self.inner.views.replace(Vec::new());
+        for view in views.iter_mut() {
+            view(&model);
+        }
+
+        let mut current_views = self.inner.views.borrow_mut();
+        views.append(&mut current_views);
+        *current_views = views;
+    }
+
+}
+
+impl<M, Msg> Clone for App<M, Msg> {
+    fn clone(&self) -> App<M, Msg> {
+        App { inner: self.inner.clone() }
+    }
+}
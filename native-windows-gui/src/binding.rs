@@ -0,0 +1,322 @@
+/*!
+    Bind model values to the controls properties. Requires the `binding` feature.
+
+    An `Observable` holds a value and notifies its observers when the value changes.
+    The `bind_*` methods keep a control property in sync with the value, and the `bind_*_two_way` methods
+    also write the user edits back into the value.
+
+    Observables are not `Send`, so the controls are always updated from the GUI thread.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn bind(name_input: &nwg::TextInput, name_label: &nwg::Label, enabled_check: &nwg::CheckBox) -> Vec<nwg::Binding> {
+        let name = nwg::Observable::new(String::from("Heisenberg"));
+        let enabled = nwg::Observable::new(true);
+
+        let bindings = vec![
+            name.bind_text_two_way(name_input).unwrap(),
+            name.bind_text(name_label),
+            enabled.bind_checked_two_way(enabled_check).unwrap(),
+        ];
+
+        name.set("Walter".into());
+        bindings
+    }
+    ```
+*/
+use winapi::shared::windef::HWND;
+use winapi::shared::basetsd::UINT_PTR;
+use crate::win32::window_helper as wh;
+use crate::win32::window::{bind_raw_event_handler_inner, has_raw_handler};
+use crate::{ControlHandle, NwgError};
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
+use std::str::FromStr;
+use std::rc::{Rc, Weak};
+
+/// Id of the raw event handler that writes the control edits back into the observables
+const BINDING_HANDLER_ID: UINT_PTR = 0xFF07;
+
+/// A control notification handler of a two way binding
+struct ParentBinding {
+    id: usize,
+    parent: HWND,
+    control: HWND,
+    callback: Rc<dyn Fn(HWND, u32) -> ()>,
+}
+
+thread_local! {
+    /// The two way bindings of the thread. Called by the `BINDING_HANDLER_ID` handler of the control parent.
+    static PARENT_BINDINGS: RefCell<Vec<ParentBinding>> = RefCell::new(Vec::new());
+
+    static NEXT_BINDING_ID: Cell<usize> = Cell::new(0);
+}
+
+type Observer<T> = Rc<dyn Fn(&T) -> ()>;
+
+struct ObservableInner<T> {
+    value: RefCell<T>,
+    observers: RefCell<Vec<(usize, Observer<T>)>>,
+    next_id: Cell<usize>,
+}
+
+/**
+    A value that notifies its observers when it changes. Cloning an observable returns a new reference to the same value.
+*/
+pub struct Observable<T> {
+    inner: Rc<ObservableInner<T>>
+}
+
+impl<T: Clone + 'static> Observable<T> {
+
+    pub fn new(value: T) -> Observable<T> {
+        let inner = ObservableInner {
+            value: RefCell::new(value),
+            observers: RefCell::new(Vec::new()),
+            next_id: Cell::new(0),
+        };
+
+        Observable { inner: Rc::new(inner) }
+    }
+
+    /// Return a copy of the value
+    pub fn get(&self) -> T {
+        self.inner.value.borrow().clone()
+    }
+
+    /// Call `f` with a reference to the value
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        f(&self.inner.value.borrow())
+    }
+
+    /// Replace the value and notify the observers
+    pub fn set(&self, value: T) {
+        *self.inner.value.borrow_mut() = value;
+        self.notify();
+    }
+
+    /// Modify the value in place and notify the observers
+    pub fn update<F: FnOnce(&mut T)>(&self, f: F) {
+        f(&mut self.inner.value.borrow_mut());
+        self.notify();
+    }
+
+    /**
+        Call `f` each time the value changes. `f` is not called with the current value.
+        Returns a `Binding` that can be used to remove the observer.
+    */
+    pub fn subscribe<F: Fn(&T) -> () + 'static>(&self, f: F) -> Binding {
+        let id = self.inner.next_id.get();
+        self.inner.next_id.set(id + 1);
+        self.inner.observers.borrow_mut().push((id, Rc::new(f)));
+
+        let inner = Rc::downgrade(&self.inner);
+        let unsubscribe = move || {
+            if let Some(inner) = inner.upgrade() {
+                inner.observers.borrow_mut().retain(|(i, _)| *i != id);
+            }
+        };
+
+        Binding { unsubscribe: Box::new(unsubscribe), parent_binding: None }
+    }
+
+    fn notify(&self) {
+        // The observers can read or set the value, and add or remove observers
+        let value = self.get();
+        let observers: Vec<Observer<T>> = self.inner.observers.borrow().iter().map(|(_, o)| o.clone()).collect();
+        for observer in observers {
+            observer(&value);
+        }
+    }
+
+    /// Call `f` with the value now and each time it changes, while the control exists
+    fn bind_control<F: Fn(HWND, &T) -> () + 'static>(&self, control: ControlHandle, f: F) -> Binding {
+        let hwnd = control.hwnd().expect("Bound controls must be window-like controls (HWND handle)");
+        f(hwnd, &self.inner.value.borrow());
+
+        self.subscribe(move |value| {
+            if wh::window_valid(hwnd) {
+                f(hwnd, value);
+            }
+        })
+    }
+
+    /// Set the value from the notifications of `control`. The notifications are dispatched by a single raw event handler on the parent.
+    fn bind_parent<F>(&self, binding: &mut Binding, control: ControlHandle, f: F) -> Result<(), NwgError>
+        where F: Fn(&Observable<T>, HWND, u32) -> () + 'static
+    {
+        use winapi::um::winuser::{WM_COMMAND, WM_NCDESTROY};
+        use winapi::shared::minwindef::HIWORD;
+
+        let hwnd = control.hwnd().expect("Bound controls must be window-like controls (HWND handle)");
+        let parent_hwnd = wh::get_window_parent(hwnd);
+        let parent = ControlHandle::Hwnd(parent_hwnd);
+        let observable: Weak<ObservableInner<T>> = Rc::downgrade(&self.inner);
+
+        if !has_raw_handler(&parent, BINDING_HANDLER_ID) {
+            bind_raw_event_handler_inner(&parent, BINDING_HANDLER_ID, |hwnd, msg, w, l| {
+                match msg {
+                    WM_COMMAND => notify_binding(l as HWND, HIWORD(w as u32) as u32),
+                    WM_NCDESTROY => PARENT_BINDINGS.with(|bindings| bindings.borrow_mut().retain(|b| b.parent != hwnd)),
+                    _ => {}
+                }
+
+                None
+            })?;
+        }
+
+        let callback = move |hwnd, code| {
+            if let Some(inner) = observable.upgrade() {
+                f(&Observable { inner }, hwnd, code);
+            }
+        };
+
+        let id = NEXT_BINDING_ID.with(|next| next.replace(next.get() + 1));
+        let parent_binding = ParentBinding { id, parent: parent_hwnd, control: hwnd, callback: Rc::new(callback) };
+        PARENT_BINDINGS.with(|bindings| bindings.borrow_mut().push(parent_binding));
+        binding.parent_binding = Some(id);
+
+        Ok(())
+    }
+
+}
+
+impl<T: Clone + Display + 'static> Observable<T> {
+
+    /// Display the value in the text of `control`
+    pub fn bind_text<C: Into<ControlHandle>>(&self, control: C) -> Binding {
+        self.bind_control(control.into(), |hwnd, value| unsafe { wh::set_window_text(hwnd, &value.to_string()) })
+    }
+
+}
+
+impl<T: Clone + Display + FromStr + PartialEq + 'static> Observable<T> {
+
+    /**
+        Display the value in the text of an edit `control` and set the value when the user edits the text.
+        Text that cannot be parsed into a value is ignored.
+    */
+    pub fn bind_text_two_way<C: Into<ControlHandle>>(&self, control: C) -> Result<Binding, NwgError> {
+        use winapi::um::winuser::EN_CHANGE;
+
+        let control = control.into();
+
+        // Rewriting the text that the user is typing would reset the caret and remove the characters
+        // that do not change the value (ex: the "." of "1.")
+        let mut binding = self.bind_control(control, |hwnd, value| {
+            let text = unsafe { wh::get_window_text(hwnd) };
+            if text.parse::<T>().ok().as_ref() != Some(value) {
+                unsafe { wh::set_window_text(hwnd, &value.to_string()); }
+            }
+        });
+
+        self.bind_parent(&mut binding, control, |observable, hwnd, code| {
+            if code != EN_CHANGE as u32 {
+                return;
+            }
+
+            let text = unsafe { wh::get_window_text(hwnd) };
+            if let Ok(value) = text.parse::<T>() {
+                if observable.with(|v| *v != value) {
+                    observable.set(value);
+                }
+            }
+        })?;
+
+        Ok(binding)
+    }
+
+}
+
+impl Observable<bool> {
+
+    /// Check `control` (a check box or a radio button) if the value is true
+    pub fn bind_checked<C: Into<ControlHandle>>(&self, control: C) -> Binding {
+        use winapi::um::winuser::{BM_SETCHECK, BST_CHECKED, BST_UNCHECKED};
+
+        self.bind_control(control.into(), |hwnd, &checked| {
+            let state = match checked { true => BST_CHECKED, false => BST_UNCHECKED };
+            wh::send_message(hwnd, BM_SETCHECK, state as _, 0);
+        })
+    }
+
+    /// Same as `bind_checked`, but the value is also set when the user clicks the control
+    pub fn bind_checked_two_way<C: Into<ControlHandle>>(&self, control: C) -> Result<Binding, NwgError> {
+        use winapi::um::winuser::{BM_GETCHECK, BN_CLICKED, BST_CHECKED};
+
+        let control = control.into();
+        let mut binding = self.bind_checked(control);
+
+        self.bind_parent(&mut binding, control, |observable, hwnd, code| {
+            if code == BN_CLICKED as u32 {
+                let checked = wh::send_message(hwnd, BM_GETCHECK, 0, 0) as usize == BST_CHECKED;
+                if observable.get() != checked {
+                    observable.set(checked);
+                }
+            }
+        })?;
+
+        Ok(binding)
+    }
+
+}
+
+impl Observable<u32> {
+
+    /// Set the position of a progress bar `control` to the value
+    pub fn bind_progress<C: Into<ControlHandle>>(&self, control: C) -> Binding {
+        use winapi::um::commctrl::PBM_SETPOS;
+
+        self.bind_control(control.into(), |hwnd, &pos| {
+            wh::send_message(hwnd, PBM_SETPOS, pos as _, 0);
+        })
+    }
+
+}
+
+impl<T> Clone for Observable<T> {
+    fn clone(&self) -> Observable<T> {
+        Observable { inner: self.inner.clone() }
+    }
+}
+
+impl<T: Clone + Default + 'static> Default for Observable<T> {
+    fn default() -> Observable<T> {
+        Observable::new(Default::default())
+    }
+}
+
+/**
+    A link between an observable and an observer or a control. Must be removed with `unbind`, otherwise
+    the link stays active as long as the observable and the control exist.
+*/
+pub struct Binding {
+    unsubscribe: Box<dyn Fn() -> ()>,
+    parent_binding: Option<usize>,
+}
+
+impl Binding {
+
+    /// Remove the binding
+    pub fn unbind(self) {
+        (self.unsubscribe)();
+
+        if let Some(id) = self.parent_binding {
+            PARENT_BINDINGS.with(|bindings| bindings.borrow_mut().retain(|b| b.id != id));
+        }
+    }
+
+}
+
+/// Call the two way bindings of `control` with a notification code
+fn notify_binding(control: HWND, code: u32) {
+    // The callbacks set the observables, which can add or remove bindings
+    let callbacks: Vec<Rc<dyn Fn(HWND, u32) -> ()>> = PARENT_BINDINGS.with(|bindings| {
+        bindings.borrow().iter().filter(|b| b.control == control).map(|b| b.callback.clone()).collect()
+    });
+
+    for callback in callbacks {
+        callback(control, code);
+    }
+}
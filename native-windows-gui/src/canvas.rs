@@ -0,0 +1,302 @@
+/*!
+    A small safe wrapper over the GDI drawing functions. See `Canvas`.
+*/
+use winapi::shared::windef::{HDC, RECT, POINT};
+use winapi::um::wingdi::RGB;
+use crate::win32::base_helper::to_utf16;
+use crate::{Pen, Brush, Font, Bitmap, HTextAlign, VTextAlign};
+use std::marker::PhantomData;
+use std::ptr;
+
+
+fn rect(r: [i32; 4]) -> RECT {
+    let [left, top, right, bottom] = r;
+    RECT { left, top, right, bottom }
+}
+
+fn points(pts: &[[i32; 2]]) -> Vec<POINT> {
+    pts.iter().map(|&[x, y]| POINT { x, y }).collect()
+}
+
+
+/**
+    A GDI drawing surface. Rectangles are `[left, top, right, bottom]`, points are `[x, y]` and colors are `[r, g, b]`.
+    Coordinates are in physical pixels and relative to the top left corner of the control.
+
+    The state of the device context (selected pen, brush, font, clipping, colors) is saved when the canvas is created
+    and restored when it is dropped. Pens, brushes and fonts selected in the canvas must outlive the canvas.
+
+    Lines and the outlines of the shapes use the selected pen. Shapes are filled with the selected brush.
+    By default, the pen is a black 1 pixel solid line and the brush is white.
+
+    In a `OnPaint` event, a canvas is created with `EventData::on_paint().paint()`. See `Paint`.
+    A canvas can also wrap any device context with `Canvas::from_hdc`.
+
+    Requires the `gdi-canvas` feature.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn paint(data: &nwg::EventData, pen: &nwg::Pen, brush: &nwg::Brush, font: &nwg::Font) {
+        let paint = data.on_paint().paint();
+
+        paint.set_pen(pen);
+        paint.set_brush(brush);
+        paint.ellipse([10, 10, 110, 110]);
+        paint.line([10, 120], [110, 120]);
+
+        paint.set_font(font);
+        paint.set_text_color([20, 20, 20]);
+        paint.draw_text("Hello", [10, 130, 110, 150], nwg::HTextAlign::Center, nwg::VTextAlign::Center);
+    }
+    ```
+*/
+pub struct Canvas<'a> {
+    hdc: HDC,
+    saved: i32,
+    p: PhantomData<&'a ()>,
+}
+
+impl<'a> Canvas<'a> {
+
+    /**
+        Wraps a device context. The state of the device context is restored when the canvas is dropped.
+
+        Safety: `hdc` must be a valid device context that outlives the canvas.
+    */
+    pub unsafe fn from_hdc(hdc: HDC) -> Canvas<'a> {
+        use winapi::um::wingdi::{SaveDC, SetBkMode, TRANSPARENT};
+
+        let saved = SaveDC(hdc);
+        SetBkMode(hdc, TRANSPARENT as _);
+
+        Canvas { hdc, saved, p: PhantomData }
+    }
+
+    /// Returns the raw device context, for drawing operations not covered by the canvas
+    pub fn hdc(&self) -> HDC {
+        self.hdc
+    }
+
+    /// Sets the pen used to draw the lines and the outline of the shapes
+    pub fn set_pen(&self, pen: &'a Pen) {
+        use winapi::um::wingdi::SelectObject;
+        unsafe { SelectObject(self.hdc, pen.handle as _); }
+    }
+
+    /// Sets the brush used to fill the shapes
+    pub fn set_brush(&self, brush: &'a Brush) {
+        use winapi::um::wingdi::SelectObject;
+        unsafe { SelectObject(self.hdc, brush.handle as _); }
+    }
+
+    /// Sets the font used to draw text
+    pub fn set_font(&self, font: &'a Font) {
+        use winapi::um::wingdi::SelectObject;
+        unsafe { SelectObject(self.hdc, font.handle as _); }
+    }
+
+    /// Sets the color of the text
+    pub fn set_text_color(&self, color: [u8; 3]) {
+        use winapi::um::wingdi::SetTextColor;
+        unsafe { SetTextColor(self.hdc, RGB(color[0], color[1], color[2])); }
+    }
+
+    /// Sets the color behind the text and between the lines of the hatched brushes and the styled pens.
+    /// If `None`, the background is not drawn (the default).
+    pub fn set_background_color(&self, color: Option<[u8; 3]>) {
+        use winapi::um::wingdi::{SetBkMode, SetBkColor, OPAQUE, TRANSPARENT};
+
+        unsafe {
+            match color {
+                Some([r, g, b]) => {
+                    SetBkMode(self.hdc, OPAQUE as _);
+                    SetBkColor(self.hdc, RGB(r, g, b));
+                },
+                None => {
+                    SetBkMode(self.hdc, TRANSPARENT as _);
+                }
+            }
+        }
+    }
+
+    /// Sets the color of a single pixel
+    pub fn set_pixel(&self, point: [i32; 2], color: [u8; 3]) {
+        use winapi::um::wingdi::SetPixel;
+        unsafe { SetPixel(self.hdc, point[0], point[1], RGB(color[0], color[1], color[2])); }
+    }
+
+    /// Draws a line from `from` to `to`. The last pixel is not drawn.
+    pub fn line(&self, from: [i32; 2], to: [i32; 2]) {
+        use winapi::um::wingdi::{MoveToEx, LineTo};
+
+        unsafe {
+            MoveToEx(self.hdc, from[0], from[1], ptr::null_mut());
+            LineTo(self.hdc, to[0], to[1]);
+        }
+    }
+
+    /// Draws connected lines between the points
+    pub fn polyline(&self, pts: &[[i32; 2]]) {
+        use winapi::um::wingdi::Polyline;
+
+        let pts = points(pts);
+        unsafe { Polyline(self.hdc, pts.as_ptr(), pts.len() as _); }
+    }
+
+    /// Draws a rectangle
+    pub fn rectangle(&self, r: [i32; 4]) {
+        use winapi::um::wingdi::Rectangle;
+        unsafe { Rectangle(self.hdc, r[0], r[1], r[2], r[3]); }
+    }
+
+    /// Draws a rectangle with rounded corners. `corner` is the width and the height of the ellipse used to draw the corners.
+    pub fn round_rectangle(&self, r: [i32; 4], corner: [i32; 2]) {
+        use winapi::um::wingdi::RoundRect;
+        unsafe { RoundRect(self.hdc, r[0], r[1], r[2], r[3], corner[0], corner[1]); }
+    }
+
+    /// Draws the ellipse that fits in `r`
+    pub fn ellipse(&self, r: [i32; 4]) {
+        use winapi::um::wingdi::Ellipse;
+        unsafe { Ellipse(self.hdc, r[0], r[1], r[2], r[3]); }
+    }
+
+    /// Draws a closed polygon. The last point is connected to the first one.
+    pub fn polygon(&self, pts: &[[i32; 2]]) {
+        use winapi::um::wingdi::Polygon;
+
+        let pts = points(pts);
+        unsafe { Polygon(self.hdc, pts.as_ptr(), pts.len() as _); }
+    }
+
+    /// Fills a rectangle with `brush`, without an outline. The right and the bottom edges are not filled.
+    pub fn fill_rect(&self, r: [i32; 4], brush: &Brush) {
+        use winapi::um::winuser::FillRect;
+        unsafe { FillRect(self.hdc, &rect(r), brush.handle); }
+    }
+
+    /// Draws a 1 pixel border around a rectangle with `brush`
+    pub fn frame_rect(&self, r: [i32; 4], brush: &Brush) {
+        use winapi::um::winuser::FrameRect;
+        unsafe { FrameRect(self.hdc, &rect(r), brush.handle); }
+    }
+
+    /// Draws a single line of text. `point` is the top left corner of the text.
+    pub fn text(&self, point: [i32; 2], text: &str) {
+        use winapi::um::wingdi::TextOutW;
+
+        let text: Vec<u16> = text.encode_utf16().collect();
+        unsafe { TextOutW(self.hdc, point[0], point[1], text.as_ptr(), text.len() as _); }
+    }
+
+    /**
+        Draws text in a rectangle. The text is clipped to the rectangle.
+
+        If `v_align` is `Top`, long lines are wrapped. Otherwise, the text is drawn on a single line.
+    */
+    pub fn draw_text(&self, text: &str, r: [i32; 4], h_align: HTextAlign, v_align: VTextAlign) {
+        use winapi::um::winuser::{DrawTextW, DT_LEFT, DT_CENTER, DT_RIGHT, DT_TOP, DT_VCENTER, DT_BOTTOM, DT_SINGLELINE, DT_WORDBREAK, DT_NOPREFIX};
+
+        let mut flags = DT_NOPREFIX;
+        flags |= match h_align {
+            HTextAlign::Left => DT_LEFT,
+            HTextAlign::Center => DT_CENTER,
+            HTextAlign::Right => DT_RIGHT,
+        };
+
+        flags |= match v_align {
+            VTextAlign::Top => DT_TOP | DT_WORDBREAK,
+            VTextAlign::Center => DT_VCENTER | DT_SINGLELINE,
+            VTextAlign::Bottom => DT_BOTTOM | DT_SINGLELINE,
+        };
+
+        let text = to_utf16(text);
+        let mut r = rect(r);
+        unsafe { DrawTextW(self.hdc, text.as_ptr(), -1, &mut r, flags); }
+    }
+
+    /// Returns the size of a single line of text drawn with the selected font
+    pub fn text_size(&self, text: &str) -> [u32; 2] {
+        use winapi::um::wingdi::GetTextExtentPoint32W;
+        use winapi::shared::windef::SIZE;
+
+        let text: Vec<u16> = text.encode_utf16().collect();
+        let mut size = SIZE { cx: 0, cy: 0 };
+        unsafe { GetTextExtentPoint32W(self.hdc, text.as_ptr(), text.len() as _, &mut size); }
+
+        [size.cx as u32, size.cy as u32]
+    }
+
+    /// Restricts the drawing to the intersection of the current clipping region and `r`
+    pub fn clip_rect(&self, r: [i32; 4]) {
+        use winapi::um::wingdi::IntersectClipRect;
+        unsafe { IntersectClipRect(self.hdc, r[0], r[1], r[2], r[3]); }
+    }
+
+    /// Prevents the drawing in `r`
+    pub fn exclude_clip_rect(&self, r: [i32; 4]) {
+        use winapi::um::wingdi::ExcludeClipRect;
+        unsafe { ExcludeClipRect(self.hdc, r[0], r[1], r[2], r[3]); }
+    }
+
+    /// Removes the clipping set by `clip_rect` and `exclude_clip_rect`.
+    /// The drawing is still limited to the region being painted.
+    pub fn reset_clip(&self) {
+        use winapi::um::wingdi::SelectClipRgn;
+        unsafe { SelectClipRgn(self.hdc, ptr::null_mut()); }
+    }
+
+    /// Copies a bitmap at `point` (the top left corner of the bitmap)
+    pub fn draw_bitmap(&self, bitmap: &Bitmap, point: [i32; 2]) {
+        let [width, height] = self.bitmap_size(bitmap);
+        self.draw_bitmap_scaled(bitmap, [point[0], point[1], point[0] + width, point[1] + height]);
+    }
+
+    /// Copies a bitmap and scales it to fill `r`
+    pub fn draw_bitmap_scaled(&self, bitmap: &Bitmap, r: [i32; 4]) {
+        use winapi::um::wingdi::{CreateCompatibleDC, DeleteDC, SelectObject, BitBlt, StretchBlt, SetStretchBltMode, SRCCOPY, HALFTONE};
+
+        if bitmap.handle.is_null() {
+            return;
+        }
+
+        let [width, height] = self.bitmap_size(bitmap);
+        let [dst_width, dst_height] = [r[2] - r[0], r[3] - r[1]];
+
+        unsafe {
+            let src = CreateCompatibleDC(self.hdc);
+            let old = SelectObject(src, bitmap.handle as _);
+
+            if width == dst_width && height == dst_height {
+                BitBlt(self.hdc, r[0], r[1], width, height, src, 0, 0, SRCCOPY);
+            } else {
+                let old_mode = SetStretchBltMode(self.hdc, HALFTONE as _);
+                StretchBlt(self.hdc, r[0], r[1], dst_width, dst_height, src, 0, 0, width, height, SRCCOPY);
+                SetStretchBltMode(self.hdc, old_mode);
+            }
+
+            SelectObject(src, old);
+            DeleteDC(src);
+        }
+    }
+
+    fn bitmap_size(&self, bitmap: &Bitmap) -> [i32; 2] {
+        use winapi::um::wingdi::{GetObjectW, BITMAP};
+        use std::mem;
+
+        unsafe {
+            let mut info: BITMAP = mem::zeroed();
+            GetObjectW(bitmap.handle as _, mem::size_of::<BITMAP>() as _, &mut info as *mut BITMAP as _);
+            [info.bmWidth, info.bmHeight]
+        }
+    }
+
+}
+
+impl<'a> Drop for Canvas<'a> {
+    fn drop(&mut self) {
+        use winapi::um::wingdi::RestoreDC;
+        unsafe { RestoreDC(self.hdc, self.saved); }
+    }
+}
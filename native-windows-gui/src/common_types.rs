@@ -18,6 +18,12 @@ pub enum VTextAlign {
     Bottom
 }
 
+impl Default for VTextAlign {
+    fn default() -> VTextAlign {
+        VTextAlign::Center
+    }
+}
+
 pub mod keys {
     //! Windows virtual key code
     
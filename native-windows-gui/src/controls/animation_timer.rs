@@ -220,44 +220,58 @@ impl AnimationTimer {
         wh::window_valid(hwnd)
     }
 
-    /**
-        Start the selected timer. If the timer is already running this resets it.
-        This resets the life time and tick count if relevant.
-    */
-    pub fn start(&self) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (_, id) = self.handle.timer().expect(BAD_HANDLE);
-        AnimationThread::reset_timer(id);
-    }
+    try_accessors! {
+        errors = "Returns an `InvalidHandle` error if the timer is not bound.";
+
+        /**
+            Start the selected timer. If the timer is already running this resets it.
+            This resets the life time and tick count if relevant.
+        */
+        pub fn start(&self) => try_start {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (_, id) = self.handle.timer().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+            AnimationThread::reset_timer(id);
+
+            Ok(())
+        }
 
-    /**
-        Stop the selected timer. If the timer is already stopped, this does nothing.
-    */
-    pub fn stop(&self) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (_, id) = self.handle.timer().expect(BAD_HANDLE);
-        AnimationThread::stop_timer(id);
-    }
+        /**
+            Stop the selected timer. If the timer is already stopped, this does nothing.
+        */
+        pub fn stop(&self) => try_stop {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (_, id) = self.handle.timer().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+            AnimationThread::stop_timer(id);
 
-    /// Sets the interval on the this timer
-    pub fn set_interval(&self, i: Duration) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (_, id) = self.handle.timer().expect(BAD_HANDLE);
-        AnimationThread::update_timer(id, Some(i), None, None);
-    }
+            Ok(())
+        }
 
-    /// Sets the life time on the this timer
-    pub fn set_lifetime(&self, life: Option<Duration>) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (_, id) = self.handle.timer().expect(BAD_HANDLE);
-        AnimationThread::update_timer(id, None, Some(life), None);
-    }
+        /// Sets the interval on the this timer
+        pub fn set_interval(&self, i: Duration) => try_set_interval {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (_, id) = self.handle.timer().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+            AnimationThread::update_timer(id, Some(i), None, None);
 
-    /// Sets the max tick count on the this timer
-    pub fn set_max_tick(&self, max_tick: Option<u64>) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (_, id) = self.handle.timer().expect(BAD_HANDLE);
-        AnimationThread::update_timer(id, None, None, Some(max_tick));
+            Ok(())
+        }
+
+        /// Sets the life time on the this timer
+        pub fn set_lifetime(&self, life: Option<Duration>) => try_set_lifetime {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (_, id) = self.handle.timer().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+            AnimationThread::update_timer(id, None, Some(life), None);
+
+            Ok(())
+        }
+
+        /// Sets the max tick count on the this timer
+        pub fn set_max_tick(&self, max_tick: Option<u64>) => try_set_max_tick {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (_, id) = self.handle.timer().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+            AnimationThread::update_timer(id, None, None, Some(max_tick));
+
+            Ok(())
+        }
     }
 
 }
@@ -0,0 +1,284 @@
+use winapi::shared::minwindef::DWORD;
+use crate::win32::autocomplete as ac;
+use crate::win32::base_helper::{to_utf16, from_utf16};
+use crate::NwgError;
+use super::ControlHandle;
+use std::sync::{Arc, Mutex};
+
+const NOT_BOUND: &'static str = "AutoComplete is not yet bound to a winapi object";
+const NOT_CUSTOM: &'static str = "AutoComplete source is not `AutoCompleteSource::Items`";
+
+
+bitflags! {
+    /**
+        The autocomplete flags
+
+        * SUGGEST:                 Display a dropdown with the suggestions matching the text typed by the user
+        * APPEND:                  Complete the text in the control with the first suggestion. The appended text is selected.
+        * USE_TAB:                 The TAB key selects an item in the dropdown instead of moving the focus to the next control
+        * UP_DOWN_KEYS_DROP_LIST:  Pressing the UP or DOWN keys opens the dropdown even if the control is empty. Only used with `AutoCompleteSource::Items`.
+        * WORD_FILTER:             Match the typed text against every word of the suggestions. Only used with `AutoCompleteSource::Items`.
+    */
+    pub struct AutoCompleteFlags: u32 {
+        const SUGGEST = 0x01;
+        const APPEND = 0x02;
+        const USE_TAB = 0x04;
+        const UP_DOWN_KEYS_DROP_LIST = 0x08;
+        const WORD_FILTER = 0x10;
+    }
+}
+
+/**
+    Where the autocomplete suggestions come from
+
+    * FileSystem: The files and folders of the file system
+    * FileSystemDirectories: The folders of the file system
+    * Url: The urls in the history and the recently used list of the user
+    * Items: The list of items supplied by the application
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AutoCompleteSource {
+    FileSystem,
+    FileSystemDirectories,
+    Url,
+    Items,
+}
+
+/**
+An autocomplete component adds a suggestion dropdown to an existing `TextInput` (or any other edit control).
+The suggestions can come from the system (file system paths or urls) or from a list of items supplied by the application.
+
+AutoComplete is not a window. It is attached to its parent edit control and is detached when it is dropped.
+Note that system sources (`FileSystem`, `FileSystemDirectories` and `Url`) cannot be detached and will remain active until the edit control is destroyed.
+
+Requires the `autocomplete` feature.
+
+**Builder parameters:**
+  * `parent`:   **Required.** The edit control that receives the suggestions.
+  * `source`:   Where the suggestions come from. Defaults to `AutoCompleteSource::Items`.
+  * `items`:    The initial suggestions. Only used with `AutoCompleteSource::Items`.
+  * `flags`:    A combination of the AutoCompleteFlags values. Defaults to `SUGGEST`.
+
+```rust
+use native_windows_gui as nwg;
+fn build_autocomplete(complete: &mut nwg::AutoComplete, input: &nwg::TextInput) {
+    nwg::AutoComplete::builder()
+        .parent(input)
+        .items(&["Apple", "Banana", "Cherry"])
+        .flags(nwg::AutoCompleteFlags::SUGGEST | nwg::AutoCompleteFlags::APPEND)
+        .build(complete);
+}
+```
+*/
+#[derive(Default)]
+pub struct AutoComplete {
+    /// Handle of the edit control the autocomplete is attached to
+    pub handle: ControlHandle,
+    source: Option<AutoCompleteSource>,
+    items: ac::SuggestionList,
+    auto_complete: Option<usize>,
+}
+
+impl AutoComplete {
+
+    pub fn builder<'a>() -> AutoCompleteBuilder<'a> {
+        AutoCompleteBuilder {
+            source: AutoCompleteSource::Items,
+            items: &[],
+            flags: None,
+            parent: None,
+        }
+    }
+
+    /// Return the source of the suggestions
+    pub fn source(&self) -> AutoCompleteSource {
+        self.source.expect(NOT_BOUND)
+    }
+
+    /// Return the suggestions supplied by the application
+    pub fn items(&self) -> Vec<String> {
+        let items = self.items.lock().unwrap();
+        items.iter().map(|i| from_utf16(i)).collect()
+    }
+
+    /// Replace the suggestions supplied by the application.
+    /// Panics if the source of the autocomplete is not `AutoCompleteSource::Items`.
+    pub fn set_items<S: AsRef<str>>(&self, items: &[S]) {
+        let auto_complete = self.custom();
+
+        {
+            let mut list = self.items.lock().unwrap();
+            *list = items.iter().map(|i| without_nul(to_utf16(i.as_ref()))).collect();
+        }
+
+        unsafe { ac::reset_autocomplete(auto_complete); }
+    }
+
+    /// Enable or disable the autocomplete. A disabled autocomplete keeps its items.
+    /// Panics if the source of the autocomplete is not `AutoCompleteSource::Items`.
+    pub fn set_enabled(&self, enabled: bool) {
+        let auto_complete = self.custom();
+        unsafe { auto_complete.Enable(enabled as _); }
+    }
+
+    /// Return the autocomplete flags.
+    /// Panics if the source of the autocomplete is not `AutoCompleteSource::Items`.
+    pub fn flags(&self) -> AutoCompleteFlags {
+        let auto_complete = self.custom();
+        let mut options: DWORD = 0;
+        unsafe { auto_complete.GetOptions(&mut options); }
+
+        let mut flags = AutoCompleteFlags::empty();
+        if options & ac::ACO_AUTOSUGGEST != 0 { flags |= AutoCompleteFlags::SUGGEST; }
+        if options & ac::ACO_AUTOAPPEND != 0 { flags |= AutoCompleteFlags::APPEND; }
+        if options & ac::ACO_USETAB != 0 { flags |= AutoCompleteFlags::USE_TAB; }
+        if options & ac::ACO_UPDOWNKEYDROPSLIST != 0 { flags |= AutoCompleteFlags::UP_DOWN_KEYS_DROP_LIST; }
+        if options & ac::ACO_WORDFILTER != 0 { flags |= AutoCompleteFlags::WORD_FILTER; }
+
+        flags
+    }
+
+    /// Change the autocomplete flags.
+    /// Panics if the source of the autocomplete is not `AutoCompleteSource::Items`.
+    pub fn set_flags(&self, flags: AutoCompleteFlags) {
+        let auto_complete = self.custom();
+        unsafe { auto_complete.SetOptions(item_options(flags)); }
+    }
+
+    try_accessors! {
+        /// Check if the suggestion dropdown is currently visible.
+        /// Always returns `false` if the source of the autocomplete is not `AutoCompleteSource::Items`.
+        pub fn dropdown_visible(&self) -> bool => try_dropdown_visible {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            Ok(match self.auto_complete {
+                Some(ptr) => unsafe { ac::autocomplete_dropdown_visible(&*(ptr as *mut ac::IAutoComplete2)) },
+                None => false
+            })
+        }
+    }
+
+    fn custom(&self) -> &ac::IAutoComplete2 {
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        match self.auto_complete {
+            Some(ptr) => unsafe { &*(ptr as *mut ac::IAutoComplete2) },
+            None => panic!("{}", NOT_CUSTOM)
+        }
+    }
+
+}
+
+impl Drop for AutoComplete {
+    fn drop(&mut self) {
+        if let Some(ptr) = self.auto_complete.take() {
+            unsafe {
+                let auto_complete = &*(ptr as *mut ac::IAutoComplete2);
+                auto_complete.Enable(0);
+                auto_complete.Release();
+            }
+        }
+
+        self.handle = ControlHandle::NoHandle;
+    }
+}
+
+pub struct AutoCompleteBuilder<'a> {
+    source: AutoCompleteSource,
+    items: &'a [&'a str],
+    flags: Option<AutoCompleteFlags>,
+    parent: Option<ControlHandle>,
+}
+
+impl<'a> AutoCompleteBuilder<'a> {
+
+    pub fn source(mut self, source: AutoCompleteSource) -> AutoCompleteBuilder<'a> {
+        self.source = source;
+        self
+    }
+
+    pub fn items(mut self, items: &'a [&'a str]) -> AutoCompleteBuilder<'a> {
+        self.items = items;
+        self
+    }
+
+    pub fn flags(mut self, flags: AutoCompleteFlags) -> AutoCompleteBuilder<'a> {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> AutoCompleteBuilder<'a> {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn build(self, out: &mut AutoComplete) -> Result<(), NwgError> {
+        let hwnd = match self.parent {
+            Some(p) => match p.hwnd() {
+                Some(hwnd) => hwnd,
+                None => { return Err(NwgError::control_create("AutoComplete parent must be an edit control")); }
+            },
+            None => { return Err(NwgError::no_parent("AutoComplete")); }
+        };
+
+        *out = AutoComplete::default();
+
+        let flags = self.flags.unwrap_or(AutoCompleteFlags::SUGGEST);
+        match self.source {
+            AutoCompleteSource::Items => {
+                let items = self.items.iter().map(|i| without_nul(to_utf16(i))).collect();
+                out.items = Arc::new(Mutex::new(items));
+
+                let auto_complete = unsafe { ac::create_autocomplete(hwnd, out.items.clone(), item_options(flags))? };
+                out.auto_complete = Some(auto_complete as usize);
+            },
+            source => unsafe {
+                ac::shell_autocomplete(hwnd, shell_options(source, flags))?;
+            }
+        }
+
+        out.source = Some(self.source);
+        out.handle = ControlHandle::Hwnd(hwnd);
+
+        Ok(())
+    }
+
+}
+
+fn without_nul(mut text: Vec<u16>) -> Vec<u16> {
+    text.pop();
+    text
+}
+
+fn item_options(flags: AutoCompleteFlags) -> DWORD {
+    let mut options = 0;
+    if flags.contains(AutoCompleteFlags::SUGGEST) { options |= ac::ACO_AUTOSUGGEST; }
+    if flags.contains(AutoCompleteFlags::APPEND) { options |= ac::ACO_AUTOAPPEND; }
+    if flags.contains(AutoCompleteFlags::USE_TAB) { options |= ac::ACO_USETAB; }
+    if flags.contains(AutoCompleteFlags::UP_DOWN_KEYS_DROP_LIST) { options |= ac::ACO_UPDOWNKEYDROPSLIST; }
+    if flags.contains(AutoCompleteFlags::WORD_FILTER) { options |= ac::ACO_WORDFILTER; }
+    options
+}
+
+fn shell_options(source: AutoCompleteSource, flags: AutoCompleteFlags) -> DWORD {
+    let mut options = match source {
+        AutoCompleteSource::FileSystem => ac::SHACF_FILESYSTEM,
+        AutoCompleteSource::FileSystemDirectories => ac::SHACF_FILESYS_DIRS,
+        AutoCompleteSource::Url => ac::SHACF_URLALL,
+        AutoCompleteSource::Items => unreachable!(),
+    };
+
+    options |= match flags.contains(AutoCompleteFlags::SUGGEST) {
+        true => ac::SHACF_AUTOSUGGEST_FORCE_ON,
+        false => ac::SHACF_AUTOSUGGEST_FORCE_OFF
+    };
+
+    options |= match flags.contains(AutoCompleteFlags::APPEND) {
+        true => ac::SHACF_AUTOAPPEND_FORCE_ON,
+        false => ac::SHACF_AUTOAPPEND_FORCE_OFF
+    };
+
+    if flags.contains(AutoCompleteFlags::USE_TAB) {
+        options |= ac::SHACF_USETAB;
+    }
+
+    options
+}
@@ -1,11 +1,20 @@
-use winapi::um::winuser::{WS_DISABLED, BS_ICON, BS_BITMAP, BS_NOTIFY, WS_VISIBLE, WS_TABSTOP, WS_CHILD};
+use winapi::um::winuser::{WS_DISABLED, BS_ICON, BS_BITMAP, BS_NOTIFY, BS_PUSHBUTTON, BS_OWNERDRAW, WS_VISIBLE, WS_TABSTOP, WS_CHILD};
+use winapi::shared::windef::HWND;
+use winapi::um::commctrl::{BS_SPLITBUTTON, BS_COMMANDLINK};
 use crate::win32::{
-    base_helper::check_hwnd,  
+    base_helper::{try_check_hwnd, to_utf16, from_utf16},
     window_helper as wh,
     resources_helper as rh
 };
-use crate::{NwgError, Font, Bitmap, Icon};
+use crate::{NwgError, Font, Bitmap, Icon, RawEventHandler, unbind_raw_event_handler};
 use super::{ControlBase, ControlHandle};
+use std::cell::{Cell, RefCell};
+
+#[cfg(feature="image-list")]
+use crate::ImageList;
+
+#[cfg(feature="menu")]
+use crate::Menu;
 
 const NOT_BOUND: &'static str = "Button is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: Button handle is not HWND!";
@@ -22,6 +31,9 @@ bitflags! {
         * ICON:     The button will display a icon image with no text. Must have a icon or else it will only show text.
         * NOTIFY:   Enable the `OnButtonDoubleClick` event
         * TAB_STOP: The control can be selected using tab navigation
+        * SPLIT:    The button has a drop down arrow on its right side. Clicking the arrow raises `OnButtonDropDown` instead of `OnButtonClick`.
+        * COMMAND_LINK: The button is a command link: a large button with an arrow, the text as the main instruction and a note line under it. Cannot be used with `SPLIT`.
+        * OWNER_DRAW: The button is painted by the application in the `OnDrawItem` event. Cannot be used with `SPLIT` or `COMMAND_LINK`.
     */
     pub struct ButtonFlags: u32 {
         const NONE = 0;
@@ -31,7 +43,54 @@ bitflags! {
         const BITMAP = BS_BITMAP;
         const NOTIFY = BS_NOTIFY;
         const TAB_STOP = WS_TABSTOP;
+        const SPLIT = BS_SPLITBUTTON;
+        const COMMAND_LINK = BS_COMMANDLINK;
+        const OWNER_DRAW = BS_OWNERDRAW;
+    }
+}
+
+/**
+    The alignment of the images of a button image list. See `Button::set_image_list`
+*/
+#[cfg(feature="image-list")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ButtonImageAlign {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Center
+}
+
+#[cfg(feature="image-list")]
+impl ButtonImageAlign {
+
+    fn to_raw(&self) -> u32 {
+        use winapi::um::commctrl::{BUTTON_IMAGELIST_ALIGN_LEFT, BUTTON_IMAGELIST_ALIGN_RIGHT, BUTTON_IMAGELIST_ALIGN_TOP,
+            BUTTON_IMAGELIST_ALIGN_BOTTOM, BUTTON_IMAGELIST_ALIGN_CENTER};
+
+        match self {
+            Self::Left => BUTTON_IMAGELIST_ALIGN_LEFT,
+            Self::Right => BUTTON_IMAGELIST_ALIGN_RIGHT,
+            Self::Top => BUTTON_IMAGELIST_ALIGN_TOP,
+            Self::Bottom => BUTTON_IMAGELIST_ALIGN_BOTTOM,
+            Self::Center => BUTTON_IMAGELIST_ALIGN_CENTER,
+        }
+    }
+
+    fn from_raw(value: u32) -> ButtonImageAlign {
+        use winapi::um::commctrl::{BUTTON_IMAGELIST_ALIGN_RIGHT, BUTTON_IMAGELIST_ALIGN_TOP,
+            BUTTON_IMAGELIST_ALIGN_BOTTOM, BUTTON_IMAGELIST_ALIGN_CENTER};
+
+        match value {
+            BUTTON_IMAGELIST_ALIGN_RIGHT => Self::Right,
+            BUTTON_IMAGELIST_ALIGN_TOP => Self::Top,
+            BUTTON_IMAGELIST_ALIGN_BOTTOM => Self::Bottom,
+            BUTTON_IMAGELIST_ALIGN_CENTER => Self::Center,
+            _ => Self::Left,
+        }
     }
+
 }
 
 /**
@@ -51,11 +110,20 @@ Button is not behind any features.
   * `font`:     The font used for the button text
   * `bitmap`:   A bitmap to display next to the button text. If this value is set, icon is ignored.
   * `icon`:     An icon to display next to the button text
+  * `image_list`:  An image list with the images of each state of the button. If this value is set, bitmap and icon are ignored. Requires the `image-list` feature.
+  * `image_align`: The alignment of the image list images in the button. Requires the `image-list` feature.
+  * `drop_down_menu`: A popup menu shown under the button when its drop down arrow is clicked. Requires the `SPLIT` flag and the `menu` feature.
+  * `note`:     The note displayed under the text of a command link button. Requires the `COMMAND_LINK` flag.
+  * `shield`:   Display the shield icon in the button to indicate that the action requires elevation
+  * `default`:  The button is clicked when the user presses ENTER in the window. See `Button::set_default`.
+  * `cancel`:   The button is clicked when the user presses ESCAPE in the window. See `Button::set_cancel`.
   * `focus`:    The control receive focus after being created
 
 **Control events:**
   * `OnButtonClick`: When the button is clicked once by the user
   * `OnButtonDoubleClick`: When the button is clicked twice rapidly by the user
+  * `OnButtonDropDown`: When the drop down arrow of a split button is clicked by the user
+  * `OnDrawItem`: When an owner drawn button must be painted. Sent to the button handle.
   * `MousePress(_)`: Generic mouse press events on the button
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -72,10 +140,39 @@ fn build_button(button: &mut nwg::Button, window: &nwg::Window, font: &nwg::Font
 }
 ```
 
+```rust
+use native_windows_gui as nwg;
+fn build_split_button(button: &mut nwg::Button, window: &nwg::Window, menu: &nwg::Menu) {
+    nwg::Button::builder()
+        .text("Save")
+        .flags(nwg::ButtonFlags::VISIBLE | nwg::ButtonFlags::SPLIT)
+        .drop_down_menu(Some(menu))
+        .parent(window)
+        .build(button);
+}
+```
+
+```rust
+use native_windows_gui as nwg;
+fn build_command_link(button: &mut nwg::Button, window: &nwg::Window) {
+    nwg::Button::builder()
+        .text("Install for all users")
+        .note("Administrator rights are required")
+        .shield(true)
+        .size((300, 60))
+        .flags(nwg::ButtonFlags::VISIBLE | nwg::ButtonFlags::COMMAND_LINK)
+        .parent(window)
+        .build(button);
+}
+```
+
 */
-#[derive(Default, Eq, PartialEq)]
+#[derive(Default)]
 pub struct Button {
-    pub handle: ControlHandle
+    pub handle: ControlHandle,
+    cancel: Cell<bool>,
+    handler0: RefCell<Option<RawEventHandler>>,
+    handler1: RefCell<Option<RawEventHandler>>,
 }
 
 impl Button {
@@ -92,154 +189,448 @@ impl Button {
             parent: None,
             bitmap: None,
             icon: None,
+            #[cfg(feature="image-list")]
+            image_list: None,
+            #[cfg(feature="image-list")]
+            image_align: ButtonImageAlign::Left,
+            #[cfg(feature="menu")]
+            drop_down_menu: None,
+            note: None,
+            shield: false,
+            default: false,
+            cancel: false,
             focus: false
         }
     }
 
-    /// Simulate a user click
-    pub fn click(&self) {
-        use winapi::um::winuser::BM_CLICK;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, BM_CLICK, 0, 0);
-    }
+    try_accessors! {
+        /// Simulate a user click
+        pub fn click(&self) => try_click {
+            use winapi::um::winuser::BM_CLICK;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, BM_CLICK, 0, 0);
 
-    /// Sets the bitmap image of the button. Replace the current bitmap or icon.
-    /// Set `image` to `None` to remove the image
-    pub fn set_bitmap<'a>(&self, image: Option<&'a Bitmap>) {
-        use winapi::um::winuser::{BM_SETIMAGE, IMAGE_BITMAP};
-        use winapi::shared::minwindef::{WPARAM, LPARAM};
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Sets the bitmap image of the button. Replace the current bitmap or icon.
+        /// Set `image` to `None` to remove the image
+        pub fn set_bitmap<'a>(&self, image: Option<&'a Bitmap>) => try_set_bitmap {
+            use winapi::um::winuser::{BM_SETIMAGE, IMAGE_BITMAP};
+            use winapi::shared::minwindef::{WPARAM, LPARAM};
 
-        let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
-        wh::send_message(handle, BM_SETIMAGE, IMAGE_BITMAP as WPARAM, image_handle);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Sets the bitmap image of the button. Replace the current bitmap or icon.
-    /// Set `image` to `None` to remove the image
-    pub fn set_icon<'a>(&self, image: Option<&'a Icon>) {
-        use winapi::um::winuser::{BM_SETIMAGE, IMAGE_ICON};
-        use winapi::shared::minwindef::{WPARAM, LPARAM};
+            let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
+            wh::send_message(handle, BM_SETIMAGE, IMAGE_BITMAP as WPARAM, image_handle);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            Ok(())
+        }
 
-        let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
-        wh::send_message(handle, BM_SETIMAGE, IMAGE_ICON as WPARAM, image_handle);
-    }
+        /// Sets the bitmap image of the button. Replace the current bitmap or icon.
+        /// Set `image` to `None` to remove the image
+        pub fn set_icon<'a>(&self, image: Option<&'a Icon>) => try_set_icon {
+            use winapi::um::winuser::{BM_SETIMAGE, IMAGE_ICON};
+            use winapi::shared::minwindef::{WPARAM, LPARAM};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Returns the current image in the button.
-    /// If the button has a bitmap, the value will be returned in `bitmap`
-    /// If the button has a icon, the value will be returned in `icon`
-    pub fn image<'a>(&self, bitmap: &mut Option<Bitmap>, icon: &mut Option<Icon>) {
-        use winapi::um::winuser::{BM_GETIMAGE, IMAGE_BITMAP, IMAGE_ICON};
-        use winapi::shared::minwindef::WPARAM;
-        use winapi::shared::windef::HBITMAP;
-        use winapi::um::winnt::HANDLE;
+            let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
+            wh::send_message(handle, BM_SETIMAGE, IMAGE_ICON as WPARAM, image_handle);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            Ok(())
+        }
+
+        /// Returns the current image in the button.
+        /// If the button has a bitmap, the value will be returned in `bitmap`
+        /// If the button has a icon, the value will be returned in `icon`
+        pub fn image<'a>(&self, bitmap: &mut Option<Bitmap>, icon: &mut Option<Icon>) => try_image {
+            use winapi::um::winuser::{BM_GETIMAGE, IMAGE_BITMAP, IMAGE_ICON};
+            use winapi::shared::minwindef::WPARAM;
+            use winapi::shared::windef::HBITMAP;
+            use winapi::um::winnt::HANDLE;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let bitmap_handle = wh::send_message(handle, BM_GETIMAGE, IMAGE_BITMAP as WPARAM, 0);
+            let icon_handle = wh::send_message(handle, BM_GETIMAGE, IMAGE_ICON as WPARAM, 0);
 
-        let bitmap_handle = wh::send_message(handle, BM_GETIMAGE, IMAGE_BITMAP as WPARAM, 0);
-        let icon_handle = wh::send_message(handle, BM_GETIMAGE, IMAGE_ICON as WPARAM, 0);
+            *bitmap = None;
+            *icon = None;
 
-        *bitmap = None;
-        *icon = None;
+            if bitmap_handle != 0 && rh::is_bitmap(bitmap_handle as HBITMAP) {
+                *bitmap = Some(Bitmap { handle: bitmap_handle as HANDLE, owned: false });
+            } else if icon_handle != 0 {
+                *icon = Some(Icon { handle: icon_handle as HANDLE, owned: false });
+            }
 
-        if bitmap_handle != 0 && rh::is_bitmap(bitmap_handle as HBITMAP) {
-            *bitmap = Some(Bitmap { handle: bitmap_handle as HANDLE, owned: false });
-        } else if icon_handle != 0 {
-            *icon = Some(Icon { handle: icon_handle as HANDLE, owned: false });
+            Ok(())
         }
-    }
 
-    /// Returns the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Returns true if the button only displays its image (the `BITMAP` or `ICON` flag is set).
+        pub fn image_only(&self) -> bool => try_image_only {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::get_style(handle) & (BS_BITMAP | BS_ICON) != 0)
+        }
 
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+        /// Show or hide the button text when the button has an image set with `set_bitmap` or `set_icon`.
+        /// If `image_only` is false, the image is displayed next to the text.
+        pub fn set_image_only(&self, image_only: bool) => try_set_image_only {
+            use winapi::um::winuser::{BM_GETIMAGE, IMAGE_BITMAP, InvalidateRect};
+            use std::ptr;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let mut style = wh::get_style(handle) & !(BS_BITMAP | BS_ICON);
+            if image_only {
+                match wh::send_message(handle, BM_GETIMAGE, IMAGE_BITMAP as _, 0) {
+                    0 => { style |= BS_ICON; },
+                    _ => { style |= BS_BITMAP; }
+                }
+            }
+
+            wh::set_style(handle, style);
+            unsafe { InvalidateRect(handle, ptr::null(), 1); }
+
+            Ok(())
         }
-    }
 
-    /// Sets the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /**
+            Sets an image list that contains the images of each state of the button. The image list replaces the bitmap or icon of the button.
+            Set `list` to `None` to remove the image list.
 
-    /// Returns true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+            If the image list contains a single image, it is used for every state. Otherwise the images are used in this order:
+            normal, hover, pressed, disabled, defaulted (the button has the focus) and stylus hover.
 
-    /// Sets the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+            The image list is not owned by the button and must outlive it.
+        */
+        #[cfg(feature="image-list")]
+        pub fn set_image_list(&self, list: Option<&ImageList>, align: ButtonImageAlign) => try_set_image_list {
+            use winapi::um::commctrl::{BCM_SETIMAGELIST, BUTTON_IMAGELIST};
+            use winapi::um::winuser::InvalidateRect;
+            use winapi::shared::windef::RECT;
+            use std::ptr;
 
-    /// Returns true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+            let mut info = BUTTON_IMAGELIST {
+                himl: list.map(|l| l.handle).unwrap_or(ptr::null_mut()),
+                margin: RECT { left: 0, top: 0, right: 0, bottom: 0 },
+                uAlign: align.to_raw(),
+            };
 
-    /// Returns true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            wh::send_message(handle, BCM_SETIMAGELIST, 0, &mut info as *mut BUTTON_IMAGELIST as _);
+            unsafe { InvalidateRect(handle, ptr::null(), 1); }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Returns the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Returns the image list of the button and the alignment of its images. The returned image list will not be owned.
+        /// Returns `None` if there is no image list.
+        #[cfg(feature="image-list")]
+        pub fn image_list(&self) -> Option<(ImageList, ButtonImageAlign)> => try_image_list {
+            use winapi::um::commctrl::{BCM_GETIMAGELIST, BUTTON_IMAGELIST};
 
-    /// Sets the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Returns the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+            let mut info: BUTTON_IMAGELIST = unsafe { ::std::mem::zeroed() };
+            wh::send_message(handle, BCM_GETIMAGELIST, 0, &mut info as *mut BUTTON_IMAGELIST as _);
 
-    /// Sets the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
-    }
+            Ok(match info.himl.is_null() {
+                true => None,
+                false => Some((ImageList { handle: info.himl, owned: false }, ButtonImageAlign::from_raw(info.uAlign)))
+            })
+        }
 
-    /// Returns the button label
-    pub fn text(&self) -> String { 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_text(handle) }
-    }
+        /// Returns true if the button has a drop down arrow (the `SPLIT` flag is set)
+        pub fn split(&self) -> bool => try_split {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(button_type(handle) == BS_SPLITBUTTON)
+        }
+
+        /// Add or remove the drop down arrow of the button. This also turns a command link into a regular button.
+        pub fn set_split(&self, split: bool) => try_set_split {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            match split {
+                true => set_button_type(handle, BS_SPLITBUTTON),
+                false => set_button_type(handle, BS_PUSHBUTTON),
+            }
+
+            Ok(())
+        }
+
+        /// Returns true if the button is a command link (the `COMMAND_LINK` flag is set)
+        pub fn command_link(&self) -> bool => try_command_link {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(button_type(handle) == BS_COMMANDLINK)
+        }
+
+        /// Turn the button into a command link or back into a regular button. This also removes the drop down arrow of a split button.
+        pub fn set_command_link(&self, command_link: bool) => try_set_command_link {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            match command_link {
+                true => set_button_type(handle, BS_COMMANDLINK),
+                false => set_button_type(handle, BS_PUSHBUTTON),
+            }
+
+            Ok(())
+        }
+
+        /// Returns the note displayed under the text of a command link button
+        pub fn note(&self) -> String => try_note {
+            use winapi::um::commctrl::{BCM_GETNOTE, BCM_GETNOTELENGTH};
+            use winapi::shared::minwindef::DWORD;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let length = wh::send_message(handle, BCM_GETNOTELENGTH, 0, 0) as usize;
+            if length == 0 {
+                return Ok(String::new());
+            }
+
+            let mut size = (length + 1) as DWORD;
+            let mut buffer: Vec<u16> = vec![0; length + 1];
+            wh::send_message(handle, BCM_GETNOTE, &mut size as *mut DWORD as _, buffer.as_mut_ptr() as _);
+
+            Ok(from_utf16(&buffer))
+        }
+
+        /// Sets the note displayed under the text of a command link button. Does nothing if the button is not a command link.
+        pub fn set_note<'a>(&self, note: &'a str) => try_set_note {
+            use winapi::um::commctrl::BCM_SETNOTE;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let note = to_utf16(note);
+            wh::send_message(handle, BCM_SETNOTE, 0, note.as_ptr() as _);
+
+            Ok(())
+        }
+
+        /// Show or hide the shield icon that indicates that the button action requires elevation.
+        /// For command link buttons, the shield replaces the arrow.
+        pub fn set_shield(&self, shield: bool) => try_set_shield {
+            use winapi::um::commctrl::BCM_SETSHIELD;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, BCM_SETSHIELD, 0, shield as _);
+
+            Ok(())
+        }
+
+        /**
+            Sets the popup menu shown under the button when the user clicks its drop down arrow.
+            Set `menu` to `None` to remove the menu. The `OnButtonDropDown` event is still raised when a menu is set.
+
+            The menu must be a popup menu and the button must have the `SPLIT` flag. The items of the menu
+            raise `OnMenuItemSelected` on the menu parent window, like with `Menu::popup`.
+
+            The menu is not owned by the button and must outlive it.
+        */
+        #[cfg(feature="menu")]
+        pub fn set_drop_down_menu<'a>(&self, menu: Option<&'a Menu>) => try_set_drop_down_menu {
+            use crate::bind_raw_event_handler_inner;
+            use winapi::um::winuser::{WM_NOTIFY, GetWindowRect, TrackPopupMenu, SetForegroundWindow, TPM_LEFTALIGN, TPM_TOPALIGN};
+            use winapi::um::commctrl::BCN_DROPDOWN;
+            use winapi::shared::{basetsd::UINT_PTR, windef::RECT};
+            use winapi::um::winuser::NMHDR;
+            use std::{mem, ptr};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            if let Some(h) = self.handler0.borrow_mut().take() {
+                drop(unbind_raw_event_handler(&h));
+            }
+
+            let (owner, hmenu) = match menu.and_then(|m| m.handle.pop_hmenu()) {
+                Some(v) => v,
+                None => { return Ok(()); }
+            };
+
+            let parent_handle = ControlHandle::Hwnd(wh::get_window_parent(handle));
+            let handler = bind_raw_event_handler_inner(&parent_handle, handle as UINT_PTR, move |_hwnd, msg, _w, l| { unsafe {
+                if msg == WM_NOTIFY {
+                    let notif = &*(l as *const NMHDR);
+                    if notif.hwndFrom == handle && notif.code == BCN_DROPDOWN {
+                        let mut rect: RECT = mem::zeroed();
+                        GetWindowRect(handle, &mut rect);
+
+                        SetForegroundWindow(owner);
+                        TrackPopupMenu(hmenu, TPM_LEFTALIGN | TPM_TOPALIGN, rect.left, rect.bottom, 0, owner, ptr::null());
+                    }
+                }
+
+                None
+            } });
+
+            *self.handler0.borrow_mut() = Some(handler?);
+
+            Ok(())
+        }
+
+        /// Returns true if the button is the default button of its window
+        pub fn is_default(&self) -> bool => try_is_default {
+            use winapi::um::winuser::{BS_TYPEMASK, BS_DEFPUSHBUTTON};
+            use winapi::um::commctrl::{BS_DEFSPLITBUTTON, BS_DEFCOMMANDLINK};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(match wh::get_style(handle) & BS_TYPEMASK {
+                BS_DEFPUSHBUTTON | BS_DEFSPLITBUTTON | BS_DEFCOMMANDLINK => true,
+                _ => false
+            })
+        }
+
+        /**
+            Make the button the default button of its window. The default button has a thicker border and it is clicked
+            (raising `OnButtonClick`) when the user presses ENTER while a control that does not handle the key has the focus.
+            If the focus is on another button, that button is clicked instead.
+
+            The window still raises `OnKeyEnter`. A window should have a single default button.
+            Owner drawn buttons cannot be default buttons and this function does nothing.
+        */
+        pub fn set_default(&self, default: bool) => try_set_default {
+            use winapi::um::winuser::{BS_TYPEMASK, BM_SETSTYLE};
+            use winapi::shared::minwindef::WPARAM;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let button_type = button_type(handle);
+            if button_type == BS_OWNERDRAW {
+                return Ok(());
+            }
+
+            // The "default" variant of each button type is the type value + 1
+            let style = (wh::get_style(handle) & 0xFFFF & !BS_TYPEMASK) | (button_type + default as u32);
+            wh::send_message(handle, BM_SETSTYLE, style as WPARAM, 1);
+
+            self.bind_dialog_keys(handle);
+
+            Ok(())
+        }
+
+        /// Returns true if the button is the cancel button of its window
+        pub fn is_cancel(&self) -> bool => try_is_cancel {
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(self.cancel.get())
+        }
+
+        /**
+            Make the button the cancel button of its window. The cancel button is clicked (raising `OnButtonClick`)
+            when the user presses ESCAPE in the window.
+
+            The window still raises `OnKeyEsc`. A window should have a single cancel button.
+        */
+        pub fn set_cancel(&self, cancel: bool) => try_set_cancel {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            self.cancel.set(cancel);
+            self.bind_dialog_keys(handle);
+
+            Ok(())
+        }
+
+        /// Returns the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
+        }
+
+        /// Sets the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
+
+            Ok(())
+        }
+
+        /// Returns true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
+
+        /// Sets the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
+
+            Ok(())
+        }
+
+        /// Returns true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
+
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
+
+            Ok(())
+        }
+
+        /// Returns true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
 
-    /// Sets the button label
-    pub fn set_text<'a>(&self, v: &'a str) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
+        /// Returns the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Sets the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
+        }
+
+        /// Returns the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Sets the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Returns the button label
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
+
+        /// Sets the button label
+        pub fn set_text<'a>(&self, v: &'a str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, v) }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -259,12 +650,100 @@ impl Button {
 
 }
 
+impl Button {
+
+    /// Click the button when the dialog manager (`IsDialogMessage`) receives ENTER or ESCAPE for the top level window.
+    /// The dialog manager sends `WM_COMMAND` with the `IDOK` or `IDCANCEL` id to the window. That command is also used by `OnKeyEnter` and `OnKeyEsc`.
+    fn bind_dialog_keys(&self, handle: HWND) {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_COMMAND, BN_CLICKED, IDOK, IDCANCEL, GA_ROOT, GetAncestor, GetDlgCtrlID, IsWindowEnabled, IsWindowVisible};
+        use winapi::shared::minwindef::{WPARAM, LPARAM, LOWORD, MAKELONG};
+        use winapi::shared::basetsd::UINT_PTR;
+
+        if let Some(h) = self.handler1.borrow_mut().take() {
+            drop(unbind_raw_event_handler(&h));
+        }
+
+        let (default, cancel) = (self.is_default(), self.cancel.get());
+        if !default && !cancel {
+            return;
+        }
+
+        // The drop down menu handler may already use `handle` as id on the same window
+        let root = ControlHandle::Hwnd(unsafe { GetAncestor(handle, GA_ROOT) });
+        let handler = bind_raw_event_handler_inner(&root, !(handle as UINT_PTR), move |_hwnd, msg, w, l| { unsafe {
+            if msg == WM_COMMAND && l == 0 {
+                let command = LOWORD(w as u32) as i32;
+                let clicked = (command == IDOK && default) || (command == IDCANCEL && cancel);
+                if clicked && IsWindowEnabled(handle) != 0 && IsWindowVisible(handle) != 0 {
+                    let parent = wh::get_window_parent(handle);
+                    let w = MAKELONG(GetDlgCtrlID(handle) as u16, BN_CLICKED) as u32 as WPARAM;
+                    wh::send_message(parent, WM_COMMAND, w, handle as LPARAM);
+                }
+            }
+
+            None
+        } });
+
+        *self.handler1.borrow_mut() = Some(handler.unwrap());
+    }
+
+}
+
 impl Drop for Button {
     fn drop(&mut self) {
+        let handler = self.handler0.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
+        let handler = self.handler1.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
         self.handle.destroy();
     }
 }
 
+/// Returns the type of a button, ignoring the "default" variant of each type (ex: `BS_DEFSPLITBUTTON` returns `BS_SPLITBUTTON`)
+fn button_type(handle: HWND) -> u32 {
+    use winapi::um::winuser::{BS_TYPEMASK, BS_DEFPUSHBUTTON};
+    use winapi::um::commctrl::{BS_DEFSPLITBUTTON, BS_DEFCOMMANDLINK};
+
+    match wh::get_style(handle) & BS_TYPEMASK {
+        BS_DEFPUSHBUTTON => BS_PUSHBUTTON,
+        BS_DEFSPLITBUTTON => BS_SPLITBUTTON,
+        BS_DEFCOMMANDLINK => BS_COMMANDLINK,
+        t => t
+    }
+}
+
+/// Changes the type of a button. Keeps the "default" variant of the type if the button was a default button.
+fn set_button_type(handle: HWND, new_type: u32) {
+    use winapi::um::winuser::{BS_TYPEMASK, BS_DEFPUSHBUTTON, InvalidateRect};
+    use winapi::um::commctrl::{BS_DEFSPLITBUTTON, BS_DEFCOMMANDLINK};
+    use std::ptr;
+
+    // The "default" variant of each button type is the type value + 1
+    let style = wh::get_style(handle);
+    let new_type = match style & BS_TYPEMASK {
+        BS_DEFPUSHBUTTON | BS_DEFSPLITBUTTON | BS_DEFCOMMANDLINK => new_type + 1,
+        _ => new_type
+    };
+
+    wh::set_style(handle, (style & !BS_TYPEMASK) | new_type);
+    unsafe { InvalidateRect(handle, ptr::null(), 1); }
+}
+
+impl PartialEq for Button {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl Eq for Button {}
+
 pub struct ButtonBuilder<'a> {
     text: &'a str,
     size: (i32, i32),
@@ -275,6 +754,16 @@ pub struct ButtonBuilder<'a> {
     font: Option<&'a Font>,
     bitmap: Option<&'a Bitmap>,
     icon: Option<&'a Icon>,
+    #[cfg(feature="image-list")]
+    image_list: Option<&'a ImageList>,
+    #[cfg(feature="image-list")]
+    image_align: ButtonImageAlign,
+    #[cfg(feature="menu")]
+    drop_down_menu: Option<&'a Menu>,
+    note: Option<&'a str>,
+    shield: bool,
+    default: bool,
+    cancel: bool,
     parent: Option<ControlHandle>,
     focus: bool,
 }
@@ -326,6 +815,44 @@ impl<'a> ButtonBuilder<'a> {
         self
     }
 
+    #[cfg(feature="image-list")]
+    pub fn image_list(mut self, list: Option<&'a ImageList>) -> ButtonBuilder<'a> {
+        self.image_list = list;
+        self
+    }
+
+    #[cfg(feature="image-list")]
+    pub fn image_align(mut self, align: ButtonImageAlign) -> ButtonBuilder<'a> {
+        self.image_align = align;
+        self
+    }
+
+    #[cfg(feature="menu")]
+    pub fn drop_down_menu(mut self, menu: Option<&'a Menu>) -> ButtonBuilder<'a> {
+        self.drop_down_menu = menu;
+        self
+    }
+
+    pub fn note(mut self, note: &'a str) -> ButtonBuilder<'a> {
+        self.note = Some(note);
+        self
+    }
+
+    pub fn shield(mut self, shield: bool) -> ButtonBuilder<'a> {
+        self.shield = shield;
+        self
+    }
+
+    pub fn default(mut self, default: bool) -> ButtonBuilder<'a> {
+        self.default = default;
+        self
+    }
+
+    pub fn cancel(mut self, cancel: bool) -> ButtonBuilder<'a> {
+        self.cancel = cancel;
+        self
+    }
+
     pub fn focus(mut self, focus: bool) -> ButtonBuilder<'a> {
         self.focus = focus;
         self
@@ -358,6 +885,11 @@ impl<'a> ButtonBuilder<'a> {
             .parent(Some(parent))
             .build()?;
 
+        let handle = out.handle.hwnd().unwrap();
+        if button_type(handle) == BS_OWNERDRAW {
+            crate::win32::window::register_owner_draw(handle);
+        }
+
         if self.font.is_some() {
             out.set_font(self.font);
         } else {
@@ -372,6 +904,36 @@ impl<'a> ButtonBuilder<'a> {
             out.set_icon(self.icon);
         }
 
+        #[cfg(feature="image-list")]
+        {
+            if self.image_list.is_some() {
+                out.set_image_list(self.image_list, self.image_align);
+            }
+        }
+
+        if let Some(note) = self.note {
+            out.set_note(note);
+        }
+
+        if self.shield {
+            out.set_shield(true);
+        }
+
+        if self.default {
+            out.set_default(true);
+        }
+
+        if self.cancel {
+            out.set_cancel(true);
+        }
+
+        #[cfg(feature="menu")]
+        {
+            if self.drop_down_menu.is_some() {
+                out.set_drop_down_menu(self.drop_down_menu);
+            }
+        }
+
         if self.focus {
             out.set_focus();
         }
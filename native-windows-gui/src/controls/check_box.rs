@@ -1,9 +1,9 @@
 use winapi::um::{
-    winuser::{WS_VISIBLE, WS_DISABLED, BS_AUTOCHECKBOX, BS_AUTO3STATE, BS_PUSHLIKE, WS_TABSTOP},
+    winuser::{WS_VISIBLE, WS_DISABLED, BS_AUTOCHECKBOX, BS_AUTO3STATE, BS_PUSHLIKE, BS_TYPEMASK, WS_TABSTOP},
     wingdi::DeleteObject
 };
 use winapi::shared::windef::HBRUSH;
-use crate::win32::{base_helper::check_hwnd, window_helper as wh};
+use crate::win32::{base_helper::try_check_hwnd, window_helper as wh};
 use crate::{Font, NwgError, RawEventHandler};
 use super::{ControlBase, ControlHandle};
 use std::cell::RefCell;
@@ -19,7 +19,7 @@ bitflags! {
         * NONE:     No flags. Equivalent to a invisible default checkbox.
         * VISIBLE:  The checkbox is immediatly visible after creation
         * DISABLED: The checkbox cannot be interacted with by the user. It also has a grayed out look.
-        * TRISTATE: The checkbox will have a 3rd state
+        * TRISTATE: The checkbox will have a 3rd state (`CheckBoxState::Indeterminate`). Clicking the checkbox cycles between checked, indeterminate and unchecked.
         * PUSHLIKE: The checkbox will look like a regular button
         * TAB_STOP: The control can be selected using tab navigation
     */
@@ -39,7 +39,7 @@ pub enum CheckBoxState {
     Checked,
     Unchecked,
 
-    /// New state for the tristate checkbox
+    /// New state for the tristate checkbox. Usually used to indicate that only some of the sub options are selected.
     Indeterminate
 }
 
@@ -59,7 +59,7 @@ CheckBox is not behind any features.
   * `ex_flags`:         A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
   * `font`:             The font used for the checkbox text
   * `background_color`: The background color of the checkbox. Defaults to the default window background (light gray)
-  * `check_state`:      The default check state
+  * `check_state`:      The default check state. `CheckBoxState::Indeterminate` implies the `TRISTATE` flag.
   * `focus`:            The control receive focus after being created
 
 **Control events:**
@@ -107,147 +107,179 @@ impl CheckBox {
         }
     }
 
-    /// Return `true` if the checkbox can have a third state or `false` otherwise
-    pub fn tristate(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let style = wh::get_style(handle);
-        style & BS_AUTO3STATE == BS_AUTO3STATE
-    }
+    try_accessors! {
+        /// Return `true` if the checkbox can have a third state or `false` otherwise
+        pub fn tristate(&self) -> bool => try_tristate {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let style = wh::get_style(handle);
+            Ok(style & BS_TYPEMASK == BS_AUTO3STATE)
+        }
 
-    /// Sets or unsets the checkbox as tristate
-    pub fn set_tristate(&self, tri: bool) {
-        use winapi::um::winuser::{BM_SETSTYLE};
-        use winapi::shared::minwindef::WPARAM;
+        /// Sets or unsets the checkbox as tristate.
+        /// If the checkbox was indeterminate when the third state is removed, it becomes unchecked.
+        pub fn set_tristate(&self, tri: bool) => try_set_tristate {
+            use winapi::um::winuser::{BM_SETSTYLE};
+            use winapi::shared::minwindef::WPARAM;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        
-        let style = match tri {
-            true => BS_AUTO3STATE,
-            false => BS_AUTOCHECKBOX
-        };
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        wh::send_message(handle, BM_SETSTYLE, style as WPARAM, 1);
-    }
+            if !tri && self.check_state() == CheckBoxState::Indeterminate {
+                self.set_check_state(CheckBoxState::Unchecked);
+            }
 
-    /// Return the check state of the check box
-    pub fn check_state(&self) -> CheckBoxState {
-        use winapi::um::winuser::{BM_GETCHECK, BST_CHECKED, BST_INDETERMINATE, BST_UNCHECKED};
+            let button_type = match tri {
+                true => BS_AUTO3STATE,
+                false => BS_AUTOCHECKBOX
+            };
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            // BM_SETSTYLE replaces every button style, so the other styles (ex: PUSHLIKE) must be kept
+            let style = (wh::get_style(handle) & 0xFFFF & !BS_TYPEMASK) | button_type;
+            wh::send_message(handle, BM_SETSTYLE, style as WPARAM, 1);
 
-        match wh::send_message(handle, BM_GETCHECK, 0, 0) as usize {
-            BST_UNCHECKED => CheckBoxState::Unchecked,
-            BST_CHECKED => CheckBoxState::Checked,
-            BST_INDETERMINATE => CheckBoxState::Indeterminate,
-            _ => unreachable!()
+            Ok(())
         }
-    }
 
-    /// Sets the check state of the check box
-    pub fn set_check_state(&self, state: CheckBoxState) {
-        use winapi::um::winuser::{BM_SETCHECK, BST_CHECKED, BST_INDETERMINATE, BST_UNCHECKED};
-        use winapi::shared::minwindef::WPARAM;
+        /// Return the check state of the check box
+        pub fn check_state(&self) -> CheckBoxState => try_check_state {
+            use winapi::um::winuser::{BM_GETCHECK, BST_CHECKED, BST_INDETERMINATE, BST_UNCHECKED};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let x = match state {
-            CheckBoxState::Unchecked => BST_UNCHECKED,
-            CheckBoxState::Checked => BST_CHECKED,
-            CheckBoxState::Indeterminate => BST_INDETERMINATE,
-        };
+            Ok(match wh::send_message(handle, BM_GETCHECK, 0, 0) as usize {
+                BST_UNCHECKED => CheckBoxState::Unchecked,
+                BST_CHECKED => CheckBoxState::Checked,
+                BST_INDETERMINATE => CheckBoxState::Indeterminate,
+                _ => unreachable!()
+            })
+        }
 
-        wh::send_message(handle, BM_SETCHECK, x as WPARAM, 0);
-    }
+        /// Sets the check state of the check box.
+        /// Setting `CheckBoxState::Indeterminate` on a checkbox that is not tristate does nothing.
+        pub fn set_check_state(&self, state: CheckBoxState) => try_set_check_state {
+            use winapi::um::winuser::{BM_SETCHECK, BST_CHECKED, BST_INDETERMINATE, BST_UNCHECKED};
+            use winapi::shared::minwindef::WPARAM;
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+            if state == CheckBoxState::Indeterminate && !self.tristate() {
+                return Ok(());
+            }
+
+            let x = match state {
+                CheckBoxState::Unchecked => BST_UNCHECKED,
+                CheckBoxState::Checked => BST_CHECKED,
+                CheckBoxState::Indeterminate => BST_INDETERMINATE,
+            };
+
+            wh::send_message(handle, BM_SETCHECK, x as WPARAM, 0);
+
+            Ok(())
         }
-    }
 
-    /// Set the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
+        }
 
-    /// Set the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Set the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+            Ok(())
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+        /// Set the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Return the size of the check box in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Set the size of the check box in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Return the position of the check box in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the position of the check box in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Return the check box label
-    pub fn text(&self) -> String { 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_text(handle) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the check box in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Set the size of the check box in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
 
-    /// Set the check box label
-    pub fn set_text<'a>(&self, v: &'a str) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
+            Ok(())
+        }
+
+        /// Return the position of the check box in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the check box in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Return the check box label
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
+
+        /// Set the check box label
+        pub fn set_text<'a>(&self, v: &'a str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, v) }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -270,6 +302,7 @@ impl CheckBox {
     /// Change the checkbox background color.
     fn hook_background_color(&mut self, c: [u8; 3]) {
         use crate::bind_raw_event_handler_inner;
+        use crate::win32::high_contrast::high_contrast;
         use winapi::um::winuser::{WM_CTLCOLORSTATIC};
         use winapi::shared::{basetsd::UINT_PTR, windef::HWND, minwindef::LRESULT};
         use winapi::um::wingdi::{CreateSolidBrush, RGB};
@@ -286,7 +319,7 @@ impl CheckBox {
             match msg {
                 WM_CTLCOLORSTATIC => {
                     let child = l as HWND;
-                    if child == handle {
+                    if child == handle && !high_contrast() {
                         return Some(brush as LRESULT);
                     }
                 },
@@ -391,8 +424,12 @@ impl<'a> CheckBoxBuilder<'a> {
 
     pub fn build(self, out: &mut CheckBox) -> Result<(), NwgError> {
         let mut flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
-        if flags & BS_AUTO3STATE == 0 {
-            flags |= BS_AUTOCHECKBOX;
+        if self.check_state == CheckBoxState::Indeterminate {
+            flags |= BS_AUTO3STATE;
+        }
+
+        if flags & BS_TYPEMASK != BS_AUTO3STATE {
+            flags = (flags & !BS_TYPEMASK) | BS_AUTOCHECKBOX;
         }
 
         let parent = match self.parent {
@@ -1,11 +1,12 @@
 use winapi::shared::windef::HWND;
 use winapi::shared::minwindef::{LPARAM, WPARAM};
-use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
-use crate::win32::base_helper::{check_hwnd, to_utf16, from_utf16};
+use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP, CBS_DROPDOWN, CBS_DROPDOWNLIST, CBS_AUTOHSCROLL, CBS_OWNERDRAWVARIABLE, CBS_HASSTRINGS};
+use crate::win32::base_helper::{check_hwnd, try_check_hwnd, to_utf16, with_utf16, from_utf16};
 use crate::win32::window_helper as wh;
 use crate::{Font, NwgError, VTextAlign, RawEventHandler, unbind_raw_event_handler};
 use super::{ControlHandle, ControlBase};
-use std::cell::{Ref, RefMut, RefCell};
+use std::cell::{Cell, Ref, RefMut, RefCell};
+use std::rc::Rc;
 use std::fmt::Display;
 use std::mem;
 
@@ -21,12 +22,16 @@ bitflags! {
         * VISIBLE:  The combobox is immediatly visible after creation
         * DISABLED: The combobox cannot be interacted with by the user. It also has a grayed out look.
         * TAB_STOP: The control can be selected using tab navigation
+        * EDITABLE: The selection field is an edit control. The user can type a value that is not in the list.
+        * OWNER_DRAW: The items are painted by the application in the `OnDrawItem` event. The height of each item can be set in the `OnMeasureItem` event.
     */
     pub struct ComboBoxFlags: u32 {
         const NONE = 0;
         const VISIBLE = WS_VISIBLE;
         const DISABLED = WS_DISABLED;
         const TAB_STOP = WS_TABSTOP;
+        const EDITABLE = CBS_DROPDOWN;
+        const OWNER_DRAW = CBS_OWNERDRAWVARIABLE | CBS_HASSTRINGS;
     }
 }
 
@@ -46,12 +51,17 @@ Requires the `combobox` feature.
   * `font`:           The font used for the combobox text
   * `collection`:     The default collection of the combobox
   * `selected_index`: The default selected index. None means no values are selected.  
+  * `text`:           The default text of an editable combobox
+  * `auto_complete`:  Complete the text typed in an editable combobox with the first matching item of the list
   * `focus`:          The control receive focus after being created
 
 **Control events:**
   * `OnComboBoxClosed`: When the combobox dropdown is closed
   * `OnComboBoxDropdown`: When the combobox dropdown is opened
   * `OnComboxBoxSelection`: When a new value in a combobox is choosen
+  * `OnComboBoxTextChanged`: When the user changes the text of an editable combobox
+  * `OnMeasureItem`: When an item is added to an owner drawn combobox. Sent to the combobox handle.
+  * `OnDrawItem`: When an item or the selection field of an owner drawn combobox must be painted. Sent to the combobox handle.
   * `MousePress(_)`: Generic mouse press events on the checkbox
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -70,12 +80,25 @@ fn build_combobox(combo: &mut nwg::ComboBox<&'static str>, window: &nwg::Window)
         .build(combo);
 }
 ```
+
+```rust
+use native_windows_gui as nwg;
+fn build_editable_combobox(combo: &mut nwg::ComboBox<&'static str>, window: &nwg::Window) {
+    nwg::ComboBox::builder()
+        .flags(nwg::ComboBoxFlags::VISIBLE | nwg::ComboBoxFlags::EDITABLE)
+        .collection(vec!["Arial", "Courier New", "Segoe UI"])
+        .auto_complete(true)
+        .parent(window)
+        .build(combo);
+}
+```
 */
 #[derive(Default)]
 pub struct ComboBox<D: Display+Default> {
     pub handle: ControlHandle,
     collection: RefCell<Vec<D>>,
     handler0: RefCell<Option<RawEventHandler>>,
+    handler1: RefCell<Option<RawEventHandler>>,
 }
 
 impl<D: Display+Default> ComboBox<D> {
@@ -91,282 +114,382 @@ impl<D: Display+Default> ComboBox<D> {
             font: None,
             collection: None,
             selected_index: None,
+            text: None,
+            auto_complete: false,
             parent: None
         }
     }
 
-    /// Remove the item at the selected index and returns it.
-    /// Panic of the index is out of bounds
-    pub fn remove(&self, index: usize) -> D {
-        use winapi::um::winuser::CB_DELETESTRING;
+    try_accessors! {
+        /// Remove the item at the selected index and returns it.
+        /// Panic of the index is out of bounds
+        pub fn remove(&self, index: usize) -> D => try_remove {
+            use winapi::um::winuser::CB_DELETESTRING;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        wh::send_message(handle, CB_DELETESTRING, index as WPARAM, 0);
+            wh::send_message(handle, CB_DELETESTRING, index as WPARAM, 0);
 
-        let mut col_ref = self.collection.borrow_mut();
-        col_ref.remove(index)
-    }
+            let mut col_ref = self.collection.borrow_mut();
+            Ok(col_ref.remove(index))
+        }
 
-    /// Sort the inner collection by the display value of it's items and update the view
-    /// Internally this uses `Vec.sort_unstable_by`.
-    pub fn sort(&self) {
-        use winapi::um::winuser::{CB_ADDSTRING};
+        /// Sort the inner collection by the display value of it's items and update the view
+        /// Internally this uses `Vec.sort_unstable_by`.
+        pub fn sort(&self) => try_sort {
+            use winapi::um::winuser::{CB_ADDSTRING};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        self.clear_inner(handle);
+            self.clear_inner(handle);
 
-        let mut col = self.collection.borrow_mut();
-        col.sort_unstable_by(|a, b| {
-            let astr = format!("{}", a);
-            let bstr = format!("{}", b);
-            astr.cmp(&bstr)
-        });
+            let mut col = self.collection.borrow_mut();
+            col.sort_unstable_by(|a, b| {
+                let astr = format!("{}", a);
+                let bstr = format!("{}", b);
+                astr.cmp(&bstr)
+            });
 
-        for item in col.iter() {
-            let display = format!("{}", item);
-            let display_os = to_utf16(&display);
-            wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_ptr() as LPARAM);
+            for item in col.iter() {
+                let display = format!("{}", item);
+                with_utf16(&display, |text| wh::send_message(handle, CB_ADDSTRING, 0, text.as_ptr() as LPARAM));
+            }
+
+            Ok(())
         }
-    }
 
-    /// Show or hide the dropdown of the combox
-    pub fn dropdown(&self, v: bool) {
-        use winapi::um::winuser::CB_SHOWDROPDOWN;
+        /// Show or hide the dropdown of the combox
+        pub fn dropdown(&self, v: bool) => try_dropdown {
+            use winapi::um::winuser::CB_SHOWDROPDOWN;
     
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, CB_SHOWDROPDOWN, v as usize, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, CB_SHOWDROPDOWN, v as usize, 0);
 
-    /// Return the index of the currencty selected item. Return `None` if no item is selected.
-    pub fn selection(&self) -> Option<usize> {
-        use winapi::um::winuser::{CB_GETCURSEL, CB_ERR};
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Return the index of the currencty selected item. Return `None` if no item is selected.
+        pub fn selection(&self) -> Option<usize> => try_selection {
+            use winapi::um::winuser::{CB_GETCURSEL, CB_ERR};
 
-        let index = wh::send_message(handle, CB_GETCURSEL, 0, 0);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        if index == CB_ERR { None }
-        else { Some(index as usize) }
-    }
+            let index = wh::send_message(handle, CB_GETCURSEL, 0, 0);
 
-    /// Return the display value of the currenctly selected item
-    /// Return `None` if no item is selected. This reads the visual value.
-    pub fn selection_string(&self) -> Option<String> {
-        use winapi::um::winuser::{CB_GETCURSEL, CB_GETLBTEXTLEN, CB_GETLBTEXT, CB_ERR};
-        use winapi::shared::ntdef::WCHAR;
+            Ok(if index == CB_ERR { None }
+            else { Some(index as usize) })
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Return the display value of the currenctly selected item
+        /// Return `None` if no item is selected. This reads the visual value.
+        pub fn selection_string(&self) -> Option<String> => try_selection_string {
+            use winapi::um::winuser::{CB_GETCURSEL, CB_GETLBTEXTLEN, CB_GETLBTEXT, CB_ERR};
+            use winapi::shared::ntdef::WCHAR;
 
-        let index = wh::send_message(handle, CB_GETCURSEL, 0, 0);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        if index == CB_ERR { None }
-        else {
-            let index = index as usize;
-            let length = (wh::send_message(handle, CB_GETLBTEXTLEN, index, 0) as usize) + 1; // +1 for the null character
-            let mut buffer: Vec<WCHAR> = Vec::with_capacity(length);
-            unsafe { 
-                buffer.set_len(length); 
-                wh::send_message(handle, CB_GETLBTEXT, index, buffer.as_ptr() as LPARAM);
-            }
+            let index = wh::send_message(handle, CB_GETCURSEL, 0, 0);
+
+            Ok(if index == CB_ERR { None }
+            else {
+                let index = index as usize;
+                let length = (wh::send_message(handle, CB_GETLBTEXTLEN, index, 0) as usize) + 1; // +1 for the null character
+                let mut buffer: Vec<WCHAR> = Vec::with_capacity(length);
+                unsafe { 
+                    buffer.set_len(length); 
+                    wh::send_message(handle, CB_GETLBTEXT, index, buffer.as_ptr() as LPARAM);
+                }
 
-            Some(from_utf16(&buffer))
+                Some(from_utf16(&buffer))
+            })
         }
-    }
 
-    /// Set the currently selected item in the combobox.
-    /// Does nothing if the index is out of bound
-    /// If the value is None, remove the selected value
-    pub fn set_selection(&self, index: Option<usize>) {
-        use winapi::um::winuser::CB_SETCURSEL;
+        /// Set the currently selected item in the combobox.
+        /// Does nothing if the index is out of bound
+        /// If the value is None, remove the selected value
+        pub fn set_selection(&self, index: Option<usize>) => try_set_selection {
+            use winapi::um::winuser::CB_SETCURSEL;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let index = index.unwrap_or(-1isize as usize);
-        wh::send_message(handle, CB_SETCURSEL, index, 0);
-    }
+            let index = index.unwrap_or(-1isize as usize);
+            wh::send_message(handle, CB_SETCURSEL, index, 0);
 
-    /// Search an item that begins by the value and select the first one found.
-    /// The search is not case sensitive, so this string can contain any combination of uppercase and lowercase letters.
-    /// Return the index of the selected string or None if the search was not successful
-    pub fn set_selection_string(&self, value: &str) -> Option<usize> {
-        use winapi::um::winuser::{CB_SELECTSTRING, CB_ERR};
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Search an item that begins by the value and select the first one found.
+        /// The search is not case sensitive, so this string can contain any combination of uppercase and lowercase letters.
+        /// Return the index of the selected string or None if the search was not successful
+        pub fn set_selection_string(&self, value: &str) -> Option<usize> => try_set_selection_string {
+            use winapi::um::winuser::{CB_SELECTSTRING, CB_ERR};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
         
-        let os_string = to_utf16(value);
+            let os_string = to_utf16(value);
+
+            let index = wh::send_message(handle, CB_SELECTSTRING, 0, os_string.as_ptr() as LPARAM);
+            Ok(if index == CB_ERR {
+                None
+            } else {
+                Some(index as usize)
+            })
+        }
 
-        let index = wh::send_message(handle, CB_SELECTSTRING, 0, os_string.as_ptr() as LPARAM);
-        if index == CB_ERR {
-            None
-        } else {
-            Some(index as usize)
+        /// Search an item with a display value equal to `value` and select it. Unlike `set_selection_string`, the whole value must match.
+        /// The search is not case sensitive. Return the index of the selected item or None if no item matches.
+        /// If no item matches, the selection is cleared. The text of an editable combobox is kept.
+        pub fn set_selection_from_text(&self, value: &str) -> Option<usize> => try_set_selection_from_text {
+            use winapi::um::winuser::{CB_FINDSTRINGEXACT, CB_SETCURSEL, CB_ERR};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let os_string = to_utf16(value);
+            let index = wh::send_message(handle, CB_FINDSTRINGEXACT, -1isize as WPARAM, os_string.as_ptr() as LPARAM);
+            Ok(if index == CB_ERR {
+                // Clearing the selection also clears the edit field
+                let text = self.text();
+                wh::send_message(handle, CB_SETCURSEL, -1isize as WPARAM, 0);
+                self.set_text(&text);
+                None
+            } else {
+                wh::send_message(handle, CB_SETCURSEL, index as WPARAM, 0);
+                Some(index as usize)
+            })
         }
-    }
 
-    /// Add a new item to the combobox. Sort the collection if the combobox is sorted.
-    pub fn push(&self, item: D) {
-        use winapi::um::winuser::CB_ADDSTRING;
+        /// Return the text in the selection field of the combobox.
+        /// For an editable combobox, this is the text typed by the user. Otherwise, this is the display value of the selected item.
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Set the text in the selection field of an editable combobox. Does nothing if the combobox is not editable.
+        /// This does not change the selected item.
+        pub fn set_text<'b>(&self, text: &'b str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, text) }
 
-        let display = format!("{}", item);
-        let display_os = to_utf16(&display);
+            Ok(())
+        }
 
-        wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_ptr() as LPARAM);
+        /// Return true if the combobox has the `EDITABLE` flag
+        pub fn editable(&self) -> bool => try_editable {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::get_style(handle) & CBS_DROPDOWNLIST == CBS_DROPDOWN)
+        }
 
-        self.collection.borrow_mut().push(item);
-    }
+        /// Set the selected text range in the edit field of an editable combobox. The range is in UTF-16 code units.
+        pub fn set_text_selection(&self, r: ::std::ops::Range<u32>) => try_set_text_selection {
+            use winapi::um::winuser::CB_SETEDITSEL;
+            use winapi::shared::minwindef::MAKELONG;
 
-    /// Insert an item in the collection and the control.
-    ///
-    /// SPECIAL behaviour! If index is `std::usize::MAX`, the item is added at the end of the collection.
-    /// The method will still panic if `index > len` with every other values.
-    pub fn insert(&self, index: usize, item: D) {
-        use winapi::um::winuser::CB_INSERTSTRING;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, CB_SETEDITSEL, 0, MAKELONG(r.start as u16, r.end as u16) as LPARAM);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            Ok(())
+        }
 
-        let display = format!("{}", item);
-        let display_os = to_utf16(&display);
+        /// Return the selected text range in the edit field of an editable combobox
+        pub fn text_selection(&self) -> ::std::ops::Range<u32> => try_text_selection {
+            use winapi::um::winuser::CB_GETEDITSEL;
+            use winapi::shared::minwindef::DWORD;
 
-        let mut col = self.collection.borrow_mut();
-        if index == std::usize::MAX {
-            col.push(item);
-        } else {
-            col.insert(index, item);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let (mut start, mut end): (DWORD, DWORD) = (0, 0);
+            wh::send_message(handle, CB_GETEDITSEL, &mut start as *mut DWORD as WPARAM, &mut end as *mut DWORD as LPARAM);
+
+            Ok(start..end)
         }
 
-        wh::send_message(handle, CB_INSERTSTRING, index, display_os.as_ptr() as LPARAM);
-    }
+        /// Add a new item to the combobox. Sort the collection if the combobox is sorted.
+        pub fn push(&self, item: D) => try_push {
+            use winapi::um::winuser::CB_ADDSTRING;
 
-    /// Update the visual of the control with the inner collection.
-    /// This rebuild every item in the combobox and can take some time on big collections.
-    pub fn sync(&self) {
-        use winapi::um::winuser::CB_ADDSTRING;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let display = format!("{}", item);
+            with_utf16(&display, |text| wh::send_message(handle, CB_ADDSTRING, 0, text.as_ptr() as LPARAM));
 
-        self.clear_inner(handle);
+            self.collection.borrow_mut().push(item);
+
+            Ok(())
+        }
+
+        /// Insert an item in the collection and the control.
+        ///
+        /// SPECIAL behaviour! If index is `std::usize::MAX`, the item is added at the end of the collection.
+        /// The method will still panic if `index > len` with every other values.
+        pub fn insert(&self, index: usize, item: D) => try_insert {
+            use winapi::um::winuser::CB_INSERTSTRING;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        for item in self.collection.borrow().iter() {
             let display = format!("{}", item);
             let display_os = to_utf16(&display);
-            
-            wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_ptr() as LPARAM);
+
+            let mut col = self.collection.borrow_mut();
+            if index == std::usize::MAX {
+                col.push(item);
+            } else {
+                col.insert(index, item);
+            }
+
+            wh::send_message(handle, CB_INSERTSTRING, index, display_os.as_ptr() as LPARAM);
+
+            Ok(())
+        }
+
+        /// Update the visual of the control with the inner collection.
+        /// This rebuild every item in the combobox and can take some time on big collections.
+        pub fn sync(&self) => try_sync {
+            use winapi::um::winuser::CB_ADDSTRING;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            self.clear_inner(handle);
+
+            for item in self.collection.borrow().iter() {
+                let display = format!("{}", item);
+                with_utf16(&display, |text| wh::send_message(handle, CB_ADDSTRING, 0, text.as_ptr() as LPARAM));
+            }
+
+            Ok(())
         }
     }
 
     /// Set the item collection of the combobox. Return the old collection
-    pub fn set_collection(&self, mut col: Vec<D>) -> Vec<D> {
+    pub fn set_collection(&self, col: Vec<D>) -> Vec<D> {
+        self.try_set_collection(col).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `set_collection`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the control is not bound or if its window was destroyed.
+    pub fn try_set_collection(&self, mut col: Vec<D>) -> Result<Vec<D>, NwgError> {
         use winapi::um::winuser::CB_ADDSTRING;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
         self.clear_inner(handle);
 
         for item in col.iter() {
             let display = format!("{}", item);
-            let display_os = to_utf16(&display);
-            wh::send_message(handle, CB_ADDSTRING, 0, display_os.as_ptr() as LPARAM);
+            with_utf16(&display, |text| wh::send_message(handle, CB_ADDSTRING, 0, text.as_ptr() as LPARAM));
         }
 
         let mut col_ref = self.collection.borrow_mut();
         mem::swap::<Vec<D>>(&mut col_ref, &mut col);
 
-        col
+        Ok(col)
     }
 
-    /// Return the number of items in the control. NOT the inner rust collection
-    pub fn len(&self) -> usize {
-        use winapi::um::winuser::CB_GETCOUNT;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, CB_GETCOUNT, 0, 0) as usize
+    try_accessors! {
+        /// Return the number of items in the control. NOT the inner rust collection
+        pub fn len(&self) -> usize => try_len {
+            use winapi::um::winuser::CB_GETCOUNT;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, CB_GETCOUNT, 0, 0) as usize)
+        }
     }
 
     //
     // Common control functions
     //
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+    try_accessors! {
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
+        }
 
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+        /// Set the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
+
+            Ok(())
         }
-    }
 
-    /// Set the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+        /// Set the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Set the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+            Ok(())
+        }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            Ok(())
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+            Ok(())
+        }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
     }
 
     /// Get read-only access to the inner collection of the combobox
@@ -395,8 +518,58 @@ impl<D: Display+Default> ComboBox<D> {
 
     /// Winapi flags required by the control
     pub fn forced_flags(&self) -> u32 {
-        use winapi::um::winuser::{CBS_DROPDOWNLIST, WS_BORDER, WS_CHILD};
-        CBS_DROPDOWNLIST | WS_CHILD | WS_BORDER
+        use winapi::um::winuser::{WS_BORDER, WS_CHILD};
+        WS_CHILD | WS_BORDER
+    }
+
+    /// Complete the text typed by the user with the first item that begins with it. The completed part is selected
+    /// so that typing replaces it. The text is not completed when the user deletes characters.
+    fn hook_auto_complete(&self) {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_COMMAND, CBN_EDITUPDATE, CB_FINDSTRING, CB_GETLBTEXTLEN, CB_GETLBTEXT, CB_SETEDITSEL, CB_ERR};
+        use winapi::shared::minwindef::{HIWORD, MAKELONG};
+        use winapi::shared::basetsd::UINT_PTR;
+
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let parent_handle = ControlHandle::Hwnd(wh::get_window_parent(handle));
+        let last_len = Rc::new(Cell::new(0usize));
+
+        let handler = bind_raw_event_handler_inner(&parent_handle, handle as UINT_PTR, move |_hwnd, msg, w, l| {
+            if msg != WM_COMMAND || l as HWND != handle || HIWORD(w as u32) != CBN_EDITUPDATE {
+                return None;
+            }
+
+            let text = unsafe { wh::get_window_text(handle) };
+            let text_len = text.encode_utf16().count();
+            let deleting = text_len <= last_len.get();
+            last_len.set(text_len);
+
+            if deleting || text_len == 0 {
+                return None;
+            }
+
+            let text_os = to_utf16(&text);
+            let index = wh::send_message(handle, CB_FINDSTRING, -1isize as WPARAM, text_os.as_ptr() as LPARAM);
+            if index == CB_ERR {
+                return None;
+            }
+
+            let index = index as WPARAM;
+            let length = (wh::send_message(handle, CB_GETLBTEXTLEN, index, 0) as usize) + 1;
+            let mut buffer: Vec<u16> = vec![0; length];
+            wh::send_message(handle, CB_GETLBTEXT, index, buffer.as_mut_ptr() as LPARAM);
+
+            // Setting the text raises `CBN_EDITUPDATE` again. Update the length first so that the text is not completed twice.
+            let item = from_utf16(&buffer);
+            last_len.set(length - 1);
+            unsafe { wh::set_window_text(handle, &item); }
+            last_len.set(text_len);
+            wh::send_message(handle, CB_SETEDITSEL, 0, MAKELONG(text_len as u16, (length - 1) as u16) as LPARAM);
+
+            None
+        });
+
+        *self.handler1.borrow_mut() = Some(handler.unwrap());
     }
 
     /// Remove all value displayed in the control without touching the rust collection
@@ -409,6 +582,7 @@ impl<D: Display+Default> ComboBox<D> {
     #[allow(unused)]
     fn hook_non_client_size(&self, bg: Option<[u8; 3]>, v_align: VTextAlign) {
         use crate::bind_raw_event_handler_inner;
+        use crate::win32::high_contrast::background_brush;
         use winapi::shared::windef::{HGDIOBJ, RECT, HBRUSH, POINT};
         use winapi::um::winuser::{WM_NCCALCSIZE, WM_NCPAINT, WM_SIZE, DT_CALCRECT, DT_LEFT, NCCALCSIZE_PARAMS, COLOR_WINDOW};
         use winapi::um::winuser::{SWP_NOOWNERZORDER, SWP_NOSIZE, SWP_NOMOVE, SWP_FRAMECHANGED};
@@ -494,6 +668,7 @@ impl<D: Display+Default> ComboBox<D> {
                     };
 
                     let dc = GetDC(hwnd);
+                    let brush = background_brush(brush, COLOR_WINDOW);
                     FillRect(dc, &top, brush);
                     FillRect(dc, &bottom, brush);
                     ReleaseDC(hwnd, dc);
@@ -521,6 +696,11 @@ impl<D: Display+Default> Drop for ComboBox<D> {
             drop(unbind_raw_event_handler(h));
         }
 
+        let handler = self.handler1.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
         self.handle.destroy();
     }
 }
@@ -535,6 +715,8 @@ pub struct ComboBoxBuilder<'a, D: Display+Default> {
     font: Option<&'a Font>,
     collection: Option<Vec<D>>,
     selected_index: Option<usize>,
+    text: Option<&'a str>,
+    auto_complete: bool,
     parent: Option<ControlHandle>
 }
 
@@ -580,6 +762,16 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
         self
     }
 
+    pub fn text(mut self, text: &'a str) -> ComboBoxBuilder<'a, D> {
+        self.text = Some(text);
+        self
+    }
+
+    pub fn auto_complete(mut self, auto_complete: bool) -> ComboBoxBuilder<'a, D> {
+        self.auto_complete = auto_complete;
+        self
+    }
+
     pub fn enabled(mut self, e: bool) -> ComboBoxBuilder<'a, D> {
         self.enabled = e;
         self
@@ -596,7 +788,11 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
     }
 
     pub fn build(self, out: &mut ComboBox<D>) -> Result<(), NwgError> {
-        let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+        let mut flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+        flags = match flags & CBS_DROPDOWNLIST == CBS_DROPDOWN {
+            true => flags | CBS_AUTOHSCROLL,
+            false => flags | CBS_DROPDOWNLIST
+        };
 
         let parent = match self.parent {
             Some(p) => Ok(p),
@@ -616,6 +812,10 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
             .parent(Some(parent))
             .build()?;
 
+        if flags & CBS_OWNERDRAWVARIABLE == CBS_OWNERDRAWVARIABLE {
+            crate::win32::window::register_owner_draw(out.handle.hwnd().unwrap());
+        }
+
         if self.font.is_some() {
             out.set_font(self.font);
         } else {
@@ -630,6 +830,14 @@ impl<'a, D: Display+Default> ComboBoxBuilder<'a, D> {
             out.set_selection(self.selected_index);
         }
 
+        if let Some(text) = self.text {
+            out.set_text(text);
+        }
+
+        if self.auto_complete {
+            out.hook_auto_complete();
+        }
+
         out.set_enabled(self.enabled);
 
         if self.focus {
@@ -1,7 +1,8 @@
 use winapi::shared::minwindef::DWORD;
 use winapi::shared::windef::{HWND};
 use super::ControlHandle;
-use crate::win32::window::{build_hwnd_control, build_timer, build_notice};
+use crate::win32::window::{build_hwnd_control, build_timer, build_notice, build_tray};
+#[cfg(feature = "toast-notification")] use crate::win32::window::build_toast;
 use crate::{NwgError};
 
 #[cfg(feature = "menu")] use crate::win32::menu::build_hmenu_control;
@@ -9,6 +10,8 @@ use crate::{NwgError};
 
 const NOTICE: u32 = 1;
 const TRAY: u32 = 2;
+#[cfg(feature = "toast-notification")]
+const TOAST: u32 = 3;
 
 
 /**
@@ -73,6 +76,14 @@ impl ControlBase {
             ty: TRAY
         }
     }
+
+    #[cfg(feature = "toast-notification")]
+    pub fn build_toast_notification() -> OtherBuilder {
+        OtherBuilder {
+            parent: None,
+            ty: TOAST
+        }
+    }
 }
 
 
@@ -159,6 +170,7 @@ pub struct HmenuBuilder {
     item: bool,
     separator: bool,
     popup: bool,
+    position: Option<u32>,
     parent_menu: Option<HMENU>,
     parent_window: Option<HWND>,
 }
@@ -190,6 +202,12 @@ impl HmenuBuilder {
         self
     }
 
+    /// Insert the menu at this position in its parent instead of appending it
+    pub fn position(mut self, p: Option<u32>) -> HmenuBuilder {
+        self.position = p;
+        self
+    }
+
     /// Set the parent of the menu. Can be a window or another menu.
     pub fn parent(mut self, parent: ControlHandle) -> HmenuBuilder {
         match parent {
@@ -208,6 +226,7 @@ impl HmenuBuilder {
             self.item,
             self.separator,
             self.popup,
+            self.position,
             self.parent_menu,
             self.parent_window
         )? };
@@ -259,7 +278,7 @@ impl TimerBuilder {
 
 
 /// Low level builder for controls without specific winapi contructors. 
-/// Instanced by `ControlBase::build_notice` or `ControlBase::build_tray_notification`.
+/// Instanced by `ControlBase::build_notice`, `ControlBase::build_tray_notification` or `ControlBase::build_toast_notification`.
 #[derive(Default)]
 pub struct OtherBuilder {
     parent: Option<HWND>,
@@ -277,7 +296,9 @@ impl OtherBuilder {
         let handle = self.parent.expect("Internal error. Control without window parent");
         let base = match self.ty {
             NOTICE => build_notice(handle),
-            TRAY => ControlHandle::SystemTray(handle),
+            TRAY => build_tray(handle),
+            #[cfg(feature = "toast-notification")]
+            TOAST => build_toast(handle),
             _ => unreachable!()
         };
 
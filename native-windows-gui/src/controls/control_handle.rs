@@ -1,5 +1,17 @@
 use winapi::shared::windef::{HWND, HMENU};
 use crate::win32::window_helper as wh;
+use crate::win32::back_buffer;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "accessibility")]
+use crate::win32::accessibility;
+
+#[cfg(feature = "accessibility")]
+use crate::{AccessibleRole, NwgError};
+
+#[cfg(feature = "ime")]
+use crate::win32::ime;
 
 
 /**
@@ -27,7 +39,12 @@ pub enum ControlHandle {
     Timer(HWND, u32),
 
     /// System tray control
-    SystemTray(HWND)
+    /// (Parent window / Unique ID).
+    SystemTray(HWND, u32),
+
+    /// Toast notification control
+    /// (Parent window / Unique ID).
+    Toast(HWND, u32)
 }
 
 impl ControlHandle {
@@ -40,12 +57,55 @@ impl ControlHandle {
             &mut ControlHandle::Menu(_parent, m) => wh::destroy_menu(m),
             &mut ControlHandle::MenuItem(parent, id) => wh::destroy_menu_item(parent, id),
             &mut ControlHandle::PopMenu(_parent, m) => wh::destroy_menu(m),
+            #[cfg(feature = "timer")]
+            &mut ControlHandle::Timer(h, id) => if wh::window_valid(h) { wh::kill_timer(h, id) },
             _ => {}
         }
 
         *self = ControlHandle::NoHandle;
     }
 
+    /**
+        Return true if the handle still points to a living object of its type: a window for the window-like handles
+        (controls, timers, notices, tray notifications) and a menu for the menu handles. Return false for `NoHandle`.
+
+        Windows reuses the handles of the destroyed windows. Use `downgrade` to detect that a window was replaced.
+    */
+    pub fn is_valid(&self) -> bool {
+        use winapi::um::winuser::{IsMenu, GetMenuState, MF_BYCOMMAND};
+
+        unsafe {
+            match self {
+                &ControlHandle::NoHandle => false,
+                &ControlHandle::Hwnd(h) => wh::window_valid(h),
+                &ControlHandle::Menu(_, m) => IsMenu(m) != 0,
+                &ControlHandle::PopMenu(h, m) => wh::window_valid(h) && IsMenu(m) != 0,
+                &ControlHandle::MenuItem(m, id) => IsMenu(m) != 0 && GetMenuState(m, id, MF_BYCOMMAND) != u32::max_value(),
+                &ControlHandle::Notice(h, _) | &ControlHandle::Timer(h, _) |
+                &ControlHandle::SystemTray(h, _) | &ControlHandle::Toast(h, _) => wh::window_valid(h),
+            }
+        }
+    }
+
+    /// Create a weak reference to the control. See `WeakControl`.
+    pub fn downgrade(&self) -> WeakControl {
+        let token = match self.window() {
+            Some(h) if wh::window_valid(h) => window_token(h),
+            _ => 0
+        };
+
+        WeakControl { handle: *self, token }
+    }
+
+    /// Return the window of the window-like handles
+    fn window(&self) -> Option<HWND> {
+        match self {
+            &ControlHandle::Hwnd(h) | &ControlHandle::PopMenu(h, _) | &ControlHandle::Notice(h, _) |
+            &ControlHandle::Timer(h, _) | &ControlHandle::SystemTray(h, _) | &ControlHandle::Toast(h, _) => Some(h),
+            _ => None
+        }
+    }
+
     pub fn blank(&self) -> bool {
         match self {
             &ControlHandle::NoHandle => true,
@@ -95,13 +155,320 @@ impl ControlHandle {
         }
     }
 
-    pub fn tray(&self) -> Option<HWND> {
+    pub fn tray(&self) -> Option<(HWND, u32)> {
+        match self {
+            &ControlHandle::SystemTray(h, i) => Some((h, i)),
+            _ => None,
+        }
+    }
+
+    pub fn toast(&self) -> Option<(HWND, u32)> {
         match self {
-            &ControlHandle::SystemTray(h) => Some(h),
+            &ControlHandle::Toast(h, i) => Some((h, i)),
             _ => None,
         }
     }
 
+    /// Invalidate the whole client area of the control. The control will receive a `OnPaint` event.
+    /// Does nothing if the handle is not a window handle.
+    pub fn invalidate(&self) {
+        use winapi::um::winuser::InvalidateRect;
+        use std::ptr;
+
+        if let Some(h) = self.hwnd() {
+            unsafe { InvalidateRect(h, ptr::null(), 1); }
+        }
+    }
+
+    /// Invalidate a region of the client area of the control. `r` is `[left, top, right, bottom]` in pixels.
+    /// The control will receive a `OnPaint` event. Does nothing if the handle is not a window handle.
+    pub fn invalidate_rect(&self, r: [i32; 4]) {
+        use winapi::um::winuser::InvalidateRect;
+        use winapi::shared::windef::RECT;
+
+        if let Some(h) = self.hwnd() {
+            let [left, top, right, bottom] = r;
+            let rect = RECT { left, top, right, bottom };
+            unsafe { InvalidateRect(h, &rect, 1); }
+        }
+    }
+
+    /// Return true if the control is painted in a back buffer
+    pub fn double_buffered(&self) -> bool {
+        match self.hwnd() {
+            Some(h) => back_buffer::enabled(h),
+            None => false
+        }
+    }
+
+    /**
+        Paint the control in a back buffer to remove the flickering when it is redrawn.
+        The back buffer has the size of the client area and is copied to the control at the end of the paint event.
+        The background of a double buffered control is not erased (`WM_ERASEBKGND` is ignored).
+
+        The back buffer is used by `PaintData::paint`. Does nothing if the handle is not a window handle.
+    */
+    pub fn set_double_buffered(&self, v: bool) {
+        if let Some(h) = self.hwnd() {
+            back_buffer::set_enabled(h, v);
+        }
+    }
+
+    /**
+        Stop redrawing the control until `thaw` is called. Use it when adding or changing many items of a list box,
+        a tree view or a combo box, so that the control is not repainted after each item.
+
+        The calls can be nested: the control is redrawn when `thaw` was called as many times as `freeze`.
+        Does nothing if the handle is not a window handle.
+    */
+    pub fn freeze(&self) {
+        use winapi::um::winuser::WM_SETREDRAW;
+
+        let h = match self.hwnd() {
+            Some(h) => h,
+            None => { return; }
+        };
+
+        let first = FROZEN.with(|frozen| {
+            let mut frozen = frozen.borrow_mut();
+            match frozen.iter_mut().find(|(hwnd, _)| *hwnd == h) {
+                Some((_, count)) => { *count += 1; false },
+                None => { frozen.push((h, 1)); true }
+            }
+        });
+
+        if first {
+            wh::send_message(h, WM_SETREDRAW, 0, 0);
+            forget_frozen_on_destroy(h);
+        }
+    }
+
+    /// Redraw the control and its children after the last call to `thaw` matching a call to `freeze`.
+    /// Does nothing if the control is not frozen.
+    pub fn thaw(&self) {
+        use winapi::um::winuser::{RedrawWindow, WM_SETREDRAW, RDW_ERASE, RDW_FRAME, RDW_INVALIDATE, RDW_ALLCHILDREN};
+        use std::ptr;
+
+        let h = match self.hwnd() {
+            Some(h) => h,
+            None => { return; }
+        };
+
+        let last = FROZEN.with(|frozen| {
+            let mut frozen = frozen.borrow_mut();
+            let index = match frozen.iter().position(|(hwnd, _)| *hwnd == h) {
+                Some(i) => i,
+                None => { return false; }
+            };
+
+            frozen[index].1 -= 1;
+            match frozen[index].1 == 0 {
+                true => { frozen.swap_remove(index); true },
+                false => false
+            }
+        });
+
+        if last && wh::window_valid(h) {
+            wh::send_message(h, WM_SETREDRAW, 1, 0);
+            unsafe { RedrawWindow(h, ptr::null(), ptr::null_mut(), RDW_ERASE | RDW_FRAME | RDW_INVALIDATE | RDW_ALLCHILDREN); }
+        }
+    }
+
+    /// Return true if the control was frozen with `freeze`
+    pub fn frozen(&self) -> bool {
+        match self.hwnd() {
+            Some(h) => FROZEN.with(|frozen| frozen.borrow().iter().any(|(hwnd, _)| *hwnd == h)),
+            None => false
+        }
+    }
+
+    /**
+        Freeze the control until the returned guard is dropped. See `freeze`.
+
+        ```rust
+        use native_windows_gui as nwg;
+
+        fn fill(list: &nwg::ListBox<String>, items: &[String]) {
+            let _freeze = list.handle.freeze_guard();
+            for item in items {
+                list.push(item.clone());
+            }
+        }
+        ```
+    */
+    pub fn freeze_guard(&self) -> FreezeGuard {
+        self.freeze();
+        FreezeGuard { handle: *self }
+    }
+
+    /// Return true if the control can be selected using tab navigation. Return false if the handle is not a window handle.
+    pub fn tab_stop(&self) -> bool {
+        use winapi::um::winuser::{GWL_STYLE, WS_TABSTOP};
+
+        match self.hwnd() {
+            Some(h) => wh::get_window_long(h, GWL_STYLE) as u32 & WS_TABSTOP == WS_TABSTOP,
+            None => false
+        }
+    }
+
+    /// Set if the control can be selected using tab navigation (`WS_TABSTOP`). Does nothing if the handle is not a window handle.
+    pub fn set_tab_stop(&self, v: bool) {
+        use winapi::um::winuser::{GWL_STYLE, WS_TABSTOP};
+
+        if let Some(h) = self.hwnd() {
+            let style = wh::get_window_long(h, GWL_STYLE) as u32;
+            let style = match v {
+                true => style | WS_TABSTOP,
+                false => style & !WS_TABSTOP
+            };
+
+            wh::set_window_long(h, GWL_STYLE, style as usize);
+        }
+    }
+
+    /// Return true if the control is mirrored for right-to-left languages. Return false if the handle is not a window handle.
+    pub fn rtl_layout(&self) -> bool {
+        match self.hwnd() {
+            Some(h) => wh::is_rtl_layout(h),
+            None => false
+        }
+    }
+
+    /**
+        Mirror the control for right-to-left languages (`WS_EX_LAYOUTRTL`). The origin of a mirrored control is its top right corner,
+        so its children and the layouts of its children (ex: the columns of a `GridLayout`) are placed from right to left.
+        The children created after the call inherit the layout.
+
+        Does nothing if the handle is not a window handle.
+    */
+    pub fn set_rtl_layout(&self, v: bool) {
+        use winapi::um::winuser::{WS_EX_LAYOUTRTL, InvalidateRect};
+
+        if let Some(h) = self.hwnd() {
+            set_ex_style(h, WS_EX_LAYOUTRTL, v);
+            unsafe { InvalidateRect(h, ::std::ptr::null(), 1); }
+        }
+    }
+
+    /// Return true if the text of the control is displayed with the right-to-left reading order. Return false if the handle is not a window handle.
+    pub fn rtl_reading(&self) -> bool {
+        use winapi::um::winuser::{GWL_EXSTYLE, WS_EX_RTLREADING};
+
+        match self.hwnd() {
+            Some(h) => wh::get_window_long(h, GWL_EXSTYLE) as u32 & WS_EX_RTLREADING == WS_EX_RTLREADING,
+            None => false
+        }
+    }
+
+    /**
+        Display the text of the control with the right-to-left reading order (`WS_EX_RTLREADING`) without mirroring the control.
+        Does nothing if the handle is not a window handle.
+    */
+    pub fn set_rtl_reading(&self, v: bool) {
+        use winapi::um::winuser::{WS_EX_RTLREADING, InvalidateRect};
+
+        if let Some(h) = self.hwnd() {
+            set_ex_style(h, WS_EX_RTLREADING, v);
+            unsafe { InvalidateRect(h, ::std::ptr::null(), 1); }
+        }
+    }
+
+    /**
+        Set the name announced by the screen readers for the control. By default, the name of a control is its text
+        (or the text of the label placed before it).
+
+        Requires the `accessibility` feature. Returns an error if the handle is not a window handle.
+    */
+    #[cfg(feature = "accessibility")]
+    pub fn set_accessible_name(&self, name: &str) -> Result<(), NwgError> {
+        let h = self.hwnd().ok_or_else(|| NwgError::accessibility("The control is not a window"))?;
+        unsafe { accessibility::set_name(h, name) }
+    }
+
+    /**
+        Set the description announced by the screen readers after the name of the control. Use it to explain what the control does.
+
+        Requires the `accessibility` feature. Returns an error if the handle is not a window handle.
+    */
+    #[cfg(feature = "accessibility")]
+    pub fn set_accessible_description(&self, description: &str) -> Result<(), NwgError> {
+        let h = self.hwnd().ok_or_else(|| NwgError::accessibility("The control is not a window"))?;
+        unsafe { accessibility::set_description(h, description) }
+    }
+
+    /**
+        Override the role announced by the screen readers for the control. Use it on custom drawn controls
+        (ex: an `ExternCanvas` drawing a button).
+
+        Requires the `accessibility` feature. Returns an error if the handle is not a window handle.
+    */
+    #[cfg(feature = "accessibility")]
+    pub fn set_accessible_role(&self, role: AccessibleRole) -> Result<(), NwgError> {
+        let h = self.hwnd().ok_or_else(|| NwgError::accessibility("The control is not a window"))?;
+        unsafe { accessibility::set_role(h, role) }
+    }
+
+    /// Remove the accessible name, description and role set on the control. Does nothing if the handle is not a window handle.
+    #[cfg(feature = "accessibility")]
+    pub fn clear_accessibility(&self) {
+        if let Some(h) = self.hwnd() {
+            accessibility::clear(h);
+        }
+    }
+
+    /**
+        Move the composition window and the candidate window of the input method editor (IME) to `(x, y)`, in client coordinates.
+        Custom text controls should call this with the position of their text cursor so that the East Asian text is composed inline.
+
+        Requires the `ime` feature. Returns false if the handle is not a window handle or if the control does not have an input context.
+    */
+    #[cfg(feature = "ime")]
+    pub fn set_ime_position(&self, x: i32, y: i32) -> bool {
+        match self.hwnd() {
+            Some(h) => unsafe {
+                let (x, y) = crate::win32::high_dpi::logical_to_physical(x, y);
+                ime::set_position(h, x, y)
+            },
+            None => false
+        }
+    }
+
+    /// Return true if the input method editor (IME) of the control is open. Requires the `ime` feature.
+    #[cfg(feature = "ime")]
+    pub fn ime_open(&self) -> bool {
+        match self.hwnd() {
+            Some(h) => unsafe { ime::open_status(h) },
+            None => false
+        }
+    }
+
+    /**
+        Open or close the input method editor (IME) of the control. A closed IME sends the keys to the control without composing text.
+
+        Requires the `ime` feature. Returns false if the handle is not a window handle or if the control does not have an input context.
+    */
+    #[cfg(feature = "ime")]
+    pub fn set_ime_open(&self, open: bool) -> bool {
+        match self.hwnd() {
+            Some(h) => unsafe { ime::set_open_status(h, open) },
+            None => false
+        }
+    }
+
+}
+
+
+/// Add or remove an extended window style
+fn set_ex_style(h: HWND, style: u32, v: bool) {
+    use winapi::um::winuser::GWL_EXSTYLE;
+
+    let ex_style = wh::get_window_long(h, GWL_EXSTYLE) as u32;
+    let ex_style = match v {
+        true => ex_style | style,
+        false => ex_style & !style
+    };
+
+    wh::set_window_long(h, GWL_EXSTYLE, ex_style as usize);
 }
 
 
@@ -127,13 +494,16 @@ impl PartialEq for ControlHandle {
                 _ => false
             },
             // HMENU
+            // Menu events do not know if a menu is a popup menu, so both types compare the same way
             &ControlHandle::Menu(_, h1) => match other {
                 &ControlHandle::Menu(_, h2) => h1 == h2,
+                &ControlHandle::PopMenu(_, h2) => h1 == h2,
                 _ => false
             },
             // HMENU
             &ControlHandle::PopMenu(_, h1) => match other {
                 &ControlHandle::PopMenu(_, h2) => h1 == h2,
+                &ControlHandle::Menu(_, h2) => h1 == h2,
                 _ => false
             },
             // HMENU / ITEM
@@ -152,8 +522,13 @@ impl PartialEq for ControlHandle {
                 _ => false
             },
             // System tray
-            &ControlHandle::SystemTray(hwnd1) => match other {
-                &ControlHandle::SystemTray(hwnd2) => hwnd1 == hwnd2,
+            &ControlHandle::SystemTray(hwnd1, id1) => match other {
+                &ControlHandle::SystemTray(hwnd2, id2) => hwnd1 == hwnd2 && id1 == id2,
+                _ => false
+            },
+            // Toast notification
+            &ControlHandle::Toast(hwnd1, id1) => match other {
+                &ControlHandle::Toast(hwnd2, id2) => hwnd1 == hwnd2 && id1 == id2,
                 _ => false
             }
         }
@@ -165,3 +540,186 @@ impl Eq for ControlHandle {}
 impl From<&ControlHandle> for ControlHandle {
     fn from(control: &ControlHandle) -> Self { *control }
 }
+
+
+/**
+    A weak reference to a control, created with `ControlHandle::downgrade`.
+
+    Windows reuses the handles of the destroyed windows, so a handle copied into an event handler can end up pointing to
+    another window. A `WeakControl` remembers the window it was created from and `upgrade` returns `None` once that window is destroyed.
+
+    Weak references must be upgraded in the thread that created the control.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn bind_close_button(window: &nwg::Window, button: &nwg::Button) -> nwg::EventHandler {
+        let weak_window = window.handle.downgrade();
+        let button_handle = button.handle;
+
+        nwg::full_bind_event_handler(&window.handle, move |evt, _evt_data, handle| {
+            if evt == nwg::Event::OnButtonClick && handle == button_handle {
+                if let Some(mut window) = weak_window.upgrade() {
+                    window.destroy();
+                }
+            }
+        })
+    }
+    ```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeakControl {
+    handle: ControlHandle,
+    token: usize,
+}
+
+impl WeakControl {
+
+    /// Return the handle of the control if it was not destroyed
+    pub fn upgrade(&self) -> Option<ControlHandle> {
+        match self.alive() {
+            true => Some(self.handle),
+            false => None
+        }
+    }
+
+    /// Return true if the control was not destroyed
+    pub fn alive(&self) -> bool {
+        if !self.handle.is_valid() {
+            return false;
+        }
+
+        match self.handle.window() {
+            Some(h) => WINDOW_TOKENS.with(|tokens| tokens.borrow().iter().any(|&(hwnd, token)| hwnd == h && token == self.token)),
+            None => true
+        }
+    }
+
+}
+
+impl Default for WeakControl {
+    fn default() -> WeakControl {
+        WeakControl { handle: ControlHandle::NoHandle, token: 0 }
+    }
+}
+
+/**
+    Keeps a control frozen until it is dropped. Created by `ControlHandle::freeze_guard`.
+*/
+pub struct FreezeGuard {
+    handle: ControlHandle,
+}
+
+impl Drop for FreezeGuard {
+    fn drop(&mut self) {
+        self.handle.thaw();
+    }
+}
+
+thread_local! {
+    /// The windows frozen with `ControlHandle::freeze` and the number of calls to `freeze`
+    static FROZEN: RefCell<Vec<(HWND, u32)>> = RefCell::new(Vec::new());
+}
+
+/// Raw event handler id used to forget the token of a destroyed window
+const WEAK_HANDLER_ID: usize = 0xFF01;
+
+/// Raw event handler id used to forget a frozen window when it is destroyed
+const FREEZE_HANDLER_ID: usize = 0xFF03;
+
+static NEXT_TOKEN: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    /// The tokens of the windows that have weak references. A token is removed when its window is destroyed.
+    static WINDOW_TOKENS: RefCell<Vec<(HWND, usize)>> = RefCell::new(Vec::new());
+}
+
+/// Return the token of `hwnd`, creating it if needed
+fn window_token(hwnd: HWND) -> usize {
+    use winapi::um::winuser::WM_NCDESTROY;
+    use crate::win32::window::bind_raw_event_handler_inner;
+
+    let token = WINDOW_TOKENS.with(|tokens| tokens.borrow().iter().find(|&&(h, _)| h == hwnd).map(|&(_, token)| token));
+    if let Some(token) = token {
+        return token;
+    }
+
+    let token = NEXT_TOKEN.fetch_add(1, Ordering::SeqCst);
+    WINDOW_TOKENS.with(|tokens| tokens.borrow_mut().push((hwnd, token)));
+
+    // The handler is freed with the window
+    bind_raw_event_handler_inner(&ControlHandle::Hwnd(hwnd), WEAK_HANDLER_ID, move |hwnd, msg, _w, _l| {
+        if msg == WM_NCDESTROY {
+            WINDOW_TOKENS.with(|tokens| tokens.borrow_mut().retain(|&(h, _)| h != hwnd));
+        }
+        None
+    }).ok();
+
+    token
+}
+
+/// Remove `hwnd` from the frozen windows when it is destroyed, so that a new window with the same handle is not frozen
+fn forget_frozen_on_destroy(hwnd: HWND) {
+    use winapi::um::winuser::WM_NCDESTROY;
+    use crate::win32::window::bind_raw_event_handler_inner;
+
+    // The handler is freed with the window. It is already bound if the window was frozen before.
+    bind_raw_event_handler_inner(&ControlHandle::Hwnd(hwnd), FREEZE_HANDLER_ID, move |hwnd, msg, _w, _l| {
+        if msg == WM_NCDESTROY {
+            FROZEN.with(|frozen| frozen.borrow_mut().retain(|&(h, _)| h != hwnd));
+        }
+        None
+    }).ok();
+}
+
+
+#[cfg(feature = "raw-win-handle")]
+use raw_window_handle::{HasRawWindowHandle, RawWindowHandle, windows::WindowsHandle};
+
+/// Only window-like handles can be used as a window handle. Other handles return an empty handle.
+#[cfg(feature = "raw-win-handle")]
+unsafe impl HasRawWindowHandle for ControlHandle {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        use winapi::um::winuser::GWL_HINSTANCE;
+
+        match self {
+            &ControlHandle::Hwnd(hwnd) => {
+                let hinstance = wh::get_window_long(hwnd, GWL_HINSTANCE);
+
+                RawWindowHandle::Windows(WindowsHandle {
+                    hwnd: hwnd as _,
+                    hinstance: hinstance as _,
+                    ..WindowsHandle::empty()
+                })
+            }
+            // Not a valid window handle, so return an empty handle
+            _ => RawWindowHandle::Windows(WindowsHandle::empty())
+        }
+    }
+}
+
+#[cfg(feature = "raw-win-handle-05")]
+use raw_window_handle_05::{HasRawDisplayHandle, RawDisplayHandle, WindowsDisplayHandle, Win32WindowHandle};
+
+/// Only window-like handles can be used as a window handle. Other handles return an empty handle.
+#[cfg(feature = "raw-win-handle-05")]
+unsafe impl raw_window_handle_05::HasRawWindowHandle for ControlHandle {
+    fn raw_window_handle(&self) -> raw_window_handle_05::RawWindowHandle {
+        use winapi::um::winuser::GWL_HINSTANCE;
+
+        let mut handle = Win32WindowHandle::empty();
+        if let &ControlHandle::Hwnd(hwnd) = self {
+            handle.hwnd = hwnd as _;
+            handle.hinstance = wh::get_window_long(hwnd, GWL_HINSTANCE) as _;
+        }
+
+        raw_window_handle_05::RawWindowHandle::Win32(handle)
+    }
+}
+
+#[cfg(feature = "raw-win-handle-05")]
+unsafe impl HasRawDisplayHandle for ControlHandle {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        RawDisplayHandle::Windows(WindowsDisplayHandle::empty())
+    }
+}
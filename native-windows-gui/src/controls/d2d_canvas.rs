@@ -0,0 +1,295 @@
+use winapi::um::winuser::{WS_CHILD, WS_VISIBLE, WS_DISABLED, WS_CLIPCHILDREN, WS_CLIPSIBLINGS, WS_TABSTOP};
+use winapi::um::d2d1::ID2D1Factory;
+use crate::win32::base_helper::{check_hwnd, try_check_hwnd};
+use crate::win32::window_helper as wh;
+use crate::win32::d2d_canvas::D2DRenderer;
+use crate::NwgError;
+use super::{ControlBase, ControlHandle};
+
+pub use crate::win32::d2d_canvas::D2DContext;
+
+const NOT_BOUND: &'static str = "D2DCanvas is not yet bound to a winapi object";
+const BAD_HANDLE: &'static str = "INTERNAL ERROR: D2DCanvas handle is not HWND!";
+
+
+bitflags! {
+    /**
+        The D2D canvas flags
+
+        * NONE:     No flags. Equivalent to a invisible canvas.
+        * VISIBLE:  The canvas is immediatly visible after creation
+        * DISABLED: The canvas cannot be interacted with by the user.
+        * TAB_STOP: The canvas can be selected using tab navigation
+    */
+    pub struct D2DCanvasFlags: u32 {
+        const NONE = 0;
+        const VISIBLE = WS_VISIBLE;
+        const DISABLED = WS_DISABLED;
+        const TAB_STOP = WS_TABSTOP;
+    }
+}
+
+/**
+    A children control that is drawn with Direct2D (hardware-accelerated 2D drawing).
+
+    The canvas manages an `ID2D1HwndRenderTarget`: the render target is created on the first call to `draw`,
+    resized when the size of the canvas changes, and created again if the graphic device is lost (ex: the driver was updated).
+    When the device is lost, the canvas is invalidated so that the next `OnPaint` event draws it with the new render target.
+
+    Drawing is done in the closure passed to `draw`, between the `BeginDraw` and `EndDraw` calls of the render target.
+    Call `draw` in the `OnPaint` event of the canvas, and call `invalidate` to request a new frame.
+
+    Requires the `d2d-canvas` feature.
+
+    **Builder parameters:**
+      * `parent`:   **Required.** The canvas parent container.
+      * `size`:     The canvas size.
+      * `position`: The canvas position.
+      * `flags`:    A combination of the D2DCanvasFlags values.
+      * `ex_flags`: A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
+
+    **Control events:**
+      * `OnPaint`: When the canvas must be drawn
+      * `MousePress(_)`: Generic mouse press events on the canvas
+      * `OnMouseMove`: Generic mouse mouse event
+      * `OnMouseWheel`: Generic mouse wheel event
+      * `OnResize`: When the canvas is resized
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn paint(canvas: &nwg::D2DCanvas) -> Result<(), nwg::NwgError> {
+        canvas.draw(|ctx| {
+            let [width, height] = ctx.size();
+            ctx.clear([255, 255, 255, 255]);
+            ctx.fill_ellipse([width / 2.0, height / 2.0], [50.0, 50.0], [255, 200, 0, 255]);
+            ctx.draw_line([0.0, 0.0], [width, height], [0, 0, 0, 128], 2.0);
+        })
+    }
+    ```
+*/
+#[derive(Default)]
+pub struct D2DCanvas {
+    pub handle: ControlHandle,
+    renderer: Option<D2DRenderer>,
+}
+
+impl D2DCanvas {
+
+    pub fn builder() -> D2DCanvasBuilder {
+        D2DCanvasBuilder {
+            size: (100, 100),
+            position: (0, 0),
+            flags: None,
+            ex_flags: 0,
+            parent: None,
+        }
+    }
+
+    /**
+        Draws the canvas. `draw` is called between the `BeginDraw` and `EndDraw` calls of the render target.
+        The drawing is clipped to the canvas, and the invalid region of the canvas is validated.
+
+        Returns an error if the render target cannot be created or if the drawing failed for a reason other than a device loss.
+    */
+    pub fn draw<F: FnOnce(&D2DContext)>(&self, draw: F) -> Result<(), NwgError> {
+        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        self.renderer.as_ref().expect(NOT_BOUND).draw(handle, draw)
+    }
+
+    /// Returns the direct2D factory of the canvas, to create device independent resources (ex: geometries)
+    pub fn factory(&self) -> &ID2D1Factory {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        unsafe { &*self.renderer.as_ref().expect(NOT_BOUND).factory() }
+    }
+
+    try_accessors! {
+        /// Invalidate the whole canvas. The canvas will receive a `OnPaint` event.
+        pub fn invalidate(&self) => try_invalidate {
+            use winapi::um::winuser::InvalidateRect;
+            use std::ptr;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { InvalidateRect(handle, ptr::null(), 0); }
+
+            Ok(())
+        }
+
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
+
+        /// Set the keyboard focus on the canvas
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
+
+            Ok(())
+        }
+
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
+
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return true if the control is visible to the user. Will return true even if the
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the canvas in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Return the physical size of canvas in pixels considering the dpi scale
+        pub fn physical_size(&self) -> (u32, u32) => try_physical_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_physical_size(handle) })
+        }
+
+        /// Set the size of the canvas in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, true) }
+
+            Ok(())
+        }
+
+        /// Return the position of the canvas in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the canvas in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+    }
+
+    /// Winapi class name used during control creation
+    pub fn class_name(&self) -> &'static str {
+        "NWG_EXTERN_CANVAS"
+    }
+
+    // Winapi base flags used during window creation
+    pub fn flags(&self) -> u32 {
+        WS_VISIBLE
+    }
+
+    /// Winapi flags required by the control
+    pub fn forced_flags(&self) -> u32 {
+        WS_CHILD | WS_CLIPCHILDREN | WS_CLIPSIBLINGS
+    }
+
+}
+
+impl Drop for D2DCanvas {
+    fn drop(&mut self) {
+        // The render target must be released before its window
+        self.renderer = None;
+        self.handle.destroy();
+    }
+}
+
+impl PartialEq for D2DCanvas {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+pub struct D2DCanvasBuilder {
+    size: (i32, i32),
+    position: (i32, i32),
+    flags: Option<D2DCanvasFlags>,
+    ex_flags: u32,
+    parent: Option<ControlHandle>,
+}
+
+impl D2DCanvasBuilder {
+
+    pub fn flags(mut self, flags: D2DCanvasFlags) -> D2DCanvasBuilder {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn ex_flags(mut self, flags: u32) -> D2DCanvasBuilder {
+        self.ex_flags = flags;
+        self
+    }
+
+    pub fn size(mut self, size: (i32, i32)) -> D2DCanvasBuilder {
+        self.size = size;
+        self
+    }
+
+    pub fn position(mut self, pos: (i32, i32)) -> D2DCanvasBuilder {
+        self.position = pos;
+        self
+    }
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> D2DCanvasBuilder {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn build(self, out: &mut D2DCanvas) -> Result<(), NwgError> {
+        let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+
+        let parent = match self.parent {
+            Some(p) => Ok(p),
+            None => Err(NwgError::no_parent("D2DCanvas"))
+        }?;
+
+        *out = Default::default();
+
+        out.handle = ControlBase::build_hwnd()
+            .class_name(out.class_name())
+            .forced_flags(out.forced_flags())
+            .flags(flags)
+            .ex_flags(self.ex_flags)
+            .size(self.size)
+            .position(self.position)
+            .text("")
+            .parent(Some(parent))
+            .build()?;
+
+        match D2DRenderer::init() {
+            Ok(renderer) => {
+                out.renderer = Some(renderer);
+                Ok(())
+            },
+            Err(e) => {
+                *out = Default::default();
+                Err(e)
+            }
+        }
+    }
+
+}
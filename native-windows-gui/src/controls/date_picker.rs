@@ -1,6 +1,8 @@
 use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
+use winapi::um::commctrl::{DTS_TIMEFORMAT, DTS_UPDOWN, DTS_LONGDATEFORMAT};
+use winapi::um::minwinbase::SYSTEMTIME;
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::{to_utf16, check_hwnd};
+use crate::win32::base_helper::{to_utf16, try_check_hwnd};
 use crate::{Font, NwgError};
 use super::{ControlBase, ControlHandle};
 
@@ -17,11 +19,17 @@ bitflags! {
         * VISIBLE:  The date picker is immediatly visible after creation
         * DISABLED: The date picker cannot be interacted with by the user. It also has a grayed out look.
         * TAB_STOP: The control can be selected using tab navigation
+        * TIME_FORMAT: The date picker displays and edits a time instead of a date. The control has an up-down control instead of a calendar.
+        * UP_DOWN: The date is edited with an up-down control instead of a dropdown calendar
+        * LONG_DATE: The date is displayed with the long date format of the user (ex: "Friday, April 19, 1996")
     */
     pub struct DatePickerFlags: u32 {
         const VISIBLE = WS_VISIBLE;
         const DISABLED = WS_DISABLED;
         const TAB_STOP = WS_TABSTOP;
+        const TIME_FORMAT = DTS_TIMEFORMAT;
+        const UP_DOWN = DTS_UPDOWN;
+        const LONG_DATE = DTS_LONGDATEFORMAT;
     }
 }
 
@@ -36,6 +44,16 @@ pub struct DatePickerValue {
     pub day: u16
 }
 
+/**
+    A time struct that can be passed to a date time picker control. Used with the `TIME_FORMAT` flag.
+    Fields are self explanatory. `hour` is in 24-hour format.
+*/
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DatePickerTime {
+    pub hour: u16,
+    pub minute: u16,
+    pub second: u16
+}
 
 
 /**
@@ -53,6 +71,7 @@ Requires the `datetime-picker` feature.
   * `ex_flags`: A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
   * `font`:     The font used for the dtp text
   * `date`:     The default date as a `DatePickerValue` value
+  * `time`:     The default time as a `DatePickerTime` value
   * `format`:   The format of the date. See the `set_format` method.
   * `range`:    The accepted range of dates. The value is inclusive.
   * `focus`:    The control receive focus after being created
@@ -82,6 +101,18 @@ fn build_dtp(date: &mut nwg::DatePicker, window: &nwg::Window) {
         .build(date);
 }
 ```
+
+```rust
+use native_windows_gui as nwg;
+fn build_time_picker(time: &mut nwg::DatePicker, window: &nwg::Window) {
+    nwg::DatePicker::builder()
+        .flags(nwg::DatePickerFlags::VISIBLE | nwg::DatePickerFlags::TIME_FORMAT)
+        .time(Some(nwg::DatePickerTime { hour: 13, minute: 30, second: 0 }))
+        .format(Some("HH':'mm"))
+        .parent(window)
+        .build(time);
+}
+```
 */
 #[derive(Default, PartialEq, Eq)]
 pub struct DatePicker {
@@ -100,249 +131,295 @@ impl DatePicker {
             font: None,
             parent: None,
             date: None,
+            time: None,
             format: None,
             range: None
         }
     }
 
-    /**
-        Sets the date format of the control
-
-        About the format string:  
-        - `d` 	    The one- or two-digit day.  
-        - `dd` 	    The two-digit day. Single-digit day values are preceded by a zero.  
-        - `ddd` 	The three-character weekday abbreviation.  
-        - `dddd` 	The full weekday name.  
-        - `M` 	    The one- or two-digit month number.  
-        - `MM` 	    The two-digit month number. Single-digit values are preceded by a zero.  
-        - `MMM` 	The three-character month abbreviation.  
-        - `MMMM` 	The full month name.  
-        - `t` 	    The one-letter AM/PM abbreviation (that is, AM is displayed as "A").  
-        - `tt` 	    The two-letter AM/PM abbreviation (that is, AM is displayed as "AM").  
-        - `yy` 	    The last two digits of the year (that is, 1996 would be displayed as "96").  
-        - `yyyy` 	The full year (that is, 1996 would be displayed as "1996").   
-
-        - `h` 	The one- or two-digit hour in 12-hour format.
-        - `hh` 	The two-digit hour in 12-hour format. Single-digit values are preceded by a zero.
-        - `H` 	The one- or two-digit hour in 24-hour format.
-        - `HH` 	The two-digit hour in 24-hour format. Single-digit values are preceded by a zero.
-        - `m` 	The one- or two-digit minute.
-        - `mm` 	The two-digit minute. Single-digit values are preceded by a zero.
+    try_accessors! {
+        /**
+            Sets the date format of the control
+
+            About the format string:  
+            - `d` 	    The one- or two-digit day.  
+            - `dd` 	    The two-digit day. Single-digit day values are preceded by a zero.  
+            - `ddd` 	The three-character weekday abbreviation.  
+            - `dddd` 	The full weekday name.  
+            - `M` 	    The one- or two-digit month number.  
+            - `MM` 	    The two-digit month number. Single-digit values are preceded by a zero.  
+            - `MMM` 	The three-character month abbreviation.  
+            - `MMMM` 	The full month name.  
+            - `t` 	    The one-letter AM/PM abbreviation (that is, AM is displayed as "A").  
+            - `tt` 	    The two-letter AM/PM abbreviation (that is, AM is displayed as "AM").  
+            - `yy` 	    The last two digits of the year (that is, 1996 would be displayed as "96").  
+            - `yyyy` 	The full year (that is, 1996 would be displayed as "1996").   
+
+            - `h` 	The one- or two-digit hour in 12-hour format.
+            - `hh` 	The two-digit hour in 12-hour format. Single-digit values are preceded by a zero.
+            - `H` 	The one- or two-digit hour in 24-hour format.
+            - `HH` 	The two-digit hour in 24-hour format. Single-digit values are preceded by a zero.
+            - `m` 	The one- or two-digit minute.
+            - `mm` 	The two-digit minute. Single-digit values are preceded by a zero.
         
-        Furthermore, any string enclosed in `'` can be used in the format to display text.  
-        For example, to display the current date with the format `'Today is: Tuesday Mar 23, 1996`, the format string is `'Today is: 'dddd MMM dd', 'yyyy`. 
+            Furthermore, any string enclosed in `'` can be used in the format to display text.  
+            For example, to display the current date with the format `'Today is: Tuesday Mar 23, 1996`, the format string is `'Today is: 'dddd MMM dd', 'yyyy`. 
     
-        If `format` is set to `None`, use the default system format.
-    */
-    pub fn set_format<'a>(&self, format: Option<&'a str>) {
-        use winapi::um::commctrl::DTM_SETFORMATW;
-        use winapi::shared::minwindef::LPARAM;
+            If `format` is set to `None`, use the default system format.
+        */
+        pub fn set_format<'a>(&self, format: Option<&'a str>) => try_set_format {
+            use winapi::um::commctrl::DTM_SETFORMATW;
+            use winapi::shared::minwindef::LPARAM;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let (_format, format_ptr) = if format.is_some() {
-            let f = to_utf16(format.unwrap());
-            let fptr = f.as_ptr() as LPARAM;
-            (f, fptr)
-        } else {
-            (Vec::new(), 0)
-        };
+            let (_format, format_ptr) = if format.is_some() {
+                let f = to_utf16(format.unwrap());
+                let fptr = f.as_ptr() as LPARAM;
+                (f, fptr)
+            } else {
+                (Vec::new(), 0)
+            };
 
-        wh::send_message(handle, DTM_SETFORMATW, 0, format_ptr);
-    }
+            wh::send_message(handle, DTM_SETFORMATW, 0, format_ptr);
 
-    /**
-        Return the check state of the checkbox of the control.  
-        If the date time picker is not optional, return false.
+            Ok(())
+        }
 
-        To set the check state of the control, use `set_value` method
-    */
-    pub fn checked(&self) -> bool {
-        use winapi::um::winuser::STATE_SYSTEM_CHECKED;
+        /**
+            Return the check state of the checkbox of the control.  
+            When the checkbox is unchecked, the control has no value.
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            To set the check state of the control, use `set_value` method
+        */
+        pub fn checked(&self) -> bool => try_checked {
+            use winapi::um::winuser::STATE_SYSTEM_CHECKED;
 
-        let info = unsafe{ get_dtp_info(handle) };
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        match info.stateCheck {
-            STATE_SYSTEM_CHECKED => true,
-            _ => false
-        }
-    }
+            let info = unsafe{ get_dtp_info(handle) };
 
-    /// Close the calendar popup if it is open. Note that there is no way to force the calendar to drop down
-    pub fn close_calendar(&self) {
-        use winapi::um::commctrl::DTM_CLOSEMONTHCAL;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, DTM_CLOSEMONTHCAL, 0, 0);
-    }
+            Ok(match info.stateCheck {
+                STATE_SYSTEM_CHECKED => true,
+                _ => false
+            })
+        }
 
-    /**
-        Return the time set in the control in a `PickerDate` structure.  
-        Return None if `optional` was set and the checkbox is not checked.  
-        Note: use `get_text` to get the text value of the control.
-    */
-    pub fn value(&self) -> Option<DatePickerValue> {
-        use winapi::um::commctrl::{GDT_VALID, DTM_GETSYSTEMTIME};
-        use winapi::um::minwinbase::SYSTEMTIME;
-        use std::mem;
+        /// Close the calendar popup if it is open. Note that there is no way to force the calendar to drop down
+        pub fn close_calendar(&self) => try_close_calendar {
+            use winapi::um::commctrl::DTM_CLOSEMONTHCAL;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, DTM_CLOSEMONTHCAL, 0, 0);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            Ok(())
+        }
 
-        let mut syst: SYSTEMTIME = unsafe{ mem::zeroed() };
+        /**
+            Return the time set in the control in a `PickerDate` structure.  
+            Return None if the checkbox is not checked.  
+            Note: use `get_text` to get the text value of the control.
+        */
+        pub fn value(&self) -> Option<DatePickerValue> => try_value {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let r = unsafe{ wh::send_message(handle, DTM_GETSYSTEMTIME, 0, mem::transmute(&mut syst)) };
-        match r {
-            GDT_VALID => Some(DatePickerValue {
+            Ok(unsafe { get_system_time(handle) }.map(|syst| DatePickerValue {
                 year: syst.wYear,
                 month: syst.wMonth,
                 day: syst.wDay
-            }),
-            _ => None
+            }))
         }
-    }
 
-    /**
-        Set the time set in the control in a `PickerDate` structure.  
-        If `None` is passed, this clears the checkbox.
-    */
-    pub fn set_value(&self, date: Option<DatePickerValue>) {
-        use winapi::um::commctrl::{DTM_SETSYSTEMTIME, GDT_VALID, GDT_NONE};
-        use winapi::shared::minwindef::{WPARAM, LPARAM};
-        use winapi::um::minwinbase::SYSTEMTIME;
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-
-        match date {
-            Some(date) => {
-                let syst: SYSTEMTIME = SYSTEMTIME{ 
-                    wYear: date.year, 
-                    wMonth: date.month, 
-                    wDay: date.day, 
-                    wDayOfWeek:0, wHour:0, wMinute:0, wSecond:0, wMilliseconds: 0 
+        /**
+            Set the time set in the control in a `PickerDate` structure. The time of the control is kept.
+            If `None` is passed, this clears the checkbox.
+        */
+        pub fn set_value(&self, date: Option<DatePickerValue>) => try_set_value {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let syst = date.map(|date| {
+                let mut syst = unsafe { get_system_time(handle) }.unwrap_or(empty_system_time());
+                syst.wYear = date.year;
+                syst.wMonth = date.month;
+                syst.wDay = date.day;
+                syst.wDayOfWeek = 0;
+                syst
+            });
+
+            unsafe { set_system_time(handle, syst); }
+
+            Ok(())
+        }
+
+        /**
+            Return the time set in the control in a `DatePickerTime` structure.
+            Return None if the checkbox is not checked.
+        */
+        pub fn time(&self) -> Option<DatePickerTime> => try_time {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            Ok(unsafe { get_system_time(handle) }.map(|syst| DatePickerTime {
+                hour: syst.wHour,
+                minute: syst.wMinute,
+                second: syst.wSecond
+            }))
+        }
+
+        /**
+            Set the time set in the control in a `DatePickerTime` structure. The date of the control is kept.
+            If `None` is passed, this clears the checkbox.
+        */
+        pub fn set_time(&self, time: Option<DatePickerTime>) => try_set_time {
+            use winapi::um::sysinfoapi::GetLocalTime;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let syst = time.map(|time| {
+                let mut syst = match unsafe { get_system_time(handle) } {
+                    Some(syst) => syst,
+                    None => unsafe {
+                        let mut syst = empty_system_time();
+                        GetLocalTime(&mut syst);
+                        syst
+                    }
                 };
 
-                wh::send_message(handle, DTM_SETSYSTEMTIME, GDT_VALID as WPARAM, &syst as *const SYSTEMTIME as LPARAM);
-            },
-            None => { 
-                wh::send_message(handle, DTM_SETSYSTEMTIME, GDT_NONE as WPARAM, 0); 
-            }
-        };
-    }
+                syst.wHour = time.hour;
+                syst.wMinute = time.minute;
+                syst.wSecond = time.second;
+                syst.wMilliseconds = 0;
+                syst
+            });
 
-    /// Gets the current minimum and maximum allowable system times for a date and time picker control.
-    pub fn range(&self) -> [DatePickerValue; 2] {
-        use winapi::um::commctrl::DTM_GETRANGE;
-        use winapi::um::minwinbase::SYSTEMTIME;
-        use winapi::shared::minwindef::{LPARAM};
-        use std::mem;
+            unsafe { set_system_time(handle, syst); }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            Ok(())
+        }
 
-        let mut tr: [SYSTEMTIME; 2] = unsafe { mem::zeroed() };
+        /// Gets the current minimum and maximum allowable system times for a date and time picker control.
+        pub fn range(&self) -> [DatePickerValue; 2] => try_range {
+            use winapi::um::commctrl::DTM_GETRANGE;
+            use winapi::shared::minwindef::{LPARAM};
+            use std::mem;
 
-        wh::send_message(handle, DTM_GETRANGE, 0, &mut tr as *mut [SYSTEMTIME; 2] as LPARAM); 
-    
-        [
-            DatePickerValue { year: tr[0].wYear, month: tr[0].wMonth, day: tr[0].wDay },
-            DatePickerValue { year: tr[1].wYear, month: tr[1].wMonth, day: tr[1].wDay },
-        ]
-        
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let mut tr: [SYSTEMTIME; 2] = unsafe { mem::zeroed() };
+
+            wh::send_message(handle, DTM_GETRANGE, 0, &mut tr as *mut [SYSTEMTIME; 2] as LPARAM); 
     
-    /// Sets the minimum and maximum allowable system times for a date and time picker control. 
-    pub fn set_range(&self, r: &[DatePickerValue; 2]) {
-        use winapi::um::commctrl::DTM_SETRANGE;
-        use winapi::um::commctrl::{GDTR_MIN, GDTR_MAX};
-        use winapi::um::minwinbase::SYSTEMTIME;
-        use winapi::shared::minwindef::{LPARAM};
+            Ok([
+                DatePickerValue { year: tr[0].wYear, month: tr[0].wMonth, day: tr[0].wDay },
+                DatePickerValue { year: tr[1].wYear, month: tr[1].wMonth, day: tr[1].wDay },
+            ])
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Sets the minimum and maximum allowable system times for a date and time picker control. 
+        pub fn set_range(&self, r: &[DatePickerValue; 2]) => try_set_range {
+            use winapi::um::commctrl::DTM_SETRANGE;
+            use winapi::um::commctrl::{GDTR_MIN, GDTR_MAX};
+            use winapi::shared::minwindef::{LPARAM};
 
-        let values = [
-            SYSTEMTIME { wYear: r[0].year , wMonth: r[0].month, wDayOfWeek: 0, wDay: r[0].day, wHour: 0, wMinute: 0, wSecond: 0, wMilliseconds: 0 },
-            SYSTEMTIME { wYear: r[1].year , wMonth: r[1].month, wDayOfWeek: 0, wDay: r[1].day, wHour: 0, wMinute: 0, wSecond: 0, wMilliseconds: 0 }
-        ];
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        wh::send_message(handle, DTM_SETRANGE, GDTR_MIN | GDTR_MAX, &values as *const [SYSTEMTIME; 2] as LPARAM);
-    }
+            let values = [
+                SYSTEMTIME { wYear: r[0].year , wMonth: r[0].month, wDayOfWeek: 0, wDay: r[0].day, wHour: 0, wMinute: 0, wSecond: 0, wMilliseconds: 0 },
+                SYSTEMTIME { wYear: r[1].year , wMonth: r[1].month, wDayOfWeek: 0, wDay: r[1].day, wHour: 0, wMinute: 0, wSecond: 0, wMilliseconds: 0 }
+            ];
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+            wh::send_message(handle, DTM_SETRANGE, GDTR_MIN | GDTR_MAX, &values as *const [SYSTEMTIME; 2] as LPARAM);
+
+            Ok(())
         }
-    }
 
-    /// Sets the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
+        }
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+        /// Sets the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-    /// Sets the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+            Ok(())
+        }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Sets the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            Ok(())
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Return the size of the date picker in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Set the size of the date picker in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+            Ok(())
+        }
 
-    /// Return the position of the date picker in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the date picker in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Set the position of the date picker in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+        /// Set the size of the date picker in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
+        }
+
+        /// Return the position of the date picker in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the date picker in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
     }
 
 
@@ -381,6 +458,7 @@ pub struct DatePickerBuilder<'a> {
     focus: bool,
     parent: Option<ControlHandle>,
     date: Option<DatePickerValue>,
+    time: Option<DatePickerTime>,
     format: Option<&'a str>,
     range: Option<[DatePickerValue; 2]>
 }
@@ -422,6 +500,11 @@ impl<'a> DatePickerBuilder<'a> {
         self
     }
 
+    pub fn time(mut self, time: Option<DatePickerTime>) -> DatePickerBuilder<'a> {
+        self.time = time;
+        self
+    }
+
     pub fn format(mut self, format: Option<&'a str>) -> DatePickerBuilder<'a> {
         self.format = format;
         self
@@ -467,6 +550,10 @@ impl<'a> DatePickerBuilder<'a> {
             out.set_value(self.date)
         }
 
+        if self.time.is_some() {
+            out.set_time(self.time)
+        }
+
         if self.range.is_some() {
             out.set_range(&self.range.unwrap());
         }
@@ -499,3 +586,28 @@ unsafe fn get_dtp_info(handle: HWND) -> DATETIMEPICKERINFO {
 
     dtp_info
 }
+
+fn empty_system_time() -> SYSTEMTIME {
+    SYSTEMTIME { wYear: 0, wMonth: 0, wDayOfWeek: 0, wDay: 0, wHour: 0, wMinute: 0, wSecond: 0, wMilliseconds: 0 }
+}
+
+unsafe fn get_system_time(handle: HWND) -> Option<SYSTEMTIME> {
+    use winapi::um::commctrl::{GDT_VALID, DTM_GETSYSTEMTIME};
+    use winapi::shared::minwindef::LPARAM;
+
+    let mut syst = empty_system_time();
+    match wh::send_message(handle, DTM_GETSYSTEMTIME, 0, &mut syst as *mut SYSTEMTIME as LPARAM) {
+        GDT_VALID => Some(syst),
+        _ => None
+    }
+}
+
+unsafe fn set_system_time(handle: HWND, syst: Option<SYSTEMTIME>) {
+    use winapi::um::commctrl::{DTM_SETSYSTEMTIME, GDT_VALID, GDT_NONE};
+    use winapi::shared::minwindef::{WPARAM, LPARAM};
+
+    match syst {
+        Some(syst) => { wh::send_message(handle, DTM_SETSYSTEMTIME, GDT_VALID as WPARAM, &syst as *const SYSTEMTIME as LPARAM); },
+        None => { wh::send_message(handle, DTM_SETSYSTEMTIME, GDT_NONE as WPARAM, 0); }
+    }
+}
@@ -0,0 +1,118 @@
+use super::control_handle::ControlHandle;
+use crate::win32::drop_target;
+use crate::NwgError;
+
+
+const NOT_BOUND: &'static str = "DropTarget is not yet bound to a winapi object";
+
+/**
+An invisible component that accepts the data dragged over a control by the OLE drag and drop of another application (or of the same application).
+
+The drag and drop events are sent to the target control:
+- `OnDragEnter`: The data enters the control. Use `EventData::on_drag_drop` to check the data and select the drop effect.
+- `OnDragOver`: The mouse moves over the control. The drop effect can be changed.
+- `OnDragLeave`: The data leaves the control or the drag is cancelled
+- `OnDrop`: The data is dropped on the control. Read the data from the event data.
+
+The data is refused until a drop effect other than `DropEffect::NONE` is selected.
+A control can only have one drop target. The target is removed when the `DropTarget` is dropped.
+
+Requires the `drag-drop` feature.
+
+**Builder parameters:**
+  * `parent`: **Required.** The control receiving the dropped data. Must be a window-like control.
+
+## Example
+
+```rust
+use native_windows_gui as nwg;
+
+fn build_target(target: &mut nwg::DropTarget, list: &nwg::ListBox<String>) {
+    nwg::DropTarget::builder()
+        .parent(list)
+        .build(target);
+}
+
+fn drag_event(evt: nwg::Event, data: &nwg::EventData) {
+    match evt {
+        nwg::Event::OnDragEnter | nwg::Event::OnDragOver => {
+            let data = data.on_drag_drop();
+            if data.has_files() {
+                data.set_effect(nwg::DropEffect::COPY);
+            }
+        },
+        nwg::Event::OnDrop => {
+            for path in data.on_drag_drop().files() {
+                println!("{}", path);
+            }
+        },
+        _ => {}
+    }
+}
+```
+*/
+#[derive(Default, PartialEq, Eq)]
+pub struct DropTarget {
+    pub handle: ControlHandle
+}
+
+impl DropTarget {
+
+    pub fn builder() -> DropTargetBuilder {
+        DropTargetBuilder {
+            parent: None
+        }
+    }
+
+    /// Returns the control receiving the dropped data. Panics if the drop target is not initialized.
+    pub fn target(&self) -> ControlHandle {
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        self.handle
+    }
+
+}
+
+impl Drop for DropTarget {
+    fn drop(&mut self) {
+        use winapi::um::winuser::IsWindow;
+
+        if let Some(hwnd) = self.handle.hwnd() {
+            unsafe {
+                if IsWindow(hwnd) != 0 {
+                    drop_target::revoke(hwnd);
+                }
+            }
+        }
+    }
+}
+
+
+pub struct DropTargetBuilder {
+    parent: Option<ControlHandle>
+}
+
+impl DropTargetBuilder {
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> DropTargetBuilder {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn build(self, out: &mut DropTarget) -> Result<(), NwgError> {
+        let parent = match self.parent {
+            Some(p) => match p.hwnd() {
+                Some(handle) => Ok(handle),
+                None => Err(NwgError::control_create("Wrong parent type"))
+            },
+            None => Err(NwgError::no_parent("DropTarget"))
+        }?;
+
+        *out = Default::default();
+        unsafe { drop_target::register(parent)?; }
+
+        out.handle = ControlHandle::Hwnd(parent);
+
+        Ok(())
+    }
+
+}
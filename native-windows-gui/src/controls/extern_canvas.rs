@@ -1,7 +1,7 @@
 use winapi::um::winuser::{WS_OVERLAPPEDWINDOW, WS_VISIBLE, WS_DISABLED, WS_MAXIMIZE, WS_MINIMIZE, WS_CAPTION,
-WS_MINIMIZEBOX, WS_MAXIMIZEBOX, WS_SYSMENU, WS_THICKFRAME, WS_CLIPCHILDREN, WS_CLIPSIBLINGS };
+WS_MINIMIZEBOX, WS_MAXIMIZEBOX, WS_SYSMENU, WS_THICKFRAME, WS_CLIPCHILDREN, WS_CLIPSIBLINGS, WS_TABSTOP };
 
-use crate::win32::base_helper::check_hwnd;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::win32::window_helper as wh;
 use crate::{NwgError, Icon};
 use super::{ControlBase, ControlHandle};
@@ -29,6 +29,7 @@ bitflags! {
 
         General flags:
         * VISIBLE: Show the window right away
+        * TAB_STOP: The canvas can be selected using tab navigation (child canvas only)
     */
     pub struct ExternCanvasFlags: u32 {
         const NONE = 0;
@@ -42,6 +43,7 @@ bitflags! {
         const MAXIMIZED = WS_MAXIMIZE;
         const MINIMIZED = WS_MINIMIZE;
         const RESIZABLE = WS_THICKFRAME | WS_MAXIMIZEBOX;
+        const TAB_STOP = WS_TABSTOP;
     }
 }
 
@@ -65,6 +67,7 @@ bitflags! {
       * `position`: The default position of the window in the desktop
       * `icon`: The window icon
       * `parent`: Logical parent of the window, unlike children controls, this is NOT required.
+      * `double_buffered`: Paint the canvas in a back buffer. See `set_double_buffered`
 
     **Control events:**
       * `OnInit`: The window was created
@@ -98,125 +101,164 @@ impl ExternCanvas {
             flags: None,
             ex_flags: 0,
             icon: None,
+            double_buffered: false,
             parent: None
         }
     }
 
-    /// Invalidate the whole drawing region. For canvas that are children control, this should be called in the paint event.
-    pub fn invalidate(&self) {
-        use winapi::um::winuser::InvalidateRect;
-        use std::ptr;
+    try_accessors! {
+        /// Invalidate the whole drawing region. For canvas that are children control, this should be called in the paint event.
+        pub fn invalidate(&self) => try_invalidate {
+            use winapi::um::winuser::InvalidateRect;
+            use std::ptr;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { InvalidateRect(handle, ptr::null(), 1); }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { InvalidateRect(handle, ptr::null(), 1); }
+
+            Ok(())
+        }
 
-    /// Return the icon of the window
-    pub fn icon(&self) -> Option<Icon> {
-        use winapi::um::winuser::WM_GETICON;
-        use winapi::um::winnt::HANDLE;
+        /// Return true if the canvas is painted in a back buffer
+        pub fn double_buffered(&self) -> bool => try_double_buffered {
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(self.handle.double_buffered())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /**
+            Paint the canvas in a back buffer that has the size of the client area. In the `OnPaint` event, the
+            device context returned by `PaintData::paint` is the back buffer and the painted region is copied to the
+            canvas when the painting ends. This removes the flickering of the canvas that are redrawn often.
 
-        let icon_handle = wh::send_message(handle, WM_GETICON, 0, 0);
-        if icon_handle == 0 {
-            None
-        } else {
-            Some(Icon { handle: icon_handle as HANDLE, owned: false })
+            Canvas that are painted by an external API (ex: OpenGL) must not be double buffered.
+        */
+        pub fn set_double_buffered(&self, v: bool) => try_set_double_buffered {
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            self.handle.set_double_buffered(v);
+
+            Ok(())
         }
-    }
 
-    /// Set the icon in the window
-    /// - icon: The new icon. If None, the icon is removed
-    pub fn set_icon(&self, icon: Option<&Icon>) {
-        use winapi::um::winuser::WM_SETICON;
-        use std::{mem, ptr};
+        /// Return the icon of the window
+        pub fn icon(&self) -> Option<Icon> => try_icon {
+            use winapi::um::winuser::WM_GETICON;
+            use winapi::um::winnt::HANDLE;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let image_handle = icon.map(|i| i.handle).unwrap_or(ptr::null_mut());
-        unsafe {
-            wh::send_message(handle, WM_SETICON, 0, mem::transmute(image_handle));
+            let icon_handle = wh::send_message(handle, WM_GETICON, 0, 0);
+            Ok(if icon_handle == 0 {
+                None
+            } else {
+                Some(Icon { handle: icon_handle as HANDLE, owned: false })
+            })
         }
-    }
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+        /// Set the icon in the window
+        /// - icon: The new icon. If None, the icon is removed
+        pub fn set_icon(&self, icon: Option<&Icon>) => try_set_icon {
+            use winapi::um::winuser::WM_SETICON;
+            use std::{mem, ptr};
 
-    /// Set the keyboard focus on the button
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+            let image_handle = icon.map(|i| i.handle).unwrap_or(ptr::null_mut());
+            unsafe {
+                wh::send_message(handle, WM_SETICON, 0, mem::transmute(image_handle));
+            }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Set the keyboard focus on the button
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+            Ok(())
+        }
 
-    /// Return the physical size of canvas in pixels considering the dpi scale
-    pub fn physical_size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_physical_size(handle) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, true) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Return window title
-    pub fn text(&self) -> String { 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_text(handle) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
 
-    /// Set the window title
-    pub fn set_text<'a>(&self, v: &'a str) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Return the physical size of canvas in pixels considering the dpi scale
+        pub fn physical_size(&self) -> (u32, u32) => try_physical_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_physical_size(handle) })
+        }
+
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, true) }
+
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Return window title
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
+
+        /// Set the window title
+        pub fn set_text<'a>(&self, v: &'a str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, v) }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -242,26 +284,29 @@ impl Drop for ExternCanvas {
 }
 
 #[cfg(feature = "raw-win-handle")]
-use raw_window_handle::{HasRawWindowHandle, RawWindowHandle, windows::WindowsHandle};
+use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
 
 #[cfg(feature = "raw-win-handle")]
 unsafe impl HasRawWindowHandle for ExternCanvas {
     fn raw_window_handle(&self) -> RawWindowHandle {
-        use winapi::um::winuser::GWL_HINSTANCE;
+        self.handle.raw_window_handle()
+    }
+}
 
-        match self.handle {
-            ControlHandle::Hwnd(hwnd) => {
-                let hinstance = wh::get_window_long(hwnd, GWL_HINSTANCE);
+#[cfg(feature = "raw-win-handle-05")]
+use raw_window_handle_05::{HasRawDisplayHandle, RawDisplayHandle};
 
-                RawWindowHandle::Windows(WindowsHandle {
-                    hwnd: hwnd as _,
-                    hinstance: hinstance as _,
-                    ..WindowsHandle::empty()
-                })
-            }
-            // Not a valid window handle, so return an empty handle
-            _ => RawWindowHandle::Windows(WindowsHandle::empty())
-        }
+#[cfg(feature = "raw-win-handle-05")]
+unsafe impl raw_window_handle_05::HasRawWindowHandle for ExternCanvas {
+    fn raw_window_handle(&self) -> raw_window_handle_05::RawWindowHandle {
+        raw_window_handle_05::HasRawWindowHandle::raw_window_handle(&self.handle)
+    }
+}
+
+#[cfg(feature = "raw-win-handle-05")]
+unsafe impl HasRawDisplayHandle for ExternCanvas {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        self.handle.raw_display_handle()
     }
 }
 
@@ -272,6 +317,7 @@ pub struct ExternCanvasBuilder<'a> {
     flags: Option<ExternCanvasFlags>,
     ex_flags: u32,
     icon: Option<&'a Icon>,
+    double_buffered: bool,
     parent: Option<ControlHandle>
 }
 
@@ -307,6 +353,11 @@ impl<'a> ExternCanvasBuilder<'a> {
         self
     }
 
+    pub fn double_buffered(mut self, double_buffered: bool) -> ExternCanvasBuilder<'a> {
+        self.double_buffered = double_buffered;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: Option<C>) -> ExternCanvasBuilder<'a> {
         self.parent = p.map(|p2| p2.into());
         self
@@ -340,6 +391,10 @@ impl<'a> ExternCanvasBuilder<'a> {
             out.set_icon(self.icon);
         }
 
+        if self.double_buffered {
+            out.set_double_buffered(true);
+        }
+
         Ok(())
     }
 
@@ -109,15 +109,25 @@ use super::TrackBar;
 handles!(TrackBar);
 
 #[cfg(feature = "menu")]
-use super::{Menu, MenuItem, MenuSeparator};
+use super::{Menu, MenuBar, PopupMenu, MenuItem, MenuSeparator};
 
 #[cfg(feature = "menu")]
 handles!(Menu);
 #[cfg(feature = "menu")]
+handles!(MenuBar);
+#[cfg(feature = "menu")]
+handles!(PopupMenu);
+#[cfg(feature = "menu")]
 handles!(MenuItem);
 #[cfg(feature = "menu")]
 handles!(MenuSeparator);
 
+#[cfg(feature = "menu")]
+use super::RecentFilesMenu;
+
+#[cfg(feature = "menu")]
+handles!(RecentFilesMenu);
+
 #[cfg(feature = "combobox")]
 use super::ComboBox;
 
@@ -196,6 +206,12 @@ use super::TrayNotification;
 #[cfg(feature = "tray-notification")]
 handles!(TrayNotification);
 
+#[cfg(feature = "toast-notification")]
+use super::ToastNotification;
+
+#[cfg(feature = "toast-notification")]
+handles!(ToastNotification);
+
 #[cfg(feature = "message-window")]
 use super::MessageWindow;
 
@@ -270,3 +286,21 @@ use super::Plotters;
 
 #[cfg(feature = "plotting")]
 handles!(Plotters);
+
+#[cfg(feature = "d2d-canvas")]
+use super::D2DCanvas;
+
+#[cfg(feature = "d2d-canvas")]
+handles!(D2DCanvas);
+
+#[cfg(feature = "plot-canvas")]
+use super::PlotCanvas;
+
+#[cfg(feature = "plot-canvas")]
+handles!(PlotCanvas);
+
+#[cfg(feature = "media-player")]
+use super::MediaPlayer;
+
+#[cfg(feature = "media-player")]
+handles!(MediaPlayer);
@@ -1,14 +1,15 @@
 use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED};
 use winapi::um::wingdi::DeleteObject;
-use winapi::shared::windef::HBRUSH;
+use winapi::shared::windef::{HWND, HBRUSH, HBITMAP, HICON};
 use crate::win32::{
-    base_helper::check_hwnd,  
+    base_helper::try_check_hwnd,
     window_helper as wh,
     resources_helper as rh
 };
 use super::{ControlBase, ControlHandle};
 use crate::{Bitmap, Icon, NwgError, RawEventHandler, unbind_raw_event_handler};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
 const NOT_BOUND: &'static str = "ImageFrame is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: ImageFrame handle is not HWND!";
@@ -21,6 +22,28 @@ bitflags! {
     }
 }
 
+/**
+    How the image frame displays an image that does not have the size of the control
+
+    * Center: The image keeps its size and is centered in the frame. A larger image is clipped. This is the default.
+    * Stretch: The image is stretched to the size of the frame. The image might be distorted.
+    * Fit: The image is scaled to the largest size that fits in the frame without changing its aspect ratio, and centered.
+    * Tile: The image keeps its size and is repeated from the top left corner of the frame.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageFrameScaling {
+    Center,
+    Stretch,
+    Fit,
+    Tile,
+}
+
+impl Default for ImageFrameScaling {
+    fn default() -> ImageFrameScaling {
+        ImageFrameScaling::Center
+    }
+}
+
 /**
 An image frame is a control that displays a `Bitmap` or a `Icon` image resource.
 
@@ -35,6 +58,7 @@ ImageFrame is not behind any features.
   * `background_color`: The background color of the image frame. Used if the image is smaller than the control
   * `bitmap`:           A bitmap to display. If this value is set, icon is ignored.
   * `icon`:             An icon to display
+  * `scaling`:          How the image is displayed if it does not have the size of the image frame. Defaults to `ImageFrameScaling::Center`
 
 **Control events:**
   * `OnImageFrameClick`: When the image frame is clicked once by the user
@@ -57,6 +81,8 @@ pub struct ImageFrame {
     pub handle: ControlHandle,
     background_brush: Option<HBRUSH>,
     handler0: RefCell<Option<RawEventHandler>>,
+    handler1: RefCell<Option<RawEventHandler>>,
+    scaling: Rc<Cell<ImageFrameScaling>>,
 }
 
 impl ImageFrame {
@@ -70,110 +96,144 @@ impl ImageFrame {
             bitmap: None,
             icon: None,
             parent: None,
-            background_color: None
+            background_color: None,
+            scaling: ImageFrameScaling::Center,
         }
     }
 
-    /// Sets the bitmap image of the image frame. Replace the current bitmap or icon.
-    /// Set `image` to `None` to remove the image
-    pub fn set_bitmap<'a>(&self, image: Option<&'a Bitmap>) {
-        use winapi::um::winuser::{STM_SETIMAGE, IMAGE_BITMAP};
-        use winapi::shared::minwindef::{WPARAM, LPARAM};
+    try_accessors! {
+        /// Sets the bitmap image of the image frame. Replace the current bitmap or icon.
+        /// Set `image` to `None` to remove the image
+        pub fn set_bitmap<'a>(&self, image: Option<&'a Bitmap>) => try_set_bitmap {
+            use winapi::um::winuser::{STM_SETIMAGE, IMAGE_BITMAP};
+            use winapi::shared::minwindef::{WPARAM, LPARAM};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
+            let prev_img = wh::send_message(handle, STM_SETIMAGE, IMAGE_BITMAP as WPARAM, image_handle);
+            if prev_img != 0 {
+                unsafe { DeleteObject(prev_img as _); }
+            }
 
-        let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
-        let prev_img = wh::send_message(handle, STM_SETIMAGE, IMAGE_BITMAP as WPARAM, image_handle);
-        if prev_img != 0 {
-            unsafe { DeleteObject(prev_img as _); }
+            Ok(())
         }
-    }
 
-    /// Sets the bitmap image of the image frame. Replace the current bitmap or icon.
-    /// Set `image` to `None` to remove the image
-    pub fn set_icon<'a>(&self, image: Option<&'a Icon>) {
-        use winapi::um::winuser::{STM_SETIMAGE, IMAGE_ICON};
-        use winapi::shared::minwindef::{WPARAM, LPARAM};
+        /// Sets the bitmap image of the image frame. Replace the current bitmap or icon.
+        /// Set `image` to `None` to remove the image
+        pub fn set_icon<'a>(&self, image: Option<&'a Icon>) => try_set_icon {
+            use winapi::um::winuser::{STM_SETIMAGE, IMAGE_ICON};
+            use winapi::shared::minwindef::{WPARAM, LPARAM};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
-        let prev_img = wh::send_message(handle, STM_SETIMAGE, IMAGE_ICON as WPARAM, image_handle);
-        if prev_img != 0 {
-            unsafe { DeleteObject(prev_img as _); }
+            let image_handle = image.map(|i| i.handle as LPARAM).unwrap_or(0);
+            let prev_img = wh::send_message(handle, STM_SETIMAGE, IMAGE_ICON as WPARAM, image_handle);
+            if prev_img != 0 {
+                unsafe { DeleteObject(prev_img as _); }
+            }
+
+            Ok(())
         }
-    }
 
-    /// Returns the current image in the image frame.
-    /// If the image frame has a bitmap, the value will be returned in `bitmap`
-    /// If the image frame has a icon, the value will be returned in `icon`
-    pub fn image<'a>(&self, bitmap: &mut Option<Bitmap>, icon: &mut Option<Icon>) {
-        use winapi::um::winuser::{STM_GETIMAGE, IMAGE_BITMAP, IMAGE_ICON};
-        use winapi::shared::minwindef::WPARAM;
-        use winapi::shared::windef::HBITMAP;
-        use winapi::um::winnt::HANDLE;
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let bitmap_handle = wh::send_message(handle, STM_GETIMAGE, IMAGE_BITMAP as WPARAM, 0);
-        let icon_handle = wh::send_message(handle, STM_GETIMAGE, IMAGE_ICON as WPARAM, 0);
-
-        *bitmap = None;
-        *icon = None;
-
-        if bitmap_handle != 0 && rh::is_bitmap(bitmap_handle as HBITMAP) {
-            *bitmap = Some(Bitmap { handle: bitmap_handle as HANDLE, owned: false });
-        } else if icon_handle != 0 {
-            *icon = Some(Icon { handle: icon_handle as HANDLE, owned: false });
+        /// Returns the current image in the image frame.
+        /// If the image frame has a bitmap, the value will be returned in `bitmap`
+        /// If the image frame has a icon, the value will be returned in `icon`
+        pub fn image<'a>(&self, bitmap: &mut Option<Bitmap>, icon: &mut Option<Icon>) => try_image {
+            use winapi::um::winuser::{STM_GETIMAGE, IMAGE_BITMAP, IMAGE_ICON};
+            use winapi::shared::minwindef::WPARAM;
+            use winapi::um::winnt::HANDLE;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let bitmap_handle = wh::send_message(handle, STM_GETIMAGE, IMAGE_BITMAP as WPARAM, 0);
+            let icon_handle = wh::send_message(handle, STM_GETIMAGE, IMAGE_ICON as WPARAM, 0);
+
+            *bitmap = None;
+            *icon = None;
+
+            if bitmap_handle != 0 && rh::is_bitmap(bitmap_handle as HBITMAP) {
+                *bitmap = Some(Bitmap { handle: bitmap_handle as HANDLE, owned: false });
+            } else if icon_handle != 0 {
+                *icon = Some(Icon { handle: icon_handle as HANDLE, owned: false });
+            }
+
+            Ok(())
         }
-    }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+        /// Return how the image is displayed if it does not have the size of the image frame
+        pub fn scaling(&self) -> ImageFrameScaling => try_scaling {
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(self.scaling.get())
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Set how the image is displayed if it does not have the size of the image frame
+        pub fn set_scaling(&self, scaling: ImageFrameScaling) => try_set_scaling {
+            use winapi::um::winuser::InvalidateRect;
+            use std::ptr;
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            self.scaling.set(scaling);
+            unsafe { InvalidateRect(handle, ptr::null(), 1); }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Return the size of the image frame in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Set the size of the image frame in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Return the position of the image frame in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+            Ok(())
+        }
+
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the image frame in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Set the position of the image frame in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+        /// Set the size of the image frame in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
+        }
+
+        /// Return the position of the image frame in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the image frame in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -197,6 +257,7 @@ impl ImageFrame {
     /// Change the checkbox background color.
     fn hook_background_color(&mut self, c: [u8; 3]) {
         use crate::bind_raw_event_handler_inner;
+        use crate::win32::high_contrast::high_contrast;
         use winapi::um::winuser::{WM_CTLCOLORSTATIC};
         use winapi::shared::{basetsd::UINT_PTR, windef::{HWND}, minwindef::LRESULT};
         use winapi::um::wingdi::{CreateSolidBrush, RGB};
@@ -212,7 +273,7 @@ impl ImageFrame {
             match msg {
                 WM_CTLCOLORSTATIC => {
                     let child = l as HWND;
-                    if child == handle {
+                    if child == handle && !high_contrast() {
                         return Some(brush as LRESULT);
                     }
                 },
@@ -225,6 +286,161 @@ impl ImageFrame {
         *self.handler0.borrow_mut() = Some(handler.unwrap());
     }
 
+    /// Paint the image when it is scaled. The static control only knows how to center the image.
+    fn hook_scaling(&mut self) {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_PAINT, WM_ERASEBKGND, WM_SIZE, InvalidateRect};
+        use std::ptr;
+
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+
+        let scaling = self.scaling.clone();
+        let handler = bind_raw_event_handler_inner(&self.handle, 0, move |hwnd, msg, _w, _l| {
+            let scaling = scaling.get();
+            if scaling == ImageFrameScaling::Center {
+                return None;
+            }
+
+            match msg {
+                WM_PAINT => {
+                    unsafe { paint_scaled_image(hwnd, scaling); }
+                    Some(0)
+                },
+                WM_ERASEBKGND => Some(1),
+                WM_SIZE => {
+                    unsafe { InvalidateRect(hwnd, ptr::null(), 1); }
+                    None
+                },
+                _ => None
+            }
+        });
+
+        *self.handler1.borrow_mut() = Some(handler.unwrap());
+    }
+
+}
+
+/// Paint the background and the scaled image of an image frame
+unsafe fn paint_scaled_image(hwnd: HWND, scaling: ImageFrameScaling) {
+    use winapi::um::winuser::{STM_GETIMAGE, IMAGE_BITMAP, IMAGE_ICON, WM_CTLCOLORSTATIC, PAINTSTRUCT};
+    use winapi::um::winuser::{BeginPaint, EndPaint, GetClientRect, FillRect, SendMessageW, DrawIconEx};
+    use winapi::um::wingdi::{CreateCompatibleDC, DeleteDC, SelectObject, StretchBlt, SetStretchBltMode, SetBrushOrgEx, SRCCOPY, HALFTONE};
+    use winapi::shared::windef::RECT;
+    use std::{mem, ptr};
+
+    // Missing from winapi
+    const DI_NORMAL: u32 = 0x0003;
+
+    let mut paint: PAINTSTRUCT = mem::zeroed();
+    let dc = BeginPaint(hwnd, &mut paint);
+
+    let mut r: RECT = mem::zeroed();
+    GetClientRect(hwnd, &mut r);
+
+    // The parent returns the background brush, like when the static control paints itself
+    let brush = SendMessageW(wh::get_window_parent(hwnd), WM_CTLCOLORSTATIC, dc as _, hwnd as _);
+    FillRect(dc, &r, brush as HBRUSH);
+
+    let bitmap = SendMessageW(hwnd, STM_GETIMAGE, IMAGE_BITMAP as _, 0) as HBITMAP;
+    let icon = SendMessageW(hwnd, STM_GETIMAGE, IMAGE_ICON as _, 0) as HICON;
+
+    let image = if !bitmap.is_null() && rh::is_bitmap(bitmap) {
+        Some((bitmap_size(bitmap), true))
+    } else if !icon.is_null() {
+        Some((icon_size(icon), false))
+    } else {
+        None
+    };
+
+    if let Some(([width, height], is_bitmap)) = image {
+        let targets = scaled_rects(scaling, [r.right, r.bottom], [width, height]);
+
+        if is_bitmap {
+            let src = CreateCompatibleDC(dc);
+            let old = SelectObject(src, bitmap as _);
+            let old_mode = SetStretchBltMode(dc, HALFTONE as _);
+            SetBrushOrgEx(dc, 0, 0, ptr::null_mut());
+
+            for [x, y, w, h] in targets {
+                StretchBlt(dc, x, y, w, h, src, 0, 0, width, height, SRCCOPY);
+            }
+
+            SetStretchBltMode(dc, old_mode);
+            SelectObject(src, old);
+            DeleteDC(src);
+        } else {
+            for [x, y, w, h] in targets {
+                DrawIconEx(dc, x, y, icon, w, h, 0, ptr::null_mut(), DI_NORMAL);
+            }
+        }
+    }
+
+    EndPaint(hwnd, &paint);
+}
+
+/// Return the `[x, y, width, height]` rectangles where the image is drawn in a frame of size `frame`
+fn scaled_rects(scaling: ImageFrameScaling, frame: [i32; 2], image: [i32; 2]) -> Vec<[i32; 4]> {
+    let [frame_width, frame_height] = frame;
+    let [width, height] = image;
+    if width <= 0 || height <= 0 {
+        return Vec::new();
+    }
+
+    match scaling {
+        ImageFrameScaling::Center => {
+            vec![[(frame_width - width) / 2, (frame_height - height) / 2, width, height]]
+        },
+        ImageFrameScaling::Stretch => {
+            vec![[0, 0, frame_width, frame_height]]
+        },
+        ImageFrameScaling::Fit => {
+            let scale = f64::min(frame_width as f64 / width as f64, frame_height as f64 / height as f64);
+            let w = (width as f64 * scale).round() as i32;
+            let h = (height as f64 * scale).round() as i32;
+            vec![[(frame_width - w) / 2, (frame_height - h) / 2, w, h]]
+        },
+        ImageFrameScaling::Tile => {
+            let mut rects = Vec::new();
+            for y in (0..frame_height).step_by(height as usize) {
+                for x in (0..frame_width).step_by(width as usize) {
+                    rects.push([x, y, width, height]);
+                }
+            }
+            rects
+        }
+    }
+}
+
+unsafe fn bitmap_size(bitmap: HBITMAP) -> [i32; 2] {
+    use winapi::um::wingdi::{GetObjectW, BITMAP};
+    use std::mem;
+
+    let mut info: BITMAP = mem::zeroed();
+    GetObjectW(bitmap as _, mem::size_of::<BITMAP>() as _, &mut info as *mut BITMAP as _);
+
+    [info.bmWidth, info.bmHeight]
+}
+
+unsafe fn icon_size(icon: HICON) -> [i32; 2] {
+    use winapi::um::winuser::{GetIconInfo, ICONINFO};
+    use std::mem;
+
+    let mut info: ICONINFO = mem::zeroed();
+    if GetIconInfo(icon, &mut info) == 0 {
+        return [0, 0];
+    }
+
+    // Monochrome icons store both the AND and the XOR mask in `hbmMask`, so the image is twice as high
+    let has_color = !info.hbmColor.is_null();
+    let [width, height] = bitmap_size(if has_color { info.hbmColor } else { info.hbmMask });
+
+    if has_color { DeleteObject(info.hbmColor as _); }
+    if !info.hbmMask.is_null() { DeleteObject(info.hbmMask as _); }
+
+    match has_color {
+        true => [width, height],
+        false => [width, height / 2]
+    }
 }
 
 impl Drop for ImageFrame {
@@ -234,6 +450,11 @@ impl Drop for ImageFrame {
             drop(unbind_raw_event_handler(h));
         }
 
+        let handler = self.handler1.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
         if let Some(bg) = self.background_brush {
             unsafe { DeleteObject(bg as _); }
         }
@@ -251,6 +472,7 @@ pub struct ImageFrameBuilder<'a> {
     icon: Option<&'a Icon>,
     parent: Option<ControlHandle>,
     background_color: Option<[u8; 3]>,
+    scaling: ImageFrameScaling,
 }
 
 impl<'a> ImageFrameBuilder<'a> {
@@ -295,6 +517,11 @@ impl<'a> ImageFrameBuilder<'a> {
         self
     }
 
+    pub fn scaling(mut self, scaling: ImageFrameScaling) -> ImageFrameBuilder<'a> {
+        self.scaling = scaling;
+        self
+    }
+
     pub fn build(self, out: &mut ImageFrame) -> Result<(), NwgError> {
         use winapi::um::winuser::{SS_BITMAP, SS_ICON};
 
@@ -332,6 +559,9 @@ impl<'a> ImageFrameBuilder<'a> {
             out.hook_background_color(self.background_color.unwrap());
         }
 
+        out.scaling.set(self.scaling);
+        out.hook_scaling();
+
         Ok(())
     }
 
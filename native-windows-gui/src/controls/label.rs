@@ -1,14 +1,15 @@
 use winapi::um::{
-    winuser::{WS_VISIBLE, WS_DISABLED, SS_WORDELLIPSIS},
+    winuser::{WS_VISIBLE, WS_DISABLED, SS_WORDELLIPSIS, SS_ENDELLIPSIS, SS_PATHELLIPSIS, SS_NOPREFIX, SS_LEFTNOWORDWRAP},
     wingdi::DeleteObject
 };
 
-use winapi::shared::windef::HBRUSH;
+use winapi::shared::windef::{HWND, HBRUSH};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::check_hwnd;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::{Font, NwgError, HTextAlign, VTextAlign, RawEventHandler, unbind_raw_event_handler};
 use super::{ControlBase, ControlHandle};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
 const NOT_BOUND: &'static str = "Label is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: Label handle is not HWND!";
@@ -20,6 +21,8 @@ bitflags! {
         * NONE:     No flags. Equivalent to a invisible blank label.
         * VISIBLE:  The label is immediatly visible after creation
         * DISABLED: The label cannot be interacted with by the user. It also has a grayed out look.
+        * NO_PREFIX: The `&` characters are displayed instead of underlining the next character
+        * NO_WORD_WRAP: The lines that are too long are clipped instead of wrapped. Ignored if the label is not aligned on the left.
     */
     pub struct LabelFlags: u32 {
         const NONE = 0;
@@ -28,9 +31,34 @@ bitflags! {
 
         /// Truncate the label if the text is too long. A label with this style CANNOT have multiple lines.
         const ELIPSIS = SS_WORDELLIPSIS;
+
+        /// Replace the end of the text with an ellipsis if the text is too long. A label with this style CANNOT have multiple lines.
+        const END_ELLIPSIS = SS_ENDELLIPSIS;
+
+        /// Replace the middle of the text with an ellipsis, keeping the file name of a path. A label with this style CANNOT have multiple lines.
+        const PATH_ELLIPSIS = SS_PATHELLIPSIS;
+
+        const NO_PREFIX = SS_NOPREFIX;
+        const NO_WORD_WRAP = SS_LEFTNOWORDWRAP;
     }
 }
 
+/**
+    How a label truncates a text that is too long
+
+    * None: The text is clipped
+    * End: The end of the text is replaced with an ellipsis
+    * Path: The middle of the text is replaced with an ellipsis. The text after the last backslash is kept.
+    * Word: The text is truncated after the last word that fits and an ellipsis is added
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LabelEllipsis {
+    None,
+    End,
+    Path,
+    Word,
+}
+
 /**
 A label is a single line of static text. Use `\r\n` to split the text on multiple lines.
 
@@ -47,6 +75,7 @@ Label is not behind any features.
   * `font`:             The font used for the label text
   * `background_color`: The background color of the label
   * `h_align`:          The horizontal aligment of the label
+  * `v_align`:          The vertical aligment of the label
 
 **Control events:**
   * `OnLabelClick`: When the user click the label
@@ -76,6 +105,7 @@ pub struct Label {
     background_brush: Option<HBRUSH>,
     handler0: RefCell<Option<RawEventHandler>>,
     handler1: RefCell<Option<RawEventHandler>>,
+    v_align: Rc<Cell<VTextAlign>>,
 }
 
 impl Label {
@@ -95,95 +125,232 @@ impl Label {
         }
     }
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+    try_accessors! {
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
         }
-    }
 
-    /// Set the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Set the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+        /// Set the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Return the size of the label in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the size of the label in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Return the position of the label in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
 
-    /// Set the position of the label in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
-    }
+            Ok(())
+        }
 
-    /// Return the label text
-    pub fn text(&self) -> String { 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_text(handle) }
-    }
+        /// Return the size of the label in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Set the size of the label in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
 
-    /// Set the label text
-    pub fn set_text<'a>(&self, v: &'a str) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
+            Ok(())
+        }
+
+        /// Return the position of the label in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the label in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Return the label text
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
+
+        /// Set the label text
+        pub fn set_text<'a>(&self, v: &'a str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the horizontal alignment of the label text
+        pub fn h_align(&self) -> HTextAlign => try_h_align {
+            use winapi::um::winuser::{SS_TYPEMASK, SS_RIGHT, SS_CENTER};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(match wh::get_style(handle) & SS_TYPEMASK {
+                SS_RIGHT => HTextAlign::Right,
+                SS_CENTER => HTextAlign::Center,
+                _ => HTextAlign::Left
+            })
+        }
+
+        /// Set the horizontal alignment of the label text. Centered and right aligned labels always wrap their text.
+        pub fn set_h_align(&self, align: HTextAlign) => try_set_h_align {
+            use winapi::um::winuser::{SS_TYPEMASK, SS_LEFT, SS_RIGHT, SS_CENTER};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let no_wrap = wh::get_style(handle) & SS_TYPEMASK == SS_LEFTNOWORDWRAP;
+
+            let align = match align {
+                HTextAlign::Left if no_wrap => SS_LEFTNOWORDWRAP,
+                HTextAlign::Left => SS_LEFT,
+                HTextAlign::Right => SS_RIGHT,
+                HTextAlign::Center => SS_CENTER,
+            };
+
+            set_label_style(handle, SS_TYPEMASK, align);
+
+            Ok(())
+        }
+
+        /// Return the vertical alignment of the label text
+        pub fn v_align(&self) -> VTextAlign => try_v_align {
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(self.v_align.get())
+        }
+
+        /// Set the vertical alignment of the label text
+        pub fn set_v_align(&self, align: VTextAlign) => try_set_v_align {
+            use winapi::um::winuser::{SetWindowPos, SWP_NOOWNERZORDER, SWP_NOZORDER, SWP_NOSIZE, SWP_NOMOVE, SWP_FRAMECHANGED};
+            use std::ptr;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            self.v_align.set(align);
+
+            // The text is aligned by the non client area, which is computed again when the frame changes
+            unsafe { SetWindowPos(handle, ptr::null_mut(), 0, 0, 0, 0, SWP_NOOWNERZORDER | SWP_NOZORDER | SWP_NOSIZE | SWP_NOMOVE | SWP_FRAMECHANGED); }
+
+            Ok(())
+        }
+
+        /// Return true if the lines that are too long are wrapped
+        pub fn word_wrap(&self) -> bool => try_word_wrap {
+            use winapi::um::winuser::SS_TYPEMASK;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::get_style(handle) & SS_TYPEMASK != SS_LEFTNOWORDWRAP)
+        }
+
+        /// Wrap or clip the lines that are too long. Only a label aligned on the left can clip its text,
+        /// so disabling the word wrap also aligns the text on the left.
+        pub fn set_word_wrap(&self, wrap: bool) => try_set_word_wrap {
+            use winapi::um::winuser::{SS_TYPEMASK, SS_LEFT};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let style = wh::get_style(handle) & SS_TYPEMASK;
+
+            match (wrap, style) {
+                (true, SS_LEFTNOWORDWRAP) => set_label_style(handle, SS_TYPEMASK, SS_LEFT),
+                (false, _) => set_label_style(handle, SS_TYPEMASK, SS_LEFTNOWORDWRAP),
+                _ => {}
+            }
+
+            Ok(())
+        }
+
+        /// Return how the label truncates a text that is too long
+        pub fn ellipsis(&self) -> LabelEllipsis => try_ellipsis {
+            use winapi::um::winuser::SS_ELLIPSISMASK;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(match wh::get_style(handle) & SS_ELLIPSISMASK {
+                SS_ENDELLIPSIS => LabelEllipsis::End,
+                SS_PATHELLIPSIS => LabelEllipsis::Path,
+                SS_WORDELLIPSIS => LabelEllipsis::Word,
+                _ => LabelEllipsis::None
+            })
+        }
+
+        /// Set how the label truncates a text that is too long. A label with an ellipsis CANNOT have multiple lines.
+        pub fn set_ellipsis(&self, ellipsis: LabelEllipsis) => try_set_ellipsis {
+            use winapi::um::winuser::SS_ELLIPSISMASK;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let ellipsis = match ellipsis {
+                LabelEllipsis::None => 0,
+                LabelEllipsis::End => SS_ENDELLIPSIS,
+                LabelEllipsis::Path => SS_PATHELLIPSIS,
+                LabelEllipsis::Word => SS_WORDELLIPSIS,
+            };
+
+            set_label_style(handle, SS_ELLIPSISMASK, ellipsis);
+
+            Ok(())
+        }
+
+        /// Return true if the `&` characters underline the next character of the text
+        pub fn prefix(&self) -> bool => try_prefix {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::get_style(handle) & SS_NOPREFIX == 0)
+        }
+
+        /// If `true`, the `&` characters underline the next character of the text (ex: `&File`) and `&&` displays a `&`.
+        /// If `false`, the `&` characters are displayed as is. Labels are created without the prefix by default.
+        pub fn set_prefix(&self, prefix: bool) => try_set_prefix {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            set_label_style(handle, SS_NOPREFIX, if prefix { 0 } else { SS_NOPREFIX });
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -193,7 +360,7 @@ impl Label {
 
     /// Winapi base flags used during window creation
     pub fn flags(&self) -> u32 {
-        use winapi::um::winuser::{SS_NOPREFIX, SS_LEFT};
+        use winapi::um::winuser::SS_LEFT;
 
         WS_VISIBLE | SS_NOPREFIX | SS_LEFT
     }
@@ -208,7 +375,8 @@ impl Label {
     /// Center the text vertically.
     fn hook_non_client_size(&mut self, bg: Option<[u8; 3]>, v_align: VTextAlign) {
         use crate::bind_raw_event_handler_inner;
-        use winapi::shared::windef::{HWND, HGDIOBJ, RECT, POINT};
+        use crate::win32::high_contrast::{high_contrast, background_brush};
+        use winapi::shared::windef::{HGDIOBJ, RECT, POINT};
         use winapi::shared::{basetsd::UINT_PTR, minwindef::LRESULT};
         use winapi::um::winuser::{WM_CTLCOLORSTATIC, WM_NCCALCSIZE, WM_NCPAINT, WM_SIZE, DT_CALCRECT, DT_LEFT, NCCALCSIZE_PARAMS, COLOR_WINDOW};
         use winapi::um::winuser::{SWP_NOOWNERZORDER, SWP_NOSIZE, SWP_NOMOVE, SWP_FRAMECHANGED};
@@ -237,7 +405,7 @@ impl Label {
                 match msg {
                     WM_CTLCOLORSTATIC => {
                         let child = l as HWND;
-                        if child == handle {
+                        if child == handle && !high_contrast() {
                             return Some(brush as LRESULT);
                         }
                     },
@@ -250,6 +418,9 @@ impl Label {
             *self.handler0.borrow_mut() = Some(handler0.unwrap());
         }
 
+        self.v_align.set(v_align);
+        let v_align = self.v_align.clone();
+
         let handler1 = bind_raw_event_handler_inner(&self.handle, 0, move |hwnd, msg, w, l| {
             match msg {
                 WM_NCCALCSIZE  => {
@@ -299,7 +470,7 @@ impl Label {
                     let window_height = window.bottom - window.top;
                     let info_ptr: *mut NCCALCSIZE_PARAMS = l as *mut NCCALCSIZE_PARAMS;
                     let info = &mut *info_ptr;
-                    match v_align {
+                    match v_align.get() {
                         VTextAlign::Top => {
                             info.rgrc[0].bottom -= window_height - client_height;
                         },
@@ -340,6 +511,7 @@ impl Label {
                     };
 
                     let dc = GetDC(hwnd);
+                    let brush = background_brush(brush, COLOR_WINDOW);
                     FillRect(dc, &top, brush);
                     FillRect(dc, &bottom, brush);
                     ReleaseDC(hwnd, dc);
@@ -360,6 +532,16 @@ impl Label {
 
 }
 
+/// Replace the `mask` bits of the label style and redraw the label
+fn set_label_style(handle: HWND, mask: u32, value: u32) {
+    use winapi::um::winuser::InvalidateRect;
+    use std::ptr;
+
+    let style = wh::get_style(handle);
+    wh::set_style(handle, (style & !mask) | value);
+    unsafe { InvalidateRect(handle, ptr::null(), 1); }
+}
+
 impl PartialEq for Label {
     fn eq(&self, other: &Self) -> bool {
         self.handle == other.handle
@@ -453,11 +635,16 @@ impl<'a> LabelBuilder<'a> {
     }
 
     pub fn build(self, out: &mut Label) -> Result<(), NwgError> {
-        use winapi::um::winuser::{SS_LEFT, SS_RIGHT, SS_CENTER};
+        use winapi::um::winuser::{SS_TYPEMASK, SS_LEFT, SS_RIGHT, SS_CENTER};
 
         let mut flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
 
+        // `SS_LEFTNOWORDWRAP` is a type of static control like the alignments, so the values cannot be combined
+        let no_wrap = flags & SS_TYPEMASK == SS_LEFTNOWORDWRAP;
+        flags &= !SS_TYPEMASK;
+
         match self.h_align {
+            HTextAlign::Left if no_wrap => { flags |= SS_LEFTNOWORDWRAP; },
             HTextAlign::Left => { flags |= SS_LEFT; },
             HTextAlign::Right => { flags |= SS_RIGHT; },
             HTextAlign::Center => { flags |= SS_CENTER; },
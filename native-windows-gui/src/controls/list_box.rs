@@ -1,8 +1,8 @@
 use winapi::shared::windef::HWND;
 use winapi::shared::minwindef::{WPARAM, LPARAM};
-use winapi::um::winuser::{LBS_MULTIPLESEL, LBS_NOSEL, WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
+use winapi::um::winuser::{LBS_MULTIPLESEL, LBS_EXTENDEDSEL, LBS_NOSEL, LBS_OWNERDRAWVARIABLE, WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::{to_utf16, from_utf16, check_hwnd};
+use crate::win32::base_helper::{to_utf16, with_utf16, from_utf16, try_check_hwnd};
 use crate::{Font, NwgError};
 use super::{ControlBase, ControlHandle};
 use std::cell::{Ref, RefMut, RefCell};
@@ -21,17 +21,21 @@ bitflags! {
         * NONE:     No flags. Equivalent to a invisible listbox.
         * VISIBLE:  The listbox is immediatly visible after creation
         * DISABLED: The listbox cannot be interacted with by the user. It also has a grayed out look.
-        * MULTI_SELECT: It is possible for the user to select more than 1 item at a time
+        * MULTI_SELECT: It is possible for the user to select more than 1 item at a time. A single click toggles an item.
+        * EXTENDED_SELECT: It is possible for the user to select more than 1 item at a time using the SHIFT/CTRL keys and the mouse.
         * NO_SELECT: It is impossible for the user to select the listbox items
         * TAB_STOP: The control can be selected using tab navigation
+        * OWNER_DRAW: The items are painted by the application in the `OnDrawItem` event. The height of each item can be set in the `OnMeasureItem` event.
     */
     pub struct ListBoxFlags: u32 {
         const NONE = 0;
         const VISIBLE = WS_VISIBLE;
         const DISABLED = WS_DISABLED;
         const MULTI_SELECT = LBS_MULTIPLESEL;
+        const EXTENDED_SELECT = LBS_EXTENDEDSEL;
         const NO_SELECT = LBS_NOSEL;
         const TAB_STOP = WS_TABSTOP;
+        const OWNER_DRAW = LBS_OWNERDRAWVARIABLE;
     }
 }
 
@@ -51,11 +55,14 @@ Requires the `list-box` feature.
   * `font`:            The font used for the listbox text
   * `collection`:      The default collections of the listbox
   * `selected_index`:  The default selected index in the listbox collection
-  * `multi_selection`: The collections of indices to set as selected in a multi selection listbox 
+  * `multi_selection`: The collections of indices to set as selected in a multi selection (or extended selection) listbox 
 
 **Control events:**
   * `OnListBoxSelect`: When the current listbox selection is changed
+  * `OnListBoxSelectionChanged`: When the listbox selection is changed. The event data holds every selected index.
   * `OnListBoxDoubleClick`: When a listbox item is clicked twice rapidly
+  * `OnMeasureItem`: When an item is added to an owner drawn listbox. Sent to the listbox handle.
+  * `OnDrawItem`: When an item of an owner drawn listbox must be painted. Sent to the listbox handle.
   * `MousePress(_)`: Generic mouse press events on the listbox
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -98,262 +105,397 @@ impl<D: Display+Default> ListBox<D> {
         }
     }
 
-    /// Add a new item to the listbox. Sort the collection if the listbox is sorted.
-    pub fn push(&self, item: D) {
-        use winapi::um::winuser::LB_ADDSTRING;
+    try_accessors! {
+        /// Add a new item to the listbox. Sort the collection if the listbox is sorted.
+        pub fn push(&self, item: D) => try_push {
+            use winapi::um::winuser::LB_ADDSTRING;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let display = format!("{}", item);
+            with_utf16(&display, |text| wh::send_message(handle, LB_ADDSTRING, 0, text.as_ptr() as LPARAM));
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let display = format!("{}", item);
-        let display_os = to_utf16(&display);
+            self.collection.borrow_mut().push(item);
 
-        unsafe {
-            wh::send_message(handle, LB_ADDSTRING, 0, mem::transmute(display_os.as_ptr()));
+            Ok(())
         }
 
-        self.collection.borrow_mut().push(item);
-    }
+        /// Add many items to the listbox. The control is not redrawn until all the items are added.
+        /// Sort the collection if the listbox is sorted.
+        pub fn push_all(&self, items: Vec<D>) => try_push_all {
+            use winapi::um::winuser::{LB_ADDSTRING, LB_INITSTORAGE};
 
-    /// Insert an item in the collection and the control. 
-    ///
-    /// SPECIAL behaviour! If index is `std::usize::MAX`, the item is added at the end of the collection.
-    /// The method will still panic if `index > len` with every other values.
-    pub fn insert(&self, index: usize, item: D) {
-        use winapi::um::winuser::LB_INSERTSTRING;
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let display = format!("{}", item);
-        let display_os = to_utf16(&display);
-
-        let mut col = self.collection.borrow_mut();
-        if index == std::usize::MAX {
-            col.push(item);
-        } else {
-            col.insert(index, item);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let _freeze = self.handle.freeze_guard();
+
+            let display: Vec<Vec<u16>> = items.iter().map(|item| to_utf16(&format!("{}", item))).collect();
+            let text_size: usize = display.iter().map(|d| d.len() * mem::size_of::<u16>()).sum();
+            wh::send_message(handle, LB_INITSTORAGE, items.len(), text_size as LPARAM);
+
+            for display_os in display.iter() {
+                wh::send_message(handle, LB_ADDSTRING, 0, display_os.as_ptr() as LPARAM);
+            }
+
+            self.collection.borrow_mut().extend(items);
+
+            Ok(())
         }
 
-        unsafe {
-            wh::send_message(handle, LB_INSERTSTRING, index, mem::transmute(display_os.as_ptr()));
+        /// Insert an item in the collection and the control. 
+        ///
+        /// SPECIAL behaviour! If index is `std::usize::MAX`, the item is added at the end of the collection.
+        /// The method will still panic if `index > len` with every other values.
+        pub fn insert(&self, index: usize, item: D) => try_insert {
+            use winapi::um::winuser::LB_INSERTSTRING;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let display = format!("{}", item);
+            let display_os = to_utf16(&display);
+
+            let mut col = self.collection.borrow_mut();
+            if index == std::usize::MAX {
+                col.push(item);
+            } else {
+                col.insert(index, item);
+            }
+
+            unsafe {
+                wh::send_message(handle, LB_INSERTSTRING, index, mem::transmute(display_os.as_ptr()));
+            }
+
+            Ok(())
         }
-    }
 
+        /// Remove the item at the selected index and returns it.
+        /// Panic of the index is out of bounds
+        pub fn remove(&self, index: usize) -> D => try_remove {
+            use winapi::um::winuser::LB_DELETESTRING;
 
-    /// Remove the item at the selected index and returns it.
-    /// Panic of the index is out of bounds
-    pub fn remove(&self, index: usize) -> D {
-        use winapi::um::winuser::LB_DELETESTRING;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LB_DELETESTRING, index as WPARAM, 0);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LB_DELETESTRING, index as WPARAM, 0);
+            let mut col_ref = self.collection.borrow_mut();
+            Ok(col_ref.remove(index))
+        }
 
-        let mut col_ref = self.collection.borrow_mut();
-        col_ref.remove(index)
-    }
+        /// Return the index of the currencty selected item for single value list box.
+        /// Return `None` if no item is selected.
+        pub fn selection(&self) -> Option<usize> => try_selection {
+            use winapi::um::winuser::{LB_GETCURSEL, LB_ERR};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let index = wh::send_message(handle, LB_GETCURSEL , 0, 0);
 
-    /// Return the index of the currencty selected item for single value list box.
-    /// Return `None` if no item is selected.
-    pub fn selection(&self) -> Option<usize> {
-        use winapi::um::winuser::{LB_GETCURSEL, LB_ERR};
+            Ok(if index == LB_ERR { None }
+            else { Some(index as usize) })
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let index = wh::send_message(handle, LB_GETCURSEL , 0, 0);
+        /// Return the number of selected item in the list box
+        /// Returns 0 for single select list box
+        pub fn multi_selection_len(&self) -> usize => try_multi_selection_len {
+            use winapi::um::winuser::{LB_GETSELCOUNT, LB_ERR};
 
-        if index == LB_ERR { None }
-        else { Some(index as usize) }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(match wh::send_message(handle, LB_GETSELCOUNT, 0, 0) {
+                LB_ERR => 0,
+                value => value as usize
+            })
+        }
+
+        /// Return a list index
+        /// Returns an empty vector for single select list box.
+        pub fn multi_selection(&self) -> Vec<usize> => try_multi_selection {
+            use winapi::um::winuser::{LB_GETSELCOUNT, LB_GETSELITEMS, LB_ERR};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let select_count = match wh::send_message(handle, LB_GETSELCOUNT, 0, 0) {
+                LB_ERR => usize::max_value(),
+                value => value as usize
+            };
 
-    /// Return the number of selected item in the list box
-    /// Returns 0 for single select list box
-    pub fn multi_selection_len(&self) -> usize {
-        use winapi::um::winuser::{LB_GETSELCOUNT, LB_ERR};
+            if select_count == usize::max_value() || usize::max_value() == 0 {
+                return Ok(Vec::new());
+            }
+
+            let mut indices_buffer: Vec<u32> = Vec::with_capacity(select_count);
+            unsafe { indices_buffer.set_len(select_count) };
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        match wh::send_message(handle, LB_GETSELCOUNT, 0, 0) {
-            LB_ERR => 0,
-            value => value as usize
+            wh::send_message(
+                handle,
+                LB_GETSELITEMS,
+                select_count as WPARAM,
+                indices_buffer.as_mut_ptr() as LPARAM
+            );
+
+            Ok(indices_buffer.into_iter().map(|i| i as usize).collect())
         }
     }
 
-    /// Return a list index
-    /// Returns an empty vector for single select list box.
-    pub fn multi_selection(&self) -> Vec<usize> {
-        use winapi::um::winuser::{LB_GETSELCOUNT, LB_GETSELITEMS, LB_ERR};
+    /// Return the indices of every selected item, for any kind of list box.
+    /// For a single select list box, the returned vector holds at most one value.
+    pub fn selected_indices(&self) -> Vec<usize> {
+        match self.multi_select() {
+            true => self.multi_selection(),
+            false => self.selection().into_iter().collect()
+        }
+    }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let select_count = match wh::send_message(handle, LB_GETSELCOUNT, 0, 0) {
-            LB_ERR => usize::max_value(),
-            value => value as usize
-        };
+    try_accessors! {
+        /// Return `true` if the list box allows the user to select more than one item
+        /// (ie: if it has the `MULTI_SELECT` or the `EXTENDED_SELECT` flag)
+        pub fn multi_select(&self) -> bool => try_multi_select {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let style = wh::get_style(handle);
+            Ok(style & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) != 0)
+        }
 
-        if select_count == usize::max_value() || usize::max_value() == 0 {
-            return Vec::new();
+        /// Return the display value of the currenctly selected item for single value
+        /// Return `None` if no item is selected. This reads the visual value.
+        pub fn selection_string(&self) -> Option<String> => try_selection_string {
+            use winapi::um::winuser::{LB_GETCURSEL, LB_GETTEXTLEN, LB_GETTEXT, LB_ERR};
+            use winapi::shared::ntdef::WCHAR;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let index = wh::send_message(handle, LB_GETCURSEL, 0, 0);
+
+            Ok(if index == LB_ERR { None }
+            else {
+                let index = index as usize;
+                let length = (wh::send_message(handle, LB_GETTEXTLEN, index, 0) as usize) + 1;  // +1 for the terminating null character
+                let mut buffer: Vec<WCHAR> = Vec::with_capacity(length);
+                unsafe { 
+                    buffer.set_len(length); 
+                    wh::send_message(handle, LB_GETTEXT, index, mem::transmute(buffer.as_ptr()));
+                }
+
+                Some(from_utf16(&buffer))
+            })
         }
 
-        let mut indices_buffer: Vec<u32> = Vec::with_capacity(select_count);
-        unsafe { indices_buffer.set_len(select_count) };
+        /// Set the currently selected item in the list box for single value list box.
+        /// Does nothing if the index is out of bound
+        /// If the value is None, remove the selected value
+        pub fn set_selection(&self, index: Option<usize>) => try_set_selection {
+            use winapi::um::winuser::LB_SETCURSEL;
 
-        wh::send_message(
-            handle,
-            LB_GETSELITEMS,
-            select_count as WPARAM,
-            indices_buffer.as_mut_ptr() as LPARAM
-        );
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let index = index.unwrap_or(-1isize as usize);
+            wh::send_message(handle, LB_SETCURSEL, index, 0);
 
-        indices_buffer.into_iter().map(|i| i as usize).collect()
-    }
+            Ok(())
+        }
 
-    /// Return the display value of the currenctly selected item for single value
-    /// Return `None` if no item is selected. This reads the visual value.
-    pub fn selection_string(&self) -> Option<String> {
-        use winapi::um::winuser::{LB_GETCURSEL, LB_GETTEXTLEN, LB_GETTEXT, LB_ERR};
-        use winapi::shared::ntdef::WCHAR;
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let index = wh::send_message(handle, LB_GETCURSEL, 0, 0);
-
-        if index == LB_ERR { None }
-        else {
-            let index = index as usize;
-            let length = (wh::send_message(handle, LB_GETTEXTLEN, index, 0) as usize) + 1;  // +1 for the terminating null character
-            let mut buffer: Vec<WCHAR> = Vec::with_capacity(length);
-            unsafe { 
-                buffer.set_len(length); 
-                wh::send_message(handle, LB_GETTEXT, index, mem::transmute(buffer.as_ptr()));
-            }
+        /// Select the item as index `index` in a multi item list box
+        pub fn multi_add_selection(&self, index: usize) => try_multi_add_selection {
+            use winapi::um::winuser::LB_SETSEL;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LB_SETSEL, 1, index as LPARAM);
 
-            Some(from_utf16(&buffer))
+            Ok(())
         }
-    }
 
-    /// Set the currently selected item in the list box for single value list box.
-    /// Does nothing if the index is out of bound
-    /// If the value is None, remove the selected value
-    pub fn set_selection(&self, index: Option<usize>) {
-        use winapi::um::winuser::LB_SETCURSEL;
+        /// Unselect the item as index `index` in a multi item list box
+        pub fn multi_remove_selection(&self, index: usize) => try_multi_remove_selection {
+            use winapi::um::winuser::LB_SETSEL;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LB_SETSEL, 0, index as LPARAM);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let index = index.unwrap_or(-1isize as usize);
-        wh::send_message(handle, LB_SETCURSEL, index, 0);
-    }
+            Ok(())
+        }
 
-    /// Select the item as index `index` in a multi item list box
-    pub fn multi_add_selection(&self, index: usize) {
-        use winapi::um::winuser::LB_SETSEL;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LB_SETSEL, 1, index as LPARAM);
-    }
+        /// Unselect every item in the list box
+        pub fn unselect_all(&self) => try_unselect_all {
+            use winapi::um::winuser::LB_SETSEL;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LB_SETSEL, 0, -1);
 
-    /// Unselect the item as index `index` in a multi item list box
-    pub fn multi_remove_selection(&self, index: usize) {
-        use winapi::um::winuser::LB_SETSEL;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LB_SETSEL, 0, index as LPARAM);
-    }
+            Ok(())
+        }
 
-    /// Unselect every item in the list box
-    pub fn unselect_all(&self) {
-        use winapi::um::winuser::LB_SETSEL;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LB_SETSEL, 0, -1);
-    }
+        /// Select every item in the list box
+        pub fn select_all(&self) => try_select_all {
+            use winapi::um::winuser::LB_SETSEL;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LB_SETSEL, 1, -1);
 
-    /// Select every item in the list box
-    pub fn select_all(&self) {
-        use winapi::um::winuser::LB_SETSEL;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LB_SETSEL, 1, -1);
-    }
+            Ok(())
+        }
 
-    /// Select a range of items in a multi list box
-    pub fn multi_select_range(&self, range: Range<usize>) {
-        use winapi::um::winuser::LB_SELITEMRANGEEX;
+        /// Select a range of items in a multi list box
+        pub fn multi_select_range(&self, range: Range<usize>) => try_multi_select_range {
+            use winapi::um::winuser::LB_SELITEMRANGEEX;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let start = range.start as WPARAM;
-        let end = range.end as LPARAM;
-        wh::send_message(handle, LB_SELITEMRANGEEX, start, end);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let start = range.start as WPARAM;
+            let end = range.end as LPARAM;
+            wh::send_message(handle, LB_SELITEMRANGEEX, start, end);
 
-    /// Unselect a range of items in a multi list box
-    pub fn multi_unselect_range(&self, range: Range<usize>) {
-        use winapi::um::winuser::LB_SELITEMRANGEEX;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let start = range.start as LPARAM;
-        let end = range.end as WPARAM;
-        wh::send_message(handle, LB_SELITEMRANGEEX, end, start);
-    }
+        /// Unselect a range of items in a multi list box
+        pub fn multi_unselect_range(&self, range: Range<usize>) => try_multi_unselect_range {
+            use winapi::um::winuser::LB_SELITEMRANGEEX;
 
-    /// Search an item that begins by the value and select the first one found.
-    /// The search is not case sensitive, so this string can contain any combination of uppercase and lowercase letters.
-    /// Return the index of the selected string or None if the search was not successful
-    pub fn set_selection_string(&self, value: &str) -> Option<usize> {
-        use winapi::um::winuser::{LB_SELECTSTRING, LB_ERR};
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let start = range.start as LPARAM;
+            let end = range.end as WPARAM;
+            wh::send_message(handle, LB_SELITEMRANGEEX, end, start);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let os_string = to_utf16(value);
+            Ok(())
+        }
 
-        unsafe {
-            let index = wh::send_message(handle, LB_SELECTSTRING, 0, mem::transmute(os_string.as_ptr()));
-            if index == LB_ERR {
-                None
-            } else {
-                Some(index as usize)
+        /// Select or unselect the items in `range` in a multi list box. Unlike `multi_select_range`, the end of the range is excluded.
+        /// Does nothing if the range is empty.
+        pub fn select_range(&self, range: Range<usize>, selected: bool) => try_select_range {
+            use winapi::um::winuser::LB_SELITEMRANGEEX;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            if range.start >= range.end {
+                return Ok(());
             }
+
+            let first = range.start;
+            let last = range.end - 1;
+            match selected {
+                true => wh::send_message(handle, LB_SELITEMRANGEEX, first as WPARAM, last as LPARAM),
+                false => wh::send_message(handle, LB_SELITEMRANGEEX, last as WPARAM, first as LPARAM),
+            };
+
+            Ok(())
         }
-    }
 
-    /// Check if the item at `index` is selected by the user
-    /// Return `false` if the index is out of range.
-    pub fn selected(&self, index: usize) -> bool {
-        use winapi::um::winuser::LB_GETSEL;
+        /// Invert the selection state of the item at `index` in a multi list box.
+        /// Returns the new selection state of the item.
+        pub fn toggle(&self, index: usize) -> bool => try_toggle {
+            use winapi::um::winuser::LB_SETSEL;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LB_GETSEL, index as WPARAM, 0) > 0
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let selected = !self.selected(index);
+            wh::send_message(handle, LB_SETSEL, selected as WPARAM, index as LPARAM);
+            Ok(selected)
+        }
 
-    /// Update the visual of the control with the inner collection.
-    /// This rebuild every item in the list box and can take some time on big collections.
-    pub fn sync(&self) {
-        use winapi::um::winuser::{LB_ADDSTRING, LB_INITSTORAGE};
+        /// Return the anchor item of a multi list box. The anchor is the item from which a SHIFT+click range selection starts.
+        /// Returns `None` if the list box is empty.
+        pub fn anchor_index(&self) -> Option<usize> => try_anchor_index {
+            use winapi::um::winuser::{LB_GETANCHORINDEX, LB_ERR};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(match wh::send_message(handle, LB_GETANCHORINDEX, 0, 0) {
+                LB_ERR => None,
+                i if i as usize >= self.len() => None,
+                i => Some(i as usize)
+            })
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Set the anchor item of a multi list box.
+        pub fn set_anchor_index(&self, index: usize) => try_set_anchor_index {
+            use winapi::um::winuser::LB_SETANCHORINDEX;
 
-        self.clear_inner(handle);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LB_SETANCHORINDEX, index as WPARAM, 0);
 
-        let item_count = self.collection.borrow().len();
-        wh::send_message(handle, LB_INITSTORAGE, item_count as WPARAM, (10*item_count) as LPARAM);
+            Ok(())
+        }
 
-        for item in self.collection.borrow().iter() {
-            let display = format!("{}", item);
-            let display_os = to_utf16(&display);
-            
-            unsafe {
-                wh::send_message(handle, LB_ADDSTRING, 0, mem::transmute(display_os.as_ptr()));
+        /// Return the item that has the focus rectangle in a multi list box.
+        /// In a single select list box, this is the selected item.
+        /// Returns `None` if the list box is empty.
+        pub fn caret_index(&self) -> Option<usize> => try_caret_index {
+            use winapi::um::winuser::{LB_GETCARETINDEX, LB_ERR};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(match wh::send_message(handle, LB_GETCARETINDEX, 0, 0) {
+                LB_ERR => None,
+                i if i as usize >= self.len() => None,
+                i => Some(i as usize)
+            })
+        }
+
+        /// Give the focus rectangle to the item at `index` and scroll it into view.
+        /// The selection is not changed.
+        pub fn set_caret_index(&self, index: usize) => try_set_caret_index {
+            use winapi::um::winuser::LB_SETCARETINDEX;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LB_SETCARETINDEX, index as WPARAM, 0);
+
+            Ok(())
+        }
+
+        /// Search an item that begins by the value and select the first one found.
+        /// The search is not case sensitive, so this string can contain any combination of uppercase and lowercase letters.
+        /// Return the index of the selected string or None if the search was not successful
+        pub fn set_selection_string(&self, value: &str) -> Option<usize> => try_set_selection_string {
+            use winapi::um::winuser::{LB_SELECTSTRING, LB_ERR};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let os_string = to_utf16(value);
+
+            Ok(unsafe {
+                let index = wh::send_message(handle, LB_SELECTSTRING, 0, mem::transmute(os_string.as_ptr()));
+                if index == LB_ERR {
+                    None
+                } else {
+                    Some(index as usize)
+                }
+            })
+        }
+
+        /// Check if the item at `index` is selected by the user
+        /// Return `false` if the index is out of range.
+        pub fn selected(&self, index: usize) -> bool => try_selected {
+            use winapi::um::winuser::LB_GETSEL;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, LB_GETSEL, index as WPARAM, 0) > 0)
+        }
+
+        /// Update the visual of the control with the inner collection.
+        /// This rebuild every item in the list box and can take some time on big collections.
+        pub fn sync(&self) => try_sync {
+            use winapi::um::winuser::{LB_ADDSTRING, LB_INITSTORAGE};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            self.clear_inner(handle);
+
+            let item_count = self.collection.borrow().len();
+            wh::send_message(handle, LB_INITSTORAGE, item_count as WPARAM, (10*item_count) as LPARAM);
+
+            for item in self.collection.borrow().iter() {
+                let display = format!("{}", item);
+                with_utf16(&display, |text| wh::send_message(handle, LB_ADDSTRING, 0, text.as_ptr() as LPARAM));
             }
+
+            Ok(())
         }
     }
 
     /// Set the item collection of the list box. Return the old collection
-    pub fn set_collection(&self, mut col: Vec<D>) -> Vec<D> {
+    pub fn set_collection(&self, col: Vec<D>) -> Vec<D> {
+        self.try_set_collection(col).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `set_collection`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the control is not bound or if its window was destroyed.
+    pub fn try_set_collection(&self, mut col: Vec<D>) -> Result<Vec<D>, NwgError> {
         use winapi::um::winuser::LB_ADDSTRING;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
         self.clear_inner(handle);
 
         for item in col.iter() {
             let display = format!("{}", item);
-            let display_os = to_utf16(&display);
-            
-            unsafe {
-                wh::send_message(handle, LB_ADDSTRING, 0, mem::transmute(display_os.as_ptr()));
-            }
+            with_utf16(&display, |text| wh::send_message(handle, LB_ADDSTRING, 0, text.as_ptr() as LPARAM));
         }
 
         let mut col_ref = self.collection.borrow_mut();
         mem::swap::<Vec<D>>(&mut col_ref, &mut col);
 
-        col
+        Ok(col)
     }
 
     /// Clears the control and free the underlying collection. Same as `set_collection(Vec::new())`
@@ -361,11 +503,13 @@ impl<D: Display+Default> ListBox<D> {
         self.set_collection(Vec::new());
     }
 
-    /// Return the number of items in the control. NOT the inner rust collection
-    pub fn len(&self) -> usize {
-        use winapi::um::winuser::LB_GETCOUNT;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LB_GETCOUNT, 0, 0) as usize
+    try_accessors! {
+        /// Return the number of items in the control. NOT the inner rust collection
+        pub fn len(&self) -> usize => try_len {
+            use winapi::um::winuser::LB_GETCOUNT;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, LB_GETCOUNT, 0, 0) as usize)
+        }
     }
 
 
@@ -373,83 +517,97 @@ impl<D: Display+Default> ListBox<D> {
     // Common control functions
     //
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+    try_accessors! {
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
         }
-    }
 
-    /// Set the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Set the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+        /// Set the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
     }
 
     /// Get read-only access to the inner collection of the list box
@@ -588,6 +746,10 @@ impl<'a, D: Display+Default> ListBoxBuilder<'a, D> {
             .parent(Some(parent))
             .build()?;
 
+        if flags & LBS_OWNERDRAWVARIABLE == LBS_OWNERDRAWVARIABLE {
+            crate::win32::window::register_owner_draw(out.handle.hwnd().unwrap());
+        }
+
         if self.font.is_some() {
             out.set_font(self.font);
         } else {
@@ -598,7 +760,7 @@ impl<'a, D: Display+Default> ListBoxBuilder<'a, D> {
             out.set_collection(col);
         }
 
-        if flags & LBS_MULTIPLESEL == LBS_MULTIPLESEL {
+        if flags & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) != 0 {
             for i in self.multi_selection {
                 out.multi_add_selection(i);
             }
@@ -8,7 +8,7 @@ use winapi::um::commctrl::{
 };
 use super::{ControlBase, ControlHandle};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::{to_utf16, from_utf16, check_hwnd};
+use crate::win32::base_helper::{to_utf16, from_utf16, try_check_hwnd};
 use crate::{NwgError, RawEventHandler, unbind_raw_event_handler};
 use std::{mem, ptr, rc::Rc, cell::RefCell};
 
@@ -301,128 +301,145 @@ impl ListView {
         }
     }
 
-    /// Sets the image list of the listview
-    /// A listview can accept different kinds of image list. See `ListViewImageListType`
-    #[cfg(feature="image-list")]
-    pub fn set_image_list(&self, list: Option<&ImageList>, list_type: ListViewImageListType) {
-        use winapi::um::commctrl::LVM_SETIMAGELIST;
+    try_accessors! {
+        /// Sets the image list of the listview
+        /// A listview can accept different kinds of image list. See `ListViewImageListType`
+        #[cfg(feature="image-list")]
+        pub fn set_image_list(&self, list: Option<&ImageList>, list_type: ListViewImageListType) => try_set_image_list {
+            use winapi::um::commctrl::LVM_SETIMAGELIST;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let list_handle = list.map(|l| l.handle).unwrap_or(ptr::null_mut());
-        wh::send_message(handle, LVM_SETIMAGELIST, list_type.to_raw() as _, list_handle as _);
+            let list_handle = list.map(|l| l.handle).unwrap_or(ptr::null_mut());
+            wh::send_message(handle, LVM_SETIMAGELIST, list_type.to_raw() as _, list_handle as _);
         
-        self.invalidate();
-    }
+            self.invalidate();
 
-    /// Returns the current image list for the selected type. The returned image list will not be owned.
-    /// Can return `None` if there is no assocaited image list
-    #[cfg(feature="image-list")]
-    pub fn image_list(&self, list_type: ListViewImageListType) -> Option<ImageList> {
-        use winapi::um::commctrl::LVM_GETIMAGELIST;
+            Ok(())
+        }
+
+        /// Returns the current image list for the selected type. The returned image list will not be owned.
+        /// Can return `None` if there is no assocaited image list
+        #[cfg(feature="image-list")]
+        pub fn image_list(&self, list_type: ListViewImageListType) -> Option<ImageList> => try_image_list {
+            use winapi::um::commctrl::LVM_GETIMAGELIST;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        match wh::send_message(handle, LVM_GETIMAGELIST, list_type.to_raw() as _, 0) {
-            0 => None,
-            handle => Some( ImageList {
-                handle: handle as _,
-                owned: false
+            Ok(match wh::send_message(handle, LVM_GETIMAGELIST, list_type.to_raw() as _, 0) {
+                0 => None,
+                handle => Some( ImageList {
+                    handle: handle as _,
+                    owned: false
+                })
             })
         }
-    }
 
-    /// Sets the text color of the list view
-    pub fn set_text_color(&self, r: u8, g: u8, b: u8) {
-        use winapi::um::commctrl::LVM_SETTEXTCOLOR;
-        use winapi::um::wingdi::RGB;
+        /// Sets the text color of the list view
+        pub fn set_text_color(&self, r: u8, g: u8, b: u8) => try_set_text_color {
+            use winapi::um::commctrl::LVM_SETTEXTCOLOR;
+            use winapi::um::wingdi::RGB;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let color = RGB(r, g, b);
+            let color = RGB(r, g, b);
 
-        wh::send_message(handle, LVM_SETTEXTCOLOR, 0, color as _);
+            wh::send_message(handle, LVM_SETTEXTCOLOR, 0, color as _);
 
-        self.invalidate();
-    }
+            self.invalidate();
 
-    /// Returns the current text color
-    pub fn text_color(&self) -> [u8; 3] {
-        use winapi::um::commctrl::LVM_GETTEXTCOLOR;
-        use winapi::um::wingdi::{GetRValue, GetGValue, GetBValue};
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let col = wh::send_message(handle, LVM_GETTEXTCOLOR, 0, 0) as u32;
+        /// Returns the current text color
+        pub fn text_color(&self) -> [u8; 3] => try_text_color {
+            use winapi::um::commctrl::LVM_GETTEXTCOLOR;
+            use winapi::um::wingdi::{GetRValue, GetGValue, GetBValue};
 
-        [
-            GetRValue(col),
-            GetGValue(col),
-            GetBValue(col),
-        ]
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let col = wh::send_message(handle, LVM_GETTEXTCOLOR, 0, 0) as u32;
 
-    /// Sets the background color of the list view
-    pub fn set_background_color(&self, r: u8, g: u8, b: u8) {
-        use winapi::um::commctrl::LVM_SETBKCOLOR;
-        use winapi::um::wingdi::RGB;
+            Ok([
+                GetRValue(col),
+                GetGValue(col),
+                GetBValue(col),
+            ])
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Sets the background color of the list view
+        pub fn set_background_color(&self, r: u8, g: u8, b: u8) => try_set_background_color {
+            use winapi::um::commctrl::LVM_SETBKCOLOR;
+            use winapi::um::wingdi::RGB;
 
-        let color = RGB(r, g, b);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        wh::send_message(handle, LVM_SETBKCOLOR, 0, color as _);
+            let color = RGB(r, g, b);
 
-        self.invalidate();
-    }
+            wh::send_message(handle, LVM_SETBKCOLOR, 0, color as _);
 
-    /// Returns the background color of the list view
-    pub fn background_color(&self) -> [u8; 3] {
-        use winapi::um::commctrl::LVM_GETBKCOLOR;
-        use winapi::um::wingdi::{GetRValue, GetGValue, GetBValue};
+            self.invalidate();
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let col = wh::send_message(handle, LVM_GETBKCOLOR, 0, 0) as u32;
+            Ok(())
+        }
 
-        [
-            GetRValue(col),
-            GetGValue(col),
-            GetBValue(col),
-        ]
-    }
+        /// Returns the background color of the list view
+        pub fn background_color(&self) -> [u8; 3] => try_background_color {
+            use winapi::um::commctrl::LVM_GETBKCOLOR;
+            use winapi::um::wingdi::{GetRValue, GetGValue, GetBValue};
 
-    /// Returns the index of the selected column. Only available if Comclt32.dll version is >= 6.0.
-    pub fn selected_column(&self) -> usize {
-        use winapi::um::commctrl::LVM_GETSELECTEDCOLUMN;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let col = wh::send_message(handle, LVM_GETBKCOLOR, 0, 0) as u32;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_GETSELECTEDCOLUMN, 0, 0) as usize
-    }
+            Ok([
+                GetRValue(col),
+                GetGValue(col),
+                GetBValue(col),
+            ])
+        }
 
-    /// Sets the selected column. Only available if Comclt32.dll version is >= 6.0.
-    pub fn set_selected_column(&self, index: usize) {
-        use winapi::um::commctrl::LVM_SETSELECTEDCOLUMN;
+        /// Returns the index of the selected column. Only available if Comclt32.dll version is >= 6.0.
+        pub fn selected_column(&self) -> usize => try_selected_column {
+            use winapi::um::commctrl::LVM_GETSELECTEDCOLUMN;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_SETSELECTEDCOLUMN, index as _, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, LVM_GETSELECTEDCOLUMN, 0, 0) as usize)
+        }
 
-    /// Returns the number of selected items
-    pub fn selected_count(&self) -> usize {
-        use winapi::um::commctrl::LVM_GETSELECTEDCOUNT;
+        /// Sets the selected column. Only available if Comclt32.dll version is >= 6.0.
+        pub fn set_selected_column(&self, index: usize) => try_set_selected_column {
+            use winapi::um::commctrl::LVM_SETSELECTEDCOLUMN;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_GETSELECTEDCOUNT, 0, 0) as usize
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LVM_SETSELECTEDCOLUMN, index as _, 0);
+
+            Ok(())
+        }
+
+        /// Returns the number of selected items
+        pub fn selected_count(&self) -> usize => try_selected_count {
+            use winapi::um::commctrl::LVM_GETSELECTEDCOUNT;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, LVM_GETSELECTEDCOUNT, 0, 0) as usize)
+        }
     }
 
     /// Inserts a column in the report. Column are only used with the Detailed list view style.
     pub fn insert_column<I: Into<InsertListViewColumn>>(&self, insert: I) {
+        self.try_insert_column(insert).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `insert_column`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the control is not bound or if its window was destroyed.
+    pub fn try_insert_column<I: Into<InsertListViewColumn>>(&self, insert: I) -> Result<(), NwgError> {
         use winapi::um::commctrl::LVM_INSERTCOLUMNW;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
         match self.list_style() {
             ListViewStyle::Detailed => {},
-            _ => { return; }
+            _ => { return Ok(()); }
         }
 
         let insert = insert.into();
@@ -451,53 +468,64 @@ impl ListView {
             insert.index.unwrap_or(col_count) as usize, 
             (&item as *const LVCOLUMNW) as _
         );
+
+        Ok(())
     }
 
-    /// Checks if there is a column at the selected index
-    pub fn has_column(&self, index: usize) -> bool {
-        use winapi::um::commctrl::LVM_GETCOLUMNW;
+    try_accessors! {
+        /// Checks if there is a column at the selected index
+        pub fn has_column(&self, index: usize) -> bool => try_has_column {
+            use winapi::um::commctrl::LVM_GETCOLUMNW;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let mut col: LVCOLUMNW = unsafe { mem::zeroed() };
+            let mut col: LVCOLUMNW = unsafe { mem::zeroed() };
 
-        wh::send_message(handle, LVM_GETCOLUMNW, index as _, &mut col as *mut LVCOLUMNW as _) != 0
-    }
+            Ok(wh::send_message(handle, LVM_GETCOLUMNW, index as _, &mut col as *mut LVCOLUMNW as _) != 0)
+        }
 
-    /// Returns the information of a column.
-    /// Because there's no way to fetch the actual text length, it's up to you to set the maximum buffer size
-    pub fn column(&self, index: usize, text_buffer_size: i32) -> Option<ListViewColumn> {
-        use winapi::um::commctrl::LVM_GETCOLUMNW;
+        /// Returns the information of a column.
+        /// Because there's no way to fetch the actual text length, it's up to you to set the maximum buffer size
+        pub fn column(&self, index: usize, text_buffer_size: i32) -> Option<ListViewColumn> => try_column {
+            use winapi::um::commctrl::LVM_GETCOLUMNW;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let mut text_buffer: Vec<u16> = Vec::with_capacity(text_buffer_size as _);
-        unsafe { text_buffer.set_len(text_buffer_size as _); }
+            let mut text_buffer: Vec<u16> = Vec::with_capacity(text_buffer_size as _);
+            unsafe { text_buffer.set_len(text_buffer_size as _); }
 
-        let mut col: LVCOLUMNW = unsafe { mem::zeroed() };
-        col.mask = LVCF_TEXT | LVCF_WIDTH | LVCF_FMT;
-        col.pszText = text_buffer.as_mut_ptr();
-        col.cchTextMax = text_buffer_size;
+            let mut col: LVCOLUMNW = unsafe { mem::zeroed() };
+            col.mask = LVCF_TEXT | LVCF_WIDTH | LVCF_FMT;
+            col.pszText = text_buffer.as_mut_ptr();
+            col.cchTextMax = text_buffer_size;
 
-        match wh::send_message(handle, LVM_GETCOLUMNW, index as _, &mut col as *mut LVCOLUMNW as _) == 0 {
-            true => None,
-            false => Some(ListViewColumn {
-                fmt: col.fmt,
-                width: col.cx,
-                text: from_utf16(&text_buffer),
+            Ok(match wh::send_message(handle, LVM_GETCOLUMNW, index as _, &mut col as *mut LVCOLUMNW as _) == 0 {
+                true => None,
+                false => Some(ListViewColumn {
+                    fmt: col.fmt,
+                    width: col.cx,
+                    text: from_utf16(&text_buffer),
+                })
             })
         }
     }
 
     /// Sets the information of a column. Does nothing if there is no column at the selected index
     pub fn update_column<I: Into<InsertListViewColumn>>(&self, index: usize, column: I) {
+        self.try_update_column(index, column).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `update_column`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the control is not bound or if its window was destroyed.
+    pub fn try_update_column<I: Into<InsertListViewColumn>>(&self, index: usize, column: I) -> Result<(), NwgError> {
         use winapi::um::commctrl::LVM_SETCOLUMNW;
 
         if !self.has_column(index) {
-            return;
+            return Ok(());
         }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
         let insert = column.into();
 
         let use_text = insert.text.is_some();
@@ -523,154 +551,175 @@ impl ListView {
         }
 
         wh::send_message(handle, LVM_SETCOLUMNW, index as _, &mut item as *mut LVCOLUMNW as _);
-    }
 
-    /// Deletes a column in a list view. Removing the column at index 0 is only available if ComCtl32.dll is version 6 or later.
-    pub fn remove_column(&self, column_index: usize) {
-        use winapi::um::commctrl::LVM_DELETECOLUMN;
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_DELETECOLUMN , column_index as _, 0);
+        Ok(())
     }
 
-    /// Returns true if list view headers are visible
-    pub fn headers_enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let style = wh::get_style(handle);
-        (style & LVS_REPORT == LVS_REPORT) &&
-            (style & LVS_NOCOLUMNHEADER != LVS_NOCOLUMNHEADER)
-    }
+    try_accessors! {
+        /// Deletes a column in a list view. Removing the column at index 0 is only available if ComCtl32.dll is version 6 or later.
+        pub fn remove_column(&self, column_index: usize) => try_remove_column {
+            use winapi::um::commctrl::LVM_DELETECOLUMN;
 
-    /// Enable or disable list view headers
-    pub fn set_headers_enabled(&self, enable: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let style = wh::get_style(handle);
-        if style & LVS_REPORT == LVS_REPORT {
-            if !enable {
-                wh::set_style(handle, style | LVS_NOCOLUMNHEADER);
-            } else {
-                wh::set_style(handle, style & !LVS_NOCOLUMNHEADER);
-            }
-        }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LVM_DELETECOLUMN , column_index as _, 0);
 
-    /// Returns column sort indicator
-    pub fn column_sort_arrow(&self, column_index: usize) -> Option<ListViewColumnSortArrow> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-
-        let headers = wh::send_message(handle, LVM_GETHEADER, 0, 0);
-        if headers == 0 { return None; }
+            Ok(())
+        }
 
-        let mut header: HDITEMW = unsafe { mem::zeroed() };
-        header.mask = HDI_FORMAT;
+        /// Returns true if list view headers are visible
+        pub fn headers_enabled(&self) -> bool => try_headers_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let style = wh::get_style(handle);
+            Ok((style & LVS_REPORT == LVS_REPORT) &&
+                (style & LVS_NOCOLUMNHEADER != LVS_NOCOLUMNHEADER))
+        }
 
-        let l = &mut header as *mut HDITEMW as _;
-        wh::send_message(headers as *mut _, HDM_GETITEMW, column_index, l);
+        /// Enable or disable list view headers
+        pub fn set_headers_enabled(&self, enable: bool) => try_set_headers_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let style = wh::get_style(handle);
+            if style & LVS_REPORT == LVS_REPORT {
+                if !enable {
+                    wh::set_style(handle, style | LVS_NOCOLUMNHEADER);
+                } else {
+                    wh::set_style(handle, style & !LVS_NOCOLUMNHEADER);
+                }
+            }
 
-        match header.fmt & (HDF_SORTUP | HDF_SORTDOWN) {
-            HDF_SORTUP => Some(ListViewColumnSortArrow::Up),
-            HDF_SORTDOWN => Some(ListViewColumnSortArrow::Down),
-            _ => None,
+            Ok(())
         }
-    }
 
-    /// Enable or disable column sort indicator. Draws a up-arrow / down-arrow.
-    pub fn set_column_sort_arrow(&self, column_index: usize, sort: Option<ListViewColumnSortArrow>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Returns column sort indicator
+        pub fn column_sort_arrow(&self, column_index: usize) -> Option<ListViewColumnSortArrow> => try_column_sort_arrow {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let headers = wh::send_message(handle, LVM_GETHEADER, 0, 0);
+            if headers == 0 { return Ok(None); }
 
-        let headers = wh::send_message(handle, LVM_GETHEADER, 0, 0);
-        if headers != 0 {
             let mut header: HDITEMW = unsafe { mem::zeroed() };
             header.mask = HDI_FORMAT;
 
             let l = &mut header as *mut HDITEMW as _;
             wh::send_message(headers as *mut _, HDM_GETITEMW, column_index, l);
 
-            header.fmt &= !(HDF_SORTUP | HDF_SORTDOWN);
-            match sort {
-                Some(ListViewColumnSortArrow::Up) => header.fmt |= HDF_SORTUP,
-                Some(ListViewColumnSortArrow::Down) => header.fmt |= HDF_SORTDOWN,
-                _ => {}
-            };
-
-            let l = &mut header as *mut HDITEMW as _;
-            wh::send_message(headers as *mut _, HDM_SETITEMW, column_index, l);
+            Ok(match header.fmt & (HDF_SORTUP | HDF_SORTDOWN) {
+                HDF_SORTUP => Some(ListViewColumnSortArrow::Up),
+                HDF_SORTDOWN => Some(ListViewColumnSortArrow::Down),
+                _ => None,
+            })
         }
-    }
 
-    /// Set the width of a column
-    pub fn set_column_width(&self, column_index: usize, width: isize) {
-        use winapi::um::commctrl::LVM_SETCOLUMNWIDTH;
+        /// Enable or disable column sort indicator. Draws a up-arrow / down-arrow.
+        pub fn set_column_sort_arrow(&self, column_index: usize, sort: Option<ListViewColumnSortArrow>) => try_set_column_sort_arrow {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_SETCOLUMNWIDTH , column_index as _, width);
-    }
+            let headers = wh::send_message(handle, LVM_GETHEADER, 0, 0);
+            if headers != 0 {
+                let mut header: HDITEMW = unsafe { mem::zeroed() };
+                header.mask = HDI_FORMAT;
 
-    /// Returns the width of a column
-    pub fn column_width(&self) -> usize {
-        use winapi::um::commctrl::LVM_GETCOLUMNWIDTH;
+                let l = &mut header as *mut HDITEMW as _;
+                wh::send_message(headers as *mut _, HDM_GETITEMW, column_index, l);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_GETCOLUMNWIDTH, 0, 0) as usize
-    }
+                header.fmt &= !(HDF_SORTUP | HDF_SORTDOWN);
+                match sort {
+                    Some(ListViewColumnSortArrow::Up) => header.fmt |= HDF_SORTUP,
+                    Some(ListViewColumnSortArrow::Down) => header.fmt |= HDF_SORTDOWN,
+                    _ => {}
+                };
 
-    /// Select or unselect an item at `row_index`. Does nothing if the index is out of bounds.
-    pub fn select_item(&self, row_index: usize, selected: bool) {
-        use winapi::um::commctrl::{LVM_SETITEMW, LVIF_STATE, LVIS_SELECTED};
+                let l = &mut header as *mut HDITEMW as _;
+                wh::send_message(headers as *mut _, HDM_SETITEMW, column_index, l);
+            }
 
-        if !self.has_item(row_index, 0) {
-            return;
+            Ok(())
         }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Set the width of a column
+        pub fn set_column_width(&self, column_index: usize, width: isize) => try_set_column_width {
+            use winapi::um::commctrl::LVM_SETCOLUMNWIDTH;
 
-        let mut item: LVITEMW = unsafe { mem::zeroed() };
-        item.iItem = row_index as _;
-        item.mask = LVIF_STATE;
-        item.state = match selected { true => LVIS_SELECTED, false => 0 };
-        item.stateMask = LVIS_SELECTED;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LVM_SETCOLUMNWIDTH , column_index as _, width);
 
-        wh::send_message(handle, LVM_SETITEMW , 0, &mut item as *mut LVITEMW as _);
-    }
+            Ok(())
+        }
+
+        /// Returns the width of a column
+        pub fn column_width(&self) -> usize => try_column_width {
+            use winapi::um::commctrl::LVM_GETCOLUMNWIDTH;
 
-    /// Returns the index of the first selected item.
-    /// If there's more than one item selected, use `selected_items`
-    pub fn selected_item(&self) -> Option<usize> {
-        use winapi::um::commctrl::{LVM_GETNEXTITEMINDEX, LVNI_SELECTED, LVITEMINDEX};
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, LVM_GETCOLUMNWIDTH, 0, 0) as usize)
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut index = None;
+        /// Select or unselect an item at `row_index`. Does nothing if the index is out of bounds.
+        pub fn select_item(&self, row_index: usize, selected: bool) => try_select_item {
+            use winapi::um::commctrl::{LVM_SETITEMW, LVIF_STATE, LVIS_SELECTED};
+
+            if !self.has_item(row_index, 0) {
+                return Ok(());
+            }
 
-        let mut i_data = LVITEMINDEX { iItem: -1, iGroup: -1 };
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        if wh::send_message(handle, LVM_GETNEXTITEMINDEX, &mut i_data as *mut LVITEMINDEX as _, LVNI_SELECTED) != 0 {
-            index = Some(i_data.iItem as usize);
+            let mut item: LVITEMW = unsafe { mem::zeroed() };
+            item.iItem = row_index as _;
+            item.mask = LVIF_STATE;
+            item.state = match selected { true => LVIS_SELECTED, false => 0 };
+            item.stateMask = LVIS_SELECTED;
+
+            wh::send_message(handle, LVM_SETITEMW , 0, &mut item as *mut LVITEMW as _);
+
+            Ok(())
         }
 
-        index
-    }
+        /// Returns the index of the first selected item.
+        /// If there's more than one item selected, use `selected_items`
+        pub fn selected_item(&self) -> Option<usize> => try_selected_item {
+            use winapi::um::commctrl::{LVM_GETNEXTITEMINDEX, LVNI_SELECTED, LVITEMINDEX};
 
-    /// Returns the indices of every selected items.
-    pub fn selected_items(&self) -> Vec<usize> {
-        use winapi::um::commctrl::{LVM_GETNEXTITEMINDEX, LVNI_SELECTED, LVITEMINDEX};
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let mut index = None;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut indices = Vec::with_capacity(self.len());
+            let mut i_data = LVITEMINDEX { iItem: -1, iGroup: -1 };
 
-        let mut i_data = LVITEMINDEX { iItem: -1, iGroup: -1 };
-        
-        while wh::send_message(handle, LVM_GETNEXTITEMINDEX, &mut i_data as *mut LVITEMINDEX as _, LVNI_SELECTED) != 0 {
-            indices.push(i_data.iItem as usize);
+            if wh::send_message(handle, LVM_GETNEXTITEMINDEX, &mut i_data as *mut LVITEMINDEX as _, LVNI_SELECTED) != 0 {
+                index = Some(i_data.iItem as usize);
+            }
+
+            Ok(index)
         }
 
-        indices
+        /// Returns the indices of every selected items.
+        pub fn selected_items(&self) -> Vec<usize> => try_selected_items {
+            use winapi::um::commctrl::{LVM_GETNEXTITEMINDEX, LVNI_SELECTED, LVITEMINDEX};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let mut indices = Vec::with_capacity(self.len());
+
+            let mut i_data = LVITEMINDEX { iItem: -1, iGroup: -1 };
+        
+            while wh::send_message(handle, LVM_GETNEXTITEMINDEX, &mut i_data as *mut LVITEMINDEX as _, LVNI_SELECTED) != 0 {
+                indices.push(i_data.iItem as usize);
+            }
+
+            Ok(indices)
+        }
     }
 
     /// Inserts a new item into the list view
     pub fn insert_item<I: Into<InsertListViewItem>>(&self, insert: I) {
+        self.try_insert_item(insert).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `insert_item`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the control is not bound or if its window was destroyed.
+    pub fn try_insert_item<I: Into<InsertListViewItem>>(&self, insert: I) -> Result<(), NwgError> {
         use winapi::um::commctrl::{LVM_INSERTITEMW, LVM_SETITEMW};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
         let insert = insert.into();
 
         let row_insert = insert.index.unwrap_or(i32::max_value());
@@ -704,66 +753,77 @@ impl ListView {
         } else {
             wh::send_message(handle, LVM_SETITEMW , 0, &mut item as *mut LVITEMW as _);
         }
+
+        Ok(())
     }
 
-    /// Checks if the item at the selected row is visible
-    pub fn item_is_visible(&self, index: usize) -> bool {
-        use winapi::um::commctrl::LVM_ISITEMVISIBLE;
+    try_accessors! {
+        /// Checks if the item at the selected row is visible
+        pub fn item_is_visible(&self, index: usize) -> bool => try_item_is_visible {
+            use winapi::um::commctrl::LVM_ISITEMVISIBLE;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_ISITEMVISIBLE , index as _, 0) == 1
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, LVM_ISITEMVISIBLE , index as _, 0) == 1)
+        }
 
-    /// Returns `true` if an item exists at the selected index or `false` otherwise.
-    pub fn has_item(&self, row_index: usize, column_index: usize) -> bool {
-        use winapi::um::commctrl::LVM_GETITEMW;
+        /// Returns `true` if an item exists at the selected index or `false` otherwise.
+        pub fn has_item(&self, row_index: usize, column_index: usize) -> bool => try_has_item {
+            use winapi::um::commctrl::LVM_GETITEMW;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let mut item: LVITEMW = unsafe { mem::zeroed() };
-        item.iItem = row_index as _;
-        item.iSubItem = column_index as _;
+            let mut item: LVITEMW = unsafe { mem::zeroed() };
+            item.iItem = row_index as _;
+            item.iSubItem = column_index as _;
 
-        wh::send_message(handle, LVM_GETITEMW , 0, &mut item as *mut LVITEMW as _) == 1
-    }
+            Ok(wh::send_message(handle, LVM_GETITEMW , 0, &mut item as *mut LVITEMW as _) == 1)
+        }
 
-    /// Returns data of an item in the list view. Returns `None` if there is no data at the selected index
-    /// Because there is no way to fetch the actual text size, `text_buffer_size` must be set manually
-    pub fn item(&self, row_index: usize, column_index: usize, text_buffer_size: usize) -> Option<ListViewItem> {
-        use winapi::um::commctrl::{LVM_GETITEMW, LVIF_STATE, LVIS_SELECTED};
+        /// Returns data of an item in the list view. Returns `None` if there is no data at the selected index
+        /// Because there is no way to fetch the actual text size, `text_buffer_size` must be set manually
+        pub fn item(&self, row_index: usize, column_index: usize, text_buffer_size: usize) -> Option<ListViewItem> => try_item {
+            use winapi::um::commctrl::{LVM_GETITEMW, LVIF_STATE, LVIS_SELECTED};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let mut item: LVITEMW = unsafe { mem::zeroed() };
-        item.iItem = row_index as _;
-        item.iSubItem = column_index as _;
-        item.mask = LVIF_IMAGE | LVIF_TEXT | LVIF_STATE;
-        item.stateMask = LVIS_SELECTED;
+            let mut item: LVITEMW = unsafe { mem::zeroed() };
+            item.iItem = row_index as _;
+            item.iSubItem = column_index as _;
+            item.mask = LVIF_IMAGE | LVIF_TEXT | LVIF_STATE;
+            item.stateMask = LVIS_SELECTED;
 
-        let mut text_buffer: Vec<u16> = Vec::with_capacity(text_buffer_size);
-        unsafe { text_buffer.set_len(text_buffer_size); }
-        item.pszText = text_buffer.as_mut_ptr();
-        item.cchTextMax = text_buffer_size as _;
+            let mut text_buffer: Vec<u16> = Vec::with_capacity(text_buffer_size);
+            unsafe { text_buffer.set_len(text_buffer_size); }
+            item.pszText = text_buffer.as_mut_ptr();
+            item.cchTextMax = text_buffer_size as _;
 
 
-        let found = wh::send_message(handle, LVM_GETITEMW , 0, &mut item as *mut LVITEMW as _) == 1;
-        if !found {
-            return None;
-        }
+            let found = wh::send_message(handle, LVM_GETITEMW , 0, &mut item as *mut LVITEMW as _) == 1;
+            if !found {
+                return Ok(None);
+            }
 
-        Some ( build_list_view_image(row_index, column_index, item.state, &text_buffer, item.iImage)  )
+            Ok(Some ( build_list_view_image(row_index, column_index, item.state, &text_buffer, item.iImage)  ))
+        }
     }
 
     /// Updates the item at the selected position
     /// Does nothing if there is no item at the selected position
     pub fn update_item<I: Into<InsertListViewItem>>(&self, row_index: usize, data: I) {
+        self.try_update_item(row_index, data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `update_item`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the control is not bound or if its window was destroyed.
+    pub fn try_update_item<I: Into<InsertListViewItem>>(&self, row_index: usize, data: I) -> Result<(), NwgError> {
         use winapi::um::commctrl::LVM_SETITEMW;
 
         if !self.has_item(row_index, 0) {
-            return;
+            return Ok(());
         }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
         let insert = data.into();
 
         let mut mask = check_image_mask(&insert);
@@ -789,28 +849,50 @@ impl ListView {
         }
         
         wh::send_message(handle, LVM_SETITEMW , 0, &mut item as *mut LVITEMW as _);
+
+        Ok(())
     }
 
-    /// Remove all items on the seleted row. Returns `true` if an item was removed or false otherwise.
-    /// To "remove" an item without deleting the row, use `update_item` and set the text to "".
-    pub fn remove_item(&self, row_index: usize) -> bool {
-        use winapi::um::commctrl::LVM_DELETEITEM;
+    try_accessors! {
+        /// Remove all items on the seleted row. Returns `true` if an item was removed or false otherwise.
+        /// To "remove" an item without deleting the row, use `update_item` and set the text to "".
+        pub fn remove_item(&self, row_index: usize) -> bool => try_remove_item {
+            use winapi::um::commctrl::LVM_DELETEITEM;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_DELETEITEM , row_index as _, 0) == 1
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, LVM_DELETEITEM , row_index as _, 0) == 1)
+        }
     }
 
     /// Inserts multiple items into the control. Basically a loop over `insert_item`.
+    /// The control is not redrawn until all the items are inserted.
     pub fn insert_items<I: Clone+Into<InsertListViewItem>>(&self, insert: &[I]) {
+        let _freeze = self.handle.freeze_guard();
+        self.reserve(insert.len());
+
         for i in insert.iter() {
             self.insert_item(i.clone());
         }
     }
 
+    try_accessors! {
+        /// Preallocate the memory of `additional` new rows
+        pub fn reserve(&self, additional: usize) => try_reserve {
+            use winapi::um::commctrl::{LVM_SETITEMCOUNT, LVSICF_NOINVALIDATEALL};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let len = self.try_len()?;
+            wh::send_message(handle, LVM_SETITEMCOUNT, len + additional, LVSICF_NOINVALIDATEALL as _);
+
+            Ok(())
+        }
+    }
+
     /// Insert multiple item at the selected row or at the end of the list if `None` was used.
     /// This method overrides the `index` and the `column_index` of the items.
     /// Useful when inserting strings into a single row. Ex: `list.insert_items_row(None, &["Hello", "World"]);`
     pub fn insert_items_row<I: Clone+Into<InsertListViewItem>>(&self, row_index: Option<i32>, insert: &[I]) {
+        let _freeze = self.handle.freeze_guard();
         let mut column_index = 0;
         let row_index = row_index.or(Some(self.len() as _));
         
@@ -825,151 +907,177 @@ impl ListView {
         }
     }
 
-    /// Returns the current style of the list view
-    pub fn list_style(&self) -> ListViewStyle {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        ListViewStyle::from_bits(wh::get_style(handle))
-    }
+    try_accessors! {
+        /// Returns the current style of the list view
+        pub fn list_style(&self) -> ListViewStyle => try_list_style {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(ListViewStyle::from_bits(wh::get_style(handle)))
+        }
 
-    /// Sets the list view style of the control
-    pub fn set_list_style(&self, style: ListViewStyle) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Sets the list view style of the control
+        pub fn set_list_style(&self, style: ListViewStyle) => try_set_list_style {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let mut old_style = wh::get_style(handle);
-        old_style = old_style & !0b11;
+            let mut old_style = wh::get_style(handle);
+            old_style = old_style & !0b11;
 
-        wh::set_style(handle, old_style | style.bits());
-    }
+            wh::set_style(handle, old_style | style.bits());
 
-    /// Returns the number of items in the list view
-    pub fn len(&self) -> usize {
-        use winapi::um::commctrl::LVM_GETITEMCOUNT;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_GETITEMCOUNT , 0, 0) as usize
-    }
+            Ok(())
+        }
+
+        /// Returns the number of items in the list view
+        pub fn len(&self) -> usize => try_len {
+            use winapi::um::commctrl::LVM_GETITEMCOUNT;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, LVM_GETITEMCOUNT , 0, 0) as usize)
+        }
 
-    /// Returns the number of columns in the list view
-    pub fn column_len(&self) -> usize {
-        use winapi::um::commctrl::LVM_GETCOLUMNWIDTH;
+        /// Returns the number of columns in the list view
+        pub fn column_len(&self) -> usize => try_column_len {
+            use winapi::um::commctrl::LVM_GETCOLUMNWIDTH;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let mut count = 0;
-        while wh::send_message(handle, LVM_GETCOLUMNWIDTH, count, 0) != 0 {
-            count += 1;
+            let mut count = 0;
+            while wh::send_message(handle, LVM_GETCOLUMNWIDTH, count, 0) != 0 {
+                count += 1;
+            }
+
+            Ok(count)
         }
 
-        count
-    }
+        /// Preallocate space for n number of item in the whole control.
+        /// For example calling this method with n=1000 while the list has 500 items will add space for 500 new items.
+        pub fn set_item_count(&self, n: u32) => try_set_item_count {
+            use winapi::um::commctrl::LVM_SETITEMCOUNT;
 
-    /// Preallocate space for n number of item in the whole control.
-    /// For example calling this method with n=1000 while the list has 500 items will add space for 500 new items.
-    pub fn set_item_count(&self, n: u32) {
-        use winapi::um::commctrl::LVM_SETITEMCOUNT;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LVM_SETITEMCOUNT, n as _, 0);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_SETITEMCOUNT, n as _, 0);
-    }
+            Ok(())
+        }
 
-    /// Enable or disable the redrawing of the control when a new item is added.
-    /// When inserting a large number of items, it's better to disable redraw and reenable it after the items are inserted.
-    pub fn set_redraw(&self, enabled: bool) {
-        use winapi::um::winuser::WM_SETREDRAW;
+        /// Enable or disable the redrawing of the control when a new item is added.
+        /// When inserting a large number of items, it's better to disable redraw and reenable it after the items are inserted.
+        pub fn set_redraw(&self, enabled: bool) => try_set_redraw {
+            use winapi::um::winuser::WM_SETREDRAW;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, WM_SETREDRAW, enabled as _, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, WM_SETREDRAW, enabled as _, 0);
+
+            Ok(())
+        }
+
+        /// Sets the spacing between icons in list-view controls that have the ICON style.
+        /// `dx` specifies the distance, in pixels, to set between icons on the x-axis
+        /// `dy` specifies the distance, in pixels, to set between icons on the y-axis
+        pub fn set_icon_spacing(&self, dx: u16, dy: u16) => try_set_icon_spacing {
+            use winapi::um::commctrl::LVM_SETICONSPACING;
+            use winapi::shared::minwindef::MAKELONG;
 
-    /// Sets the spacing between icons in list-view controls that have the ICON style.
-    /// `dx` specifies the distance, in pixels, to set between icons on the x-axis
-    /// `dy` specifies the distance, in pixels, to set between icons on the y-axis
-    pub fn set_icon_spacing(&self, dx: u16, dy: u16) {
-        use winapi::um::commctrl::LVM_SETICONSPACING;
-        use winapi::shared::minwindef::MAKELONG;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let spacing = MAKELONG(dx, dy);
+            wh::send_message(handle, LVM_SETICONSPACING, 0 as _, spacing as _);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let spacing = MAKELONG(dx, dy);
-        wh::send_message(handle, LVM_SETICONSPACING, 0 as _, spacing as _);
+            self.invalidate();
 
-        self.invalidate();
+            Ok(())
+        }
     }
 
     // Common methods
 
-    /// Invalidate the whole drawing region.
-    pub fn invalidate(&self) {
-        use winapi::um::winuser::InvalidateRect;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { InvalidateRect(handle, ptr::null(), 1); }
-    }
+    try_accessors! {
+        /// Invalidate the whole drawing region.
+        pub fn invalidate(&self) => try_invalidate {
+            use winapi::um::winuser::InvalidateRect;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { InvalidateRect(handle, ptr::null(), 1); }
 
-    /// Removes all item from the listview
-    pub fn clear(&self) {
-        use winapi::um::commctrl::LVM_DELETEALLITEMS;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, LVM_DELETEALLITEMS, 0, 0);
-    }
+        /// Removes all item from the listview
+        pub fn clear(&self) => try_clear {
+            use winapi::um::commctrl::LVM_DELETEALLITEMS;
 
-    /// Returns true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, LVM_DELETEALLITEMS, 0, 0);
 
-    /// Sets the keyboard focus on the button
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+            Ok(())
+        }
 
-    /// Returns true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+        /// Returns true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Sets the keyboard focus on the button
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Returns true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            Ok(())
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Returns true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Returns the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Sets the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, true) }
-    }
+            Ok(())
+        }
 
-    /// Returns the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Returns true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
 
-    /// Sets the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+        /// Returns the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Sets the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, true) }
+
+            Ok(())
+        }
+
+        /// Returns the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Sets the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
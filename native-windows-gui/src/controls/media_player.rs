@@ -0,0 +1,413 @@
+use winapi::shared::windef::HWND;
+use winapi::um::winuser::{WS_CHILD, WS_VISIBLE, WS_DISABLED, WS_CLIPCHILDREN, WS_CLIPSIBLINGS};
+use crate::win32::base_helper::{check_hwnd, try_check_hwnd};
+use crate::win32::window_helper as wh;
+use crate::win32::media_player as mp;
+use crate::{NwgError, RawEventHandler, unbind_raw_event_handler};
+use super::{ControlBase, ControlHandle};
+use std::time::Duration;
+
+const NOT_BOUND: &'static str = "MediaPlayer is not yet bound to a winapi object";
+const BAD_HANDLE: &'static str = "INTERNAL ERROR: MediaPlayer handle is not HWND!";
+
+
+bitflags! {
+    /**
+        The media player flags
+
+        * NONE:     No flags. Equivalent to a invisible media player.
+        * VISIBLE:  The media player is immediatly visible after creation
+        * DISABLED: The media player cannot be interacted with by the user.
+    */
+    pub struct MediaPlayerFlags: u32 {
+        const NONE = 0;
+        const VISIBLE = WS_VISIBLE;
+        const DISABLED = WS_DISABLED;
+    }
+}
+
+/// The playback state of a `MediaPlayer`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MediaState {
+    /// No media is opened, or the device is not ready
+    NotReady,
+    Stopped,
+    Playing,
+    Paused,
+    Seeking,
+}
+
+
+/**
+    A control that plays audio and video files. Videos are displayed in the control and scaled to its size.
+
+    The media player hosts a MCIWnd window (Video for Windows). The supported formats are the ones of the MCI devices
+    installed on the system; on modern Windows, the DirectShow device plays most common audio and video files (wav, mp3, avi, mp4, wmv).
+
+    Playback positions and lengths are `Duration` with a millisecond precision. The volume goes from 0 to 1000 (the normal volume).
+
+    Requires the `media-player` feature.
+
+    **Builder parameters:**
+      * `parent`:   **Required.** The media player parent container.
+      * `size`:     The media player size.
+      * `position`: The media player position.
+      * `flags`:    A combination of the MediaPlayerFlags values.
+      * `ex_flags`: A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
+      * `playbar`:  Display the MCI play bar (play/stop button and a seek bar) under the media
+      * `source`:   A media file to open after the control is created. Building the control fails if the file cannot be opened.
+
+    **Control events:**
+      * `OnMediaEnd`: When the player stops at the end of the media
+      * `OnResize`: When the media player is resized
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn play(player: &nwg::MediaPlayer) -> Result<(), nwg::NwgError> {
+        player.open("C:\\Windows\\Media\\tada.wav")?;
+        player.set_volume(500);
+        player.play();
+        Ok(())
+    }
+    ```
+*/
+#[derive(Default)]
+pub struct MediaPlayer {
+    pub handle: ControlHandle,
+    player: Option<HWND>,
+    handler: Option<RawEventHandler>,
+}
+
+impl MediaPlayer {
+
+    pub fn builder<'a>() -> MediaPlayerBuilder<'a> {
+        MediaPlayerBuilder {
+            size: (320, 240),
+            position: (0, 0),
+            flags: None,
+            ex_flags: 0,
+            playbar: false,
+            source: None,
+            parent: None,
+        }
+    }
+
+    /// Opens a media file. The media that was previously opened is closed.
+    /// Returns an error with the MCI error message if the file cannot be opened.
+    pub fn open(&self, path: &str) -> Result<(), NwgError> {
+        let player = self.player();
+        unsafe { mp::open(player, path) }
+    }
+
+    /// Closes the media
+    pub fn close(&self) {
+        let player = self.player();
+        wh::send_message(player, mp::MCI_CLOSE, 0, 0);
+    }
+
+    /// Plays the media from the current position. If the media was stopped at its end, it is played from the start.
+    pub fn play(&self) {
+        let player = self.player();
+        if self.state() == MediaState::Stopped && unsafe { mp::at_end(player) } {
+            wh::send_message(player, mp::MCI_SEEK, 0, 0);
+        }
+
+        wh::send_message(player, mp::MCI_PLAY, 0, 0);
+    }
+
+    /// Pauses the media. Call `play` to resume.
+    pub fn pause(&self) {
+        let player = self.player();
+        wh::send_message(player, mp::MCI_PAUSE, 0, 0);
+    }
+
+    /// Stops the media and moves to its start
+    pub fn stop(&self) {
+        let player = self.player();
+        wh::send_message(player, mp::MCI_STOP, 0, 0);
+        wh::send_message(player, mp::MCI_SEEK, 0, 0);
+    }
+
+    /// Moves to `position`. A playing media keeps on playing from the new position.
+    pub fn seek(&self, position: Duration) {
+        let playing = self.state() == MediaState::Playing;
+        let player = self.player();
+        wh::send_message(player, mp::MCI_SEEK, 0, position.as_millis() as _);
+
+        if playing {
+            wh::send_message(player, mp::MCI_PLAY, 0, 0);
+        }
+    }
+
+    /// Returns the current playback position in the media
+    pub fn playback_position(&self) -> Duration {
+        let player = self.player();
+        let position = wh::send_message(player, mp::MCIWNDM_GETPOSITION, 0, 0);
+        Duration::from_millis(position.max(0) as u64)
+    }
+
+    /// Returns the length of the media. Returns a zero duration if no media is opened.
+    pub fn length(&self) -> Duration {
+        let player = self.player();
+        let length = wh::send_message(player, mp::MCIWNDM_GETLENGTH, 0, 0);
+        Duration::from_millis(length.max(0) as u64)
+    }
+
+    /// Returns the playback state of the media
+    pub fn state(&self) -> MediaState {
+        let player = self.player();
+        match wh::send_message(player, mp::MCIWNDM_GETMODE, 0, 0) as u32 {
+            mp::MCI_MODE_STOP => MediaState::Stopped,
+            mp::MCI_MODE_PLAY => MediaState::Playing,
+            mp::MCI_MODE_PAUSE => MediaState::Paused,
+            mp::MCI_MODE_SEEK => MediaState::Seeking,
+            _ => MediaState::NotReady
+        }
+    }
+
+    /// Returns the volume of the media, from 0 to 1000
+    pub fn volume(&self) -> u32 {
+        let player = self.player();
+        wh::send_message(player, mp::MCIWNDM_GETVOLUME, 0, 0).max(0) as u32
+    }
+
+    /// Sets the volume of the media, from 0 to 1000. The volume is reset when a new media is opened.
+    pub fn set_volume(&self, volume: u32) {
+        let player = self.player();
+        wh::send_message(player, mp::MCIWNDM_SETVOLUME, 0, volume.min(1000) as _);
+    }
+
+    /// Returns true if the MCI play bar is displayed under the media
+    pub fn playbar(&self) -> bool {
+        let player = self.player();
+        let styles = wh::send_message(player, mp::MCIWNDM_GETSTYLES, 0, 0) as u32;
+        styles & mp::MCIWNDF_NOPLAYBAR == 0
+    }
+
+    /// Shows or hides the MCI play bar under the media
+    pub fn set_playbar(&self, v: bool) {
+        let player = self.player();
+        let value = match v {
+            true => 0,
+            false => mp::MCIWNDF_NOPLAYBAR
+        };
+
+        wh::send_message(player, mp::MCIWNDM_CHANGESTYLES, mp::MCIWNDF_NOPLAYBAR as _, value as _);
+    }
+
+    try_accessors! {
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
+
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return true if the control is visible to the user. Will return true even if the
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the media player in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Set the size of the media player in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, true) }
+
+            Ok(())
+        }
+
+        /// Return the position of the media player in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the media player in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+    }
+
+    /// Winapi class name used during control creation
+    pub fn class_name(&self) -> &'static str {
+        "NWG_EXTERN_CANVAS"
+    }
+
+    // Winapi base flags used during window creation
+    pub fn flags(&self) -> u32 {
+        WS_VISIBLE
+    }
+
+    /// Winapi flags required by the control
+    pub fn forced_flags(&self) -> u32 {
+        WS_CHILD | WS_CLIPCHILDREN | WS_CLIPSIBLINGS
+    }
+
+    /// Returns the MCIWnd window
+    fn player(&self) -> HWND {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        self.player.expect(NOT_BOUND)
+    }
+
+    /// Resizes the MCIWnd window with the control and raises `OnMediaEnd` when the media stops at its end
+    fn bind_events(&mut self) -> Result<(), NwgError> {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_SIZE, MoveWindow};
+        use winapi::shared::minwindef::{LOWORD, HIWORD};
+        use std::cell::Cell;
+
+        let player = self.player.unwrap();
+        let last_mode = Cell::new(mp::MCI_MODE_NOT_READY);
+
+        let handler = bind_raw_event_handler_inner(&self.handle, 0, move |hwnd, msg, w, l| {
+            match msg {
+                WM_SIZE => unsafe {
+                    let (width, height) = (LOWORD(l as u32), HIWORD(l as u32));
+                    MoveWindow(player, 0, 0, width as i32, height as i32, 1);
+                },
+                mp::MCIWNDM_NOTIFYMODE if w as HWND == player => {
+                    let mode = l as u32;
+                    let ended = last_mode.replace(mode) == mp::MCI_MODE_PLAY && mode == mp::MCI_MODE_STOP;
+                    if ended && unsafe { mp::at_end(player) } {
+                        wh::send_message(hwnd, wh::NWG_MEDIA_END, 0, 0);
+                    }
+                },
+                _ => {}
+            }
+
+            None
+        });
+
+        self.handler = Some(handler?);
+        Ok(())
+    }
+
+}
+
+impl Drop for MediaPlayer {
+    fn drop(&mut self) {
+        if let Some(h) = self.handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
+        if let Some(player) = self.player {
+            wh::send_message(player, mp::MCI_CLOSE, 0, 0);
+        }
+
+        self.handle.destroy();
+    }
+}
+
+impl PartialEq for MediaPlayer {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+pub struct MediaPlayerBuilder<'a> {
+    size: (i32, i32),
+    position: (i32, i32),
+    flags: Option<MediaPlayerFlags>,
+    ex_flags: u32,
+    playbar: bool,
+    source: Option<&'a str>,
+    parent: Option<ControlHandle>,
+}
+
+impl<'a> MediaPlayerBuilder<'a> {
+
+    pub fn flags(mut self, flags: MediaPlayerFlags) -> MediaPlayerBuilder<'a> {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn ex_flags(mut self, flags: u32) -> MediaPlayerBuilder<'a> {
+        self.ex_flags = flags;
+        self
+    }
+
+    pub fn size(mut self, size: (i32, i32)) -> MediaPlayerBuilder<'a> {
+        self.size = size;
+        self
+    }
+
+    pub fn position(mut self, pos: (i32, i32)) -> MediaPlayerBuilder<'a> {
+        self.position = pos;
+        self
+    }
+
+    pub fn playbar(mut self, playbar: bool) -> MediaPlayerBuilder<'a> {
+        self.playbar = playbar;
+        self
+    }
+
+    pub fn source(mut self, source: Option<&'a str>) -> MediaPlayerBuilder<'a> {
+        self.source = source;
+        self
+    }
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> MediaPlayerBuilder<'a> {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn build(self, out: &mut MediaPlayer) -> Result<(), NwgError> {
+        let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+
+        let parent = match self.parent {
+            Some(p) => Ok(p),
+            None => Err(NwgError::no_parent("MediaPlayer"))
+        }?;
+
+        *out = Default::default();
+
+        out.handle = ControlBase::build_hwnd()
+            .class_name(out.class_name())
+            .forced_flags(out.forced_flags())
+            .flags(flags)
+            .ex_flags(self.ex_flags)
+            .size(self.size)
+            .position(self.position)
+            .text("")
+            .parent(Some(parent))
+            .build()?;
+
+        let handle = out.handle.hwnd().unwrap();
+        out.player = Some(unsafe { mp::create_player(handle, self.playbar)? });
+        out.bind_events()?;
+
+        if let Some(source) = self.source {
+            out.open(source)?;
+        }
+
+        Ok(())
+    }
+
+}
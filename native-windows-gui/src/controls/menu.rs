@@ -1,5 +1,5 @@
 use crate::win32::menu as mh;
-use crate::NwgError;
+use crate::{NwgError, Bitmap};
 use super::{ControlBase, ControlHandle};
 use std::ptr;
 
@@ -32,6 +32,10 @@ bitflags! {
         * ANIMATE_LEFT_TO_RIGHT:  Animates the menu from left to right. 
         * ANIMATE_BOTTOM_TO_TOP:  Animates the menu from bottom to top. 
         * ANIMATE_TOP_TO_BOTTOM: Animates the menu from top to bottom. 
+
+        Behaviour flags:
+
+        * RETURN_COMMAND: The selected menu item is returned by `PopupMenu::popup_at_with_flags` instead of raising a `OnMenuItemSelected` event.
     */
     pub struct PopupMenuFlags: u32 {
         const ALIGN_LEFT = 0x0000;
@@ -50,6 +54,8 @@ bitflags! {
         const ANIMATE_LEFT_TO_RIGHT = 0x4000;
         const ANIMATE_BOTTOM_TO_TOP = 0x2000;
         const ANIMATE_TOP_TO_BOTTOM = 0x1000;
+
+        const RETURN_COMMAND = 0x0100;
     }
 }
 
@@ -62,10 +68,12 @@ bitflags! {
       - text: The text of the menu
       - disabled: If the menu can be selected by the user
       - popup: The menu is a context menu
+      - position: Insert the menu at this position in its parent instead of appending it
       - parent: A top level window, a menu or None. With a top level window, the menu is added to the menu bar if popup is set to false.
     
     **Control events:**
       - OnMenuOpen: Sent when a drop-down menu or submenu is about to become active. 
+      - OnMenuClose: Sent when a drop-down menu or submenu is closed.
       - OnMenuHover: When the user hovers the menu
       - OnMenuEnter: When the user enters the menu. Technically, when the user enters the menu modal loop.
       - OnMenuExit: When the menu is closed. Technically, when the user exits the menu modal loop.
@@ -104,63 +112,166 @@ impl Menu {
             text: "Menu",
             disabled: false,
             popup: false,
+            position: None,
             parent: None
         }
     }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (parent_handle, handle) = match self.handle {
-            ControlHandle::Menu(parent, menu) => (parent, menu),
-            ControlHandle::PopMenu(_, _) => { return true; },
-            _ => panic!("{}", BAD_HANDLE)
-        };
+    try_accessors! {
+        errors = "Returns an `InvalidHandle` error if the menu is not bound.";
 
-        unsafe { mh::is_menu_enabled(parent_handle, handle) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, handle) = match self.handle {
+                ControlHandle::Menu(parent, menu) => (parent, menu),
+                ControlHandle::PopMenu(_, _) => { return Ok(true); },
+                _ => return Err(NwgError::invalid_handle(BAD_HANDLE))
+            };
 
-    /// Enable or disable the control
-    /// A popup menu cannot be disabled
-    pub fn set_enabled(&self, v: bool) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (parent_handle, handle) = match self.handle {
-            ControlHandle::Menu(parent, menu) => (parent, menu),
-            ControlHandle::PopMenu(_, _) => { return; },
-            _ => panic!("{}", BAD_HANDLE)
-        };
+            Ok(unsafe { mh::is_menu_enabled(parent_handle, handle) })
+        }
 
-        unsafe { mh::enable_menu(parent_handle, handle, v); }
-    }
+        /// Enable or disable the control
+        /// A popup menu cannot be disabled
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, handle) = match self.handle {
+                ControlHandle::Menu(parent, menu) => (parent, menu),
+                ControlHandle::PopMenu(_, _) => { return Ok(()); },
+                _ => return Err(NwgError::invalid_handle(BAD_HANDLE))
+            };
+
+            unsafe { 
+                mh::enable_menu(parent_handle, handle, v);
+                mh::redraw_menu_bar(parent_handle);
+            }
+
+            Ok(())
+        }
 
-    /// Show a popup menu as the selected position. Do nothing for menubar menu.
-    pub fn popup_with_flags(&self, x: i32, y: i32, flags: PopupMenuFlags) {
-        use winapi::um::winuser::{TrackPopupMenu, SetForegroundWindow};
-        use winapi::ctypes::c_int;
+        /// Return the text of the menu. Popup menus do not have a text and return an empty string.
+        pub fn text(&self) -> String => try_text {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, handle) = match self.handle {
+                ControlHandle::Menu(parent, menu) => (parent, menu),
+                ControlHandle::PopMenu(_, _) => { return Ok(String::new()); },
+                _ => return Err(NwgError::invalid_handle(BAD_HANDLE))
+            };
+
+            unsafe { 
+                let index = mh::menu_index_in_parent(parent_handle, handle);
+                Ok(mh::menu_item_text(parent_handle, index, true))
+            }
+        }
 
-        if self.handle.blank() { panic!("Menu is not bound"); }
-        let (parent_handle, handle) = match self.handle.pop_hmenu() {
-            Some(v) => v,
-            None => { return; }
-        };
+        /// Set the text of the menu. Does nothing for popup menus.
+        pub fn set_text(&self, text: &str) => try_set_text {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, handle) = match self.handle {
+                ControlHandle::Menu(parent, menu) => (parent, menu),
+                ControlHandle::PopMenu(_, _) => { return Ok(()); },
+                _ => return Err(NwgError::invalid_handle(BAD_HANDLE))
+            };
+
+            unsafe { 
+                let index = mh::menu_index_in_parent(parent_handle, handle);
+                mh::set_menu_item_text(parent_handle, index, true, text);
+                mh::redraw_menu_bar(parent_handle);
+            }
+
+            Ok(())
+        }
+
+        /**
+            Turn `items` into a group of radio items: each item displays a bullet instead of a check mark,
+            and only the item at index `checked` in `items` is checked. Set `checked` to `None` to uncheck every item.
+
+            Menus do not update the group when an item is clicked, so this must be called again when the selection changes.
+
+            Panics if one of the items is not a direct child of this menu.
+        */
+        pub fn set_radio_group(&self, items: &[&MenuItem], checked: Option<usize>) => try_set_radio_group {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let handle = match self.handle {
+                ControlHandle::Menu(_, menu) => menu,
+                ControlHandle::PopMenu(_, menu) => menu,
+                _ => return Err(NwgError::invalid_handle(BAD_HANDLE))
+            };
+
+            for (index, item) in items.iter().enumerate() {
+                if item.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+                let (parent_handle, id) = item.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+                if parent_handle != handle {
+                    return Err(NwgError::invalid_handle("Menu item is not a child of the radio group menu"));
+                }
+
+                unsafe {
+                    mh::set_menu_item_radio(handle, id, true);
+                    match checked == Some(index) {
+                        true => mh::check_menu_radio_item(handle, id),
+                        false => mh::check_menu_item(handle, id, false)
+                    }
+                }
+            }
+
+            Ok(())
+        }
 
-        unsafe { 
-            SetForegroundWindow(parent_handle);
-            TrackPopupMenu(
-                handle,
-                flags.bits(),
-                x as c_int,
-                y as c_int,
-                0,
-                parent_handle,
-                ptr::null()
-            );
+        /// Show a popup menu as the selected position. Do nothing for menubar menu.
+        pub fn popup_with_flags(&self, x: i32, y: i32, flags: PopupMenuFlags) => try_popup_with_flags {
+            use winapi::um::winuser::{TrackPopupMenu, SetForegroundWindow};
+            use winapi::ctypes::c_int;
+
+            if self.handle.blank() { return Err(NwgError::invalid_handle("Menu is not bound")); }
+            let (parent_handle, handle) = match self.handle.pop_hmenu() {
+                Some(v) => v,
+                None => { return Ok(()); }
+            };
+
+            unsafe { 
+                SetForegroundWindow(parent_handle);
+                TrackPopupMenu(
+                    handle,
+                    flags.bits(),
+                    x as c_int,
+                    y as c_int,
+                    0,
+                    parent_handle,
+                    ptr::null()
+                );
+            }
+
+            Ok(())
+        }
+
+        /// Show a popup menu as the selected position. Do nothing for menubar menu.
+        pub fn popup(&self, x: i32, y: i32) => try_popup {
+            self.try_popup_with_flags(x, y, PopupMenuFlags::empty())
         }
     }
 
-    /// Show a popup menu as the selected position. Do nothing for menubar menu.
-    pub fn popup(&self, x: i32, y: i32) {
-        self.popup_with_flags(x, y, PopupMenuFlags::empty())
+    /// Remove the menu from its parent and destroy it, along with its children. 
+    /// The menu cannot be used after this call.
+    pub fn remove(&mut self) {
+        self.try_remove().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `remove`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the menu is not bound.
+    pub fn try_remove(&mut self) -> Result<(), NwgError> {
+        if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+        if let ControlHandle::Menu(parent_handle, handle) = self.handle {
+            unsafe { 
+                mh::remove_menu(parent_handle, handle);
+                mh::redraw_menu_bar(parent_handle);
+            }
+        }
+
+        self.handle.destroy();
+
+        Ok(())
     }
 
 }
@@ -175,6 +286,7 @@ pub struct MenuBuilder<'a> {
     text: &'a str,
     disabled: bool,
     popup: bool,
+    position: Option<u32>,
     parent: Option<ControlHandle>
 }
 
@@ -195,6 +307,11 @@ impl<'a> MenuBuilder<'a> {
         self
     }
 
+    pub fn position(mut self, position: Option<u32>) -> MenuBuilder<'a> {
+        self.position = position;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> MenuBuilder<'a> {
         self.parent = Some(p.into());
         self
@@ -209,6 +326,7 @@ impl<'a> MenuBuilder<'a> {
             .text(self.text)
             .item(false)
             .popup(self.popup)
+            .position(self.position)
             .parent(self.parent.unwrap())
             .build()?;
 
@@ -221,6 +339,244 @@ impl<'a> MenuBuilder<'a> {
 }
 
 
+/**
+    A menu bar that is not created by a window. Menus are added to it like a regular `Menu`, and the menu bar can then be
+    displayed in a window with `Window::set_menu_bar`. This can be used to switch the menus of a window depending on the document type.
+
+    A menu bar can be attached to many windows at the same time. Dropping the menu bar removes it from the windows that use it.
+
+    Requires the `menu` feature.
+
+    **Builder parameters:**
+      - window: A window that will display the menu bar once it is built
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn text_menus(bar: &mut nwg::MenuBar, file: &mut nwg::Menu, window: &nwg::Window) -> Result<(), nwg::NwgError> {
+        nwg::MenuBar::builder()
+            .build(bar)?;
+
+        nwg::Menu::builder()
+            .text("&File")
+            .parent(&*bar)
+            .build(file)?;
+
+        window.set_menu_bar(Some(&*bar));
+
+        Ok(())
+    }
+    ```
+*/
+#[derive(Default, PartialEq, Eq)]
+pub struct MenuBar {
+    pub handle: ControlHandle
+}
+
+impl MenuBar {
+
+    pub fn builder() -> MenuBarBuilder {
+        MenuBarBuilder {
+            window: None
+        }
+    }
+
+}
+
+impl Drop for MenuBar {
+    fn drop(&mut self) {
+        if let Some((_, menu)) = self.handle.hmenu() {
+            unsafe { mh::detach_menu_bar(menu); }
+        }
+
+        self.handle.destroy();
+    }
+}
+
+pub struct MenuBarBuilder {
+    window: Option<ControlHandle>
+}
+
+impl MenuBarBuilder {
+
+    pub fn window<C: Into<ControlHandle>>(mut self, w: C) -> MenuBarBuilder {
+        self.window = Some(w.into());
+        self
+    }
+
+    pub fn build(self, bar: &mut MenuBar) -> Result<(), NwgError> {
+        let window = match self.window {
+            Some(ControlHandle::Hwnd(hwnd)) => Some(hwnd),
+            Some(_) => { return Err(NwgError::menu_create("MenuBar window must be a window")); },
+            None => None
+        };
+
+        *bar = Default::default();
+        bar.handle = unsafe { mh::create_menu_bar()? };
+
+        if let (Some(hwnd), Some((_, menu))) = (window, bar.handle.hmenu()) {
+            unsafe { mh::set_menu_bar(hwnd, menu); }
+        }
+
+        Ok(())
+    }
+}
+
+
+/** 
+    A context menu that is not attached to a window menu bar. Menu items and submenus are added to it like a regular `Menu`.
+
+    Unlike `Menu::popup`, the menu can be shown at the cursor position, which makes it a good fit for the `OnContextMenu` event.
+    The menu can also return the item selected by the user instead of raising an event (see `popup_at_with_flags`).
+
+    Requires the `menu` feature. 
+
+    **Builder parameters:**
+      - parent: **Required.** The window that receives the menu events.
+
+    **Control events:**
+      - OnMenuOpen: Sent when the menu or a submenu is about to become active. 
+      - OnMenuClose: Sent when the menu or a submenu is closed.
+      - OnMenuEnter: When the menu is shown
+      - OnMenuExit: When the menu is closed
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn context_menu(menu: &mut nwg::PopupMenu, item: &mut nwg::MenuItem, window: &nwg::Window) -> Result<(), nwg::NwgError> {
+        nwg::PopupMenu::builder()
+            .parent(window)
+            .build(menu)?;
+
+        nwg::MenuItem::builder()
+            .text("Copy")
+            .parent(&*menu)
+            .build(item)
+    }
+
+    fn on_context_menu(menu: &nwg::PopupMenu, item: &nwg::MenuItem) {
+        match menu.popup_at_cursor_with_flags(nwg::PopupMenuFlags::RETURN_COMMAND) {
+            Some(handle) if handle == item.handle => println!("Copy"),
+            _ => {}
+        }
+    }
+    ```
+*/
+#[derive(Default, PartialEq, Eq)]
+pub struct PopupMenu {
+    pub handle: ControlHandle
+}
+
+impl PopupMenu {
+
+    pub fn builder() -> PopupMenuBuilder {
+        PopupMenuBuilder {
+            parent: None
+        }
+    }
+
+    try_accessors! {
+        errors = "Returns an `InvalidHandle` error if the popup menu is not bound.";
+
+        /// Show the menu at the selected position, in screen coordinates.
+        pub fn popup_at(&self, x: i32, y: i32) => try_popup_at {
+            self.try_popup_at_with_flags(x, y, PopupMenuFlags::empty())?;
+            Ok(())
+        }
+
+        /// Show the menu at the current cursor position.
+        pub fn popup_at_cursor(&self) => try_popup_at_cursor {
+            self.try_popup_at_cursor_with_flags(PopupMenuFlags::empty())?;
+            Ok(())
+        }
+
+        /// Show the menu at the current cursor position. See `popup_at_with_flags`.
+        pub fn popup_at_cursor_with_flags(&self, flags: PopupMenuFlags) -> Option<ControlHandle> => try_popup_at_cursor_with_flags {
+            use winapi::um::winuser::GetCursorPos;
+            use winapi::shared::windef::POINT;
+
+            let mut p = POINT { x: 0, y: 0 };
+            unsafe { GetCursorPos(&mut p); }
+
+            self.try_popup_at_with_flags(p.x, p.y, flags)
+        }
+
+        /**
+            Show the menu at the selected position, in screen coordinates. This function blocks until the menu is closed.
+
+            If `flags` contains `RETURN_COMMAND`, no `OnMenuItemSelected` event is raised and the handle of the
+            selected menu item is returned instead. Returns `None` if the user closed the menu without selecting an item or
+            if `RETURN_COMMAND` is not set.
+        */
+        pub fn popup_at_with_flags(&self, x: i32, y: i32, flags: PopupMenuFlags) -> Option<ControlHandle> => try_popup_at_with_flags {
+            use winapi::um::winuser::{TrackPopupMenuEx, SetForegroundWindow, PostMessageW, WM_NULL};
+            use winapi::ctypes::c_int;
+
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, handle) = self.handle.pop_hmenu().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            let id = unsafe { 
+                SetForegroundWindow(parent_handle);
+                let id = TrackPopupMenuEx(
+                    handle,
+                    flags.bits(),
+                    x as c_int,
+                    y as c_int,
+                    parent_handle,
+                    ptr::null_mut()
+                );
+
+                // Required for the menu to close properly if the window was not in the foreground (ex: tray menus)
+                PostMessageW(parent_handle, WM_NULL, 0, 0);
+
+                id
+            };
+
+            if !flags.contains(PopupMenuFlags::RETURN_COMMAND) || id <= 0 {
+                return Ok(None);
+            }
+
+            let id = id as u32;
+            Ok(unsafe { mh::find_menu_item_parent(handle, id) }
+                .map(|parent| ControlHandle::MenuItem(parent, id)))
+        }
+    }
+
+}
+
+impl Drop for PopupMenu {
+    fn drop(&mut self) {
+        self.handle.destroy();
+    }
+}
+
+pub struct PopupMenuBuilder {
+    parent: Option<ControlHandle>
+}
+
+impl PopupMenuBuilder {
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> PopupMenuBuilder {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn build(self, menu: &mut PopupMenu) -> Result<(), NwgError> {
+        let parent = match self.parent {
+            Some(ControlHandle::Hwnd(hwnd)) => ControlHandle::Hwnd(hwnd),
+            Some(_) => { return Err(NwgError::menu_create("PopupMenu parent must be a window")); },
+            None => { return Err(NwgError::no_parent_menu()); }
+        };
+
+        menu.handle = ControlBase::build_hmenu()
+            .popup(true)
+            .parent(parent)
+            .build()?;
+
+        Ok(())
+    }
+}
+
 /** 
     A windows menu item. Can be added to a menubar or another menu.
 
@@ -230,11 +586,17 @@ impl<'a> MenuBuilder<'a> {
       - text: The text of the menu item
       - disabled: If the item can be selected by the user
       - check: If the item should have a check mark next to it.
+      - bitmap: An image displayed next to the item text. See `MenuItem::set_bitmap`.
+      - owner_draw: If the item is drawn by the application. See `MenuItem::set_owner_draw`.
+      - accelerator: Register the shortcut written after a tab in the text as a keyboard accelerator. See `MenuItem::register_accelerator`.
+      - position: Insert the item at this position in its parent instead of appending it
       - parent: A top level window or a menu. With a top level window, the menu item is added to the menu bar.
 
    **Control events:**
       - OnMenuItemSelected: When a menu item is selected. This can be done by clicking or using the hot-key.
       - OnMenuHover: When the user hovers the menu
+      - OnMenuItemMeasure: When the system needs the size of an owner drawn menu item
+      - OnMenuItemDraw: When an owner drawn menu item must be painted
 
 
     **Menu Access Keys**
@@ -271,46 +633,187 @@ impl MenuItem {
             text: "Menu Item",
             disabled: false,
             check: false,
+            bitmap: None,
+            owner_draw: false,
+            accelerator: false,
+            position: None,
             parent: None
         }
     }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (parent_handle, id) = self.handle.hmenu_item().expect(BAD_HANDLE);
-        
-        unsafe { mh::is_menuitem_enabled(parent_handle, None, Some(id)) }
-    }
+    try_accessors! {
+        errors = "Returns an `InvalidHandle` error if the menu item is not bound.";
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (parent_handle, id) = self.handle.hmenu_item().expect(BAD_HANDLE);
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
 
-        unsafe { mh::enable_menuitem(parent_handle, None, Some(id), v); }
-    }
+            Ok(unsafe { mh::is_menuitem_enabled(parent_handle, None, Some(id)) })
+        }
+
+        /// Enable or disable the control. A disabled menu item is grayed out.
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            unsafe {
+                mh::enable_menuitem(parent_handle, None, Some(id), v);
+                mh::redraw_menu_bar(parent_handle);
+            }
+
+            Ok(())
+        }
+
+        /// Return the text of the menu item
+        pub fn text(&self) -> String => try_text {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            Ok(unsafe { mh::menu_item_text(parent_handle, id, false) })
+        }
+
+        /// Set the text of the menu item
+        pub fn set_text(&self, text: &str) => try_set_text {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            unsafe {
+                mh::set_menu_item_text(parent_handle, id, false, text);
+                mh::redraw_menu_bar(parent_handle);
+
+                if mh::accelerator_registered(id) {
+                    mh::register_accelerator(parent_handle, id);
+                }
+            }
+
+            Ok(())
+        }
+
+        /**
+            Register the shortcut written after a tab in the item text (ex: `"&Save\tCtrl+S"`) as a keyboard accelerator.
+            Pressing the shortcut raises `OnMenuItemSelected` for this item, like a click would. 
+            The accelerator is updated when the text changes with `set_text`.
+
+            Accelerators are active in every window of the thread and do nothing while the item is disabled.
+            Supported keys are letters, digits, `F1`-`F24`, `Plus`, `Minus`, `Del`, `Ins`, `Home`, `End`, `PgUp`, `PgDn`,
+            `Enter`, `Esc`, `Tab`, `Space`, `Backspace` and the arrow keys, combined with `Ctrl`, `Shift` and `Alt`.
+
+            Returns `false` if the text does not contain a valid shortcut.
+        */
+        pub fn register_accelerator(&self) -> bool => try_register_accelerator {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
 
-    /// Sets the check state of a menu item
-    pub fn set_checked(&self, check: bool) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (parent_handle, id) = self.handle.hmenu_item().expect(BAD_HANDLE);
+            Ok(unsafe { mh::register_accelerator(parent_handle, id) })
+        }
+
+        /// Remove the keyboard accelerator of the item. Does nothing if the item has no accelerator.
+        pub fn unregister_accelerator(&self) => try_unregister_accelerator {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (_parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            unsafe { mh::unregister_accelerator(id); }
+
+            Ok(())
+        }
+
+        /// Returns `true` if the item has a registered keyboard accelerator
+        pub fn has_accelerator(&self) -> bool => try_has_accelerator {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (_parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            Ok(mh::accelerator_registered(id))
+        }
+
+        /**
+            Sets if the menu item is drawn by the application. An owner drawn item raises `OnMenuItemMeasure`
+            when the menu is opened and `OnMenuItemDraw` each time it must be painted. The events are sent to
+            the window that owns the menu.
+
+            The text of the item is kept and can be read with `MenuItemDrawData::text`, but the item has no default visual.
+        */
+        pub fn set_owner_draw(&self, owner_draw: bool) => try_set_owner_draw {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            unsafe {
+                mh::set_menu_item_owner_draw(parent_handle, id, owner_draw);
+                mh::redraw_menu_bar(parent_handle);
+            }
+
+            Ok(())
+        }
+
+        /// Returns `true` if the menu item is drawn by the application
+        pub fn owner_draw(&self) -> bool => try_owner_draw {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            Ok(unsafe { mh::menu_item_owner_draw(parent_handle, id) })
+        }
+
+        /// Sets the check state of a menu item
+        pub fn set_checked(&self, check: bool) => try_set_checked {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            unsafe { mh::check_menu_item(parent_handle, id, check); }
+
+            Ok(())
+        }
+
+        /// Returns the check state of a menu item
+        pub fn checked(&self) -> bool => try_checked {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            Ok(unsafe { mh::menu_item_checked(parent_handle, id) })
+        }
+
+        /// Sets the image displayed next to the menu item text. Set `bitmap` to `None` to remove the image.
+        ///
+        /// The menu does not take ownership of the bitmap, so it must stay alive as long as it is displayed.
+        /// Use a 32 bits bitmap for transparency. Icons can be converted using `Icon::copy_as_bitmap`.
+        /// The small icon size (16x16 at 96 dpi) matches the size of the standard menu images.
+        pub fn set_bitmap(&self, bitmap: Option<&Bitmap>) => try_set_bitmap {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            let bitmap = bitmap.map(|b| b.handle as _).unwrap_or(ptr::null_mut());
+            unsafe { mh::set_menu_item_bitmap(parent_handle, id, bitmap); }
+
+            Ok(())
+        }
+    }
 
-        unsafe { mh::check_menu_item(parent_handle, id, check); }
+    /// Remove the menu item from its parent. The menu item cannot be used after this call.
+    pub fn remove(&mut self) {
+        self.try_remove().unwrap_or_else(|e| panic!("{}", e))
     }
 
-    /// Returns the check state of a menu item
-    pub fn checked(&self) -> bool {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let (parent_handle, id) = self.handle.hmenu_item().expect(BAD_HANDLE);
+    /// Same as `remove`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the menu item is not bound.
+    pub fn try_remove(&mut self) -> Result<(), NwgError> {
+        if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+        let (parent_handle, id) = self.handle.hmenu_item().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+        unsafe { mh::unregister_accelerator(id); }
+        self.handle.destroy();
+        unsafe { mh::redraw_menu_bar(parent_handle); }
 
-        unsafe { mh::menu_item_checked(parent_handle, id) }
+        Ok(())
     }
 
 }
 
 impl Drop for MenuItem {
     fn drop(&mut self) {
+        if let Some((_, id)) = self.handle.hmenu_item() {
+            unsafe { mh::unregister_accelerator(id); }
+        }
+
         self.handle.destroy();
     }
 }
@@ -319,6 +822,10 @@ pub struct MenuItemBuilder<'a> {
     text: &'a str,
     disabled: bool,
     check: bool,
+    bitmap: Option<&'a Bitmap>,
+    owner_draw: bool,
+    accelerator: bool,
+    position: Option<u32>,
     parent: Option<ControlHandle>
 }
 
@@ -339,6 +846,26 @@ impl<'a> MenuItemBuilder<'a> {
         self
     }
 
+    pub fn bitmap(mut self, bitmap: Option<&'a Bitmap>) -> MenuItemBuilder<'a> {
+        self.bitmap = bitmap;
+        self
+    }
+
+    pub fn owner_draw(mut self, owner_draw: bool) -> MenuItemBuilder<'a> {
+        self.owner_draw = owner_draw;
+        self
+    }
+
+    pub fn accelerator(mut self, accelerator: bool) -> MenuItemBuilder<'a> {
+        self.accelerator = accelerator;
+        self
+    }
+
+    pub fn position(mut self, position: Option<u32>) -> MenuItemBuilder<'a> {
+        self.position = position;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> MenuItemBuilder<'a> {
         self.parent = Some(p.into());
         self
@@ -352,6 +879,7 @@ impl<'a> MenuItemBuilder<'a> {
         item.handle = ControlBase::build_hmenu()
             .text(self.text)
             .item(true)
+            .position(self.position)
             .parent(self.parent.unwrap())
             .build()?;
 
@@ -363,6 +891,18 @@ impl<'a> MenuItemBuilder<'a> {
             item.set_checked(true);
         }
 
+        if self.bitmap.is_some() {
+            item.set_bitmap(self.bitmap);
+        }
+
+        if self.owner_draw {
+            item.set_owner_draw(true);
+        }
+
+        if self.accelerator {
+            item.register_accelerator();
+        }
+
         Ok(())
     }
 }
@@ -373,6 +913,7 @@ impl<'a> MenuItemBuilder<'a> {
     Requires the `menu` feature. 
 
     **Builder parameters:**
+      - position: Insert the separator at this position in its parent instead of appending it
       - parent: A top level window or a menu. With a top level window, the menu item is added to the menu bar.
 
    **Control events:**
@@ -397,6 +938,7 @@ impl MenuSeparator {
 
     pub fn builder() -> MenuSeparatorBuilder {
         MenuSeparatorBuilder {
+            position: None,
             parent: None
         }
     }
@@ -404,11 +946,17 @@ impl MenuSeparator {
 }
 
 pub struct MenuSeparatorBuilder {
+    position: Option<u32>,
     parent: Option<ControlHandle>
 }
 
 impl MenuSeparatorBuilder {
 
+    pub fn position(mut self, position: Option<u32>) -> MenuSeparatorBuilder {
+        self.position = position;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> MenuSeparatorBuilder {
         self.parent = Some(p.into());
         self
@@ -421,6 +969,7 @@ impl MenuSeparatorBuilder {
 
         sep.handle = ControlBase::build_hmenu()
             .separator(true)
+            .position(self.position)
             .parent(self.parent.unwrap())
             .build()?;
 
@@ -1,3 +1,54 @@
+/**
+    Generates the accessors of a control from the body of their `try_` version.
+    The accessor calls the `try_` version and panics if it returns an error.
+
+    The errors documented on the `try_` versions can be replaced with a leading `errors = "...";`
+    for the objects that are not windows.
+
+    ```ignore
+    try_accessors! {
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
+    }
+    ```
+*/
+macro_rules! try_accessors {
+    (@ret) => { () };
+    (@ret $ret:ty) => { $ret };
+
+    (@errors $errors:expr; $(
+        $(#[doc = $doc:expr])*
+        $(#[cfg($cfg:meta)])*
+        pub fn $name:ident $(<$($lt:lifetime),+>)? (&$self:ident $(, $arg:ident: $arg_ty:ty)*) $(-> $ret:ty)? => $try_name:ident $body:block
+    )*) => {$(
+        $(#[doc = $doc])*
+        $(#[cfg($cfg)])*
+        pub fn $name $(<$($lt),+>)? (&$self $(, $arg: $arg_ty)*) $(-> $ret)? {
+            $self.$try_name($($arg),*).unwrap_or_else(|e| panic!("{}", e))
+        }
+
+        #[doc = concat!("Same as `", stringify!($name), "`, but returns an error instead of panicking.")]
+        #[doc = ""]
+        #[doc = $errors]
+        $(#[cfg($cfg)])*
+        pub fn $try_name $(<$($lt),+>)? (&$self $(, $arg: $arg_ty)*) -> Result<try_accessors!(@ret $($ret)?), NwgError> $body
+    )*};
+
+    (errors = $errors:expr; $($items:tt)*) => {
+        try_accessors!(@errors $errors; $($items)*);
+    };
+
+    ($($items:tt)*) => {
+        try_accessors!(@errors concat!(
+            "Returns an `InvalidHandle` error if the control is not bound or if its window was destroyed.\n",
+            "In debug builds, the error is also returned if the control is used from a thread that did not create it."
+        ); $($items)*);
+    };
+}
+
 mod control_handle;
 mod control_base;
 mod window;
@@ -29,6 +80,9 @@ mod track_bar;
 #[cfg(feature = "menu")]
 mod menu;
 
+#[cfg(feature = "menu")]
+mod recent_files_menu;
+
 #[cfg(feature = "timer")]
 mod timer;
 
@@ -62,6 +116,9 @@ mod treeview_iterator;
 #[cfg(feature = "tray-notification")]
 mod tray_notification;
 
+#[cfg(feature = "toast-notification")]
+mod toast_notification;
+
 #[cfg(feature = "message-window")]
 mod message_window;
 
@@ -83,17 +140,32 @@ mod scroll_bar;
 #[cfg(feature = "plotting")]
 mod plotters;
 
+#[cfg(feature = "d2d-canvas")]
+mod d2d_canvas;
+
+#[cfg(feature = "plot-canvas")]
+mod plot_canvas;
+
+#[cfg(feature = "media-player")]
+mod media_player;
+
+#[cfg(feature = "drag-drop")]
+mod drop_target;
+
+#[cfg(feature = "autocomplete")]
+mod auto_complete;
+
 mod handle_from_control;
 
-pub use control_handle::ControlHandle;
+pub use control_handle::{ControlHandle, WeakControl, FreezeGuard};
 pub use control_base::{ControlBase, HwndBuilder, TimerBuilder as BaseTimerBuilder, OtherBuilder};
 pub use window::{Window, WindowBuilder, WindowFlags};
 pub use button::{Button, ButtonBuilder, ButtonFlags};
 pub use check_box::{CheckBox, CheckBoxBuilder, CheckBoxState, CheckBoxFlags};
 pub use radio_button::{RadioButton, RadioButtonBuilder, RadioButtonState, RadioButtonFlags};
 pub use text_input::{TextInput, TextInputBuilder, TextInputFlags};
-pub use label::{Label, LabelBuilder, LabelFlags};
-pub use image_frame::{ImageFrame, ImageFrameBuilder, ImageFrameFlags};
+pub use label::{Label, LabelBuilder, LabelFlags, LabelEllipsis};
+pub use image_frame::{ImageFrame, ImageFrameBuilder, ImageFrameFlags, ImageFrameScaling};
 
 #[cfg(feature = "textbox")]
 pub use text_box::{TextBox, TextBoxBuilder, TextBoxFlags};
@@ -111,10 +183,16 @@ pub use status_bar::{StatusBar, StatusBarBuilder};
 pub use tooltip::{Tooltip, TooltipBuilder, TooltipIcon};
 
 #[cfg(feature = "trackbar")]
-pub use track_bar::{TrackBar, TrackBarBuilder, TrackBarFlags};
+pub use track_bar::{TrackBar, TrackBarBuilder, TrackBarFlags, TrackBarBuddySide};
+
+#[cfg(feature = "menu")]
+pub use menu::{Menu, MenuBuilder, MenuBar, MenuBarBuilder, PopupMenu, PopupMenuBuilder, MenuItem, MenuSeparator, MenuItemBuilder, PopupMenuFlags};
 
 #[cfg(feature = "menu")]
-pub use menu::{Menu, MenuBuilder, MenuItem, MenuSeparator, MenuItemBuilder, PopupMenuFlags};
+pub use recent_files_menu::{RecentFilesMenu, RecentFilesMenuBuilder};
+
+#[cfg(feature = "menu")]
+pub(crate) use recent_files_menu::recent_file;
 
 #[cfg(feature = "menu")]
 pub use control_base::HmenuBuilder;
@@ -137,7 +215,7 @@ pub use combo_box::{ComboBox, ComboBoxFlags, ComboBoxBuilder};
 pub use list_box::{ListBox, ListBoxFlags, ListBoxBuilder};
 
 #[cfg(feature = "datetime-picker")]
-pub use date_picker::{DatePicker, DatePickerValue, DatePickerFlags, DatePickerBuilder};
+pub use date_picker::{DatePicker, DatePickerValue, DatePickerTime, DatePickerFlags, DatePickerBuilder};
 
 #[cfg(feature = "progress-bar")]
 pub use progress_bar::{ProgressBar, ProgressBarState, ProgressBarFlags, ProgressBarBuilder};
@@ -154,6 +232,12 @@ pub use treeview_iterator::TreeViewIterator;
 #[cfg(feature = "tray-notification")]
 pub use tray_notification::{TrayNotificationFlags, TrayNotification, TrayNotificationBuilder};
 
+#[cfg(feature = "tray-notification")]
+pub(crate) use tray_notification::{taskbar_created_message, recreate_tray_icons};
+
+#[cfg(feature = "toast-notification")]
+pub use toast_notification::{ToastNotification, ToastNotificationBuilder, ToastButton};
+
 #[cfg(feature = "message-window")]
 pub use message_window::{MessageWindow, MessageWindowBuilder};
 
@@ -163,6 +247,9 @@ pub use list_view::{ListView, ListViewStyle, ListViewBuilder, ListViewFlags, Lis
 #[cfg(all(feature="list-view", feature="image-list"))]
 pub use list_view::ListViewImageListType;
 
+#[cfg(feature="image-list")]
+pub use button::ButtonImageAlign;
+
 #[cfg(feature = "number-select")]
 pub use number_select::{NumberSelect, NumberSelectBuilder, NumberSelectFlags, NumberSelectData};
 
@@ -178,4 +265,19 @@ pub use scroll_bar::{ScrollBar, ScrollBarBuilder, ScrollBarFlags};
 #[cfg(feature = "plotting")]
 pub use self::plotters::{Plotters, PlottersBuilder, PlottersDrawingArea, PlottersBackend, PlottersError};
 
+#[cfg(feature = "d2d-canvas")]
+pub use d2d_canvas::{D2DCanvas, D2DCanvasBuilder, D2DCanvasFlags, D2DContext};
+
+#[cfg(feature = "plot-canvas")]
+pub use plot_canvas::{PlotCanvas, PlotCanvasBuilder, PlotCanvasFlags, PlotKind, PlotPoint};
+
+#[cfg(feature = "media-player")]
+pub use media_player::{MediaPlayer, MediaPlayerBuilder, MediaPlayerFlags, MediaState};
+
+#[cfg(feature = "drag-drop")]
+pub use drop_target::{DropTarget, DropTargetBuilder};
+
+#[cfg(feature = "autocomplete")]
+pub use auto_complete::{AutoComplete, AutoCompleteBuilder, AutoCompleteFlags, AutoCompleteSource};
+
 pub use handle_from_control::*;
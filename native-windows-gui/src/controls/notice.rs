@@ -87,23 +87,36 @@ impl Notice {
     /// Change the parent window of the notice. This won't update the NoticeSender already created.
     /// Panics if the control is not a window-like control or if the notice was not initialized
     pub fn set_window_handle<C: Into<ControlHandle>>(&mut self, window: C) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        self.try_set_window_handle(window).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `set_window_handle`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the notice is not bound or if `window` is not a window-like control.
+    pub fn try_set_window_handle<C: Into<ControlHandle>>(&mut self, window: C) -> Result<(), NwgError> {
+        if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
 
-        let hwnd = window.into().hwnd().expect("New notice parent is not a window control");
-        let (_, id) = self.handle.notice().expect(BAD_HANDLE);
+        let hwnd = window.into().hwnd().ok_or_else(|| NwgError::invalid_handle("New notice parent is not a window control"))?;
+        let (_, id) = self.handle.notice().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
 
         self.handle = ControlHandle::Notice(hwnd, id);
+
+        Ok(())
     }
 
-    /// Create a new `NoticeSender` bound to this Notice
-    pub fn sender(&self) -> NoticeSender {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        if !self.valid() { panic!("{}", UNUSABLE_NOTICE); }
-        let (hwnd, id) = self.handle.notice().expect(BAD_HANDLE);
+    try_accessors! {
+        errors = "Returns an `InvalidHandle` error if the notice is not bound or if its parent window was destroyed.";
+
+        /// Create a new `NoticeSender` bound to this Notice
+        pub fn sender(&self) -> NoticeSender => try_sender {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            if !self.valid() { return Err(NwgError::invalid_handle(UNUSABLE_NOTICE)); }
+            let (hwnd, id) = self.handle.notice().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
 
-        NoticeSender { 
-            hwnd: hwnd as usize,
-            id,
+            Ok(NoticeSender { 
+                hwnd: hwnd as usize,
+                id,
+            })
         }
     }
 
@@ -1,9 +1,10 @@
 use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP, WS_EX_CONTROLPARENT};
-use std::cell::RefCell;
+use winapi::shared::windef::HWND;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::check_hwnd;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::{NwgError, Font, RawEventHandler, bind_raw_event_handler_inner, unbind_raw_event_handler};
 use super::{ControlBase, ControlHandle, TextInput, Button, ButtonFlags, TextInputFlags};
 
@@ -57,6 +58,36 @@ impl NumberSelectData {
         }
     }
 
+    /**
+        Parse `text` as the new value. Returns `None` if `text` is not a number or if the number is outside of the limits.
+        The decimal separator of a float value can be a dot or the decimal separator of the user locale.
+    */
+    pub fn parse(&self, text: &str) -> Option<NumberSelectData> {
+        let text = text.trim();
+        let mut data = *self;
+
+        match &mut data {
+            NumberSelectData::Int{ value, max, min, ..} => {
+                let v: i64 = text.parse().ok()?;
+                if v < *min || v > *max {
+                    return None;
+                }
+
+                *value = v;
+            },
+            NumberSelectData::Float{ value, max, min, ..} => {
+                let v: f64 = text.replacen(decimal_separator().as_str(), ".", 1).parse().ok()?;
+                if !v.is_finite() || v < *min || v > *max {
+                    return None;
+                }
+
+                *value = v;
+            }
+        }
+
+        Some(data)
+    }
+
     pub fn increase(&mut self) {
         match self {
             NumberSelectData::Int{ value, step, max, ..} => {
@@ -87,11 +118,16 @@ impl Default for NumberSelectData {
 A NumberSelect control is a pair of arrow buttons that the user can click to increment or decrement a value.
 NumberSelect is implemented as a custom control because the one provided by winapi really sucks.
 
+The value can also be typed by the user. Float values are displayed with the decimal separator of the user locale.
+
 Requires the `number-select` feature. 
 
 **Builder parameters:**
   * `parent`:   **Required.** The number select parent container.
-  * `value`:    The default value of the number select
+  * `value_int`, `value_float`: The default value of the number select
+  * `step_int`, `step_float`:   The value added or removed by the arrow buttons
+  * `min_int`, `min_float`, `max_int`, `max_float`: The limits of the value
+  * `decimals`: The number of decimals displayed for a float value
   * `size`:     The number select size.
   * `position`: The number select position.
   * `enabled`:  If the number select can be used by the user. It also has a grayed out look if disabled.
@@ -99,6 +135,7 @@ Requires the `number-select` feature.
   * `font`:     The font used for the number select text
 
 **Control events:**
+  * `OnNumberChanged`: When the value is changed by the user. The event data holds the new value.
   * `MousePress(_)`: Generic mouse press events on the button
   * `OnMouseMove`: Generic mouse mouse event
 
@@ -117,6 +154,7 @@ fn build_number_select(num_select: &mut nwg::NumberSelect, window: &nwg::Window,
 pub struct NumberSelect {
     pub handle: ControlHandle,
     data: Rc<RefCell<NumberSelectData>>,
+    updating: Rc<Cell<bool>>,
     edit: TextInput,
     btn_up: Button,
     btn_down: Button,
@@ -147,85 +185,102 @@ impl NumberSelect {
     /// See [NumberSelectData](enum.NumberSelectData.html) for the possible values
     pub fn set_data(&self, v: NumberSelectData) {
         *self.data.borrow_mut() = v;
-        self.edit.set_text(&v.formatted_value());
-    }
 
-    /// Returns the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+        if let Some(handle) = self.edit.handle.hwnd() {
+            set_display_value(handle, &self.updating, &v);
         }
     }
 
-    /// Sets the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+    try_accessors! {
+        /// Returns the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
+        }
 
-    /// Returns true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+        /// Sets the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-    /// Sets the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+            Ok(())
+        }
 
-    /// Returns true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+        /// Returns true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Sets the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Returns true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            Ok(())
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Returns true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Returns the size of the control in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Sets the size of the control in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+            Ok(())
+        }
 
-    /// Returns the position of the control in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Returns true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Returns the size of the control in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Sets the size of the control in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
+        }
+
+        /// Returns the position of the control in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Sets the position of the control in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
 
-    /// Sets the position of the control in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -246,6 +301,37 @@ impl NumberSelect {
 
 }
 
+/// The value displayed in the number select. Float values use the decimal separator of the user locale.
+fn display_value(data: &NumberSelectData) -> String {
+    let text = data.formatted_value();
+    match data {
+        NumberSelectData::Int { .. } => text,
+        NumberSelectData::Float { .. } => text.replacen('.', &decimal_separator(), 1)
+    }
+}
+
+/// Set the text of the number select without parsing it back
+fn set_display_value(edit: HWND, updating: &Cell<bool>, data: &NumberSelectData) {
+    updating.set(true);
+    unsafe { wh::set_window_text(edit, &display_value(data)); }
+    updating.set(false);
+}
+
+/// The decimal separator of the user locale
+fn decimal_separator() -> String {
+    use winapi::um::winnls::GetLocaleInfoEx;
+    use crate::win32::base_helper::from_utf16;
+    use std::ptr;
+
+    // LOCALE_SDECIMAL. A null locale name is the user default locale.
+    let mut buffer: [u16; 8] = [0; 8];
+    let length = unsafe { GetLocaleInfoEx(ptr::null(), 0x0000000E, buffer.as_mut_ptr(), buffer.len() as _) };
+    match length > 1 {
+        true => from_utf16(&buffer[..(length as usize - 1)]),
+        false => ".".to_string()
+    }
+}
+
 impl Drop for NumberSelect {
 
     fn drop(&mut self) {
@@ -409,7 +495,7 @@ impl<'a> NumberSelectBuilder<'a> {
             .build()?;
 
         TextInput::builder()
-            .text(&self.data.formatted_value())
+            .text(&display_value(&self.data))
             .size((w-19, h))
             .parent(&out.handle)
             .flags(text_flags)
@@ -444,33 +530,48 @@ impl<'a> NumberSelectBuilder<'a> {
         }
 
         let handler_data = out.data.clone();
+        let updating = out.updating.clone();
         let plus_button = out.btn_up.handle.clone();
         let minus_button = out.btn_down.handle.clone();
         let text_handle = out.edit.handle.clone();
 
-        let handler = bind_raw_event_handler_inner(&out.handle, 0x4545, move |_hwnd, msg, w, l| {
-            use winapi::shared::windef::HWND;
-            use winapi::um::winuser::{WM_COMMAND, BN_CLICKED};
-            use winapi::shared::minwindef::HIWORD;
+        let handler = bind_raw_event_handler_inner(&out.handle, 0x4545, move |hwnd, msg, w, l| {
+            use winapi::um::winuser::{WM_COMMAND, BN_CLICKED, EN_CHANGE, EN_KILLFOCUS};
+            use winapi::shared::minwindef::{HIWORD, LPARAM};
             
             match msg {
                 WM_COMMAND => {
                     let handle = ControlHandle::Hwnd(l as HWND);
                     let message = HIWORD(w as u32) as u16;
-                    if message == BN_CLICKED && handle == plus_button {
-                        let mut data = handler_data.borrow_mut();
-                        data.increase();
+                    let edit = text_handle.hwnd().unwrap();
+                    let old = *handler_data.borrow();
 
-                        let handle = text_handle.hwnd().unwrap();
-                        let text = data.formatted_value();
-                        unsafe { wh::set_window_text(handle, &text); }
+                    // The data is not borrowed when the event is raised, so that the handlers can read it
+                    let new = if message == BN_CLICKED && handle == plus_button {
+                        let mut data = old;
+                        data.increase();
+                        set_display_value(edit, &updating, &data);
+                        Some(data)
                     } else if message == BN_CLICKED && handle == minus_button {
-                        let mut data = handler_data.borrow_mut();
+                        let mut data = old;
                         data.decrease();
-
-                        let handle = text_handle.hwnd().unwrap();
-                        let text = data.formatted_value();
-                        unsafe { wh::set_window_text(handle, &text); }
+                        set_display_value(edit, &updating, &data);
+                        Some(data)
+                    } else if message == EN_CHANGE && handle == text_handle && !updating.get() {
+                        // Invalid text is kept while the user types. It is replaced when the control loses the focus.
+                        old.parse(&unsafe { wh::get_window_text(edit) })
+                    } else if message == EN_KILLFOCUS && handle == text_handle {
+                        set_display_value(edit, &updating, &old);
+                        None
+                    } else {
+                        None
+                    };
+
+                    if let Some(data) = new {
+                        *handler_data.borrow_mut() = data;
+                        if data.formatted_value() != old.formatted_value() {
+                            wh::send_message(hwnd, wh::NWG_NUMBER_CHANGED, 0, &data as *const NumberSelectData as LPARAM);
+                        }
                     }
                 },
                 
@@ -0,0 +1,1008 @@
+use winapi::um::winuser::{WS_CHILD, WS_VISIBLE, WS_DISABLED, WS_CLIPCHILDREN, WS_CLIPSIBLINGS};
+use winapi::shared::windef::{HWND, HDC, HFONT};
+use crate::win32::base_helper::{check_hwnd, try_check_hwnd};
+use crate::win32::window_helper as wh;
+use crate::{Canvas, Pen, Brush, Font, HTextAlign, VTextAlign, NwgError, RawEventHandler, unbind_raw_event_handler};
+use super::{ControlBase, ControlHandle};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::ptr;
+
+const NOT_BOUND: &'static str = "PlotCanvas is not yet bound to a winapi object";
+const BAD_HANDLE: &'static str = "INTERNAL ERROR: PlotCanvas handle is not HWND!";
+
+/// Distance in logical pixels under which a data point is considered hovered
+const HOVER_RADIUS: i32 = 10;
+
+
+bitflags! {
+    /**
+        The plot canvas flags
+
+        * NONE:     No flags. Equivalent to a invisible plot canvas.
+        * VISIBLE:  The plot canvas is immediatly visible after creation
+        * DISABLED: The plot canvas cannot be interacted with by the user.
+    */
+    pub struct PlotCanvasFlags: u32 {
+        const NONE = 0;
+        const VISIBLE = WS_VISIBLE;
+        const DISABLED = WS_DISABLED;
+    }
+}
+
+/// How the points of a series are drawn
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PlotKind {
+    /// The points are joined by a line
+    Line,
+
+    /// Each point is a vertical bar starting at 0. Bars of different series are drawn side by side.
+    Bar,
+
+    /// Each point is a small circle
+    Scatter,
+}
+
+/// A data point of a `PlotCanvas`. Sent with the `OnPlotHover` event.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PlotPoint {
+    /// Index of the series
+    pub series: usize,
+
+    /// Index of the point in the series
+    pub index: usize,
+
+    /// The value of the point as `[x, y]`
+    pub value: [f64; 2],
+}
+
+
+struct PlotSeries {
+    name: String,
+    kind: PlotKind,
+    color: [u8; 3],
+    points: Vec<[f64; 2]>,
+}
+
+struct PlotState {
+    series: Vec<PlotSeries>,
+    x_range: Option<[f64; 2]>,
+    y_range: Option<[f64; 2]>,
+    max_points: Option<usize>,
+    background: [u8; 3],
+    grid: bool,
+    font: Option<HFONT>,
+    hover: Option<PlotPoint>,
+    tracking: bool,
+}
+
+/// Position of the plot area and of the axes ticks, in physical pixels
+struct PlotLayout {
+    area: [i32; 4],
+    x_range: [f64; 2],
+    y_range: [f64; 2],
+    x_ticks: (Vec<f64>, f64),
+    y_ticks: (Vec<f64>, f64),
+    text_height: i32,
+}
+
+impl PlotLayout {
+
+    fn position(&self, value: [f64; 2]) -> [i32; 2] {
+        let [left, top, right, bottom] = self.area;
+        let [x0, x1] = self.x_range;
+        let [y0, y1] = self.y_range;
+
+        let x = left as f64 + (value[0] - x0) / (x1 - x0) * (right - left) as f64;
+        let y = bottom as f64 - (value[1] - y0) / (y1 - y0) * (bottom - top) as f64;
+
+        // Keep far away values in the range of GDI coordinates
+        let clamp = |v: f64| v.round().clamp(-100_000.0, 100_000.0) as i32;
+        [clamp(x), clamp(y)]
+    }
+
+}
+
+
+/// Returns a "round" step (1, 2 or 5 times a power of 10) that splits `span` in about `count` parts
+fn nice_step(span: f64, count: i32) -> f64 {
+    let raw = span / count.max(1) as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+
+    let step = match raw / magnitude {
+        n if n <= 1.0 => 1.0,
+        n if n <= 2.0 => 2.0,
+        n if n <= 5.0 => 5.0,
+        _ => 10.0
+    };
+
+    step * magnitude
+}
+
+fn ticks(range: [f64; 2], count: i32) -> (Vec<f64>, f64) {
+    let step = nice_step(range[1] - range[0], count);
+    let mut ticks = Vec::new();
+
+    let mut tick = (range[0] / step).ceil() * step;
+    while tick <= range[1] + step * 1e-9 {
+        ticks.push(tick);
+        tick += step;
+    }
+
+    (ticks, step)
+}
+
+fn format_value(value: f64, step: f64) -> String {
+    let decimals = match step >= 1.0 {
+        true => 0,
+        false => ((-step.log10().floor()) as usize).min(6)
+    };
+
+    // Do not display "-0"
+    let value = match value.abs() < step * 1e-6 {
+        true => 0.0,
+        false => value
+    };
+
+    format!("{:.*}", decimals, value)
+}
+
+/// Makes sure a range is not empty
+fn expand_range(range: [f64; 2]) -> [f64; 2] {
+    let [min, max] = range;
+    match max - min > 0.0 {
+        true => range,
+        false => {
+            let pad = match min.abs() > 0.0 { true => min.abs() * 0.1, false => 1.0 };
+            [min - pad, max + pad]
+        }
+    }
+}
+
+impl PlotState {
+
+    /// The range of all values on one axis. `None` if there are no points.
+    fn data_range(&self, axis: usize) -> Option<[f64; 2]> {
+        self.series.iter()
+            .flat_map(|s| s.points.iter())
+            .map(|p| p[axis])
+            .filter(|v| v.is_finite())
+            .fold(None, |range, v| match range {
+                None => Some([v, v]),
+                Some([min, max]) => Some([min.min(v), max.max(v)])
+            })
+    }
+
+    fn bar_series(&self) -> usize {
+        self.series.iter().filter(|s| s.kind == PlotKind::Bar).count()
+    }
+
+    /// The smallest distance between two consecutive x values of the bar series
+    fn bar_spacing(&self) -> f64 {
+        let mut xs: Vec<f64> = self.series.iter()
+            .filter(|s| s.kind == PlotKind::Bar)
+            .flat_map(|s| s.points.iter().map(|p| p[0]))
+            .filter(|v| v.is_finite())
+            .collect();
+
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        xs.windows(2)
+            .map(|w| w[1] - w[0])
+            .filter(|d| *d > 0.0)
+            .fold(None, |min: Option<f64>, d| Some(min.map(|m| m.min(d)).unwrap_or(d)))
+            .unwrap_or(1.0)
+    }
+
+    fn auto_x_range(&self) -> [f64; 2] {
+        let range = self.data_range(0).unwrap_or([0.0, 1.0]);
+        match self.bar_series() > 0 {
+            true => {
+                let pad = self.bar_spacing() / 2.0;
+                [range[0] - pad, range[1] + pad]
+            },
+            false => expand_range(range)
+        }
+    }
+
+    fn auto_y_range(&self) -> [f64; 2] {
+        let mut range = self.data_range(1).unwrap_or([0.0, 1.0]);
+
+        // Bars start at 0
+        if self.bar_series() > 0 {
+            range = [range[0].min(0.0), range[1].max(0.0)];
+        }
+
+        expand_range(range)
+    }
+
+    fn layout(&self, canvas: &Canvas, size: [i32; 2]) -> PlotLayout {
+        let [width, height] = size;
+        let text_height = canvas.text_size("0")[1] as i32;
+        let (pad, _) = unsafe { crate::win32::high_dpi::logical_to_physical(10, 0) };
+
+        let top = pad;
+        let bottom = (height - text_height - pad).max(top + 1);
+        let y_count = (bottom - top) / (text_height * 3).max(1);
+
+        // Autoscaled y ranges are extended to the closest ticks
+        let (y_range, y_ticks) = match self.y_range {
+            Some(range) => {
+                let range = expand_range(range);
+                (range, ticks(range, y_count))
+            },
+            None => {
+                let range = self.auto_y_range();
+                let step = nice_step(range[1] - range[0], y_count);
+                let range = [(range[0] / step).floor() * step, (range[1] / step).ceil() * step];
+                (range, ticks(range, y_count))
+            }
+        };
+
+        let label_width = y_ticks.0.iter()
+            .map(|v| canvas.text_size(&format_value(*v, y_ticks.1))[0] as i32)
+            .max()
+            .unwrap_or(0);
+
+        let left = label_width + pad;
+        let right = (width - pad * 2).max(left + 1);
+        let x_range = self.x_range.map(expand_range).unwrap_or_else(|| self.auto_x_range());
+        let x_count = (right - left) / (text_height * 6).max(1);
+
+        PlotLayout {
+            area: [left, top, right, bottom],
+            x_range,
+            y_range,
+            x_ticks: ticks(x_range, x_count),
+            y_ticks,
+            text_height,
+        }
+    }
+
+    /// Calls `f` with a canvas over the device context of the control, for text measurements
+    fn with_canvas<T, F: FnOnce(&Canvas) -> T>(&self, hwnd: HWND, f: F) -> T {
+        use winapi::um::winuser::{GetDC, ReleaseDC};
+
+        unsafe {
+            let hdc = GetDC(hwnd);
+            let result = {
+                let font = self.font.map(|handle| Font { handle });
+                let canvas = Canvas::from_hdc(hdc);
+                if let Some(font) = font.as_ref() {
+                    canvas.set_font(font);
+                }
+                f(&canvas)
+            };
+            ReleaseDC(hwnd, hdc);
+            result
+        }
+    }
+
+    fn client_size(hwnd: HWND) -> [i32; 2] {
+        use winapi::um::winuser::GetClientRect;
+        use winapi::shared::windef::RECT;
+
+        let mut r = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+        unsafe { GetClientRect(hwnd, &mut r); }
+        [r.right, r.bottom]
+    }
+
+    /// Returns the position of every point of a series. Bars are positioned at the middle of their top edge.
+    fn series_positions(&self, layout: &PlotLayout, series: usize) -> Vec<[i32; 2]> {
+        let s = &self.series[series];
+        let offset = match s.kind {
+            PlotKind::Bar => {
+                let (bar_width, first) = self.bar_geometry(layout);
+                let bar_index = self.series[..series].iter().filter(|s| s.kind == PlotKind::Bar).count() as i32;
+                first + bar_width * bar_index + bar_width / 2
+            },
+            _ => 0
+        };
+
+        s.points.iter()
+            .map(|p| {
+                let [x, y] = layout.position(*p);
+                [x + offset, y]
+            })
+            .collect()
+    }
+
+    /// Returns the width of a bar and the offset of the first bar from the x value
+    fn bar_geometry(&self, layout: &PlotLayout) -> (i32, i32) {
+        let [left, _, right, _] = layout.area;
+        let [x0, x1] = layout.x_range;
+
+        let group_width = (self.bar_spacing() / (x1 - x0) * (right - left) as f64 * 0.8) as i32;
+        let bar_width = (group_width / self.bar_series().max(1) as i32).max(1);
+        (bar_width, -(bar_width * self.bar_series() as i32) / 2)
+    }
+
+    fn point_at(&self, hwnd: HWND, pos: [i32; 2]) -> Option<PlotPoint> {
+        let size = PlotState::client_size(hwnd);
+        let layout = self.with_canvas(hwnd, |c| self.layout(c, size));
+        let (radius, _) = unsafe { crate::win32::high_dpi::logical_to_physical(HOVER_RADIUS, 0) };
+
+        let mut nearest: Option<(i64, PlotPoint)> = None;
+        for series in 0..self.series.len() {
+            let points = &self.series[series].points;
+            for (index, p) in self.series_positions(&layout, series).into_iter().enumerate() {
+                let (dx, dy) = ((p[0] - pos[0]) as i64, (p[1] - pos[1]) as i64);
+                let distance = dx * dx + dy * dy;
+                if distance > (radius * radius) as i64 || nearest.map(|(d, _)| distance >= d).unwrap_or(false) {
+                    continue;
+                }
+
+                nearest = Some((distance, PlotPoint { series, index, value: points[index] }));
+            }
+        }
+
+        nearest.map(|(_, point)| point)
+    }
+
+    /// Draws the plot. The canvas is created after the pens and brushes because it borrows them.
+    unsafe fn draw(&self, hdc: HDC, size: [i32; 2]) {
+        fn pen(color: [u8; 3], width: u32) -> Pen {
+            let mut pen = Pen::default();
+            let _ = Pen::builder().color(color).width(width).build(&mut pen);
+            pen
+        }
+
+        fn brush(color: [u8; 3]) -> Brush {
+            let mut brush = Brush::default();
+            let _ = Brush::builder().color(color).build(&mut brush);
+            brush
+        }
+
+        let font = self.font.map(|handle| Font { handle });
+        let background = brush(self.background);
+        let grid_pen = pen([220, 220, 220], 1);
+        let axis_pen = pen([100, 100, 100], 1);
+        let line_pens: Vec<Pen> = self.series.iter().map(|s| pen(s.color, 2)).collect();
+        let outline_pens: Vec<Pen> = self.series.iter().map(|s| pen(s.color, 1)).collect();
+        let brushes: Vec<Brush> = self.series.iter().map(|s| brush(s.color)).collect();
+        let hover_pen = pen([30, 30, 30], 2);
+
+        let canvas = Canvas::from_hdc(hdc);
+        if let Some(font) = font.as_ref() {
+            canvas.set_font(font);
+        }
+
+        let layout = self.layout(&canvas, size);
+        let [left, top, right, bottom] = layout.area;
+
+        canvas.fill_rect([0, 0, size[0], size[1]], &background);
+        canvas.set_text_color([60, 60, 60]);
+
+        // Grid and labels
+        let (y_ticks, y_step) = &layout.y_ticks;
+        for y in y_ticks.iter() {
+            let [_, py] = layout.position([layout.x_range[0], *y]);
+            if self.grid {
+                canvas.set_pen(&grid_pen);
+                canvas.line([left, py], [right, py]);
+            }
+
+            let r = [0, py - layout.text_height, left - 5, py + layout.text_height];
+            canvas.draw_text(&format_value(*y, *y_step), r, HTextAlign::Right, VTextAlign::Center);
+        }
+
+        let (x_ticks, x_step) = &layout.x_ticks;
+        for x in x_ticks.iter() {
+            let [px, _] = layout.position([*x, layout.y_range[0]]);
+            if self.grid {
+                canvas.set_pen(&grid_pen);
+                canvas.line([px, top], [px, bottom]);
+            }
+
+            let r = [px - 100, bottom + 2, px + 100, size[1]];
+            canvas.draw_text(&format_value(*x, *x_step), r, HTextAlign::Center, VTextAlign::Top);
+        }
+
+        // Series
+        canvas.clip_rect([left, top, right + 1, bottom + 1]);
+
+        let (bar_width, _) = self.bar_geometry(&layout);
+        let [_, zero] = layout.position([0.0, 0.0f64.max(layout.y_range[0]).min(layout.y_range[1])]);
+
+        for (i, s) in self.series.iter().enumerate() {
+            let positions = self.series_positions(&layout, i);
+            match s.kind {
+                PlotKind::Line => {
+                    canvas.set_pen(&line_pens[i]);
+                    canvas.polyline(&positions);
+                },
+                PlotKind::Scatter => {
+                    canvas.set_pen(&outline_pens[i]);
+                    canvas.set_brush(&brushes[i]);
+                    for [x, y] in positions {
+                        canvas.ellipse([x - 3, y - 3, x + 4, y + 4]);
+                    }
+                },
+                PlotKind::Bar => {
+                    for [x, y] in positions {
+                        let left = x - bar_width / 2;
+                        canvas.fill_rect([left, y.min(zero), left + bar_width, y.max(zero)], &brushes[i]);
+                    }
+                }
+            }
+        }
+
+        if let Some(hover) = self.hover {
+            if let Some(&[x, y]) = self.series_positions(&layout, hover.series).get(hover.index) {
+                canvas.set_pen(&hover_pen);
+                canvas.set_brush(&brushes[hover.series]);
+                canvas.ellipse([x - 5, y - 5, x + 6, y + 6]);
+            }
+        }
+
+        canvas.reset_clip();
+
+        // Axes
+        canvas.set_pen(&axis_pen);
+        canvas.polyline(&[[left, top], [left, bottom], [right, bottom]]);
+
+        // Legend
+        let mut legend_top = top + 5;
+        for (i, s) in self.series.iter().enumerate().filter(|(_, s)| !s.name.is_empty()) {
+            let [text_width, _] = canvas.text_size(&s.name);
+            let text_left = right - 5 - text_width as i32;
+            let square = layout.text_height / 2;
+            let square_top = legend_top + (layout.text_height - square) / 2;
+
+            canvas.fill_rect([text_left - square - 5, square_top, text_left - 5, square_top + square], &brushes[i]);
+            canvas.text([text_left, legend_top], &s.name);
+            legend_top += layout.text_height;
+        }
+    }
+
+    fn paint(&self, hwnd: HWND) {
+        use winapi::um::wingdi::{CreateCompatibleDC, CreateCompatibleBitmap, SelectObject, BitBlt, DeleteObject, DeleteDC, SRCCOPY};
+
+        let size = PlotState::client_size(hwnd);
+        let data = crate::PaintData { hwnd };
+        let paint = data.paint();
+        let hdc = paint.hdc();
+
+        // The plot is drawn in a memory bitmap to prevent flickering
+        unsafe {
+            let memory = CreateCompatibleDC(hdc);
+            let bitmap = CreateCompatibleBitmap(hdc, size[0].max(1), size[1].max(1));
+            let old_bitmap = SelectObject(memory, bitmap as _);
+
+            self.draw(memory, size);
+            BitBlt(hdc, 0, 0, size[0], size[1], memory, 0, 0, SRCCOPY);
+
+            SelectObject(memory, old_bitmap);
+            DeleteObject(bitmap as _);
+            DeleteDC(memory);
+        }
+    }
+
+}
+
+
+/**
+    A control that draws line, bar and scatter charts. The plot is drawn with GDI.
+
+    Data is organized in series. Each series has a name (displayed in the legend if it is not empty), a kind and a color.
+    The axes are scaled to fit the data, unless a fixed range is set with `set_x_range` or `set_y_range`.
+    For monitoring tools, `max_points` limits the number of points of each series: `push_point` removes the
+    oldest point of a full series, so the x axis scrolls.
+
+    The control raises `OnPlotHover` when the data point under the mouse changes.
+
+    Requires the `plot-canvas` feature.
+
+    **Builder parameters:**
+      * `parent`:           **Required.** The plot canvas parent container.
+      * `size`:             The plot canvas size.
+      * `position`:         The plot canvas position.
+      * `flags`:            A combination of the PlotCanvasFlags values.
+      * `ex_flags`:         A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
+      * `font`:             The font used for the axes labels and the legend
+      * `background_color`: The background color of the plot
+      * `grid`:             Draw the grid lines at the axes ticks
+      * `max_points`:       The maximum number of points in each series
+      * `x_range`:          A fixed range for the x axis. `None` fits the data.
+      * `y_range`:          A fixed range for the y axis. `None` fits the data.
+
+    **Control events:**
+      * `OnPlotHover`: When the data point under the mouse changes. The event data holds the point, or `None` if the mouse left the point.
+      * `MousePress(_)`: Generic mouse press events on the plot canvas
+      * `OnMouseMove`: Generic mouse mouse event
+      * `OnMouseWheel`: Generic mouse wheel event
+      * `OnResize`: When the plot canvas is resized
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn build_plot(plot: &mut nwg::PlotCanvas, window: &nwg::Window) -> Result<(), nwg::NwgError> {
+        nwg::PlotCanvas::builder()
+            .size((400, 300))
+            .max_points(Some(100))
+            .parent(window)
+            .build(plot)?;
+
+        let cpu = plot.add_series("CPU", nwg::PlotKind::Line, [0, 120, 215]);
+        plot.set_points(cpu, &[[0.0, 10.0], [1.0, 35.0], [2.0, 20.0]]);
+        plot.set_y_range(Some([0.0, 100.0]));
+
+        Ok(())
+    }
+    ```
+*/
+#[derive(Default)]
+pub struct PlotCanvas {
+    pub handle: ControlHandle,
+    state: Option<Rc<RefCell<PlotState>>>,
+    handler: Option<RawEventHandler>,
+}
+
+impl PlotCanvas {
+
+    pub fn builder() -> PlotCanvasBuilder<'static> {
+        PlotCanvasBuilder {
+            size: (300, 200),
+            position: (0, 0),
+            flags: None,
+            ex_flags: 0,
+            font: None,
+            background_color: [255, 255, 255],
+            grid: true,
+            max_points: None,
+            x_range: None,
+            y_range: None,
+            parent: None,
+        }
+    }
+
+    /// Adds a series to the plot and returns its index. If `name` is empty, the series is not displayed in the legend.
+    pub fn add_series(&self, name: &str, kind: PlotKind, color: [u8; 3]) -> usize {
+        self.update(|state| {
+            state.series.push(PlotSeries { name: name.to_string(), kind, color, points: Vec::new() });
+            state.series.len() - 1
+        })
+    }
+
+    /// Removes a series from the plot. The index of the following series is decreased by 1.
+    /// Panics if `series` is out of bounds.
+    pub fn remove_series(&self, series: usize) {
+        self.update(|state| {
+            state.series.remove(series);
+            state.hover = None;
+        })
+    }
+
+    /// Removes all the series from the plot
+    pub fn clear(&self) {
+        self.update(|state| {
+            state.series.clear();
+            state.hover = None;
+        })
+    }
+
+    /// Returns the number of series in the plot
+    pub fn series_len(&self) -> usize {
+        self.state().borrow().series.len()
+    }
+
+    /// Replaces the points of a series. Points are `[x, y]`. Line series are drawn in the order of the points.
+    /// If the number of points is over `max_points`, only the last points are kept.
+    /// Panics if `series` is out of bounds.
+    pub fn set_points(&self, series: usize, points: &[[f64; 2]]) {
+        self.update(|state| {
+            let start = state.max_points.map(|max| points.len().saturating_sub(max)).unwrap_or(0);
+            state.series[series].points = points[start..].to_vec();
+            state.hover = None;
+        })
+    }
+
+    /// Adds a point at the end of a series. If the series has `max_points` points, the first point is removed.
+    /// Panics if `series` is out of bounds.
+    pub fn push_point(&self, series: usize, point: [f64; 2]) {
+        self.update(|state| {
+            let max_points = state.max_points;
+            let points = &mut state.series[series].points;
+            points.push(point);
+
+            if let Some(max) = max_points {
+                if points.len() > max {
+                    let extra = points.len() - max;
+                    points.drain(0..extra);
+                }
+            }
+
+            state.hover = None;
+        })
+    }
+
+    /// Returns the points of a series. Panics if `series` is out of bounds.
+    pub fn points(&self, series: usize) -> Vec<[f64; 2]> {
+        self.state().borrow().series[series].points.clone()
+    }
+
+    /// Returns the maximum number of points in each series
+    pub fn max_points(&self) -> Option<usize> {
+        self.state().borrow().max_points
+    }
+
+    /// Sets the maximum number of points in each series. The first points of the series over the limit are removed.
+    pub fn set_max_points(&self, max_points: Option<usize>) {
+        self.update(|state| {
+            state.max_points = max_points;
+            if let Some(max) = max_points {
+                for s in state.series.iter_mut() {
+                    let extra = s.points.len().saturating_sub(max);
+                    s.points.drain(0..extra);
+                }
+            }
+
+            state.hover = None;
+        })
+    }
+
+    /// Returns the range of the x axis as `[min, max]`. If the range is not fixed, this is the range fitting the data.
+    pub fn x_range(&self) -> [f64; 2] {
+        let state = self.state().borrow();
+        state.x_range.map(expand_range).unwrap_or_else(|| state.auto_x_range())
+    }
+
+    /// Sets a fixed range for the x axis as `[min, max]`. `None` fits the axis to the data.
+    pub fn set_x_range(&self, range: Option<[f64; 2]>) {
+        self.update(|state| state.x_range = range)
+    }
+
+    /// Returns the range of the y axis as `[min, max]`. If the range is not fixed, this is the range fitting the data.
+    /// The drawn axis is extended to the closest ticks.
+    pub fn y_range(&self) -> [f64; 2] {
+        let state = self.state().borrow();
+        state.y_range.map(expand_range).unwrap_or_else(|| state.auto_y_range())
+    }
+
+    /// Sets a fixed range for the y axis as `[min, max]`. `None` fits the axis to the data.
+    pub fn set_y_range(&self, range: Option<[f64; 2]>) {
+        self.update(|state| state.y_range = range)
+    }
+
+    /// Returns the background color of the plot
+    pub fn background_color(&self) -> [u8; 3] {
+        self.state().borrow().background
+    }
+
+    /// Sets the background color of the plot
+    pub fn set_background_color(&self, color: [u8; 3]) {
+        self.update(|state| state.background = color)
+    }
+
+    /// Returns true if the grid lines are drawn
+    pub fn grid(&self) -> bool {
+        self.state().borrow().grid
+    }
+
+    /// Sets if the grid lines are drawn
+    pub fn set_grid(&self, grid: bool) {
+        self.update(|state| state.grid = grid)
+    }
+
+    /// Returns the font of the control
+    pub fn font(&self) -> Option<Font> {
+        self.state().borrow().font.map(|handle| Font { handle })
+    }
+
+    /// Sets the font of the control
+    pub fn set_font(&self, font: Option<&Font>) {
+        self.update(|state| state.font = font.map(|f| f.handle))
+    }
+
+    try_accessors! {
+        /// Returns the position of a value in the control, in physical pixels
+        pub fn value_position(&self, value: [f64; 2]) -> [i32; 2] => try_value_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let state = self.state().borrow();
+            let size = PlotState::client_size(handle);
+
+            Ok(state.with_canvas(handle, |c| state.layout(c, size)).position(value))
+        }
+
+        /// Returns the data point closest to `pos` (in physical pixels in the control) if it is near enough to be hovered
+        pub fn point_at(&self, pos: [i32; 2]) -> Option<PlotPoint> => try_point_at {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(self.state().borrow().point_at(handle, pos))
+        }
+    }
+
+    /// Returns the data point under the mouse
+    pub fn hovered(&self) -> Option<PlotPoint> {
+        self.state().borrow().hover
+    }
+
+    try_accessors! {
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
+
+        /// Set the keyboard focus on the plot canvas
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
+
+            Ok(())
+        }
+
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
+
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return true if the control is visible to the user. Will return true even if the
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the plot canvas in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Return the physical size of plot canvas in pixels considering the dpi scale
+        pub fn physical_size(&self) -> (u32, u32) => try_physical_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_physical_size(handle) })
+        }
+
+        /// Set the size of the plot canvas in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, true) }
+
+            Ok(())
+        }
+
+        /// Return the position of the plot canvas in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the plot canvas in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+    }
+
+    /// Winapi class name used during control creation
+    pub fn class_name(&self) -> &'static str {
+        "NWG_EXTERN_CANVAS"
+    }
+
+    // Winapi base flags used during window creation
+    pub fn flags(&self) -> u32 {
+        WS_VISIBLE
+    }
+
+    /// Winapi flags required by the control
+    pub fn forced_flags(&self) -> u32 {
+        WS_CHILD | WS_CLIPCHILDREN | WS_CLIPSIBLINGS
+    }
+
+    fn state(&self) -> &Rc<RefCell<PlotState>> {
+        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        self.state.as_ref().expect(NOT_BOUND)
+    }
+
+    /// Changes the plot state and redraws the plot
+    fn update<T, F: FnOnce(&mut PlotState) -> T>(&self, f: F) -> T {
+        use winapi::um::winuser::InvalidateRect;
+
+        let result = f(&mut self.state().borrow_mut());
+        unsafe { InvalidateRect(self.handle.hwnd().unwrap(), ptr::null(), 0); }
+        result
+    }
+
+    /// Paints the plot and raises `OnPlotHover` when the data point under the mouse changes
+    fn hook_events(&mut self) {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_PAINT, WM_MOUSEMOVE, WM_MOUSELEAVE, TME_LEAVE, TRACKMOUSEEVENT, TrackMouseEvent, InvalidateRect};
+        use winapi::shared::minwindef::{LPARAM, LOWORD, HIWORD};
+        use std::mem;
+
+        let state = self.state.clone().unwrap();
+        let handler = bind_raw_event_handler_inner(&self.handle, 0, move |hwnd, msg, _w, l| {
+            let hover = match msg {
+                WM_PAINT => {
+                    state.borrow().paint(hwnd);
+                    return Some(0);
+                },
+                WM_MOUSEMOVE => {
+                    let mut state = state.borrow_mut();
+                    if !state.tracking {
+                        let mut track = TRACKMOUSEEVENT {
+                            cbSize: mem::size_of::<TRACKMOUSEEVENT>() as u32,
+                            dwFlags: TME_LEAVE,
+                            hwndTrack: hwnd,
+                            dwHoverTime: 0
+                        };
+
+                        unsafe { TrackMouseEvent(&mut track); }
+                        state.tracking = true;
+                    }
+
+                    let pos = [LOWORD(l as u32) as i16 as i32, HIWORD(l as u32) as i16 as i32];
+                    state.point_at(hwnd, pos)
+                },
+                WM_MOUSELEAVE => {
+                    state.borrow_mut().tracking = false;
+                    None
+                },
+                _ => { return None; }
+            };
+
+            if state.borrow().hover != hover {
+                state.borrow_mut().hover = hover;
+
+                // The state must not be borrowed while the application handles the event
+                unsafe { InvalidateRect(hwnd, ptr::null(), 0); }
+                let data = hover.as_ref().map(|p| p as *const PlotPoint as LPARAM).unwrap_or(0);
+                wh::send_message(hwnd, wh::NWG_PLOT_HOVER, 0, data);
+            }
+
+            None
+        });
+
+        self.handler = Some(handler.unwrap());
+    }
+
+}
+
+impl Drop for PlotCanvas {
+    fn drop(&mut self) {
+        if let Some(h) = self.handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
+
+        self.handle.destroy();
+    }
+}
+
+impl PartialEq for PlotCanvas {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+pub struct PlotCanvasBuilder<'a> {
+    size: (i32, i32),
+    position: (i32, i32),
+    flags: Option<PlotCanvasFlags>,
+    ex_flags: u32,
+    font: Option<&'a Font>,
+    background_color: [u8; 3],
+    grid: bool,
+    max_points: Option<usize>,
+    x_range: Option<[f64; 2]>,
+    y_range: Option<[f64; 2]>,
+    parent: Option<ControlHandle>,
+}
+
+impl<'a> PlotCanvasBuilder<'a> {
+
+    pub fn flags(mut self, flags: PlotCanvasFlags) -> PlotCanvasBuilder<'a> {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn ex_flags(mut self, flags: u32) -> PlotCanvasBuilder<'a> {
+        self.ex_flags = flags;
+        self
+    }
+
+    pub fn size(mut self, size: (i32, i32)) -> PlotCanvasBuilder<'a> {
+        self.size = size;
+        self
+    }
+
+    pub fn position(mut self, pos: (i32, i32)) -> PlotCanvasBuilder<'a> {
+        self.position = pos;
+        self
+    }
+
+    pub fn font(mut self, font: Option<&'a Font>) -> PlotCanvasBuilder<'a> {
+        self.font = font;
+        self
+    }
+
+    pub fn background_color(mut self, color: [u8; 3]) -> PlotCanvasBuilder<'a> {
+        self.background_color = color;
+        self
+    }
+
+    pub fn grid(mut self, grid: bool) -> PlotCanvasBuilder<'a> {
+        self.grid = grid;
+        self
+    }
+
+    pub fn max_points(mut self, max_points: Option<usize>) -> PlotCanvasBuilder<'a> {
+        self.max_points = max_points;
+        self
+    }
+
+    pub fn x_range(mut self, range: Option<[f64; 2]>) -> PlotCanvasBuilder<'a> {
+        self.x_range = range;
+        self
+    }
+
+    pub fn y_range(mut self, range: Option<[f64; 2]>) -> PlotCanvasBuilder<'a> {
+        self.y_range = range;
+        self
+    }
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> PlotCanvasBuilder<'a> {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn build(self, out: &mut PlotCanvas) -> Result<(), NwgError> {
+        let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
+
+        let parent = match self.parent {
+            Some(p) => Ok(p),
+            None => Err(NwgError::no_parent("PlotCanvas"))
+        }?;
+
+        *out = Default::default();
+
+        out.handle = ControlBase::build_hwnd()
+            .class_name(out.class_name())
+            .forced_flags(out.forced_flags())
+            .flags(flags)
+            .ex_flags(self.ex_flags)
+            .size(self.size)
+            .position(self.position)
+            .text("")
+            .parent(Some(parent))
+            .build()?;
+
+        let font = self.font.map(|f| f.handle).or_else(|| Font::global_default().map(|f| f.handle));
+
+        out.state = Some(Rc::new(RefCell::new(PlotState {
+            series: Vec::new(),
+            x_range: self.x_range,
+            y_range: self.y_range,
+            max_points: self.max_points,
+            background: self.background_color,
+            grid: self.grid,
+            font,
+            hover: None,
+            tracking: false,
+        })));
+
+        out.hook_events();
+
+        Ok(())
+    }
+
+}
@@ -6,7 +6,7 @@ that indicates what the button does when the user selects it.
 use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED};
 use winapi::um::commctrl::{PBS_MARQUEE, PBS_VERTICAL};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::check_hwnd;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::NwgError;
 use super::{ControlHandle, ControlBase};
 use std::ops::Range;
@@ -92,197 +92,227 @@ impl ProgressBar {
         }
     }
 
-    /// Return the current state of the progress bar
-    pub fn state(&self) -> ProgressBarState {
-        use winapi::um::commctrl::{PBM_GETSTATE, PBST_NORMAL, PBST_ERROR, PBST_PAUSED};
+    try_accessors! {
+        /// Return the current state of the progress bar
+        pub fn state(&self) -> ProgressBarState => try_state {
+            use winapi::um::commctrl::{PBM_GETSTATE, PBST_NORMAL, PBST_ERROR, PBST_PAUSED};
         
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-
-        match wh::send_message(handle, PBM_GETSTATE, 0, 0) as i32 {
-            PBST_NORMAL => ProgressBarState::Normal,
-            PBST_ERROR => ProgressBarState::Error,
-            PBST_PAUSED => ProgressBarState::Paused,
-            _ => panic!("Unkown progress bar state")
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            Ok(match wh::send_message(handle, PBM_GETSTATE, 0, 0) as i32 {
+                PBST_NORMAL => ProgressBarState::Normal,
+                PBST_ERROR => ProgressBarState::Error,
+                PBST_PAUSED => ProgressBarState::Paused,
+                _ => panic!("Unkown progress bar state")
+            })
         }
-    }
 
-    /// Set the state of the progress bar
-    pub fn set_state(&self, state: ProgressBarState) {
-        use winapi::um::commctrl::{PBM_SETSTATE, PBST_NORMAL, PBST_ERROR, PBST_PAUSED};
-        use winapi::shared::minwindef::WPARAM;
+        /// Set the state of the progress bar
+        pub fn set_state(&self, state: ProgressBarState) => try_set_state {
+            use winapi::um::commctrl::{PBM_SETSTATE, PBST_NORMAL, PBST_ERROR, PBST_PAUSED};
+            use winapi::shared::minwindef::WPARAM;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let state = match state {
-            ProgressBarState::Normal => PBST_NORMAL,
-            ProgressBarState::Error => PBST_ERROR,
-            ProgressBarState::Paused => PBST_PAUSED
-        };
+            let state = match state {
+                ProgressBarState::Normal => PBST_NORMAL,
+                ProgressBarState::Error => PBST_ERROR,
+                ProgressBarState::Paused => PBST_PAUSED
+            };
 
-        wh::send_message(handle, PBM_SETSTATE, state as WPARAM, 0);
-    }
+            wh::send_message(handle, PBM_SETSTATE, state as WPARAM, 0);
+
+            Ok(())
+        }
 
-    /// Increase the bar value by the step value
-    pub fn advance(&self) {
-        use winapi::um::commctrl::PBM_STEPIT;
+        /// Increase the bar value by the step value
+        pub fn advance(&self) => try_advance {
+            use winapi::um::commctrl::PBM_STEPIT;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, PBM_STEPIT, 0, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, PBM_STEPIT, 0, 0);
 
-    /// Increase the bar value by a value
-    pub fn advance_delta(&self, v: u32) {
-        use winapi::um::commctrl::PBM_DELTAPOS;
-        use winapi::shared::minwindef::WPARAM;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, PBM_DELTAPOS, v as WPARAM, 0);
-    }
+        /// Increase the bar value by a value
+        pub fn advance_delta(&self, v: u32) => try_advance_delta {
+            use winapi::um::commctrl::PBM_DELTAPOS;
+            use winapi::shared::minwindef::WPARAM;
 
-    /// Return the step of the progress bar.
-    pub fn step(&self) -> u32 {
-        use winapi::um::commctrl::PBM_GETSTEP;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, PBM_DELTAPOS, v as WPARAM, 0);
+
+            Ok(())
+        }
+
+        /// Return the step of the progress bar.
+        pub fn step(&self) -> u32 => try_step {
+            use winapi::um::commctrl::PBM_GETSTEP;
         
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, PBM_GETSTEP, 0, 0) as u32
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, PBM_GETSTEP, 0, 0) as u32)
+        }
 
-    /// Set the step of the progress bar.
-    pub fn set_step(&self, s: u32) {
-        use winapi::um::commctrl::PBM_SETSTEP;
-        use winapi::shared::minwindef::WPARAM;
+        /// Set the step of the progress bar.
+        pub fn set_step(&self, s: u32) => try_set_step {
+            use winapi::um::commctrl::PBM_SETSTEP;
+            use winapi::shared::minwindef::WPARAM;
         
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, PBM_SETSTEP, s as WPARAM, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, PBM_SETSTEP, s as WPARAM, 0);
+
+            Ok(())
+        }
 
-    /// Return the position of the progress bar.
-    pub fn pos(&self) -> u32 {
-        use winapi::um::commctrl::PBM_GETPOS;
+        /// Return the position of the progress bar.
+        pub fn pos(&self) -> u32 => try_pos {
+            use winapi::um::commctrl::PBM_GETPOS;
         
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, PBM_GETPOS, 0, 0) as u32
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, PBM_GETPOS, 0, 0) as u32)
+        }
 
-    /// Set the position of the progress bar. If the value is outside of range
-    /// sets the value to the nearest bound.
-    pub fn set_pos(&self, p: u32) {
-        use winapi::um::commctrl::PBM_SETPOS;
-        use winapi::shared::minwindef::WPARAM;
+        /// Set the position of the progress bar. If the value is outside of range
+        /// sets the value to the nearest bound.
+        pub fn set_pos(&self, p: u32) => try_set_pos {
+            use winapi::um::commctrl::PBM_SETPOS;
+            use winapi::shared::minwindef::WPARAM;
         
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, PBM_SETPOS, p as WPARAM, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, PBM_SETPOS, p as WPARAM, 0);
+
+            Ok(())
+        }
 
-    /// Get the range of the progress bar
-    pub fn range(&self) -> Range<u32> {
-        use winapi::um::commctrl::PBM_GETRANGE;
+        /// Get the range of the progress bar
+        pub fn range(&self) -> Range<u32> => try_range {
+            use winapi::um::commctrl::PBM_GETRANGE;
         
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
         
-        let low = wh::send_message(handle, PBM_GETRANGE, 1, 0) as u32;
-        let high = wh::send_message(handle, PBM_GETRANGE, 0, 0) as u32;
+            let low = wh::send_message(handle, PBM_GETRANGE, 1, 0) as u32;
+            let high = wh::send_message(handle, PBM_GETRANGE, 0, 0) as u32;
 
-        low..high
-    }
+            Ok(low..high)
+        }
 
-    /// Set the range of the progress bar
-    pub fn set_range(&self, range: Range<u32>) {
-        use winapi::um::commctrl::PBM_SETRANGE32;
-        use winapi::shared::minwindef::{WPARAM, LPARAM};
+        /// Set the range of the progress bar
+        pub fn set_range(&self, range: Range<u32>) => try_set_range {
+            use winapi::um::commctrl::PBM_SETRANGE32;
+            use winapi::shared::minwindef::{WPARAM, LPARAM};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, PBM_SETRANGE32, range.start as WPARAM, range.end as LPARAM);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, PBM_SETRANGE32, range.start as WPARAM, range.end as LPARAM);
 
-    /// Set the progress bar marquee mode
-    pub fn set_marquee(&self, enable: bool, update_interval: u32) {
-        use winapi::shared::minwindef::{LPARAM, WPARAM};
-        use winapi::um::commctrl::PBM_SETMARQUEE;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, PBM_SETMARQUEE, enable as WPARAM, update_interval as LPARAM);
-    }
+        /// Set the progress bar marquee mode
+        pub fn set_marquee(&self, enable: bool, update_interval: u32) => try_set_marquee {
+            use winapi::shared::minwindef::{LPARAM, WPARAM};
+            use winapi::um::commctrl::PBM_SETMARQUEE;
 
-    /// Updates the flags of the progress bar.
-    pub fn add_flags(&self, styles: ProgressBarFlags) {
-        let styles = styles.bits() as u32;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, PBM_SETMARQUEE, enable as WPARAM, update_interval as LPARAM);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let active_styles = wh::get_style(handle);
-        
-        wh::set_style(handle, active_styles | styles);
-    }
+            Ok(())
+        }
 
-    /// Removes flags from the progress bar.
-    pub fn remove_flags(&self, styles: ProgressBarFlags) {
-        let styles = styles.bits() as u32;
+        /// Updates the flags of the progress bar.
+        pub fn add_flags(&self, styles: ProgressBarFlags) => try_add_flags {
+            let styles = styles.bits() as u32;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let active_styles = wh::get_style(handle);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let active_styles = wh::get_style(handle);
 
-        wh::set_style(handle, active_styles & !styles);
-    }
+            wh::set_style(handle, active_styles | styles);
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Removes flags from the progress bar.
+        pub fn remove_flags(&self, styles: ProgressBarFlags) => try_remove_flags {
+            let styles = styles.bits() as u32;
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let active_styles = wh::get_style(handle);
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+            wh::set_style(handle, active_styles & !styles);
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            Ok(())
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Set the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+            Ok(())
+        }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
+
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
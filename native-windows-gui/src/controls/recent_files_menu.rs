@@ -0,0 +1,318 @@
+use winapi::shared::windef::HMENU;
+use crate::NwgError;
+use super::{ControlHandle, Menu, MenuItem};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+const NOT_BOUND: &'static str = "RecentFilesMenu is not yet bound to a winapi object";
+
+thread_local! {
+    /// Maps the id of the recent files menu items to their parent menu and their path
+    static RECENT_FILES: RefCell<HashMap<u32, (HMENU, String)>> = RefCell::new(HashMap::new());
+}
+
+/// Return the recent files menu and the path associated with a menu item id
+pub(crate) fn recent_file(id: u32) -> Option<(HMENU, String)> {
+    RECENT_FILES.with(|files| files.borrow().get(&id).cloned())
+}
+
+
+/**
+    A submenu that lists the most recently used files of an application, from the most recent to the oldest.
+    Items are numbered so that the first nine files can be opened using their access key.
+
+    The menu does not read or write the list on disk. Instead, the list is loaded with the `paths` builder parameter (or `set_paths`)
+    and the `on_changed` callback is called each time `add`, `remove`, or `clear` changes the list so it can be saved.
+
+    Requires the `menu` feature.
+
+    **Builder parameters:**
+      - text: The text of the submenu
+      - parent: A top level window or a menu. With a top level window, the submenu is added to the menu bar.
+      - paths: The initial list of files, from the most recent to the oldest
+      - max_len: The maximum number of files in the list. Defaults to 10.
+      - empty_text: The text of the disabled item displayed when the list is empty. Defaults to "(Empty)".
+      - on_changed: A callback called with the new list of files each time the list is changed by the menu
+
+    **Control events:**
+      - OnRecentFileSelected: When a file is selected in the menu. The event data holds the file path.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn recent_files(recent: &mut nwg::RecentFilesMenu, menu: &nwg::Menu) -> Result<(), nwg::NwgError> {
+        nwg::RecentFilesMenu::builder()
+            .text("Open &Recent")
+            .paths(vec!["C:\\hello.txt".to_string()])
+            .on_changed(|paths| println!("Save {:?}", paths))
+            .parent(menu)
+            .build(recent)
+    }
+
+    fn on_event(recent: &nwg::RecentFilesMenu, evt: nwg::Event, data: &nwg::EventData, handle: nwg::ControlHandle) {
+        if evt == nwg::Event::OnRecentFileSelected && handle == recent.handle {
+            println!("Open {}", data.on_recent_file_selected());
+        }
+    }
+    ```
+*/
+#[derive(Default)]
+pub struct RecentFilesMenu {
+    pub handle: ControlHandle,
+    items: RefCell<Vec<MenuItem>>,
+    menu: Menu,
+    paths: RefCell<Vec<String>>,
+    max_len: Cell<usize>,
+    empty_text: RefCell<String>,
+    on_changed: RefCell<Option<Box<dyn Fn(&[String])>>>,
+}
+
+impl RecentFilesMenu {
+
+    pub fn builder<'a>() -> RecentFilesMenuBuilder<'a> {
+        RecentFilesMenuBuilder {
+            text: "Recent files",
+            paths: Vec::new(),
+            max_len: 10,
+            empty_text: "(Empty)",
+            on_changed: None,
+            parent: None
+        }
+    }
+
+    /// Returns the files in the list, from the most recent to the oldest
+    pub fn paths(&self) -> Vec<String> {
+        self.paths.borrow().clone()
+    }
+
+    /// Replace the files in the list. Use this to load a saved list. `on_changed` is not called.
+    pub fn set_paths(&self, paths: Vec<String>) {
+        {
+            let mut current = self.paths.borrow_mut();
+            *current = Vec::with_capacity(paths.len());
+            for path in paths {
+                if !current.contains(&path) {
+                    current.push(path);
+                }
+            }
+
+            current.truncate(self.max_len.get());
+        }
+
+        self.sync();
+    }
+
+    /// Move `path` at the top of the list, adding it if needed. The oldest files are removed if the list is too long.
+    pub fn add(&self, path: &str) {
+        {
+            let mut paths = self.paths.borrow_mut();
+            paths.retain(|p| p != path);
+            paths.insert(0, path.to_string());
+            paths.truncate(self.max_len.get());
+        }
+
+        self.changed();
+    }
+
+    /// Remove `path` from the list. Ex: when the file no longer exists. Does nothing if `path` is not in the list.
+    pub fn remove(&self, path: &str) {
+        let len = self.paths.borrow().len();
+        self.paths.borrow_mut().retain(|p| p != path);
+
+        if self.paths.borrow().len() != len {
+            self.changed();
+        }
+    }
+
+    /// Remove every file from the list
+    pub fn clear(&self) {
+        self.paths.borrow_mut().clear();
+        self.changed();
+    }
+
+    /// Returns the maximum number of files in the list
+    pub fn max_len(&self) -> usize {
+        self.max_len.get()
+    }
+
+    /// Sets the maximum number of files in the list. Extra files are removed, starting with the oldest.
+    pub fn set_max_len(&self, max_len: usize) {
+        self.max_len.set(max_len);
+
+        let len = self.paths.borrow().len();
+        if len > max_len {
+            self.paths.borrow_mut().truncate(max_len);
+            self.changed();
+        }
+    }
+
+    /// Returns the path associated with a menu item of the list. Returns `None` if the item is not in the list.
+    pub fn path(&self, item: &ControlHandle) -> Option<String> {
+        let (_, id) = item.hmenu_item()?;
+        let items = self.items.borrow();
+        let index = items.iter().position(|i| i.handle.hmenu_item().map(|(_, item_id)| item_id) == Some(id))?;
+        self.paths.borrow().get(index).cloned()
+    }
+
+    /// Sets the callback called with the new list of files each time the list is changed by the menu
+    pub fn set_on_changed<F: Fn(&[String]) + 'static>(&self, callback: Option<F>) {
+        *self.on_changed.borrow_mut() = callback.map(|c| Box::new(c) as Box<dyn Fn(&[String])>);
+    }
+
+    /// Rebuild the menu items and call the `on_changed` callback
+    fn changed(&self) {
+        self.sync();
+
+        let paths = self.paths.borrow().clone();
+        if let Some(callback) = self.on_changed.borrow().as_ref() {
+            callback(&paths);
+        }
+    }
+
+    /// Rebuild the menu items from the list of files
+    fn sync(&self) {
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+
+        let mut items = self.items.borrow_mut();
+        self.unregister(&items);
+        items.clear();
+
+        let paths = self.paths.borrow();
+        if paths.is_empty() {
+            let mut item = MenuItem::default();
+            let built = MenuItem::builder()
+                .text(&self.empty_text.borrow())
+                .disabled(true)
+                .parent(&self.menu)
+                .build(&mut item);
+
+            if built.is_ok() {
+                items.push(item);
+            }
+
+            return;
+        }
+
+        for (index, path) in paths.iter().enumerate() {
+            // `&` would be read as an access key
+            let text = format!("{} {}", Self::item_number(index), path.replace("&", "&&"));
+
+            let mut item = MenuItem::default();
+            let built = MenuItem::builder()
+                .text(&text)
+                .parent(&self.menu)
+                .build(&mut item);
+
+            if built.is_err() {
+                continue;
+            }
+
+            if let Some((parent, id)) = item.handle.hmenu_item() {
+                RECENT_FILES.with(|files| files.borrow_mut().insert(id, (parent, path.clone())));
+            }
+
+            items.push(item);
+        }
+    }
+
+    /// The number of the item at `index`. The first ten items use their number as access key.
+    fn item_number(index: usize) -> String {
+        match index {
+            0..=8 => format!("&{}", index + 1),
+            9 => "1&0".to_string(),
+            i => format!("{}", i + 1)
+        }
+    }
+
+    fn unregister(&self, items: &[MenuItem]) {
+        RECENT_FILES.with(|files| {
+            let mut files = files.borrow_mut();
+            for item in items.iter() {
+                if let Some((_, id)) = item.handle.hmenu_item() {
+                    files.remove(&id);
+                }
+            }
+        });
+    }
+
+}
+
+impl Drop for RecentFilesMenu {
+    fn drop(&mut self) {
+        let items = self.items.borrow();
+        self.unregister(&items);
+    }
+}
+
+impl PartialEq for RecentFilesMenu {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl Eq for RecentFilesMenu {}
+
+pub struct RecentFilesMenuBuilder<'a> {
+    text: &'a str,
+    paths: Vec<String>,
+    max_len: usize,
+    empty_text: &'a str,
+    on_changed: Option<Box<dyn Fn(&[String])>>,
+    parent: Option<ControlHandle>
+}
+
+impl<'a> RecentFilesMenuBuilder<'a> {
+
+    pub fn text(mut self, text: &'a str) -> RecentFilesMenuBuilder<'a> {
+        self.text = text;
+        self
+    }
+
+    pub fn paths(mut self, paths: Vec<String>) -> RecentFilesMenuBuilder<'a> {
+        self.paths = paths;
+        self
+    }
+
+    pub fn max_len(mut self, max_len: usize) -> RecentFilesMenuBuilder<'a> {
+        self.max_len = max_len;
+        self
+    }
+
+    pub fn empty_text(mut self, empty_text: &'a str) -> RecentFilesMenuBuilder<'a> {
+        self.empty_text = empty_text;
+        self
+    }
+
+    pub fn on_changed<F: Fn(&[String]) + 'static>(mut self, callback: F) -> RecentFilesMenuBuilder<'a> {
+        self.on_changed = Some(Box::new(callback));
+        self
+    }
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> RecentFilesMenuBuilder<'a> {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn build(self, out: &mut RecentFilesMenu) -> Result<(), NwgError> {
+        let parent = match self.parent {
+            Some(p) => p,
+            None => { return Err(NwgError::no_parent_menu()); }
+        };
+
+        *out = Default::default();
+
+        Menu::builder()
+            .text(self.text)
+            .parent(parent)
+            .build(&mut out.menu)?;
+
+        out.handle = out.menu.handle;
+        out.max_len.set(self.max_len);
+        *out.empty_text.borrow_mut() = self.empty_text.to_string();
+        *out.on_changed.borrow_mut() = self.on_changed;
+        out.set_paths(self.paths);
+
+        Ok(())
+    }
+
+}
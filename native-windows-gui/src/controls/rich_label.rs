@@ -1,6 +1,6 @@
 use winapi::um::winuser::{WS_VISIBLE, ES_MULTILINE, WS_DISABLED, EM_SETSEL};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::check_hwnd;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::win32::richedit as rich;
 use crate::{Font, NwgError, RawEventHandler, HTextAlign, unbind_raw_event_handler};
 use super::{ControlBase, ControlHandle, CharFormat, ParaFormat};
@@ -98,82 +98,92 @@ impl RichLabel {
         }
     }
 
-    /// Sets the background color for a rich edit control.
-    /// You cannot get the background color of a rich label
-    pub fn set_background_color(&self, color: [u8; 3]) {
-        use winapi::um::wingdi::RGB;
+    try_accessors! {
+        /// Sets the background color for a rich edit control.
+        /// You cannot get the background color of a rich label
+        pub fn set_background_color(&self, color: [u8; 3]) => try_set_background_color {
+            use winapi::um::wingdi::RGB;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let color = RGB(color[0], color[1], color[2]);
-        wh::send_message(handle, rich::EM_SETBKGNDCOLOR, 0, color as _);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let color = RGB(color[0], color[1], color[2]);
+            wh::send_message(handle, rich::EM_SETBKGNDCOLOR, 0, color as _);
 
-    /// Sets the character format of the selected range of text
-    pub fn set_char_format(&self, r: Range<u32>, fmt: &CharFormat) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
-        rich::set_char_format(handle, fmt);
-        wh::send_message(handle, EM_SETSEL as u32, 0, 0);
-    }
+            Ok(())
+        }
 
-    /// Returns the character format of the selected range of text
-    pub fn char_format(&self, r: Range<u32>) -> CharFormat {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Sets the character format of the selected range of text
+        pub fn set_char_format(&self, r: Range<u32>, fmt: &CharFormat) => try_set_char_format {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
+            rich::set_char_format(handle, fmt);
+            wh::send_message(handle, EM_SETSEL as u32, 0, 0);
 
-        wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
-        let out = rich::char_format(handle);
-        wh::send_message(handle, EM_SETSEL as u32, 0, 0);
+            Ok(())
+        }
 
-        out
-    }
+        /// Returns the character format of the selected range of text
+        pub fn char_format(&self, r: Range<u32>) -> CharFormat => try_char_format {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Sets the paragraph formatting for the selected range of text in a rich edit control
-    pub fn set_para_format(&self, r: Range<u32>, fmt: &ParaFormat) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
+            let out = rich::char_format(handle);
+            wh::send_message(handle, EM_SETSEL as u32, 0, 0);
 
-        wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
-        rich::set_para_format(handle, fmt);
-        wh::send_message(handle, EM_SETSEL as u32, 0, 0);
-    }
+            Ok(out)
+        }
 
-    /// Returns the paragraph formatting for the selected range of text in a rich edit control
-    /// If more than one paragraph is selected, receive the attributes of the first paragraph
-    pub fn para_format(&self, r: Range<u32>) -> ParaFormat {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Sets the paragraph formatting for the selected range of text in a rich edit control
+        pub fn set_para_format(&self, r: Range<u32>, fmt: &ParaFormat) => try_set_para_format {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
-        let out = rich::para_format(handle);
-        wh::send_message(handle, EM_SETSEL as u32, 0, 0);
+            wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
+            rich::set_para_format(handle, fmt);
+            wh::send_message(handle, EM_SETSEL as u32, 0, 0);
 
-        out
-    }
+            Ok(())
+        }
 
-    /// Return the selected range of characters by the user in the text input
-    pub fn selection(&self) -> Range<usize> {
-        use winapi::um::winuser::EM_GETSEL;
+        /// Returns the paragraph formatting for the selected range of text in a rich edit control
+        /// If more than one paragraph is selected, receive the attributes of the first paragraph
+        pub fn para_format(&self, r: Range<u32>) -> ParaFormat => try_para_format {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
+            let out = rich::para_format(handle);
+            wh::send_message(handle, EM_SETSEL as u32, 0, 0);
 
-        let (mut out1, mut out2) = (0u32, 0u32);
-        let (ptr1, ptr2) = (&mut out1 as *mut u32, &mut out2 as *mut u32);
-        wh::send_message(handle, EM_GETSEL as u32, ptr1 as _, ptr2 as _);
+            Ok(out)
+        }
 
-        Range { start: out1 as usize, end: out2 as usize }
-    }
+        /// Return the selected range of characters by the user in the text input
+        pub fn selection(&self) -> Range<usize> => try_selection {
+            use winapi::um::winuser::EM_GETSEL;
 
-    /// Return the selected range of characters by the user in the text input
-    pub fn set_selection(&self, r: Range<u32>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let (mut out1, mut out2) = (0u32, 0u32);
+            let (ptr1, ptr2) = (&mut out1 as *mut u32, &mut out2 as *mut u32);
+            wh::send_message(handle, EM_GETSEL as u32, ptr1 as _, ptr2 as _);
+
+            Ok(Range { start: out1 as usize, end: out2 as usize })
+        }
+
+        /// Return the selected range of characters by the user in the text input
+        pub fn set_selection(&self, r: Range<u32>) => try_set_selection {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
 
-    /// Return the length of the user input in the control. This is better than `control.text().len()` as it
-    /// does not allocate a string in memory
-    pub fn len(&self) -> u32 {
-        use winapi::um::winuser::EM_LINELENGTH;
+            Ok(())
+        }
+
+        /// Return the length of the user input in the control. This is better than `control.text().len()` as it
+        /// does not allocate a string in memory
+        pub fn len(&self) -> u32 => try_len {
+            use winapi::um::winuser::EM_LINELENGTH;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_LINELENGTH as u32, 0, 0) as u32
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_LINELENGTH as u32, 0, 0) as u32)
+        }
     }
 
     /// Remove all text from the textbox
@@ -191,60 +201,72 @@ impl RichLabel {
         *self.line_height.borrow()
     }
 
-    /// Set base font of the control
-    /// It is not possible to get the base font handle of a rich label. Use `char_format` instead.
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+    try_accessors! {
+        /// Set base font of the control
+        /// It is not possible to get the base font handle of a rich label. Use `char_format` instead.
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            Ok(())
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+            Ok(())
+        }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
-    }
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
 
-    /// Return the text displayed in the TextInput
-    pub fn text(&self) -> String { 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_text(handle) }
-    }
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Return the text displayed in the TextInput
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
 
-    /// Set the text displayed in the TextInput
-    pub fn set_text<'a>(&self, v: &'a str) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
+        /// Set the text displayed in the TextInput
+        pub fn set_text<'a>(&self, v: &'a str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, v) }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
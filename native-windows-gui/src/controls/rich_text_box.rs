@@ -1,7 +1,7 @@
 use winapi::shared::minwindef::{WPARAM, LPARAM};
 use winapi::um::winuser::{ES_AUTOVSCROLL, ES_AUTOHSCROLL, WS_VISIBLE, WS_DISABLED, WS_TABSTOP, WS_VSCROLL, WS_HSCROLL};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::check_hwnd;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::win32::richedit as rich;
 use crate::{Font, NwgError};
 use super::{ControlBase, ControlHandle};
@@ -94,6 +94,10 @@ pub struct CharFormat {
 
     /// Text underline type. Does not work with effects
     pub underline_type: Option<UnderlineType>,
+
+    /// Text background (highlight) color.
+    /// When returned by `char_format`, this is `None` if the text uses the default background color.
+    pub background_color: Option<[u8; 3]>,
 }
 
 
@@ -231,6 +235,7 @@ The rich text box control supports the following rich text features:
 * Bullet point list
 * Paragraph with custom indent/offset
 * Custom line spacing
+* Loading and saving RTF data
 
 
 See: https://docs.microsoft.com/en-us/windows/win32/controls/about-rich-edit-controls#rich-edit-version-41
@@ -248,6 +253,8 @@ Note: Use `\r\n` to input a new line not just `\n`.
   * `limit`:    The maximum number of character that can be inserted in the control
   * `readonly`: If the textbox should allow user input or not
   * `focus`:    The control receive focus after being created
+  * `spell_checking`: Underline the misspelled words of the control
+  * `undo_limit`: The maximum number of actions in the undo queue. Defaults to 100.
 
 **Control events:**
   * `OnMouseMove`:   Generic mouse mouse event
@@ -256,6 +263,8 @@ Note: Use `\r\n` to input a new line not just `\n`.
   * `OnKeyPress`:    Generic key press event
   * `OnKeyRelease`:  Generic key release event
   * `OnChar`:        Generic key event. Returns a `char` instead of a virtual key code
+  * `OnTextLimitReached`: When the user tries to insert more characters than the control `limit`
+  * `OnMisspelledWord`: When the user right clicks a misspelled word. Requires spell checking and the `spell-check` feature.
 */
 #[derive(Default, PartialEq, Eq)]
 pub struct RichTextBox {
@@ -274,110 +283,227 @@ impl RichTextBox {
             limit: 0,
             readonly: false,
             focus: false,
+            spell_checking: false,
+            undo_limit: None,
             font: None,
             parent: None
         }
     }
 
-    /// Sets the background color for a rich edit control.
-    /// You cannot get the background color of a rich text box
-    pub fn set_background_color(&self, color: [u8; 3]) {
-        use winapi::um::wingdi::RGB;
+    try_accessors! {
+        /// Sets the background color for a rich edit control.
+        /// You cannot get the background color of a rich text box
+        pub fn set_background_color(&self, color: [u8; 3]) => try_set_background_color {
+            use winapi::um::wingdi::RGB;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let color = RGB(color[0], color[1], color[2]);
-        wh::send_message(handle, rich::EM_SETBKGNDCOLOR, 0, color as _);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let color = RGB(color[0], color[1], color[2]);
+            wh::send_message(handle, rich::EM_SETBKGNDCOLOR, 0, color as _);
 
-    /// Sets the character format of the currently selected text
-    pub fn set_char_format(&self, fmt: &CharFormat) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        rich::set_char_format(handle, fmt);
-    }
+            Ok(())
+        }
 
-    /// Returns the character format of the current selected text
-    pub fn char_format(&self) -> CharFormat {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        rich::char_format(handle)
-    }
+        /// Sets the character format of the currently selected text
+        pub fn set_char_format(&self, fmt: &CharFormat) => try_set_char_format {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            rich::set_char_format(handle, fmt);
 
-    /// Sets the paragraph formatting for the current selection in a rich edit control
-    pub fn set_para_format(&self, fmt: &ParaFormat) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        rich::set_para_format(handle, fmt)
-    }
+            Ok(())
+        }
 
-    /// Returns the paragraph formatting for the current selection in a rich edit control
-    /// If more than one paragraph is selected, receive the attributes of the first paragraph
-    pub fn para_format(&self) -> ParaFormat {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        rich::para_format(handle)
-    }
+        /// Returns the character format of the current selected text
+        pub fn char_format(&self) -> CharFormat => try_char_format {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(rich::char_format(handle))
+        }
 
-    /// Set the font of the control
-    /// It is not possible to get the base font handle of a rich label. Use `char_format` instead.
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Sets the paragraph formatting for the current selection in a rich edit control
+        pub fn set_para_format(&self, fmt: &ParaFormat) => try_set_para_format {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            rich::set_para_format(handle, fmt);
 
-    /// Return the number of maximum character allowed in this text input
-    pub fn limit(&self) -> u32 {
-        use winapi::um::winuser::EM_GETLIMITTEXT;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_GETLIMITTEXT as u32, 0, 0) as u32
-    }
+            Ok(())
+        }
 
-    /// Set the number of maximum character allowed in this text input
-    pub fn set_limit(&self, limit: usize) {
-        use winapi::um::winuser::EM_SETLIMITTEXT;
+        /// Returns the paragraph formatting for the current selection in a rich edit control
+        /// If more than one paragraph is selected, receive the attributes of the first paragraph
+        pub fn para_format(&self) -> ParaFormat => try_para_format {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(rich::para_format(handle))
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETLIMITTEXT as u32, limit, 0);
-    }
+        /// Replace the content of the rich text box with RTF data. If the data is not valid RTF, it is inserted as plain text.
+        pub fn set_rtf(&self, rtf: &[u8]) => try_set_rtf {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            rich::stream_in_rtf(handle, rtf, false);
+
+            Ok(())
+        }
 
-    /// Check if the content of the text input was modified after it's creation
-    pub fn modified(&self) -> bool {
-        use winapi::um::winuser::EM_GETMODIFY;
+        /// Returns the content of the rich text box as RTF data
+        pub fn rtf(&self) -> Vec<u8> => try_rtf {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(rich::stream_out_rtf(handle, false))
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_GETMODIFY as u32, 0, 0) != 0
-    }
+        /// Replace the current selection with RTF data
+        pub fn set_selection_rtf(&self, rtf: &[u8]) => try_set_selection_rtf {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            rich::stream_in_rtf(handle, rtf, true);
 
-    /// Manually set modified flag of the text input
-    pub fn set_modified(&self, e: bool) {
-        use winapi::um::winuser::EM_SETMODIFY;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETMODIFY as u32, e as usize, 0);
-    }
+            Ok(())
+        }
 
-    /// Undo the last action by the user in the control
-    pub fn undo(&self) {
-        use winapi::um::winuser::EM_UNDO;
+        /// Returns the current selection as RTF data
+        pub fn selection_rtf(&self) -> Vec<u8> => try_selection_rtf {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(rich::stream_out_rtf(handle, true))
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_UNDO as u32, 0, 0);
-    }
+        /// Enable or disable the spell checking of the control. Misspelled words are underlined using the language of the user.
+        /// Requires Windows 8 or later. See the `spell-check` feature to get suggestions when a misspelled word is right clicked.
+        pub fn set_spell_checking(&self, enabled: bool) => try_set_spell_checking {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            rich::set_spell_checking(handle, enabled);
 
-    /// Return the selected range of characters by the user in the text input
-    pub fn selection(&self) -> Range<u32> {
-        use winapi::um::winuser::EM_GETSEL;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Check if the spell checking of the control is enabled
+        pub fn spell_checking(&self) -> bool => try_spell_checking {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(rich::spell_checking(handle))
+        }
+
+        /// Return the word at the character index `index` and its range in the control. Returns `None` if there is no word at `index`.
+        pub fn word_at(&self, index: usize) -> Option<(String, Range<usize>)> => try_word_at {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(rich::word_at(handle, index))
+        }
 
-        let (mut out1, mut out2) = (0u32, 0u32);
-        let (ptr1, ptr2) = (&mut out1 as *mut u32, &mut out2 as *mut u32);
-        wh::send_message(handle, EM_GETSEL as u32, ptr1 as WPARAM, ptr2 as LPARAM);
+        /// Set the font of the control
+        /// It is not possible to get the base font handle of a rich label. Use `char_format` instead.
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-        Range { start: out1 as u32, end: out2 as u32 }
-    }
+            Ok(())
+        }
+
+        /// Return the number of maximum character allowed in this text input
+        pub fn limit(&self) -> u32 => try_limit {
+            use winapi::um::winuser::EM_GETLIMITTEXT;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_GETLIMITTEXT as u32, 0, 0) as u32)
+        }
 
-    /// Return the selected range of characters by the user in the text input
-    pub fn set_selection(&self, r: Range<u32>) {
-        use winapi::um::winuser::EM_SETSEL;
+        /// Set the number of maximum character allowed in this text input
+        pub fn set_limit(&self, limit: usize) => try_set_limit {
+            use winapi::um::winuser::EM_SETLIMITTEXT;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETLIMITTEXT as u32, limit, 0);
+
+            Ok(())
+        }
+
+        /// Check if the content of the text input was modified after it's creation
+        pub fn modified(&self) -> bool => try_modified {
+            use winapi::um::winuser::EM_GETMODIFY;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_GETMODIFY as u32, 0, 0) != 0)
+        }
+
+        /// Manually set modified flag of the text input
+        pub fn set_modified(&self, e: bool) => try_set_modified {
+            use winapi::um::winuser::EM_SETMODIFY;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETMODIFY as u32, e as usize, 0);
+
+            Ok(())
+        }
+
+        /// Undo the last action by the user in the control
+        pub fn undo(&self) => try_undo {
+            use winapi::um::winuser::EM_UNDO;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_UNDO as u32, 0, 0);
+
+            Ok(())
+        }
+
+        /// Redo the last action undone in the control
+        pub fn redo(&self) => try_redo {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, rich::EM_REDO, 0, 0);
+
+            Ok(())
+        }
+
+        /// Check if there are actions in the undo queue of the control
+        pub fn can_undo(&self) -> bool => try_can_undo {
+            use winapi::um::winuser::EM_CANUNDO;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_CANUNDO as u32, 0, 0) != 0)
+        }
+
+        /// Check if there are actions in the redo queue of the control
+        pub fn can_redo(&self) -> bool => try_can_redo {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, rich::EM_CANREDO, 0, 0) != 0)
+        }
+
+        /// Clear the undo and redo queues of the control
+        pub fn empty_undo_buffer(&self) => try_empty_undo_buffer {
+            use winapi::um::winuser::EM_EMPTYUNDOBUFFER;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_EMPTYUNDOBUFFER as u32, 0, 0);
+
+            Ok(())
+        }
+
+        /// Set the maximum number of actions that can be stored in the undo queue. The default limit is 100.
+        /// Setting the limit to 0 disables undo. Returns the new limit, which can be smaller than `limit` if memory is limited.
+        pub fn set_undo_limit(&self, limit: u32) -> u32 => try_set_undo_limit {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, rich::EM_SETUNDOLIMIT, limit as WPARAM, 0) as u32)
+        }
+
+        /// Stop merging the characters typed by the user into the current undo action.
+        /// The next characters typed by the user will be undone separately.
+        pub fn stop_group_typing(&self) => try_stop_group_typing {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, rich::EM_STOPGROUPTYPING, 0, 0);
+
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
+        /// Return the selected range of characters by the user in the text input
+        pub fn selection(&self) -> Range<u32> => try_selection {
+            use winapi::um::winuser::EM_GETSEL;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let (mut out1, mut out2) = (0u32, 0u32);
+            let (ptr1, ptr2) = (&mut out1 as *mut u32, &mut out2 as *mut u32);
+            wh::send_message(handle, EM_GETSEL as u32, ptr1 as WPARAM, ptr2 as LPARAM);
+
+            Ok(Range { start: out1 as u32, end: out2 as u32 })
+        }
+
+        /// Return the selected range of characters by the user in the text input
+        pub fn set_selection(&self, r: Range<u32>) => try_set_selection {
+            use winapi::um::winuser::EM_SETSEL;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
+
+            Ok(())
+        }
     }
 
     /// Return the length of the user input in the control. Performs a newline conversion first since
@@ -388,21 +514,25 @@ impl RichTextBox {
         dos2unix(&self.text()).chars().count().try_into().unwrap_or_default()
     }
     
-    /// Return the number of lines in the multiline edit control.
-    /// If the control has no text, the return value is 1.
-    pub fn linecount(&self) -> i32 {
-        use winapi::um::winuser::EM_GETLINECOUNT;
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_GETLINECOUNT as u32, 0, 0) as i32
-    }  
-    
-    /// Scroll `v` lines in the multiline edit control.
-    pub fn scroll(&self, v: i32) {
-        use winapi::um::winuser::EM_LINESCROLL;
+    try_accessors! {
+        /// Return the number of lines in the multiline edit control.
+        /// If the control has no text, the return value is 1.
+        pub fn linecount(&self) -> i32 => try_linecount {
+            use winapi::um::winuser::EM_GETLINECOUNT;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_GETLINECOUNT as u32, 0, 0) as i32)
+        }
+
+        /// Scroll `v` lines in the multiline edit control.
+        pub fn scroll(&self, v: i32) => try_scroll {
+            use winapi::um::winuser::EM_LINESCROLL;
         
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_LINESCROLL as u32, 0, v as LPARAM);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_LINESCROLL as u32, 0, v as LPARAM);
+
+            Ok(())
+        }
     }
     
     /// Get the linecount and then scroll the text to the last line
@@ -412,22 +542,26 @@ impl RichTextBox {
         self.scroll(lines - 2);
     }
 
-    /// Return true if the TextInput value cannot be edited. Retrurn false otherwise.
-    /// A user can still copy text from a readonly TextEdit (unlike disabled)
-    pub fn readonly(&self) -> bool {
-        use winapi::um::winuser::ES_READONLY;
+    try_accessors! {
+        /// Return true if the TextInput value cannot be edited. Retrurn false otherwise.
+        /// A user can still copy text from a readonly TextEdit (unlike disabled)
+        pub fn readonly(&self) -> bool => try_readonly {
+            use winapi::um::winuser::ES_READONLY;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::get_style(handle) & ES_READONLY == ES_READONLY
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::get_style(handle) & ES_READONLY == ES_READONLY)
+        }
 
-    /// Set the readonly flag of the text input
-    /// A user can still copy text from a readonly TextEdit (unlike disabled)
-    pub fn set_readonly(&self, r: bool) {
-        use winapi::um::winuser::EM_SETREADONLY;
+        /// Set the readonly flag of the text input
+        /// A user can still copy text from a readonly TextEdit (unlike disabled)
+        pub fn set_readonly(&self, r: bool) => try_set_readonly {
+            use winapi::um::winuser::EM_SETREADONLY;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETREADONLY as u32, r as WPARAM, 0);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETREADONLY as u32, r as WPARAM, 0);
+
+            Ok(())
+        }
     }
 
     /// Remove all text from the textbox
@@ -435,83 +569,99 @@ impl RichTextBox {
         self.set_text("");
     }
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+    try_accessors! {
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Set the keyboard focus on the button
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Set the keyboard focus on the button
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+            Ok(())
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
-    }
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Return the text displayed in the TextInput
-    pub fn text(&self) -> String { 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_text(handle) }
-    }
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
 
-    /// Set the text displayed in the TextInput
-    pub fn set_text<'a>(&self, v: &'a str) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
-    }
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Return the text displayed in the TextInput
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
+
+        /// Set the text displayed in the TextInput
+        pub fn set_text<'a>(&self, v: &'a str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, v) }
 
-    /// Set the text in the current control, converting unix-style newlines in the input to "\r\n"
-    pub fn set_text_unix2dos<'a>(&self, v: &'a str) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle,  &unix2dos(&v).to_string()) }
+            Ok(())
+        }
+
+        /// Set the text in the current control, converting unix-style newlines in the input to "\r\n"
+        pub fn set_text_unix2dos<'a>(&self, v: &'a str) => try_set_text_unix2dos {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle,  &unix2dos(&v).to_string()) }
+
+            Ok(())
+        }
     }
 
     /// Append text to the current control
@@ -561,6 +711,8 @@ pub struct RichTextBoxBuilder<'a> {
     limit: usize,
     readonly: bool,
     focus: bool,
+    spell_checking: bool,
+    undo_limit: Option<u32>,
     font: Option<&'a Font>,
     parent: Option<ControlHandle>
 }
@@ -612,6 +764,16 @@ impl<'a> RichTextBoxBuilder<'a> {
         self
     }
 
+    pub fn spell_checking(mut self, spell_checking: bool) -> RichTextBoxBuilder<'a> {
+        self.spell_checking = spell_checking;
+        self
+    }
+
+    pub fn undo_limit(mut self, limit: u32) -> RichTextBoxBuilder<'a> {
+        self.undo_limit = Some(limit);
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> RichTextBoxBuilder<'a> {
         self.parent = Some(p.into());
         self
@@ -652,6 +814,14 @@ impl<'a> RichTextBoxBuilder<'a> {
             out.set_font(Font::global_default().as_ref());
         }
 
+        if self.spell_checking {
+            out.set_spell_checking(true);
+        }
+
+        if let Some(limit) = self.undo_limit {
+            out.set_undo_limit(limit);
+        }
+
         if self.focus {
             out.set_focus();
         }
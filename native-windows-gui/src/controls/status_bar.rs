@@ -1,6 +1,6 @@
 use winapi::shared::minwindef::{WPARAM, LPARAM};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::check_hwnd;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::{Font, NwgError, RawEventHandler, unbind_raw_event_handler};
 use super::{ControlHandle, ControlBase};
 use std::cell::RefCell;
@@ -18,6 +18,7 @@ Requires the `status-bar` feature.
   * `parent`:   **Required.** The status bar parent container.
   * `text`:     The status bar text.
   * `font`:     The font used for the status bar text
+  * `ex_flags`: A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
 
 **Control events:**
   * `MousePress(_)`: Generic mouse press events on the status bar
@@ -49,63 +50,71 @@ impl StatusBar {
         StatusBarBuilder {
             text: "",
             font: None,
+            ex_flags: 0,
             parent: None
         }
     }
 
-    /// Set the minimum height of the statusbar (in pixels)
-    pub fn set_min_height(&self, height: u32) {
-        use winapi::um::commctrl::SB_SETMINHEIGHT;
-        use winapi::um::winuser::WM_SIZE;
+    try_accessors! {
+        /// Set the minimum height of the statusbar (in pixels)
+        pub fn set_min_height(&self, height: u32) => try_set_min_height {
+            use winapi::um::commctrl::SB_SETMINHEIGHT;
+            use winapi::um::winuser::WM_SIZE;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, SB_SETMINHEIGHT, height as WPARAM, 0); 
-        wh::send_message(handle, WM_SIZE, 0, 0);  // redraw the statusbar
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, SB_SETMINHEIGHT, height as WPARAM, 0); 
+            wh::send_message(handle, WM_SIZE, 0, 0);  // redraw the statusbar;
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+            Ok(())
         }
-    }
 
-    /// Set the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
+        }
 
-    /// Return the text in one of the region of the status bar
-    pub fn text<'a>(&self, index: u8) -> String {
-        use winapi::um::commctrl::{SB_GETTEXTLENGTHW, SB_GETTEXTW};
-        use winapi::shared::minwindef::LOWORD;
-        use crate::win32::base_helper::from_utf16;
+        /// Set the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let result = wh::send_message(handle, SB_GETTEXTLENGTHW, index as WPARAM, 0);
-        let text_length = (LOWORD(result as u32) as usize) + 1; // +1 for the terminating null character
+            Ok(())
+        }
+
+        /// Return the text in one of the region of the status bar
+        pub fn text<'a>(&self, index: u8) -> String => try_text {
+            use winapi::um::commctrl::{SB_GETTEXTLENGTHW, SB_GETTEXTW};
+            use winapi::shared::minwindef::LOWORD;
+            use crate::win32::base_helper::from_utf16;
 
-        let mut buffer: Vec<u16> = Vec::with_capacity(text_length);
-        unsafe { buffer.set_len(text_length); }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let result = wh::send_message(handle, SB_GETTEXTLENGTHW, index as WPARAM, 0);
+            let text_length = (LOWORD(result as u32) as usize) + 1; // +1 for the terminating null character
+
+            let mut buffer: Vec<u16> = Vec::with_capacity(text_length);
+            unsafe { buffer.set_len(text_length); }
         
-        wh::send_message(handle, SB_GETTEXTW, index as WPARAM, buffer.as_mut_ptr() as LPARAM);
+            wh::send_message(handle, SB_GETTEXTW, index as WPARAM, buffer.as_mut_ptr() as LPARAM);
 
-        from_utf16(&buffer)
-    }
+            Ok(from_utf16(&buffer))
+        }
+
+        /// Set the text in one of the region of the status bar
+        pub fn set_text<'a>(&self, index: u8, text: &'a str) => try_set_text {
+            use winapi::um::commctrl::SB_SETTEXTW;
+            use crate::win32::base_helper::with_utf16;
 
-    /// Set the text in one of the region of the status bar
-    pub fn set_text<'a>(&self, index: u8, text: &'a str) {
-        use winapi::um::commctrl::SB_SETTEXTW;
-        use crate::win32::base_helper::to_utf16;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            with_utf16(text, |text| wh::send_message(handle, SB_SETTEXTW, index as WPARAM, text.as_ptr() as LPARAM));
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let text = to_utf16(text);
-        wh::send_message(handle, SB_SETTEXTW, index as WPARAM, text.as_ptr() as LPARAM);
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -125,25 +134,28 @@ impl StatusBar {
         WS_CHILD
     }
 
-    /// Status bar do not resize automatically. Instead, a resize message must be
-    /// manually sent by the parent window to trigger the resize action.
-    pub fn hook_parent_resize(&self) {
-        use winapi::um::winuser::WM_SIZE;
-        use crate::bind_raw_event_handler_inner;
+    try_accessors! {
+        /// Status bar do not resize automatically. Instead, a resize message must be
+        /// manually sent by the parent window to trigger the resize action.
+        pub fn hook_parent_resize(&self) => try_hook_parent_resize {
+            use winapi::um::winuser::WM_SIZE;
+            use crate::bind_raw_event_handler_inner;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+            let parent_handle = ControlHandle::Hwnd(wh::get_window_parent(handle));
+            let handler = bind_raw_event_handler_inner(&parent_handle, handle as usize, move |_hwnd, msg, _w, _l| {
+                if msg == WM_SIZE {
+                    wh::send_message(handle, WM_SIZE, 0, 0);
+                }
 
-        let parent_handle = ControlHandle::Hwnd(wh::get_window_parent(handle));
-        let handler = bind_raw_event_handler_inner(&parent_handle, handle as usize, move |_hwnd, msg, _w, _l| {
-            if msg == WM_SIZE {
-                wh::send_message(handle, WM_SIZE, 0, 0);
-            }
+                None
+            });
 
-            None
-        });
+            *self.handler0.borrow_mut() = Some(handler.unwrap());
 
-        *self.handler0.borrow_mut() = Some(handler.unwrap());
+            Ok(())
+        }
     }
 
 }
@@ -161,6 +173,7 @@ impl Drop for StatusBar {
 pub struct StatusBarBuilder<'a> {
     text: &'a str,
     font: Option<&'a Font>,
+    ex_flags: u32,
     parent: Option<ControlHandle>
 }
 
@@ -176,6 +189,11 @@ impl<'a> StatusBarBuilder<'a> {
         self
     }
 
+    pub fn ex_flags(mut self, flags: u32) -> StatusBarBuilder<'a> {
+        self.ex_flags = flags;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> StatusBarBuilder<'a> {
         self.parent = Some(p.into());
         self
@@ -193,6 +211,7 @@ impl<'a> StatusBarBuilder<'a> {
             .class_name(out.class_name())
             .forced_flags(out.forced_flags())
             .flags(out.flags())
+            .ex_flags(self.ex_flags)
             .parent(Some(parent))
             .build()?;
 
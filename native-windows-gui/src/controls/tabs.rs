@@ -2,25 +2,50 @@ use winapi::shared::minwindef::{WPARAM, LPARAM, BOOL};
 use winapi::shared::windef::HWND;
 use winapi::um::winnt::LPWSTR;
 use winapi::um::winuser::{EnumChildWindows, WS_VISIBLE, WS_DISABLED, WS_EX_CONTROLPARENT};
-use crate::win32::{base_helper::{to_utf16, check_hwnd}, window_helper as wh};
+use winapi::um::commctrl::{TCS_MULTILINE, TCS_BOTTOM, TCS_RIGHT, TCS_VERTICAL, TCS_SCROLLOPPOSITE};
+use crate::win32::{base_helper::{to_utf16, from_utf16, try_check_hwnd}, window_helper as wh};
 use crate::{NwgError, Font, RawEventHandler, unbind_raw_event_handler};
 use super::{ControlBase, ControlHandle};
-use std::{mem, cell::RefCell};
+use std::{mem, cell::{Cell, RefCell}, rc::Rc};
 
 #[cfg(feature="image-list")]
 use crate::ImageList;
 
-#[cfg(feature="image-list")]
 use std::ptr;
 
 const NOT_BOUND: &'static str = "TabsContainer/Tab is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: TabsContainer/Tab handle is not HWND!";
 
+/// The default horizontal padding of the tab headers
+const TAB_PADDING: i32 = 6;
+
+/// The size of the close button of the tabs, in logical pixels
+const CLOSE_BUTTON_SIZE: i32 = 8;
+
 
 bitflags! {
+    /**
+        The tabs container flags
+
+        * VISIBLE:         The tabs container is immediatly visible after creation
+        * DISABLED:        The tabs container cannot be interacted with by the user
+        * MULTILINE:       The tab headers are displayed on multiple rows instead of a single row with a scroller
+        * BOTTOM:          The tab headers are displayed at the bottom of the container
+        * VERTICAL:        The tab headers are displayed on the left of the container. Implies `MULTILINE`.
+        * RIGHT:           The tab headers are displayed on the right of the container. Implies `MULTILINE`.
+        * SCROLL_OPPOSITE: The rows of headers that are not selected move to the other side of the container
+
+        The visual styles of Windows do not support the `BOTTOM`, `VERTICAL` and `RIGHT` flags. The tab headers
+        might not be drawn correctly if the application enables the visual styles.
+    */
     pub struct TabsContainerFlags: u32 {
         const VISIBLE = WS_VISIBLE;
         const DISABLED = WS_DISABLED;
+        const MULTILINE = TCS_MULTILINE;
+        const BOTTOM = TCS_BOTTOM;
+        const VERTICAL = TCS_VERTICAL | TCS_MULTILINE;
+        const RIGHT = TCS_VERTICAL | TCS_RIGHT | TCS_MULTILINE;
+        const SCROLL_OPPOSITE = TCS_SCROLLOPPOSITE;
     }
 }
 
@@ -39,11 +64,13 @@ Requires the `tabs` feature
   * `flags`:      A combination of the `TabsContainerFlags` values.
   * `ex_flags`: A combination of win32 window extended flags. Unlike `flags`, ex_flags must be used straight from winapi
   * `image_list`: The image list specifying the tabs icons
+  * `close_buttons`: Show a close button on each tab header
 
 
 **Control events:**
   * `TabsContainerChanged`: The select tab of a TabsContainer changed
   * `TabsContainerChanging`: The selected tab of a TabsContainer is about to be changed
+  * `OnTabCloseRequest`: The user clicked the close button of a tab. The event data holds the index of the tab.
   * `MousePress(_)`: Generic mouse press events on the button
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -54,6 +81,7 @@ pub struct TabsContainer {
     pub handle: ControlHandle,
     handler0: RefCell<Option<RawEventHandler>>,
     handler1: RefCell<Option<RawEventHandler>>,
+    close_buttons: Rc<Cell<bool>>,
 }
 
 impl TabsContainer {
@@ -66,80 +94,224 @@ impl TabsContainer {
             font: None,
             flags: None,
             ex_flags: 0,
+            close_buttons: false,
 
             #[cfg(feature = "image-list")]
             image_list: None
         }
     }
 
-    /// Return the index of the currently selected tab
-    /// May return `usize::max_value()` if no tab is selected
-    pub fn selected_tab(&self) -> usize {
-        use winapi::um::commctrl::{TCM_GETCURSEL};
+    try_accessors! {
+        /// Return the index of the currently selected tab
+        /// May return `usize::max_value()` if no tab is selected
+        pub fn selected_tab(&self) -> usize => try_selected_tab {
+            use winapi::um::commctrl::{TCM_GETCURSEL};
         
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TCM_GETCURSEL, 0, 0) as usize
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TCM_GETCURSEL, 0, 0) as usize)
+        }
 
-    /// Set the currently selected tab by index
-    pub fn set_selected_tab(&self, index: usize) {
-        use winapi::um::commctrl::TCM_SETCURSEL;
+        /// Set the currently selected tab by index
+        pub fn set_selected_tab(&self, index: usize) => try_set_selected_tab {
+            use winapi::um::commctrl::TCM_SETCURSEL;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TCM_SETCURSEL, index as WPARAM, 0);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TCM_SETCURSEL, index as WPARAM, 0);
 
-        // Update the visible state of the tabs (this is not done automatically)
-        let data: (HWND, i32) = (handle, index as i32);
-        let data_ptr = &data as *const (HWND, i32);
+            // Update the visible state of the tabs (this is not done automatically)
+            let data: (HWND, i32) = (handle, index as i32);
+            let data_ptr = &data as *const (HWND, i32);
         
-        unsafe {
-            EnumChildWindows(handle, Some(toggle_children_tabs), data_ptr as LPARAM);
+            unsafe {
+                EnumChildWindows(handle, Some(toggle_children_tabs), data_ptr as LPARAM);
+            }
+
+            Ok(())
         }
-    }
 
-    /// Return the number of tabs in the view
-    pub fn tab_count(&self) -> usize {
-        use winapi::um::commctrl::TCM_GETITEMCOUNT;
+        /// Return the number of tabs in the view
+        pub fn tab_count(&self) -> usize => try_tab_count {
+            use winapi::um::commctrl::TCM_GETITEMCOUNT;
             
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TCM_GETITEMCOUNT, 0, 0) as usize
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TCM_GETITEMCOUNT, 0, 0) as usize)
+        }
 
-    /**
-        Sets the image list of the tab container. Pass None to remove the image list.
+        /**
+            Move the tab at index `from` to the index `to`. The other tabs are shifted and the selected tab stays selected.
+            Panics if one of the indices is out of bounds.
+        */
+        pub fn move_tab(&self, from: usize, to: usize) => try_move_tab {
+            use winapi::um::commctrl::{TCM_GETITEMW, TCM_DELETEITEM, TCM_INSERTITEMW, TCM_GETCURSEL, TCM_SETCURSEL, TCIF_TEXT, TCIF_IMAGE, TCITEMW};
+            use winapi::um::winuser::GWL_USERDATA;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let count = self.try_tab_count()?;
+            if from >= count || to >= count {
+                panic!("Tab index out of bounds: {} -> {} (tab count: {})", from, to, count);
+            }
 
-        This is only available is the feature "image-list" is enabled.
-    */
-    #[cfg(feature = "image-list")]
-    pub fn set_image_list(&self, list: Option<&ImageList>) {
-        use winapi::um::commctrl::TCM_SETIMAGELIST;
+            if from == to {
+                return Ok(());
+            }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let list_handle = match list {
-            None => 0,
-            Some(list) => list.handle as _
-        };
+            let mut buffer: Vec<u16> = vec![0; 256];
+            let mut item = TCITEMW {
+                mask: TCIF_TEXT | TCIF_IMAGE,
+                dwState: 0,
+                dwStateMask: 0,
+                pszText: buffer.as_mut_ptr(),
+                cchTextMax: buffer.len() as i32,
+                iImage: -1,
+                lParam: 0
+            };
+
+            wh::send_message(handle, TCM_GETITEMW, from as WPARAM, &mut item as *mut TCITEMW as LPARAM);
+
+            // The control may return a pointer to its own buffer, which is freed with the item
+            let text = unsafe { read_text(item.pszText) };
+            let text = to_utf16(&text);
+            item.pszText = text.as_ptr() as LPWSTR;
+
+            let selected = wh::send_message(handle, TCM_GETCURSEL, 0, 0);
+            wh::send_message(handle, TCM_DELETEITEM, from as WPARAM, 0);
+            wh::send_message(handle, TCM_INSERTITEMW, to as WPARAM, &item as *const TCITEMW as LPARAM);
+
+            // The tab windows store their index + 1
+            for tab in tab_windows(handle) {
+                let index = wh::get_window_long(tab, GWL_USERDATA) as usize;
+                if index > 0 {
+                    wh::set_window_long(tab, GWL_USERDATA, moved_index(index - 1, from, to) + 1);
+                }
+            }
 
-        wh::send_message(handle, TCM_SETIMAGELIST, 0, list_handle);
-    }
+            if selected >= 0 {
+                wh::send_message(handle, TCM_SETCURSEL, moved_index(selected as usize, from, to), 0);
+            }
 
-    /**
-        Returns a reference to the current image list in the tab container. The image list
-        is not owned and dropping it won't free the resources.
+            unsafe { show_selected_tab(handle); }
 
-        This is only available is the feature "image-list" is enabled.
-    */
-    #[cfg(feature = "image-list")]
-    pub fn image_list(&self) -> Option<ImageList> {
-        use winapi::um::commctrl::TCM_GETIMAGELIST;
-
-        let control_handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let handle = wh::send_message(control_handle, TCM_GETIMAGELIST, 0, 0);
-        match handle == 0 {
-            true => None,
-            false => Some(ImageList {
-                handle: handle as _,
-                owned: false,
+            Ok(())
+        }
+
+        /// Return the number of rows of tab headers. Always 1 if the container does not have the `MULTILINE` flag.
+        pub fn row_count(&self) -> usize => try_row_count {
+            use winapi::um::commctrl::TCM_GETROWCOUNT;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TCM_GETROWCOUNT, 0, 0) as usize)
+        }
+
+        /**
+            Return the indices of the tabs whose header is fully visible. On a single row, the headers that are
+            hidden by the scroller or clipped by the container are not returned.
+        */
+        pub fn visible_tabs(&self) -> Vec<usize> => try_visible_tabs {
+            use winapi::um::commctrl::{TCM_GETITEMRECT, UPDOWN_CLASS};
+            use winapi::shared::windef::RECT;
+            use winapi::um::winuser::{GetClientRect, GetWindowRect, MapWindowPoints};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let count = self.try_tab_count()?;
+
+            unsafe {
+                let mut area: RECT = mem::zeroed();
+                GetClientRect(handle, &mut area);
+
+                // The scroller of a single row of tabs covers the last headers
+                let mut scroller = None;
+                wh::iterate_window_children(handle, |child| {
+                    if wh::get_window_visibility(child) && wh::get_window_class_name(child) == UPDOWN_CLASS {
+                        scroller = Some(child);
+                    }
+                });
+
+                if let Some(scroller) = scroller {
+                    let mut r: RECT = mem::zeroed();
+                    GetWindowRect(scroller, &mut r);
+                    MapWindowPoints(ptr::null_mut(), handle, &mut r as *mut RECT as _, 2);
+                    area.right = area.right.min(r.left);
+                }
+
+                let visible = (0..count).filter(|&index| {
+                    let mut r: RECT = mem::zeroed();
+                    wh::send_message(handle, TCM_GETITEMRECT, index, &mut r as *mut RECT as LPARAM) != 0
+                        && r.left >= area.left && r.right <= area.right && r.top >= area.top && r.bottom <= area.bottom
+                });
+
+                Ok(visible.collect())
+            }
+        }
+
+        /// Return true if the tab headers have a close button
+        pub fn close_buttons(&self) -> bool => try_close_buttons {
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(self.close_buttons.get())
+        }
+
+        /**
+            Show or hide a close button on each tab header. A click on a close button raises a `OnTabCloseRequest` event.
+            The tabs are not closed automatically: drop the `Tab` to close it.
+        */
+        pub fn set_close_buttons(&self, v: bool) => try_set_close_buttons {
+            use winapi::um::commctrl::TCM_SETPADDING;
+            use winapi::shared::minwindef::MAKELONG;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            self.close_buttons.set(v);
+
+            // The padding is added on both sides of the text, so the text stays centered
+            let padding = match v {
+                true => TAB_PADDING + close_button_size() + TAB_PADDING,
+                false => TAB_PADDING
+            };
+
+            wh::send_message(handle, TCM_SETPADDING, 0, MAKELONG(padding as u16, 3) as LPARAM);
+
+            // The tab headers are only measured again when the font changes
+            unsafe { wh::set_window_font(handle, Some(wh::get_window_font(handle)), true); }
+
+            Ok(())
+        }
+
+        /**
+            Sets the image list of the tab container. Pass None to remove the image list.
+
+            This is only available is the feature "image-list" is enabled.
+        */
+        #[cfg(feature = "image-list")]
+        pub fn set_image_list(&self, list: Option<&ImageList>) => try_set_image_list {
+            use winapi::um::commctrl::TCM_SETIMAGELIST;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let list_handle = match list {
+                None => 0,
+                Some(list) => list.handle as _
+            };
+
+            wh::send_message(handle, TCM_SETIMAGELIST, 0, list_handle);
+
+            Ok(())
+        }
+
+        /**
+            Returns a reference to the current image list in the tab container. The image list
+            is not owned and dropping it won't free the resources.
+
+            This is only available is the feature "image-list" is enabled.
+        */
+        #[cfg(feature = "image-list")]
+        pub fn image_list(&self) -> Option<ImageList> => try_image_list {
+            use winapi::um::commctrl::TCM_GETIMAGELIST;
+
+            let control_handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let handle = wh::send_message(control_handle, TCM_GETIMAGELIST, 0, 0);
+            Ok(match handle == 0 {
+                true => None,
+                false => Some(ImageList {
+                    handle: handle as _,
+                    owned: false,
+                })
             })
         }
     }
@@ -148,82 +320,96 @@ impl TabsContainer {
     // Default methods
     //
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+    try_accessors! {
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Set the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Set the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+            Ok(())
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Return the size of the tabs container in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Set the size of the tabs container in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
 
-    /// Return the position of the tabs container in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the position of the tabs container in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
-    }
+        /// Return the size of the tabs container in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+        /// Set the size of the tabs container in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
         }
-    }
 
-    /// Set the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
+        /// Return the position of the tabs container in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the tabs container in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
+        }
+
+        /// Set the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -252,7 +438,7 @@ impl TabsContainer {
     fn hook_tabs(&self) {
         use crate::bind_raw_event_handler_inner;
         use winapi::shared::minwindef::{HIWORD, LOWORD};
-        use winapi::um::winuser::{NMHDR, WM_SIZE, WM_NOTIFY};
+        use winapi::um::winuser::{NMHDR, WM_SIZE, WM_NOTIFY, WM_PAINT, WM_LBUTTONDOWN};
         use winapi::um::commctrl::{TCM_GETCURSEL, TCN_SELCHANGE};
         use winapi::um::winuser::SendMessageW;
 
@@ -265,7 +451,7 @@ impl TabsContainer {
         let handler0 = bind_raw_event_handler_inner(&parent_handle, handle as usize, move |_hwnd, msg, _w, l| { unsafe {
             match msg {
                 WM_NOTIFY => {
-                    let nmhdr: &NMHDR = mem::transmute(l);
+                    let nmhdr = &*(l as *const NMHDR);
                     if nmhdr.code == TCN_SELCHANGE {
                         let index = SendMessageW(handle, TCM_GETCURSEL, 0, 0) as i32;
                         let data: (HWND, i32) = (handle, index);
@@ -279,8 +465,33 @@ impl TabsContainer {
             None
         } });
 
-        let handler1 = bind_raw_event_handler_inner(&self.handle, handle as usize, move |hwnd, msg, _w, l| { unsafe {
+        let close_buttons = self.close_buttons.clone();
+        let handler1 = bind_raw_event_handler_inner(&self.handle, handle as usize, move |hwnd, msg, w, l| { unsafe {
             match msg {
+                WM_PAINT if close_buttons.get() => {
+                    use winapi::um::commctrl::DefSubclassProc;
+
+                    DefSubclassProc(hwnd, msg, w, l);
+                    paint_close_buttons(hwnd);
+                    return Some(0);
+                },
+                WM_LBUTTONDOWN if close_buttons.get() => {
+                    let x = LOWORD(l as u32) as i16 as i32;
+                    let y = HIWORD(l as u32) as i16 as i32;
+                    if let Some(index) = close_button_hit(hwnd, x, y) {
+                        // Do not select the tab that is being closed
+                        wh::send_message(hwnd, wh::NWG_TAB_CLOSE, index, 0);
+                        return Some(0);
+                    }
+                },
+                WM_SIZE if has_custom_layout(hwnd) => {
+                    use winapi::um::commctrl::DefSubclassProc;
+
+                    // The rows of headers are computed by the control when it is resized
+                    DefSubclassProc(hwnd, msg, w, l);
+                    resize_tabs_to_display_area(hwnd);
+                    return Some(0);
+                },
                 WM_SIZE => {
                     use winapi::shared::windef::{RECT, HGDIOBJ};
                     use winapi::um::winuser::{GetDC, DrawTextW, ReleaseDC, DT_CALCRECT, DT_LEFT};
@@ -361,6 +572,7 @@ pub struct TabsContainerBuilder<'a> {
     font: Option<&'a Font>,
     flags: Option<TabsContainerFlags>,
     ex_flags: u32,
+    close_buttons: bool,
 
     #[cfg(feature = "image-list")]
     image_list: Option<&'a ImageList>
@@ -404,6 +616,11 @@ impl<'a> TabsContainerBuilder<'a> {
         self
     }
 
+    pub fn close_buttons(mut self, v: bool) -> TabsContainerBuilder<'a> {
+        self.close_buttons = v;
+        self
+    }
+
     pub fn build(self, out: &mut TabsContainer) -> Result<(), NwgError> {
         let flags = self.flags.map(|f| f.bits()).unwrap_or(out.flags());
 
@@ -432,6 +649,10 @@ impl<'a> TabsContainerBuilder<'a> {
             out.set_font(Font::global_default().as_ref());
         }
 
+        if self.close_buttons {
+            out.set_close_buttons(true);
+        }
+
         // Image list
         #[cfg(feature = "image-list")]
         fn set_image_list(b: &TabsContainerBuilder, out: &mut TabsContainer) {
@@ -455,6 +676,9 @@ A subwindow in a `TabContainer` widget. A Tab control can only be added as a chi
 
 A Tab controls doesn't do much on its own. See `TabContainer` for the tab specific events.
 
+Dropping a Tab removes its header from the `TabContainer` and the tabs after it move back by one index.
+If the dropped tab was selected, the next tab is selected. A Tab must be kept alive as long as its header should be displayed.
+
 **Builder parameters:**
   * `parent`:      **Required.** The Tab parent container.
   * `text`:        The tab text
@@ -477,87 +701,120 @@ impl Tab {
         }
     }
 
-    /// Sets the title of the tab
-    pub fn set_text<'a>(&self, text: &'a str) {
-        use winapi::um::commctrl::{TCM_SETITEMW, TCIF_TEXT, TCITEMW};
-        use winapi::um::winuser::GWL_USERDATA;
+    try_accessors! {
+        /// Sets the title of the tab
+        pub fn set_text<'a>(&self, text: &'a str) => try_set_text {
+            use winapi::um::commctrl::{TCM_SETITEMW, TCIF_TEXT, TCITEMW};
+            use winapi::um::winuser::GWL_USERDATA;
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let tab_index = (wh::get_window_long(handle, GWL_USERDATA) - 1) as WPARAM;
+            let tab_index = (wh::get_window_long(handle, GWL_USERDATA) - 1) as WPARAM;
 
-        let tab_view_handle = wh::get_window_parent(handle);
+            let tab_view_handle = wh::get_window_parent(handle);
 
-        let text = to_utf16(text);
-        let item = TCITEMW {
-            mask: TCIF_TEXT,
-            dwState: 0,
-            dwStateMask: 0,
-            pszText: text.as_ptr() as LPWSTR,
-            cchTextMax: 0,
-            iImage: -1,
-            lParam: 0
-        };
+            let text = to_utf16(text);
+            let item = TCITEMW {
+                mask: TCIF_TEXT,
+                dwState: 0,
+                dwStateMask: 0,
+                pszText: text.as_ptr() as LPWSTR,
+                cchTextMax: 0,
+                iImage: -1,
+                lParam: 0
+            };
 
-        let item_ptr = &item as *const TCITEMW;
-        wh::send_message(tab_view_handle, TCM_SETITEMW, tab_index, item_ptr as LPARAM);
-    }
+            let item_ptr = &item as *const TCITEMW;
+            wh::send_message(tab_view_handle, TCM_SETITEMW, tab_index, item_ptr as LPARAM);
 
-    /**
-        Sets the image of the tab. index is the index of the image in the tab container image list.
+            Ok(())
+        }
 
-        This is only available if the "image-list" feature is enabled
-    */
-    #[cfg(feature = "image-list")]
-    pub fn set_image_index(&self, index: Option<i32>) {
-        use winapi::um::commctrl::{TCM_SETITEMW, TCIF_IMAGE, TCITEMW};
-        use winapi::um::winuser::GWL_USERDATA;
+        /**
+            Sets the image of the tab. index is the index of the image in the tab container image list.
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+            This is only available if the "image-list" feature is enabled
+        */
+        #[cfg(feature = "image-list")]
+        pub fn set_image_index(&self, index: Option<i32>) => try_set_image_index {
+            use winapi::um::commctrl::{TCM_SETITEMW, TCIF_IMAGE, TCITEMW};
+            use winapi::um::winuser::GWL_USERDATA;
 
-        let tab_index = (wh::get_window_long(handle, GWL_USERDATA) - 1) as WPARAM;
-        let tab_view_handle = wh::get_window_parent(handle);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let item = TCITEMW {
-            mask: TCIF_IMAGE,
-            dwState: 0,
-            dwStateMask: 0,
-            pszText: ptr::null_mut(),
-            cchTextMax: 0,
-            iImage: index.unwrap_or(-1),
-            lParam: 0
-        };
+            let tab_index = (wh::get_window_long(handle, GWL_USERDATA) - 1) as WPARAM;
+            let tab_view_handle = wh::get_window_parent(handle);
 
-        let item_ptr = &item as *const TCITEMW;
-        wh::send_message(tab_view_handle, TCM_SETITEMW, tab_index, item_ptr as LPARAM);
-    }
+            let item = TCITEMW {
+                mask: TCIF_IMAGE,
+                dwState: 0,
+                dwStateMask: 0,
+                pszText: ptr::null_mut(),
+                cchTextMax: 0,
+                iImage: index.unwrap_or(-1),
+                lParam: 0
+            };
 
-    /**
-        Returns the index of image of the tab.
-        The index maps to the image list of the tab container.
+            let item_ptr = &item as *const TCITEMW;
+            wh::send_message(tab_view_handle, TCM_SETITEMW, tab_index, item_ptr as LPARAM);
 
-        This is only available if the "image-list" feature is enabled
-    */
-    #[cfg(feature = "image-list")]
-    pub fn image_index(&self) -> Option<i32> {
-        None
-    }
+            Ok(())
+        }
 
-    /// Returns true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+        /**
+            Returns the index of image of the tab.
+            The index maps to the image list of the tab container.
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
+            This is only available if the "image-list" feature is enabled
+        */
+        #[cfg(feature = "image-list")]
+        pub fn image_index(&self) -> Option<i32> => try_image_index {
+            use winapi::um::commctrl::{TCM_GETITEMW, TCIF_IMAGE, TCITEMW};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let tab_view_handle = wh::get_window_parent(handle);
+
+            let mut item = TCITEMW {
+                mask: TCIF_IMAGE,
+                dwState: 0,
+                dwStateMask: 0,
+                pszText: ptr::null_mut(),
+                cchTextMax: 0,
+                iImage: -1,
+                lParam: 0
+            };
+
+            let item_ptr = &mut item as *mut TCITEMW;
+            wh::send_message(tab_view_handle, TCM_GETITEMW, self.try_index()? as WPARAM, item_ptr as LPARAM);
+
+            Ok(match item.iImage {
+                -1 => None,
+                i => Some(i)
+            })
+        }
+
+        /// Returns the index of the tab in the tab container
+        pub fn index(&self) -> usize => try_index {
+            use winapi::um::winuser::GWL_USERDATA;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok((wh::get_window_long(handle, GWL_USERDATA) - 1) as usize)
+        }
+
+        /// Returns true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
     }
     
 
@@ -645,12 +902,24 @@ impl Tab {
 
         let tab_info_ptr = &tab_info as *const TCITEMW;
         wh::send_message(tab_view_handle, TCM_INSERTITEMW, next_index as WPARAM, tab_info_ptr as LPARAM);
+
+        // A new header can add a row of headers
+        if has_custom_layout(tab_view_handle) {
+            unsafe { resize_tabs_to_display_area(tab_view_handle); }
+        }
     }
 
 }
 
 impl Drop for Tab {
     fn drop(&mut self) {
+        if let Some(handle) = self.handle.hwnd() {
+            // The tabs container might already be destroyed
+            if wh::window_valid(handle) {
+                unsafe { remove_tab(handle); }
+            }
+        }
+
         self.handle.destroy();
     }
 }
@@ -733,6 +1002,185 @@ impl<'a> TabBuilder<'a> {
 }
 
 
+/// Remove the header of a tab and shift the index of the next tabs
+unsafe fn remove_tab(handle: HWND) {
+    use winapi::um::commctrl::{TCM_DELETEITEM, TCM_GETCURSEL, TCM_SETCURSEL, TCM_GETITEMCOUNT};
+    use winapi::um::winuser::GWL_USERDATA;
+
+    let tab_view_handle = wh::get_window_parent(handle);
+    let index = wh::get_window_long(handle, GWL_USERDATA) as usize;
+    if index == 0 {
+        return;
+    }
+
+    let removed = index - 1;
+    let selected = wh::send_message(tab_view_handle, TCM_GETCURSEL, 0, 0);
+
+    wh::send_message(tab_view_handle, TCM_DELETEITEM, removed as WPARAM, 0);
+    wh::set_window_long(handle, GWL_USERDATA, 0);
+
+    for tab in tab_windows(tab_view_handle) {
+        let index = wh::get_window_long(tab, GWL_USERDATA) as usize;
+        if index > removed + 1 {
+            wh::set_window_long(tab, GWL_USERDATA, index - 1);
+        }
+    }
+
+    // Select the next tab if the selected tab was removed
+    let count = wh::send_message(tab_view_handle, TCM_GETITEMCOUNT, 0, 0) as usize;
+    if selected == removed as isize && count > 0 {
+        wh::send_message(tab_view_handle, TCM_SETCURSEL, removed.min(count - 1), 0);
+    }
+
+    show_selected_tab(tab_view_handle);
+
+    if has_custom_layout(tab_view_handle) {
+        resize_tabs_to_display_area(tab_view_handle);
+    }
+}
+
+/// Show the tab window of the selected tab and hide the others
+unsafe fn show_selected_tab(tab_view_handle: HWND) {
+    use winapi::um::commctrl::TCM_GETCURSEL;
+
+    let index = wh::send_message(tab_view_handle, TCM_GETCURSEL, 0, 0) as i32;
+    let data: (HWND, i32) = (tab_view_handle, index);
+    let data_ptr = &data as *const (HWND, i32);
+    EnumChildWindows(tab_view_handle, Some(toggle_children_tabs), data_ptr as LPARAM);
+}
+
+/// Return true if the tab headers are not a single row at the top of the container
+fn has_custom_layout(tab_view_handle: HWND) -> bool {
+    wh::get_style(tab_view_handle) & (TCS_MULTILINE | TCS_BOTTOM | TCS_VERTICAL) != 0
+}
+
+/// Fit the tab windows in the display area of the container, which excludes the tab headers
+unsafe fn resize_tabs_to_display_area(tab_view_handle: HWND) {
+    use winapi::um::commctrl::TCM_ADJUSTRECT;
+    use winapi::um::winuser::GetClientRect;
+    use winapi::shared::windef::RECT;
+    use crate::win32::high_dpi::physical_to_logical;
+
+    let mut r: RECT = mem::zeroed();
+    GetClientRect(tab_view_handle, &mut r);
+    wh::send_message(tab_view_handle, TCM_ADJUSTRECT, 0, &mut r as *mut RECT as LPARAM);
+
+    let (x, y) = physical_to_logical(r.left, r.top);
+    let (width, height) = physical_to_logical((r.right - r.left).max(0), (r.bottom - r.top).max(0));
+
+    for tab in tab_windows(tab_view_handle) {
+        wh::set_window_size(tab, width as u32, height as u32, false);
+        wh::set_window_position(tab, x, y);
+    }
+}
+
+/// The tab windows of a tabs container
+fn tab_windows(tab_view_handle: HWND) -> Vec<HWND> {
+    let mut tabs = Vec::new();
+    wh::iterate_window_children(tab_view_handle, |child| {
+        if unsafe { &wh::get_window_class_name(child) } == "NWG_TAB" {
+            tabs.push(child);
+        }
+    });
+
+    tabs
+}
+
+/// The new index of the tab at `index` after the tab at `from` was moved to `to`
+fn moved_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < to && index > from && index <= to {
+        index - 1
+    } else if to < from && index >= to && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+unsafe fn read_text(text: *const u16) -> String {
+    let mut length = 0;
+    while *text.add(length) != 0 {
+        length += 1;
+    }
+
+    from_utf16(std::slice::from_raw_parts(text, length))
+}
+
+fn close_button_size() -> i32 {
+    unsafe { crate::win32::high_dpi::logical_to_physical(CLOSE_BUTTON_SIZE, 0).0 }
+}
+
+/// The rectangle of the close button of the tab header at `index`
+unsafe fn close_button_rect(hwnd: HWND, index: usize) -> Option<winapi::shared::windef::RECT> {
+    use winapi::um::commctrl::TCM_GETITEMRECT;
+    use winapi::shared::windef::RECT;
+
+    let mut r: RECT = mem::zeroed();
+    if wh::send_message(hwnd, TCM_GETITEMRECT, index, &mut r as *mut RECT as LPARAM) == 0 {
+        return None;
+    }
+
+    let size = close_button_size();
+    let top = r.top + (r.bottom - r.top - size) / 2;
+    let right = r.right - TAB_PADDING;
+
+    Some(RECT { left: right - size, top, right, bottom: top + size })
+}
+
+/// Draw a cross over the close button of each tab header
+unsafe fn paint_close_buttons(hwnd: HWND) {
+    use winapi::um::winuser::{GetDC, ReleaseDC, GetSysColor, COLOR_BTNTEXT};
+    use winapi::um::wingdi::{CreatePen, SelectObject, DeleteObject, MoveToEx, LineTo, PS_SOLID};
+    use winapi::um::commctrl::TCM_GETITEMCOUNT;
+
+    let count = wh::send_message(hwnd, TCM_GETITEMCOUNT, 0, 0) as usize;
+
+    let dc = GetDC(hwnd);
+    let pen = CreatePen(PS_SOLID as _, 1, GetSysColor(COLOR_BTNTEXT));
+    let old = SelectObject(dc, pen as _);
+
+    for index in 0..count {
+        if let Some(r) = close_button_rect(hwnd, index) {
+            // `LineTo` does not draw the last pixel
+            MoveToEx(dc, r.left, r.top, ptr::null_mut());
+            LineTo(dc, r.right + 1, r.bottom + 1);
+            MoveToEx(dc, r.right, r.top, ptr::null_mut());
+            LineTo(dc, r.left - 1, r.bottom + 1);
+        }
+    }
+
+    SelectObject(dc, old);
+    DeleteObject(pen as _);
+    ReleaseDC(hwnd, dc);
+}
+
+/// Return the index of the tab if `[x, y]` is over its close button
+unsafe fn close_button_hit(hwnd: HWND, x: i32, y: i32) -> Option<usize> {
+    use winapi::um::commctrl::{TCM_HITTEST, TCHITTESTINFO};
+    use winapi::um::winuser::PtInRect;
+    use winapi::shared::windef::POINT;
+
+    let pt = POINT { x, y };
+    let mut info = TCHITTESTINFO { pt, flags: 0 };
+    let index = wh::send_message(hwnd, TCM_HITTEST, 0, &mut info as *mut TCHITTESTINFO as LPARAM);
+    if index < 0 {
+        return None;
+    }
+
+    let index = index as usize;
+    let mut r = close_button_rect(hwnd, index)?;
+
+    // Make the button easier to click
+    r.left -= 2; r.top -= 2; r.right += 2; r.bottom += 2;
+
+    match PtInRect(&r, pt) != 0 {
+        true => Some(index),
+        false => None
+    }
+}
+
 struct ResizeDirectChildrenParams {
     parent: HWND,
     width: u32,
@@ -757,7 +1205,7 @@ unsafe extern "system" fn count_children(handle: HWND, params: LPARAM) -> BOOL {
 
     if &wh::get_window_class_name(handle) == "NWG_TAB" {
         let tab_index = (wh::get_window_long(handle, GWL_USERDATA)) as WPARAM;
-        let count: &mut usize = ::std::mem::transmute(params);
+        let count = &mut *(params as *mut usize);
         *count = usize::max(tab_index+1, *count);
     }
     
@@ -768,7 +1216,7 @@ unsafe extern "system" fn count_children(handle: HWND, params: LPARAM) -> BOOL {
 unsafe extern "system" fn toggle_children_tabs(handle: HWND, params: LPARAM) -> BOOL {
     use winapi::um::winuser::GWL_USERDATA;
     
-    let &(parent, index): &(HWND, i32) = mem::transmute(params);
+    let &(parent, index) = &*(params as *const (HWND, i32));
     if wh::get_window_parent(handle) == parent {
         let tab_index = wh::get_window_long(handle, GWL_USERDATA) as i32;
         let visible = tab_index == index + 1;
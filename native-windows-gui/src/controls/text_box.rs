@@ -1,6 +1,7 @@
 use winapi::shared::minwindef::{WPARAM, LPARAM};
 use winapi::um::winuser::{WS_VSCROLL, WS_HSCROLL, ES_AUTOVSCROLL, ES_AUTOHSCROLL, WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
 use crate::win32::window_helper as wh;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::{Font, NwgError};
 use super::{ControlBase, ControlHandle};
 use std::ops::Range;
@@ -57,12 +58,15 @@ Note: Use `\r\n` to input a new line not just `\n`.
 
 **Control events:**
   * `OnTextInput`: When a TextBox value is changed
+  * `OnTextLimitReached`: When the user tries to insert more characters than the control `limit`
   * `MousePress(_)`: Generic mouse press events on the button
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
   * `OnKeyPress`:    Generic key press event
   * `OnKeyRelease`:  Generic key release event
 
+Word wrap is enabled unless the text box has the `AUTOHSCROLL` or `HSCROLL` flags. See `set_word_wrap` to change it after creation.
+
 ```rust
 use native_windows_gui as nwg;
 fn build_box(tbox: &mut nwg::TextBox, window: &nwg::Window, font: &nwg::Font) {
@@ -96,96 +100,163 @@ impl TextBox {
         }
     }
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+    try_accessors! {
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
         }
-    }
 
-    /// Set the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Set the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-    /// Return the number of maximum character allowed in this text input
-    pub fn limit(&self) -> u32 {
-        use winapi::um::winuser::EM_GETLIMITTEXT;
+            Ok(())
+        }
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+        /// Return the number of maximum character allowed in this text input
+        pub fn limit(&self) -> u32 => try_limit {
+            use winapi::um::winuser::EM_GETLIMITTEXT;
 
-        wh::send_message(handle, EM_GETLIMITTEXT as u32, 0, 0) as u32
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Set the number of maximum character allowed in this text input
-    pub fn set_limit(&self, limit: usize) {
-        use winapi::um::winuser::EM_SETLIMITTEXT;
+            Ok(wh::send_message(handle, EM_GETLIMITTEXT as u32, 0, 0) as u32)
+        }
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+        /// Set the number of maximum character allowed in this text input
+        pub fn set_limit(&self, limit: usize) => try_set_limit {
+            use winapi::um::winuser::EM_SETLIMITTEXT;
 
-        wh::send_message(handle, EM_SETLIMITTEXT as u32, limit, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Check if the content of the text input was modified after it's creation
-    pub fn modified(&self) -> bool {
-        use winapi::um::winuser::EM_GETMODIFY;
+            wh::send_message(handle, EM_SETLIMITTEXT as u32, limit, 0);
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+            Ok(())
+        }
 
-        wh::send_message(handle, EM_GETMODIFY as u32, 0, 0) != 0
-    }
+        /// Check if the content of the text input was modified after it's creation
+        pub fn modified(&self) -> bool => try_modified {
+            use winapi::um::winuser::EM_GETMODIFY;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            Ok(wh::send_message(handle, EM_GETMODIFY as u32, 0, 0) != 0)
+        }
+
+        /// Manually set modified flag of the text input
+        pub fn set_modified(&self, e: bool) => try_set_modified {
+            use winapi::um::winuser::EM_SETMODIFY;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            wh::send_message(handle, EM_SETMODIFY as u32, e as usize, 0);
+
+            Ok(())
+        }
+
+        /// Undo the last action by the user in the control
+        pub fn undo(&self) => try_undo {
+            use winapi::um::winuser::EM_UNDO;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            wh::send_message(handle, EM_UNDO as u32, 0, 0);
+
+            Ok(())
+        }
+
+        /// Check if the last action of the user can be undone.
+        /// Edit controls only remember the last action, and undoing an undo redoes the action.
+        pub fn can_undo(&self) -> bool => try_can_undo {
+            use winapi::um::winuser::EM_CANUNDO;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            Ok(wh::send_message(handle, EM_CANUNDO as u32, 0, 0) != 0)
+        }
+
+        /// Clear the undo buffer of the control
+        pub fn empty_undo_buffer(&self) => try_empty_undo_buffer {
+            use winapi::um::winuser::EM_EMPTYUNDOBUFFER;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            wh::send_message(handle, EM_EMPTYUNDOBUFFER as u32, 0, 0);
+
+            Ok(())
+        }
 
-    /// Manually set modified flag of the text input
-    pub fn set_modified(&self, e: bool) {
-        use winapi::um::winuser::EM_SETMODIFY;
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+        /// Return the selected range of characters by the user in the text input
+        pub fn selection(&self) -> Range<u32> => try_selection {
+            use winapi::um::winuser::EM_GETSEL;
 
-        wh::send_message(handle, EM_SETMODIFY as u32, e as usize, 0);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let (mut out1, mut out2) = (0u32, 0u32);
+            let (ptr1, ptr2) = (&mut out1 as *mut u32, &mut out2 as *mut u32);
+            wh::send_message(handle, EM_GETSEL as u32, ptr1 as WPARAM, ptr2 as LPARAM);
+
+            Ok(Range { start: out1 as u32, end: out2 as u32 })
+        }
+
+        /// Return the selected range of characters by the user in the text input
+        pub fn set_selection(&self, r: Range<u32>) => try_set_selection {
+            use winapi::um::winuser::EM_SETSEL;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
+
+            Ok(())
+        }
     }
 
-    /// Undo the last action by the user in the control
-    pub fn undo(&self) {
-        use winapi::um::winuser::EM_UNDO;
+    /// Return the text currently selected by the user in the text box
+    pub fn selected_text(&self) -> String {
+        let text: Vec<u16> = self.text().encode_utf16().collect();
+        let sel = self.selection();
+        let start = (sel.start as usize).min(text.len());
+        let end = (sel.end as usize).min(text.len()).max(start);
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+        String::from_utf16_lossy(&text[start..end])
+    }
 
-        wh::send_message(handle, EM_UNDO as u32, 0, 0);
+    /// Return the position of the caret in the text box
+    pub fn caret(&self) -> u32 {
+        self.selection().end
     }
 
-    /// Return the selected range of characters by the user in the text input
-    pub fn selection(&self) -> Range<u32> {
-        use winapi::um::winuser::EM_GETSEL;
+    try_accessors! {
+        /// Move the caret of the text box at the character index `pos` and scroll it into view. This clears the selection.
+        pub fn set_caret(&self, pos: u32) => try_set_caret {
+            use winapi::um::winuser::{EM_SETSEL, EM_SCROLLCARET};
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETSEL as u32, pos as usize, pos as isize);
+            wh::send_message(handle, EM_SCROLLCARET as u32, 0, 0);
 
-        let (mut out1, mut out2) = (0u32, 0u32);
-        let (ptr1, ptr2) = (&mut out1 as *mut u32, &mut out2 as *mut u32);
-        wh::send_message(handle, EM_GETSEL as u32, ptr1 as WPARAM, ptr2 as LPARAM);
+            Ok(())
+        }
 
-        Range { start: out1 as u32, end: out2 as u32 }
-    }
+        /// Return the index of the character nearest to the point `(x, y)`. The point is local to the control.
+        /// Because of a winapi limitation, the index is not valid if the text is longer than 65535 characters.
+        pub fn char_index_from_position(&self, x: i32, y: i32) -> u32 => try_char_index_from_position {
+            use winapi::um::winuser::EM_CHARFROMPOS;
+            use winapi::shared::minwindef::{MAKELONG, LOWORD};
+            use crate::win32::high_dpi;
 
-    /// Return the selected range of characters by the user in the text input
-    pub fn set_selection(&self, r: Range<u32>) {
-        use winapi::um::winuser::EM_SETSEL;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let (x, y) = unsafe { high_dpi::logical_to_physical(x, y) };
+            let pos = MAKELONG(x as u16, y as u16) as LPARAM;
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
+            let result = wh::send_message(handle, EM_CHARFROMPOS as u32, 0, pos);
+            Ok(LOWORD(result as u32) as u32)
+        }
     }
 
     /// Return the length of the user input in the control. Performs a newline conversion first since
@@ -198,48 +269,176 @@ impl TextBox {
     
     /// Return the number of lines in the multiline edit control.
     /// If the control has no text, the return value is 1.
+    #[deprecated(note = "Use `line_count` instead")]
     pub fn linecount(&self) -> i32 {
-        use winapi::um::winuser::EM_GETLINECOUNT;
-
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        wh::send_message(handle, EM_GETLINECOUNT as u32, 0, 0) as i32
+        self.line_count() as i32
     }  
+
+    try_accessors! {
+        /// Return the number of lines in the multiline edit control.
+        /// If the control has no text, the return value is 1.
+        pub fn line_count(&self) -> u32 => try_line_count {
+            use winapi::um::winuser::EM_GETLINECOUNT;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_GETLINECOUNT as u32, 0, 0) as u32)
+        }
+
+        /// Return the text of the line at index `line`, without the line break. Lines are zero-indexed.
+        /// If word wrap is enabled, a line is a visual line and not a line of the text.
+        pub fn line(&self, line: u32) -> String => try_line {
+            use winapi::um::winuser::{EM_GETLINE, EM_LINELENGTH};
+            use crate::win32::base_helper::from_utf16;
+
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let handle = self.handle.hwnd().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            if line >= self.line_count() {
+                return Ok(String::new());
+            }
+
+            let index = self.line_index(line);
+            let length = wh::send_message(handle, EM_LINELENGTH as u32, index as WPARAM, 0) as usize;
+
+            // The first word of the buffer must be set to the size of the buffer
+            let mut buffer: Vec<u16> = vec![0; length.max(1) + 1];
+            buffer[0] = buffer.len() as u16;
+
+            let copied = wh::send_message(handle, EM_GETLINE as u32, line as WPARAM, buffer.as_mut_ptr() as LPARAM) as usize;
+            Ok(from_utf16(&buffer[..copied]))
+        }
+
+        /// Return the character index of the first character of the line at index `line`. Lines are zero-indexed.
+        /// If `line` is out of bounds, returns the length of the text.
+        pub fn line_index(&self, line: u32) -> u32 => try_line_index {
+            use winapi::um::winuser::{EM_LINEINDEX, GetWindowTextLengthW};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            Ok(match wh::send_message(handle, EM_LINEINDEX as u32, line as WPARAM, 0) {
+                -1 => unsafe { GetWindowTextLengthW(handle) as u32 },
+                index => index as u32
+            })
+        }
+
+        /// Return the index of the line that contains the character at index `index`
+        pub fn line_from_char(&self, index: u32) -> u32 => try_line_from_char {
+            use winapi::um::winuser::EM_LINEFROMCHAR;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_LINEFROMCHAR as u32, index as WPARAM, 0) as u32)
+        }
+
+        /// Return the index of the uppermost visible line
+        pub fn first_visible_line(&self) -> u32 => try_first_visible_line {
+            use winapi::um::winuser::EM_GETFIRSTVISIBLELINE;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_GETFIRSTVISIBLELINE as u32, 0, 0) as u32)
+        }
+    }
+
+    /// Scroll the text box so that the line at index `line` is the first visible line.
+    /// Lines are zero-indexed. If `line` is out of bounds, the text box scrolls to the last line.
+    pub fn scroll_to_line(&self, line: u32) {
+        let first = self.first_visible_line() as i32;
+        self.scroll(line as i32 - first);
+    }
+
+    try_accessors! {
+        /// Return true if the text box wraps the lines that are wider than the control
+        pub fn word_wrap(&self) -> bool => try_word_wrap {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::get_style(handle) & (ES_AUTOHSCROLL | WS_HSCROLL) == 0)
+        }
+    }
+
+    /// Enable or disable word wrap. Disabling word wrap adds a horizontal scrollbar to the text box.
+    ///
+    /// Edit controls cannot change their word wrap after creation, so the control is recreated behind the scenes.
+    /// The text, the selection, the scroll position, the font and the event handlers are moved to the new control.
+    /// Because the handle of the text box changes, layouts that contain the text box must be updated
+    /// (ex: calling `remove_child` before and `add_child` after) and raw event handlers bound by the application must be bound again.
+    pub fn set_word_wrap(&mut self, wrap: bool) {
+        self.try_set_word_wrap(wrap).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `set_word_wrap`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the control is not bound or if its window was destroyed.
+    pub fn try_set_word_wrap(&mut self, wrap: bool) -> Result<(), NwgError> {
+        use crate::win32::window::recreate_control;
+
+        if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+        let handle = self.handle.hwnd().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+        if self.word_wrap() == wrap {
+            return Ok(());
+        }
+
+        let style = match wrap {
+            true => wh::get_style(handle) & !(ES_AUTOHSCROLL | WS_HSCROLL),
+            false => wh::get_style(handle) | ES_AUTOHSCROLL | WS_HSCROLL,
+        };
+
+        let text = self.text();
+        let selection = self.selection();
+        let modified = self.modified();
+        let limit = self.limit();
+        let first_line = self.first_visible_line();
+
+        self.handle = ControlHandle::Hwnd(unsafe { recreate_control(handle, style) });
+
+        self.set_limit(limit as usize);
+        self.set_text(&text);
+        self.set_selection(selection);
+        self.set_modified(modified);
+        self.scroll_to_line(first_line);
+
+        wh::destroy_window(handle);
+
+        Ok(())
+    }
     
-    /// Scroll `v` lines in the multiline edit control.
-    pub fn scroll(&self, v: i32) {
-        use winapi::um::winuser::EM_LINESCROLL;
+    try_accessors! {
+        /// Scroll `v` lines in the multiline edit control.
+        pub fn scroll(&self, v: i32) => try_scroll {
+            use winapi::um::winuser::EM_LINESCROLL;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_LINESCROLL as u32, 0, v as LPARAM);
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        wh::send_message(handle, EM_LINESCROLL as u32, 0, v as LPARAM);
+            Ok(())
+        }
     }
     
     /// Get the linecount and then scroll the text to the last line
     pub fn scroll_lastline(&self) {
-        let lines = self.linecount();
+        let lines = self.line_count() as i32;
         self.scroll(lines * -1);
         self.scroll(lines - 2);
     }
 
-    /// Return true if the TextInput value cannot be edited. Retrurn false otherwise.
-    /// A user can still copy text from a readonly TextEdit (unlike disabled)
-    pub fn readonly(&self) -> bool {
-        use winapi::um::winuser::ES_READONLY;
+    try_accessors! {
+        /// Return true if the TextInput value cannot be edited. Retrurn false otherwise.
+        /// A user can still copy text from a readonly TextEdit (unlike disabled)
+        pub fn readonly(&self) -> bool => try_readonly {
+            use winapi::um::winuser::ES_READONLY;
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        wh::get_style(handle) & ES_READONLY == ES_READONLY
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::get_style(handle) & ES_READONLY == ES_READONLY)
+        }
+
+        /// Set the readonly flag of the text input
+        /// A user can still copy text from a readonly TextEdit (unlike disabled)
+        pub fn set_readonly(&self, r: bool) => try_set_readonly {
+            use winapi::um::winuser::EM_SETREADONLY;
 
-    /// Set the readonly flag of the text input
-    /// A user can still copy text from a readonly TextEdit (unlike disabled)
-    pub fn set_readonly(&self, r: bool) {
-        use winapi::um::winuser::EM_SETREADONLY;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETREADONLY as u32, r as WPARAM, 0);
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        wh::send_message(handle, EM_SETREADONLY as u32, r as WPARAM, 0);
+            Ok(())
+        }
     }
 
     /// Remove all text from the textbox
@@ -247,96 +446,99 @@ impl TextBox {
         self.set_text("");
     }
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+    try_accessors! {
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Set the keyboard focus on the button
-    pub fn set_focus(&self) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Set the keyboard focus on the button
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+            Ok(())
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
-    }
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Return the text displayed in the TextInput
-    pub fn text(&self) -> String { 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::get_window_text(handle) }
-    }
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
 
-    /// Set the text displayed in the TextInput
-    pub fn set_text<'a>(&self, v: &'a str) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
-    }
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
 
-    /// Set the text in the current control, converting unix-style newlines in the input to "\r\n"
-    pub fn set_text_unix2dos<'a>(&self, v: &'a str) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        let handle = self.handle.hwnd().expect(BAD_HANDLE);
-        unsafe { wh::set_window_text(handle,  &unix2dos(&v).to_string()) }
+        /// Return the text displayed in the TextInput
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
+
+        /// Set the text displayed in the TextInput
+        pub fn set_text<'a>(&self, v: &'a str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, v) }
+
+            Ok(())
+        }
+
+        /// Set the text in the current control, converting unix-style newlines in the input to "\r\n"
+        pub fn set_text_unix2dos<'a>(&self, v: &'a str) => try_set_text_unix2dos {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle,  &unix2dos(&v).to_string()) }
+
+            Ok(())
+        }
     }
 
     /// Append text to the current control
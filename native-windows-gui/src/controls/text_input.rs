@@ -1,16 +1,16 @@
 use winapi::shared::{
-    windef::HBRUSH,
-    minwindef::{UINT, WPARAM, LPARAM}
+    windef::{HBRUSH, HWND},
+    minwindef::{UINT, WPARAM, LPARAM, LRESULT}
 };
 use winapi::um::{
-    winuser::{WS_VISIBLE, WS_DISABLED, ES_NUMBER, ES_LEFT, ES_CENTER, ES_RIGHT, WS_TABSTOP, ES_AUTOHSCROLL},
+    winuser::{WS_VISIBLE, WS_DISABLED, ES_NUMBER, ES_LEFT, ES_CENTER, ES_RIGHT, WS_TABSTOP, ES_AUTOHSCROLL, ES_PASSWORD},
     wingdi::DeleteObject,
 };
 use crate::win32::window_helper as wh; 
-use crate::win32::base_helper::{check_hwnd, to_utf16};
+use crate::win32::base_helper::{try_check_hwnd, to_utf16};
 use crate::{Font, NwgError, HTextAlign, RawEventHandler};
 use super::{ControlBase, ControlHandle};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::ops::Range;
 use std::char;
 
@@ -28,6 +28,7 @@ bitflags! {
         * AUTO_SCROLL: The text input automatically scrolls text to the right by 10 characters when the user types a character 
                        at the end of the line. When the user presses the ENTER key, the control scrolls all text back to position zero.
         * TAB_STOP:    The text input can be selected using tab navigation
+        * PASSWORD:    The text input hides its characters behind the system password character
     */
     pub struct TextInputFlags: u32 {
         const VISIBLE = WS_VISIBLE;
@@ -35,6 +36,7 @@ bitflags! {
         const NUMBER = ES_NUMBER;
         const AUTO_SCROLL = ES_AUTOHSCROLL;
         const TAB_STOP = WS_TABSTOP;
+        const PASSWORD = ES_PASSWORD;
     }
 }
 
@@ -56,12 +58,15 @@ TextInput is not behind any features.
   * `limit`:            The maximum number of character that can be inserted in the control
   * `readonly`:         If the text input should allow user input or not
   * `password`:         The password character. If set to None, the textinput is a regular control.
+  * `placeholder_text`: Hint text displayed in the text input when it is empty and does not have focus
+  * `validator`:        A callback that can reject user edits. See `TextInput::set_validator`
   * `align`:            The alignment of the text in the text input
   * `background_color`: The color of the textinput top and bottom padding. This is not the white background under the text.
   * `focus`:            The control receive focus after being created
 
 **Control events:**
   * `OnTextInput`: When a TextInput value is changed
+  * `OnTextLimitReached`: When the user tries to insert more characters than the control `limit`
   * `MousePress(_)`: Generic mouse press events on the button
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -82,6 +87,8 @@ pub struct TextInput {
     pub handle: ControlHandle,
     background_brush: Option<HBRUSH>,
     handler0: RefCell<Option<RawEventHandler>>,
+    handler1: RefCell<Option<RawEventHandler>>,
+    revealed_password_char: Cell<Option<char>>,
 }
 
 impl TextInput {
@@ -102,239 +109,453 @@ impl TextInput {
             font: None,
             parent: None,
             background_color: None,
+            validator: None,
         }
     }
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+    try_accessors! {
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
         }
-    }
 
-    /// Set the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Set the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-    /// Return the password character displayed by the text input. If the input is not a password, return None.
-    pub fn password_char(&self) -> Option<char> {
-        use winapi::um::winuser::EM_GETPASSWORDCHAR;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Return the password character displayed by the text input. If the input is not a password, return None.
+        pub fn password_char(&self) -> Option<char> => try_password_char {
+            use winapi::um::winuser::EM_GETPASSWORDCHAR;
 
-        let raw_char = wh::send_message(handle, EM_GETPASSWORDCHAR as u32, 0, 0) as u32;
-        match raw_char {
-            0 => None,
-            v => char::from_u32(v)
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let raw_char = wh::send_message(handle, EM_GETPASSWORDCHAR as u32, 0, 0) as u32;
+            Ok(match raw_char {
+                0 => None,
+                v => char::from_u32(v)
+            })
         }
-    }
 
-    /// Set or Remove the password character displayed by the text input.
-    /// If the input is not a password all character are re-rendered with the new character
-    pub fn set_password_char(&self, c: Option<char>) {
-        use winapi::um::winuser::{InvalidateRect, EM_SETPASSWORDCHAR};
+        /// Set or Remove the password character displayed by the text input.
+        /// If the input is not a password all character are re-rendered with the new character
+        pub fn set_password_char(&self, c: Option<char>) => try_set_password_char {
+            use winapi::um::winuser::{InvalidateRect, EM_SETPASSWORDCHAR};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETPASSWORDCHAR as u32, c.map(|c| c as usize).unwrap_or(0), 0);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            self.revealed_password_char.set(None);
+            wh::send_message(handle, EM_SETPASSWORDCHAR as u32, c.map(|c| c as usize).unwrap_or(0), 0);
 
-        // The control needs to be manually refreshed
-        unsafe { InvalidateRect(handle, ::std::ptr::null(), 1); }
+            // The control needs to be manually refreshed
+            unsafe { InvalidateRect(handle, ::std::ptr::null(), 1); }
+
+            Ok(())
+        }
     }
 
-    /// Return the number of maximum character allowed in this text input
-    pub fn limit(&self) -> u32 {
-        use winapi::um::winuser::EM_GETLIMITTEXT;
+    /// Show or hide the characters of a password text input without losing its password character.
+    /// Use this to implement a "peek" button on login forms. Does nothing if the input is not a password.
+    pub fn set_password_visible(&self, visible: bool) {
+        match (visible, self.revealed_password_char.get()) {
+            (true, None) => if let Some(c) = self.password_char() {
+                self.set_password_char(None);
+                self.revealed_password_char.set(Some(c));
+            },
+            (false, Some(c)) => self.set_password_char(Some(c)),
+            _ => {}
+        }
+    }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_GETLIMITTEXT as u32, 0, 0) as u32
+    /// Return true if the characters of a password text input are currently revealed by `set_password_visible`
+    pub fn password_visible(&self) -> bool {
+        self.revealed_password_char.get().is_some()
     }
 
-    /// Set the number of maximum character allowed in this text input
-    /// If `limit` is 0, the text length is set to 0x7FFFFFFE characters 
-    pub fn set_limit(&self, limit: usize) {
-        use winapi::um::winuser::EM_SETLIMITTEXT;
+    try_accessors! {
+        /// Return the number of maximum character allowed in this text input
+        pub fn limit(&self) -> u32 => try_limit {
+            use winapi::um::winuser::EM_GETLIMITTEXT;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETLIMITTEXT as u32, limit, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_GETLIMITTEXT as u32, 0, 0) as u32)
+        }
 
-    /// Check if the content of the text input was modified after it's creation
-    pub fn modified(&self) -> bool {
-        use winapi::um::winuser::EM_GETMODIFY;
+        /// Set the number of maximum character allowed in this text input
+        /// If `limit` is 0, the text length is set to 0x7FFFFFFE characters 
+        pub fn set_limit(&self, limit: usize) => try_set_limit {
+            use winapi::um::winuser::EM_SETLIMITTEXT;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_GETMODIFY as u32, 0, 0) != 0
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETLIMITTEXT as u32, limit, 0);
 
-    /// Manually set modified flag of the text input
-    pub fn set_modified(&self, e: bool) {
-        use winapi::um::winuser::EM_SETMODIFY;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETMODIFY as u32, e as usize, 0);
-    }
+            Ok(())
+        }
 
-    /// Undo the last action by the user in the control
-    pub fn undo(&self) {
-        use winapi::um::winuser::EM_UNDO;
+        /// Check if the content of the text input was modified after it's creation
+        pub fn modified(&self) -> bool => try_modified {
+            use winapi::um::winuser::EM_GETMODIFY;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_UNDO as u32, 0, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_GETMODIFY as u32, 0, 0) != 0)
+        }
 
-    /// Return the selected range of characters by the user in the text input
-    pub fn selection(&self) -> Range<u32> {
-        use winapi::um::winuser::EM_GETSEL;
+        /// Manually set modified flag of the text input
+        pub fn set_modified(&self, e: bool) => try_set_modified {
+            use winapi::um::winuser::EM_SETMODIFY;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETMODIFY as u32, e as usize, 0);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            Ok(())
+        }
 
-        let mut start = 0u32;
-        let mut end = 0u32;
-        let ptr1 = &mut start as *mut u32;
-        let ptr2 = &mut end as *mut u32;
-        wh::send_message(handle, EM_GETSEL as UINT, ptr1 as WPARAM, ptr2 as LPARAM);
+        /// Undo the last action by the user in the control
+        pub fn undo(&self) => try_undo {
+            use winapi::um::winuser::EM_UNDO;
 
-        start..end
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_UNDO as u32, 0, 0);
 
-    /// Return the selected range of characters by the user in the text input
-    pub fn set_selection(&self, r: Range<u32>) {
-        use winapi::um::winuser::EM_SETSEL;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
-    }
+        /// Check if the last action of the user can be undone.
+        /// Edit controls only remember the last action, and undoing an undo redoes the action.
+        pub fn can_undo(&self) -> bool => try_can_undo {
+            use winapi::um::winuser::EM_CANUNDO;
 
-    /// Return the length of the user input in the control. This is better than `input.text().len()` as it
-    /// does not allocate a string in memory
-    pub fn len(&self) -> u32 {
-        use winapi::um::winuser::EM_LINELENGTH;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_LINELENGTH as u32, 0, 0) as u32
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_CANUNDO as u32, 0, 0) != 0)
+        }
 
-    /// Return true if the TextInput value cannot be edited. Retrurn false otherwise.
-    /// A user can still copy text from a readonly TextEdit (unlike disabled)
-    pub fn readonly(&self) -> bool {
-        use winapi::um::winuser::ES_READONLY;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::get_style(handle) & ES_READONLY == ES_READONLY
-    }
+        /// Clear the undo buffer of the control
+        pub fn empty_undo_buffer(&self) => try_empty_undo_buffer {
+            use winapi::um::winuser::EM_EMPTYUNDOBUFFER;
 
-    /// Set the readonly flag of the text input
-    /// A user can still copy text from a readonly TextEdit (unlike disabled)
-    pub fn set_readonly(&self, r: bool) {
-        use winapi::um::winuser::EM_SETREADONLY;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, EM_SETREADONLY as u32, r as WPARAM, 0);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_EMPTYUNDOBUFFER as u32, 0, 0);
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the keyboard focus on the button
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Return the selected range of characters by the user in the text input
+        pub fn selection(&self) -> Range<u32> => try_selection {
+            use winapi::um::winuser::EM_GETSEL;
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+            let mut start = 0u32;
+            let mut end = 0u32;
+            let ptr1 = &mut start as *mut u32;
+            let ptr2 = &mut end as *mut u32;
+            wh::send_message(handle, EM_GETSEL as UINT, ptr1 as WPARAM, ptr2 as LPARAM);
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            Ok(start..end)
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Return the selected range of characters by the user in the text input
+        pub fn set_selection(&self, r: Range<u32>) => try_set_selection {
+            use winapi::um::winuser::EM_SETSEL;
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETSEL as u32, r.start as usize, r.end as isize);
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
+            Ok(())
+        }
     }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+    /// Return the text currently selected by the user in the text input
+    pub fn selected_text(&self) -> String {
+        let text: Vec<u16> = self.text().encode_utf16().collect();
+        let sel = self.selection();
+        let start = (sel.start as usize).min(text.len());
+        let end = (sel.end as usize).min(text.len()).max(start);
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+        String::from_utf16_lossy(&text[start..end])
     }
 
-    /// Return the text displayed in the TextInput
-    pub fn text(&self) -> String { 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_text(handle) }
+    try_accessors! {
+        /// Return the position of the caret in the text input
+        pub fn caret(&self) -> u32 => try_caret {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(get_selection(handle).1 as u32)
+        }
+
+        /// Move the caret of the text input at the character index `pos`. This clears the selection.
+        pub fn set_caret(&self, pos: u32) => try_set_caret {
+            use winapi::um::winuser::{EM_SETSEL, EM_SCROLLCARET};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETSEL as u32, pos as usize, pos as isize);
+            wh::send_message(handle, EM_SCROLLCARET as u32, 0, 0);
+
+            Ok(())
+        }
+
+        /// Return the index of the character nearest to the point `(x, y)`. The point is local to the control.
+        /// Because of a winapi limitation, the index is not valid if the text is longer than 65535 characters.
+        pub fn char_index_from_position(&self, x: i32, y: i32) -> u32 => try_char_index_from_position {
+            use winapi::um::winuser::EM_CHARFROMPOS;
+            use winapi::shared::minwindef::{MAKELONG, LOWORD};
+            use crate::win32::high_dpi;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let (x, y) = unsafe { high_dpi::logical_to_physical(x, y) };
+            let pos = MAKELONG(x as u16, y as u16) as LPARAM;
+
+            let result = wh::send_message(handle, EM_CHARFROMPOS as u32, 0, pos);
+            Ok(LOWORD(result as u32) as u32)
+        }
+
+        /// Return the length of the user input in the control. This is better than `input.text().len()` as it
+        /// does not allocate a string in memory
+        pub fn len(&self) -> u32 => try_len {
+            use winapi::um::winuser::EM_LINELENGTH;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, EM_LINELENGTH as u32, 0, 0) as u32)
+        }
+
+        /// Return true if the TextInput value cannot be edited. Retrurn false otherwise.
+        /// A user can still copy text from a readonly TextEdit (unlike disabled)
+        pub fn readonly(&self) -> bool => try_readonly {
+            use winapi::um::winuser::ES_READONLY;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::get_style(handle) & ES_READONLY == ES_READONLY)
+        }
+
+        /// Set the readonly flag of the text input
+        /// A user can still copy text from a readonly TextEdit (unlike disabled)
+        pub fn set_readonly(&self, r: bool) => try_set_readonly {
+            use winapi::um::winuser::EM_SETREADONLY;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, EM_SETREADONLY as u32, r as WPARAM, 0);
+
+            Ok(())
+        }
+
+        /// Return true if the text input only accepts digits
+        pub fn numbers_only(&self) -> bool => try_numbers_only {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::get_style(handle) & ES_NUMBER == ES_NUMBER)
+        }
+
+        /// Set if the text input only accepts digits. Note that the user can still paste non-digits characters.
+        /// Use `set_validator` to fully validate the content of the control.
+        pub fn set_numbers_only(&self, v: bool) => try_set_numbers_only {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let style = wh::get_style(handle);
+            match v {
+                true => wh::set_style(handle, style | ES_NUMBER),
+                false => wh::set_style(handle, style & !ES_NUMBER),
+            }
+
+            Ok(())
+        }
     }
 
-    /// Set the text displayed in the TextInput
-    pub fn set_text<'a>(&self, v: &'a str) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
+    /**
+        Set a callback that validates the user input. The callback receives the text the control would have after the edit
+        and returns `false` to reject it.
+
+        Typed characters are filtered before they reach the control. Other edits (pasting, cutting, deleting, undoing)
+        are rolled back after the fact, so an `OnTextInput` event may be raised for the rejected value.
+        Text set with `set_text` is not validated.
+
+        Calling this function replaces the previous validator.
+    */
+    pub fn set_validator<F>(&self, validator: F)
+        where F: Fn(&str) -> bool + 'static
+    {
+        use crate::bind_raw_event_handler_inner;
+        use winapi::um::winuser::{WM_CHAR, WM_KEYDOWN, WM_PASTE, WM_CUT, WM_CLEAR, WM_UNDO, EM_UNDO, VK_DELETE};
+        use winapi::um::commctrl::DefSubclassProc;
+
+        self.clear_validator();
+
+        let handler = bind_raw_event_handler_inner(&self.handle, 1, move |hwnd, msg, w, l| {
+            match msg {
+                WM_CHAR if w >= 0x20 => {
+                    let text: Vec<u16> = unsafe { wh::get_window_text(hwnd) }.encode_utf16().collect();
+                    let (start, end) = get_selection(hwnd);
+                    let start = start.min(text.len());
+                    let end = end.min(text.len()).max(start);
+
+                    let mut new_text = text[..start].to_vec();
+                    new_text.push(w as u16);
+                    new_text.extend_from_slice(&text[end..]);
+
+                    match validator(&String::from_utf16_lossy(&new_text)) {
+                        true => None,
+                        false => Some(0)
+                    }
+                },
+                WM_CHAR | WM_PASTE | WM_CUT | WM_CLEAR | WM_UNDO => unsafe {
+                    Some(validate_edit(hwnd, msg, w, l, &validator))
+                },
+                _ if msg == EM_UNDO as UINT || (msg == WM_KEYDOWN && w as i32 == VK_DELETE) => unsafe {
+                    Some(validate_edit(hwnd, msg, w, l, &validator))
+                },
+                _ => None
+            }
+        });
+
+        *self.handler1.borrow_mut() = Some(handler.unwrap());
+
+        unsafe fn validate_edit<F: Fn(&str) -> bool>(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM, validator: &F) -> LRESULT {
+            use winapi::um::winuser::EM_SETSEL;
+
+            let old_text = wh::get_window_text(hwnd);
+            let (start, end) = get_selection(hwnd);
+
+            let result = DefSubclassProc(hwnd, msg, w, l);
+
+            let new_text = wh::get_window_text(hwnd);
+            if new_text != old_text && !validator(&new_text) {
+                wh::set_window_text(hwnd, &old_text);
+                wh::send_message(hwnd, EM_SETSEL as u32, start, end as LPARAM);
+            }
+
+            result
+        }
     }
 
-    /// Return the placeholder text displayed in the TextInput
-    /// when it is empty and does not have focus. The string returned will be
-    /// as long as the user specified, however it might be longer or shorter than
-    /// the actual placeholder text.
-    pub fn placeholder_text<'a>(&self, text_length: usize) -> String { 
-        use std::ffi::OsString;
-        use std::os::windows::ffi::OsStringExt;
-        use winapi::shared::ntdef::WCHAR;
-        use winapi::um::commctrl::EM_GETCUEBANNER;
+    try_accessors! {
+        /// Remove the validator set by `set_validator`. Does nothing if the control has no validator.
+        pub fn clear_validator(&self) => try_clear_validator {
+            use crate::unbind_raw_event_handler;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut placeholder_text: Vec<WCHAR> = Vec::with_capacity(text_length);
-        unsafe {
-            placeholder_text.set_len(text_length);
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            if let Some(h) = self.handler1.borrow_mut().take() {
+                drop(unbind_raw_event_handler(&h));
+            }
+
+            Ok(())
+        }
+
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
+
+        /// Set the keyboard focus on the button
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
+
+            Ok(())
+        }
+
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
+
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Return the text displayed in the TextInput
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
+
+        /// Set the text displayed in the TextInput
+        pub fn set_text<'a>(&self, v: &'a str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the placeholder text displayed in the TextInput
+        /// when it is empty and does not have focus. The string returned will be
+        /// at most `text_length` characters long. Longer placeholder texts are truncated.
+        pub fn placeholder_text<'a>(&self, text_length: usize) -> String => try_placeholder_text {
+            use std::ffi::OsString;
+            use std::os::windows::ffi::OsStringExt;
+            use winapi::shared::ntdef::WCHAR;
+            use winapi::um::commctrl::EM_GETCUEBANNER;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let mut placeholder_text: Vec<WCHAR> = vec![0; text_length + 1];
             wh::send_message(handle, EM_GETCUEBANNER, placeholder_text.as_mut_ptr() as WPARAM, placeholder_text.len() as LPARAM);
-            OsString::from_wide(&placeholder_text).into_string().unwrap_or("".to_string())
+
+            // The buffer is zero filled, only keep what's before the first NULL
+            let len = placeholder_text.iter().position(|&c| c == 0).unwrap_or(text_length);
+            Ok(OsString::from_wide(&placeholder_text[..len]).into_string().unwrap_or("".to_string()))
         }
-    }
 
-    /// Set the placeholder text displayed in the TextInput
-    /// when it is empty and does not have focus
-    pub fn set_placeholder_text<'a>(&self, v: Option<&'a str>) {
-        use winapi::um::commctrl::EM_SETCUEBANNER;
+        /// Set the placeholder text displayed in the TextInput
+        /// when it is empty and does not have focus
+        pub fn set_placeholder_text<'a>(&self, v: Option<&'a str>) => try_set_placeholder_text {
+            use winapi::um::commctrl::EM_SETCUEBANNER;
     
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let placeholder_text = v.unwrap_or("");
-        let text = to_utf16(placeholder_text);
-        wh::send_message(handle, EM_SETCUEBANNER, 0, text.as_ptr() as LPARAM);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let placeholder_text = v.unwrap_or("");
+            let text = to_utf16(placeholder_text);
+            wh::send_message(handle, EM_SETCUEBANNER, 0, text.as_ptr() as LPARAM);
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -357,6 +578,7 @@ impl TextInput {
     /// Center the text vertically. Can't believe that must be manually hacked in.
     fn hook_non_client_size(&mut self, bg: Option<[u8; 3]>) {
         use crate::bind_raw_event_handler_inner;
+        use crate::win32::high_contrast::background_brush;
         use winapi::shared::windef::{HGDIOBJ, RECT, POINT};
         use winapi::um::winuser::{WM_NCCALCSIZE, WM_NCPAINT, WM_SIZE, DT_CALCRECT, DT_LEFT, NCCALCSIZE_PARAMS, COLOR_WINDOW,};
         use winapi::um::winuser::{SWP_NOOWNERZORDER, SWP_NOSIZE, SWP_NOMOVE, SWP_FRAMECHANGED};
@@ -440,6 +662,7 @@ impl TextInput {
                     };
 
                     let dc = GetDC(hwnd);
+                    let brush = background_brush(brush, COLOR_WINDOW);
                     FillRect(dc, &top, brush);
                     FillRect(dc, &bottom, brush);
                     ReleaseDC(hwnd, dc);
@@ -468,6 +691,11 @@ impl Drop for TextInput {
         if let Some(h) = handler.as_ref() {
             drop(unbind_raw_event_handler(h));
         }
+
+        let handler = self.handler1.borrow();
+        if let Some(h) = handler.as_ref() {
+            drop(unbind_raw_event_handler(h));
+        }
         
         if let Some(bg) = self.background_brush {
             unsafe { DeleteObject(bg as _); }
@@ -492,6 +720,7 @@ pub struct TextInputBuilder<'a> {
     parent: Option<ControlHandle>,
     background_color: Option<[u8; 3]>,
     focus: bool,
+    validator: Option<Box<dyn Fn(&str) -> bool>>,
 }
 
 impl<'a> TextInputBuilder<'a> {
@@ -561,6 +790,11 @@ impl<'a> TextInputBuilder<'a> {
         self
     }
 
+    pub fn validator<F: Fn(&str) -> bool + 'static>(mut self, validator: F) -> TextInputBuilder<'a> {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> TextInputBuilder<'a> {
         self.parent = Some(p.into());
         self
@@ -624,11 +858,26 @@ impl<'a> TextInputBuilder<'a> {
             out.set_placeholder_text(self.placeholder_text);
         }
 
+        if let Some(validator) = self.validator {
+            out.set_validator(move |text| validator(text));
+        }
+
         Ok(())
     }
 
 }
 
+/// Return the selected range of characters (in UTF-16 units) of an edit control
+fn get_selection(hwnd: HWND) -> (usize, usize) {
+    use winapi::um::winuser::EM_GETSEL;
+
+    let mut start = 0u32;
+    let mut end = 0u32;
+    wh::send_message(hwnd, EM_GETSEL as UINT, &mut start as *mut u32 as WPARAM, &mut end as *mut u32 as LPARAM);
+
+    (start as usize, end as usize)
+}
+
 impl PartialEq for TextInput {
     fn eq(&self, other: &Self) -> bool {
         self.handle == other.handle
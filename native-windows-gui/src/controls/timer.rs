@@ -84,22 +84,30 @@ impl Timer {
         *self.interval.borrow_mut() = i;
     }
 
-    /// Stops the timer.
-    pub fn stop(&self) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        if !self.valid() { panic!("{}", UNUSABLE_TIMER); }
-        let (hwnd, id) = self.handle.timer().expect(BAD_HANDLE);
+    try_accessors! {
+        errors = "Returns an `InvalidHandle` error if the timer is not bound or if its parent window was destroyed.";
 
-        wh::kill_timer(hwnd, id);
-    }
+        /// Stops the timer.
+        pub fn stop(&self) => try_stop {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            if !self.valid() { return Err(NwgError::invalid_handle(UNUSABLE_TIMER)); }
+            let (hwnd, id) = self.handle.timer().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            wh::kill_timer(hwnd, id);
+
+            Ok(())
+        }
+
+        /// Starts the timer. If the timer is already running, this restarts it.
+        pub fn start(&self) => try_start {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            if !self.valid() { return Err(NwgError::invalid_handle(UNUSABLE_TIMER)); }
+            let (hwnd, id) = self.handle.timer().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
 
-    /// Starts the timer. If the timer is already running, this restarts it.
-    pub fn start(&self) {
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        if !self.valid() { panic!("{}", UNUSABLE_TIMER); }
-        let (hwnd, id) = self.handle.timer().expect(BAD_HANDLE);
+            wh::start_timer(hwnd, id, self.interval());
 
-        wh::start_timer(hwnd, id, self.interval());
+            Ok(())
+        }
     }
 
 }
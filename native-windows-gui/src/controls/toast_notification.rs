@@ -0,0 +1,229 @@
+use crate::win32::toast as th;
+use crate::NwgError;
+use super::{ControlBase, ControlHandle, TrayNotification, TrayNotificationFlags};
+use std::mem::ManuallyDrop;
+
+const NOT_BOUND: &'static str = "ToastNotification is not yet bound to a winapi object";
+const BAD_HANDLE: &'static str = "INTERNAL ERROR: ToastNotification handle is not a toast handle!";
+
+
+/// A button displayed at the bottom of a toast notification
+#[derive(Copy, Clone, Debug)]
+pub struct ToastButton<'a> {
+    /// The text of the button
+    pub text: &'a str,
+
+    /// The value of `EventData::OnToastActivated` when the button is clicked
+    pub arguments: &'a str,
+}
+
+
+/**
+    Windows 10/11 toast notifications. A modern alternative to the tray notification balloons.
+    Toasts are displayed by the system notification center and can have an image and buttons.
+
+    The system needs an application user model ID (`app_id`) to display the toasts. For an application that is not installed
+    from a package, the ID must be set on a start menu shortcut of the application or be registered under
+    `HKEY_CURRENT_USER\Software\Classes\AppUserModelId`.
+
+    Toasts require Windows 8 or later. If toasts cannot be displayed and a `fallback` tray notification is set,
+    the notification is shown as a balloon of the tray icon instead.
+
+    Requires the `toast-notification` feature.
+
+    **Builder parameters:**
+        * `parent`:   **Required.** The window that receives the toast events.
+        * `app_id`:   **Required.** The application user model ID of the application
+        * `fallback`: A tray notification used to show a balloon when toasts are not available
+
+    **Control events:**
+        * `OnToastActivated`: When the user clicks the toast or one of its buttons. The event data holds the arguments of the button.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn notify(toast: &nwg::ToastNotification) -> Result<(), nwg::NwgError> {
+        let buttons = [
+            nwg::ToastButton { text: "Open", arguments: "open" },
+            nwg::ToastButton { text: "Dismiss", arguments: "dismiss" },
+        ];
+
+        toast.show("Download complete", "hello.txt was downloaded", None, &buttons)
+    }
+
+    fn on_event(toast: &nwg::ToastNotification, evt: nwg::Event, data: &nwg::EventData, handle: nwg::ControlHandle) {
+        if evt == nwg::Event::OnToastActivated && handle == toast.handle {
+            println!("Toast activated with {:?}", data.on_toast_activated());
+        }
+    }
+    ```
+*/
+#[derive(Default)]
+pub struct ToastNotification {
+    pub handle: ControlHandle,
+    app_id: String,
+    fallback: Option<ControlHandle>,
+}
+
+impl ToastNotification {
+
+    pub fn builder<'a>() -> ToastNotificationBuilder<'a> {
+        ToastNotificationBuilder {
+            parent: None,
+            app_id: None,
+            fallback: None,
+        }
+    }
+
+    /// Return `true` if the system can display toast notifications
+    pub fn supported() -> bool {
+        th::toasts_supported()
+    }
+
+    /**
+        Show a toast notification
+
+        Parameters:
+          - title: The title of the toast
+          - body: The text of the toast
+          - image: The path of an image displayed next to the text
+          - buttons: The buttons at the bottom of the toast
+
+        Returns an error if the toast cannot be displayed and no fallback tray notification is set.
+        The balloon of the fallback only shows the title and the body.
+    */
+    pub fn show<'a>(&self, title: &'a str, body: &'a str, image: Option<&'a str>, buttons: &[ToastButton<'a>]) -> Result<(), NwgError> {
+        if self.handle.blank() { panic!("{}", NOT_BOUND); }
+        let (hwnd, id) = self.handle.toast().expect(BAD_HANDLE);
+
+        let xml = toast_xml(title, body, image, buttons);
+        match unsafe { th::show_toast(&self.app_id, &xml, hwnd, id) } {
+            Ok(()) => Ok(()),
+            Err(e) => match self.fallback {
+                Some(handle) => {
+                    // The tray notification is owned by the application
+                    let tray = ManuallyDrop::new(TrayNotification { handle });
+                    tray.show(body, Some(title), Some(TrayNotificationFlags::INFO_ICON), None);
+                    Ok(())
+                },
+                None => Err(e)
+            }
+        }
+    }
+
+    /// Forget the toasts shown by this control. The toasts stay in the notification center,
+    /// but activating them no longer raises `OnToastActivated`.
+    pub fn clear(&self) {
+        if let Some((hwnd, id)) = self.handle.toast() {
+            th::forget_toasts(hwnd, id);
+        }
+    }
+
+}
+
+impl Drop for ToastNotification {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl PartialEq for ToastNotification {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+
+impl Eq for ToastNotification {}
+
+/// Escape the special xml characters of `text`
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Build the toast xml document. See https://docs.microsoft.com/en-us/windows/uwp/design/shell/tiles-and-notifications/adaptive-interactive-toasts
+fn toast_xml(title: &str, body: &str, image: Option<&str>, buttons: &[ToastButton]) -> String {
+    let mut xml = String::from("<toast><visual><binding template=\"ToastGeneric\">");
+    xml.push_str(&format!("<text>{}</text><text>{}</text>", xml_escape(title), xml_escape(body)));
+
+    if let Some(image) = image {
+        let src = match image.contains("://") {
+            true => image.to_string(),
+            false => format!("file:///{}", image)
+        };
+
+        xml.push_str(&format!("<image placement=\"appLogoOverride\" src=\"{}\"/>", xml_escape(&src)));
+    }
+
+    xml.push_str("</binding></visual>");
+
+    if !buttons.is_empty() {
+        xml.push_str("<actions>");
+        for button in buttons {
+            xml.push_str(&format!("<action content=\"{}\" arguments=\"{}\"/>", xml_escape(button.text), xml_escape(button.arguments)));
+        }
+        xml.push_str("</actions>");
+    }
+
+    xml.push_str("</toast>");
+    xml
+}
+
+pub struct ToastNotificationBuilder<'a> {
+    parent: Option<ControlHandle>,
+    app_id: Option<&'a str>,
+    fallback: Option<ControlHandle>,
+}
+
+impl<'a> ToastNotificationBuilder<'a> {
+
+    pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> ToastNotificationBuilder<'a> {
+        self.parent = Some(p.into());
+        self
+    }
+
+    pub fn app_id(mut self, app_id: &'a str) -> ToastNotificationBuilder<'a> {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    pub fn fallback(mut self, tray: Option<&TrayNotification>) -> ToastNotificationBuilder<'a> {
+        self.fallback = tray.map(|t| t.handle);
+        self
+    }
+
+    pub fn build(self, out: &mut ToastNotification) -> Result<(), NwgError> {
+        let parent = match self.parent {
+            Some(p) => match p.hwnd() {
+                Some(handle) => Ok(handle),
+                None => Err(NwgError::control_create("ToastNotification must be window-like control."))
+            },
+            None => Err(NwgError::no_parent("ToastNotification"))
+        }?;
+
+        let app_id = match self.app_id {
+            Some(id) => id,
+            None => { return Err(NwgError::control_create("ToastNotification requires an application ID")); }
+        };
+
+        if let Some(fallback) = self.fallback {
+            if fallback.tray().is_none() {
+                return Err(NwgError::control_create("ToastNotification fallback must be a TrayNotification"));
+            }
+        }
+
+        *out = Default::default();
+        out.handle = ControlBase::build_toast_notification()
+            .parent(parent)
+            .build()?;
+
+        out.app_id = app_id.to_string();
+        out.fallback = self.fallback;
+
+        Ok(())
+    }
+
+}
@@ -1,7 +1,7 @@
 use winapi::shared::minwindef::{UINT, LPARAM, WPARAM};
 use winapi::um::winnt::WCHAR;
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::{check_hwnd, to_utf16, from_utf16};
+use crate::win32::base_helper::{try_check_hwnd, to_utf16, from_utf16};
 use crate::{Icon, NwgError};
 use super::{ControlBase, ControlHandle};
 use std::{mem, ptr};
@@ -99,7 +99,11 @@ impl Tooltip {
             ico: None,
             default_ico: None,
             register: Vec::new(),
-            register_cb: Vec::new()
+            register_cb: Vec::new(),
+            delay_time: None,
+            reshow_delay: None,
+            autopop_delay: None,
+            max_width: None,
         }
     }
 
@@ -138,163 +142,259 @@ impl Tooltip {
     }
     */
 
-    /// Return the current text of the tooltip. There is no way to know the size of the text so you have
-    /// to pass the buffer size. The default buffer size is 200 characters.
-    pub fn text(&self, owner: &ControlHandle, buffer_size: Option<usize>) -> String {
-        use winapi::um::commctrl::{TTM_GETTEXTW, TTTOOLINFOW, TTF_IDISHWND, TTF_SUBCLASS};
-        use winapi::shared::{basetsd::UINT_PTR, windef::RECT};
+    try_accessors! {
+        /// Return the current text of the tooltip. There is no way to know the size of the text so you have
+        /// to pass the buffer size. The default buffer size is 200 characters.
+        pub fn text(&self, owner: &ControlHandle, buffer_size: Option<usize>) -> String => try_text {
+            use winapi::um::commctrl::{TTM_GETTEXTW, TTTOOLINFOW, TTF_IDISHWND, TTF_SUBCLASS};
+            use winapi::shared::{basetsd::UINT_PTR, windef::RECT};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let owner_handle = {
+                if owner.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+                owner.hwnd().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?
+            };
+
+            let buffer_size = buffer_size.unwrap_or(200);
+            let mut text: Vec<WCHAR> = Vec::with_capacity(buffer_size);
+            unsafe { text.set_len(buffer_size); }
+
+            let mut tool = TTTOOLINFOW {
+                cbSize: mem::size_of::<TTTOOLINFOW>() as UINT,
+                uFlags: TTF_IDISHWND | TTF_SUBCLASS,
+                hwnd: owner_handle,
+                uId: owner_handle as UINT_PTR,
+                rect: RECT { left: 0, top: 0, right: 0, bottom: 0 },
+                hinst: ptr::null_mut(),
+                lpszText: text.as_mut_ptr(),
+                lParam: 0,
+                lpReserved: ptr::null_mut()
+            };
+
+            let tool_ptr = &mut tool as *mut TTTOOLINFOW;
+            wh::send_message(handle, TTM_GETTEXTW, 0, tool_ptr as LPARAM);
+
+            Ok(from_utf16(&text))
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Change the text of a previously registered control
+        /// Use the `register` function is associate a control with this tooltip
+        pub fn set_text<'a>(&self, owner: &ControlHandle, text: &'a str) => try_set_text {
+            use winapi::um::commctrl::{TTM_UPDATETIPTEXTW, TTTOOLINFOW, TTF_IDISHWND, TTF_SUBCLASS};
+            use winapi::shared::{basetsd::UINT_PTR, windef::RECT};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let mut text = to_utf16(text);
+            let owner_handle = {
+                if owner.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+                owner.hwnd().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?
+            };
+
+            let tool = TTTOOLINFOW {
+                cbSize: mem::size_of::<TTTOOLINFOW>() as UINT,
+                uFlags: TTF_IDISHWND | TTF_SUBCLASS,
+                hwnd: owner_handle,
+                uId: owner_handle as UINT_PTR,
+                rect: RECT { left: 0, top: 0, right: 0, bottom: 0 },
+                hinst: ptr::null_mut(),
+                lpszText: text.as_mut_ptr(),
+                lParam: 0,
+                lpReserved: ptr::null_mut()
+            };
+
+            let tool_ptr = &tool as *const TTTOOLINFOW;
+            wh::send_message(handle, TTM_UPDATETIPTEXTW, 0, tool_ptr as LPARAM);
+
+            Ok(())
+        }
 
-        let owner_handle = {
-            if owner.blank() { panic!("{}", NOT_BOUND); }
-            owner.hwnd().expect(BAD_HANDLE)
-        };
+        /// Set the icon and the title of a tooltip. This method use custom icon defined by user resources
+        pub fn set_decoration<'a>(&self, title: &'a str, ico: &Icon) => try_set_decoration {
+            use winapi::um::commctrl::{TTM_SETTITLEW};
+        
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let title = to_utf16(title);
+            wh::send_message(handle, TTM_SETTITLEW, ico.handle as WPARAM, title.as_ptr() as LPARAM);
 
-        let buffer_size = buffer_size.unwrap_or(200);
-        let mut text: Vec<WCHAR> = Vec::with_capacity(buffer_size);
-        unsafe { text.set_len(buffer_size); }
+            Ok(())
+        }
 
-        let mut tool = TTTOOLINFOW {
-            cbSize: mem::size_of::<TTTOOLINFOW>() as UINT,
-            uFlags: TTF_IDISHWND | TTF_SUBCLASS,
-            hwnd: owner_handle,
-            uId: owner_handle as UINT_PTR,
-            rect: RECT { left: 0, top: 0, right: 0, bottom: 0 },
-            hinst: ptr::null_mut(),
-            lpszText: text.as_mut_ptr(),
-            lParam: 0,
-            lpReserved: ptr::null_mut()
-        };
+        /// Set the icon and the title of a tooltip. This method use built-in icon defined by TooltipIcon
+        pub fn set_default_decoration<'a>(&self, title: &'a str, icon: TooltipIcon) => try_set_default_decoration {
+            use winapi::um::commctrl::{TTM_SETTITLEW};
+            use winapi::um::commctrl::{TTI_NONE, TTI_INFO, TTI_WARNING, TTI_ERROR, TTI_INFO_LARGE, TTI_WARNING_LARGE, TTI_ERROR_LARGE};
+        
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let tool_ptr = &mut tool as *mut TTTOOLINFOW;
-        wh::send_message(handle, TTM_GETTEXTW, 0, tool_ptr as LPARAM);
+            let bitmap_handle = match icon {
+                TooltipIcon::None => TTI_NONE,
+                TooltipIcon::Info => TTI_INFO,
+                TooltipIcon::Warning => TTI_WARNING,
+                TooltipIcon::Error => TTI_ERROR,
+                TooltipIcon::InfoLarge => TTI_INFO_LARGE,
+                TooltipIcon::WarningLarge => TTI_WARNING_LARGE,
+                TooltipIcon::ErrorLarge => TTI_ERROR_LARGE
+            };
 
-        from_utf16(&text)
-    }
+            let title = to_utf16(title);
 
-    /// Change the text of a previously registered control
-    /// Use the `register` function is associate a control with this tooltip
-    pub fn set_text<'a>(&self, owner: &ControlHandle, text: &'a str) {
-        use winapi::um::commctrl::{TTM_UPDATETIPTEXTW, TTTOOLINFOW, TTF_IDISHWND, TTF_SUBCLASS};
-        use winapi::shared::{basetsd::UINT_PTR, windef::RECT};
+            wh::send_message(handle, TTM_SETTITLEW, bitmap_handle as WPARAM, title.as_ptr() as LPARAM);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            Ok(())
+        }
 
-        let mut text = to_utf16(text);
-        let owner_handle = {
-            if owner.blank() { panic!("{}", NOT_BOUND); }
-            owner.hwnd().expect(BAD_HANDLE)
-        };
+        /// Show the tooltip popup of the registered control under the pointer, without waiting for the delay time
+        pub fn show(&self) => try_show {
+            use winapi::um::commctrl::TTM_POPUP;
 
-        let tool = TTTOOLINFOW {
-            cbSize: mem::size_of::<TTTOOLINFOW>() as UINT,
-            uFlags: TTF_IDISHWND | TTF_SUBCLASS,
-            hwnd: owner_handle,
-            uId: owner_handle as UINT_PTR,
-            rect: RECT { left: 0, top: 0, right: 0, bottom: 0 },
-            hinst: ptr::null_mut(),
-            lpszText: text.as_mut_ptr(),
-            lParam: 0,
-            lpReserved: ptr::null_mut()
-        };
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TTM_POPUP, 0, 0);
 
-        let tool_ptr = &tool as *const TTTOOLINFOW;
-        wh::send_message(handle, TTM_UPDATETIPTEXTW, 0, tool_ptr as LPARAM);
-    }
+            Ok(())
+        }
 
-    /// Set the icon and the title of a tooltip. This method use custom icon defined by user resources
-    pub fn set_decoration<'a>(&self, title: &'a str, ico: &Icon) {
-        use winapi::um::commctrl::{TTM_SETTITLEW};
-        
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let title = to_utf16(title);
-        wh::send_message(handle, TTM_SETTITLEW, ico.handle as WPARAM, title.as_ptr() as LPARAM);
-    }
+        /// Hide the tooltip popup
+        pub fn hide(&self) => try_hide {
+            use winapi::um::commctrl::{TTM_POP};
 
-    /// Set the icon and the title of a tooltip. This method use built-in icon defined by TooltipIcon
-    pub fn set_default_decoration<'a>(&self, title: &'a str, icon: TooltipIcon) {
-        use winapi::um::commctrl::{TTM_SETTITLEW};
-        use winapi::um::commctrl::{TTI_NONE, TTI_INFO, TTI_WARNING, TTI_ERROR, TTI_INFO_LARGE, TTI_WARNING_LARGE, TTI_ERROR_LARGE};
-        
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-
-        let bitmap_handle = match icon {
-            TooltipIcon::None => TTI_NONE,
-            TooltipIcon::Info => TTI_INFO,
-            TooltipIcon::Warning => TTI_WARNING,
-            TooltipIcon::Error => TTI_ERROR,
-            TooltipIcon::InfoLarge => TTI_INFO_LARGE,
-            TooltipIcon::WarningLarge => TTI_WARNING_LARGE,
-            TooltipIcon::ErrorLarge => TTI_ERROR_LARGE
-        };
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TTM_POP, 0, 0);
 
-        let title = to_utf16(title);
+            Ok(())
+        }
 
-        wh::send_message(handle, TTM_SETTITLEW, bitmap_handle as WPARAM, title.as_ptr() as LPARAM);
-    }
+        /// Return the number of controls registered by the tooltip
+        pub fn count(&self) -> usize => try_count {
+            use winapi::um::commctrl::{TTM_GETTOOLCOUNT};
 
-    /// Hide the tooltip popup
-    pub fn hide(&self) {
-        use winapi::um::commctrl::{TTM_POP};
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TTM_GETTOOLCOUNT, 0, 0) as usize)
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TTM_POP, 0, 0);
-    }
+        /// Set the delay time for the tooltip to spawn in milliseconds
+        /// Set the value to `None` to reset the value to default
+        pub fn set_delay_time(&self, delay: Option<u16>) => try_set_delay_time {
+            use winapi::um::commctrl::TTDT_INITIAL;
+            self.try_set_delay(TTDT_INITIAL, delay)
+        }
 
-    /// Return the number of controls registered by the tooltip
-    pub fn count(&self) -> usize {
-        use winapi::um::commctrl::{TTM_GETTOOLCOUNT};
+        /// Return the delay time of the tooltip in milliseconds
+        pub fn delay_time(&self) -> u16 => try_delay_time {
+            use winapi::um::commctrl::TTDT_INITIAL;
+            self.try_delay(TTDT_INITIAL)
+        }
+
+        /// Set the delay time, in milliseconds, for the tooltip to spawn when the pointer moves from a control to another
+        /// Set the value to `None` to reset the value to default
+        pub fn set_reshow_delay(&self, delay: Option<u16>) => try_set_reshow_delay {
+            use winapi::um::commctrl::TTDT_RESHOW;
+            self.try_set_delay(TTDT_RESHOW, delay)
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TTM_GETTOOLCOUNT, 0, 0) as usize
+        /// Return the reshow delay of the tooltip in milliseconds
+        pub fn reshow_delay(&self) -> u16 => try_reshow_delay {
+            use winapi::um::commctrl::TTDT_RESHOW;
+            self.try_delay(TTDT_RESHOW)
+        }
+
+        /// Set how long, in milliseconds, the tooltip stays visible if the pointer does not move. Increase it for long texts.
+        /// Set the value to `None` to reset the value to default
+        pub fn set_autopop_delay(&self, delay: Option<u16>) => try_set_autopop_delay {
+            use winapi::um::commctrl::TTDT_AUTOPOP;
+            self.try_set_delay(TTDT_AUTOPOP, delay)
+        }
+
+        /// Return how long the tooltip stays visible in milliseconds
+        pub fn autopop_delay(&self) -> u16 => try_autopop_delay {
+            use winapi::um::commctrl::TTDT_AUTOPOP;
+            self.try_delay(TTDT_AUTOPOP)
+        }
+
+        /// Set the maximum width of the tooltip in pixels. Longer texts are wrapped on multiple lines.
+        /// Set the value to `None` to display the texts on a single line (the default)
+        pub fn set_max_width(&self, width: Option<u32>) => try_set_max_width {
+            use winapi::um::commctrl::TTM_SETMAXTIPWIDTH;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let value = match width {
+                Some(w) => w as i32,
+                None => -1,
+            };
+
+            wh::send_message(handle, TTM_SETMAXTIPWIDTH, 0, value as LPARAM);
+
+            Ok(())
+        }
+
+        /// Return the maximum width of the tooltip in pixels, or `None` if the texts are displayed on a single line
+        pub fn max_width(&self) -> Option<u32> => try_max_width {
+            use winapi::um::commctrl::TTM_GETMAXTIPWIDTH;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            match wh::send_message(handle, TTM_GETMAXTIPWIDTH, 0, 0) as i32 {
+                -1 => Ok(None),
+                w => Ok(Some(w as u32))
+            }
+        }
     }
 
-    /// Set the delay time for the tooltip to spawn in milliseconds
-    /// Set the value to `None` to reset the value to default
-    pub fn set_delay_time(&self, delay: Option<u16>) {
-        use winapi::um::commctrl::{TTDT_INITIAL, TTM_SETDELAYTIME};
+    fn try_set_delay(&self, which: WPARAM, delay: Option<u16>) -> Result<(), NwgError> {
+        use winapi::um::commctrl::TTM_SETDELAYTIME;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
         let value = match delay {
             Some(d) => d & 0xFFFF,
             None => u16::max_value() & 0xFFFF,
         };
 
-        wh::send_message(handle, TTM_SETDELAYTIME, TTDT_INITIAL as WPARAM, value as LPARAM);
+        wh::send_message(handle, TTM_SETDELAYTIME, which, value as LPARAM);
+
+        Ok(())
     }
 
-    /// Return the delay time of the tooltip in milliseconds
-    pub fn delay_time(&self) -> u16 {
-        use winapi::um::commctrl::{TTDT_INITIAL, TTM_GETDELAYTIME};
+    fn try_delay(&self, which: WPARAM) -> Result<u16, NwgError> {
+        use winapi::um::commctrl::TTM_GETDELAYTIME;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TTM_GETDELAYTIME, TTDT_INITIAL as WPARAM, 0) as u16
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+        Ok(wh::send_message(handle, TTM_GETDELAYTIME, which, 0) as u16)
     }
 
-    /// Enable or disable the control
-    /// Windows does not support reading the enabled state of a tooltip btw.
-    pub fn set_enabled(&self, v: bool) {
-        use winapi::um::commctrl::TTM_ACTIVATE;
+    try_accessors! {
+        /// Enable or disable the control
+        /// Windows does not support reading the enabled state of a tooltip btw.
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            use winapi::um::commctrl::TTM_ACTIVATE;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TTM_ACTIVATE, v as WPARAM, 0);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TTM_ACTIVATE, v as WPARAM, 0);
+
+            Ok(())
+        }
     }
 
     /// Register the tooltip under a control.
     /// `owner` must be a window control.
     pub fn register<'a, W: Into<ControlHandle>>(&self, owner: W, text: &'a str) {
+        self.try_register(owner, text).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `register`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the tooltip or `owner` is not bound or if their windows were destroyed.
+    pub fn try_register<'a, W: Into<ControlHandle>>(&self, owner: W, text: &'a str) -> Result<(), NwgError> {
         use winapi::um::commctrl::{TTM_ADDTOOLW, TTTOOLINFOW, TTF_IDISHWND, TTF_SUBCLASS};
         use winapi::shared::{basetsd::UINT_PTR, windef::RECT};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
         let owner = owner.into();
 
         let mut text = to_utf16(text);
         let owner_handle = {
-            if owner.blank() { panic!("{}", NOT_BOUND); }
-            owner.hwnd().expect(BAD_HANDLE)
+            if owner.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            owner.hwnd().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?
         };
 
         let tool = TTTOOLINFOW {
@@ -311,21 +411,30 @@ impl Tooltip {
 
         let tool_ptr = &tool as *const TTTOOLINFOW;
         wh::send_message(handle, TTM_ADDTOOLW, 0, tool_ptr as LPARAM);
+
+        Ok(())
     }
 
     /// Register the tooltip under a control.
     /// `owner` must be a window control.
     /// When the user trigger the tooltip, the application receives a `OnTooltipText` event
     pub fn register_callback<W: Into<ControlHandle>>(&self, owner: W) {
+        self.try_register_callback(owner).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `register_callback`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the tooltip or `owner` is not bound or if their windows were destroyed.
+    pub fn try_register_callback<W: Into<ControlHandle>>(&self, owner: W) -> Result<(), NwgError> {
         use winapi::um::commctrl::{TTM_ADDTOOLW, TTTOOLINFOW, TTF_IDISHWND, TTF_SUBCLASS, LPSTR_TEXTCALLBACKW};
         use winapi::shared::{basetsd::UINT_PTR, windef::RECT};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
         let owner = owner.into();
         let owner_handle = {
-            if owner.blank() { panic!("{}", NOT_BOUND); }
-            owner.hwnd().expect(BAD_HANDLE)
+            if owner.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            owner.hwnd().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?
         };
 
         let tool = TTTOOLINFOW {
@@ -342,19 +451,28 @@ impl Tooltip {
 
         let tool_ptr = &tool as *const TTTOOLINFOW;
         wh::send_message(handle, TTM_ADDTOOLW, 0, tool_ptr as LPARAM);
+
+        Ok(())
     }
 
     /// Remove the tooltip from a control
     pub fn unregister<W: Into<ControlHandle>>(&self, owner: W) {
+        self.try_unregister(owner).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `unregister`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the tooltip or `owner` is not bound or if their windows were destroyed.
+    pub fn try_unregister<W: Into<ControlHandle>>(&self, owner: W) -> Result<(), NwgError> {
         use winapi::um::commctrl::{TTM_DELTOOLW, TTTOOLINFOW, TTF_IDISHWND, TTF_SUBCLASS};
         use winapi::shared::{basetsd::UINT_PTR, windef::RECT};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
         let owner = owner.into();
 
         let owner_handle = {
-            if owner.blank() { panic!("{}", NOT_BOUND); }
-            owner.hwnd().expect(BAD_HANDLE)
+            if owner.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            owner.hwnd().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?
         };
 
         let tool = TTTOOLINFOW {
@@ -370,7 +488,9 @@ impl Tooltip {
         };
 
         let tool_ptr = &tool as *const TTTOOLINFOW;
-        wh::send_message(handle, TTM_DELTOOLW, 0, tool_ptr as LPARAM);   
+        wh::send_message(handle, TTM_DELTOOLW, 0, tool_ptr as LPARAM);
+
+        Ok(())
     }
 
     /// Winapi class name used during control creation
@@ -404,6 +524,10 @@ pub struct TooltipBuilder<'a> {
     default_ico: Option<TooltipIcon>,
     register: Vec<(ControlHandle, &'a str)>,
     register_cb: Vec<ControlHandle>,
+    delay_time: Option<u16>,
+    reshow_delay: Option<u16>,
+    autopop_delay: Option<u16>,
+    max_width: Option<u32>,
 }
 
 impl<'a> TooltipBuilder<'a> {
@@ -430,6 +554,26 @@ impl<'a> TooltipBuilder<'a> {
         self
     }
 
+    pub fn delay_time(mut self, delay: Option<u16>) -> TooltipBuilder<'a> {
+        self.delay_time = delay;
+        self
+    }
+
+    pub fn reshow_delay(mut self, delay: Option<u16>) -> TooltipBuilder<'a> {
+        self.reshow_delay = delay;
+        self
+    }
+
+    pub fn autopop_delay(mut self, delay: Option<u16>) -> TooltipBuilder<'a> {
+        self.autopop_delay = delay;
+        self
+    }
+
+    pub fn max_width(mut self, width: Option<u32>) -> TooltipBuilder<'a> {
+        self.max_width = width;
+        self
+    }
+
     pub fn build(self, tooltip: &mut Tooltip) -> Result<(), NwgError> {
         *tooltip = Default::default();
 
@@ -456,6 +600,22 @@ impl<'a> TooltipBuilder<'a> {
             tooltip.register_callback(&handle);
         }
 
+        if self.delay_time.is_some() {
+            tooltip.set_delay_time(self.delay_time);
+        }
+
+        if self.reshow_delay.is_some() {
+            tooltip.set_reshow_delay(self.reshow_delay);
+        }
+
+        if self.autopop_delay.is_some() {
+            tooltip.set_autopop_delay(self.autopop_delay);
+        }
+
+        if self.max_width.is_some() {
+            tooltip.set_max_width(self.max_width);
+        }
+
         Ok(())
     }
 
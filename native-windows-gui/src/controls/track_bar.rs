@@ -6,9 +6,9 @@ use winapi::um::{
     winuser::{WS_VISIBLE, WS_TABSTOP},
     wingdi::DeleteObject,
 };
-use winapi::um::commctrl::{TBS_AUTOTICKS, TBS_VERT, TBS_HORZ, TBS_TOP, TBS_BOTTOM, TBS_LEFT, TBS_RIGHT, TBS_NOTICKS, TBS_ENABLESELRANGE};
+use winapi::um::commctrl::{TBS_AUTOTICKS, TBS_VERT, TBS_HORZ, TBS_TOP, TBS_BOTTOM, TBS_LEFT, TBS_RIGHT, TBS_NOTICKS, TBS_ENABLESELRANGE, TBS_TOOLTIPS};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::check_hwnd;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::{NwgError, RawEventHandler};
 use super::{ControlBase, ControlHandle};
 use std::cell::RefCell;
@@ -22,6 +22,19 @@ const BAD_HANDLE: &'static str = "INTERNAL ERROR: TrackBar handle is not HWND!";
 bitflags! {
     /**
         The track bar  flags
+
+        * VISIBLE:     The trackbar is immediatly visible after creation
+        * AUTO_TICK:   The trackbar has a tick mark for every increment of the tick frequency (see `TrackBar::set_tic_freq`)
+        * VERTICAL:    The trackbar is vertical
+        * HORIZONTAL:  The trackbar is horizontal
+        * TICK_TOP:    The tick marks are displayed above a horizontal trackbar
+        * TICK_BOTTOM: The tick marks are displayed below a horizontal trackbar
+        * TICK_LEFT:   The tick marks are displayed on the left of a vertical trackbar
+        * TICK_RIGHT:  The tick marks are displayed on the right of a vertical trackbar
+        * NO_TICK:     The trackbar does not display tick marks
+        * RANGE:       The trackbar highlights a selection range (see `TrackBar::set_selection_range_pos`)
+        * TOOLTIPS:    The trackbar displays its value in a tooltip when the slider is moved
+        * TAB_STOP:    The control can be selected using tab navigation
     */
     pub struct TrackBarFlags: u32 {
         const VISIBLE = WS_VISIBLE;
@@ -34,10 +47,23 @@ bitflags! {
         const TICK_RIGHT = TBS_RIGHT;
         const NO_TICK = TBS_NOTICKS;
         const RANGE = TBS_ENABLESELRANGE;
+        const TOOLTIPS = TBS_TOOLTIPS;
         const TAB_STOP = WS_TABSTOP;
     }
 }
 
+/**
+    The side of a trackbar where a buddy control is displayed. See `TrackBar::set_buddy`
+
+    * Start: Left of a horizontal trackbar or above a vertical trackbar
+    * End:   Right of a horizontal trackbar or below a vertical trackbar
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TrackBarBuddySide {
+    Start,
+    End,
+}
+
 /**
 A trackbar is a window that contains a slider (sometimes called a thumb) in a channel, and optional tick marks.
 When the user moves the slider, using either the mouse or the direction keys, the trackbar sends notification messages to indicate the change.
@@ -54,12 +80,15 @@ Requires the `trackbar` feature.
   * `range`:            The value range of the trackbar
   * `selected_range`:   The selected value range of the trackbar. Used with `TrackBarFlags::RANGE`
   * `pos`:              The current value of the trackbar
+  * `tic_freq`:         The interval between the tick marks of a trackbar with the `AUTO_TICK` flag
   * `background_color`: The background color the of the trackbar
 
 
 **Control events:**
   * `OnVerticalScroll`: When the value of a trackbar with the VERTICAL flags is changed
   * `OnHorizontalScroll`: When the value of a trackbar with the HORIZONTAL flags is changed
+  * `OnTrackBarValueChanged`: When the slider is moved by the user or by `set_pos`. The event data contains the new position.
+  * `TrackBarUpdated`: When the trackbar thumb is released by the user
   * `MousePress(_)`: Generic mouse press events on the button
   * `OnMouseMove`: Generic mouse mouse event
   * `OnMouseWheel`: Generic mouse wheel event
@@ -93,6 +122,7 @@ impl TrackBar {
             range: None,
             selected_range: None,
             pos: None,
+            tic_freq: None,
             flags: None,
             ex_flags: 0,
             parent: None,
@@ -100,159 +130,272 @@ impl TrackBar {
         }
     }
 
-    /// Retrieves the current logical position of the slider in a trackbar.
-    /// The logical positions are the integer values in the trackbar's range of minimum to maximum slider positions. 
-    pub fn pos(&self) -> usize {
-        use winapi::um::commctrl::TBM_GETPOS;
+    try_accessors! {
+        /// Retrieves the current logical position of the slider in a trackbar.
+        /// The logical positions are the integer values in the trackbar's range of minimum to maximum slider positions. 
+        pub fn pos(&self) -> usize => try_pos {
+            use winapi::um::commctrl::TBM_GETPOS;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TBM_GETPOS, 0, 0) as usize
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TBM_GETPOS, 0, 0) as usize)
+        }
 
-    /// Sets the current logical position of the slider in a trackbar.
-    pub fn set_pos(&self, p: usize) {
-        use winapi::um::commctrl::TBM_SETPOSNOTIFY;
+        /// Sets the current logical position of the slider in a trackbar.
+        pub fn set_pos(&self, p: usize) => try_set_pos {
+            use winapi::um::commctrl::TBM_SETPOSNOTIFY;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TBM_SETPOSNOTIFY, 1, p as LPARAM);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TBM_SETPOSNOTIFY, 1, p as LPARAM);
 
-    /// Retrieves the starting and ending position of the current selection range in a trackbar.
-    /// Only work for trackbar with the `Range` flags
-    pub fn selection_range_pos(&self) -> Range<usize> {
-        use winapi::um::commctrl::{TBM_GETSELEND, TBM_GETSELSTART};
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Retrieves the starting and ending position of the current selection range in a trackbar.
+        /// Only work for trackbar with the `Range` flags
+        pub fn selection_range_pos(&self) -> Range<usize> => try_selection_range_pos {
+            use winapi::um::commctrl::{TBM_GETSELEND, TBM_GETSELSTART};
 
-        let end = wh::send_message(handle, TBM_GETSELEND, 0, 0) as usize;
-        let start = wh::send_message(handle, TBM_GETSELSTART, 0, 0) as usize;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        start..end
-    }
+            let end = wh::send_message(handle, TBM_GETSELEND, 0, 0) as usize;
+            let start = wh::send_message(handle, TBM_GETSELSTART, 0, 0) as usize;
 
-    /// Sets the range value of the trackbar
-    /// Only work for trackbar with the `Range` flags
-    pub fn set_selection_range_pos(&self, value: Range<usize>) {
-        use winapi::um::commctrl::{TBM_SETSELEND, TBM_SETSELSTART};
+            Ok(start..end)
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Sets the range value of the trackbar
+        /// Only work for trackbar with the `Range` flags
+        pub fn set_selection_range_pos(&self, value: Range<usize>) => try_set_selection_range_pos {
+            use winapi::um::commctrl::{TBM_SETSELEND, TBM_SETSELSTART};
 
-        wh::send_message(handle, TBM_SETSELEND, 0, value.end as LPARAM);
-        wh::send_message(handle, TBM_SETSELSTART, 1, value.start as LPARAM);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Retrieves the minimum position for the slider in a trackbar. 
-    pub fn range_min(&self) -> usize {
-        use winapi::um::commctrl::TBM_GETRANGEMIN;
+            wh::send_message(handle, TBM_SETSELEND, 0, value.end as LPARAM);
+            wh::send_message(handle, TBM_SETSELSTART, 1, value.start as LPARAM);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TBM_GETRANGEMIN, 0, 0) as usize
-    }
+            Ok(())
+        }
 
-    /// Sets the minium logical position for the slider in a trackbar.
-    pub fn set_range_min(&self, min: usize) {
-        use winapi::um::commctrl::TBM_SETRANGEMIN;
+        /// Clears the current selection range in a trackbar.
+        pub fn clear_selection_range(&self) => try_clear_selection_range {
+            use winapi::um::commctrl::TBM_CLEARSEL;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TBM_SETRANGEMIN, 1, min as LPARAM);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TBM_CLEARSEL, 1, 0);
 
-    /// Retrieves the maximum position for the slider in a trackbar. 
-    pub fn range_max(&self) -> usize {
-        use winapi::um::commctrl::TBM_GETRANGEMAX;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TBM_GETRANGEMAX, 0, 0) as usize
-    }
+        /// Retrieves the minimum position for the slider in a trackbar. 
+        pub fn range_min(&self) -> usize => try_range_min {
+            use winapi::um::commctrl::TBM_GETRANGEMIN;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TBM_GETRANGEMIN, 0, 0) as usize)
+        }
+
+        /// Sets the minium logical position for the slider in a trackbar.
+        pub fn set_range_min(&self, min: usize) => try_set_range_min {
+            use winapi::um::commctrl::TBM_SETRANGEMIN;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TBM_SETRANGEMIN, 1, min as LPARAM);
 
-    /// Sets the maximum logical position for the slider in a trackbar.
-    pub fn set_range_max(&self, max: usize) {
-        use winapi::um::commctrl::TBM_SETRANGEMAX;
+            Ok(())
+        }
+
+        /// Retrieves the maximum position for the slider in a trackbar. 
+        pub fn range_max(&self) -> usize => try_range_max {
+            use winapi::um::commctrl::TBM_GETRANGEMAX;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TBM_GETRANGEMAX, 0, 0) as usize)
+        }
+
+        /// Sets the maximum logical position for the slider in a trackbar.
+        pub fn set_range_max(&self, max: usize) => try_set_range_max {
+            use winapi::um::commctrl::TBM_SETRANGEMAX;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TBM_SETRANGEMAX, 1, max as LPARAM);
+
+            Ok(())
+        }
+
+        /// Retrieves the number of tick marks in a trackbar
+        pub fn tics_len(&self) -> usize => try_tics_len {
+            use winapi::um::commctrl::TBM_GETNUMTICS;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TBM_GETNUMTICS, 0, 0) as usize)
+        }
+
+        /// Retrieves the logical position of a tick mark in a trackbar.
+        /// The logical position can be any of the integer values in the trackbar's range of minimum to maximum slider positions. 
+        pub fn tic_value(&self, index: usize) -> usize => try_tic_value {
+            use winapi::um::commctrl::TBM_GETTIC;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TBM_GETTIC, index as WPARAM, 0) as usize)
+        }
+
+        /// Sets the interval between the tick marks of a trackbar with the `AUTO_TICK` flag.
+        /// For example, with a frequency of 10, a trackbar with a range of 0..100 displays 11 tick marks.
+        pub fn set_tic_freq(&self, freq: usize) => try_set_tic_freq {
+            use winapi::um::commctrl::TBM_SETTICFREQ;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TBM_SETTICFREQ, freq as WPARAM, 0);
+
+            Ok(())
+        }
+
+        /// Adds a tick mark at a logical position of the trackbar.
+        /// Returns `false` if the tick mark could not be added (ex: the position is outside the range of the trackbar).
+        pub fn add_tic(&self, value: usize) -> bool => try_add_tic {
+            use winapi::um::commctrl::TBM_SETTIC;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TBM_SETTIC, 0, value as LPARAM) != 0)
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TBM_SETRANGEMAX, 1, max as LPARAM);
+        /// Removes every tick mark added with `add_tic` or `set_tic_freq`. The first and the last tick marks are kept.
+        pub fn clear_tics(&self) => try_clear_tics {
+            use winapi::um::commctrl::TBM_CLEARTICS;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TBM_CLEARTICS, 1, 0);
+
+            Ok(())
+        }
     }
 
-    /// Retrieves the number of tick marks in a trackbar
-    pub fn tics_len(&self) -> usize {
-        use winapi::um::commctrl::TBM_GETNUMTICS;
+    /// Replaces the tick marks of the trackbar by tick marks at the specified logical positions
+    pub fn set_tics(&self, values: &[usize]) {
+        self.clear_tics();
+        for &value in values {
+            self.add_tic(value);
+        }
+    }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TBM_GETNUMTICS, 0, 0) as usize
+    /// Returns the logical positions of the tick marks of the trackbar, excluding the first and the last tick marks
+    pub fn tics(&self) -> Vec<usize> {
+        let count = self.tics_len().saturating_sub(2);
+        (0..count).map(|i| self.tic_value(i)).collect()
     }
 
-    /// Retrieves the logical position of a tick mark in a trackbar.
-    /// The logical position can be any of the integer values in the trackbar's range of minimum to maximum slider positions. 
-    pub fn tic_value(&self, index: usize) -> usize {
-        use winapi::um::commctrl::TBM_GETTIC;
+    try_accessors! {
+        /**
+            Sets a buddy control displayed next to the trackbar, usually a `Label` that displays the range bounds or the current value.
+            The buddy is moved and resized by the trackbar. Set `buddy` to `None` to remove the buddy of a side.
+        */
+        pub fn set_buddy(&self, side: TrackBarBuddySide, buddy: Option<ControlHandle>) => try_set_buddy {
+            use winapi::um::commctrl::TBM_SETBUDDY;
+            use std::ptr;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let buddy_handle = match buddy {
+                Some(b) => b.hwnd().expect("Trackbar buddy must be a window control"),
+                None => ptr::null_mut()
+            };
+
+            let start = (side == TrackBarBuddySide::Start) as WPARAM;
+            wh::send_message(handle, TBM_SETBUDDY, start, buddy_handle as LPARAM);
+
+            Ok(())
+        }
+
+        /// Returns the buddy control of a side of the trackbar
+        pub fn buddy(&self, side: TrackBarBuddySide) -> Option<ControlHandle> => try_buddy {
+            use winapi::um::commctrl::TBM_GETBUDDY;
+            use winapi::shared::windef::HWND;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TBM_GETTIC, index as WPARAM, 0) as usize
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let start = (side == TrackBarBuddySide::Start) as WPARAM;
+            Ok(match wh::send_message(handle, TBM_GETBUDDY, start, 0) {
+                0 => None,
+                h => Some(ControlHandle::Hwnd(h as HWND))
+            })
+        }
     }
 
     //
     // Basic methods
     //
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+    try_accessors! {
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            Ok(())
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+            Ok(())
+        }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
-    }
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
 
-    /// Set the keyboard focus on the track bar
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
+
+        /// Set the keyboard focus on the track bar
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -275,6 +418,7 @@ impl TrackBar {
     /// Change the label background color to transparent.
     fn hook_background_color(&mut self, c: [u8; 3]) {
         use crate::bind_raw_event_handler_inner;
+        use crate::win32::high_contrast::high_contrast;
         use winapi::um::winuser::{WM_CTLCOLORSTATIC};
         use winapi::shared::{basetsd::UINT_PTR, windef::{HWND}, minwindef::LRESULT};
         use winapi::um::wingdi::{CreateSolidBrush, RGB};
@@ -290,7 +434,7 @@ impl TrackBar {
             match msg {
                 WM_CTLCOLORSTATIC => {
                     let child = l as HWND;
-                    if child == handle {
+                    if child == handle && !high_contrast() {
                         return Some(brush as LRESULT);
                     }
                 },
@@ -330,6 +474,7 @@ pub struct TrackBarBuilder {
     range: Option<Range<usize>>,
     selected_range: Option<Range<usize>>,
     pos: Option<usize>,
+    tic_freq: Option<usize>,
     flags: Option<TrackBarFlags>,
     ex_flags: u32,
     parent: Option<ControlHandle>,
@@ -378,6 +523,11 @@ impl TrackBarBuilder {
         self
     }
 
+    pub fn tic_freq(mut self, freq: Option<usize>) -> TrackBarBuilder {
+        self.tic_freq = freq;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: C) -> TrackBarBuilder {
         self.parent = Some(p.into());
         self
@@ -426,6 +576,10 @@ impl TrackBarBuilder {
             out.set_selection_range_pos(range);
         }
 
+        if let Some(freq) = self.tic_freq {
+            out.set_tic_freq(freq);
+        }
+
         if let Some(pos) = self.pos {
             out.set_pos(pos);
         }
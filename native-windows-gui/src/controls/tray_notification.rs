@@ -4,13 +4,135 @@ use super::{ControlBase, ControlHandle};
 use crate::win32::base_helper::to_utf16;
 use crate::win32::window_helper as wh;
 use crate::{Icon, NwgError};
+use winapi::shared::windef::HWND;
+use winapi::shared::minwindef::UINT;
+use winapi::shared::guiddef::GUID;
+use std::cell::RefCell;
 use std::{mem, ptr};
 
 const NOT_BOUND: &'static str = "TrayNotification is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: TrayNotification handle is not HWND!";
 
+thread_local! {
+    /// The state of the live tray icons, used to add the icons back when the taskbar is recreated.
+    /// The icon handles are copies owned by this list.
+    static TRAY_ICONS: RefCell<Vec<NOTIFYICONDATAW>> = RefCell::new(Vec::new());
+}
+
+lazy_static! {
+    static ref TASKBAR_CREATED: UINT = unsafe {
+        use winapi::um::winuser::RegisterWindowMessageW;
+        let name = to_utf16("TaskbarCreated");
+        RegisterWindowMessageW(name.as_ptr())
+    };
+}
+
+/// The message broadcasted to the top level windows when the taskbar is created (ex: when explorer.exe restarts)
+pub(crate) fn taskbar_created_message() -> UINT {
+    *TASKBAR_CREATED
+}
+
+/// Add the tray icons of `hwnd` back to the taskbar. Returns the handles of the icons.
+pub(crate) fn recreate_tray_icons(hwnd: HWND) -> Vec<ControlHandle> {
+    use winapi::um::shellapi::{NIM_ADD, NIM_SETVERSION};
+
+    TRAY_ICONS.with(|icons| {
+        let icons = icons.borrow();
+        icons.iter()
+            .filter(|data| data.hWnd == hwnd)
+            .map(|data| {
+                let mut data = *data;
+                unsafe {
+                    Shell_NotifyIconW(NIM_ADD, &mut data);
+                    Shell_NotifyIconW(NIM_SETVERSION, &mut data);
+                }
+                ControlHandle::SystemTray(hwnd, data.uID)
+            })
+            .collect()
+    })
+}
+
+/// Save the state of a tray icon to recreate it later
+unsafe fn save_tray_icon(data: &NOTIFYICONDATAW) {
+    use winapi::um::shellapi::{NIF_INFO, NIF_REALTIME};
+    use winapi::um::winuser::CopyIcon;
+
+    let mut saved = *data;
+    saved.uFlags &= !(NIF_INFO | NIF_REALTIME);
+    saved.szInfo = mem::zeroed();
+    saved.szInfoTitle = mem::zeroed();
+    saved.dwInfoFlags = 0;
+    saved.hBalloonIcon = ptr::null_mut();
+    saved.hIcon = CopyIcon(data.hIcon);
+
+    TRAY_ICONS.with(|icons| icons.borrow_mut().push(saved));
+}
+
+/// Update the saved state of a tray icon
+fn update_tray_icon<F: FnOnce(&mut NOTIFYICONDATAW)>(hwnd: HWND, id: u32, update: F) {
+    TRAY_ICONS.with(|icons| {
+        let mut icons = icons.borrow_mut();
+        if let Some(data) = icons.iter_mut().find(|d| d.hWnd == hwnd && d.uID == id) {
+            update(data);
+        }
+    });
+}
+
+/// Returns the GUID of a tray icon, if the icon was added with one
+fn tray_icon_guid(hwnd: HWND, id: u32) -> Option<GUID> {
+    use winapi::um::shellapi::NIF_GUID;
+
+    TRAY_ICONS.with(|icons| {
+        icons.borrow().iter()
+            .find(|d| d.hWnd == hwnd && d.uID == id && d.uFlags & NIF_GUID == NIF_GUID)
+            .map(|d| d.guidItem)
+    })
+}
+
+fn guid_from_u128(value: u128) -> GUID {
+    GUID {
+        Data1: (value >> 96) as u32,
+        Data2: (value >> 80) as u16,
+        Data3: (value >> 64) as u16,
+        Data4: (value as u64).to_be_bytes(),
+    }
+}
+
+fn guid_to_u128(guid: &GUID) -> u128 {
+    ((guid.Data1 as u128) << 96)
+        | ((guid.Data2 as u128) << 80)
+        | ((guid.Data3 as u128) << 64)
+        | (u64::from_be_bytes(guid.Data4) as u128)
+}
+
+/// Forget the saved state of a tray icon
+fn remove_tray_icon(hwnd: HWND, id: u32) {
+    use winapi::um::winuser::DestroyIcon;
+
+    TRAY_ICONS.with(|icons| {
+        let mut icons = icons.borrow_mut();
+        if let Some(index) = icons.iter().position(|d| d.hWnd == hwnd && d.uID == id) {
+            let data = icons.remove(index);
+            unsafe { DestroyIcon(data.hIcon); }
+        }
+    });
+}
+
 
 bitflags! {
+    /**
+        Flags that specify how a tray notification popup is shown
+
+        * NO_ICON: No icon in the popup
+        * INFO_ICON: An information icon
+        * WARNING_ICON: A warning icon
+        * ERROR_ICON: An error icon
+        * USER_ICON: Use the balloon icon, or the tray icon if no balloon icon is set
+        * SILENT: Do not play the notification sound
+        * LARGE_ICON: Use the large version of the icon
+        * QUIET: Do not show the popup during quiet time (ex: the first hour after a new user logs in)
+        * REALTIME: Discard the popup if it cannot be displayed immediately
+    */
     pub struct TrayNotificationFlags: u32 {
         const NO_ICON = NIIF_NONE;
         const INFO_ICON = NIIF_INFO;
@@ -20,6 +142,9 @@ bitflags! {
         const SILENT = NIIF_NOSOUND;
         const LARGE_ICON = NIIF_LARGE_ICON;
         const QUIET = NIIF_RESPECT_QUIET_TIME;
+
+        // Not a NIIF value. Translated to `NIF_REALTIME` when the popup is shown.
+        const REALTIME = 0x10000;
     }
 }
 
@@ -28,29 +153,43 @@ bitflags! {
     A control that handle system tray notification.
     A TrayNotification wraps a single icon in the Windows system tray.
     
-    An application can have many TrayNotification, but each window (aka parent) can only have a single traynotification.
+    An application can have many TrayNotification, and a window (aka parent) can own many of them (ex: one icon per account).
+    Each icon sends its events with its own handle, so they can be told apart with `handle == tray.handle`.
     It is possible to create system tray only application with the `MessageOnlyWindow` control.
 
     A system tray will receive events if `callback` is set to true in the builder (the default behaviour).
     The control will generate mouse events such as `OnMouseMove` when the user interact with the tray icon or the message popup.
     A system tray will also receive a `OnContextMenu` when the user right click the icon. It is highly recommended handle this message and display a popup menu
 
+    The icon and the tooltip can be changed after creation with `set_icon` and `set_tip`.
+
     You can't get information on the state of a tray notification (such as visibility) because Windows don't want you to.
 
+    By default, Windows remembers if the user chose to always show the icon in the notification area using the path of the executable
+    and the icon id. Set a `guid` to keep this preference when the application is updated. Each icon must use its own GUID (ex: generated with `uuidgen`).
+    Windows ties the GUID to the path of the executable unless the executable is signed, so a GUID registered by an unsigned application
+    cannot be used by a copy of the application at another path. In that case, the icon is added without the GUID and `guid` returns `None`.
+
     **Builder parameters:**
         * `parent`:       **Required.** The tray notification parent container.
         * `icon`:         **Required.** The icon to display in the system tray
         * `tips`:         Display a simple tooltip when hovering the icon in the system tray
         * `flags`:        A combination of the TrayNotificationFlags values.
         * `visible`:      If the icon should be visible in the system tray
-        * `realtime`:     If the balloon notification cannot be displayed immediately, discard it.
+        * `guid`:         A GUID that identifies the icon across the application restarts and updates (Windows 7 and later)
+        * `realtime`:     If the balloon notification cannot be displayed immediately, discard it. Same as the `REALTIME` flag.
         * `info`:         Display a fancy tooltip when the system tray icon is hovered (replaces tip) 
         * `balloon_icon`: The icon to display in the fancy tooltip  
         * `info_title`:   The title of the fancy tooltip  
 
     **Control events:**
-        * `OnContextMenu`: When the user right clicks on the system tray icon
+        * `OnContextMenu`: When the user right clicks on the system tray icon, or opens its menu with the keyboard
         * `MousePressLeftUp`: When the user left click the system tray icon
+        * `OnTrayNotificationDoubleClick`: When the user double clicks the system tray icon
+        * `OnTrayNotificationSelect`: When the user selects the system tray icon with the mouse
+        * `OnTrayNotificationKeySelect`: When the user selects the system tray icon with the keyboard (`Space` or `Enter`)
+        * `OnTrayRecreated`: When the icon was added back to the system tray after the taskbar was recreated (ex: explorer.exe restarted).
+           Only sent if the parent is a top level window, because message-only windows do not receive the taskbar broadcast.
         * `OnTrayNotificationShow`: When a TrayNotification info popup (not the tooltip) is shown 
         * `OnTrayNotificationHide`: When a TrayNotification info popup (not the tooltip) is hidden 
         * `OnTrayNotificationTimeout`: When a TrayNotification is closed due to a timeout
@@ -67,6 +206,16 @@ bitflags! {
     }
     ```
 
+    ```rust
+    use native_windows_gui as nwg;
+
+    // Call this on every `OnTimerTick` of an `AnimationTimer`
+    fn animate_tray(tray: &nwg::TrayNotification, frames: &[nwg::Icon], frame: &mut usize) {
+        *frame = (*frame + 1) % frames.len();
+        tray.set_icon(&frames[*frame]);
+    }
+    ```
+
     ```rust
     use native_windows_gui as nwg;
     fn build_tray(tray: &mut nwg::TrayNotification, window: &nwg::Window, icon: &nwg::Icon) {
@@ -99,131 +248,180 @@ impl TrayNotification {
             realtime: false,
             callback: true,
             visible: true,
+            guid: None,
         }
     }
 
-    /// Set the visibility of the icon in the system tray
-    pub fn set_visibility(&self, v: bool) {
-        use winapi::um::shellapi::{NIF_STATE, NIM_MODIFY, NIS_HIDDEN};  
-
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        self.handle.tray().expect(BAD_HANDLE);
+    try_accessors! {
+        errors = "Returns an `InvalidHandle` error if the tray notification is not bound.";
 
-        unsafe {
-            let mut data = self.notify_default();
-            data.uFlags |= NIF_STATE;
-            data.dwState = if v { 0 } else { NIS_HIDDEN };
-            data.dwStateMask = NIS_HIDDEN;
-            Shell_NotifyIconW(NIM_MODIFY, &mut data);
+        /// Returns the GUID that identifies the icon, or `None` if the icon was added without a GUID
+        pub fn guid(&self) -> Option<u128> => try_guid {
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            let (hwnd, id) = self.handle.tray().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+            Ok(tray_icon_guid(hwnd, id).map(|guid| guid_to_u128(&guid)))
         }
-    }
 
-    /// Set the tooltip for the tray notification.
-    /// Note: tip will be truncated to 127 characters
-    pub fn set_tip<'a>(&self, tip: &'a str) {
-        use winapi::um::shellapi::{NIM_MODIFY, NIF_TIP, NIF_SHOWTIP};  
+        /// Set the visibility of the icon in the system tray
+        pub fn set_visibility(&self, v: bool) => try_set_visibility {
+            use winapi::um::shellapi::{NIF_STATE, NIM_MODIFY, NIS_HIDDEN};
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        self.handle.tray().expect(BAD_HANDLE);
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            self.handle.tray().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
 
-        unsafe {
-            let mut data = self.notify_default();
-            
-            data.uFlags = NIF_TIP | NIF_SHOWTIP;
-            
-            let tip_v = to_utf16(tip);
-            let length = if tip_v.len() >= 128 { 127 } else { tip_v.len() };
-            for i in 0..length {
-                data.szTip[i] = tip_v[i];
+            unsafe {
+                let mut data = self.notify_default();
+                data.uFlags |= NIF_STATE;
+                data.dwState = if v { 0 } else { NIS_HIDDEN };
+                data.dwStateMask = NIS_HIDDEN;
+                Shell_NotifyIconW(NIM_MODIFY, &mut data);
+
+                update_tray_icon(data.hWnd, data.uID, |saved| {
+                    saved.uFlags |= NIF_STATE;
+                    saved.dwState = data.dwState;
+                    saved.dwStateMask = NIS_HIDDEN;
+                });
             }
 
-            Shell_NotifyIconW(NIM_MODIFY, &mut data);
+            Ok(())
         }
-    }
 
-    /// Set the focus to the tray icon
-    pub fn set_focus(&self) {
-        use winapi::um::shellapi::{NIM_SETFOCUS};
+        /// Set the tooltip for the tray notification.
+        /// Note: tip will be truncated to 127 characters
+        pub fn set_tip<'a>(&self, tip: &'a str) => try_set_tip {
+            use winapi::um::shellapi::{NIM_MODIFY, NIF_TIP, NIF_SHOWTIP};
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        self.handle.tray().expect(BAD_HANDLE);
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            self.handle.tray().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
 
-        unsafe {
-            let mut data = self.notify_default();
-            Shell_NotifyIconW(NIM_SETFOCUS, &mut data);
+            unsafe {
+                let mut data = self.notify_default();
+
+                data.uFlags |= NIF_TIP | NIF_SHOWTIP;
+
+                let tip_v = to_utf16(tip);
+                let length = if tip_v.len() >= 128 { 127 } else { tip_v.len() };
+                for i in 0..length {
+                    data.szTip[i] = tip_v[i];
+                }
+
+                Shell_NotifyIconW(NIM_MODIFY, &mut data);
+
+                update_tray_icon(data.hWnd, data.uID, |saved| {
+                    saved.uFlags |= NIF_TIP | NIF_SHOWTIP;
+                    saved.szTip = data.szTip;
+                });
+            }
+
+            Ok(())
         }
-    }
 
-    /// Update the icon in the system tray
-    pub fn set_icon(&self, icon: &Icon) {
-        use winapi::um::shellapi::{NIF_ICON, NIM_MODIFY};
-        use winapi::shared::windef::HICON;
+        /// Set the focus to the tray icon
+        pub fn set_focus(&self) => try_set_focus {
+            use winapi::um::shellapi::{NIM_SETFOCUS};
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        self.handle.tray().expect(BAD_HANDLE);
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            self.handle.tray().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
 
-        unsafe {
-            let mut data = self.notify_default();
-            
-            data.uFlags = NIF_ICON;
-            data.hIcon = icon.handle as HICON;
-            Shell_NotifyIconW(NIM_MODIFY, &mut data);
+            unsafe {
+                let mut data = self.notify_default();
+                Shell_NotifyIconW(NIM_SETFOCUS, &mut data);
+            }
+
+            Ok(())
         }
-    }
 
-    /// Shows a popup message on top of the system tray
-    ///
-    /// Parameters:
-    ///   - text: The text in the popup
-    ///   - title: The title of the popup
-    ///   - flags: Flags that specify how the popup is shown. Default is NO_ICON | QUIET.
-    ///   - icon: Icon to display in the popup. Only used if `USER_ICON` is set in flags.
-    ///
-    /// Note 1: text will be truncated to 255 characters
-    /// Note 2: title will be truncated to 63 characters
-    pub fn show<'a>(&self, text: &'a str, title: Option<&'a str>, flags: Option<TrayNotificationFlags>, icon: Option<&'a Icon>) {
-        use winapi::um::shellapi::{NIF_INFO, NIM_MODIFY};
-        use winapi::shared::windef::HICON;
+        /// Update the icon in the system tray
+        ///
+        /// Only the icon is sent to the shell, so this can be called on every tick of a timer to animate the icon
+        /// (ex: a progress badge). The shell keeps a copy of the icon, so `icon` can be dropped after the call.
+        pub fn set_icon(&self, icon: &Icon) => try_set_icon {
+            use winapi::um::shellapi::{NIF_ICON, NIM_MODIFY};
+            use winapi::um::winuser::{CopyIcon, DestroyIcon};
+            use winapi::shared::windef::HICON;
 
-        if self.handle.blank() { panic!("{}", NOT_BOUND); }
-        self.handle.tray().expect(BAD_HANDLE);
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            self.handle.tray().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
 
-        let default_flags = TrayNotificationFlags::NO_ICON | TrayNotificationFlags::SILENT;
+            unsafe {
+                let mut data = self.notify_default();
 
-        unsafe {
-            let mut data = self.notify_default();
-            data.uFlags = NIF_INFO;
-            data.dwInfoFlags = flags.unwrap_or(default_flags).bits();
-            data.hBalloonIcon = icon.map(|i| i.handle as HICON).unwrap_or(ptr::null_mut());
-            
-            let info_v = to_utf16(text);
-            let length = if info_v.len() >= 256 { 255 } else { info_v.len() };
-            for i in 0..length {
-                data.szInfo[i] = info_v[i];
+                data.uFlags |= NIF_ICON;
+                data.hIcon = icon.handle as HICON;
+                Shell_NotifyIconW(NIM_MODIFY, &mut data);
+
+                update_tray_icon(data.hWnd, data.uID, |saved| {
+                    DestroyIcon(saved.hIcon);
+                    saved.hIcon = CopyIcon(data.hIcon);
+                });
             }
-        
-            let info_title_v = match title {
-                Some(t) => to_utf16(t),
-                None => vec![]
-            };
 
-            let length = if info_title_v.len() >= 256 { 255 } else { info_title_v.len() };
-            for i in 0..length {
-                data.szInfoTitle[i] = info_title_v[i];
+            Ok(())
+        }
+
+        /// Shows a popup message on top of the system tray
+        ///
+        /// Parameters:
+        ///   - text: The text in the popup
+        ///   - title: The title of the popup
+        ///   - flags: Flags that specify how the popup is shown. Default is NO_ICON | SILENT.
+        ///   - icon: Icon to display in the popup. Only used if `USER_ICON` is set in flags. If `None`, the tray icon is used.
+        ///
+        /// Note 1: text will be truncated to 255 characters
+        /// Note 2: title will be truncated to 63 characters
+        pub fn show<'a>(&self, text: &'a str, title: Option<&'a str>, flags: Option<TrayNotificationFlags>, icon: Option<&'a Icon>) => try_show {
+            use winapi::um::shellapi::{NIF_INFO, NIF_REALTIME, NIM_MODIFY};
+            use winapi::shared::windef::HICON;
+
+            if self.handle.blank() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            self.handle.tray().ok_or_else(|| NwgError::invalid_handle(BAD_HANDLE))?;
+
+            let default_flags = TrayNotificationFlags::NO_ICON | TrayNotificationFlags::SILENT;
+            let flags = flags.unwrap_or(default_flags);
+
+            unsafe {
+                let mut data = self.notify_default();
+                data.uFlags |= NIF_INFO;
+                if flags.contains(TrayNotificationFlags::REALTIME) { data.uFlags |= NIF_REALTIME; }
+
+                data.dwInfoFlags = (flags - TrayNotificationFlags::REALTIME).bits();
+                data.hBalloonIcon = icon.map(|i| i.handle as HICON).unwrap_or(ptr::null_mut());
+
+                let info_v = to_utf16(text);
+                let length = if info_v.len() >= 256 { 255 } else { info_v.len() };
+                for i in 0..length {
+                    data.szInfo[i] = info_v[i];
+                }
+
+                let info_title_v = match title {
+                    Some(t) => to_utf16(t),
+                    None => vec![]
+                };
+
+                let length = if info_title_v.len() >= 64 { 63 } else { info_title_v.len() };
+                for i in 0..length {
+                    data.szInfoTitle[i] = info_title_v[i];
+                }
+
+                Shell_NotifyIconW(NIM_MODIFY, &mut data);
             }
 
-            Shell_NotifyIconW(NIM_MODIFY, &mut data);
+            Ok(())
         }
     }
 
     fn notify_default(&self) -> NOTIFYICONDATAW {
+        use winapi::um::shellapi::NIF_GUID;
+
         unsafe {
-            let parent = self.handle.tray().unwrap();
+            let (parent, id) = self.handle.tray().unwrap();
+            let guid = tray_icon_guid(parent, id);
+
             NOTIFYICONDATAW {
                 cbSize: mem::size_of::<NOTIFYICONDATAW>() as u32,
                 hWnd: parent,
-                uID: 0,
-                uFlags: 0,
+                uID: id,
+                uFlags: if guid.is_some() { NIF_GUID } else { 0 },
                 uCallbackMessage: 0,
                 hIcon: ptr::null_mut(),
                 szTip: mem::zeroed(),
@@ -233,7 +431,7 @@ impl TrayNotification {
                 u: mem::zeroed(),
                 szInfoTitle: mem::zeroed(),
                 dwInfoFlags: 0,
-                guidItem: mem::zeroed(),
+                guidItem: guid.unwrap_or(mem::zeroed()),
                 hBalloonIcon: ptr::null_mut()
             }
         }
@@ -245,11 +443,13 @@ impl Drop for TrayNotification {
     fn drop(&mut self) {
         use winapi::um::shellapi::NIM_DELETE;
 
-        if self.handle.tray().is_some() {
+        if let Some((hwnd, id)) = self.handle.tray() {
             let mut data = self.notify_default();
             unsafe {
                 Shell_NotifyIconW(NIM_DELETE, &mut data);
             }
+
+            remove_tray_icon(hwnd, id);
         }
 
         self.handle.destroy();
@@ -270,6 +470,7 @@ pub struct TrayNotificationBuilder<'a> {
     realtime: bool,
     callback: bool,
     visible: bool,
+    guid: Option<u128>,
 }
 
 impl<'a> TrayNotificationBuilder<'a> {
@@ -299,6 +500,12 @@ impl<'a> TrayNotificationBuilder<'a> {
         self
     }
 
+    /// Note: the GUID is ignored before Windows 7
+    pub fn guid(mut self, guid: Option<u128>) -> TrayNotificationBuilder<'a> {
+        self.guid = guid;
+        self
+    }
+
     /// Note: balloon_icon is only used if `info` is set AND flags uses `USER_ICON`
     pub fn balloon_icon(mut self, ico: Option<&'a Icon>) -> TrayNotificationBuilder<'a> {
         self.balloon_icon = ico;
@@ -331,29 +538,32 @@ impl<'a> TrayNotificationBuilder<'a> {
     }
 
     pub fn build(self, out: &mut TrayNotification) -> Result<(), NwgError> {
-        use winapi::um::shellapi::{NIM_ADD, NIF_ICON, NIF_TIP, NIF_SHOWTIP, NIF_INFO, NOTIFYICONDATAW_u, NOTIFYICON_VERSION_4,
-         NIF_REALTIME, NIF_MESSAGE, NIS_HIDDEN, NIF_STATE};
+        use winapi::um::shellapi::{NIM_ADD, NIM_SETVERSION, NIF_ICON, NIF_TIP, NIF_SHOWTIP, NIF_INFO, NOTIFYICONDATAW_u, NOTIFYICON_VERSION,
+         NIF_REALTIME, NIF_MESSAGE, NIS_HIDDEN, NIF_STATE, NIF_GUID, NIM_DELETE};
+        use winapi::um::winuser::ChangeWindowMessageFilterEx;
         use winapi::shared::windef::HICON;
         use winapi::um::winnt::WCHAR;
 
         // Flags
-        let version = NOTIFYICON_VERSION_4;
+        // Version 3 sends NIN_SELECT, NIN_KEYSELECT and WM_CONTEXTMENU while keeping the icon ID in WPARAM
+        let version = NOTIFYICON_VERSION;
         let mut flags = NIF_ICON;
         let mut info_flags = 0;
         let mut state = 0;
         
         if self.info.is_some() {
             flags |= NIF_INFO;
-            info_flags |= self.flags.bits();
+            info_flags |= (self.flags - TrayNotificationFlags::REALTIME).bits();
         } 
         
         if self.tip.is_some() {
             flags |= NIF_TIP | NIF_SHOWTIP;
         }
 
-        if self.realtime { flags |= NIF_REALTIME; }
+        if self.realtime || self.flags.contains(TrayNotificationFlags::REALTIME) { flags |= NIF_REALTIME; }
         if self.callback { flags |= NIF_MESSAGE; }
         if !self.visible { state |= NIS_HIDDEN; flags |= NIF_STATE; }
+        if self.guid.is_some() { flags |= NIF_GUID; }
 
         // Resource handles
 
@@ -402,13 +612,15 @@ impl<'a> TrayNotificationBuilder<'a> {
         let mut title: [WCHAR; 64] = [0; 64];
         if self.info.is_some() && self.info_title.is_some() {
             let info_title_v = to_utf16(self.info_title.unwrap());
-            let length = if info_title_v.len() >= 256 { 255 } else { info_title_v.len() };
+            let length = if info_title_v.len() >= 64 { 63 } else { info_title_v.len() };
             for i in 0..length {
                 title[i] = info_title_v[i];
             }
         }
 
         // Creation
+        let (_, id) = handle.tray().expect(BAD_HANDLE);
+
         unsafe {
             let mut u: NOTIFYICONDATAW_u = mem::zeroed();
             *u.uVersion_mut() = version;
@@ -416,7 +628,7 @@ impl<'a> TrayNotificationBuilder<'a> {
             let mut data = NOTIFYICONDATAW {
                 cbSize: mem::size_of::<NOTIFYICONDATAW>() as u32,
                 hWnd: parent,
-                uID: 0,
+                uID: id,
                 uFlags: flags,
                 uCallbackMessage: wh::NWG_TRAY,
                 hIcon: icon,
@@ -427,11 +639,32 @@ impl<'a> TrayNotificationBuilder<'a> {
                 u,
                 szInfoTitle: title,
                 dwInfoFlags: info_flags,
-                guidItem: mem::zeroed(),
+                guidItem: self.guid.map(guid_from_u128).unwrap_or(mem::zeroed()),
                 hBalloonIcon: balloon_icon
             };
 
-            Shell_NotifyIconW(NIM_ADD, &mut data);
+            if self.guid.is_some() {
+                // An icon left behind by a previous instance that did not exit cleanly keeps the GUID registered
+                let mut stale = data;
+                Shell_NotifyIconW(NIM_DELETE, &mut stale);
+
+                // The GUID is registered to another executable path
+                if Shell_NotifyIconW(NIM_ADD, &mut data) == 0 {
+                    data.uFlags &= !NIF_GUID;
+                    data.guidItem = mem::zeroed();
+                    Shell_NotifyIconW(NIM_ADD, &mut data);
+                }
+            } else {
+                Shell_NotifyIconW(NIM_ADD, &mut data);
+            }
+
+            Shell_NotifyIconW(NIM_SETVERSION, &mut data);
+
+            save_tray_icon(&data);
+
+            // Receive the taskbar creation message even if the application runs as administrator
+            const MSGFLT_ALLOW: u32 = 1;
+            ChangeWindowMessageFilterEx(parent, taskbar_created_message(), MSGFLT_ALLOW, ptr::null_mut());
         }
 
 
@@ -6,7 +6,7 @@ use winapi::shared::minwindef::{WPARAM, LPARAM};
 use winapi::um::winuser::{WS_VISIBLE, WS_DISABLED, WS_TABSTOP};
 use winapi::um::commctrl::{HTREEITEM, TVIS_EXPANDED, TVIS_SELECTED, TVS_SHOWSELALWAYS, TVITEMW};
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::{check_hwnd, to_utf16, from_utf16};
+use crate::win32::base_helper::{try_check_hwnd, to_utf16, from_utf16};
 use crate::{Font, NwgError};
 use super::{ControlBase, ControlHandle};
 use std::{mem, ptr};
@@ -166,120 +166,130 @@ impl TreeView {
         }
     }
 
-    /// Sets the image list of the treeview
-    #[cfg(feature="image-list")]
-    pub fn set_image_list(&self, list: Option<&ImageList>) {
-        use winapi::um::commctrl::{TVM_SETIMAGELIST, TVSIL_NORMAL};
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let list_handle = list.map(|l| l.handle).unwrap_or(ptr::null_mut());
+    try_accessors! {
+        /// Sets the image list of the treeview
+        #[cfg(feature="image-list")]
+        pub fn set_image_list(&self, list: Option<&ImageList>) => try_set_image_list {
+            use winapi::um::commctrl::{TVM_SETIMAGELIST, TVSIL_NORMAL};
 
-        wh::send_message(handle, TVM_SETIMAGELIST, TVSIL_NORMAL, list_handle as _);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let list_handle = list.map(|l| l.handle).unwrap_or(ptr::null_mut());
 
-    /// Returns the image list of the treeview or None if there is none.
-    /// The returned image list is not owned
-    #[cfg(feature="image-list")]
-    pub fn image_list(&self) -> Option<ImageList> {
-        use winapi::um::commctrl::{TVM_GETIMAGELIST, TVSIL_NORMAL};
+            wh::send_message(handle, TVM_SETIMAGELIST, TVSIL_NORMAL, list_handle as _);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let handle = wh::send_message(handle, TVM_GETIMAGELIST, TVSIL_NORMAL, 0) as HIMAGELIST;
-        if handle.is_null() {
-            None
-        } else {
-            Some(ImageList { handle, owned: false })
+            Ok(())
         }
-    }
 
-    /// Sets the image that will appear left to the item text. `index` is the index of the image in the image-list
-    /// Won't do anything if the control do not have a image list or if the item is not in the tree
-    /// If `on_select` is set to true, sets the icon that is used when an item is active
-    #[cfg(feature="image-list")]
-    pub fn set_item_image(&self, item: &TreeItem, index: i32, on_select: bool) {
-        use winapi::um::commctrl::{TVM_SETITEMW, TVIF_IMAGE, TVIF_SELECTEDIMAGE};
+        /// Returns the image list of the treeview or None if there is none.
+        /// The returned image list is not owned
+        #[cfg(feature="image-list")]
+        pub fn image_list(&self) -> Option<ImageList> => try_image_list {
+            use winapi::um::commctrl::{TVM_GETIMAGELIST, TVSIL_NORMAL};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let handle = wh::send_message(handle, TVM_GETIMAGELIST, TVSIL_NORMAL, 0) as HIMAGELIST;
+            Ok(if handle.is_null() {
+                None
+            } else {
+                Some(ImageList { handle, owned: false })
+            })
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut tree_item = blank_item();
-        tree_item.hItem = item.handle;
+        /// Sets the image that will appear left to the item text. `index` is the index of the image in the image-list
+        /// Won't do anything if the control do not have a image list or if the item is not in the tree
+        /// If `on_select` is set to true, sets the icon that is used when an item is active
+        #[cfg(feature="image-list")]
+        pub fn set_item_image(&self, item: &TreeItem, index: i32, on_select: bool) => try_set_item_image {
+            use winapi::um::commctrl::{TVM_SETITEMW, TVIF_IMAGE, TVIF_SELECTEDIMAGE};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let mut tree_item = blank_item();
+            tree_item.hItem = item.handle;
+
+            tree_item.mask = match on_select {
+                true => TVIF_SELECTEDIMAGE,
+                false => TVIF_IMAGE
+            };
+
+            match on_select {
+                true => { tree_item.iSelectedImage = index; },
+                false => { tree_item.iImage = index; }
+            }
 
-        tree_item.mask = match on_select {
-            true => TVIF_SELECTEDIMAGE,
-            false => TVIF_IMAGE
-        };
+            wh::send_message(handle, TVM_SETITEMW, 0, &mut tree_item as *mut TVITEMW as LPARAM);
 
-        match on_select {
-            true => { tree_item.iSelectedImage = index; },
-            false => { tree_item.iImage = index; }
+            Ok(())
         }
 
-        wh::send_message(handle, TVM_SETITEMW, 0, &mut tree_item as *mut TVITEMW as LPARAM);
-    }
-
-    /// Returns the index of the image in the tree view image list.
-    /// If there is no image list in the control or the item is not in the control, 0 will be returned.
-    /// If `on_select` is set to true, returns the icon that is used when an item is active
-    #[cfg(feature="image-list")]
-    pub fn item_image(&self, item: &TreeItem, on_select: bool) -> i32 {
-        use winapi::um::commctrl::{TVM_GETITEMW, TVIF_IMAGE, TVIF_SELECTEDIMAGE};
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut tree_item = blank_item();
-        tree_item.mask = TVIF_IMAGE | TVIF_SELECTEDIMAGE;
-        tree_item.hItem = item.handle;
-
-        match wh::send_message(handle, TVM_GETITEMW, 0, &mut tree_item as *mut TVITEMW as LPARAM) {
-            0 => 0,
-            _ => match on_select {
-                true => tree_item.iSelectedImage,
-                false => tree_item.iImage
-            }
+        /// Returns the index of the image in the tree view image list.
+        /// If there is no image list in the control or the item is not in the control, 0 will be returned.
+        /// If `on_select` is set to true, returns the icon that is used when an item is active
+        #[cfg(feature="image-list")]
+        pub fn item_image(&self, item: &TreeItem, on_select: bool) -> i32 => try_item_image {
+            use winapi::um::commctrl::{TVM_GETITEMW, TVIF_IMAGE, TVIF_SELECTEDIMAGE};
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let mut tree_item = blank_item();
+            tree_item.mask = TVIF_IMAGE | TVIF_SELECTEDIMAGE;
+            tree_item.hItem = item.handle;
+
+            Ok(match wh::send_message(handle, TVM_GETITEMW, 0, &mut tree_item as *mut TVITEMW as LPARAM) {
+                0 => 0,
+                _ => match on_select {
+                    true => tree_item.iSelectedImage,
+                    false => tree_item.iImage
+                }
+            })
         }
-    }
 
-    /// Sets the text color in the treeview
-    pub fn set_text_color(&self, r: u8, g: u8, b: u8) {
-        use winapi::um::commctrl::TVM_SETTEXTCOLOR;
-        use winapi::um::wingdi::RGB;
+        /// Sets the text color in the treeview
+        pub fn set_text_color(&self, r: u8, g: u8, b: u8) => try_set_text_color {
+            use winapi::um::commctrl::TVM_SETTEXTCOLOR;
+            use winapi::um::wingdi::RGB;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let color = RGB(r, g, b);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let color = RGB(r, g, b);
 
-        wh::send_message(handle, TVM_SETTEXTCOLOR, 0, color as _);
+            wh::send_message(handle, TVM_SETTEXTCOLOR, 0, color as _);
 
-        self.invalidate();
-    }
+            self.invalidate();
 
-    /// Returns the text color in the treeview
-    pub fn text_color(&self) -> [u8; 3] {
-        use winapi::um::commctrl::TVM_GETTEXTCOLOR;
-        use winapi::um::wingdi::{GetRValue, GetGValue, GetBValue};
+            Ok(())
+        }
+
+        /// Returns the text color in the treeview
+        pub fn text_color(&self) -> [u8; 3] => try_text_color {
+            use winapi::um::commctrl::TVM_GETTEXTCOLOR;
+            use winapi::um::wingdi::{GetRValue, GetGValue, GetBValue};
         
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let col = wh::send_message(handle, TVM_GETTEXTCOLOR, 0, 0) as u32;
+            let col = wh::send_message(handle, TVM_GETTEXTCOLOR, 0, 0) as u32;
 
-        [
-            GetRValue(col),
-            GetGValue(col),
-            GetBValue(col),
-        ]
-    }
+            Ok([
+                GetRValue(col),
+                GetGValue(col),
+                GetBValue(col),
+            ])
+        }
 
-    /// Retrieves the amount, in pixels, that child items are indented relative to their parent items. 
-    pub fn indent(&self) -> u32 {
-        use winapi::um::commctrl::TVM_GETINDENT;
+        /// Retrieves the amount, in pixels, that child items are indented relative to their parent items. 
+        pub fn indent(&self) -> u32 => try_indent {
+            use winapi::um::commctrl::TVM_GETINDENT;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TVM_GETINDENT, 0, 0) as u32
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TVM_GETINDENT, 0, 0) as u32)
+        }
+
+        /// Sets the width of indentation for a tree-view control and redraws the control to reflect the new width.
+        pub fn set_indent(&self, indent: u32) => try_set_indent {
+            use winapi::um::commctrl::TVM_SETINDENT;
 
-    /// Sets the width of indentation for a tree-view control and redraws the control to reflect the new width.
-    pub fn set_indent(&self, indent: u32) {
-        use winapi::um::commctrl::TVM_SETINDENT;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TVM_SETINDENT, indent as _, 0);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TVM_SETINDENT, indent as _, 0);
+            Ok(())
+        }
     }
 
     /// Return the root item of the tree view if one is present.
@@ -314,327 +324,369 @@ impl TreeView {
         next_treeview_item(&self.handle, TVGN_PARENT, item.handle)
     }
 
-    /// Return the currently selected item. If there are more than one selected item, returns the first one.
-    /// If there is no selected item, returns `None`.
-    pub fn selected_item(&self) -> Option<TreeItem> {
-        use winapi::um::commctrl::{TVM_GETNEXTITEM, TVGN_NEXTSELECTED};
+    try_accessors! {
+        /// Return the currently selected item. If there are more than one selected item, returns the first one.
+        /// If there is no selected item, returns `None`.
+        pub fn selected_item(&self) -> Option<TreeItem> => try_selected_item {
+            use winapi::um::commctrl::{TVM_GETNEXTITEM, TVGN_NEXTSELECTED};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let tree_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, 0) as HTREEITEM;
-        if tree_handle.is_null() {
-            None
-        } else {
-            Some(TreeItem { handle: tree_handle })
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let tree_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, 0) as HTREEITEM;
+            Ok(if tree_handle.is_null() {
+                None
+            } else {
+                Some(TreeItem { handle: tree_handle })
+            })
         }
-    }
 
-    /// Returns the selected items in a Treeview
-    /// If there is no selected items, returns an empty `Vec`.
-    pub fn selected_items(&self) -> Vec<TreeItem> {
-        use winapi::um::commctrl::{TVM_GETNEXTITEM, TVGN_NEXTSELECTED};
+        /// Returns the selected items in a Treeview
+        /// If there is no selected items, returns an empty `Vec`.
+        pub fn selected_items(&self) -> Vec<TreeItem> => try_selected_items {
+            use winapi::um::commctrl::{TVM_GETNEXTITEM, TVGN_NEXTSELECTED};
 
-        let mut items = Vec::new();
+            let mut items = Vec::new();
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut last_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, 0);
-        while last_handle != 0 {
-            items.push(TreeItem { handle: last_handle as _ } );
-            last_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, last_handle as _);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let mut last_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, 0);
+            while last_handle != 0 {
+                items.push(TreeItem { handle: last_handle as _ } );
+                last_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, last_handle as _);
+            }
+
+            Ok(items)
         }
 
-        items
-    }
+        /// Returns the number of selected item in the tree view
+        pub fn selected_item_count(&self) -> usize => try_selected_item_count {
+            use winapi::um::commctrl::{TVM_GETNEXTITEM, TVGN_NEXTSELECTED};
 
-    /// Returns the number of selected item in the tree view
-    pub fn selected_item_count(&self) -> usize {
-        use winapi::um::commctrl::{TVM_GETNEXTITEM, TVGN_NEXTSELECTED};
+            let mut count = 0;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let mut count = 0;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let mut last_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, 0);
+            while last_handle != 0 {
+                count += 1;
+                last_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, last_handle as _);
+            }
 
-        let mut last_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, 0);
-        while last_handle != 0 {
-            count += 1;
-            last_handle = wh::send_message(handle, TVM_GETNEXTITEM, TVGN_NEXTSELECTED, last_handle as _);
+            Ok(count)
         }
 
-        count
-    }
-
-    /// Insert a new item into the TreeView and return a reference to new newly added item
-    pub fn insert_item<'a>(&self, new: &'a str, parent: Option<&TreeItem>, position: TreeInsert) -> TreeItem {
-        use winapi::um::commctrl::{TVM_INSERTITEMW, TVINSERTSTRUCTW, TVI_FIRST, TVI_LAST, TVI_ROOT, TVI_SORT, TVIF_TEXT};
-        use winapi::um::commctrl::TVINSERTSTRUCTW_u;
-        use winapi::um::winnt::LPWSTR;
+        /// Insert a new item into the TreeView and return a reference to new newly added item
+        pub fn insert_item<'a>(&self, new: &'a str, parent: Option<&TreeItem>, position: TreeInsert) -> TreeItem => try_insert_item {
+            use winapi::um::commctrl::{TVM_INSERTITEMW, TVINSERTSTRUCTW, TVI_FIRST, TVI_LAST, TVI_ROOT, TVI_SORT, TVIF_TEXT};
+            use winapi::um::commctrl::TVINSERTSTRUCTW_u;
+            use winapi::um::winnt::LPWSTR;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let insert = match position {
-            TreeInsert::First => TVI_FIRST,
-            TreeInsert::Last => TVI_LAST,
-            TreeInsert::Root => TVI_ROOT,
-            TreeInsert::Sort => TVI_SORT,
-            TreeInsert::After(i) => i
-        };
+            let insert = match position {
+                TreeInsert::First => TVI_FIRST,
+                TreeInsert::Last => TVI_LAST,
+                TreeInsert::Root => TVI_ROOT,
+                TreeInsert::Sort => TVI_SORT,
+                TreeInsert::After(i) => i
+            };
 
-        let text = to_utf16(new);
+            let text = to_utf16(new);
 
-        let item = {
-            let mut item: TVINSERTSTRUCTW_u = unsafe { mem::zeroed() };
-            let i = unsafe { item.item_mut() };
-            i.mask = TVIF_TEXT;
-            i.pszText = text.as_ptr() as LPWSTR;
-            item
-        };
+            let item = {
+                let mut item: TVINSERTSTRUCTW_u = unsafe { mem::zeroed() };
+                let i = unsafe { item.item_mut() };
+                i.mask = TVIF_TEXT;
+                i.pszText = text.as_ptr() as LPWSTR;
+                item
+            };
 
-        let new_item = TVINSERTSTRUCTW {
-            hParent: parent.map(|p| p.handle ).unwrap_or(ptr::null_mut()),
-            hInsertAfter: insert,
-            u: item
-        };
+            let new_item = TVINSERTSTRUCTW {
+                hParent: parent.map(|p| p.handle ).unwrap_or(ptr::null_mut()),
+                hInsertAfter: insert,
+                u: item
+            };
 
-        let ptr = &new_item as *const TVINSERTSTRUCTW;
-        let handle = wh::send_message(handle, TVM_INSERTITEMW, 0, ptr as LPARAM) as HTREEITEM;
+            let ptr = &new_item as *const TVINSERTSTRUCTW;
+            let handle = wh::send_message(handle, TVM_INSERTITEMW, 0, ptr as LPARAM) as HTREEITEM;
 
-        self.invalidate();
-
-        TreeItem { handle }
-    }
+            self.invalidate();
 
-    /// Insert a new item into the TreeView with associated lParam and return a reference to new newly added item
-    pub fn insert_item_with_param<'a>(&self, new: &'a str, parent: Option<&TreeItem>, position: TreeInsert, data: isize) -> TreeItem {
-        use winapi::um::commctrl::{TVM_INSERTITEMW, TVINSERTSTRUCTW, TVI_FIRST, TVI_LAST, TVI_ROOT, TVI_SORT, TVIF_TEXT, TVIF_PARAM};
-        use winapi::um::commctrl::TVINSERTSTRUCTW_u;
-        use winapi::um::winnt::LPWSTR;
+            Ok(TreeItem { handle })
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /**
+            Insert many items into the TreeView and return a reference to the newly added items. The control is not redrawn until all the items are added.
+            The items keep their order: with `TreeInsert::First` or `TreeInsert::After`, each item is inserted after the previous one.
+        */
+        pub fn insert_items<'a>(&self, new: &[&'a str], parent: Option<&TreeItem>, position: TreeInsert) -> Vec<TreeItem> => try_insert_items {
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let _freeze = self.handle.freeze_guard();
+
+            let mut position = position;
+            let mut items = Vec::with_capacity(new.len());
+            for text in new {
+                let item = self.try_insert_item(text, parent, position)?;
+
+                position = match position {
+                    TreeInsert::First | TreeInsert::After(_) => TreeInsert::After(item.handle),
+                    p => p
+                };
+
+                items.push(item);
+            }
 
-        let insert = match position {
-            TreeInsert::First => TVI_FIRST,
-            TreeInsert::Last => TVI_LAST,
-            TreeInsert::Root => TVI_ROOT,
-            TreeInsert::Sort => TVI_SORT,
-            TreeInsert::After(i) => i
-        };
+            Ok(items)
+        }
 
-        let text = to_utf16(new);
+        /// Insert a new item into the TreeView with associated lParam and return a reference to new newly added item
+        pub fn insert_item_with_param<'a>(&self, new: &'a str, parent: Option<&TreeItem>, position: TreeInsert, data: isize) -> TreeItem => try_insert_item_with_param {
+            use winapi::um::commctrl::{TVM_INSERTITEMW, TVINSERTSTRUCTW, TVI_FIRST, TVI_LAST, TVI_ROOT, TVI_SORT, TVIF_TEXT, TVIF_PARAM};
+            use winapi::um::commctrl::TVINSERTSTRUCTW_u;
+            use winapi::um::winnt::LPWSTR;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let insert = match position {
+                TreeInsert::First => TVI_FIRST,
+                TreeInsert::Last => TVI_LAST,
+                TreeInsert::Root => TVI_ROOT,
+                TreeInsert::Sort => TVI_SORT,
+                TreeInsert::After(i) => i
+            };
+
+            let text = to_utf16(new);
+
+            let item = {
+                let mut item: TVINSERTSTRUCTW_u = unsafe { mem::zeroed() };
+                let i = unsafe { item.item_mut() };
+                i.mask = TVIF_TEXT | TVIF_PARAM;            
+                i.pszText = text.as_ptr() as LPWSTR;
+                i.lParam = data;
+                item
+            };
+
+            let new_item = TVINSERTSTRUCTW {
+                hParent: parent.map(|p| p.handle ).unwrap_or(ptr::null_mut()),
+                hInsertAfter: insert,
+                u: item
+            };
+
+            let ptr = &new_item as *const TVINSERTSTRUCTW;
+            let handle = wh::send_message(handle, TVM_INSERTITEMW, 0, ptr as LPARAM) as HTREEITEM;
+
+            self.invalidate();
+
+            Ok(TreeItem { handle })
+        }
 
-        let item = {
-            let mut item: TVINSERTSTRUCTW_u = unsafe { mem::zeroed() };
-            let i = unsafe { item.item_mut() };
-            i.mask = TVIF_TEXT | TVIF_PARAM;            
-            i.pszText = text.as_ptr() as LPWSTR;
-            i.lParam = data;
-            item
-        };
+        /// Remove an item and its children from the tree view
+        pub fn remove_item(&self, item: &TreeItem) => try_remove_item {
+            use winapi::um::commctrl::{TVM_DELETEITEM};
 
-        let new_item = TVINSERTSTRUCTW {
-            hParent: parent.map(|p| p.handle ).unwrap_or(ptr::null_mut()),
-            hInsertAfter: insert,
-            u: item
-        };
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TVM_DELETEITEM, 0, item.handle as LPARAM);
 
-        let ptr = &new_item as *const TVINSERTSTRUCTW;
-        let handle = wh::send_message(handle, TVM_INSERTITEMW, 0, ptr as LPARAM) as HTREEITEM;
+            Ok(())
+        }
 
-        self.invalidate();
+        /// Selects the specified tree-view item and scrolls the item into view.
+        pub fn select_item(&self, item: &TreeItem) => try_select_item {
+            use winapi::um::commctrl::{TVM_SETITEMW, TVIF_STATE};
 
-        TreeItem { handle }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let mut tree_item = blank_item();
+            tree_item.mask = TVIF_STATE;
+            tree_item.hItem = item.handle;
+            tree_item.state = TVIS_SELECTED;
+            tree_item.stateMask = TVIS_SELECTED;
 
-    /// Remove an item and its children from the tree view
-    pub fn remove_item(&self, item: &TreeItem) {
-        use winapi::um::commctrl::{TVM_DELETEITEM};
+            wh::send_message(handle, TVM_SETITEMW, 0, &mut tree_item as *mut TVITEMW as LPARAM);
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TVM_DELETEITEM, 0, item.handle as LPARAM);
-    }
+            Ok(())
+        }
 
-    /// Selects the specified tree-view item and scrolls the item into view.
-    pub fn select_item(&self, item: &TreeItem) {
-        use winapi::um::commctrl::{TVM_SETITEMW, TVIF_STATE};
+        /// Unselects an item from the treeview
+        pub fn unselect_item(&self, item: &TreeItem) => try_unselect_item {
+            use winapi::um::commctrl::{TVM_SETITEMW, TVIF_STATE};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut tree_item = blank_item();
-        tree_item.mask = TVIF_STATE;
-        tree_item.hItem = item.handle;
-        tree_item.state = TVIS_SELECTED;
-        tree_item.stateMask = TVIS_SELECTED;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let mut tree_item = blank_item();
+            tree_item.mask = TVIF_STATE;
+            tree_item.hItem = item.handle;
+            tree_item.state = 0;
+            tree_item.stateMask = TVIS_SELECTED;
 
-        wh::send_message(handle, TVM_SETITEMW, 0, &mut tree_item as *mut TVITEMW as LPARAM);
-    }
+            wh::send_message(handle, TVM_SETITEMW, 0, &mut tree_item as *mut TVITEMW as LPARAM);
 
-    /// Unselects an item from the treeview
-    pub fn unselect_item(&self, item: &TreeItem) {
-        use winapi::um::commctrl::{TVM_SETITEMW, TVIF_STATE};
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let mut tree_item = blank_item();
-        tree_item.mask = TVIF_STATE;
-        tree_item.hItem = item.handle;
-        tree_item.state = 0;
-        tree_item.stateMask = TVIS_SELECTED;
+        /// Creates an iterator over the tree view items
+        #[cfg(feature="tree-view-iterator")]
+        pub fn iter(&self) -> crate::TreeViewIterator<'_> => try_iter {
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(crate::TreeViewIterator::new(self, ptr::null_mut()))
+        }
 
-        wh::send_message(handle, TVM_SETITEMW, 0, &mut tree_item as *mut TVITEMW as LPARAM);
-    }
+        /// Creates an iterator over the children of an item. This does not include the item itself.
+        #[cfg(feature="tree-view-iterator")]
+        pub fn iter_item(&self, item: &TreeItem) -> crate::TreeViewIterator<'_> => try_iter_item {
+            try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(crate::TreeViewIterator::new(self, item.handle))
+        }
 
-    /// Creates an iterator over the tree view items
-    #[cfg(feature="tree-view-iterator")]
-    pub fn iter<'a>(&'a self) -> crate::TreeViewIterator<'a> {
-        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        crate::TreeViewIterator::new(self, ptr::null_mut())
-    }
+        /// Returns the text of the selected item. Return None if the item is not in the tree view.
+        /// The returned text value cannot be bigger than 260 characters
+        pub fn item_text(&self, tree_item: &TreeItem) -> Option<String> => try_item_text {
+            use winapi::um::commctrl::{TVM_GETITEMW, TVIF_TEXT, TVIF_HANDLE};
+            const BUFFER_MAX: usize = 260;
 
-    /// Creates an iterator over the children of an item. This does not include the item itself.
-    #[cfg(feature="tree-view-iterator")]
-    pub fn iter_item<'a>(&'a self, item: &TreeItem) -> crate::TreeViewIterator<'a> {
-        check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        crate::TreeViewIterator::new(self, item.handle)
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Returns the text of the selected item. Return None if the item is not in the tree view.
-    /// The returned text value cannot be bigger than 260 characters
-    pub fn item_text(&self, tree_item: &TreeItem) -> Option<String> {
-        use winapi::um::commctrl::{TVM_GETITEMW, TVIF_TEXT, TVIF_HANDLE};
-        const BUFFER_MAX: usize = 260;
+            let mut text_buffer = Vec::with_capacity(BUFFER_MAX);
+            unsafe { text_buffer.set_len(BUFFER_MAX); }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let mut item: TVITEMW = blank_item();
+            item.mask = TVIF_TEXT | TVIF_HANDLE;
+            item.hItem = tree_item.handle;
+            item.pszText = text_buffer.as_mut_ptr();
+            item.cchTextMax = BUFFER_MAX as _;
 
-        let mut text_buffer = Vec::with_capacity(BUFFER_MAX);
-        unsafe { text_buffer.set_len(BUFFER_MAX); }
+            let result = wh::send_message(handle, TVM_GETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
+            if result == 0 {
+                return Ok(None);
+            }
 
-        let mut item: TVITEMW = blank_item();
-        item.mask = TVIF_TEXT | TVIF_HANDLE;
-        item.hItem = tree_item.handle;
-        item.pszText = text_buffer.as_mut_ptr();
-        item.cchTextMax = BUFFER_MAX as _;
-        
-        let result = wh::send_message(handle, TVM_GETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
-        if result == 0 {
-            return None;
+            Ok(Some(from_utf16(&text_buffer)))
         }
-
-        Some(from_utf16(&text_buffer))
     }
     
-    /// Set the text for specified item in the treeview.
-    pub fn set_item_text(&self, tree_item: &TreeItem, new_text: &str) {
-        use winapi::um::commctrl::{TVM_SETITEMW, TVIF_TEXT};
-        use winapi::um::winnt::LPWSTR;
+    try_accessors! {
+        /// Set the text for specified item in the treeview.
+        pub fn set_item_text(&self, tree_item: &TreeItem, new_text: &str) => try_set_item_text {
+            use winapi::um::commctrl::{TVM_SETITEMW, TVIF_TEXT};
+            use winapi::um::winnt::LPWSTR;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let text = to_utf16(new_text);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let text = to_utf16(new_text);
 
-        let mut item: TVITEMW = blank_item();
-        item.mask = TVIF_TEXT;
-        item.hItem = tree_item.handle;
-        item.pszText = text.as_ptr() as LPWSTR;
+            let mut item: TVITEMW = blank_item();
+            item.mask = TVIF_TEXT;
+            item.hItem = tree_item.handle;
+            item.pszText = text.as_ptr() as LPWSTR;
 
-        wh::send_message(handle, TVM_SETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
-    }
+            wh::send_message(handle, TVM_SETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
 
-    /// Returns the lParam of the selected item. Return None if the item is not in the tree view.
-    pub fn item_param(&self, tree_item: &TreeItem) -> Option<isize> {
-        use winapi::um::commctrl::{TVM_GETITEMW, TVIF_PARAM, TVIF_HANDLE};
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Returns the lParam of the selected item. Return None if the item is not in the tree view.
+        pub fn item_param(&self, tree_item: &TreeItem) -> Option<isize> => try_item_param {
+            use winapi::um::commctrl::{TVM_GETITEMW, TVIF_PARAM, TVIF_HANDLE};
 
-        let mut item: TVITEMW = blank_item();
-        item.mask = TVIF_HANDLE | TVIF_PARAM;
-        item.hItem = tree_item.handle;
-        
-        let result = wh::send_message(handle, TVM_GETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
-        if result == 0 {
-            return None;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+
+            let mut item: TVITEMW = blank_item();
+            item.mask = TVIF_HANDLE | TVIF_PARAM;
+            item.hItem = tree_item.handle;
+
+            let result = wh::send_message(handle, TVM_GETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
+            if result == 0 {
+                return Ok(None);
+            }
+
+            Ok(Some(item.lParam))
         }
 
-        Some(item.lParam)
-    }
+        /// Returns `true` if the tree view item has children. Returns `None` if the item is not in the tree view.
+        pub fn item_has_children(&self, tree_item: &TreeItem) -> Option<bool> => try_item_has_children {
+            use winapi::um::commctrl::{TVM_GETITEMW, TVIF_CHILDREN, TVIF_HANDLE};
 
-    /// Returns `true` if the tree view item has children. Returns `None` if the item is not in the tree view.
-    pub fn item_has_children(&self, tree_item: &TreeItem) -> Option<bool> {
-        use winapi::um::commctrl::{TVM_GETITEMW, TVIF_CHILDREN, TVIF_HANDLE};
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let mut item: TVITEMW = blank_item();
+            item.hItem = tree_item.handle;
+            item.mask = TVIF_CHILDREN | TVIF_HANDLE;
 
-        let mut item: TVITEMW = blank_item();
-        item.hItem = tree_item.handle;
-        item.mask = TVIF_CHILDREN | TVIF_HANDLE;
-        
-        let result = wh::send_message(handle, TVM_GETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
-        if result == 0 {
-            return None;
+            let result = wh::send_message(handle, TVM_GETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
+            if result == 0 {
+                return Ok(None);
+            }
+
+            Ok(Some(item.cChildren != 0))
         }
 
-        Some(item.cChildren != 0)
-    }
+        /// Returns the item state in the tree view or `None` if the item is not in the tree view
+        pub fn item_state(&self, tree_item: &TreeItem) -> Option<TreeItemState> => try_item_state {
+            use winapi::um::commctrl::{TVM_GETITEMW, TVIF_STATE, TVIF_HANDLE};
 
-    /// Returns the item state in the tree view or `None` if the item is not in the tree view
-    pub fn item_state(&self, tree_item: &TreeItem) -> Option<TreeItemState> {
-        use winapi::um::commctrl::{TVM_GETITEMW, TVIF_STATE, TVIF_HANDLE};
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let mut item: TVITEMW = unsafe { mem::zeroed() };
+            item.hItem = tree_item.handle;
+            item.mask = TVIF_STATE | TVIF_HANDLE;
+            item.stateMask = 0xFF;
 
-        let mut item: TVITEMW = unsafe { mem::zeroed() };
-        item.hItem = tree_item.handle;
-        item.mask = TVIF_STATE | TVIF_HANDLE;
-        item.stateMask = 0xFF;
-        
-        let result = wh::send_message(handle, TVM_GETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
-        if result == 0 {
-            return None;
+            let result = wh::send_message(handle, TVM_GETITEMW, 0, &mut item as *mut TVITEMW as LPARAM);
+            if result == 0 {
+                return Ok(None);
+            }
+
+            Ok(Some(TreeItemState::from_bits_truncate(item.state)))
         }
 
-        Some(TreeItemState::from_bits_truncate(item.state))
-    }
+        /// Expands or collapses the list of child items associated with the specified parent item, if any. 
+        pub fn set_expand_state(&self, item: &TreeItem, state: ExpandState) => try_set_expand_state {
+            use winapi::um::commctrl::{TVM_EXPAND, TVE_COLLAPSE, TVE_COLLAPSERESET, TVE_EXPAND, TVE_EXPANDPARTIAL, TVE_TOGGLE};
 
-    /// Expands or collapses the list of child items associated with the specified parent item, if any. 
-    pub fn set_expand_state(&self, item: &TreeItem, state: ExpandState) {
-        use winapi::um::commctrl::{TVM_EXPAND, TVE_COLLAPSE, TVE_COLLAPSERESET, TVE_EXPAND, TVE_EXPANDPARTIAL, TVE_TOGGLE};
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let state = match state {
+                ExpandState::Collapse => TVE_COLLAPSE,
+                ExpandState::CollapseReset => TVE_COLLAPSE | TVE_COLLAPSERESET,
+                ExpandState::Expand => TVE_EXPAND,
+                ExpandState::ExpandPartial => TVE_EXPANDPARTIAL,
+                ExpandState::Toggle => TVE_TOGGLE,
+            };
 
-        let state = match state {
-            ExpandState::Collapse => TVE_COLLAPSE,
-            ExpandState::CollapseReset => TVE_COLLAPSE | TVE_COLLAPSERESET,
-            ExpandState::Expand => TVE_EXPAND,
-            ExpandState::ExpandPartial => TVE_EXPANDPARTIAL,
-            ExpandState::Toggle => TVE_TOGGLE,
-        };
+            wh::send_message(handle, TVM_EXPAND, state as WPARAM, item.handle as LPARAM);
 
-        wh::send_message(handle, TVM_EXPAND, state as WPARAM, item.handle as LPARAM);
-    }
+            Ok(())
+        }
 
-    /// Ensures that a tree-view item is visible, expanding the parent item or scrolling the tree-view control, if necessary.
-    pub fn ensure_visible(&self, item: &TreeItem) {
-        use winapi::um::commctrl::{TVM_ENSUREVISIBLE};
+        /// Ensures that a tree-view item is visible, expanding the parent item or scrolling the tree-view control, if necessary.
+        pub fn ensure_visible(&self, item: &TreeItem) => try_ensure_visible {
+            use winapi::um::commctrl::{TVM_ENSUREVISIBLE};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TVM_ENSUREVISIBLE, 0, item.handle as LPARAM);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TVM_ENSUREVISIBLE, 0, item.handle as LPARAM);
+
+            Ok(())
+        }
 
-    /// Remove every item from the treeview by removing the root item
-    pub fn clear(&self) {
-        use winapi::um::commctrl::{TVM_DELETEITEM, TVI_ROOT};
+        /// Remove every item from the treeview by removing the root item
+        pub fn clear(&self) => try_clear {
+            use winapi::um::commctrl::{TVM_DELETEITEM, TVI_ROOT};
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TVM_DELETEITEM, 0, TVI_ROOT  as LPARAM);
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::send_message(handle, TVM_DELETEITEM, 0, TVI_ROOT  as LPARAM);
 
-    /// Return the total number of item in the tree view
-    pub fn len(&self) -> usize {
-        use winapi::um::commctrl::TVM_GETCOUNT;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TVM_GETCOUNT, 0, 0) as usize
-    }
+        /// Return the total number of item in the tree view
+        pub fn len(&self) -> usize => try_len {
+            use winapi::um::commctrl::TVM_GETCOUNT;
 
-    /// Return the number of item in the tree view visible by the user
-    pub fn visible_len(&self) -> usize {
-        use winapi::um::commctrl::TVM_GETVISIBLECOUNT;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TVM_GETCOUNT, 0, 0) as usize)
+        }
+
+        /// Return the number of item in the tree view visible by the user
+        pub fn visible_len(&self) -> usize => try_visible_len {
+            use winapi::um::commctrl::TVM_GETVISIBLECOUNT;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::send_message(handle, TVM_GETVISIBLECOUNT, 0, 0) as usize
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(wh::send_message(handle, TVM_GETVISIBLECOUNT, 0, 0) as usize)
+        }
     }
 
 
@@ -642,92 +694,107 @@ impl TreeView {
     // Common methods
     //
 
-    /// Invalidate the whole drawing region.
-    pub fn invalidate(&self) {
-        use winapi::um::winuser::InvalidateRect;
+    try_accessors! {
+        /// Invalidate the whole drawing region.
+        pub fn invalidate(&self) => try_invalidate {
+            use winapi::um::winuser::InvalidateRect;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { InvalidateRect(handle, ptr::null(), 1); }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { InvalidateRect(handle, ptr::null(), 1); }
 
+            Ok(())
+        }
 
-    /// Return the font of the control
-    pub fn font(&self) -> Option<Font> {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+        /// Return the font of the control
+        pub fn font(&self) -> Option<Font> => try_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-        let font_handle = wh::get_window_font(handle);
-        if font_handle.is_null() {
-            None
-        } else {
-            Some(Font { handle: font_handle })
+            let font_handle = wh::get_window_font(handle);
+            Ok(if font_handle.is_null() {
+                None
+            } else {
+                Some(Font { handle: font_handle })
+            })
         }
-    }
 
-    /// Set the font of the control
-    pub fn set_font(&self, font: Option<&Font>) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
-    }
+        /// Set the font of the control
+        pub fn set_font(&self, font: Option<&Font>) => try_set_font {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_font(handle, font.map(|f| f.handle), true); }
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the keyboard focus on the button.
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+        /// Set the keyboard focus on the button.
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+            Ok(())
+        }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+            Ok(())
+        }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, false) }
-    }
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
-    }
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
+
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, false) }
+
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -750,31 +817,33 @@ impl TreeView {
         WS_CHILD | WS_BORDER | TVS_NOTOOLTIPS
     }
 
-    /// Begins to in-place edit the specified item's text.
-    /// Return None if Failed.
-    /// Return the treeview's handle if successful.
-    pub fn edit_label(&self, item: &TreeItem) -> Option<ControlHandle> {
-        use winapi::um::commctrl::TVM_EDITLABELW;
-        use winapi::shared::windef::HWND;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+    try_accessors! {
+        /// Begins to in-place edit the specified item's text.
+        /// Return None if Failed.
+        /// Return the treeview's handle if successful.
+        pub fn edit_label(&self, item: &TreeItem) -> Option<ControlHandle> => try_edit_label {
+            use winapi::um::commctrl::TVM_EDITLABELW;
+            use winapi::shared::windef::HWND;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
     
-        let result = wh::send_message(handle,  TVM_EDITLABELW, 0, item.handle as HTREEITEM as LPARAM); 
+            let result = wh::send_message(handle,  TVM_EDITLABELW, 0, item.handle as HTREEITEM as LPARAM); 
         
-        if result == 0 {
-            return None;
-        }
-        Some(ControlHandle::Hwnd(result as HWND))
-    } 
-
-    /// End the in-place editing of the tree item's label.
-    /// The parameter f_cancel indicates whether the editing is canceled without being saved to the label. 
-    /// If this parameter is TRUE, the system cancels editing without saving the changes. Otherwise, the system saves the changes to the label.
-    /// Return true if successful, otherwise return false.
-    pub fn end_edit_label_now(&self, f_cancel: bool) -> bool {
-        use winapi::um::commctrl::TVM_ENDEDITLABELNOW;
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            if result == 0 {
+                return Ok(None);
+            }
+            Ok(Some(ControlHandle::Hwnd(result as HWND)))
+        }
+
+        /// End the in-place editing of the tree item's label.
+        /// The parameter f_cancel indicates whether the editing is canceled without being saved to the label. 
+        /// If this parameter is TRUE, the system cancels editing without saving the changes. Otherwise, the system saves the changes to the label.
+        /// Return true if successful, otherwise return false.
+        pub fn end_edit_label_now(&self, f_cancel: bool) -> bool => try_end_edit_label_now {
+            use winapi::um::commctrl::TVM_ENDEDITLABELNOW;
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
     
-        wh::send_message(handle,  TVM_ENDEDITLABELNOW, f_cancel as WPARAM, 0) != 0
+            Ok(wh::send_message(handle,  TVM_ENDEDITLABELNOW, f_cancel as WPARAM, 0) != 0)
+        }
     }
 }
 
@@ -1,11 +1,15 @@
 use winapi::um::winuser::{WS_OVERLAPPEDWINDOW, WS_CLIPCHILDREN, WS_VISIBLE, WS_DISABLED, WS_MAXIMIZE, WS_MINIMIZE, WS_CAPTION,
-WS_MINIMIZEBOX, WS_MAXIMIZEBOX, WS_SYSMENU, WS_THICKFRAME, WS_POPUP, WS_EX_TOPMOST, WS_EX_ACCEPTFILES};
+WS_MINIMIZEBOX, WS_MAXIMIZEBOX, WS_SYSMENU, WS_THICKFRAME, WS_POPUP, WS_EX_TOPMOST, WS_EX_ACCEPTFILES, WS_EX_LAYOUTRTL,
+WS_EX_COMPOSITED};
 
 use crate::win32::window_helper as wh;
-use crate::win32::base_helper::check_hwnd;
+use crate::win32::base_helper::try_check_hwnd;
 use crate::{NwgError, Icon};
 use super::{ControlBase, ControlHandle};
 
+#[cfg(feature = "menu")]
+use crate::win32::menu as mh;
+
 const NOT_BOUND: &'static str = "Window is not yet bound to a winapi object";
 const BAD_HANDLE: &'static str = "INTERNAL ERROR: Window handle is not HWND!";
 
@@ -62,6 +66,10 @@ bitflags! {
       * `minimized`:   If the window should be minimized at creation
       * `center`:      Center the window in the current monitor based on its size. If `true`, this overrides `position`
       * `topmost`:     If the window should always be on top of other system window
+      * `rtl_layout`:  Mirror the window for right-to-left languages: the origin is the top right corner and the children inherit the layout.
+                       The default value is the layout set with `set_process_default_layout`.
+      * `composited`:  Paint the window and its children bottom-to-top in a back buffer (`WS_EX_COMPOSITED`). This reduces the flickering,
+                       but it slows down the GPU canvases and some common controls. Defaults to `false`.
       * `parent`:      Logical parent of the window, unlike children controls, this is NOT required.
 
     **Control events:**
@@ -99,6 +107,8 @@ impl Window {
             center: false,
             maximized: false,
             minimized: false,
+            rtl_layout: false,
+            composited: false,
             flags: None,
             ex_flags: 0,
             icon: None,
@@ -106,139 +116,224 @@ impl Window {
         }
     }
 
-    /// Maximize the window
-    pub fn maximize(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::maximize_window(handle);
-    }
+    try_accessors! {
+        /// Maximize the window
+        pub fn maximize(&self) => try_maximize {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::maximize_window(handle);
 
-    /// Minimize the window
-    pub fn minimize(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::minimize_window(handle);
-    }
+            Ok(())
+        }
 
-    /// Restore a minimized/maximized window
-    pub fn restore(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::restore_window(handle);
-    }
+        /// Minimize the window
+        pub fn minimize(&self) => try_minimize {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::minimize_window(handle);
 
-    /// Force the window to refraw iteself and all its children
-    pub fn invalidate(&self) {
-        use winapi::um::winuser::InvalidateRect;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { InvalidateRect(handle, ::std::ptr::null(), 1); }
-    }
+        /// Restore a minimized/maximized window
+        pub fn restore(&self) => try_restore {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::restore_window(handle);
 
-    /// Close the window as if the user clicked the X button.
-    pub fn close(&self) {
-        use winapi::um::winuser::WM_CLOSE;
+            Ok(())
+        }
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        wh::post_message(handle, WM_CLOSE, 0, 0);
-    }
+        /// Force the window to refraw iteself and all its children
+        pub fn invalidate(&self) => try_invalidate {
+            use winapi::um::winuser::InvalidateRect;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { InvalidateRect(handle, ::std::ptr::null(), 1); }
 
-    /// Return the icon of the window
-    pub fn icon(&self) -> Option<Icon> {
-        use winapi::um::winuser::WM_GETICON;
-        use winapi::um::winnt::HANDLE;
-
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        let handle = wh::send_message(handle, WM_GETICON, 0, 0);
-        if handle == 0 {
-            None
-        } else {
-            Some(Icon { handle: handle as HANDLE, owned: false })
+            Ok(())
         }
-    }
 
-    /// Set the icon in the window
-    /// - icon: The new icon. If None, the icon is removed
-    pub fn set_icon(&self, icon: Option<&Icon>) {
-        use winapi::um::winuser::WM_SETICON;
-        use std::{mem, ptr};
+        /// Close the window as if the user clicked the X button.
+        pub fn close(&self) => try_close {
+            use winapi::um::winuser::WM_CLOSE;
 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            wh::post_message(handle, WM_CLOSE, 0, 0);
 
-        let image_handle = icon.map(|i| i.handle).unwrap_or(ptr::null_mut());
-        unsafe {
-            wh::send_message(handle, WM_SETICON, 0, mem::transmute(image_handle));
+            Ok(())
         }
-    }
 
-    /// Return true if the control currently has the keyboard focus
-    pub fn focus(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_focus(handle) }
-    }
+        /// Return the icon of the window
+        pub fn icon(&self) -> Option<Icon> => try_icon {
+            use winapi::um::winuser::WM_GETICON;
+            use winapi::um::winnt::HANDLE;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let handle = wh::send_message(handle, WM_GETICON, 0, 0);
+            Ok(if handle == 0 {
+                None
+            } else {
+                Some(Icon { handle: handle as HANDLE, owned: false })
+            })
+        }
 
-    /// Set the keyboard focus on the button
-    pub fn set_focus(&self) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_focus(handle); }
-    }
+        /// Set the icon in the window
+        /// - icon: The new icon. If None, the icon is removed
+        pub fn set_icon(&self, icon: Option<&Icon>) => try_set_icon {
+            use winapi::um::winuser::WM_SETICON;
+            use std::{mem, ptr};
 
-    /// Return true if the control user can interact with the control, return false otherwise
-    pub fn enabled(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_enabled(handle) }
-    }
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
 
-    /// Enable or disable the control
-    pub fn set_enabled(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_enabled(handle, v) }
-    }
+            let image_handle = icon.map(|i| i.handle).unwrap_or(ptr::null_mut());
+            unsafe {
+                wh::send_message(handle, WM_SETICON, 0, mem::transmute(image_handle));
+            }
 
-    /// Return true if the control is visible to the user. Will return true even if the 
-    /// control is outside of the parent client view (ex: at the position (10000, 10000))
-    pub fn visible(&self) -> bool {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_visibility(handle) }
-    }
+            Ok(())
+        }
 
-    /// Show or hide the control to the user
-    pub fn set_visible(&self, v: bool) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_visibility(handle, v) }
-    }
+        /// Return true if the control currently has the keyboard focus
+        pub fn focus(&self) -> bool => try_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_focus(handle) })
+        }
 
-    /// Return the size of the button in the parent window
-    pub fn size(&self) -> (u32, u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_size(handle) }
-    }
+        /// Set the keyboard focus on the button
+        pub fn set_focus(&self) => try_set_focus {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_focus(handle); }
 
-    /// Set the size of the button in the parent window
-    pub fn set_size(&self, x: u32, y: u32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_size(handle, x, y, true) }
-    }
+            Ok(())
+        }
+
+        /// Return true if the control user can interact with the control, return false otherwise
+        pub fn enabled(&self) -> bool => try_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_enabled(handle) })
+        }
+
+        /// Enable or disable the control
+        pub fn set_enabled(&self, v: bool) => try_set_enabled {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_enabled(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return true if the control is visible to the user. Will return true even if the 
+        /// control is outside of the parent client view (ex: at the position (10000, 10000))
+        pub fn visible(&self) -> bool => try_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_visibility(handle) })
+        }
+
+        /// Show or hide the control to the user
+        pub fn set_visible(&self, v: bool) => try_set_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_visibility(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the size of the button in the parent window
+        pub fn size(&self) -> (u32, u32) => try_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_size(handle) })
+        }
 
-    /// Return the position of the button in the parent window
-    pub fn position(&self) -> (i32, i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_position(handle) }
+        /// Set the size of the button in the parent window
+        pub fn set_size(&self, x: u32, y: u32) => try_set_size {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_size(handle, x, y, true) }
+
+            Ok(())
+        }
+
+        /// Return the position of the button in the parent window
+        pub fn position(&self) -> (i32, i32) => try_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_position(handle) })
+        }
+
+        /// Set the position of the button in the parent window
+        pub fn set_position(&self, x: i32, y: i32) => try_set_position {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_position(handle, x, y) }
+
+            Ok(())
+        }
+
+        /// Return window title
+        pub fn text(&self) -> String => try_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { wh::get_window_text(handle) })
+        }
+
+        /// Set the window title
+        pub fn set_text<'a>(&self, v: &'a str) => try_set_text {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { wh::set_window_text(handle, v) }
+
+            Ok(())
+        }
+
+        /// Return the handle of the menu bar of the window, even if the menu bar is hidden.
+        /// Compare it with the handle of a `MenuBar` to know which menu bar is displayed.
+        #[cfg(feature = "menu")]
+        pub fn menu_bar(&self) -> Option<ControlHandle> => try_menu_bar {
+            use std::ptr;
+
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            let menu = unsafe { mh::menu_bar(handle) };
+            Ok(match menu.is_null() {
+                true => None,
+                false => Some(ControlHandle::Menu(ptr::null_mut(), menu))
+            })
+        }
     }
 
-    /// Set the position of the button in the parent window
-    pub fn set_position(&self, x: i32, y: i32) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_position(handle, x, y) }
+    /// Display a menu bar in the window, or remove the menu bar if `bar` is None. `bar` is usually a `MenuBar`
+    /// or a handle returned by `menu_bar`. The previous menu bar is not destroyed.
+    /// If the menu bar is hidden, `bar` will be displayed when it is shown again.
+    #[cfg(feature = "menu")]
+    pub fn set_menu_bar<C: Into<ControlHandle>>(&self, bar: Option<C>) {
+        self.try_set_menu_bar(bar).unwrap_or_else(|e| panic!("{}", e))
     }
 
-    /// Return window title
-    pub fn text(&self) -> String { 
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::get_window_text(handle) }
+    /// Same as `set_menu_bar`, but returns an error instead of panicking.
+    ///
+    /// Returns an `InvalidHandle` error if the control is not bound or if its window was destroyed.
+    #[cfg(feature = "menu")]
+    pub fn try_set_menu_bar<C: Into<ControlHandle>>(&self, bar: Option<C>) -> Result<(), NwgError> {
+        use std::ptr;
+
+        let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+        let menu = match bar.map(|b| b.into()) {
+            Some(bar) => bar.hmenu().map(|(_, m)| m).expect("Menu bar handle is not HMENU"),
+            None => ptr::null_mut()
+        };
+
+        unsafe { mh::set_menu_bar(handle, menu); }
+
+        Ok(())
     }
 
-    /// Set the window title
-    pub fn set_text<'a>(&self, v: &'a str) {
-        let handle = check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE);
-        unsafe { wh::set_window_text(handle, v) }
+    try_accessors! {
+        /// Return true if the window has a menu bar and the menu bar is not hidden
+        #[cfg(feature = "menu")]
+        pub fn menu_bar_visible(&self) -> bool => try_menu_bar_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            Ok(unsafe { mh::menu_bar_visible(handle) })
+        }
+
+        /// Show or hide the menu bar of the window. The menus are kept while the menu bar is hidden
+        /// and new menus can still be added to it. Ex: hide the menu bar and show it when the user press `Alt`.
+        #[cfg(feature = "menu")]
+        pub fn set_menu_bar_visible(&self, v: bool) => try_set_menu_bar_visible {
+            let handle = try_check_hwnd(&self.handle, NOT_BOUND, BAD_HANDLE)?;
+            unsafe { mh::set_menu_bar_visible(handle, v) }
+
+            Ok(())
+        }
     }
 
     /// Winapi class name used during control creation
@@ -264,25 +359,29 @@ impl Drop for Window {
 }
 
 #[cfg(feature = "raw-win-handle")]
-use raw_window_handle::{HasRawWindowHandle, RawWindowHandle, windows::WindowsHandle};
+use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
 
 #[cfg(feature = "raw-win-handle")]
 unsafe impl HasRawWindowHandle for Window {
     fn raw_window_handle(&self) -> RawWindowHandle {
-        use winapi::um::winuser::GWL_HINSTANCE;
-        match self.handle {
-            ControlHandle::Hwnd(hwnd) => {
-                let hinstance = wh::get_window_long(hwnd, GWL_HINSTANCE);
-
-                RawWindowHandle::Windows(WindowsHandle {
-                    hwnd: hwnd as _,
-                    hinstance: hinstance as _,
-                    ..WindowsHandle::empty()
-                })
-            }
-            // Not a valid window handle, so return an empty handle
-            _ => RawWindowHandle::Windows(WindowsHandle::empty())
-        }
+        self.handle.raw_window_handle()
+    }
+}
+
+#[cfg(feature = "raw-win-handle-05")]
+use raw_window_handle_05::{HasRawDisplayHandle, RawDisplayHandle};
+
+#[cfg(feature = "raw-win-handle-05")]
+unsafe impl raw_window_handle_05::HasRawWindowHandle for Window {
+    fn raw_window_handle(&self) -> raw_window_handle_05::RawWindowHandle {
+        raw_window_handle_05::HasRawWindowHandle::raw_window_handle(&self.handle)
+    }
+}
+
+#[cfg(feature = "raw-win-handle-05")]
+unsafe impl HasRawDisplayHandle for Window {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        self.handle.raw_display_handle()
     }
 }
 
@@ -295,6 +394,8 @@ pub struct WindowBuilder<'a> {
     topmost: bool,
     maximized: bool,
     minimized: bool,
+    rtl_layout: bool,
+    composited: bool,
     flags: Option<WindowFlags>,
     ex_flags: u32,
     icon: Option<&'a Icon>,
@@ -358,6 +459,16 @@ impl<'a> WindowBuilder<'a> {
         self
     }
 
+    pub fn rtl_layout(mut self, rtl_layout: bool) -> WindowBuilder<'a> {
+        self.rtl_layout = rtl_layout;
+        self
+    }
+
+    pub fn composited(mut self, composited: bool) -> WindowBuilder<'a> {
+        self.composited = composited;
+        self
+    }
+
     pub fn parent<C: Into<ControlHandle>>(mut self, p: Option<C>) -> WindowBuilder<'a> {
         self.parent = p.map(|p2| p2.into());
         self
@@ -371,6 +482,8 @@ impl<'a> WindowBuilder<'a> {
         let mut ex_flags = self.ex_flags;
         if self.topmost { ex_flags |= WS_EX_TOPMOST; }
         if self.accept_files { ex_flags |= WS_EX_ACCEPTFILES; }
+        if self.rtl_layout { ex_flags |= WS_EX_LAYOUTRTL; }
+        if self.composited { ex_flags |= WS_EX_COMPOSITED; }
 
         *out = Default::default();
 
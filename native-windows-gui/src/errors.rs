@@ -1,5 +1,6 @@
 use std::fmt;
 use std::error::Error;
+use winapi::shared::windef::HWND;
 
 #[cfg(feature = "plotting")]
 use crate::win32::plotters_d2d::PlottersError;
@@ -29,10 +30,49 @@ pub enum NwgError {
     /// Error raised when an event handler could not be bound
     EventsBinding(String),
 
+    /// Error raised by the `try_` methods of the controls when the control is not bound or when its window was destroyed
+    InvalidHandle(String),
+
+    /// Error raised when a system function failed.
+    SystemError {
+        /// The operation that failed (ex: "CreateWindowExW")
+        operation: String,
+        /// The window class of the control that was created or modified, if any (ex: "BUTTON")
+        control: Option<String>,
+        /// The system error code returned by `GetLastError`
+        code: u32,
+        /// The description of the system error code
+        message: String,
+    },
+
+    /// Error raised when the system failed to create the window of a control
+    WindowCreationError {
+        /// The window class of the control (ex: "BUTTON")
+        class_name: String,
+        /// The window styles used to create the control
+        flags: u32,
+        /// The extended window styles used to create the control
+        ex_flags: u32,
+        /// The handle of the parent window, if any
+        parent: Option<usize>,
+        /// The system error code returned by `GetLastError`
+        code: u32,
+        /// The description of the system error code
+        message: String,
+    },
+
     /// Error raised by the FileDialog object
     #[cfg(feature = "file-dialog")]
     FileDialogError(String),
 
+    /// Error raised by the high level clipboard functions
+    #[cfg(feature = "clipboard")]
+    ClipboardError(String),
+
+    /// Error raised when a drop target cannot be registered
+    #[cfg(feature = "drag-drop")]
+    DragDropError(String),
+
     /// Error raised by the ImageDecoder feature
     #[cfg(feature = "image-decoder")]
     ImageDecoderError(i32, String),
@@ -44,6 +84,30 @@ pub enum NwgError {
     /// Error raised by one of the locale functions
     #[cfg(feature = "plotting")]
     Plotters(PlottersError),
+
+    /// Error raised by the direct2D canvas. Holds the HRESULT of the failed call.
+    #[cfg(feature = "d2d-canvas")]
+    Direct2D(i32, String),
+
+    /// Error raised by the media player when a media file cannot be opened
+    #[cfg(feature = "media-player")]
+    MediaPlayerError(String),
+
+    /// Error raised when a file or an url cannot be opened by the shell
+    #[cfg(feature = "shell")]
+    ShellError(String),
+
+    /// Error raised when the accessible properties of a control cannot be set
+    #[cfg(feature = "accessibility")]
+    AccessibilityError(String),
+
+    /// Error raised when a GUI description cannot be loaded
+    #[cfg(feature = "loader")]
+    LoaderError(String),
+
+    /// Error raised when the application restart or recovery cannot be registered
+    #[cfg(feature = "recovery")]
+    RecoveryError(String),
 }
 
 impl NwgError {
@@ -72,11 +136,62 @@ impl NwgError {
         NwgError::EventsBinding(e.into())
     }
 
+    pub fn invalid_handle<S: Into<String>>(e: S) -> NwgError {
+        NwgError::InvalidHandle(e.into())
+    }
+
+    /// Create a `SystemError` from the last system error of the calling thread. Must be called right after the failed function.
+    pub fn system<S: Into<String>>(operation: S) -> NwgError {
+        NwgError::system_error(operation.into(), None)
+    }
+
+    /// Same as `NwgError::system`, with the window class of the control that was created or modified
+    pub fn system_control<S1: Into<String>, S2: Into<String>>(operation: S1, control: S2) -> NwgError {
+        NwgError::system_error(operation.into(), Some(control.into()))
+    }
+
+    fn system_error(operation: String, control: Option<String>) -> NwgError {
+        let (code, message) = unsafe { crate::win32::base_helper::get_system_error() };
+        NwgError::SystemError { operation, control, code, message: message.trim_end().to_string() }
+    }
+
+    /// Create a `WindowCreationError` from the last system error of the calling thread. Must be called right after `CreateWindowExW`.
+    pub fn window_creation<S: Into<String>>(class_name: S, flags: u32, ex_flags: u32, parent: Option<HWND>) -> NwgError {
+        let (code, message) = unsafe { crate::win32::base_helper::get_system_error() };
+        NwgError::WindowCreationError {
+            class_name: class_name.into(),
+            flags,
+            ex_flags,
+            parent: parent.map(|p| p as usize),
+            code,
+            message: message.trim_end().to_string()
+        }
+    }
+
+    /// Returns the system error code of a `SystemError` or of a `WindowCreationError`
+    pub fn os_code(&self) -> Option<u32> {
+        match self {
+            NwgError::SystemError { code, .. } => Some(*code),
+            NwgError::WindowCreationError { code, .. } => Some(*code),
+            _ => None
+        }
+    }
+
     #[cfg(feature = "file-dialog")]
     pub fn file_dialog<S: Into<String>>(e: S) -> NwgError {
         NwgError::FileDialogError(e.into())
     }
 
+    #[cfg(feature = "clipboard")]
+    pub fn clipboard<S: Into<String>>(e: S) -> NwgError {
+        NwgError::ClipboardError(e.into())
+    }
+
+    #[cfg(feature = "drag-drop")]
+    pub fn drag_drop<S: Into<String>>(e: S) -> NwgError {
+        NwgError::DragDropError(e.into())
+    }
+
     #[cfg(feature = "winnls")]
     pub fn bad_locale<S: Into<String>>(e: S) -> NwgError {
         NwgError::BadLocale(e.into())
@@ -87,6 +202,36 @@ impl NwgError {
         NwgError::ImageDecoderError(code, e.into())
     }
 
+    #[cfg(feature = "d2d-canvas")]
+    pub fn direct2d<S: Into<String>>(code: i32, e: S) -> NwgError {
+        NwgError::Direct2D(code, e.into())
+    }
+
+    #[cfg(feature = "media-player")]
+    pub fn media_player<S: Into<String>>(e: S) -> NwgError {
+        NwgError::MediaPlayerError(e.into())
+    }
+
+    #[cfg(feature = "shell")]
+    pub fn shell<S: Into<String>>(e: S) -> NwgError {
+        NwgError::ShellError(e.into())
+    }
+
+    #[cfg(feature = "accessibility")]
+    pub fn accessibility<S: Into<String>>(e: S) -> NwgError {
+        NwgError::AccessibilityError(e.into())
+    }
+
+    #[cfg(feature = "loader")]
+    pub fn loader<S: Into<String>>(e: S) -> NwgError {
+        NwgError::LoaderError(e.into())
+    }
+
+    #[cfg(feature = "recovery")]
+    pub fn recovery<S: Into<String>>(e: S) -> NwgError {
+        NwgError::RecoveryError(e.into())
+    }
+
     pub fn no_parent(name: &'static str) -> NwgError {
         NwgError::ControlCreationError(format!("No parent defined for {:?} control", name))
     }
@@ -109,10 +254,25 @@ impl fmt::Display for NwgError {
             ResourceCreationError(reason) => write!(f, "Failed to create a resource: {:?}", reason),
             LayoutCreationError(reason) => write!(f, "Failed to create a layout: {:?}", reason),
             EventsBinding(reason) => write!(f, "Failed to bind events: {:?}", reason),
+            InvalidHandle(reason) => write!(f, "{}", reason),
+            SystemError { operation, control, code, message } => match control {
+                Some(control) => write!(f, "{} failed for a {:?} control: {} (error {})", operation, control, message, code),
+                None => write!(f, "{} failed: {} (error {})", operation, message, code),
+            },
+            WindowCreationError { class_name, flags, ex_flags, parent, code, message } => match parent {
+                Some(parent) => write!(f, "Failed to create a {:?} control (flags: {:#X}, ex flags: {:#X}, parent: {:#X}): {} (error {})", class_name, flags, ex_flags, parent, message, code),
+                None => write!(f, "Failed to create a {:?} control (flags: {:#X}, ex flags: {:#X}, no parent): {} (error {})", class_name, flags, ex_flags, message, code),
+            },
             
             #[cfg(feature = "file-dialog")]
             FileDialogError(reason) => write!(f, "File dialog actions failed: {:?}", reason),
 
+            #[cfg(feature = "clipboard")]
+            ClipboardError(reason) => write!(f, "Clipboard function failed: {:?}", reason),
+
+            #[cfg(feature = "drag-drop")]
+            DragDropError(reason) => write!(f, "Drag and drop function failed: {:?}", reason),
+
             #[cfg(feature = "image-decoder")]
             ImageDecoderError(_id, reason) => write!(f, "Image decoder failed: {:?}", reason),
 
@@ -121,6 +281,20 @@ impl fmt::Display for NwgError {
 
             #[cfg(feature = "plotting")]
             Plotters(reason) => write!(f, "Plotting canvas function failed: {}", reason),
+
+            #[cfg(feature = "d2d-canvas")]
+            Direct2D(code, reason) => write!(f, "Direct2D canvas function failed: {:?} (0x{:08X})", reason, code),
+
+            #[cfg(feature = "media-player")]
+            MediaPlayerError(reason) => write!(f, "Media player function failed: {:?}", reason),
+            #[cfg(feature = "shell")]
+            ShellError(reason) => write!(f, "Shell function failed: {:?}", reason),
+            #[cfg(feature = "accessibility")]
+            AccessibilityError(reason) => write!(f, "Accessibility function failed: {:?}", reason),
+            #[cfg(feature = "loader")]
+            LoaderError(reason) => write!(f, "Failed to load the GUI description: {}", reason),
+            #[cfg(feature = "recovery")]
+            RecoveryError(reason) => write!(f, "Application restart or recovery function failed: {}", reason),
         }
         
     }
@@ -28,6 +28,18 @@ pub enum Event {
     /// Read the delta value with `EventData::OnMouseWheel` to check which key.
     OnMouseWheel,
 
+    /// When a pen or a finger touches a control.
+    /// The callback will also receive a `EventData::OnPointer`
+    OnPointerDown,
+
+    /// When a pen or a finger moves over a control, or when the pressure or the buttons of the pen change.
+    /// The callback will also receive a `EventData::OnPointer`
+    OnPointerUpdate,
+
+    /// When a pen or a finger stops touching a control.
+    /// The callback will also receive a `EventData::OnPointer`
+    OnPointerUp,
+
     /// Generic window event when the user right clicks a window
     OnContextMenu,
 
@@ -37,6 +49,14 @@ pub enum Event {
     /// When a control needs to be redrawn
     OnPaint,
 
+    /// When the system needs the size of an item of an owner drawn list box or combo box.
+    /// The size is set using the event data `EventData::OnMeasureItem`.
+    OnMeasureItem,
+
+    /// When an owner drawn control (button, list box or combo box) or one of its items must be painted.
+    /// The event data `EventData::OnDrawItem` wraps the device context of the control.
+    OnDrawItem,
+
     /// When a key is pressed on a keyboard. Unlike OnKeyDown, this returns a char (ex: 'c') in a EventData::OnChar.
     OnChar,
 
@@ -99,6 +119,9 @@ pub enum Event {
     /// When a button is clicked twice rapidly
     OnButtonDoubleClick,
 
+    /// When the drop down arrow of a split button is clicked
+    OnButtonDropDown,
+
     /// When a label is clicked
     OnLabelClick,
 
@@ -114,6 +137,13 @@ pub enum Event {
     /// When TextInput value is changed
     OnTextInput,
 
+    /// When the user tries to insert more text than the limit of a text control (see `set_limit`)
+    OnTextLimitReached,
+
+    /// When the user right clicks a misspelled word in a rich text box with spell checking enabled.
+    /// Requires the `spell-check` feature.
+    OnMisspelledWord,
+
     /// When the list of a combobox is closed
     OnComboBoxClosed,
 
@@ -123,6 +153,9 @@ pub enum Event {
     /// When the current selection of the combobox was changed
     OnComboxBoxSelection,
 
+    /// When the user changed the text of an editable combobox
+    OnComboBoxTextChanged,
+
     /// When the date select dropdown is expanded
     OnDatePickerDropdown,
 
@@ -138,18 +171,37 @@ pub enum Event {
     /// When an item on a list box is selected
     OnListBoxSelect,
 
+    /// When the selection of a list box is changed by the user. The event data holds the indices of every selected item.
+    OnListBoxSelectionChanged,
+
     /// The selected tab of a TabsContainer changed
     TabsContainerChanged,
 
     /// The selected tab of a TabsContainer is about to be changed
     TabsContainerChanging,
 
+    /// When the value of a NumberSelect is changed by the user
+    /// The callback will also receive a `EventData::OnNumberChanged`
+    OnNumberChanged,
+
+    /// When the user clicks the close button of a tab. The tab is not closed automatically: drop the `Tab` to close it.
+    /// The callback will also receive a `EventData::OnTabCloseRequest`
+    OnTabCloseRequest,
+
     /// When the trackbar thumb is released by the user
     TrackBarUpdated,
 
-    /// When a menu control is opened
+    /// When the value of a trackbar is changed by the user or by `TrackBar::set_pos`
+    OnTrackBarValueChanged,
+
+    /// When a menu control is opened. Submenus can be filled at this point.
+    /// The callback will also receive a `EventData::OnMenuPopup`
     OnMenuOpen,
 
+    /// When a menu control is closed.
+    /// The callback will also receive a `EventData::OnMenuPopup`
+    OnMenuClose,
+
     /// When the user enters the menu modal loop
     OnMenuEnter,
 
@@ -157,11 +209,68 @@ pub enum Event {
     OnMenuExit,
 
     /// When a menu is hovered (either through mouse or keyboard)
+    /// The callback will also receive a `EventData::OnMenuHover`
     OnMenuHover,
 
     /// When the user selects on a menu item
     OnMenuItemSelected,
 
+    /// When the system needs the size of an owner drawn menu item.
+    /// The size is set using the event data `EventData::OnMenuItemMeasure`.
+    OnMenuItemMeasure,
+
+    /// When an owner drawn menu item must be painted.
+    /// The event data `EventData::OnMenuItemDraw` wraps the device context of the menu.
+    OnMenuItemDraw,
+
+    /// When the user selects a file in a `RecentFilesMenu`. The event is sent to the recent files menu handle.
+    /// The callback will also receive a `EventData::OnRecentFileSelected`
+    OnRecentFileSelected,
+
+    /// When the data point under the mouse in a `PlotCanvas` changes.
+    /// The callback will also receive a `EventData::OnPlotHover`
+    OnPlotHover,
+
+    /// When a `MediaPlayer` stops at the end of the media
+    OnMediaEnd,
+
+    /// When data is dragged over a control with a `DropTarget`.
+    /// The callback will also receive a `EventData::OnDragDrop`. Set the drop effect to accept the data.
+    OnDragEnter,
+
+    /// When dragged data moves over a control with a `DropTarget`.
+    /// The callback will also receive a `EventData::OnDragDrop`
+    OnDragOver,
+
+    /// When dragged data leaves a control with a `DropTarget` or when the drag is cancelled
+    OnDragLeave,
+
+    /// When data is dropped on a control with a `DropTarget`.
+    /// The callback will also receive a `EventData::OnDragDrop`
+    OnDrop,
+
+    /// When the input method editor (IME) starts composing text in a control
+    OnImeStartComposition,
+
+    /// When the composition string of the input method editor changes or when the composed text is committed.
+    /// The callback will also receive a `EventData::OnImeComposition`
+    OnImeComposition,
+
+    /// When the input method editor (IME) ends the composition of text in a control
+    OnImeEndComposition,
+
+    /// When the application crashed or stopped responding. Save the data of the user; the process ends after the event.
+    /// See `register_application_recovery`.
+    OnRecovery,
+
+    /// When another instance of the application forwards its command line with `SingleInstance::forward`
+    /// The callback will also receive a `EventData::OnInstanceArgs`
+    OnInstanceArgs,
+
+    /// When the system colors change or when the high contrast mode is toggled. Only sent to the top level windows.
+    /// Use `nwg::high_contrast` and `nwg::system_color` to read the new values.
+    OnSystemColorsChanged,
+
     /// When the user hovers over a callback tooltip
     /// The callback will also receive a `EventData::OnTooltipText`
     OnTooltipText,
@@ -255,6 +364,22 @@ pub enum Event {
     /// When a TrayNotification is closed due to a user click
     OnTrayNotificationUserClose,
 
+    /// When the user double clicks a TrayNotification icon
+    OnTrayNotificationDoubleClick,
+
+    /// When the user selects a TrayNotification icon with the mouse
+    OnTrayNotificationSelect,
+
+    /// When the user selects a TrayNotification icon with the keyboard
+    OnTrayNotificationKeySelect,
+
+    /// When a TrayNotification icon was added back to the system tray after the taskbar was recreated (ex: explorer.exe restarted)
+    OnTrayRecreated,
+
+    /// When the user clicks a toast notification or one of its buttons
+    /// The callback will also receive a `EventData::OnToastActivated`
+    OnToastActivated,
+
     /// When a timer delay is elapsed
     OnTimerTick,
 
@@ -295,6 +420,12 @@ pub enum EventData {
     /// Hold resources that will most likely be used during painting. 
     OnPaint(PaintData),
 
+    /// Sets the size of an item of an owner drawn control
+    OnMeasureItem(MeasureItemData),
+
+    /// Paint an owner drawn control or one of its items
+    OnDrawItem(DrawItemData),
+
     /// The delta value of a mouse wheel event. A positive value indicates that the wheel was rotated to the right; 
     /// a negative value indicates that the wheel was rotated to the left.
     OnMouseWheel(i32),
@@ -302,6 +433,13 @@ pub enum EventData {
     /// The path to one or more files that were dropped in the application
     OnFileDrop(DropFiles),
 
+    /// The menu being opened or closed, and if it is the window menu (the menu opened by clicking the window icon)
+    OnMenuPopup { menu: ControlHandle, system_menu: bool },
+
+    /// The menu that contains the hovered item and the hovered item (a menu item or a submenu).
+    /// `item` is `None` when the menu is closed, ex: to clear a status bar hint.
+    OnMenuHover { menu: ControlHandle, item: Option<ControlHandle> },
+
     /// The handle to the item being deleted. The item is still valid.
     #[cfg(feature="tree-view")]
     OnTreeItemDelete(crate::TreeItem),
@@ -326,6 +464,62 @@ pub enum EventData {
     /// Row index, column index, and selected state of the list view item that raised the event
     #[cfg(feature="list-view")]
     OnListViewItemChanged { row_index: usize, column_index: usize, selected: bool },
+
+    /// The misspelled word that was right clicked, its range in the control text, and the replacements suggested by the spell checker
+    #[cfg(feature="spell-check")]
+    OnMisspelledWord { word: String, range: std::ops::Range<usize>, suggestions: Vec<String> },
+
+    /// The arguments of the toast notification button that was clicked, or an empty string if the toast itself was clicked
+    #[cfg(feature="toast-notification")]
+    OnToastActivated(String),
+
+    /// The new position of the trackbar slider
+    #[cfg(feature="trackbar")]
+    OnTrackBarValueChanged(usize),
+
+    /// The indices of the selected items of a list box
+    #[cfg(feature="listbox")]
+    OnListBoxSelectionChanged(Vec<usize>),
+
+    /// Sets the size of an owner drawn menu item
+    #[cfg(feature="menu")]
+    OnMenuItemMeasure(MenuItemMeasureData),
+
+    /// Paint an owner drawn menu item
+    #[cfg(feature="menu")]
+    OnMenuItemDraw(MenuItemDrawData),
+
+    /// The path of the file selected in a recent files menu
+    #[cfg(feature="menu")]
+    OnRecentFileSelected(String),
+
+    /// The data point under the mouse in a plot canvas. `None` when the mouse left the point.
+    #[cfg(feature="plot-canvas")]
+    OnPlotHover(Option<crate::PlotPoint>),
+
+    /// The data dragged over a drop target
+    #[cfg(feature="drag-drop")]
+    OnDragDrop(DragDropData),
+
+    /// The composition string of the input method editor
+    #[cfg(feature="ime")]
+    OnImeComposition(ImeCompositionData),
+
+    /// The state of a pen or of a finger
+    #[cfg(feature="pointer-input")]
+    OnPointer(PointerData),
+
+    /// The command line forwarded by another instance of the application
+    #[cfg(feature="single-instance")]
+    OnInstanceArgs(Vec<String>),
+
+    /// The index of the tab to close
+    #[cfg(feature="tabs")]
+    OnTabCloseRequest(usize),
+
+    /// The new value of a number select
+    #[cfg(feature="number-select")]
+    OnNumberChanged(crate::NumberSelectData),
 }
 
 impl EventData {
@@ -338,6 +532,22 @@ impl EventData {
         }
     }
 
+    /// Unwraps event data into a `&MeasureItemData`. Panics if it's not the right type.
+    pub fn on_measure_item(&self) -> &MeasureItemData {
+        match self {
+            EventData::OnMeasureItem(m) => m,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&DrawItemData`. Panics if it's not the right type.
+    pub fn on_draw_item(&self) -> &DrawItemData {
+        match self {
+            EventData::OnDrawItem(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
     /// Unwraps event data into a `&MinMaxInfo`. Panics if it's not the right type.
     pub fn on_min_max(&self) -> &MinMaxInfo {
         match self {
@@ -346,6 +556,22 @@ impl EventData {
         }
     }
 
+    /// unwraps event data into the opened or closed menu and if it is the window menu
+    pub fn on_menu_popup(&self) -> (ControlHandle, bool) {
+        match self {
+            &EventData::OnMenuPopup { menu, system_menu } => (menu, system_menu),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// unwraps event data into the parent menu and the hovered item of a menu
+    pub fn on_menu_hover(&self) -> (ControlHandle, Option<ControlHandle>) {
+        match self {
+            &EventData::OnMenuHover { menu, item } => (menu, item),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
     /// Unwraps event data into a `char`. Panics if it's not the right type.
     pub fn on_char(&self) -> char {
         match self {
@@ -378,6 +604,11 @@ impl EventData {
         }
     }
 
+    /// Unwraps event data into the typed key for `OnKeyPress` and `OnKeyRelease`
+    pub fn on_key_typed(&self) -> crate::Key {
+        crate::Key::from(self.on_key())
+    }
+
     /// unwraps event data into the removed tree item
     #[cfg(feature="tree-view")]
     pub fn on_tree_item_delete(&self) -> &crate::TreeItem {
@@ -434,6 +665,132 @@ impl EventData {
         }
     }
 
+    /// unwraps event data into the misspelled word, its range in the control text, and the suggested replacements (word, range, suggestions)
+    #[cfg(feature="spell-check")]
+    pub fn on_misspelled_word(&self) -> (&str, std::ops::Range<usize>, &[String]) {
+        match self {
+            EventData::OnMisspelledWord { word, range, suggestions } => (word, range.clone(), suggestions),
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// unwraps event data into the arguments of an activated toast notification
+    #[cfg(feature="toast-notification")]
+    pub fn on_toast_activated(&self) -> &str {
+        match self {
+            EventData::OnToastActivated(arguments) => arguments,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// unwraps event data into the new position of a trackbar slider
+    #[cfg(feature="trackbar")]
+    pub fn on_track_bar_value_changed(&self) -> usize {
+        match self {
+            &EventData::OnTrackBarValueChanged(pos) => pos,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// unwraps event data into the indices of the selected items of a list box
+    #[cfg(feature="listbox")]
+    pub fn on_list_box_selection_changed(&self) -> &[usize] {
+        match self {
+            EventData::OnListBoxSelectionChanged(indices) => indices,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// unwraps event data into the path of the file selected in a recent files menu
+    #[cfg(feature="menu")]
+    pub fn on_recent_file_selected(&self) -> &str {
+        match self {
+            EventData::OnRecentFileSelected(path) => path,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the hovered point of a plot canvas. Panics if it's not the right type.
+    #[cfg(feature="plot-canvas")]
+    pub fn on_plot_hover(&self) -> Option<crate::PlotPoint> {
+        match self {
+            EventData::OnPlotHover(point) => *point,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&DragDropData`. Panics if it's not the right type.
+    #[cfg(feature="drag-drop")]
+    pub fn on_drag_drop(&self) -> &DragDropData {
+        match self {
+            EventData::OnDragDrop(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `PointerData`. Panics if it's not the right type.
+    #[cfg(feature="pointer-input")]
+    pub fn on_pointer(&self) -> PointerData {
+        match self {
+            EventData::OnPointer(data) => *data,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the command line forwarded by another instance. Panics if it's not the right type.
+    #[cfg(feature="single-instance")]
+    pub fn on_instance_args(&self) -> &[String] {
+        match self {
+            EventData::OnInstanceArgs(args) => args,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the new value of a number select. Panics if it's not the right type.
+    #[cfg(feature="number-select")]
+    pub fn on_number_changed(&self) -> crate::NumberSelectData {
+        match self {
+            EventData::OnNumberChanged(data) => *data,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into the index of the tab to close. Panics if it's not the right type.
+    #[cfg(feature="tabs")]
+    pub fn on_tab_close_request(&self) -> usize {
+        match self {
+            EventData::OnTabCloseRequest(index) => *index,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// Unwraps event data into a `&ImeCompositionData`. Panics if it's not the right type.
+    #[cfg(feature="ime")]
+    pub fn on_ime_composition(&self) -> &ImeCompositionData {
+        match self {
+            EventData::OnImeComposition(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// unwraps event data into a `&MenuItemMeasureData`. Panics if it's not the right type.
+    #[cfg(feature="menu")]
+    pub fn on_menu_item_measure(&self) -> &MenuItemMeasureData {
+        match self {
+            EventData::OnMenuItemMeasure(m) => m,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
+    /// unwraps event data into a `&MenuItemDrawData`. Panics if it's not the right type.
+    #[cfg(feature="menu")]
+    pub fn on_menu_item_draw(&self) -> &MenuItemDrawData {
+        match self {
+            EventData::OnMenuItemDraw(d) => d,
+            d => panic!("Wrong data type: {:?}", d)
+        }
+    }
+
 }
 
 //
@@ -443,9 +800,15 @@ impl EventData {
 use winapi::um::commctrl::NMTTDISPINFOW;
 use winapi::um::winuser::{PAINTSTRUCT, MINMAXINFO, BeginPaint, EndPaint};
 use winapi::um::shellapi::{HDROP, DragFinish};
-use winapi::shared::windef::{HWND, POINT};
+use winapi::shared::windef::{HWND, HDC, POINT};
+use crate::controls::ControlHandle;
+use crate::win32::back_buffer;
+use std::marker::PhantomData;
 use std::fmt;
 
+#[cfg(feature="gdi-canvas")]
+use std::{mem::ManuallyDrop, ops::Deref};
+
 /// A wrapper structure that sets the tooltip text on an `OnTooltipText` callback
 pub struct ToolTipTextData {
     pub(crate) data: *mut NMTTDISPINFOW
@@ -553,6 +916,100 @@ impl PaintData {
         }
     }
 
+    /// Begins painting and returns the device context and the region to paint. Painting ends when the value is dropped.
+    pub fn paint(&self) -> Paint<'_> {
+        Paint::new(self.hwnd)
+    }
+
+}
+
+
+/**
+    The region of a control that must be painted. Created by `PaintData::paint` in a `OnPaint` event.
+
+    `Paint` calls `BeginPaint` when it is created and `EndPaint` when it is dropped. Keep the value alive
+    until the drawing is done and do not call `PaintData::begin_paint` in the same event.
+
+    If the control is double buffered (see `ControlHandle::set_double_buffered`), the device context is a back buffer
+    that has the size of the client area. The painted rectangle is copied to the control when `Paint` is dropped.
+
+    With the `gdi-canvas` feature, `Paint` dereferences to `Canvas` for the drawing functions.
+*/
+pub struct Paint<'a> {
+    hwnd: HWND,
+    paint: PAINTSTRUCT,
+    hdc: HDC,
+    #[cfg(feature="gdi-canvas")]
+    canvas: ManuallyDrop<crate::Canvas<'a>>,
+    phantom: PhantomData<&'a PaintData>,
+}
+
+impl<'a> Paint<'a> {
+
+    fn new(hwnd: HWND) -> Paint<'a> {
+        unsafe {
+            let mut paint: PAINTSTRUCT = ::std::mem::zeroed();
+            BeginPaint(hwnd, &mut paint);
+            let hdc = back_buffer::begin(hwnd, paint.hdc).unwrap_or(paint.hdc);
+
+            Paint {
+                hwnd,
+                paint,
+                hdc,
+                #[cfg(feature="gdi-canvas")]
+                canvas: ManuallyDrop::new(crate::Canvas::from_hdc(hdc)),
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Returns the device context to paint with. The handle is only valid while `Paint` is alive.
+    pub fn hdc(&self) -> HDC {
+        self.hdc
+    }
+
+    /// Returns `true` if the painting is done in a back buffer
+    pub fn buffered(&self) -> bool {
+        self.hdc != self.paint.hdc
+    }
+
+    /// Returns the rectangle that must be painted as `[left, top, right, bottom]`.
+    /// Drawing outside of this rectangle has no effect.
+    pub fn rect(&self) -> [i32; 4] {
+        let r = &self.paint.rcPaint;
+        [r.left, r.top, r.right, r.bottom]
+    }
+
+    /// Returns `true` if the background was not erased by the window and must be painted
+    pub fn erase_background(&self) -> bool {
+        self.paint.fErase != 0
+    }
+
+}
+
+#[cfg(feature="gdi-canvas")]
+impl<'a> Deref for Paint<'a> {
+    type Target = crate::Canvas<'a>;
+
+    fn deref(&self) -> &crate::Canvas<'a> {
+        &self.canvas
+    }
+}
+
+impl<'a> Drop for Paint<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            // The device context state must be restored before it is released
+            #[cfg(feature="gdi-canvas")]
+            ManuallyDrop::drop(&mut self.canvas);
+
+            if self.buffered() {
+                back_buffer::end(self.hdc, self.paint.hdc, self.rect());
+            }
+
+            EndPaint(self.hwnd, &self.paint);
+        }
+    }
 }
 
 
@@ -702,3 +1159,595 @@ impl fmt::Debug for MinMaxInfo {
     }
 }
 
+
+
+fn fill_rect(hdc: winapi::shared::windef::HDC, rect: [i32; 4], color: [u8; 3]) {
+    use winapi::um::wingdi::{CreateSolidBrush, DeleteObject, RGB};
+    use winapi::um::winuser::FillRect;
+    use winapi::shared::windef::RECT;
+
+    let [left, top, right, bottom] = rect;
+    let r = RECT { left, top, right, bottom };
+
+    unsafe {
+        let brush = CreateSolidBrush(RGB(color[0], color[1], color[2]));
+        FillRect(hdc, &r, brush);
+        DeleteObject(brush as _);
+    }
+}
+
+/// Draws a single line of text for an owner drawn item. `state` is the `itemState` of the DRAWITEMSTRUCT.
+fn draw_item_text(hdc: winapi::shared::windef::HDC, state: u32, text: &str, rect: [i32; 4], color: [u8; 3], font: Option<&crate::Font>) {
+    use winapi::um::wingdi::{SetTextColor, SetBkMode, SelectObject, RGB, TRANSPARENT};
+    use winapi::um::winuser::{DrawTextW, ODS_NOACCEL, DT_SINGLELINE, DT_VCENTER, DT_LEFT, DT_EXPANDTABS, DT_HIDEPREFIX};
+    use winapi::shared::windef::RECT;
+    use crate::win32::base_helper::to_utf16;
+
+    let [left, top, right, bottom] = rect;
+    let mut r = RECT { left, top, right, bottom };
+    let text = to_utf16(text);
+
+    let mut flags = DT_SINGLELINE | DT_VCENTER | DT_LEFT | DT_EXPANDTABS;
+    if state & ODS_NOACCEL == ODS_NOACCEL {
+        flags |= DT_HIDEPREFIX;
+    }
+
+    unsafe {
+        let old_font = font.map(|f| SelectObject(hdc, f.handle as _));
+        let old_color = SetTextColor(hdc, RGB(color[0], color[1], color[2]));
+        let old_mode = SetBkMode(hdc, TRANSPARENT as _);
+
+        DrawTextW(hdc, text.as_ptr(), -1, &mut r, flags);
+
+        SetBkMode(hdc, old_mode);
+        SetTextColor(hdc, old_color);
+        if let Some(old_font) = old_font {
+            SelectObject(hdc, old_font);
+        }
+    }
+}
+
+
+/// Sets the size of an item of an owner drawn list box or combo box. Sizes are in physical pixels.
+/// The size defaults to the size of a standard item of the control.
+pub struct MeasureItemData {
+    pub(crate) inner: *mut winapi::um::winuser::MEASUREITEMSTRUCT,
+}
+
+impl MeasureItemData {
+
+    /// Returns the index of the item being measured.
+    /// `None` for the selection field of a combo box.
+    pub fn index(&self) -> Option<usize> {
+        match unsafe { (*self.inner).itemID } {
+            u32::MAX => None,
+            i => Some(i as usize)
+        }
+    }
+
+    /// Sets the size of the item. List boxes and combo boxes only use the height.
+    pub fn set_size(&self, width: u32, height: u32) {
+        let info = unsafe { &mut *self.inner };
+        info.itemWidth = width;
+        info.itemHeight = height;
+    }
+
+    /// Returns the size of the item
+    pub fn size(&self) -> [u32; 2] {
+        let info = unsafe { &*self.inner };
+        [info.itemWidth, info.itemHeight]
+    }
+
+}
+
+impl fmt::Debug for MeasureItemData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MeasureItemData {{ index: {:?}, size: {:?} }}", self.index(), self.size())
+    }
+}
+
+
+bitflags! {
+    /**
+        The state of an owner drawn control or item. See `DrawItemData::state`
+
+        * SELECTED: The item is selected, or the button is pushed
+        * FOCUS: The item or the button has the keyboard focus
+        * DISABLED: The control is disabled
+        * CHECKED: The item is checked
+        * DEFAULT: The button is the default button
+        * HOT: The item is under the mouse
+        * NO_ACCEL: The keyboard accelerators must be hidden
+        * NO_FOCUS_RECT: The focus rectangle must be hidden
+        * COMBOBOX_EDIT: The item is the selection field of a combo box
+    */
+    pub struct DrawItemState: u32 {
+        const SELECTED = 0x0001;
+        const DISABLED = 0x0004;
+        const CHECKED = 0x0008;
+        const FOCUS = 0x0010;
+        const DEFAULT = 0x0020;
+        const HOT = 0x0040;
+        const NO_ACCEL = 0x0100;
+        const NO_FOCUS_RECT = 0x0200;
+        const COMBOBOX_EDIT = 0x1000;
+    }
+}
+
+/// Why an owner drawn control or item must be painted. See `DrawItemData::action`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DrawItemAction {
+    /// The whole control or item must be painted
+    DrawEntire,
+
+    /// The selection state changed. Check `DrawItemState::SELECTED`
+    Select,
+
+    /// The focus state changed. Check `DrawItemState::FOCUS`
+    Focus,
+}
+
+
+/**
+    Wraps the device context of an owner drawn button, list box or combo box. Coordinates are in physical pixels.
+    The device context is only valid during the `OnDrawItem` event.
+
+    The device context is clipped to the control or item rectangle. With the `gdi-canvas` feature, `canvas`
+    returns a `Canvas` over the device context for the drawing functions that are not wrapped here.
+*/
+pub struct DrawItemData {
+    pub(crate) inner: *const winapi::um::winuser::DRAWITEMSTRUCT,
+}
+
+impl DrawItemData {
+
+    /// Returns the raw device context, for drawing operations not covered by this wrapper.
+    /// The device context state must be restored before the end of the event.
+    pub fn hdc(&self) -> winapi::shared::windef::HDC {
+        unsafe { (*self.inner).hDC }
+    }
+
+    /// Returns a canvas over the device context. The device context state is restored when the canvas is dropped.
+    /// Requires the `gdi-canvas` feature.
+    #[cfg(feature="gdi-canvas")]
+    pub fn canvas(&self) -> crate::Canvas<'_> {
+        unsafe { crate::Canvas::from_hdc(self.hdc()) }
+    }
+
+    /// Returns the rectangle of the control or item as `[left, top, right, bottom]`
+    pub fn rect(&self) -> [i32; 4] {
+        let r = unsafe { &(*self.inner).rcItem };
+        [r.left, r.top, r.right, r.bottom]
+    }
+
+    /// Returns the index of the item to paint.
+    /// `None` for buttons and for the empty list of a list box or combo box (only the focus rectangle must be drawn).
+    pub fn index(&self) -> Option<usize> {
+        use winapi::um::winuser::ODT_BUTTON;
+
+        let info = unsafe { &*self.inner };
+        match (info.CtlType, info.itemID) {
+            (ODT_BUTTON, _) | (_, u32::MAX) => None,
+            (_, i) => Some(i as usize)
+        }
+    }
+
+    /// Returns the state of the control or item
+    pub fn state(&self) -> DrawItemState {
+        DrawItemState::from_bits_truncate(unsafe { (*self.inner).itemState })
+    }
+
+    /// Returns why the control or item must be painted
+    pub fn action(&self) -> DrawItemAction {
+        use winapi::um::winuser::{ODA_SELECT, ODA_FOCUS};
+
+        match unsafe { (*self.inner).itemAction } {
+            ODA_SELECT => DrawItemAction::Select,
+            ODA_FOCUS => DrawItemAction::Focus,
+            _ => DrawItemAction::DrawEntire,
+        }
+    }
+
+    /// Returns the text of the item for list boxes and combo boxes, or the text of the button
+    pub fn text(&self) -> String {
+        use winapi::um::winuser::{ODT_LISTBOX, ODT_COMBOBOX, LB_GETTEXTLEN, LB_GETTEXT, CB_GETLBTEXTLEN, CB_GETLBTEXT};
+        use crate::win32::{window_helper as wh, base_helper::from_utf16};
+
+        let info = unsafe { &*self.inner };
+        let (get_len, get_text) = match info.CtlType {
+            ODT_LISTBOX => (LB_GETTEXTLEN, LB_GETTEXT),
+            ODT_COMBOBOX => (CB_GETLBTEXTLEN, CB_GETLBTEXT),
+            _ => { return unsafe { wh::get_window_text(info.hwndItem) }; }
+        };
+
+        let index = match self.index() {
+            Some(i) => i,
+            None => { return String::new(); }
+        };
+
+        let length = wh::send_message(info.hwndItem, get_len, index, 0);
+        if length < 0 {
+            return String::new();
+        }
+
+        let mut buffer: Vec<u16> = vec![0; length as usize + 1];
+        wh::send_message(info.hwndItem, get_text, index, buffer.as_mut_ptr() as _);
+
+        from_utf16(&buffer)
+    }
+
+    /// Fills the whole control or item rectangle with `color`
+    pub fn fill(&self, color: [u8; 3]) {
+        fill_rect(self.hdc(), self.rect(), color);
+    }
+
+    /// Draws a single line of text vertically centered in `rect` (`[left, top, right, bottom]`).
+    /// If `font` is `None`, the font of the control is used.
+    pub fn draw_text(&self, text: &str, rect: [i32; 4], color: [u8; 3], font: Option<&crate::Font>) {
+        draw_item_text(self.hdc(), self.state().bits(), text, rect, color, font);
+    }
+
+    /// Draws the focus rectangle over `rect` if the control or item has the keyboard focus and
+    /// the focus rectangle is not hidden. Drawing it a second time removes it.
+    pub fn draw_focus_rect(&self, rect: [i32; 4]) {
+        use winapi::um::winuser::DrawFocusRect;
+        use winapi::shared::windef::RECT;
+
+        let state = self.state();
+        if state.contains(DrawItemState::FOCUS) && !state.contains(DrawItemState::NO_FOCUS_RECT) {
+            let [left, top, right, bottom] = rect;
+            unsafe { DrawFocusRect(self.hdc(), &RECT { left, top, right, bottom }); }
+        }
+    }
+
+}
+
+impl fmt::Debug for DrawItemData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, 
+            "DrawItemData {{ rect: {:?}, index: {:?}, state: {:?}, action: {:?} }}",
+            self.rect(), self.index(), self.state(), self.action()
+        )
+    }
+}
+
+
+
+/// Sets the size of an owner drawn menu item. Sizes are in physical pixels.
+/// The height defaults to the height of a standard menu item.
+#[cfg(feature="menu")]
+pub struct MenuItemMeasureData {
+    pub(crate) inner: *mut winapi::um::winuser::MEASUREITEMSTRUCT,
+}
+
+#[cfg(feature="menu")]
+impl MenuItemMeasureData {
+
+    /// Sets the size of the menu item. The system adds the width of the check mark to `width`.
+    pub fn set_size(&self, width: u32, height: u32) {
+        let info = unsafe { &mut *self.inner };
+        info.itemWidth = width;
+        info.itemHeight = height;
+    }
+
+    /// Returns the size of the menu item
+    pub fn size(&self) -> [u32; 2] {
+        let info = unsafe { &*self.inner };
+        [info.itemWidth, info.itemHeight]
+    }
+
+}
+
+#[cfg(feature="menu")]
+impl fmt::Debug for MenuItemMeasureData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MenuItemMeasureData {{ size: {:?} }}", self.size())
+    }
+}
+
+
+/// Wraps the device context of an owner drawn menu item. Coordinates are in physical pixels.
+/// The device context is only valid during the `OnMenuItemDraw` event.
+#[cfg(feature="menu")]
+pub struct MenuItemDrawData {
+    pub(crate) inner: *const winapi::um::winuser::DRAWITEMSTRUCT,
+}
+
+#[cfg(feature="menu")]
+impl MenuItemDrawData {
+
+    /// Returns the raw device context, for drawing operations not covered by this wrapper.
+    /// The device context state must be restored before the end of the event.
+    pub fn hdc(&self) -> winapi::shared::windef::HDC {
+        unsafe { (*self.inner).hDC }
+    }
+
+    /// Returns the rectangle of the menu item as `[left, top, right, bottom]`
+    pub fn rect(&self) -> [i32; 4] {
+        let r = unsafe { &(*self.inner).rcItem };
+        [r.left, r.top, r.right, r.bottom]
+    }
+
+    /// Returns `true` if the menu item is highlighted (hovered or selected with the keyboard)
+    pub fn selected(&self) -> bool {
+        use winapi::um::winuser::ODS_SELECTED;
+        self.state() & ODS_SELECTED == ODS_SELECTED
+    }
+
+    /// Returns `true` if the menu item is disabled
+    pub fn disabled(&self) -> bool {
+        use winapi::um::winuser::{ODS_GRAYED, ODS_DISABLED};
+        self.state() & (ODS_GRAYED | ODS_DISABLED) != 0
+    }
+
+    /// Returns `true` if the menu item is checked
+    pub fn checked(&self) -> bool {
+        use winapi::um::winuser::ODS_CHECKED;
+        self.state() & ODS_CHECKED == ODS_CHECKED
+    }
+
+    /// Returns the text of the menu item
+    pub fn text(&self) -> String {
+        let info = unsafe { &*self.inner };
+        unsafe { crate::win32::menu::menu_item_text(info.hwndItem as _, info.itemID, false) }
+    }
+
+    /// Fills the whole menu item rectangle with `color`
+    pub fn fill(&self, color: [u8; 3]) {
+        self.fill_rect(self.rect(), color);
+    }
+
+    /// Fills `rect` (`[left, top, right, bottom]`) with `color`
+    pub fn fill_rect(&self, rect: [i32; 4], color: [u8; 3]) {
+        fill_rect(self.hdc(), rect, color);
+    }
+
+    /**
+        Draws a single line of text vertically centered in `rect` (`[left, top, right, bottom]`).
+        Tabs are expanded, so accelerator text such as `"Open\tCTRL+O"` is aligned.
+        If `font` is `None`, the menu font is used.
+    */
+    pub fn draw_text(&self, text: &str, rect: [i32; 4], color: [u8; 3], font: Option<&crate::Font>) {
+        draw_item_text(self.hdc(), self.state(), text, rect, color, font);
+    }
+
+    /// Draws an icon at `[x, y]`, scaled to `size`. The icon transparency is preserved.
+    pub fn draw_icon(&self, icon: &crate::Icon, pos: [i32; 2], size: [i32; 2]) {
+        use winapi::um::winuser::DrawIconEx;
+        use std::ptr;
+
+        // Missing from winapi
+        const DI_NORMAL: u32 = 0x0003;
+
+        unsafe {
+            DrawIconEx(self.hdc(), pos[0], pos[1], icon.handle as _, size[0], size[1], 0, ptr::null_mut(), DI_NORMAL);
+        }
+    }
+
+    fn state(&self) -> u32 {
+        unsafe { (*self.inner).itemState }
+    }
+
+}
+
+#[cfg(feature="menu")]
+impl fmt::Debug for MenuItemDrawData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, 
+            "MenuItemDrawData {{ rect: {:?}, selected: {:?}, disabled: {:?}, checked: {:?} }}",
+            self.rect(), self.selected(), self.disabled(), self.checked()
+        )
+    }
+}
+
+
+#[cfg(feature="drag-drop")]
+bitflags! {
+    /**
+        The effect of a drag and drop operation
+
+        * NONE: The data cannot be dropped
+        * COPY: The data is copied
+        * MOVE: The data is moved. The drag source removes the data after the drop.
+        * LINK: The target links to the data
+    */
+    pub struct DropEffect: u32 {
+        const NONE = 0;
+        const COPY = 1;
+        const MOVE = 2;
+        const LINK = 4;
+    }
+}
+
+/**
+    The data dragged over a control with a `DropTarget`. Only valid during the event.
+
+    The drop effect selected in `OnDragEnter` is kept for the next `OnDragOver` and `OnDrop` events.
+    The default effect is `DropEffect::NONE`, meaning that the control refuses the data.
+*/
+#[cfg(feature="drag-drop")]
+pub struct DragDropData {
+    pub(crate) inner: *mut crate::win32::drop_target::DropTargetData,
+}
+
+#[cfg(feature="drag-drop")]
+impl DragDropData {
+
+    /// Returns the position of the mouse, relative to the control
+    pub fn point(&self) -> [i32; 2] {
+        unsafe { (*self.inner).point }
+    }
+
+    /// Returns the state of the keyboard modifiers and the mouse buttons, as a combination of the winapi `MK_*` flags
+    pub fn key_state(&self) -> u32 {
+        unsafe { (*self.inner).keys }
+    }
+
+    /// Returns the effects allowed by the drag source
+    pub fn allowed_effects(&self) -> DropEffect {
+        DropEffect::from_bits_truncate(unsafe { (*self.inner).allowed })
+    }
+
+    /// Returns the selected drop effect
+    pub fn effect(&self) -> DropEffect {
+        DropEffect::from_bits_truncate(unsafe { (*self.inner).effect })
+    }
+
+    /// Sets the drop effect. The effect is limited to the effects allowed by the drag source.
+    pub fn set_effect(&self, effect: DropEffect) {
+        unsafe { (*self.inner).effect = effect.bits() & (*self.inner).allowed; }
+    }
+
+    /// Returns true if the dragged data is available in `fmt`
+    pub fn has_format(&self, fmt: crate::ClipboardFormat) -> bool {
+        unsafe { (*self.inner).has_format(fmt.into_raw()) }
+    }
+
+    /// Returns true if the dragged data contains text
+    pub fn has_text(&self) -> bool {
+        self.has_format(crate::ClipboardFormat::UnicodeText)
+    }
+
+    /// Returns true if the dragged data contains files
+    pub fn has_files(&self) -> bool {
+        self.has_format(crate::ClipboardFormat::Files)
+    }
+
+    /// Returns the dragged text. Returns `None` if the data does not contains text.
+    pub fn text(&self) -> Option<String> {
+        unsafe { (*self.inner).text() }
+    }
+
+    /// Returns the path of the dragged files. Returns an empty list if the data does not contains files.
+    pub fn files(&self) -> Vec<String> {
+        unsafe { (*self.inner).files() }
+    }
+
+    /// Returns a copy of the dragged data in `fmt`. The size of the data may be rounded up by the drag source.
+    pub fn data_bytes(&self, fmt: crate::ClipboardFormat) -> Option<Vec<u8>> {
+        unsafe { (*self.inner).bytes(fmt.into_raw()) }
+    }
+
+}
+
+#[cfg(feature="drag-drop")]
+impl fmt::Debug for DragDropData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DragDropData {{ point: {:?}, effect: {:?} }}", self.point(), self.effect())
+    }
+}
+
+
+/**
+    The composition string of the input method editor (IME), received by the `OnImeComposition` event.
+
+    The composition string is the text that the user is composing. It is displayed by the IME until the
+    user commits it. The committed text is available in `result` and is also sent to the control with the `OnChar` events.
+    To display the composition string inline, custom text controls should move the IME windows to the text cursor with `ControlHandle::set_ime_position`.
+*/
+#[cfg(feature="ime")]
+pub struct ImeCompositionData {
+    pub(crate) hwnd: HWND,
+    pub(crate) flags: u32,
+}
+
+#[cfg(feature="ime")]
+impl ImeCompositionData {
+
+    /// Returns the text being composed. Returns `None` if the composition string did not change.
+    pub fn composition(&self) -> Option<String> {
+        use crate::win32::ime::{composition_string, GCS_COMPSTR};
+
+        match self.flags & GCS_COMPSTR == GCS_COMPSTR {
+            true => unsafe { composition_string(self.hwnd, GCS_COMPSTR) },
+            false => None
+        }
+    }
+
+    /// Returns the text committed by the user. Returns `None` if the user did not commit text.
+    pub fn result(&self) -> Option<String> {
+        use crate::win32::ime::{composition_string, GCS_RESULTSTR};
+
+        match self.flags & GCS_RESULTSTR == GCS_RESULTSTR {
+            true => unsafe { composition_string(self.hwnd, GCS_RESULTSTR) },
+            false => None
+        }
+    }
+
+    /// Returns the position of the cursor in the composition string, in utf16 units.
+    /// Returns `None` if the cursor position did not change.
+    pub fn cursor(&self) -> Option<usize> {
+        use crate::win32::ime::{composition_cursor, GCS_CURSORPOS};
+
+        match self.flags & GCS_CURSORPOS == GCS_CURSORPOS {
+            true => unsafe { composition_cursor(self.hwnd) },
+            false => None
+        }
+    }
+
+}
+
+#[cfg(feature="ime")]
+impl fmt::Debug for ImeCompositionData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ImeCompositionData {{ composition: {:?}, result: {:?} }}", self.composition(), self.result())
+    }
+}
+
+
+/// The device that generated a pointer event
+#[cfg(feature="pointer-input")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PointerType {
+    Generic,
+    Touch,
+    Pen,
+    Mouse,
+    Touchpad,
+}
+
+/**
+    The state of a pointer, sent with the `OnPointerDown`, `OnPointerUpdate` and `OnPointerUp` events.
+
+    The pen and the touch screens send pointer events. The mouse does not send pointer events unless the
+    application calls `EnableMouseInPointer`. The pointer events do not replace the mouse events: a pen still
+    sends `OnMousePress` and `OnMouseMove` so that the controls that do not handle the pointer events keep working.
+
+    Requires Windows 8. On older systems, the pointer events are never sent.
+*/
+#[cfg(feature="pointer-input")]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PointerData {
+    /// Identifier of the pointer. A finger keeps the same identifier until it leaves the screen.
+    pub id: u32,
+
+    /// The device of the pointer
+    pub pointer_type: PointerType,
+
+    /// The position of the pointer, relative to the control
+    pub position: [i32; 2],
+
+    /// True if the pen or the finger touches the screen
+    pub in_contact: bool,
+
+    /// True if this is the first finger that touched the screen
+    pub primary: bool,
+
+    /// The pressure of the pen or of the finger, from `0.0` to `1.0`. `None` if the device does not report the pressure.
+    pub pressure: Option<f32>,
+
+    /// The tilt of the pen as `[x, y]`, in degrees from `-90` to `90`. `None` if the pen does not report the tilt.
+    pub tilt: Option<[i32; 2]>,
+
+    /// The clockwise rotation of the pen, in degrees from `0` to `359`. `None` if the pen does not report the rotation.
+    pub rotation: Option<u32>,
+
+    /// True if the barrel button of the pen is pressed
+    pub barrel: bool,
+
+    /// True if the pen is inverted
+    pub inverted: bool,
+
+    /// True if the eraser of the pen is used
+    pub eraser: bool,
+}
@@ -85,6 +85,9 @@ A GridLayouts has the following properties:
 * max_column - Number of columns - (default: None),
 * max_row - Number of rows - (default: None),
 
+If the parent is mirrored for right-to-left languages (see `ControlHandle::set_rtl_layout`), the first column
+is on the right side of the parent and the left margin is applied on the right side.
+
 ```rust
     use native_windows_gui as nwg;
     fn layout(layout: &nwg::GridLayout, window: &nwg::Window, item1: &nwg::Button, item2: &nwg::Button) {
@@ -24,11 +24,14 @@ pub use common_types::*;
 
 pub(crate) mod win32;
 pub use win32::{
- dispatch_thread_events, dispatch_thread_events_with_callback, stop_thread_dispatch, enable_visual_styles, init_common_controls, 
+ dispatch_thread_events, dispatch_thread_events_with_callback, stop_thread_dispatch, enable_visual_styles, init_common_controls,
+ init_common_controls_with, ControlClasses,
+ set_process_default_layout, process_default_layout,
  window::{
      EventHandler, RawEventHandler,
      full_bind_event_handler, bind_event_handler, unbind_event_handler,
-     bind_raw_event_handler, has_raw_handler, unbind_raw_event_handler
+     bind_raw_event_handler, has_raw_handler, unbind_raw_event_handler,
+     set_event_panic_handler, clear_event_panic_handler
  },
  message_box::*
 };
@@ -38,7 +41,17 @@ pub(crate) use win32::window::bind_raw_event_handler_inner;
 #[allow(deprecated)]
 pub use win32::high_dpi::{set_dpi_awareness, scale_factor, dpi};
 
-pub use win32::monitor::Monitor;
+pub use win32::monitor::{Monitor, Screen};
+
+pub use win32::system_metrics::SystemMetrics;
+
+pub use win32::keyboard::{Keyboard, KeyModifiers, Key};
+
+pub use win32::os_info::{OsInfo, os_info};
+
+pub use win32::focus::{set_tab_order, focus_next, focus_previous};
+
+pub use win32::high_contrast::{SystemColor, high_contrast, high_contrast_scheme, system_color};
 
 #[cfg(feature="cursor")]
 pub use win32::cursor::GlobalCursor;
@@ -46,18 +59,70 @@ pub use win32::cursor::GlobalCursor;
 #[cfg(feature="clipboard")]
 pub use win32::clipboard::{Clipboard, ClipboardFormat, ClipboardData};
 
-mod resources;
-pub use resources::*;
+#[cfg(feature="drag-drop")]
+pub use win32::drag_source::{DragData, begin_drag};
+
+#[cfg(feature="shell")]
+pub use win32::shell::{open_url, open_file_with_default_app, reveal_in_explorer};
+
+#[cfg(feature="accessibility")]
+pub use win32::accessibility::AccessibleRole;
 
+#[cfg(feature="accessibility")]
+pub use win32::announce::{AnnouncePriority, announce};
+
+#[cfg(feature="single-instance")]
+pub use win32::single_instance::SingleInstance;
+
+#[cfg(feature="recovery")]
+pub use win32::recovery::{RestartFlags, register_application_restart, unregister_application_restart, register_application_recovery, unregister_application_recovery};
+
+#[cfg(feature="settings")]
+pub use win32::settings::Settings;
+
+#[macro_use]
 mod controls;
 pub use controls::*;
 
+mod resources;
+pub use resources::*;
+
 mod layouts;
 pub use layouts::*;
 
+#[cfg(feature = "gdi-canvas")]
+mod canvas;
+
+#[cfg(feature = "gdi-canvas")]
+pub use canvas::Canvas;
+
 #[cfg(feature = "winnls")]
 mod winnls;
 
+#[cfg(feature = "loader")]
+pub mod loader;
+
+#[cfg(feature = "loader")]
+pub use loader::{LoadedUi, LoadedControl};
+
+#[cfg(feature = "binding")]
+mod binding;
+
+#[cfg(feature = "binding")]
+pub use binding::{Observable, Binding};
+
+#[cfg(feature = "app")]
+pub mod app;
+
+#[cfg(feature = "app")]
+pub use app::App;
+
+#[cfg(feature = "style")]
+mod style;
+
+#[cfg(feature = "style")]
+pub use style::{Style, StyleBuilder};
+
 #[cfg(feature = "winnls")]
 pub use winnls::*;
 
@@ -129,3 +194,23 @@ pub fn init() -> std::result::Result<(), errors::NwgError> {
     
     init_common_controls()
 }
+
+/// Same as `init`, but only the common control classes in `classes` are registered at initialization.
+/// The other classes are registered when the first control using them is created.
+///
+/// Example: `nwg::init_with(nwg::ControlClasses::STANDARD)`
+pub fn init_with(classes: ControlClasses) -> std::result::Result<(), errors::NwgError> {
+    if cfg!(not(feature="no-styling")) {
+        enable_visual_styles();
+    }
+
+    init_common_controls_with(classes)
+}
+
+/// Same as `init`, but the message font of the system (Segoe UI on Windows Vista and later) is set as the
+/// default font of the controls. See `Font::set_global_system_font`.
+pub fn init_with_system_font() -> std::result::Result<(), errors::NwgError> {
+    init()?;
+    Font::set_global_system_font()?;
+    Ok(())
+}
@@ -0,0 +1,409 @@
+/*!
+    Build a GUI at runtime from a declarative description. Requires the `loader` feature.
+
+    The description is a TOML document. Each `[[control]]` entry creates a control, in the order of the document.
+    A control must be declared after its parent. Each `[[layout]]` entry creates a layout over the controls.
+
+    ```toml
+    [[control]]
+    name = "window"
+    type = "Window"
+    title = "Hello"
+    size = [300, 115]
+    flags = ["WINDOW", "VISIBLE"]
+
+    [[control]]
+    name = "name_edit"
+    type = "TextInput"
+    parent = "window"
+    text = "Heisenberg"
+
+    [[control]]
+    name = "hello_button"
+    type = "Button"
+    parent = "window"
+    text = "Say my name"
+
+    [[layout]]
+    type = "GridLayout"
+    parent = "window"
+    spacing = 1
+    children = [
+        { control = "name_edit", col = 0, row = 0 },
+        { control = "hello_button", col = 0, row = 1, row_span = 2 },
+    ]
+    ```
+
+    Supported controls: `Window`, `Button`, `Label`, `TextInput`, `CheckBox` and `RadioButton`.
+
+    Supported properties:
+      * All controls: `name` (required), `type` (required), `parent` (required, except for windows), `text`, `size`, `position`, `enabled`
+      * Window: `title`, `flags` (the names of the `WindowFlags` values), `center`
+      * TextInput: `placeholder`, `readonly`
+      * CheckBox and RadioButton: `checked`
+
+    The controls are stored in a `LoadedUi` and found by name. Because the whole GUI can be destroyed and loaded again,
+    this can be used to reload a GUI while the application is running.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn load(path: &str) -> Result<(nwg::LoadedUi, nwg::EventHandler), nwg::NwgError> {
+        let ui = nwg::loader::load_file(path)?;
+        let window = ui.handle("window").unwrap();
+
+        let handler = nwg::full_bind_event_handler(&window, |evt, _evt_data, _handle| {
+            if evt == nwg::Event::OnWindowClose {
+                nwg::stop_thread_dispatch();
+            }
+        });
+
+        Ok((ui, handler))
+    }
+    ```
+*/
+use toml::Value;
+use std::path::Path;
+use crate::{NwgError, ControlHandle, Window, WindowFlags, Button, Label, TextInput, CheckBox, CheckBoxState,
+    RadioButton, RadioButtonState, GridLayout, GridLayoutItem};
+
+
+/// A control created by the loader
+pub enum LoadedControl {
+    Window(Window),
+    Button(Button),
+    Label(Label),
+    TextInput(TextInput),
+    CheckBox(CheckBox),
+    RadioButton(RadioButton),
+}
+
+impl LoadedControl {
+
+    /// Return the handle of the control
+    pub fn handle(&self) -> ControlHandle {
+        match self {
+            LoadedControl::Window(c) => c.handle,
+            LoadedControl::Button(c) => c.handle,
+            LoadedControl::Label(c) => c.handle,
+            LoadedControl::TextInput(c) => c.handle,
+            LoadedControl::CheckBox(c) => c.handle,
+            LoadedControl::RadioButton(c) => c.handle,
+        }
+    }
+
+    /// Enable or disable the control
+    pub fn set_enabled(&self, v: bool) {
+        match self {
+            LoadedControl::Window(c) => c.set_enabled(v),
+            LoadedControl::Button(c) => c.set_enabled(v),
+            LoadedControl::Label(c) => c.set_enabled(v),
+            LoadedControl::TextInput(c) => c.set_enabled(v),
+            LoadedControl::CheckBox(c) => c.set_enabled(v),
+            LoadedControl::RadioButton(c) => c.set_enabled(v),
+        }
+    }
+
+}
+
+/**
+    A GUI built by `load_str` or `load_file`. Dropping it destroys the controls and the layouts.
+*/
+pub struct LoadedUi {
+    controls: Vec<(String, LoadedControl)>,
+    layouts: Vec<GridLayout>,
+}
+
+impl LoadedUi {
+
+    /// Return the control named `name`
+    pub fn control(&self, name: &str) -> Option<&LoadedControl> {
+        self.controls.iter().find(|(n, _)| n == name).map(|(_, c)| c)
+    }
+
+    /// Return the handle of the control named `name`
+    pub fn handle(&self, name: &str) -> Option<ControlHandle> {
+        self.control(name).map(|c| c.handle())
+    }
+
+    /// Return the name of the control with the handle `handle`. Useful to dispatch the events by name.
+    pub fn name_of(&self, handle: ControlHandle) -> Option<&str> {
+        self.controls.iter().find(|(_, c)| c.handle() == handle).map(|(n, _)| n as &str)
+    }
+
+    /// Return the names of the controls, in the order they were created
+    pub fn names(&self) -> impl Iterator<Item=&str> {
+        self.controls.iter().map(|(n, _)| n as &str)
+    }
+
+    /// Return the layouts, in the order they were created
+    pub fn layouts(&self) -> &[GridLayout] {
+        &self.layouts
+    }
+
+}
+
+impl Drop for LoadedUi {
+    fn drop(&mut self) {
+        self.layouts.clear();
+
+        // Destroy the children before their parent
+        while let Some(control) = self.controls.pop() {
+            drop(control);
+        }
+    }
+}
+
+/**
+    Build the GUI described by `source`. See the module documentation for the format.
+*/
+pub fn load_str(source: &str) -> Result<LoadedUi, NwgError> {
+    let document: Value = source.parse()
+        .map_err(|e| NwgError::loader(format!("Invalid document: {}", e)))?;
+
+    let mut ui = LoadedUi { controls: Vec::new(), layouts: Vec::new() };
+
+    for item in entries(&document, "control")? {
+        let name = str_value(item, "name")?
+            .ok_or_else(|| NwgError::loader("A control does not have a name"))?;
+
+        if ui.control(name).is_some() {
+            return Err(NwgError::loader(format!("The control name {:?} is used more than once", name)));
+        }
+
+        let control = build_control(&ui, name, item)?;
+        ui.controls.push((name.to_string(), control));
+    }
+
+    for item in entries(&document, "layout")? {
+        let layout = build_layout(&ui, item)?;
+        ui.layouts.push(layout);
+    }
+
+    Ok(ui)
+}
+
+/**
+    Read the file at `path` and build the GUI it describes. See `load_str`.
+*/
+pub fn load_file<P: AsRef<Path>>(path: P) -> Result<LoadedUi, NwgError> {
+    let path = path.as_ref();
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| NwgError::loader(format!("Failed to read {:?}: {}", path, e)))?;
+
+    load_str(&source)
+}
+
+fn build_control(ui: &LoadedUi, name: &str, item: &Value) -> Result<LoadedControl, NwgError> {
+    let control_type = str_value(item, "type")?
+        .ok_or_else(|| NwgError::loader(format!("The control {:?} does not have a type", name)))?;
+
+    let parent = match str_value(item, "parent")? {
+        Some(parent) => Some(ui.handle(parent).ok_or_else(|| NwgError::loader(format!("The parent of {:?} must be declared before it", name)))?),
+        None => None
+    };
+
+    let required_parent = || parent.ok_or_else(|| NwgError::loader(format!("The control {:?} does not have a parent", name)));
+
+    let text = str_value(item, "text")?.unwrap_or("");
+    let size = pair_value(item, "size")?;
+    let position = pair_value(item, "position")?;
+
+    let control = match control_type {
+        "Window" => {
+            let mut window = Window::default();
+            let mut builder = Window::builder()
+                .title(str_value(item, "title")?.unwrap_or(text))
+                .center(bool_value(item, "center")?.unwrap_or(false))
+                .parent(parent);
+
+            if let Some(size) = size { builder = builder.size(size); }
+            if let Some(position) = position { builder = builder.position(position); }
+            if let Some(flags) = window_flags(item)? { builder = builder.flags(flags); }
+
+            builder.build(&mut window)?;
+            LoadedControl::Window(window)
+        },
+        "Button" => {
+            let mut button = Button::default();
+            let mut builder = Button::builder().text(text).parent(required_parent()?);
+            if let Some(size) = size { builder = builder.size(size); }
+            if let Some(position) = position { builder = builder.position(position); }
+
+            builder.build(&mut button)?;
+            LoadedControl::Button(button)
+        },
+        "Label" => {
+            let mut label = Label::default();
+            let mut builder = Label::builder().text(text).parent(required_parent()?);
+            if let Some(size) = size { builder = builder.size(size); }
+            if let Some(position) = position { builder = builder.position(position); }
+
+            builder.build(&mut label)?;
+            LoadedControl::Label(label)
+        },
+        "TextInput" => {
+            let mut input = TextInput::default();
+            let mut builder = TextInput::builder()
+                .text(text)
+                .placeholder_text(str_value(item, "placeholder")?)
+                .readonly(bool_value(item, "readonly")?.unwrap_or(false))
+                .parent(required_parent()?);
+
+            if let Some(size) = size { builder = builder.size(size); }
+            if let Some(position) = position { builder = builder.position(position); }
+
+            builder.build(&mut input)?;
+            LoadedControl::TextInput(input)
+        },
+        "CheckBox" => {
+            let state = match bool_value(item, "checked")?.unwrap_or(false) {
+                true => CheckBoxState::Checked,
+                false => CheckBoxState::Unchecked
+            };
+
+            let mut check = CheckBox::default();
+            let mut builder = CheckBox::builder().text(text).check_state(state).parent(required_parent()?);
+            if let Some(size) = size { builder = builder.size(size); }
+            if let Some(position) = position { builder = builder.position(position); }
+
+            builder.build(&mut check)?;
+            LoadedControl::CheckBox(check)
+        },
+        "RadioButton" => {
+            let state = match bool_value(item, "checked")?.unwrap_or(false) {
+                true => RadioButtonState::Checked,
+                false => RadioButtonState::Unchecked
+            };
+
+            let mut radio = RadioButton::default();
+            let mut builder = RadioButton::builder().text(text).check_state(state).parent(required_parent()?);
+            if let Some(size) = size { builder = builder.size(size); }
+            if let Some(position) = position { builder = builder.position(position); }
+
+            builder.build(&mut radio)?;
+            LoadedControl::RadioButton(radio)
+        },
+        other => {
+            return Err(NwgError::loader(format!("The control {:?} has an unknown type: {:?}", name, other)));
+        }
+    };
+
+    if let Some(enabled) = bool_value(item, "enabled")? {
+        control.set_enabled(enabled);
+    }
+
+    Ok(control)
+}
+
+fn build_layout(ui: &LoadedUi, item: &Value) -> Result<GridLayout, NwgError> {
+    match str_value(item, "type")? {
+        Some("GridLayout") => {},
+        Some(other) => { return Err(NwgError::loader(format!("Unknown layout type: {:?}", other))); },
+        None => { return Err(NwgError::loader("A layout does not have a type")); }
+    }
+
+    let parent = str_value(item, "parent")?
+        .ok_or_else(|| NwgError::loader("A layout does not have a parent"))?;
+
+    let parent = ui.handle(parent)
+        .ok_or_else(|| NwgError::loader(format!("The layout parent {:?} does not exist", parent)))?;
+
+    let mut builder = GridLayout::builder().parent(parent);
+
+    if let Some(spacing) = int_value(item, "spacing")? {
+        builder = builder.spacing(spacing as u32);
+    }
+
+    if let Some(margin) = item.get("margin") {
+        let margin = int_array(margin, 4).ok_or_else(|| NwgError::loader("The layout margin must be 4 integers"))?;
+        builder = builder.margin([margin[0] as u32, margin[1] as u32, margin[2] as u32, margin[3] as u32]);
+    }
+
+    for child in entries(item, "children")? {
+        let name = str_value(child, "control")?
+            .ok_or_else(|| NwgError::loader("A layout child does not have a control"))?;
+
+        let handle = ui.handle(name)
+            .ok_or_else(|| NwgError::loader(format!("The layout child {:?} does not exist", name)))?;
+
+        let int = |key: &str, default: i64| int_value(child, key).map(|v| v.unwrap_or(default) as u32);
+        let item = GridLayoutItem::new(handle, int("col", 0)?, int("row", 0)?, int("col_span", 1)?, int("row_span", 1)?);
+        builder = builder.child_item(item);
+    }
+
+    let layout = GridLayout::default();
+    builder.build(&layout)?;
+
+    Ok(layout)
+}
+
+fn window_flags(item: &Value) -> Result<Option<WindowFlags>, NwgError> {
+    let names = match item.get("flags") {
+        Some(Value::Array(names)) => names,
+        Some(_) => { return Err(NwgError::loader("The window flags must be an array of flag names")); },
+        None => { return Ok(None); }
+    };
+
+    let mut flags = WindowFlags::empty();
+    for name in names {
+        flags |= match name.as_str() {
+            Some("MAIN_WINDOW") => WindowFlags::MAIN_WINDOW,
+            Some("WINDOW") => WindowFlags::WINDOW,
+            Some("MINIMIZE_BOX") => WindowFlags::MINIMIZE_BOX,
+            Some("MAXIMIZE_BOX") => WindowFlags::MAXIMIZE_BOX,
+            Some("SYS_MENU") => WindowFlags::SYS_MENU,
+            Some("VISIBLE") => WindowFlags::VISIBLE,
+            Some("DISABLED") => WindowFlags::DISABLED,
+            Some("MAXIMIZED") => WindowFlags::MAXIMIZED,
+            Some("MINIMIZED") => WindowFlags::MINIMIZED,
+            Some("RESIZABLE") => WindowFlags::RESIZABLE,
+            Some("POPUP") => WindowFlags::POPUP,
+            _ => { return Err(NwgError::loader(format!("Unknown window flag: {}", name))); }
+        };
+    }
+
+    Ok(Some(flags))
+}
+
+/// Return the tables in the array `key` of `item`
+fn entries<'a>(item: &'a Value, key: &str) -> Result<Vec<&'a Value>, NwgError> {
+    match item.get(key) {
+        Some(Value::Array(values)) if values.iter().all(|v| v.is_table()) => Ok(values.iter().collect()),
+        Some(_) => Err(NwgError::loader(format!("{:?} must be an array of tables", key))),
+        None => Ok(Vec::new())
+    }
+}
+
+fn str_value<'a>(item: &'a Value, key: &str) -> Result<Option<&'a str>, NwgError> {
+    match item.get(key) {
+        Some(v) => v.as_str().map(Some).ok_or_else(|| NwgError::loader(format!("{:?} must be a string", key))),
+        None => Ok(None)
+    }
+}
+
+fn bool_value(item: &Value, key: &str) -> Result<Option<bool>, NwgError> {
+    match item.get(key) {
+        Some(v) => v.as_bool().map(Some).ok_or_else(|| NwgError::loader(format!("{:?} must be a boolean", key))),
+        None => Ok(None)
+    }
+}
+
+fn int_value(item: &Value, key: &str) -> Result<Option<i64>, NwgError> {
+    match item.get(key) {
+        Some(v) => v.as_integer().filter(|&i| i >= 0).map(Some).ok_or_else(|| NwgError::loader(format!("{:?} must be a positive integer", key))),
+        None => Ok(None)
+    }
+}
+
+fn pair_value(item: &Value, key: &str) -> Result<Option<(i32, i32)>, NwgError> {
+    match item.get(key) {
+        Some(v) => int_array(v, 2).map(|v| Some((v[0] as i32, v[1] as i32))).ok_or_else(|| NwgError::loader(format!("{:?} must be 2 integers", key))),
+        None => Ok(None)
+    }
+}
+
+fn int_array(value: &Value, len: usize) -> Option<Vec<i64>> {
+    let values = value.as_array().filter(|v| v.len() == len)?;
+    values.iter().map(|v| v.as_integer()).collect()
+}
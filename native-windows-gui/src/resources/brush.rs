@@ -0,0 +1,136 @@
+use winapi::shared::windef::HBRUSH;
+use crate::win32::resources_helper as rh;
+use crate::NwgError;
+use std::ptr;
+
+
+/**
+    The pattern of a hatched brush
+*/
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum BrushHatch {
+    /// `-----`
+    Horizontal,
+    /// `|||||`
+    Vertical,
+    /// `\\\\\`
+    ForwardDiagonal,
+    /// `/////`
+    BackwardDiagonal,
+    /// `+++++`
+    Cross,
+    /// `xxxxx`
+    DiagonalCross,
+}
+
+/**
+    A GDI brush. Brushes are used by a `Canvas` to fill shapes.
+
+    Requires the `gdi-canvas` feature.
+
+    **Builder parameters:**
+      * `color`: The color of the brush. Defaults to black.
+      * `hatch`: Fill with a pattern of lines instead of a solid color. The space between the lines is filled with the canvas background color.
+
+    Example:
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn build_brush() -> nwg::Brush {
+        let mut brush = nwg::Brush::default();
+
+        nwg::Brush::builder()
+            .color([255, 255, 0])
+            .build(&mut brush)
+            .unwrap();
+
+        brush
+    }
+    ```
+*/
+#[derive(PartialEq, Eq, Debug)]
+pub struct Brush {
+    pub handle: HBRUSH
+}
+
+impl Brush {
+
+    pub fn builder() -> BrushBuilder {
+        BrushBuilder {
+            color: [0, 0, 0],
+            hatch: None,
+        }
+    }
+
+}
+
+impl Default for Brush {
+
+    fn default() -> Brush {
+        Brush { handle: ptr::null_mut() }
+    }
+
+}
+
+impl Drop for Brush {
+
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            rh::destroy_obj(self.handle as _);
+        }
+    }
+
+}
+
+pub struct BrushBuilder {
+    color: [u8; 3],
+    hatch: Option<BrushHatch>,
+}
+
+impl BrushBuilder {
+
+    pub fn color(mut self, color: [u8; 3]) -> BrushBuilder {
+        self.color = color;
+        self
+    }
+
+    pub fn hatch(mut self, hatch: Option<BrushHatch>) -> BrushBuilder {
+        self.hatch = hatch;
+        self
+    }
+
+    pub fn build(self, brush: &mut Brush) -> Result<(), NwgError> {
+        use winapi::um::wingdi::{CreateSolidBrush, CreateHatchBrush, RGB, HS_HORIZONTAL, HS_VERTICAL, HS_FDIAGONAL, HS_BDIAGONAL, HS_CROSS, HS_DIAGCROSS};
+
+        let [r, g, b] = self.color;
+        let color = RGB(r, g, b);
+
+        let handle = unsafe {
+            match self.hatch {
+                None => CreateSolidBrush(color),
+                Some(hatch) => {
+                    let hatch = match hatch {
+                        BrushHatch::Horizontal => HS_HORIZONTAL,
+                        BrushHatch::Vertical => HS_VERTICAL,
+                        BrushHatch::ForwardDiagonal => HS_FDIAGONAL,
+                        BrushHatch::BackwardDiagonal => HS_BDIAGONAL,
+                        BrushHatch::Cross => HS_CROSS,
+                        BrushHatch::DiagonalCross => HS_DIAGCROSS,
+                    };
+
+                    CreateHatchBrush(hatch as _, color)
+                }
+            }
+        };
+
+        if handle.is_null() {
+            return Err(NwgError::resource_create("Failed to create the brush"));
+        }
+
+        *brush = Brush { handle };
+
+        Ok(())
+    }
+
+}
@@ -212,8 +212,11 @@ impl EmbedResource {
                 let handle = unsafe { rh::build_image_decoder_from_memory(src, size) };
                 match handle {
                     Ok(handle) => Some(Bitmap { handle, owned: true }),
+                    #[cfg_attr(not(feature = "log"), allow(unused_variables))]
                     Err(e) => {
-                        println!("{:?}", e);
+                        #[cfg(feature = "log")]
+                        log::error!("{}", e);
+
                         None
                     }
                 }
@@ -115,6 +115,20 @@ impl Font {
         Ok(Font::set_global_default(Some(font)))
     }
 
+    /// Set the message font of the system (Segoe UI on Windows Vista and later) as the default (application global!) font.
+    /// This is a shortcut over `Font::set_global_default`
+    pub fn set_global_system_font() -> Result<Option<Font>, NwgError> {
+        let font = Font::system_message_font()?;
+        Ok(Font::set_global_default(Some(font)))
+    }
+
+    /// Create a copy of the message font of the system, the font used by the message boxes.
+    /// The family and the size of the font follow the settings of the user (Segoe UI 9pt by default).
+    pub fn system_message_font() -> Result<Font, NwgError> {
+        let handle = unsafe { rh::build_message_font()? };
+        Ok(Font { handle })
+    }
+
     /// Return the default font that was previously set using `Font::set_default`
     pub fn global_default() -> Option<Font> {
         DEFAULT_FONT.lock()
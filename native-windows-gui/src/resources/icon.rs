@@ -17,6 +17,8 @@ different sources (including ".ico" files).
 
 To display a icon in an application, see the `ImageFrame` control.
 
+Icons can be converted to 32 bits bitmaps using the "copy_as_bitmap" function.
+
 Note: Loading an icon from binary source (source_bin) REQUIRES the "image-decoder" feature.
 
 **Builder parameters:**
@@ -146,6 +148,27 @@ impl Icon {
         Ok(icon)
     }
 
+    /**
+        Creates a new 32 bits ARGB bitmap from the icon data. The transparency of the icon is preserved.
+        The bitmap has the same size as the icon.
+
+        This is the format expected by controls that can only display bitmaps, such as `MenuItem::set_bitmap`.
+
+        Panics if the icon is not initialized
+    */
+    pub fn copy_as_bitmap(&self) -> Result<crate::Bitmap, NwgError> {
+        if self.handle.is_null() {
+            panic!("Icon was not initialized");
+        }
+
+        let bitmap = unsafe { rh::icon_to_argb_bitmap(self.handle)? };
+
+        Ok(crate::Bitmap {
+            handle: bitmap,
+            owned: true
+        })
+    }
+
 }
 
 pub struct IconBuilder<'a> {
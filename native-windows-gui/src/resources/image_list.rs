@@ -46,53 +46,121 @@ impl ImageList {
         }
     }
 
-    /// Returns the size of the images in the image list
-    pub fn size(&self) -> (i32, i32) {
-        use winapi::um::commctrl::ImageList_GetIconSize;
+    try_accessors! {
+        errors = "Returns an `InvalidHandle` error if the image list is not bound.";
 
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
+        /// Returns the size of the images in the image list
+        pub fn size(&self) -> (i32, i32) => try_size {
+            use winapi::um::commctrl::ImageList_GetIconSize;
 
-        let mut size = (0, 0);
-        unsafe { ImageList_GetIconSize(self.handle, &mut size.0, &mut size.1); }
+            if self.handle.is_null() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
 
-        size
-    }
+            let mut size = (0, 0);
+            unsafe { ImageList_GetIconSize(self.handle, &mut size.0, &mut size.1); }
+
+            Ok(size)
+        }
 
-    /// Sets the size of the image list. This clears all current image data.
-    pub fn set_size(&self, size: (i32, i32)) {
-        use winapi::um::commctrl::ImageList_SetIconSize;
+        /// Sets the size of the image list. This clears all current image data.
+        pub fn set_size(&self, size: (i32, i32)) => try_set_size {
+            use winapi::um::commctrl::ImageList_SetIconSize;
 
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
+            if self.handle.is_null() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
 
-        let (w, h) = size;
-        unsafe { ImageList_SetIconSize(self.handle, w, h); }
-    }
+            let (w, h) = size;
+            unsafe { ImageList_SetIconSize(self.handle, w, h); }
 
-    /// Returns the number of images in the image list
-    pub fn len(&self) -> u32 {
-        use winapi::um::commctrl::ImageList_GetImageCount;
+            Ok(())
+        }
 
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
+        /// Returns the number of images in the image list
+        pub fn len(&self) -> u32 => try_len {
+            use winapi::um::commctrl::ImageList_GetImageCount;
 
-        unsafe { ImageList_GetImageCount(self.handle) as u32 }
-    }
+            if self.handle.is_null() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+
+            Ok(unsafe { ImageList_GetImageCount(self.handle) as u32 })
+        }
+
+        /// Adds a new bitmap to the image list. Returns the index to the image. Panics if the bitmap was not initialized
+        pub fn add_bitmap(&self, bitmap: &Bitmap) -> i32 => try_add_bitmap {
+            if self.handle.is_null() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            if bitmap.handle.is_null() { return Err(NwgError::invalid_handle("Bitmap was not initialized")); }
+
+            Ok(unsafe { ImageList_AddMasked(self.handle, bitmap.handle as HBITMAP, 0) })
+        }
+
+        /// Adds a new icon to the image list. Returns the index to the image. Panics if the icon was not initialized
+        pub fn add_icon(&self, icon: &Icon) -> i32 => try_add_icon {
+            use winapi::um::winuser::{GetIconInfo, ICONINFO};
+            use winapi::um::wingdi::DeleteObject;
+
+            if self.handle.is_null() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            if icon.handle.is_null() { return Err(NwgError::invalid_handle("Icon was not initialized")); }
+
+            // Extract the bitmap from the icon
+            // Can't use `ImageList_AddIcon` because it doesn't always guess the mask
+            unsafe {
+                let mut info: ICONINFO = ::std::mem::zeroed();
+                GetIconInfo(icon.handle as _, &mut info);
+                
+                let i = ImageList_AddMasked(self.handle, info.hbmColor, 0);
+
+                DeleteObject(info.hbmMask as _);
+                DeleteObject(info.hbmColor as _);
+
+                Ok(i)
+            }
+        }
+
+        /**
+            Removes the image at the specified index
+
+            When an image is removed, the indexes of the remaining images are adjusted so that the image indexes
+            always range from zero to one less than the number of images in the image list. For example, if you
+            remove the image at index 0, then image 1 becomes image 0, image 2 becomes image 1, and so on.
+        */
+        pub fn remove(&self, index: i32) => try_remove {
+            use winapi::um::commctrl::ImageList_Remove;
+
+            if self.handle.is_null() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+
+            unsafe { ImageList_Remove(self.handle, index); }
+
+            Ok(())
+        }
+
+        /// Replaces an image in the image list. Panics if the bitmap was not initialized
+        pub fn replace_bitmap(&self, index: i32, bitmap: &Bitmap) => try_replace_bitmap {
+            use winapi::um::commctrl::ImageList_Replace;
+
+            if self.handle.is_null() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            if bitmap.handle.is_null() { return Err(NwgError::invalid_handle("Bitmap was not initialized")); }
+            
+            unsafe { ImageList_Replace(self.handle, index, bitmap.handle as HBITMAP, ptr::null_mut()); }
+
+            Ok(())
+        }
 
-    /// Adds a new bitmap to the image list. Returns the index to the image. Panics if the bitmap was not initialized
-    pub fn add_bitmap(&self, bitmap: &Bitmap) -> i32 {
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
-        if bitmap.handle.is_null() { panic!("Bitmap was not initialized"); }
+        /// Replaces an image in the image list by an icon. Panics if the icon was not initialized
+        pub fn replace_icon(&self, index: i32, icon: &Icon) => try_replace_icon {
+            use winapi::um::commctrl::ImageList_ReplaceIcon;
 
-        unsafe { ImageList_AddMasked(self.handle, bitmap.handle as HBITMAP, 0) }
+            if self.handle.is_null() { return Err(NwgError::invalid_handle(NOT_BOUND)); }
+            if icon.handle.is_null() { return Err(NwgError::invalid_handle("Icon was not initialized")); }
+
+            unsafe { ImageList_ReplaceIcon(self.handle, index, icon.handle as HICON); }
+
+            Ok(())
+        }
     }
 
     /**
         Adds a bitmap directly from a filename. The image is resized to the image list size.
-        Returns the index to the image or an error if the image could not be loaded
+        Returns the index to the image or an error if the image could not be loaded or if the image list is not bound
     */
     pub fn add_bitmap_from_filename(&self, filename: &str) -> Result<i32, NwgError> {
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
-
-        let (w, h) = self.size();
+        let (w, h) = self.try_size()?;
         let mut bitmap = Bitmap::default();
         Bitmap::builder()
             .source_file(Some(filename))
@@ -100,40 +168,15 @@ impl ImageList {
             .strict(true)
             .build(&mut bitmap)?;
 
-        unsafe { Ok(ImageList_AddMasked(self.handle, bitmap.handle as HBITMAP, 0)) }
-    }
-
-    /// Adds a new icon to the image list. Returns the index to the image. Panics if the icon was not initialized
-    pub fn add_icon(&self, icon: &Icon) -> i32 {
-        use winapi::um::winuser::{GetIconInfo, ICONINFO};
-        use winapi::um::wingdi::DeleteObject;
-
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
-        if icon.handle.is_null() { panic!("Icon was not initialized"); }
-
-        // Extract the bitmap from the icon
-        // Can't use `ImageList_AddIcon` because it doesn't always guess the mask
-        unsafe {
-            let mut info: ICONINFO = ::std::mem::zeroed();
-            GetIconInfo(icon.handle as _, &mut info);
-            
-            let i = ImageList_AddMasked(self.handle, info.hbmColor, 0);
-
-            DeleteObject(info.hbmMask as _);
-            DeleteObject(info.hbmColor as _);
-
-            i
-        }
+        self.try_add_bitmap(&bitmap)
     }
 
     /**
         Adds a icon directly from a filename. The image is resized to the image list size.
-        Returns the index to the image or an error if the image could not be loaded
+        Returns the index to the image or an error if the image could not be loaded or if the image list is not bound
     */
     pub fn add_icon_from_filename(&self, filename: &str) -> Result<i32, NwgError> {
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
-
-        let (w, h) = self.size();
+        let (w, h) = self.try_size()?;
         let mut icon = Icon::default();
         Icon::builder()
             .source_file(Some(filename))
@@ -141,42 +184,7 @@ impl ImageList {
             .strict(true)
             .build(&mut icon)?;
 
-        Ok(self.add_icon(&icon))
-    }
-
-    /**
-        Removes the image at the specified index
-
-        When an image is removed, the indexes of the remaining images are adjusted so that the image indexes
-        always range from zero to one less than the number of images in the image list. For example, if you
-        remove the image at index 0, then image 1 becomes image 0, image 2 becomes image 1, and so on.
-    */
-    pub fn remove(&self, index: i32) {
-        use winapi::um::commctrl::ImageList_Remove;
-
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
-
-        unsafe { ImageList_Remove(self.handle, index); }
-    }
-
-    /// Replaces an image in the image list. Panics if the bitmap was not initialized
-    pub fn replace_bitmap(&self, index: i32, bitmap: &Bitmap) {
-        use winapi::um::commctrl::ImageList_Replace;
-
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
-        if bitmap.handle.is_null() { panic!("Bitmap was not initialized"); }
-        
-        unsafe { ImageList_Replace(self.handle, index, bitmap.handle as HBITMAP, ptr::null_mut()); }
-    }
-
-    /// Replaces an image in the image list by an icon. Panics if the icon was not initialized
-    pub fn replace_icon(&self, index: i32, icon: &Icon) {
-        use winapi::um::commctrl::ImageList_ReplaceIcon;
-
-        if self.handle.is_null() { panic!("{}", NOT_BOUND); }
-        if icon.handle.is_null() { panic!("Icon was not initialized"); }
-
-        unsafe { ImageList_ReplaceIcon(self.handle, index, icon.handle as HICON); }
+        self.try_add_icon(&icon)
     }
 
 }
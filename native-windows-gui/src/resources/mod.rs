@@ -22,6 +22,15 @@ mod image_list;
 #[cfg(feature = "embed-resource")]
 mod embed;
 
+#[cfg(feature = "spell-check")]
+mod spell_checker;
+
+#[cfg(feature = "gdi-canvas")]
+mod pen;
+
+#[cfg(feature = "gdi-canvas")]
+mod brush;
+
 pub use font::{Font, MemFont, FontInfo, FontBuilder};
 pub use system_images::*;
 pub use icon::{Icon, IconBuilder};
@@ -46,3 +55,12 @@ pub use image_list::{ImageList, ImageListBuilder};
 #[cfg(feature = "embed-resource")]
 pub use embed::*;
 
+#[cfg(feature = "spell-check")]
+pub use spell_checker::{SpellChecker, SpellCheckerBuilder, SpellingError, SpellingAction};
+
+#[cfg(feature = "gdi-canvas")]
+pub use pen::{Pen, PenBuilder, PenStyle};
+
+#[cfg(feature = "gdi-canvas")]
+pub use brush::{Brush, BrushBuilder, BrushHatch};
+
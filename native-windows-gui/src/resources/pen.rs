@@ -0,0 +1,130 @@
+use winapi::shared::windef::HPEN;
+use crate::win32::resources_helper as rh;
+use crate::NwgError;
+use std::ptr;
+
+
+/**
+    The line style of a pen
+*/
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PenStyle {
+    Solid,
+    Dash,
+    Dot,
+    DashDot,
+    DashDotDot,
+    /// The pen does not draw anything. Ex: to draw a shape without its outline.
+    Null,
+}
+
+/**
+    A GDI pen. Pens are used by a `Canvas` to draw lines and the outline of shapes.
+
+    Requires the `gdi-canvas` feature.
+
+    **Builder parameters:**
+      * `color`: The color of the pen. Defaults to black.
+      * `width`: The width of the pen in pixels. Defaults to 1. Styles other than `Solid` only work with a width of 1.
+      * `style`: The line style of the pen. Defaults to `Solid`.
+
+    Example:
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn build_pen() -> nwg::Pen {
+        let mut pen = nwg::Pen::default();
+
+        nwg::Pen::builder()
+            .color([200, 20, 20])
+            .width(2)
+            .build(&mut pen)
+            .unwrap();
+
+        pen
+    }
+    ```
+*/
+#[derive(PartialEq, Eq, Debug)]
+pub struct Pen {
+    pub handle: HPEN
+}
+
+impl Pen {
+
+    pub fn builder() -> PenBuilder {
+        PenBuilder {
+            color: [0, 0, 0],
+            width: 1,
+            style: PenStyle::Solid,
+        }
+    }
+
+}
+
+impl Default for Pen {
+
+    fn default() -> Pen {
+        Pen { handle: ptr::null_mut() }
+    }
+
+}
+
+impl Drop for Pen {
+
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            rh::destroy_obj(self.handle as _);
+        }
+    }
+
+}
+
+pub struct PenBuilder {
+    color: [u8; 3],
+    width: u32,
+    style: PenStyle,
+}
+
+impl PenBuilder {
+
+    pub fn color(mut self, color: [u8; 3]) -> PenBuilder {
+        self.color = color;
+        self
+    }
+
+    pub fn width(mut self, width: u32) -> PenBuilder {
+        self.width = width;
+        self
+    }
+
+    pub fn style(mut self, style: PenStyle) -> PenBuilder {
+        self.style = style;
+        self
+    }
+
+    pub fn build(self, pen: &mut Pen) -> Result<(), NwgError> {
+        use winapi::um::wingdi::{CreatePen, RGB, PS_SOLID, PS_DASH, PS_DOT, PS_DASHDOT, PS_DASHDOTDOT, PS_NULL};
+
+        let style = match self.style {
+            PenStyle::Solid => PS_SOLID,
+            PenStyle::Dash => PS_DASH,
+            PenStyle::Dot => PS_DOT,
+            PenStyle::DashDot => PS_DASHDOT,
+            PenStyle::DashDotDot => PS_DASHDOTDOT,
+            PenStyle::Null => PS_NULL,
+        };
+
+        let [r, g, b] = self.color;
+        let handle = unsafe { CreatePen(style as _, self.width as _, RGB(r, g, b)) };
+        if handle.is_null() {
+            return Err(NwgError::resource_create("Failed to create the pen"));
+        }
+
+        *pen = Pen { handle };
+
+        Ok(())
+    }
+
+}
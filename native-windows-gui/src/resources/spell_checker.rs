@@ -0,0 +1,170 @@
+use crate::win32::spellcheck as sc;
+use crate::NwgError;
+use std::ops::Range;
+use std::ptr;
+
+
+/// The action suggested by the spell checker to fix a spelling error
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpellingAction {
+    /// There's no suggested action
+    None,
+    /// The word is misspelled. Use `SpellChecker::suggest` to get a list of replacements.
+    Suggest,
+    /// The word should be replaced by the inner value
+    Replace(String),
+    /// The word should be deleted (ex: a repeated word)
+    Delete,
+}
+
+/// A spelling error found by `SpellChecker::check`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpellingError {
+    /// The position of the error in the checked text. Like the indices of the text controls, the positions are in UTF-16 code units.
+    pub range: Range<usize>,
+    /// The action suggested to fix the error
+    pub action: SpellingAction,
+}
+
+/**
+    A spell checker that uses the spell checking API of the system (Windows 8 and later).
+
+    The spell checker can check any text, but it is usually used alongside a `RichTextBox` with spell checking enabled
+    (see `RichTextBox::set_spell_checking`) to offer suggestions when the `OnMisspelledWord` event is raised.
+
+    Requires the `spell-check` feature.
+
+    **Builder parameters:**
+      * `language`:  The language tag of the spell checker (ex: "en-US"). Defaults to the language of the user.
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn build_spell_checker(checker: &mut nwg::SpellChecker) -> Result<(), nwg::NwgError> {
+        nwg::SpellChecker::builder()
+            .language("en-US")
+            .build(checker)
+    }
+    ```
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn fix_spelling(checker: &nwg::SpellChecker, word: &str) -> Option<String> {
+        match checker.is_correct(word) {
+            true => None,
+            false => checker.suggest(word).into_iter().next()
+        }
+    }
+    ```
+*/
+pub struct SpellChecker {
+    checker: *mut sc::ISpellChecker,
+}
+
+impl SpellChecker {
+
+    /// Create a spell checker using the language of the user
+    pub fn new() -> Result<SpellChecker, NwgError> {
+        let mut checker = SpellChecker::default();
+        SpellChecker::builder().build(&mut checker)?;
+        Ok(checker)
+    }
+
+    pub fn builder<'a>() -> SpellCheckerBuilder<'a> {
+        SpellCheckerBuilder {
+            language: None,
+        }
+    }
+
+    /// Return the language tag of the current user. This is the default language of the spell checker.
+    pub fn user_language() -> String {
+        sc::user_language()
+    }
+
+    /// Return the language tags supported by the spell checking API on this system
+    pub fn supported_languages() -> Vec<String> {
+        unsafe { sc::supported_languages() }
+    }
+
+    /// Return the language tag of the spell checker
+    pub fn language(&self) -> String {
+        unsafe { sc::language(self.inner()) }
+    }
+
+    /// Check the spelling of a text and return the errors found
+    pub fn check(&self, text: &str) -> Vec<SpellingError> {
+        unsafe { sc::check(self.inner(), text) }
+    }
+
+    /// Check if a single word is correctly spelled
+    pub fn is_correct(&self, word: &str) -> bool {
+        self.check(word).is_empty()
+    }
+
+    /// Return a list of replacements for a misspelled word. Returns an empty list if the word is correctly spelled.
+    pub fn suggest(&self, word: &str) -> Vec<String> {
+        unsafe { sc::suggest(self.inner(), word) }
+    }
+
+    /// Add a word to the user dictionary. The word will be recognized by every spell checker of the user.
+    pub fn add_word(&self, word: &str) {
+        unsafe { sc::add(self.inner(), word) }
+    }
+
+    /// Ignore a word for the lifetime of this spell checker
+    pub fn ignore_word(&self, word: &str) {
+        unsafe { sc::ignore(self.inner(), word) }
+    }
+
+    fn inner(&self) -> &sc::ISpellChecker {
+        if self.checker.is_null() {
+            panic!("SpellChecker is not yet bound to a winapi object");
+        }
+
+        unsafe { &*self.checker }
+    }
+
+}
+
+impl Default for SpellChecker {
+    fn default() -> SpellChecker {
+        SpellChecker {
+            checker: ptr::null_mut()
+        }
+    }
+}
+
+impl Drop for SpellChecker {
+    fn drop(&mut self) {
+        if !self.checker.is_null() {
+            unsafe { (&*self.checker).Release(); }
+            self.checker = ptr::null_mut();
+        }
+    }
+}
+
+/**
+    A builder for the spell checker
+*/
+pub struct SpellCheckerBuilder<'a> {
+    language: Option<&'a str>,
+}
+
+impl<'a> SpellCheckerBuilder<'a> {
+
+    pub fn language(mut self, language: &'a str) -> SpellCheckerBuilder<'a> {
+        self.language = Some(language);
+        self
+    }
+
+    pub fn build(self, out: &mut SpellChecker) -> Result<(), NwgError> {
+        let checker = match self.language {
+            Some(language) => unsafe { sc::create_spell_checker(language)? },
+            None => unsafe { sc::create_spell_checker(&sc::user_language())? },
+        };
+
+        *out = SpellChecker { checker };
+
+        Ok(())
+    }
+
+}
@@ -0,0 +1,239 @@
+/*!
+    Styles bundle a font, text and background colors, and padding that can be applied to many controls at once. Requires the `style` feature.
+
+    The font is set with `WM_SETFONT`. The colors are set by a raw event handler on the parent of the styled controls
+    that answers the `WM_CTLCOLOR*` messages. The colors are ignored when the system is in high contrast mode.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn theme(window: &nwg::Window, font: &nwg::Font) -> nwg::Style {
+        let mut style = nwg::Style::default();
+        nwg::Style::builder()
+            .font(Some(font))
+            .text_color(Some([30, 30, 80]))
+            .background_color(Some([240, 240, 250]))
+            .build(&mut style);
+
+        // Style every control in the window
+        style.apply_all(window).unwrap();
+        style
+    }
+    ```
+*/
+use winapi::shared::windef::{HWND, HFONT, HBRUSH, HDC, COLORREF};
+use winapi::shared::minwindef::LRESULT;
+use winapi::shared::basetsd::UINT_PTR;
+use crate::win32::window_helper as wh;
+use crate::win32::window::bind_raw_event_handler_inner;
+use crate::{ControlHandle, Font, NwgError, has_raw_handler};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::ptr;
+
+/// Id of the raw event handler that colors the styled children of a window
+const STYLE_HANDLER_ID: UINT_PTR = 0xFF05;
+
+/// The colors of a style. The background brush is freed when the style and all the styled controls are gone.
+struct StyleColors {
+    text: Option<COLORREF>,
+    background: Option<(COLORREF, HBRUSH)>,
+}
+
+impl Drop for StyleColors {
+    fn drop(&mut self) {
+        use winapi::um::wingdi::DeleteObject;
+
+        if let Some((_, brush)) = self.background {
+            unsafe { DeleteObject(brush as _); }
+        }
+    }
+}
+
+thread_local! {
+    /// Colors of the styled controls of the thread
+    static STYLED_CONTROLS: RefCell<HashMap<HWND, Rc<StyleColors>>> = RefCell::new(HashMap::new());
+}
+
+/**
+    A set of visual properties that can be applied to a control or to a control and all its children.
+    A style can be applied any number of times. The controls keep their style after the style is dropped.
+
+    The font of a style must outlive the controls it is applied to.
+    The padding is only supported by the edit controls (ex: TextInput, TextBox); other controls ignore it.
+
+    Use `Style::builder` to create a style.
+*/
+#[derive(Default)]
+pub struct Style {
+    font: Option<HFONT>,
+    colors: Option<Rc<StyleColors>>,
+    padding: Option<[u16; 2]>,
+}
+
+impl Style {
+
+    pub fn builder() -> StyleBuilder {
+        StyleBuilder {
+            font: None,
+            text_color: None,
+            background_color: None,
+            padding: None,
+        }
+    }
+
+    /// Apply the style to `control`
+    pub fn apply<C: Into<ControlHandle>>(&self, control: C) -> Result<(), NwgError> {
+        let handle = control.into().hwnd().expect("Styled controls must be window-like controls (HWND handle)");
+        prune_styled_controls();
+        self.apply_hwnd(handle)
+    }
+
+    /// Apply the style to `control` and to all its children
+    pub fn apply_all<C: Into<ControlHandle>>(&self, control: C) -> Result<(), NwgError> {
+        let handle = control.into().hwnd().expect("Styled controls must be window-like controls (HWND handle)");
+        prune_styled_controls();
+
+        let mut handles = vec![handle];
+        let mut index = 0;
+        while index < handles.len() {
+            let mut children = Vec::new();
+            wh::iterate_window_children(handles[index], |child| children.push(child));
+            handles.append(&mut children);
+            index += 1;
+        }
+
+        for handle in handles {
+            self.apply_hwnd(handle)?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_hwnd(&self, handle: HWND) -> Result<(), NwgError> {
+        use winapi::um::winuser::{InvalidateRect, EM_SETMARGINS, EC_LEFTMARGIN, EC_RIGHTMARGIN, WS_CHILD};
+        use winapi::shared::minwindef::MAKELONG;
+
+        if let Some(font) = self.font {
+            unsafe { wh::set_window_font(handle, Some(font), true); }
+        }
+
+        if let Some([left, right]) = self.padding {
+            if unsafe { wh::get_window_class_name(handle) }.eq_ignore_ascii_case("Edit") {
+                let margins = MAKELONG(left, right) as u32;
+                wh::send_message(handle, EM_SETMARGINS as u32, (EC_LEFTMARGIN | EC_RIGHTMARGIN) as _, margins as _);
+            }
+        }
+
+        // Only the children of a window receive the `WM_CTLCOLOR*` messages
+        let colors = match self.colors.as_ref() {
+            Some(colors) if wh::get_style(handle) & WS_CHILD == WS_CHILD => colors.clone(),
+            _ => { return Ok(()); }
+        };
+
+        let parent = ControlHandle::Hwnd(wh::get_window_parent(handle));
+        if !has_raw_handler(&parent, STYLE_HANDLER_ID) {
+            bind_raw_event_handler_inner(&parent, STYLE_HANDLER_ID, |hwnd, msg, w, l| {
+                color_child(hwnd, msg, w as HDC, l as HWND)
+            })?;
+        }
+
+        STYLED_CONTROLS.with(|styled| styled.borrow_mut().insert(handle, colors));
+
+        unsafe { InvalidateRect(handle, ptr::null(), 1); }
+
+        Ok(())
+    }
+
+}
+
+/// Answer the `WM_CTLCOLOR*` messages sent by the styled children of a window
+fn color_child(hwnd: HWND, msg: u32, hdc: HDC, child: HWND) -> Option<LRESULT> {
+    use winapi::um::winuser::{WM_CTLCOLORSTATIC, WM_CTLCOLOREDIT, WM_CTLCOLORBTN, WM_CTLCOLORLISTBOX};
+    use winapi::um::wingdi::{SetTextColor, SetBkColor};
+    use winapi::um::commctrl::DefSubclassProc;
+    use crate::win32::high_contrast::high_contrast;
+
+    match msg {
+        WM_CTLCOLORSTATIC | WM_CTLCOLOREDIT | WM_CTLCOLORBTN | WM_CTLCOLORLISTBOX => {},
+        _ => { return None; }
+    }
+
+    let colors = STYLED_CONTROLS.with(|styled| styled.borrow().get(&child).cloned())?;
+    if high_contrast() {
+        return None;
+    }
+
+    unsafe {
+        // The default handling selects the system colors and returns the default brush
+        let mut result = DefSubclassProc(hwnd, msg, hdc as _, child as _);
+
+        if let Some(text) = colors.text {
+            SetTextColor(hdc, text);
+        }
+
+        if let Some((background, brush)) = colors.background {
+            SetBkColor(hdc, background);
+            result = brush as LRESULT;
+        }
+
+        Some(result)
+    }
+}
+
+/// Forget the styled controls that were destroyed
+fn prune_styled_controls() {
+    STYLED_CONTROLS.with(|styled| styled.borrow_mut().retain(|&handle, _| wh::window_valid(handle)));
+}
+
+pub struct StyleBuilder {
+    font: Option<HFONT>,
+    text_color: Option<[u8; 3]>,
+    background_color: Option<[u8; 3]>,
+    padding: Option<[u16; 2]>,
+}
+
+impl StyleBuilder {
+
+    pub fn font(mut self, font: Option<&Font>) -> StyleBuilder {
+        self.font = font.map(|f| f.handle);
+        self
+    }
+
+    pub fn text_color(mut self, color: Option<[u8; 3]>) -> StyleBuilder {
+        self.text_color = color;
+        self
+    }
+
+    pub fn background_color(mut self, color: Option<[u8; 3]>) -> StyleBuilder {
+        self.background_color = color;
+        self
+    }
+
+    /// The left and right padding of the text, in pixels
+    pub fn padding(mut self, padding: Option<[u16; 2]>) -> StyleBuilder {
+        self.padding = padding;
+        self
+    }
+
+    pub fn build(self, style: &mut Style) {
+        use winapi::um::wingdi::{CreateSolidBrush, RGB};
+
+        let text = self.text_color.map(|c| RGB(c[0], c[1], c[2]));
+        let background = self.background_color.map(|c| {
+            let color = RGB(c[0], c[1], c[2]);
+            (color, unsafe { CreateSolidBrush(color) })
+        });
+
+        *style = Style {
+            font: self.font,
+            colors: match text.is_some() || background.is_some() {
+                true => Some(Rc::new(StyleColors { text, background })),
+                false => None
+            },
+            padding: self.padding,
+        };
+    }
+
+}
@@ -33,10 +33,12 @@ pub struct ControlsTest {
     window_icon: Icon,
     love_icon: Icon,
     love_small_icon: Icon,
+    window_icon_small: Icon,
     ferris: Bitmap,
     popcorn: Bitmap,
     popcorn_small: Bitmap,
     ball: Bitmap,
+    menu_bitmap: Bitmap,
     arial_font: Font,
     segoe_font: Font,
     wait_cursor: Cursor,
@@ -61,6 +63,12 @@ pub struct ControlsTest {
 
     tray_icon: TrayNotification,
     tray_icon_2: TrayNotification,
+    tray_icon_3: TrayNotification,
+    toast: ToastNotification,
+    d2d_canvas: D2DCanvas,
+    plot_canvas: PlotCanvas,
+    media_player: MediaPlayer,
+    drop_target: DropTarget,
     status: StatusBar,
 
     // First Tab
@@ -84,6 +92,7 @@ pub struct ControlsTest {
     test_radio3: RadioButton,
     test_radio4: RadioButton,
     test_text_input: TextInput,
+    test_auto_complete: AutoComplete,
     test_text_box: TextBox,
     test_progress1: ProgressBar,
     test_progress2: ProgressBar,
@@ -102,6 +111,8 @@ pub struct ControlsTest {
     test_maximize: Button,
     test_minimize: Button,
     test_restore: Button,
+    test_owner_draw_list: ListBox<&'static str>,
+    test_owner_draw_button: Button,
 
     // Third Tab
     test_open_file_button: Button,
@@ -138,6 +149,8 @@ pub struct ControlsTest {
     pop_menu: Menu,
     pop_menu_item1: MenuItem,
     pop_menu_item2: MenuItem,
+    context_menu: PopupMenu,
+    context_menu_item: MenuItem,
 
     // Control panel
     pub panel: Window,
@@ -182,6 +195,13 @@ mod partial_controls_test_ui {
                 .size(Some((25, 25)))
                 .build(&mut data.love_small_icon)?;
 
+            Icon::builder()
+                .source_file(Some("./test_rc/love.ico"))
+                .size(Some((16, 16)))
+                .build(&mut data.window_icon_small)?;
+
+            data.menu_bitmap = data.window_icon_small.copy_as_bitmap()?;
+
             Bitmap::builder()
                 .source_file(Some("./test_rc/ferris.bmp"))
                 .build(&mut data.ferris)?;
@@ -269,6 +289,14 @@ mod partial_controls_test_ui {
                 .tip(Some("Native Windows GUI tests"))
                 .build(&mut data.tray_icon)?;
 
+            TrayNotification::builder()
+                .parent(&data.window)
+                .icon(Some(&data.love_icon))
+                .tip(Some("Second tray icon of the same window"))
+                .visible(false)
+                .guid(Some(0x6b2c5d8e_3f41_4a97_b0c2_9e1d7a4f5c38))
+                .build(&mut data.tray_icon_3)?;
+
             StatusBar::builder()
                 .text("Ready for tests ;)")
                 .parent(&data.window)
@@ -378,6 +406,7 @@ mod partial_controls_test_ui {
                 .parent(&data.basics_control_tab)
                 .bitmap(Some(&data.ferris))
                 .background_color(Some([255,255,255]))
+                .scaling(ImageFrameScaling::Fit)
                 .build(&mut data.test_img_frame)?;
 
             RadioButton::builder()
@@ -421,6 +450,12 @@ mod partial_controls_test_ui {
                 .parent(&data.basics_control_tab)
                 .build(&mut data.test_text_input)?;
 
+            AutoComplete::builder()
+                .items(&["Hello World!", "Hello Rust!"])
+                .flags(AutoCompleteFlags::SUGGEST | AutoCompleteFlags::APPEND)
+                .parent(&data.test_text_input)
+                .build(&mut data.test_auto_complete)?;
+
             TextBox::builder()
                 .text("Multi\r\nLine\r\nText")
                 .flags(TextBoxFlags::VISIBLE | TextBoxFlags::AUTOVSCROLL | TextBoxFlags::AUTOHSCROLL | TextBoxFlags::TAB_STOP)
@@ -549,6 +584,22 @@ mod partial_controls_test_ui {
                 .parent(&data.basics_control_tab2)
                 .build(&mut data.test_restore)?;
 
+            ListBox::builder()
+                .flags(ListBoxFlags::VISIBLE | ListBoxFlags::OWNER_DRAW)
+                .position((260, 190))
+                .size((140, 100))
+                .parent(&data.basics_control_tab2)
+                .collection(vec!["Red", "Green", "Blue"])
+                .build(&mut data.test_owner_draw_list)?;
+
+            Button::builder()
+                .flags(ButtonFlags::VISIBLE | ButtonFlags::OWNER_DRAW)
+                .text("Owner drawn")
+                .position((260, 295))
+                .size((140, 40))
+                .parent(&data.basics_control_tab2)
+                .build(&mut data.test_owner_draw_button)?;
+
             //
             // Dialogs
             //
@@ -652,6 +703,8 @@ mod partial_controls_test_ui {
                 .default_decoration(Some("More info"), Some(TooltipIcon::InfoLarge))
                 .register(&data.test_list_box1, "Simple list")
                 .register(&data.test_list_box2, "Multi select list")
+                .max_width(Some(300))
+                .autopop_delay(Some(10000))
                 .build(&mut data.test_ttp3)?;
 
             //
@@ -673,6 +726,7 @@ mod partial_controls_test_ui {
 
             MenuItem::builder()
                 .text("Test item 1")
+                .bitmap(Some(&data.menu_bitmap))
                 .parent(&data.window_menu)
                 .build(&mut data.window_menu_item1)?;
 
@@ -702,6 +756,17 @@ mod partial_controls_test_ui {
                 .parent(&data.pop_menu)
                 .build(&mut data.pop_menu_item2)?;
 
+            PopupMenu::builder()
+                .parent(&data.window)
+                .build(&mut data.context_menu)?;
+
+            MenuItem::builder()
+                .text("Context item")
+                .parent(&data.context_menu)
+                .build(&mut data.context_menu_item)?;
+
+            assert!(PopupMenu::builder().parent(&data.pop_menu).build(&mut PopupMenu::default()).is_err());
+
 
             //
             // Run tests
@@ -726,6 +791,34 @@ mod partial_controls_test_ui {
                 .tip(Some("Hello!"))
                 .build(&mut data.tray_icon_2)?;
             
+            ToastNotification::builder()
+                .parent(&data.panel)
+                .app_id("NativeWindowsGui.Tests")
+                .fallback(Some(&data.tray_icon_2))
+                .build(&mut data.toast)?;
+
+            D2DCanvas::builder()
+                .size((10, 10))
+                .flags(D2DCanvasFlags::NONE)
+                .parent(&data.panel)
+                .build(&mut data.d2d_canvas)?;
+
+            PlotCanvas::builder()
+                .size((300, 200))
+                .flags(PlotCanvasFlags::NONE)
+                .parent(&data.panel)
+                .build(&mut data.plot_canvas)?;
+
+            MediaPlayer::builder()
+                .size((100, 80))
+                .flags(MediaPlayerFlags::NONE)
+                .parent(&data.panel)
+                .build(&mut data.media_player)?;
+
+            DropTarget::builder()
+                .parent(&data.plot_canvas)
+                .build(&mut data.drop_target)?;
+
             Button::builder()
                 .text("Run window test")
                 .parent(&data.panel)
@@ -961,6 +1054,18 @@ mod partial_controls_test_ui {
                         set_lv_sort(&self.test_list_view, _evt_data);
                     }
                 },
+                E::OnMeasureItem => {
+                    if &handle == &self.test_owner_draw_list {
+                        let measure = _evt_data.on_measure_item();
+                        assert!(measure.index().is_some());
+                        measure.set_size(0, 30);
+                    }
+                },
+                E::OnDrawItem => {
+                    if &handle == &self.test_owner_draw_list || &handle == &self.test_owner_draw_button {
+                        draw_owner_item(_evt_data.on_draw_item());
+                    }
+                },
                 _ => {}
             }
         }
@@ -972,6 +1077,20 @@ mod partial_controls_test_ui {
     }
 }
 
+fn draw_owner_item(draw: &DrawItemData) {
+    let [left, top, right, bottom] = draw.rect();
+    let state = draw.state();
+
+    let (back, front) = match state.contains(DrawItemState::SELECTED) {
+        true => ([0, 120, 215], [255, 255, 255]),
+        false => ([240, 240, 240], [20, 20, 20]),
+    };
+
+    draw.fill(back);
+    draw.draw_text(&draw.text(), [left + 5, top, right, bottom], front, None);
+    draw.draw_focus_rect([left, top, right, bottom]);
+}
+
 fn item_hello(m: &'static str) {
     simple_message("Hello", &format!("Hello from {}!", m));
 }
@@ -985,7 +1104,20 @@ fn init_rich_text_box(app: &ControlsTest) {
         height: Some(250),
         text_color: Some([200, 0, 0]),
         ..Default::default()
-    })
+    });
+
+    let rtf = rich.rtf();
+    assert!(rtf.starts_with(b"{\\rtf"));
+    rich.set_rtf(&rtf);
+
+    assert_eq!(rich.set_undo_limit(10), 10);
+    rich.empty_undo_buffer();
+    assert_eq!(rich.can_undo(), false);
+    assert_eq!(rich.can_redo(), false);
+
+    rich.set_spell_checking(true);
+    assert_eq!(rich.spell_checking(), true);
+    assert_eq!(rich.word_at(0).is_some(), true);
 }
 
 fn init_tree(app: &ControlsTest) {
@@ -998,6 +1130,10 @@ fn init_tree(app: &ControlsTest) {
     let other = tree.insert_item("Another root children", Some(&item), TreeInsert::Last);
     tree.insert_item("Banana", Some(&other), TreeInsert::First);
     tree.insert_item("Pinapple", Some(&other), TreeInsert::First);
+
+    let fruits = tree.insert_items(&["Apple", "Cherry"], Some(&other), TreeInsert::Last);
+    assert_eq!(fruits.len(), 2);
+    assert_eq!(tree.item_text(&fruits[1]), Some("Cherry".to_string()));
 }
 
 fn init_list_view(app: &ControlsTest) {
@@ -1052,6 +1188,148 @@ fn run_window_tests(app: &ControlsTest, _evt: Event) {
         // The actual size return here might be less because it does not take account of the menubar
         // assert_eq!(app.window.size(), (500, 400));
 
+        // The render target is created on the first draw and resized on the next one
+        assert_eq!(app.d2d_canvas.visible(), false);
+        app.d2d_canvas.set_visible(true);
+        for size in [(10, 10), (20, 15)].iter() {
+            app.d2d_canvas.set_size(size.0, size.1);
+            app.d2d_canvas.draw(|ctx| {
+                ctx.clear([255, 255, 255, 255]);
+                ctx.fill_rect([0.0, 0.0, 5.0, 5.0], [255, 0, 0, 255]);
+                ctx.draw_line([0.0, 0.0], [10.0, 10.0], [0, 0, 0, 128], 1.0);
+                ctx.fill_ellipse([5.0, 5.0], [2.0, 2.0], [0, 0, 255, 255]);
+                assert!(ctx.size()[0] > 0.0);
+            }).expect("Failed to draw the direct2D canvas");
+        }
+
+        // Drawing validates the canvas. Hidden windows do not have an update region.
+        unsafe {
+            use winapi::um::winuser::GetUpdateRect;
+            use winapi::shared::windef::RECT;
+            use crate::win32::high_dpi::logical_to_physical;
+
+            let hwnd = app.d2d_canvas.handle.hwnd().unwrap();
+            let mut r: RECT = ::std::mem::zeroed();
+            assert_eq!(GetUpdateRect(hwnd, &mut r, 0), 0);
+
+            app.d2d_canvas.handle.invalidate_rect([2, 3, 8, 9]);
+            assert_ne!(GetUpdateRect(hwnd, &mut r, 0), 0);
+            assert_eq!([r.left, r.top, r.right, r.bottom], [2, 3, 8, 9]);
+
+            // The canvas size is in logical units, the update region is in pixels
+            let (width, height) = logical_to_physical(20, 15);
+            app.d2d_canvas.handle.invalidate();
+            assert_ne!(GetUpdateRect(hwnd, &mut r, 0), 0);
+            assert_eq!([r.left, r.top, r.right, r.bottom], [0, 0, width, height]);
+        }
+
+        app.d2d_canvas.set_visible(false);
+
+        // Tabs
+        {
+            let tabs = &app.controls_holder;
+            assert_eq!(tabs.close_buttons(), false);
+            tabs.set_close_buttons(true);
+            assert_eq!(tabs.close_buttons(), true);
+            tabs.set_close_buttons(false);
+
+            assert_eq!(app.dialog_tab.index(), 2);
+            assert_eq!(app.dialog_tab.image_index(), Some(2));
+
+            tabs.move_tab(2, 4);
+            assert_eq!(app.dialog_tab.index(), 4);
+            assert_eq!(app.list_view_tab.index(), 3);
+            assert_eq!(app.dialog_tab.image_index(), Some(2));
+            assert_eq!(tabs.selected_tab(), 0);
+
+            tabs.move_tab(4, 2);
+            assert_eq!(app.dialog_tab.index(), 2);
+            assert_eq!(app.list_view_tab.index(), 4);
+
+            assert_eq!(tabs.row_count(), 1);
+            assert!(tabs.visible_tabs().contains(&tabs.selected_tab()));
+        }
+
+        // Double buffering
+        {
+            let canvas = &app.d2d_canvas.handle;
+            assert_eq!(canvas.double_buffered(), false);
+            canvas.set_double_buffered(true);
+            assert_eq!(canvas.double_buffered(), true);
+
+            let data = PaintData { hwnd: canvas.hwnd().unwrap() };
+            assert_eq!(data.paint().buffered(), true);
+
+            canvas.set_double_buffered(false);
+            assert_eq!(canvas.double_buffered(), false);
+            assert_eq!(data.paint().buffered(), false);
+        }
+
+        {
+            let canvas = &app.d2d_canvas.handle;
+            assert!(canvas.set_accessible_name("Drawing").is_ok());
+            assert!(canvas.set_accessible_description("A canvas to draw on").is_ok());
+            assert!(canvas.set_accessible_role(AccessibleRole::Graphic).is_ok());
+            canvas.clear_accessibility();
+
+            assert!(ControlHandle::NoHandle.set_accessible_name("Nothing").is_err());
+
+            // The control tests window is the active window
+            assert!(announce("Running the window tests", AnnouncePriority::Normal).is_ok());
+        }
+
+        {
+            use winapi::um::winuser::{GetWindow, GW_HWNDNEXT};
+
+            let canvas = &app.d2d_canvas.handle;
+            assert_eq!(canvas.tab_stop(), false);
+            canvas.set_tab_stop(true);
+            assert_eq!(canvas.tab_stop(), true);
+            canvas.set_tab_stop(false);
+            assert_eq!(canvas.tab_stop(), false);
+
+            // Mirroring the parent does not change the logical position of the children
+            let panel = &app.panel.handle;
+            let position = app.d2d_canvas.position();
+            panel.set_rtl_layout(true);
+            assert_eq!(panel.rtl_layout(), true);
+            assert_eq!(app.d2d_canvas.position(), position);
+            panel.set_rtl_layout(false);
+            assert_eq!(panel.rtl_layout(), false);
+
+            canvas.set_rtl_reading(true);
+            assert_eq!(canvas.rtl_reading(), true);
+            canvas.set_rtl_reading(false);
+            assert_eq!(process_default_layout(), false);
+
+            set_tab_order(&[app.media_player.handle, app.d2d_canvas.handle, app.plot_canvas.handle]);
+            let next = |h: &ControlHandle| unsafe { GetWindow(h.hwnd().unwrap(), GW_HWNDNEXT) };
+            assert_eq!(next(&app.media_player.handle), app.d2d_canvas.handle.hwnd().unwrap());
+            assert_eq!(next(&app.d2d_canvas.handle), app.plot_canvas.handle.hwnd().unwrap());
+        }
+
+        {
+            // The composition string is only read when the flags say that it changed
+            let data = ImeCompositionData { hwnd: app.d2d_canvas.handle.hwnd().unwrap(), flags: 0 };
+            assert_eq!(data.composition(), None);
+            assert_eq!(data.result(), None);
+            assert_eq!(data.cursor(), None);
+
+            assert_eq!(ControlHandle::NoHandle.set_ime_position(10, 10), false);
+            assert_eq!(ControlHandle::NoHandle.set_ime_open(true), false);
+            assert_eq!(ControlHandle::NoHandle.ime_open(), false);
+        }
+
+        {
+            // Pointer data is only available while a pointer message is processed
+            let canvas = app.d2d_canvas.handle.hwnd().unwrap();
+            assert!(unsafe { crate::win32::pointer::pointer_data(canvas, 0xFFFF) }.is_none());
+        }
+
+        run_plot_tests(app);
+        run_media_player_tests(app);
+        run_drop_target_tests(app);
+
         app.runs.borrow_mut().window = true;
     } else {
         app.window.set_text("Controls");
@@ -1059,6 +1337,112 @@ fn run_window_tests(app: &ControlsTest, _evt: Event) {
     }
 }
 
+fn run_plot_tests(app: &ControlsTest) {
+    use winapi::um::winuser::WM_MOUSEMOVE;
+    use winapi::shared::minwindef::MAKELONG;
+    use crate::win32::window_helper as wh;
+
+    let plot = &app.plot_canvas;
+    assert_eq!(plot.series_len(), 0);
+    assert_eq!(plot.x_range(), [0.0, 1.0]);
+
+    let line = plot.add_series("Line", PlotKind::Line, [0, 120, 215]);
+    let bars = plot.add_series("", PlotKind::Bar, [215, 120, 0]);
+    assert_eq!((line, bars), (0, 1));
+    assert_eq!(plot.series_len(), 2);
+
+    // Autoscaling. Bars start at 0 and the x axis is extended by half a bar on each side
+    plot.set_points(line, &[[0.0, 10.0], [10.0, 50.0]]);
+    plot.push_point(bars, [5.0, -5.0]);
+    assert_eq!(plot.x_range(), [-0.5, 10.5]);
+    assert_eq!(plot.y_range(), [-5.0, 50.0]);
+
+    plot.set_y_range(Some([0.0, 100.0]));
+    assert_eq!(plot.y_range(), [0.0, 100.0]);
+    plot.set_y_range(None);
+
+    plot.set_max_points(Some(2));
+    plot.push_point(line, [20.0, 25.0]);
+    assert_eq!(plot.points(line), vec![[10.0, 50.0], [20.0, 25.0]]);
+    plot.set_max_points(None);
+
+    // Hover
+    let [x, y] = plot.value_position([20.0, 25.0]);
+    let point = PlotPoint { series: line, index: 1, value: [20.0, 25.0] };
+    assert_eq!(plot.point_at([x, y]), Some(point));
+    assert_eq!(plot.point_at([-1000, -1000]), None);
+
+    let hwnd = plot.handle.hwnd().unwrap();
+    wh::send_message(hwnd, WM_MOUSEMOVE, 0, MAKELONG(x as u16, y as u16) as _);
+    assert_eq!(plot.hovered(), Some(point));
+
+    plot.remove_series(bars);
+    assert_eq!(plot.series_len(), 1);
+    assert_eq!(plot.hovered(), None);
+
+    plot.clear();
+    assert_eq!(plot.series_len(), 0);
+}
+
+fn run_media_player_tests(app: &ControlsTest) {
+    use winapi::um::winuser::{GetWindow, GW_CHILD};
+    use crate::win32::window_helper as wh;
+    use std::time::Duration;
+
+    let player = &app.media_player;
+    assert_eq!(player.state(), MediaState::NotReady);
+    assert_eq!(player.length(), Duration::from_millis(0));
+    assert!(player.open("this_media_does_not_exist.wav").is_err());
+
+    assert_eq!(player.playbar(), false);
+    player.set_playbar(true);
+    assert_eq!(player.playbar(), true);
+    player.set_playbar(false);
+
+    // The MCIWnd window follows the size of the control
+    player.set_size(120, 90);
+    let mciwnd = unsafe { GetWindow(player.handle.hwnd().unwrap(), GW_CHILD) };
+    assert_eq!(unsafe { wh::get_window_size(mciwnd) }, (120, 90));
+}
+
+fn run_drop_target_tests(app: &ControlsTest) {
+    assert_eq!(app.drop_target.target(), app.plot_canvas.handle);
+
+    // A control can only have one drop target
+    let mut target = DropTarget::default();
+    let result = DropTarget::builder()
+        .parent(&app.plot_canvas)
+        .build(&mut target);
+    assert!(result.is_err());
+    assert!(target.handle.blank());
+
+    // Read the data of a drag source like a drop target would
+    let drag = DragData::new()
+        .text("Hello")
+        .files(&["C:\\hello.txt", "C:\\world.txt"])
+        .bytes(ClipboardFormat::Raw(0xC000), &[1, 2, 3]);
+
+    assert!(drag.has_format(ClipboardFormat::Files));
+    assert!(!drag.has_format(ClipboardFormat::Dib));
+
+    unsafe {
+        use crate::win32::{drag_source, drop_target::DropTargetData};
+
+        let data = drag_source::new_data_object(drag.formats.clone());
+        let target = DropTargetData { data, keys: 0, point: [0, 0], allowed: 0, effect: 0 };
+
+        assert!(target.has_format(ClipboardFormat::UnicodeText.into_raw()));
+        assert!(!target.has_format(ClipboardFormat::Dib.into_raw()));
+        assert_eq!(target.text(), Some("Hello".to_string()));
+        assert_eq!(target.files(), vec!["C:\\hello.txt".to_string(), "C:\\world.txt".to_string()]);
+        assert_eq!(&target.bytes(0xC000).unwrap()[0..3], &[1, 2, 3]);
+
+        (&*data).Release();
+    }
+
+    assert!(begin_drag(&DragData::new(), DropEffect::COPY).is_err());
+}
+
 fn run_button_tests(app: &ControlsTest, _evt: Event) {
     if !app.runs.borrow().button {
 
@@ -1104,6 +1488,54 @@ fn run_button_tests(app: &ControlsTest, _evt: Event) {
         app.test_image_button2.image(&mut bitmap, &mut icon);
         assert!(icon.is_none() && bitmap.is_none());
 
+        assert_eq!(app.test_image_button.image_only(), true);
+        assert_eq!(app.test_image_button3.image_only(), false);
+        app.test_image_button3.set_image_only(true);
+        assert_eq!(app.test_image_button3.image_only(), true);
+        app.test_image_button3.set_image_only(false);
+
+        assert!(app.test_image_button3.image_list().is_none());
+        app.test_image_button3.set_image_list(Some(&app.tabs_image_list), ButtonImageAlign::Right);
+        assert_eq!(app.test_image_button3.image_list().map(|(_, align)| align), Some(ButtonImageAlign::Right));
+        app.test_image_button3.set_image_list(None, ButtonImageAlign::Left);
+
+        assert_eq!(app.test_img_frame.scaling(), ImageFrameScaling::Fit);
+        app.test_img_frame.set_scaling(ImageFrameScaling::Tile);
+        assert_eq!(app.test_img_frame.scaling(), ImageFrameScaling::Tile);
+        app.test_img_frame.set_scaling(ImageFrameScaling::Fit);
+
+        assert_eq!(app.test_button.split(), false);
+        app.test_button.set_split(true);
+        assert_eq!(app.test_button.split(), true);
+        app.test_button.set_drop_down_menu(Some(&app.pop_menu));
+        app.test_button.set_drop_down_menu(None);
+        app.test_button.set_split(false);
+
+        assert_eq!(app.test_button.command_link(), false);
+        app.test_button.set_command_link(true);
+        assert_eq!(app.test_button.command_link(), true);
+        app.test_button.set_note("A note");
+        assert_eq!(&app.test_button.note(), "A note");
+        app.test_button.set_shield(true);
+        app.test_button.set_shield(false);
+        app.test_button.set_note("");
+        app.test_button.set_command_link(false);
+        assert_eq!(app.test_button.command_link(), false);
+
+        assert_eq!(app.test_button.is_default(), false);
+        app.test_button.set_default(true);
+        assert_eq!(app.test_button.is_default(), true);
+        app.test_button.set_split(true);
+        assert_eq!(app.test_button.is_default(), true);
+        app.test_button.set_split(false);
+        app.test_button.set_default(false);
+        assert_eq!(app.test_button.is_default(), false);
+
+        assert_eq!(app.test_button.is_cancel(), false);
+        app.test_button.set_cancel(true);
+        assert_eq!(app.test_button.is_cancel(), true);
+        app.test_button.set_cancel(false);
+
         app.runs.borrow_mut().button = true;
     } else {
         app.test_button.set_text("A simple button");
@@ -1133,6 +1565,13 @@ fn run_check_box_tests(app: &ControlsTest, _evt: Event) {
         app.test_checkbox1.set_check_state(CheckBoxState::Indeterminate);
         assert_eq!(app.test_checkbox1.check_state(), CheckBoxState::Indeterminate);
 
+        app.test_checkbox1.set_tristate(false);
+        assert_eq!(app.test_checkbox1.tristate(), false);
+        assert_eq!(app.test_checkbox1.check_state(), CheckBoxState::Unchecked);
+
+        app.test_checkbox1.set_check_state(CheckBoxState::Indeterminate);
+        assert_eq!(app.test_checkbox1.check_state(), CheckBoxState::Unchecked);
+
         app.runs.borrow_mut().check = true;
     } else {
         app.test_checkbox1.set_tristate(false);
@@ -1178,6 +1617,12 @@ fn run_combo_tests(app: &ControlsTest, _evt: Event) {
 
         app.test_combo.remove(0);
 
+        assert_eq!(app.test_combo.editable(), false);
+        assert_eq!(app.test_combo.set_selection_from_text("boo!"), Some(0));
+        assert_eq!(&app.test_combo.text(), "BOO!");
+        assert_eq!(app.test_combo.set_selection_from_text("BOO"), None);
+        assert_eq!(app.test_combo.selection(), None);
+
         app.test_combo.dropdown(true);
 
         app.runs.borrow_mut().combo = true;
@@ -1195,9 +1640,15 @@ fn run_date_tests(app: &ControlsTest, _evt: Event) {
         assert_eq!(app.test_date.value(), Some(v));
         assert_eq!(app.test_date.checked(), true);
 
+        let t = DatePickerTime { hour: 13, minute: 30, second: 15 };
+        app.test_date.set_time(Some(t));
+        assert_eq!(app.test_date.time(), Some(t));
+        assert_eq!(app.test_date.value(), Some(v));
+
         app.test_date.set_value(None);
         assert_eq!(app.test_date.value(), None);
         assert_eq!(app.test_date.checked(), false);
+        assert_eq!(app.test_date.time(), None);
 
         app.test_date.set_format(Some("'YEAR: 'yyyy"));
 
@@ -1235,6 +1686,9 @@ fn run_font_tests(app: &ControlsTest, _evt: Event) {
 
         assert_eq!(app.test_label.font().as_ref(), Some(&app.arial_font));
 
+        #[cfg(feature="gdi-canvas")]
+        run_canvas_tests(app);
+
         app.runs.borrow_mut().font = true;
     } else {
         app.test_label.set_font(None);
@@ -1255,6 +1709,68 @@ fn run_font_tests(app: &ControlsTest, _evt: Event) {
     }
 }
 
+#[cfg(feature="gdi-canvas")]
+fn run_canvas_tests(app: &ControlsTest) {
+    use winapi::um::wingdi::{CreateCompatibleDC, CreateCompatibleBitmap, SelectObject, DeleteObject, DeleteDC, GetPixel, GetCurrentObject, OBJ_PEN, RGB};
+    use winapi::um::winuser::{GetDC, ReleaseDC};
+    use std::ptr;
+
+    let mut pen = Pen::default();
+    Pen::builder().color([0, 0, 255]).width(2).style(PenStyle::Solid).build(&mut pen).unwrap();
+
+    let mut brush = Brush::default();
+    Brush::builder().color([255, 0, 0]).build(&mut brush).unwrap();
+
+    let mut hatch = Brush::default();
+    Brush::builder().color([0, 255, 0]).hatch(Some(BrushHatch::Cross)).build(&mut hatch).unwrap();
+
+    unsafe {
+        // A bitmap compatible with a memory DC is monochrome, so it is created from the screen DC
+        let screen = GetDC(ptr::null_mut());
+        let hdc = CreateCompatibleDC(screen);
+        let bitmap = CreateCompatibleBitmap(screen, 40, 40);
+        ReleaseDC(ptr::null_mut(), screen);
+        let old_bitmap = SelectObject(hdc, bitmap as _);
+        let old_pen = GetCurrentObject(hdc, OBJ_PEN);
+
+        {
+            let canvas = Canvas::from_hdc(hdc);
+            canvas.fill_rect([0, 0, 40, 40], &brush);
+            assert_eq!(GetPixel(hdc, 5, 5), RGB(255, 0, 0));
+
+            canvas.set_pixel([1, 1], [255, 255, 255]);
+            assert_eq!(GetPixel(hdc, 1, 1), RGB(255, 255, 255));
+
+            // Drawing is limited to the clipping rectangle
+            canvas.clip_rect([20, 20, 40, 40]);
+            canvas.fill_rect([0, 0, 40, 40], &hatch);
+            assert_eq!(GetPixel(hdc, 5, 5), RGB(255, 0, 0));
+            canvas.reset_clip();
+
+            canvas.set_pen(&pen);
+            canvas.set_brush(&brush);
+            canvas.ellipse([0, 0, 20, 20]);
+            canvas.polygon(&[[0, 0], [10, 10], [0, 10]]);
+            canvas.line([0, 39], [39, 39]);
+
+            canvas.set_font(&app.arial_font);
+            canvas.set_text_color([0, 0, 0]);
+            let [width, height] = canvas.text_size("Hello");
+            assert!(width > 0 && height > 0);
+            canvas.draw_text("Hello", [0, 0, 40, 40], HTextAlign::Center, VTextAlign::Center);
+
+            canvas.draw_bitmap(&app.ball, [0, 0]);
+        }
+
+        // The canvas restores the state of the device context
+        assert_eq!(GetCurrentObject(hdc, OBJ_PEN), old_pen);
+
+        SelectObject(hdc, old_bitmap);
+        DeleteObject(bitmap as _);
+        DeleteDC(hdc);
+    }
+}
+
 fn run_list_tests(app: &ControlsTest, _evt: Event) {
     if !app.runs.borrow().list {
         app.test_list_box2.unselect_all();
@@ -1273,6 +1789,11 @@ fn run_list_tests(app: &ControlsTest, _evt: Event) {
         app.test_list_box1.push("Hello!");
         assert_eq!(app.test_list_box1.len(), 6);
 
+        app.test_list_box1.push_all(vec!["Purple", "Orange"]);
+        assert_eq!(app.test_list_box1.len(), 8);
+        assert_eq!(app.test_list_box1.collection()[7], "Orange");
+        assert!(!app.test_list_box1.handle.frozen());
+
         app.test_list_box1.set_selection(Some(0));
         assert_eq!(app.test_list_box1.selected(0), true);
         
@@ -1321,6 +1842,52 @@ fn run_list_tests(app: &ControlsTest, _evt: Event) {
         assert_eq!(app.test_list_box2.multi_selection_len(), 1);
         assert_eq!(app.test_list_box2.multi_selection(), vec![2]);
 
+        assert_eq!(app.test_list_box2.multi_select(), true);
+        assert_eq!(app.test_list_box1.multi_select(), false);
+        assert_eq!(app.test_list_box2.selected_indices(), vec![2]);
+        app.test_list_box1.set_selection(Some(1));
+        assert_eq!(app.test_list_box1.selected_indices(), vec![1]);
+        app.test_list_box1.set_selection(None);
+        assert_eq!(app.test_list_box1.selected_indices(), vec![]);
+
+        app.test_list_box2.select_range(0..2, true);
+        assert_eq!(app.test_list_box2.selected_indices(), vec![0, 1, 2]);
+
+        app.test_list_box2.select_range(1..3, false);
+        assert_eq!(app.test_list_box2.selected_indices(), vec![0]);
+
+        app.test_list_box2.select_range(3..3, true);
+        assert_eq!(app.test_list_box2.selected_indices(), vec![0]);
+
+        assert_eq!(app.test_list_box2.toggle(4), true);
+        assert_eq!(app.test_list_box2.toggle(0), false);
+        assert_eq!(app.test_list_box2.selected_indices(), vec![4]);
+
+        app.test_list_box2.set_anchor_index(1);
+        assert_eq!(app.test_list_box2.anchor_index(), Some(1));
+
+        app.test_list_box2.set_caret_index(3);
+        assert_eq!(app.test_list_box2.caret_index(), Some(3));
+
+        // Owner drawn list boxes keep their strings and get an id for `OnMeasureItem`.
+        // The items are measured again when they are added after the event handler was bound.
+        app.test_owner_draw_list.set_collection(vec!["Red", "Green", "Blue"]);
+        unsafe {
+            use winapi::um::winuser::{GetDlgCtrlID, LB_GETITEMHEIGHT};
+
+            let handle = app.test_owner_draw_list.handle.hwnd().unwrap();
+            assert_ne!(GetDlgCtrlID(handle), 0);
+            assert_eq!(crate::win32::window_helper::send_message(handle, LB_GETITEMHEIGHT, 0, 0), 30);
+        }
+
+        app.test_owner_draw_list.set_selection(Some(1));
+        assert_eq!(app.test_owner_draw_list.selection_string(), Some("Green".to_string()));
+        app.test_owner_draw_list.set_selection(None);
+
+        // Owner drawn buttons cannot be default buttons
+        app.test_owner_draw_button.set_default(true);
+        assert_eq!(app.test_owner_draw_button.is_default(), false);
+
         app.runs.borrow_mut().list = true;
     } else {
         app.test_list_box2.unselect_all();
@@ -1343,11 +1910,147 @@ fn run_menu_tests(app: &ControlsTest, _evt: Event) {
 
         app.pop_menu.set_enabled(false);
 
+        app.window_menu_item1.set_bitmap(None);
+
+        app.pop_menu.set_radio_group(&[&app.pop_menu_item1, &app.pop_menu_item2], Some(1));
+        assert_eq!(app.pop_menu_item1.checked(), false);
+        assert_eq!(app.pop_menu_item2.checked(), true);
+
+        app.pop_menu.set_radio_group(&[&app.pop_menu_item1, &app.pop_menu_item2], Some(0));
+        assert_eq!(app.pop_menu_item1.checked(), true);
+        assert_eq!(app.pop_menu_item2.checked(), false);
+
+        app.pop_menu_item1.set_checked(false);
+        assert_eq!(app.pop_menu_item1.checked(), false);
+
+        {
+            // Menu events do not know if a menu is a popup menu
+            let (_, hmenu) = app.pop_menu.handle.pop_hmenu().unwrap();
+            assert_eq!(ControlHandle::Menu(std::ptr::null_mut(), hmenu), app.pop_menu.handle);
+        }
+
+        app.context_menu_item.set_enabled(false);
+        assert_eq!(app.context_menu_item.enabled(), false);
+
+        assert_eq!(app.pop_menu_item1.register_accelerator(), true);
+        assert_eq!(app.pop_menu_item1.has_accelerator(), true);
+        assert_eq!(app.window_menu_item1.register_accelerator(), false);
+        assert_eq!(app.window_menu_item1.has_accelerator(), false);
+
+        assert_eq!(app.context_menu_item.owner_draw(), false);
+        app.context_menu_item.set_owner_draw(true);
+        assert_eq!(app.context_menu_item.owner_draw(), true);
+        assert_eq!(&app.context_menu_item.text(), "Context item");
+        app.context_menu_item.set_owner_draw(false);
+        assert_eq!(app.context_menu_item.owner_draw(), false);
+
+        assert_eq!(&app.window_menu_item1.text(), "Test item 1");
+        app.window_menu_item1.set_text("Renamed item 1");
+        assert_eq!(&app.window_menu_item1.text(), "Renamed item 1");
+
+        assert_eq!(&app.window_submenu1.text(), "Test &Submenu");
+        app.window_submenu1.set_text("Renamed &Submenu");
+        assert_eq!(&app.window_submenu1.text(), "Renamed &Submenu");
+
+        {
+            let mut item = MenuItem::default();
+            MenuItem::builder()
+                .text("Inserted item")
+                .position(Some(0))
+                .parent(&app.pop_menu)
+                .build(&mut item)
+                .expect("Failed to insert menu item");
+
+            assert_eq!(&item.text(), "Inserted item");
+            assert_eq!(&app.pop_menu_item1.text(), "Popup item 1\tCTRL+P");
+
+            item.remove();
+            assert!(item.handle.blank());
+        }
+
+        {
+            let saved = std::rc::Rc::new(RefCell::new(Vec::new()));
+            let saved_cb = saved.clone();
+
+            let mut recent = RecentFilesMenu::default();
+            RecentFilesMenu::builder()
+                .text("Recent")
+                .max_len(2)
+                .paths(vec!["a.txt".to_string(), "b.txt".to_string(), "c.txt".to_string()])
+                .on_changed(move |paths| *saved_cb.borrow_mut() = paths.to_vec())
+                .parent(&app.window_submenu1)
+                .build(&mut recent)
+                .expect("Failed to build recent files menu");
+
+            assert_eq!(recent.paths(), vec!["a.txt".to_string(), "b.txt".to_string()]);
+            assert!(saved.borrow().is_empty());
+
+            recent.add("b.txt");
+            assert_eq!(recent.paths(), vec!["b.txt".to_string(), "a.txt".to_string()]);
+            assert_eq!(*saved.borrow(), recent.paths());
+
+            recent.add("R&D.txt");
+            assert_eq!(recent.paths(), vec!["R&D.txt".to_string(), "b.txt".to_string()]);
+
+            recent.remove("b.txt");
+            assert_eq!(recent.paths(), vec!["R&D.txt".to_string()]);
+            assert_eq!(*saved.borrow(), vec!["R&D.txt".to_string()]);
+
+            recent.clear();
+            assert!(recent.paths().is_empty());
+            assert!(saved.borrow().is_empty());
+        }
+
+        {
+            let original = app.window.menu_bar().expect("Window should have a menu bar");
+            assert_eq!(app.window.menu_bar_visible(), true);
+
+            app.window.set_menu_bar_visible(false);
+            assert_eq!(app.window.menu_bar_visible(), false);
+            assert_eq!(app.window.menu_bar(), Some(original));
+
+            // Menus added while the menu bar is hidden go in the hidden menu bar
+            let mut hidden_menu = Menu::default();
+            Menu::builder()
+                .text("Hidden")
+                .parent(&app.window)
+                .build(&mut hidden_menu)
+                .expect("Failed to build menu");
+            assert_eq!(app.window.menu_bar(), Some(original));
+
+            app.window.set_menu_bar_visible(true);
+            assert_eq!(app.window.menu_bar_visible(), true);
+            hidden_menu.remove();
+
+            let mut bar = MenuBar::default();
+            MenuBar::builder()
+                .window(&app.window)
+                .build(&mut bar)
+                .expect("Failed to build menu bar");
+            assert_eq!(app.window.menu_bar(), Some(bar.handle));
+
+            app.window.set_menu_bar(Some(original));
+            assert_eq!(app.window.menu_bar(), Some(original));
+
+            app.window.set_menu_bar(Some(&bar));
+            drop(bar);
+            assert_eq!(app.window.menu_bar(), None);
+
+            app.window.set_menu_bar(Some(original));
+            assert_eq!(app.window.menu_bar_visible(), true);
+        }
+
         app.runs.borrow_mut().menu = true;
     } else {
         app.pop_menu_item1.set_enabled(true);
         app.window_submenu1.set_enabled(true);
         app.window_menu_item1.set_enabled(true);
+        app.window_menu_item1.set_bitmap(Some(&app.menu_bitmap));
+        app.context_menu_item.set_enabled(true);
+        app.pop_menu_item1.unregister_accelerator();
+        assert_eq!(app.pop_menu_item1.has_accelerator(), false);
+        app.window_menu_item1.set_text("Test item 1");
+        app.window_submenu1.set_text("Test &Submenu");
         app.runs.borrow_mut().menu = false;
     }
 }
@@ -1377,15 +2080,67 @@ fn run_text_tests(app: &ControlsTest, _evt: Event) {
         app.test_text_input.set_limit(32);
         assert_eq!(app.test_text_input.limit(), 32);
 
+        assert_eq!(app.test_text_input.numbers_only(), false);
+        app.test_text_input.set_numbers_only(true);
+        assert_eq!(app.test_text_input.numbers_only(), true);
+        app.test_text_input.set_numbers_only(false);
+
+        {
+            use winapi::um::winuser::WM_CHAR;
+            use crate::win32::window_helper as wh;
+
+            let hwnd = app.test_text_input.handle.hwnd().unwrap();
+            app.test_text_input.set_validator(|text| text.chars().all(|c| c.is_ascii_digit()));
+            app.test_text_input.set_text("12");
+            app.test_text_input.set_caret(2);
+
+            // Typed characters rejected by the validator never reach the control
+            wh::send_message(hwnd, WM_CHAR, 'a' as usize, 0);
+            assert_eq!(&app.test_text_input.text(), "12");
+
+            wh::send_message(hwnd, WM_CHAR, '3' as usize, 0);
+            assert_eq!(&app.test_text_input.text(), "123");
+
+            app.test_text_input.clear_validator();
+            wh::send_message(hwnd, WM_CHAR, 'a' as usize, 0);
+            assert_eq!(&app.test_text_input.text(), "123a");
+
+            app.test_text_input.set_text("New Text");
+        }
+
+        assert_eq!(app.test_auto_complete.source(), AutoCompleteSource::Items);
+        assert_eq!(app.test_auto_complete.items(), vec!["Hello World!".to_string(), "Hello Rust!".to_string()]);
+        app.test_auto_complete.set_items(&["New Text", "New Items"]);
+        assert_eq!(app.test_auto_complete.items().len(), 2);
+        assert_eq!(app.test_auto_complete.flags(), AutoCompleteFlags::SUGGEST | AutoCompleteFlags::APPEND);
+        app.test_auto_complete.set_flags(AutoCompleteFlags::SUGGEST);
+        assert_eq!(app.test_auto_complete.flags(), AutoCompleteFlags::SUGGEST);
+        assert_eq!(app.test_auto_complete.dropdown_visible(), false);
+
         assert_eq!(app.test_text_input.password_char(), None);
         app.test_text_input.set_password_char(Some('X'));
         assert_eq!(app.test_text_input.password_char(), Some('X'));
 
+        app.test_text_input.set_password_visible(true);
+        assert_eq!(app.test_text_input.password_visible(), true);
+        assert_eq!(app.test_text_input.password_char(), None);
+        app.test_text_input.set_password_visible(false);
+        assert_eq!(app.test_text_input.password_visible(), false);
+        assert_eq!(app.test_text_input.password_char(), Some('X'));
+
+        app.test_text_input.empty_undo_buffer();
+        assert_eq!(app.test_text_input.can_undo(), false);
+
         app.test_text_input.set_modified(true);
         assert_eq!(app.test_text_input.modified(), true);
 
         app.test_text_input.set_selection(0..4);
         assert_eq!(app.test_text_input.selection(), 0..4);
+        assert_eq!(&app.test_text_input.selected_text(), "New ");
+
+        app.test_text_input.set_caret(2);
+        assert_eq!(app.test_text_input.caret(), 2);
+        assert_eq!(app.test_text_input.selection(), 2..2);
 
         assert_eq!(app.test_text_input.len(), 8);
 
@@ -1407,7 +2162,49 @@ fn run_text_tests(app: &ControlsTest, _evt: Event) {
         app.test_text_input.set_enabled(false);
         assert_eq!(app.test_text_input.enabled(), false);
 
+        assert_eq!(app.test_text_box.line_count(), 3);
+        assert_eq!(&app.test_text_box.line(1), "Line");
+        assert_eq!(app.test_text_box.line_index(1), 7);
+        assert_eq!(app.test_text_box.line_from_char(8), 1);
+        assert_eq!(app.test_text_box.first_visible_line(), 0);
+        assert_eq!(app.test_text_box.word_wrap(), false);
+        app.test_text_box.set_selection(7..11);
+        assert_eq!(&app.test_text_box.selected_text(), "Line");
+        app.test_text_box.set_caret(0);
+        assert_eq!(app.test_text_box.caret(), 0);
+        app.test_text_box.scroll_to_line(2);
+
+        let data = app.test_number_select.data();
+        assert_eq!(data.parse("12.25").map(|d| d.formatted_value()), Some("12.25".to_string()));
+        assert!(data.parse("Not a number").is_none());
+        assert!(data.parse("10000000").is_none());
+
         app.test_text_input.set_placeholder_text(Some("Placeholder!"));
+        assert_eq!(&app.test_text_input.placeholder_text(64), "Placeholder!");
+        assert_eq!(&app.test_text_input.placeholder_text(5), "Place");
+
+        assert_eq!(app.test_label.h_align(), HTextAlign::Left);
+        assert_eq!(app.test_label.v_align(), VTextAlign::Center);
+        assert_eq!(app.test_label.word_wrap(), true);
+        assert_eq!(app.test_label.ellipsis(), LabelEllipsis::None);
+        assert_eq!(app.test_label.prefix(), false);
+
+        app.test_label.set_word_wrap(false);
+        assert_eq!(app.test_label.word_wrap(), false);
+        app.test_label.set_h_align(HTextAlign::Left);
+        assert_eq!(app.test_label.word_wrap(), false);
+        app.test_label.set_h_align(HTextAlign::Right);
+        assert_eq!(app.test_label.h_align(), HTextAlign::Right);
+        assert_eq!(app.test_label.word_wrap(), true);
+
+        app.test_label.set_v_align(VTextAlign::Top);
+        assert_eq!(app.test_label.v_align(), VTextAlign::Top);
+
+        app.test_label.set_ellipsis(LabelEllipsis::Path);
+        assert_eq!(app.test_label.ellipsis(), LabelEllipsis::Path);
+
+        app.test_label.set_prefix(true);
+        assert_eq!(app.test_label.prefix(), true);
 
         app.runs.borrow_mut().text = true;
     } else {
@@ -1415,6 +2212,10 @@ fn run_text_tests(app: &ControlsTest, _evt: Event) {
         app.test_text_input.set_enabled(true);
         app.test_text_input.set_readonly(false);
         app.test_text_input.set_password_char(None);
+        app.test_label.set_h_align(HTextAlign::Left);
+        app.test_label.set_v_align(VTextAlign::Center);
+        app.test_label.set_ellipsis(LabelEllipsis::None);
+        app.test_label.set_prefix(false);
         app.runs.borrow_mut().text = false;
     }
 }
@@ -1465,6 +2266,16 @@ fn run_track_tests(app: &ControlsTest, _evt: Event) {
         app.test_track2.set_range_max(5);
         app.test_track2.set_selection_range_pos(0..3);
         assert_eq!(app.test_track2.selection_range_pos(), 0..3);
+        app.test_track2.clear_selection_range();
+        assert_eq!(app.test_track2.selection_range_pos(), 0..0);
+
+        app.test_track1.set_tics(&[2, 5, 7]);
+        assert_eq!(app.test_track1.tics(), vec![2, 5, 7]);
+        app.test_track1.clear_tics();
+        assert_eq!(app.test_track1.tics(), Vec::<usize>::new());
+
+        assert!(app.test_track1.buddy(TrackBarBuddySide::Start).is_none());
+        assert!(app.test_track1.buddy(TrackBarBuddySide::End).is_none());
 
         app.runs.borrow_mut().track = true;
     } else {
@@ -1480,10 +2291,23 @@ fn run_tooltip_tests(app: &ControlsTest, _evt: Event) {
         app.test_ttp1.set_delay_time(Some(100));
         assert_eq!(app.test_ttp1.delay_time(), 100);
 
+        app.test_ttp1.set_reshow_delay(Some(50));
+        app.test_ttp1.set_autopop_delay(Some(20000));
+        assert_eq!(app.test_ttp1.reshow_delay(), 50);
+        assert_eq!(app.test_ttp1.autopop_delay(), 20000);
+
+        assert_eq!(app.test_ttp1.max_width(), None);
+        app.test_ttp1.set_max_width(Some(200));
+        assert_eq!(app.test_ttp1.max_width(), Some(200));
+        app.test_ttp1.set_max_width(None);
+        assert_eq!(app.test_ttp1.max_width(), None);
+
         app.test_ttp1.register(&app.test_checkbox1, "A simple checkbox");
         app.test_ttp1.register(&app.test_checkbox2, "A checkbox with 3 states!");
 
         app.test_ttp3.set_default_decoration("Changed!", TooltipIcon::None);
+        assert_eq!(app.test_ttp3.max_width(), Some(300));
+        assert_eq!(app.test_ttp3.autopop_delay(), 10000);
 
         app.test_ttp1.set_text(&app.test_button.handle, "New tool tip!");
         assert_eq!(&app.test_ttp1.text(&app.test_button.handle, None), "New tool tip!");
@@ -1493,7 +2317,9 @@ fn run_tooltip_tests(app: &ControlsTest, _evt: Event) {
         app.runs.borrow_mut().tooltip = true;
     } else {
         app.test_ttp1.register(&app.test_button, "A button");
+        app.test_ttp1.set_autopop_delay(None);
         app.test_ttp2.set_enabled(true);
+        app.test_ttp2.show();
         app.runs.borrow_mut().tooltip = false;
     }
 }
@@ -1520,7 +2346,32 @@ fn run_status_tests(app: &ControlsTest, _evt: Event) {
 }
 
 fn run_tray_tests(app: &ControlsTest) {
+    // Two icons of the same window have different handles
+    assert_eq!(app.tray_icon.handle.tray().map(|(h, _)| h), app.window.handle.hwnd());
+    assert_eq!(app.tray_icon_3.handle.tray().map(|(h, _)| h), app.window.handle.hwnd());
+    assert_ne!(app.tray_icon.handle, app.tray_icon_3.handle);
+
+    // The GUID is not used if it is registered to another path of the test executable
+    assert_eq!(app.tray_icon.guid(), None);
+    match app.tray_icon_3.guid() {
+        Some(guid) => assert_eq!(guid, 0x6b2c5d8e_3f41_4a97_b0c2_9e1d7a4f5c38),
+        None => {}
+    }
+
     app.tray_icon.set_visibility(false);
+    app.tray_icon_3.set_visibility(true);
+
+    // Icons that already exist are not added twice
+    let window = app.window.handle.hwnd().unwrap();
+    let recreated = crate::controls::recreate_tray_icons(window);
+    assert!(recreated.contains(&app.tray_icon.handle));
+    assert!(recreated.contains(&app.tray_icon_3.handle));
+    assert!(!recreated.contains(&app.tray_icon_2.handle));
+    // Frames of an animated icon
+    for icon in [&app.love_icon, &app.window_icon_small, &app.love_icon].iter() {
+        app.tray_icon_2.set_icon(icon);
+    }
+
     app.tray_icon_2.set_icon(&app.window_icon);
     app.tray_icon_2.set_tip("Changed the toolip and the icon!");
 
@@ -1530,6 +2381,14 @@ fn run_tray_tests(app: &ControlsTest) {
     app.tray_icon_2.show("OH NO!", Some("Just a title"), flags, icon);
     app.tray_icon_2.show("I'm spamming the system tray popup!", Some("Just a title"), flags, icon);
     app.tray_icon_2.show("You can't stop me!!!!!", Some("Just a title (really)"), flags, Some(&app.window_icon));
+
+    let flags = Some(TrayNotificationFlags::USER_ICON | TrayNotificationFlags::QUIET | TrayNotificationFlags::REALTIME);
+    let long_title = "A title that is way too long to fit in the notification popup and that will be truncated";
+    app.tray_icon_2.show("Shown now or never", Some(long_title), flags, None);
+
+    // Falls back to the balloon of `tray_icon_2` if toasts are not supported
+    let buttons = [ToastButton { text: "Yes", arguments: "yes" }, ToastButton { text: "No & cancel", arguments: "no" }];
+    app.toast.show("Toast <test>", "A toast notification with buttons", None, &buttons).expect("Toast has a fallback");
 }
 
 fn set_tooltip_dynamic<'a>(app: &ControlsTest, handle: &ControlHandle, data: &ToolTipTextData) {
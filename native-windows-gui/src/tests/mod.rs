@@ -172,12 +172,839 @@ fn show_freeing_test(app: &TestControlPanel) {
     app.freeing_tests.window.set_focus();
 }
 
+/// Restores a global state changed by a test when it is dropped, even if an assertion of the test fails
+struct Restore<F: FnMut()>(F);
+
+impl<F: FnMut()> Drop for Restore<F> {
+    fn drop(&mut self) {
+        (self.0)();
+    }
+}
+
+/// Builds a hidden top level window for the tests that need their own window
+fn test_window() -> Window {
+    let mut window = Window::default();
+    Window::builder().flags(WindowFlags::WINDOW).build(&mut window).unwrap();
+    window
+}
+
 fn show(app: &TestControlPanel) {
+    run_clipboard_tests(app);
+    run_shell_tests();
+    run_system_tests();
+    run_keyboard_tests();
+    run_cursor_tests(app);
+    run_error_tests(app);
+    run_event_handler_tests();
+    run_owner_draw_tests();
+    run_handle_tests(app);
+    run_window_tests(app);
+    run_thread_init_tests();
+
+    #[cfg(feature = "single-instance")]
+    run_single_instance_tests();
+
+    #[cfg(feature = "recovery")]
+    run_recovery_tests();
+
+    #[cfg(feature = "settings")]
+    run_settings_tests();
+
+    #[cfg(feature = "app")]
+    run_app_tests();
+
+    #[cfg(feature = "binding")]
+    run_binding_tests();
+
+    #[cfg(feature = "style")]
+    run_style_tests();
+
+    #[cfg(feature = "loader")]
+    run_loader_tests();
+
+    #[cfg(feature = "number-select")]
+    run_number_select_tests();
+
+    #[cfg(feature = "media-player")]
+    run_media_player_subclass_tests();
+
+    #[cfg(feature = "textbox")]
+    run_recreate_control_tests();
+
+    #[cfg(feature = "menu")]
+    run_accelerator_tests();
+
+    app.window.set_visible(true);
+}
+
+fn run_clipboard_tests(app: &TestControlPanel) {
+    use std::{rc::Rc, cell::Cell};
+
+    // The text copied by the user is put back after the tests
+    let previous_text = Clipboard::data_text(&app.window);
+    let _restore = Restore(|| match previous_text.as_ref() {
+        Some(text) => Clipboard::set_data_text(&app.window, text),
+        None => Clipboard::clear::<&Window>()
+    });
+
     let text = "Hello World from Native windows GUI!";
     Clipboard::set_data_text(&app.window, text);
     assert!(Some(text) == Clipboard::data_text(&app.window).as_ref().map(|s| s as &str));
 
-    app.window.set_visible(true);
+    let bitmap = Bitmap::from_system(OemBitmap::Close);
+    Clipboard::set_data_bitmap(&app.window, &bitmap).unwrap();
+    assert!(Clipboard::data_bitmap(&app.window).is_some());
+    assert!(Clipboard::data_bytes(&app.window, ClipboardFormat::Dib).is_some());
+
+    let files = ["C:\\Windows\\notepad.exe", "C:\\Windows\\explorer.exe"];
+    Clipboard::set_data_files(&app.window, &files).unwrap();
+    assert_eq!(Clipboard::data_files(&app.window), Some(files.iter().map(|f| f.to_string()).collect()));
+    assert_eq!(Clipboard::data_text(&app.window), None);
+
+    let format = Clipboard::register_format("NativeWindowsGui.Tests");
+    assert_eq!(Clipboard::format_name(format), Some("NativeWindowsGui.Tests".to_string()));
+    assert_eq!(Clipboard::format_name(ClipboardFormat::UnicodeText), None);
+
+    Clipboard::set_data_bytes(&app.window, format, &[1, 2, 3]).unwrap();
+    assert_eq!(Clipboard::data_bytes(&app.window, format).map(|d| d[..3].to_vec()), Some(vec![1, 2, 3]));
+    assert_eq!(Clipboard::formats(&app.window).len(), 1);
+
+    // Delayed rendering. The data is only rendered once, when it is first read.
+    let renders = Rc::new(Cell::new(0));
+    let renders_count = renders.clone();
+    Clipboard::set_data_delayed(&app.window, &[format], move |_fmt| {
+        renders_count.set(renders_count.get() + 1);
+        Some(vec![4, 5, 6])
+    }).unwrap();
+
+    assert_eq!(renders.get(), 0);
+    assert_eq!(Clipboard::data_bytes(&app.window, format).map(|d| d[..3].to_vec()), Some(vec![4, 5, 6]));
+    assert_eq!(Clipboard::data_bytes(&app.window, format).map(|d| d[..3].to_vec()), Some(vec![4, 5, 6]));
+    assert_eq!(renders.get(), 1);
+    assert!(Clipboard::set_data_delayed(&app.window, &[], |_fmt| None).is_err());
+}
+
+fn run_shell_tests() {
+    assert!(open_url("C:\\Windows\\notepad.exe").is_err());
+    assert!(open_url("no scheme").is_err());
+    assert!(open_file_with_default_app("C:\\this_file_does_not_exist.txt").is_err());
+    assert!(reveal_in_explorer("C:\\this_file_does_not_exist.txt").is_err());
+}
+
+fn run_system_tests() {
+    use winapi::um::winuser::{GetSysColor, COLOR_WINDOWTEXT};
+    use winapi::um::wingdi::RGB;
+
+    let [r, g, b] = system_color(SystemColor::WindowText);
+    assert_eq!(RGB(r, g, b), unsafe { GetSysColor(COLOR_WINDOWTEXT) });
+    if !high_contrast() {
+        assert!(high_contrast_scheme().is_none());
+    }
+
+    assert!(Font::system_message_font().is_ok());
+    assert!(SystemMetrics::vertical_scrollbar_width() > 0);
+    assert!(SystemMetrics::caption_height() > 0);
+    assert!(SystemMetrics::small_icon_size()[0] <= SystemMetrics::icon_size()[0]);
+
+    let info = os_info();
+    assert!(info.major >= 6 && info.build > 0);
+    assert!(info.common_controls.0 >= 5);
+    assert_eq!(info, os_info());
+    assert!(!info.supports_mica() || info.is_windows_11());
+
+    let [vl, vt, vr, vb] = Screen::virtual_rect();
+    let [l, t, r, b] = Screen::work_area();
+    assert!(vl <= l && vt <= t && r <= vr && b <= vb);
+    assert!(r - l <= Screen::width() && b - t <= Screen::height());
+
+    let [l, t, r, b] = Screen::work_area_from_cursor();
+    let [ml, mt, mr, mb] = Screen::monitor_rect_from_cursor();
+    assert!(ml <= l && mt <= t && r <= mr && b <= mb);
+
+    assert_eq!(Screen::centered_position((100, 50), [0, 0, 300, 150]), (100, 50));
+    assert_eq!(Screen::centered_position((400, 50), [10, 10, 300, 150]), (10, 60));
+}
+
+fn run_keyboard_tests() {
+    use winapi::um::winuser::{GetKeyboardState, SetKeyboardState};
+
+    let mut saved = [0u8; 256];
+    unsafe { GetKeyboardState(saved.as_mut_ptr()); }
+    let _restore = Restore(move || unsafe { SetKeyboardState(saved.as_mut_ptr()); });
+
+    let mut state = saved;
+    state[keys::SHIFT as usize] = 0x80;
+    state[keys::CONTROL as usize] = 0x80;
+    state[keys::ALT as usize] = 0;
+    state[keys::CAPITAL as usize] ^= 1;
+    unsafe { SetKeyboardState(state.as_mut_ptr()); }
+
+    assert!(Keyboard::is_key_down(Key::Shift));
+    assert!(!Keyboard::is_key_down(Key::Alt));
+    assert_eq!(Keyboard::modifiers() & !KeyModifiers::WIN, KeyModifiers::SHIFT | KeyModifiers::CONTROL);
+    assert_eq!(Keyboard::caps_lock(), saved[keys::CAPITAL as usize] & 1 == 0);
+
+    assert_eq!(Key::from(keys::_S), Key::S);
+    assert_eq!(Key::from(keys::F12), Key::F12);
+    assert_eq!(Key::from(keys::OEM_PLUS), Key::Other(keys::OEM_PLUS));
+    assert_eq!(u32::from(Key::PageUp), keys::PRIOR);
+    assert_eq!(u32::from(Key::Other(keys::OEM_PLUS)), keys::OEM_PLUS);
+}
+
+fn run_cursor_tests(app: &TestControlPanel) {
+    let count = GlobalCursor::hide();
+    assert_eq!(GlobalCursor::show(), count + 1);
+
+    let _release = Restore(GlobalCursor::release);
+    GlobalCursor::set_capture(&app.window.handle);
+    assert_eq!(GlobalCursor::capture(), Some(app.window.handle));
+    GlobalCursor::release();
+    assert_eq!(GlobalCursor::capture(), None);
+
+    // A clip set by another application is put back after the tests
+    let [vl, vt, vr, vb] = Screen::virtual_rect();
+    let previous_clip = GlobalCursor::clip_area();
+    let _restore = Restore(|| match previous_clip == [vl, vt, vr, vb] {
+        true => GlobalCursor::unclip(),
+        false => GlobalCursor::clip_rect(previous_clip)
+    });
+
+    GlobalCursor::clip_rect([vl + 1, vt + 1, vr - 1, vb - 1]);
+    assert_eq!(GlobalCursor::clip_area(), [vl + 1, vt + 1, vr - 1, vb - 1]);
+    GlobalCursor::unclip();
+    assert_eq!(GlobalCursor::clip_area(), [vl, vt, vr, vb]);
+}
+
+fn run_error_tests(app: &TestControlPanel) {
+    use crate::win32::window::build_hwnd_control;
+    use winapi::shared::winerror::ERROR_CANNOT_FIND_WND_CLASS;
+
+    let error = unsafe { build_hwnd_control("NWG_NOT_A_CLASS", None, None, None, None, None, 0, None) }.unwrap_err();
+    assert_eq!(error.os_code(), Some(ERROR_CANNOT_FIND_WND_CLASS));
+    assert!(format!("{}", error).contains("NWG_NOT_A_CLASS"));
+
+    match error {
+        NwgError::WindowCreationError { class_name, parent, .. } => {
+            assert_eq!(&class_name, "NWG_NOT_A_CLASS");
+            assert_eq!(parent, None);
+        },
+        e => panic!("Unexpected error: {:?}", e)
+    }
+
+    let button = Button::default();
+    assert!(button.try_text().is_err());
+    assert!(button.try_set_enabled(false).is_err());
+    assert!(app.window.try_visible().is_ok());
+
+    // Controls used from another thread are an error in debug builds
+    #[cfg(debug_assertions)]
+    {
+        use crate::win32::base_helper::{check_hwnd, try_check_hwnd};
+
+        let hwnd = app.window.handle.hwnd().unwrap() as usize;
+        let result = std::thread::spawn(move || {
+            try_check_hwnd(&ControlHandle::Hwnd(hwnd as _), "", "").is_err()
+        }).join();
+
+        assert_eq!(result.ok(), Some(true));
+
+        let result = std::thread::spawn(move || {
+            check_hwnd(&ControlHandle::Hwnd(hwnd as _), "", "");
+        }).join();
+
+        assert!(result.is_err());
+    }
+}
+
+fn run_event_handler_tests() {
+    use winapi::um::winuser::{SendMessageW, WM_MOUSEMOVE, WM_USER};
+    use std::{rc::Rc, cell::Cell};
+
+    // Destroying the window frees the callbacks
+    let captured = Rc::new(());
+    let mut window = test_window();
+
+    let c = captured.clone();
+    let _raw = bind_raw_event_handler(&window.handle, 0x10000, move |_, _, _, _| { let _ = &c; None }).unwrap();
+    let c = captured.clone();
+    let _handler = full_bind_event_handler(&window.handle, move |_, _, _| { let _ = &c; });
+    assert_eq!(Rc::strong_count(&captured), 3);
+
+    window.handle.destroy();
+    assert_eq!(Rc::strong_count(&captured), 1);
+
+    // A callback that destroys its own window is freed once it returns
+    let window = test_window();
+    let moves = Rc::new(Cell::new(0));
+    let m = moves.clone();
+    let handle = window.handle;
+    let _handler = full_bind_event_handler(&window.handle, move |evt, _, _| {
+        if evt == Event::OnMouseMove {
+            let mut handle = handle;
+            handle.destroy();
+            m.set(m.get() + 1);
+        }
+    });
+
+    unsafe { SendMessageW(window.handle.hwnd().unwrap(), WM_MOUSEMOVE, 0, 0); }
+    assert_eq!(moves.get(), 1);
+    assert_eq!(Rc::strong_count(&moves), 1);
+
+    // Raw event handler ids are unique for a window
+    let mut window = test_window();
+    let handler = bind_raw_event_handler(&window.handle, 0x10000, |_, _, _, _| None).unwrap();
+    assert!(bind_raw_event_handler(&window.handle, 0x10000, |_, _, _, _| None).is_err());
+    assert!(unbind_raw_event_handler(&handler).is_ok());
+    assert!(unbind_raw_event_handler(&handler).is_err());
+
+    let handle = window.handle;
+    window.handle.destroy();
+    assert!(bind_raw_event_handler(&handle, 0x10000, |_, _, _, _| None).is_err());
+
+    // Panics in the event handlers are sent to the panic handler
+    let window = test_window();
+    let _handler = bind_raw_event_handler(&window.handle, 0x10000, |_, msg, _, _| {
+        if msg == WM_USER + 10 { panic!("Panic in an event handler"); }
+        None
+    }).unwrap();
+
+    let _restore = Restore(clear_event_panic_handler);
+    let caught = Rc::new(Cell::new(false));
+    let caught2 = caught.clone();
+    set_event_panic_handler(move |message| caught2.set(message == "Panic in an event handler"));
+
+    unsafe { SendMessageW(window.handle.hwnd().unwrap(), WM_USER + 10, 0, 0); }
+    assert!(caught.get());
+}
+
+fn run_owner_draw_tests() {
+    use winapi::um::winuser::{SendMessageW, GetDlgCtrlID, WM_DRAWITEM, DRAWITEMSTRUCT, ODT_BUTTON, GWLP_ID};
+    use crate::win32::{window::register_owner_draw, window_helper as wh};
+    use std::{rc::Rc, cell::Cell, mem};
+
+    let window = test_window();
+
+    let mut owner_drawn = Button::default();
+    Button::builder().flags(ButtonFlags::VISIBLE | ButtonFlags::OWNER_DRAW).parent(&window).build(&mut owner_drawn).unwrap();
+
+    let mut button = Button::default();
+    Button::builder().parent(&window).build(&mut button).unwrap();
+
+    let drawn = Rc::new(Cell::new(None));
+    let d = drawn.clone();
+    let handler = full_bind_event_handler(&window.handle, move |evt, _, handle| {
+        if evt == Event::OnDrawItem { d.set(Some(handle)); }
+    });
+
+    let draw_item = |item: &Button| unsafe {
+        let mut draw: DRAWITEMSTRUCT = mem::zeroed();
+        draw.CtlType = ODT_BUTTON;
+        draw.hwndItem = item.handle.hwnd().unwrap();
+        SendMessageW(window.handle.hwnd().unwrap(), WM_DRAWITEM, 0, &mut draw as *mut DRAWITEMSTRUCT as _)
+    };
+
+    // Only the owner drawn controls built by NWG raise `OnDrawItem`. The other items are left to the window procedure.
+    assert_eq!(draw_item(&owner_drawn), 1);
+    assert_eq!(drawn.take(), Some(owner_drawn.handle));
+    assert_eq!(draw_item(&button), 0);
+    assert_eq!(drawn.take(), None);
+
+    // The id of the control is kept if it has one
+    let hwnd = button.handle.hwnd().unwrap();
+    wh::set_window_long(hwnd, GWLP_ID, 1234);
+    register_owner_draw(hwnd);
+    assert_eq!(unsafe { GetDlgCtrlID(hwnd) }, 1234);
+    assert_ne!(unsafe { GetDlgCtrlID(owner_drawn.handle.hwnd().unwrap()) }, 0);
+
+    unbind_event_handler(&handler);
+}
+
+fn run_handle_tests(app: &TestControlPanel) {
+    use winapi::um::winuser::DestroyWindow;
+
+    let mut window = test_window();
+    let handle = window.handle;
+    let weak = window.handle.downgrade();
+    assert!(handle.is_valid());
+    assert_eq!(weak.upgrade(), Some(handle));
+
+    window.handle.destroy();
+    assert!(!handle.is_valid());
+    assert!(weak.upgrade().is_none());
+    assert!(!ControlHandle::NoHandle.is_valid());
+
+    let handle = app.window.handle;
+    let _restore = Restore(|| while handle.frozen() { handle.thaw(); });
+    handle.freeze();
+    {
+        let _guard = handle.freeze_guard();
+        assert!(handle.frozen());
+    }
+
+    assert!(handle.frozen());
+    handle.thaw();
+    assert!(!handle.frozen());
+
+    // A window destroyed while frozen is forgotten
+    let mut window = test_window();
+    let handle = window.handle;
+    handle.freeze();
+    assert!(handle.frozen());
+
+    unsafe { DestroyWindow(handle.hwnd().unwrap()); }
+    window.handle = ControlHandle::NoHandle;
+    assert!(!handle.frozen());
+
+    // The back buffer is released when the window is destroyed
+    let mut window = test_window();
+    let handle = window.handle;
+    handle.set_double_buffered(true);
+    assert!(handle.double_buffered());
+
+    unsafe { DestroyWindow(handle.hwnd().unwrap()); }
+    window.handle = ControlHandle::NoHandle;
+    assert!(!handle.double_buffered());
+}
+
+fn run_window_tests(app: &TestControlPanel) {
+    use winapi::um::winuser::{GetWindowLongW, GWL_EXSTYLE, WS_EX_COMPOSITED};
+    use crate::win32::base_helper::{with_utf16, from_utf16};
+
+    // Composited painting is opt-in
+    let ex_style = |window: &Window| unsafe { GetWindowLongW(window.handle.hwnd().unwrap(), GWL_EXSTYLE) as u32 };
+    assert_eq!(ex_style(&app.window) & WS_EX_COMPOSITED, 0);
+
+    let mut window = Window::default();
+    Window::builder().flags(WindowFlags::WINDOW).composited(true).build(&mut window).unwrap();
+    assert_eq!(ex_style(&window) & WS_EX_COMPOSITED, WS_EX_COMPOSITED);
+
+    // Nested conversions do not reuse the buffer of the outer conversion
+    let nested = with_utf16("outer", |outer| {
+        let inner = with_utf16("inner", |inner| from_utf16(inner));
+        (from_utf16(outer), inner)
+    });
+    assert_eq!(nested, ("outer".to_string(), "inner".to_string()));
+
+    let _restore = Restore(|| app.window.set_text("Tests Control Panel"));
+    let long_text = "Hello ".repeat(1000);
+    app.window.set_text(&long_text);
+    assert_eq!(app.window.text(), long_text);
+    app.window.set_text("Tests Control Panel");
+    assert_eq!(app.window.text(), "Tests Control Panel");
+}
+
+fn run_thread_init_tests() {
+    // Each GUI thread initializes NWG
+    let result = std::thread::spawn(|| {
+        init().expect("Failed to init NWG in another thread");
+        let mut window = Window::default();
+        Window::builder().flags(WindowFlags::WINDOW).build(&mut window).is_ok()
+    }).join();
+
+    assert_eq!(result.ok(), Some(true));
+
+    // The other control classes are registered when the first control using them is created
+    let result = std::thread::spawn(|| {
+        init_with(ControlClasses::STANDARD).expect("Failed to init NWG in another thread");
+        let window = test_window();
+
+        let mut status = StatusBar::default();
+        StatusBar::builder().parent(&window).build(&mut status).is_ok()
+    }).join();
+
+    assert_eq!(result.ok(), Some(true));
+    assert_eq!(ControlClasses::from_class_name("SysTreeView32"), ControlClasses::TREE_VIEW);
+    assert_eq!(ControlClasses::from_class_name("BUTTON"), ControlClasses::empty());
+    assert!(ControlClasses::enabled().contains(ControlClasses::STANDARD));
+}
+
+#[cfg(feature = "single-instance")]
+fn run_single_instance_tests() {
+    use std::{rc::Rc, cell::RefCell};
+
+    let first = SingleInstance::acquire("NativeWindowsGui.Tests").unwrap();
+    let second = SingleInstance::acquire("NativeWindowsGui.Tests").unwrap();
+    assert!(first.is_first());
+    assert!(!second.is_first());
+    assert!(second.forward(&["a"]).is_err());
+
+    let window = test_window();
+    first.listen(&window);
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let r = received.clone();
+    let handler = full_bind_event_handler(&window.handle, move |evt, evt_data, _| {
+        if evt == Event::OnInstanceArgs {
+            r.borrow_mut().push(evt_data.on_instance_args().to_vec());
+        }
+    });
+
+    second.forward(&["app.exe", "", "é.txt"]).unwrap();
+    second.forward::<&str>(&[]).unwrap();
+    assert_eq!(*received.borrow(), vec![vec!["app.exe".to_string(), String::new(), "é.txt".to_string()], Vec::new()]);
+
+    unbind_event_handler(&handler);
+}
+
+#[cfg(feature = "recovery")]
+fn run_recovery_tests() {
+    use std::{rc::Rc, cell::Cell};
+    use crate::win32::{recovery::NWG_RECOVERY, window_helper as wh};
+
+    // The test process must not be restarted or recovered by Windows
+    let _restore = Restore(|| {
+        let _ = unregister_application_restart();
+        let _ = unregister_application_recovery();
+    });
+
+    assert!(register_application_restart(&"a".repeat(1024), RestartFlags::NONE).is_err());
+    register_application_restart("--restore", RestartFlags::NO_REBOOT).unwrap();
+    unregister_application_restart().unwrap();
+
+    let window = test_window();
+
+    let recoveries = Rc::new(Cell::new(0));
+    let r = recoveries.clone();
+    let handler = full_bind_event_handler(&window.handle, move |evt, _, _| {
+        if evt == Event::OnRecovery { r.set(r.get() + 1); }
+    });
+
+    register_application_recovery(&window).unwrap();
+    wh::send_message(window.handle.hwnd().unwrap(), *NWG_RECOVERY, 0, 0);
+    assert_eq!(recoveries.get(), 1);
+
+    unregister_application_recovery().unwrap();
+    wh::send_message(window.handle.hwnd().unwrap(), *NWG_RECOVERY, 0, 0);
+    assert_eq!(recoveries.get(), 2);
+
+    unbind_event_handler(&handler);
+}
+
+#[cfg(feature = "settings")]
+fn run_settings_tests() {
+    use winapi::um::commctrl::LVM_GETCOLUMNORDERARRAY;
+    use crate::win32::window_helper as wh;
+
+    // The registry key is deleted after the settings are closed
+    let _restore = Restore(|| { let _ = Settings::delete("NativeWindowsGui.Tests"); });
+
+    let settings = Settings::open("NativeWindowsGui.Tests").unwrap();
+    assert_eq!(settings.value("missing"), None);
+    settings.set_value("text", "Hello é").unwrap();
+    assert_eq!(settings.value("text"), Some("Hello é".to_string()));
+    settings.remove_value("text").unwrap();
+    settings.remove_value("text").unwrap();
+    assert_eq!(settings.value("text"), None);
+
+    let mut window = Window::default();
+    Window::builder().flags(WindowFlags::WINDOW).position((120, 130)).size((300, 200)).build(&mut window).unwrap();
+    assert!(!settings.restore_window("window", &window));
+    settings.save_window("window", &window).unwrap();
+    window.set_position(200, 200);
+    assert!(settings.restore_window("window", &window));
+    assert_eq!(window.position(), (120, 130));
+
+    let mut list = ListView::default();
+    ListView::builder().parent(&window).list_style(ListViewStyle::Detailed).build(&mut list).unwrap();
+    for &column in &["A", "B", "C"] {
+        list.insert_column(column);
+    }
+
+    list.set_column_width(1, 77);
+    settings.save_list_view_columns("columns", &list).unwrap();
+    list.set_column_width(1, 10);
+    assert!(settings.restore_list_view_columns("columns", &list));
+    assert_eq!(list.column(1, 10).map(|c| c.width), Some(77));
+
+    let mut order = [0i32; 3];
+    wh::send_message(list.handle.hwnd().unwrap(), LVM_GETCOLUMNORDERARRAY, 3, order.as_mut_ptr() as _);
+    assert_eq!(order, [0, 1, 2]);
+
+    list.insert_column("D");
+    assert!(!settings.restore_list_view_columns("columns", &list));
+
+    drop(settings);
+    Settings::delete("NativeWindowsGui.Tests").unwrap();
+    Settings::delete("NativeWindowsGui.Tests").unwrap();
+}
+
+#[cfg(feature = "app")]
+fn run_app_tests() {
+    use std::{rc::Rc, cell::Cell};
+
+    enum Msg { Add(u32), Rename }
+
+    let counter = App::new((0u32, "a"), |model: &mut (u32, &str), msg| match msg {
+        Msg::Add(v) => { model.0 += v; },
+        Msg::Rename => { model.1 = "b"; },
+    });
+
+    // Views are only refreshed when their part of the model changes
+    let refreshes = Rc::new(Cell::new(0));
+    let r = refreshes.clone();
+    counter.view(|model| model.0, move |_| r.set(r.get() + 1));
+    assert_eq!(refreshes.get(), 1);
+
+    counter.send(Msg::Add(2));
+    counter.send(Msg::Rename);
+    assert_eq!(*counter.model(), (2, "b"));
+    assert_eq!(refreshes.get(), 2);
+
+    // Messages sent by a view are processed after the current message
+    let c = counter.clone();
+    counter.view(|model| model.0 == 3, move |&three| if three { c.send(Msg::Add(10)); });
+    counter.send(Msg::Add(1));
+    assert_eq!(counter.model().0, 13);
+}
+
+#[cfg(feature = "binding")]
+fn run_binding_tests() {
+    let window = test_window();
+    let mut input = TextInput::default();
+    let mut check = CheckBox::default();
+    TextInput::builder().parent(&window).build(&mut input).unwrap();
+    CheckBox::builder().parent(&window).build(&mut check).unwrap();
+
+    let count = Observable::new(10u32);
+    let checked = Observable::new(true);
+    let text_binding = count.bind_text_two_way(&input).unwrap();
+    let check_binding = checked.bind_checked(&check);
+    assert_eq!(input.text(), "10");
+    assert_eq!(check.check_state(), CheckBoxState::Checked);
+
+    count.set(20);
+    checked.set(false);
+    assert_eq!(input.text(), "20");
+    assert_eq!(check.check_state(), CheckBoxState::Unchecked);
+
+    // Edits are written back, unless they cannot be parsed
+    input.set_text("42");
+    assert_eq!(count.get(), 42);
+    input.set_text("Hello");
+    assert_eq!(count.get(), 42);
+
+    text_binding.unbind();
+    check_binding.unbind();
+    count.set(1);
+    assert_eq!(input.text(), "Hello");
+
+    // Typed text is not rewritten when it already holds the value
+    use winapi::um::winuser::WM_CHAR;
+    use crate::win32::window_helper as wh;
+
+    let ratio = Observable::new(12.0f64);
+    let ratio_binding = ratio.bind_text_two_way(&input).unwrap();
+    assert_eq!(input.text(), "12");
+
+    let hwnd = input.handle.hwnd().unwrap();
+    input.set_selection(1..2);
+    wh::send_message(hwnd, WM_CHAR, '.' as _, 0);
+    assert_eq!(ratio.get(), 1.0);
+    assert_eq!(input.text(), "1.");
+
+    wh::send_message(hwnd, WM_CHAR, '5' as _, 0);
+    assert_eq!(ratio.get(), 1.5);
+    assert_eq!(input.text(), "1.5");
+    assert_eq!(input.selection(), 3..3);
+
+    ratio.set(2.25);
+    assert_eq!(input.text(), "2.25");
+
+    ratio_binding.unbind();
+}
+
+#[cfg(feature = "style")]
+fn run_style_tests() {
+    use winapi::um::winuser::{GetDC, ReleaseDC, EM_GETMARGINS, WM_CTLCOLOREDIT};
+    use winapi::um::wingdi::{GetTextColor, RGB};
+    use winapi::shared::minwindef::{LOWORD, HIWORD};
+    use crate::win32::window_helper as wh;
+
+    let window = test_window();
+    let mut input = TextInput::default();
+    let mut font = Font::default();
+    TextInput::builder().parent(&window).build(&mut input).unwrap();
+    Font::builder().family("Arial").size(20).build(&mut font).unwrap();
+
+    let mut style = Style::default();
+    Style::builder()
+        .font(Some(&font))
+        .text_color(Some([200, 10, 10]))
+        .padding(Some([4, 6]))
+        .build(&mut style);
+    style.apply_all(&window).unwrap();
+
+    let input_handle = input.handle.hwnd().unwrap();
+    assert_eq!(wh::get_window_font(input_handle), font.handle);
+
+    let margins = wh::send_message(input_handle, EM_GETMARGINS as u32, 0, 0) as u32;
+    assert_eq!((LOWORD(margins), HIWORD(margins)), (4, 6));
+
+    // The colors are kept after the style is dropped
+    drop(style);
+    unsafe {
+        let hdc = GetDC(input_handle);
+        wh::send_message(window.handle.hwnd().unwrap(), WM_CTLCOLOREDIT, hdc as _, input_handle as _);
+        assert_eq!(GetTextColor(hdc), RGB(200, 10, 10));
+        ReleaseDC(input_handle, hdc);
+    }
+}
+
+#[cfg(feature = "loader")]
+fn run_loader_tests() {
+    let source = r#"
+        [[control]]
+        name = "window"
+        type = "Window"
+        title = "Loaded"
+        flags = ["WINDOW"]
+
+        [[control]]
+        name = "check"
+        type = "CheckBox"
+        parent = "window"
+        text = "Check me"
+        checked = true
+
+        [[layout]]
+        type = "GridLayout"
+        parent = "window"
+        children = [{ control = "check", col = 0, row = 0 }]
+    "#;
+
+    let ui = loader::load_str(source).unwrap();
+    let check = ui.handle("check").unwrap();
+    assert_eq!(ui.names().collect::<Vec<_>>(), vec!["window", "check"]);
+    assert_eq!(ui.name_of(check), Some("check"));
+    assert_eq!(ui.layouts().len(), 1);
+
+    match ui.control("check") {
+        Some(LoadedControl::CheckBox(c)) => assert_eq!(c.check_state(), CheckBoxState::Checked),
+        _ => panic!("check should be a CheckBox")
+    }
+
+    let unknown = "[[control]]\nname = \"a\"\ntype = \"Unknown\"";
+    assert!(matches!(loader::load_str(unknown), Err(NwgError::LoaderError(_))));
+
+    let orphan = "[[control]]\nname = \"b\"\ntype = \"Button\"";
+    assert!(matches!(loader::load_str(orphan), Err(NwgError::LoaderError(_))));
+}
+
+#[cfg(feature = "number-select")]
+fn run_number_select_tests() {
+    use winapi::um::winuser::FindWindowExW;
+    use crate::win32::{window_helper as wh, base_helper::to_utf16};
+    use std::{rc::Rc, cell::RefCell, ptr};
+
+    let window = test_window();
+
+    let mut number = NumberSelect::default();
+    NumberSelect::builder().value_float(1.0).decimals(2).max_float(100.0).parent(&window).build(&mut number).unwrap();
+
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let c = changes.clone();
+    let handler = full_bind_event_handler(&window.handle, move |evt, evt_data, handle| {
+        if evt == Event::OnNumberChanged {
+            c.borrow_mut().push((handle, evt_data.on_number_changed().formatted_value()));
+        }
+    });
+
+    // Editing the text raises `OnNumberChanged` with the parsed value
+    let class = to_utf16("Edit");
+    let edit = unsafe { FindWindowExW(number.handle.hwnd().unwrap(), ptr::null_mut(), class.as_ptr(), ptr::null()) };
+    unsafe { wh::set_window_text(edit, "12.25"); }
+    assert_eq!(*changes.borrow(), vec![(number.handle, "12.25".to_string())]);
+    assert_eq!(number.data().formatted_value(), "12.25");
+
+    // Invalid text and values outside of the limits are ignored
+    unsafe { wh::set_window_text(edit, "Not a number"); }
+    unsafe { wh::set_window_text(edit, "1000"); }
+    assert_eq!(changes.borrow().len(), 1);
+    assert_eq!(number.data().formatted_value(), "12.25");
+
+    unbind_event_handler(&handler);
+}
+
+#[cfg(feature = "media-player")]
+fn run_media_player_subclass_tests() {
+    use winapi::um::winuser::{GetWindow, GW_CHILD};
+    use crate::win32::{window_helper as wh, media_player::MCIWNDM_GETLENGTH};
+    use std::{rc::Rc, cell::Cell};
+
+    let window = test_window();
+
+    let mut player = MediaPlayer::default();
+    MediaPlayer::builder().parent(&window).build(&mut player).unwrap();
+
+    let stops = Rc::new(Cell::new(0));
+    let s = stops.clone();
+    let handler = full_bind_event_handler(&window.handle, move |evt, _, _| {
+        if evt == Event::OnTimerStop { s.set(s.get() + 1); }
+    });
+
+    // The MCIWnd messages overlap the NWG messages, so the MCIWnd window is not subclassed
+    let mciwnd = unsafe { GetWindow(player.handle.hwnd().unwrap(), GW_CHILD) };
+    wh::send_message(mciwnd, MCIWNDM_GETLENGTH, 0, 0);
+
+    assert_eq!(stops.get(), 0);
+    unbind_event_handler(&handler);
+}
+
+#[cfg(feature = "textbox")]
+fn run_recreate_control_tests() {
+    let window = test_window();
+    let mut text_box = TextBox::default();
+    TextBox::builder()
+        .parent(&window)
+        .flags(TextBoxFlags::VISIBLE | TextBoxFlags::AUTOHSCROLL)
+        .text("Hello\r\nWorld")
+        .build(&mut text_box)
+        .unwrap();
+
+    let old_handle = text_box.handle;
+    let handler = bind_raw_event_handler(&text_box.handle, 0x10000, |_hwnd, _msg, _w, _l| None).unwrap();
+    text_box.set_selection(2..4);
+    assert!(!text_box.word_wrap());
+
+    // Word wrap recreates the control and moves its content and its handlers
+    text_box.set_word_wrap(true);
+    assert!(text_box.word_wrap());
+    assert!(text_box.handle != old_handle);
+    assert_eq!(&text_box.text(), "Hello\r\nWorld");
+    assert_eq!(text_box.selection(), 2..4);
+    assert!(has_raw_handler(&text_box.handle, 0x10000));
+
+    text_box.set_word_wrap(false);
+    assert!(!text_box.word_wrap());
+
+    unbind_raw_event_handler(&handler).unwrap();
+    assert!(!has_raw_handler(&text_box.handle, 0x10000));
+}
+
+#[cfg(feature = "menu")]
+fn run_accelerator_tests() {
+    use crate::win32::menu::parse_accelerator;
+    use winapi::um::winuser::{FVIRTKEY, FCONTROL, FSHIFT, FALT, VK_OEM_PLUS, VK_DELETE, VK_F1, VK_F12, VK_PRIOR};
+
+    // The shortcut is the text after the tab
+    assert_eq!(parse_accelerator("&Save\tCtrl+S"), Some((FVIRTKEY | FCONTROL, 'S' as u16)));
+    assert_eq!(parse_accelerator("&Save"), None);
+    assert_eq!(parse_accelerator("Ctrl+S"), None);
+
+    assert_eq!(parse_accelerator("Save as\tctrl+shift+s"), Some((FVIRTKEY | FCONTROL | FSHIFT, 'S' as u16)));
+    assert_eq!(parse_accelerator("Exit\tAlt+F4"), Some((FVIRTKEY | FALT, (VK_F1 + 3) as u16)));
+    assert_eq!(parse_accelerator("Zoom\tCtrl++"), Some((FVIRTKEY | FCONTROL, VK_OEM_PLUS as u16)));
+    assert_eq!(parse_accelerator("Delete\t Del "), Some((FVIRTKEY, VK_DELETE as u16)));
+    assert_eq!(parse_accelerator("Next\tPgUp"), Some((FVIRTKEY, VK_PRIOR as u16)));
+    assert_eq!(parse_accelerator("Debug\tF12"), Some((FVIRTKEY, VK_F12 as u16)));
+    assert_eq!(parse_accelerator("Item 1\tShift+1"), Some((FVIRTKEY | FSHIFT, '1' as u16)));
+
+    // Unknown modifiers and keys are rejected
+    assert_eq!(parse_accelerator("Save\tMeta+S"), None);
+    assert_eq!(parse_accelerator("Save\tCtrl+Foo"), None);
+    assert_eq!(parse_accelerator("Save\tF25"), None);
+    assert_eq!(parse_accelerator("Save\tCtrl+"), None);
+    assert_eq!(parse_accelerator("Save\t"), None);
 }
 
 fn close() {
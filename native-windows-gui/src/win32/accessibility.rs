@@ -0,0 +1,300 @@
+//! winapi-rs does not implements oleacc.h (IAccPropServices), so here's the low level stuff.
+//! The accessible properties of the controls are overridden with the "Direct Annotation" of the MSAA runtime.
+//! Screen readers read the annotations through MSAA and UI Automation.
+#![allow(non_snake_case)]
+
+use winapi::um::unknwnbase::{IUnknown, IUnknownVtbl};
+use winapi::um::oaidl::VARIANT;
+use winapi::shared::minwindef::{BYTE, DWORD};
+use winapi::shared::windef::HWND;
+use winapi::shared::guiddef::GUID;
+use winapi::shared::ntdef::{HRESULT, LPCWSTR};
+use winapi::ctypes::{c_int, c_void};
+use winapi::{Interface, RIDL};
+use super::base_helper::to_utf16;
+use crate::NwgError;
+use std::cell::RefCell;
+use std::ptr;
+
+
+/**
+    The role of a control, as announced by the screen readers.
+    The default role of a control is deduced from its window class (ex: a `Button` is a push button).
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AccessibleRole {
+    Window,
+    Client,
+    Dialog,
+    Pane,
+    Grouping,
+    Alert,
+    Document,
+    StaticText,
+    Text,
+    Link,
+    Graphic,
+    Chart,
+    Animation,
+    PushButton,
+    CheckButton,
+    RadioButton,
+    ComboBox,
+    List,
+    ListItem,
+    Outline,
+    OutlineItem,
+    Table,
+    Cell,
+    PageTab,
+    PageTabList,
+    ProgressBar,
+    Slider,
+    SpinButton,
+    ScrollBar,
+    StatusBar,
+    ToolBar,
+    Separator,
+
+    /// A `ROLE_SYSTEM_*` value
+    Raw(u32),
+}
+
+impl AccessibleRole {
+
+    fn into_raw(self) -> u32 {
+        use AccessibleRole::*;
+
+        match self {
+            Window => 0x09,
+            Client => 0x0A,
+            Dialog => 0x12,
+            Pane => 0x10,
+            Grouping => 0x14,
+            Alert => 0x08,
+            Document => 0x0F,
+            StaticText => 0x29,
+            Text => 0x2A,
+            Link => 0x1E,
+            Graphic => 0x28,
+            Chart => 0x11,
+            Animation => 0x36,
+            PushButton => 0x2B,
+            CheckButton => 0x2C,
+            RadioButton => 0x2D,
+            ComboBox => 0x2E,
+            List => 0x21,
+            ListItem => 0x22,
+            Outline => 0x23,
+            OutlineItem => 0x24,
+            Table => 0x18,
+            Cell => 0x1D,
+            PageTab => 0x25,
+            PageTabList => 0x3C,
+            ProgressBar => 0x30,
+            Slider => 0x33,
+            SpinButton => 0x34,
+            ScrollBar => 0x03,
+            StatusBar => 0x17,
+            ToolBar => 0x16,
+            Separator => 0x15,
+            Raw(role) => role,
+        }
+    }
+
+}
+
+
+const CLSID_ACC_PROP_SERVICES: GUID = GUID {
+    Data1: 0xb5f8350b, Data2: 0x0548, Data3: 0x48b1,
+    Data4: [0xa6, 0xee, 0x88, 0xbd, 0x00, 0xb4, 0xa5, 0xe7]
+};
+
+const PROPID_ACC_NAME: GUID = GUID {
+    Data1: 0x608d3df8, Data2: 0x8128, Data3: 0x4aa7,
+    Data4: [0xa4, 0x28, 0xf5, 0x5e, 0x49, 0x26, 0x72, 0x91]
+};
+
+const PROPID_ACC_DESCRIPTION: GUID = GUID {
+    Data1: 0x4d48dfe4, Data2: 0xbd3f, Data3: 0x491f,
+    Data4: [0xa6, 0x48, 0x49, 0x2d, 0x6f, 0x20, 0xc5, 0x88]
+};
+
+const PROPID_ACC_ROLE: GUID = GUID {
+    Data1: 0xcb905ff2, Data2: 0x7bd1, Data3: 0x4c05,
+    Data4: [0xb3, 0xc8, 0xe6, 0xc2, 0x41, 0x36, 0x4d, 0x70]
+};
+
+// Only the methods up to `ClearHwndProps` are declared. The other methods of the interface are not used.
+RIDL!{#[uuid(0x6e26e776, 0x04f0, 0x495d, 0x80, 0xe4, 0x33, 0x30, 0x35, 0x2e, 0x31, 0x69)]
+interface IAccPropServices(IAccPropServicesVtbl): IUnknown(IUnknownVtbl) {
+    fn SetPropValue(
+        pIDString: *const BYTE,
+        dwIDStringLen: DWORD,
+        idProp: GUID,
+        var: VARIANT,
+    ) -> HRESULT,
+    fn SetPropServer(
+        pIDString: *const BYTE,
+        dwIDStringLen: DWORD,
+        paProps: *const GUID,
+        cProps: c_int,
+        pServer: *mut IUnknown,
+        annoScope: c_int,
+    ) -> HRESULT,
+    fn ClearProps(
+        pIDString: *const BYTE,
+        dwIDStringLen: DWORD,
+        paProps: *const GUID,
+        cProps: c_int,
+    ) -> HRESULT,
+    fn SetHwndProp(
+        hwnd: HWND,
+        idObject: DWORD,
+        idChild: DWORD,
+        idProp: GUID,
+        var: VARIANT,
+    ) -> HRESULT,
+    fn SetHwndPropStr(
+        hwnd: HWND,
+        idObject: DWORD,
+        idChild: DWORD,
+        idProp: GUID,
+        str: LPCWSTR,
+    ) -> HRESULT,
+    fn SetHwndPropServer(
+        hwnd: HWND,
+        idObject: DWORD,
+        idChild: DWORD,
+        paProps: *const GUID,
+        cProps: c_int,
+        pServer: *mut IUnknown,
+        annoScope: c_int,
+    ) -> HRESULT,
+    fn ClearHwndProps(
+        hwnd: HWND,
+        idObject: DWORD,
+        idChild: DWORD,
+        paProps: *const GUID,
+        cProps: c_int,
+    ) -> HRESULT,
+}}
+
+/// The window classes that do not display their window text.
+/// Their window text is used as the accessible name if the annotation service is not available.
+const TEXTLESS_CLASSES: &[&str] = &["NWG_EXTERN_CANVAS", "NWG_FRAME"];
+
+thread_local! {
+    /// The windows of the thread that have annotations. The annotations are cleared when the windows are destroyed.
+    static ANNOTATED: RefCell<Vec<HWND>> = RefCell::new(Vec::new());
+}
+
+/// Calls `f` with the annotation service. Returns `None` if the service cannot be created.
+unsafe fn with_services<T, F: FnOnce(&IAccPropServices) -> T>(f: F) -> Option<T> {
+    use winapi::um::combaseapi::CoCreateInstance;
+    use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+    use winapi::shared::winerror::S_OK;
+
+    let mut services: *mut IAccPropServices = ptr::null_mut();
+    let result = CoCreateInstance(
+        &CLSID_ACC_PROP_SERVICES,
+        ptr::null_mut(),
+        CLSCTX_INPROC_SERVER,
+        &IAccPropServices::uuidof(),
+        &mut services as *mut *mut IAccPropServices as *mut *mut c_void
+    );
+
+    if result != S_OK || services.is_null() {
+        return None;
+    }
+
+    let value = f(&*services);
+    (&*services).Release();
+
+    Some(value)
+}
+
+fn annotated(hwnd: HWND) {
+    ANNOTATED.with(|a| {
+        let mut a = a.borrow_mut();
+        if !a.contains(&hwnd) {
+            a.push(hwnd);
+        }
+    });
+}
+
+fn check(result: Option<HRESULT>, message: &str) -> Result<(), NwgError> {
+    use winapi::shared::winerror::S_OK;
+
+    match result {
+        Some(S_OK) => Ok(()),
+        Some(_) => Err(NwgError::accessibility(message)),
+        None => Err(NwgError::accessibility("The accessibility annotation service is not available"))
+    }
+}
+
+unsafe fn set_prop_str(hwnd: HWND, prop: GUID, value: &str) -> Option<HRESULT> {
+    use winapi::um::winuser::{OBJID_CLIENT, CHILDID_SELF};
+
+    let value = to_utf16(value);
+    let result = with_services(|s| s.SetHwndPropStr(hwnd, OBJID_CLIENT as DWORD, CHILDID_SELF as DWORD, prop, value.as_ptr()));
+    annotated(hwnd);
+    result
+}
+
+/// Sets the accessible name of `hwnd`. If the annotation service is not available, the name of the controls
+/// that do not display their text is set with `SetWindowText`.
+pub unsafe fn set_name(hwnd: HWND, name: &str) -> Result<(), NwgError> {
+    use super::window_helper as wh;
+
+    let result = set_prop_str(hwnd, PROPID_ACC_NAME, name);
+    if result.is_none() && TEXTLESS_CLASSES.contains(&wh::get_window_class_name(hwnd).as_str()) {
+        wh::set_window_text(hwnd, name);
+        return Ok(());
+    }
+
+    check(result, "Failed to set the accessible name")
+}
+
+/// Sets the accessible description of `hwnd`
+pub unsafe fn set_description(hwnd: HWND, description: &str) -> Result<(), NwgError> {
+    check(set_prop_str(hwnd, PROPID_ACC_DESCRIPTION, description), "Failed to set the accessible description")
+}
+
+/// Sets the accessible role of `hwnd`
+pub unsafe fn set_role(hwnd: HWND, role: AccessibleRole) -> Result<(), NwgError> {
+    use winapi::um::winuser::{OBJID_CLIENT, CHILDID_SELF};
+    use winapi::shared::wtypes::VT_I4;
+
+    let mut value: VARIANT = ::std::mem::zeroed();
+    {
+        let value = value.n1.n2_mut();
+        value.vt = VT_I4 as _;
+        *value.n3.lVal_mut() = role.into_raw() as i32;
+    }
+
+    let result = with_services(|s| s.SetHwndProp(hwnd, OBJID_CLIENT as DWORD, CHILDID_SELF as DWORD, PROPID_ACC_ROLE, value));
+    annotated(hwnd);
+
+    check(result, "Failed to set the accessible role")
+}
+
+/// Removes the annotations of `hwnd`. Does nothing if the window was never annotated.
+pub fn clear(hwnd: HWND) {
+    use winapi::um::winuser::{OBJID_CLIENT, CHILDID_SELF};
+
+    let was_annotated = ANNOTATED.with(|a| {
+        let mut a = a.borrow_mut();
+        match a.iter().position(|&h| h == hwnd) {
+            Some(i) => { a.swap_remove(i); true },
+            None => false
+        }
+    });
+
+    if was_annotated {
+        let props = [PROPID_ACC_NAME, PROPID_ACC_DESCRIPTION, PROPID_ACC_ROLE];
+        unsafe {
+            with_services(|s| s.ClearHwndProps(hwnd, OBJID_CLIENT as DWORD, CHILDID_SELF as DWORD, props.as_ptr(), props.len() as c_int));
+        }
+    }
+}
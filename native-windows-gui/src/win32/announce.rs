@@ -0,0 +1,184 @@
+//! winapi-rs does not implements the UI Automation notifications, so here's the low level stuff.
+//! `UiaRaiseNotificationEvent` is loaded at runtime because it only exists since Windows 10 1709.
+//! On older systems, the announcement is the text of an alert sent with `NotifyWinEvent`.
+use winapi::shared::windef::HWND;
+use winapi::shared::ntdef::HRESULT;
+use winapi::shared::wtypes::BSTR;
+use winapi::um::unknwnbase::IUnknown;
+use winapi::ctypes::c_int;
+use super::base_helper::to_utf16;
+use crate::NwgError;
+use std::cell::RefCell;
+use std::{mem, ptr};
+
+
+/**
+    The priority of an announcement made with `nwg::announce`
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnnouncePriority {
+    /// The announcement is read after the current speech of the screen reader
+    Normal,
+
+    /// The announcement interrupts the current speech of the screen reader
+    Important,
+}
+
+impl AnnouncePriority {
+
+    /// Returns the `NotificationProcessing` value of the priority
+    fn into_raw(self) -> c_int {
+        match self {
+            AnnouncePriority::Normal => 2, // NotificationProcessing_All
+            AnnouncePriority::Important => 0, // NotificationProcessing_ImportantAll
+        }
+    }
+
+}
+
+const NOTIFICATION_KIND_OTHER: c_int = 4;
+
+/// The UI Automation functions used by the announcements. Exported by UIAutomationCore.dll.
+struct Uia {
+    host_provider_from_hwnd: unsafe extern "system" fn(HWND, *mut *mut IUnknown) -> HRESULT,
+    raise_notification_event: unsafe extern "system" fn(*mut IUnknown, c_int, c_int, BSTR, BSTR) -> HRESULT,
+}
+
+lazy_static! {
+    static ref UIA: Option<Uia> = unsafe { load_uia() };
+}
+
+thread_local! {
+    /// The labels used to announce the text when UI Automation notifications are not available. One per top level window.
+    static ALERT_LABELS: RefCell<Vec<(HWND, HWND)>> = RefCell::new(Vec::new());
+}
+
+unsafe fn load_uia() -> Option<Uia> {
+    use winapi::um::libloaderapi::{LoadLibraryW, GetProcAddress};
+
+    let lib = to_utf16("UIAutomationCore.dll");
+    let module = LoadLibraryW(lib.as_ptr());
+    if module.is_null() {
+        return None;
+    }
+
+    let host_provider_from_hwnd = GetProcAddress(module, "UiaHostProviderFromHwnd\0".as_ptr() as _);
+    let raise_notification_event = GetProcAddress(module, "UiaRaiseNotificationEvent\0".as_ptr() as _);
+    if host_provider_from_hwnd.is_null() || raise_notification_event.is_null() {
+        return None;
+    }
+
+    Some(Uia {
+        host_provider_from_hwnd: mem::transmute(host_provider_from_hwnd),
+        raise_notification_event: mem::transmute(raise_notification_event),
+    })
+}
+
+
+/**
+    Makes the screen readers announce `text`. Use it to report the changes that happen without a user action (ex: "Download complete").
+
+    The announcement is sent from the active window of the calling thread.
+    Returns an error if the thread does not have an active window.
+
+    Requires the `accessibility` feature.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn download_complete() {
+        nwg::announce("Download complete", nwg::AnnouncePriority::Normal).ok();
+    }
+    ```
+*/
+pub fn announce(text: &str, priority: AnnouncePriority) -> Result<(), NwgError> {
+    use winapi::um::winuser::GetActiveWindow;
+
+    let hwnd = unsafe { GetActiveWindow() };
+    if hwnd.is_null() {
+        return Err(NwgError::accessibility("The thread does not have an active window"));
+    }
+
+    unsafe {
+        match UIA.as_ref() {
+            Some(uia) => raise_notification(uia, hwnd, text, priority),
+            None => raise_alert(hwnd, text)
+        }
+    }
+}
+
+unsafe fn raise_notification(uia: &Uia, hwnd: HWND, text: &str, priority: AnnouncePriority) -> Result<(), NwgError> {
+    use winapi::um::oleauto::{SysAllocString, SysFreeString};
+    use winapi::shared::winerror::S_OK;
+
+    let mut provider: *mut IUnknown = ptr::null_mut();
+    if (uia.host_provider_from_hwnd)(hwnd, &mut provider) != S_OK || provider.is_null() {
+        return Err(NwgError::accessibility("Failed to get the automation provider of the window"));
+    }
+
+    let text = to_utf16(text);
+    let activity = to_utf16("native-windows-gui");
+    let text = SysAllocString(text.as_ptr());
+    let activity = SysAllocString(activity.as_ptr());
+
+    let result = (uia.raise_notification_event)(provider, NOTIFICATION_KIND_OTHER, priority.into_raw(), text, activity);
+
+    SysFreeString(text);
+    SysFreeString(activity);
+    (&*provider).Release();
+
+    match result {
+        S_OK => Ok(()),
+        _ => Err(NwgError::accessibility("Failed to raise the notification event"))
+    }
+}
+
+/// Sets `text` on an empty static control of `hwnd` and signals it as an alert. The screen readers read the text of the alert.
+unsafe fn raise_alert(hwnd: HWND, text: &str) -> Result<(), NwgError> {
+    use winapi::um::winuser::{NotifyWinEvent, IsWindow, EVENT_SYSTEM_ALERT, OBJID_CLIENT, CHILDID_SELF};
+    use super::window_helper as wh;
+
+    let label = ALERT_LABELS.with(|labels| {
+        let mut labels = labels.borrow_mut();
+        labels.retain(|&(parent, _)| IsWindow(parent) != 0);
+        labels.iter().find(|&&(parent, _)| parent == hwnd).map(|&(_, label)| label)
+    });
+
+    // The label is created outside of the borrow, because the parent receives messages during the creation
+    let label = match label {
+        Some(label) => Some(label),
+        None => create_alert_label(hwnd).map(|label| {
+            ALERT_LABELS.with(|labels| labels.borrow_mut().push((hwnd, label)));
+            label
+        })
+    };
+
+    let label = label.ok_or_else(|| NwgError::accessibility("Failed to create the alert label"))?;
+    wh::set_window_text(label, text);
+    NotifyWinEvent(EVENT_SYSTEM_ALERT, label, OBJID_CLIENT, CHILDID_SELF as _);
+
+    Ok(())
+}
+
+/// Creates a visible static control without size, so that it is not skipped by the screen readers
+unsafe fn create_alert_label(parent: HWND) -> Option<HWND> {
+    use winapi::um::winuser::{CreateWindowExW, WS_CHILD, WS_VISIBLE};
+    use winapi::um::libloaderapi::GetModuleHandleW;
+
+    let class = to_utf16("STATIC");
+    let label = CreateWindowExW(
+        0,
+        class.as_ptr(), ptr::null(),
+        WS_CHILD | WS_VISIBLE,
+        0, 0, 0, 0,
+        parent,
+        ptr::null_mut(),
+        GetModuleHandleW(ptr::null()),
+        ptr::null_mut()
+    );
+
+    match label.is_null() {
+        true => None,
+        false => Some(label)
+    }
+}
@@ -0,0 +1,288 @@
+//! winapi-rs does not implements shldisp.h (IAutoComplete) nor SHAutoComplete, so here's the low level stuff.
+//! Also includes a rust implementation of `IEnumString` used to feed custom suggestions to the autocomplete object.
+#![allow(non_snake_case)]
+
+use winapi::um::objidlbase::{IEnumString, IEnumStringVtbl};
+use winapi::um::unknwnbase::{IUnknown, IUnknownVtbl};
+use winapi::shared::minwindef::{BOOL, DWORD, ULONG};
+use winapi::shared::windef::HWND;
+use winapi::shared::guiddef::{GUID, REFIID, IsEqualGUID};
+use winapi::shared::ntdef::{HRESULT, LPCWSTR};
+use winapi::shared::winerror::{S_OK, S_FALSE, E_NOINTERFACE, E_POINTER, E_OUTOFMEMORY};
+use winapi::shared::wtypesbase::LPOLESTR;
+use winapi::ctypes::c_void;
+use winapi::{Interface, RIDL};
+use crate::NwgError;
+use std::sync::{Arc, Mutex, atomic::{AtomicU32, Ordering}};
+use std::{mem, ptr};
+
+
+pub const SHACF_FILESYSTEM: DWORD = 0x00000001;
+pub const SHACF_URLALL: DWORD = 0x00000006;
+pub const SHACF_FILESYS_DIRS: DWORD = 0x00000020;
+pub const SHACF_USETAB: DWORD = 0x00000008;
+pub const SHACF_AUTOSUGGEST_FORCE_ON: DWORD = 0x10000000;
+pub const SHACF_AUTOSUGGEST_FORCE_OFF: DWORD = 0x20000000;
+pub const SHACF_AUTOAPPEND_FORCE_ON: DWORD = 0x40000000;
+pub const SHACF_AUTOAPPEND_FORCE_OFF: DWORD = 0x80000000;
+
+pub const ACO_AUTOSUGGEST: DWORD = 0x0001;
+pub const ACO_AUTOAPPEND: DWORD = 0x0002;
+pub const ACO_USETAB: DWORD = 0x0010;
+pub const ACO_UPDOWNKEYDROPSLIST: DWORD = 0x0020;
+pub const ACO_WORDFILTER: DWORD = 0x0080;
+
+const ACDD_VISIBLE: DWORD = 0x0001;
+
+const CLSID_AUTOCOMPLETE: GUID = GUID {
+    Data1: 0x00BB2763, Data2: 0x6A77, Data3: 0x11D0,
+    Data4: [0xA5, 0x35, 0x00, 0xC0, 0x4F, 0xD7, 0xD0, 0x62]
+};
+
+RIDL!{#[uuid(0x00bb2762, 0x6a77, 0x11d0, 0xa5, 0x35, 0x00, 0xc0, 0x4f, 0xd7, 0xd0, 0x62)]
+interface IAutoComplete(IAutoCompleteVtbl): IUnknown(IUnknownVtbl) {
+    fn Init(
+        hwndEdit: HWND,
+        punkACL: *mut IUnknown,
+        pwszRegKeyPath: LPCWSTR,
+        pwszQuickComplete: LPCWSTR,
+    ) -> HRESULT,
+    fn Enable(
+        fEnable: BOOL,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0xeac04bc0, 0x3791, 0x11d2, 0xbb, 0x95, 0x00, 0x60, 0x97, 0x7b, 0x46, 0x4c)]
+interface IAutoComplete2(IAutoComplete2Vtbl): IAutoComplete(IAutoCompleteVtbl) {
+    fn SetOptions(
+        dwFlag: DWORD,
+    ) -> HRESULT,
+    fn GetOptions(
+        pdwFlag: *mut DWORD,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0x3cd141f4, 0x3c6a, 0x11d2, 0xbc, 0xaa, 0x00, 0xc0, 0x4f, 0xd9, 0x29, 0xdb)]
+interface IAutoCompleteDropDown(IAutoCompleteDropDownVtbl): IUnknown(IUnknownVtbl) {
+    fn GetDropDownStatus(
+        pdwFlags: *mut DWORD,
+        ppwszString: *mut LPOLESTR,
+    ) -> HRESULT,
+    fn ResetEnumerator() -> HRESULT,
+}}
+
+extern "system" {
+    fn SHAutoComplete(hwndEdit: HWND, dwFlags: DWORD) -> HRESULT;
+}
+
+/// The list of suggestions shared between the `AutoComplete` control and the string enumerators.
+/// The enumerators are called by the autocomplete object from a background thread, hence the mutex.
+pub type SuggestionList = Arc<Mutex<Vec<Vec<u16>>>>;
+
+/// A rust implementation of `IEnumString` over a `SuggestionList`
+#[repr(C)]
+struct SuggestionEnum {
+    vtbl: *const IEnumStringVtbl,
+    ref_count: AtomicU32,
+    items: SuggestionList,
+    index: Mutex<usize>,
+}
+
+static SUGGESTION_ENUM_VTBL: IEnumStringVtbl = IEnumStringVtbl {
+    parent: IUnknownVtbl {
+        QueryInterface: suggestion_query_interface,
+        AddRef: suggestion_add_ref,
+        Release: suggestion_release,
+    },
+    Next: suggestion_next,
+    Skip: suggestion_skip,
+    Reset: suggestion_reset,
+    Clone: suggestion_clone,
+};
+
+fn new_suggestion_enum(items: SuggestionList, index: usize) -> *mut IEnumString {
+    let enumerator = Box::new(SuggestionEnum {
+        vtbl: &SUGGESTION_ENUM_VTBL,
+        ref_count: AtomicU32::new(1),
+        items,
+        index: Mutex::new(index),
+    });
+
+    Box::into_raw(enumerator) as *mut IEnumString
+}
+
+unsafe extern "system" fn suggestion_query_interface(this: *mut IUnknown, riid: REFIID, out: *mut *mut c_void) -> HRESULT {
+    if out.is_null() {
+        return E_POINTER;
+    }
+
+    let riid = &*riid;
+    if IsEqualGUID(riid, &IUnknown::uuidof()) || IsEqualGUID(riid, &IEnumString::uuidof()) {
+        suggestion_add_ref(this);
+        *out = this as *mut c_void;
+        S_OK
+    } else {
+        *out = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+unsafe extern "system" fn suggestion_add_ref(this: *mut IUnknown) -> ULONG {
+    let enumerator = &*(this as *mut SuggestionEnum);
+    enumerator.ref_count.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+unsafe extern "system" fn suggestion_release(this: *mut IUnknown) -> ULONG {
+    let count = {
+        let enumerator = &*(this as *mut SuggestionEnum);
+        enumerator.ref_count.fetch_sub(1, Ordering::SeqCst) - 1
+    };
+
+    if count == 0 {
+        drop(Box::from_raw(this as *mut SuggestionEnum));
+    }
+
+    count
+}
+
+unsafe extern "system" fn suggestion_next(this: *mut IEnumString, celt: ULONG, rgelt: *mut LPOLESTR, fetched: *mut ULONG) -> HRESULT {
+    use winapi::um::combaseapi::CoTaskMemAlloc;
+
+    if rgelt.is_null() {
+        return E_POINTER;
+    }
+
+    let enumerator = &*(this as *mut SuggestionEnum);
+    let items = enumerator.items.lock().unwrap();
+    let mut index = enumerator.index.lock().unwrap();
+
+    let mut count = 0;
+    while count < celt as usize && *index < items.len() {
+        let item = &items[*index];
+        let size = (item.len() + 1) * mem::size_of::<u16>();
+        let out = CoTaskMemAlloc(size) as *mut u16;
+        if out.is_null() {
+            return E_OUTOFMEMORY;
+        }
+
+        ptr::copy_nonoverlapping(item.as_ptr(), out, item.len());
+        *out.add(item.len()) = 0;
+        *rgelt.add(count) = out;
+
+        count += 1;
+        *index += 1;
+    }
+
+    if !fetched.is_null() {
+        *fetched = count as ULONG;
+    }
+
+    match count == celt as usize {
+        true => S_OK,
+        false => S_FALSE
+    }
+}
+
+unsafe extern "system" fn suggestion_skip(this: *mut IEnumString, celt: ULONG) -> HRESULT {
+    let enumerator = &*(this as *mut SuggestionEnum);
+    let len = enumerator.items.lock().unwrap().len();
+    let mut index = enumerator.index.lock().unwrap();
+
+    *index += celt as usize;
+    match *index <= len {
+        true => S_OK,
+        false => S_FALSE
+    }
+}
+
+unsafe extern "system" fn suggestion_reset(this: *mut IEnumString) -> HRESULT {
+    let enumerator = &*(this as *mut SuggestionEnum);
+    *enumerator.index.lock().unwrap() = 0;
+    S_OK
+}
+
+unsafe extern "system" fn suggestion_clone(this: *mut IEnumString, out: *mut *mut IEnumString) -> HRESULT {
+    if out.is_null() {
+        return E_POINTER;
+    }
+
+    let enumerator = &*(this as *mut SuggestionEnum);
+    let index = *enumerator.index.lock().unwrap();
+    *out = new_suggestion_enum(enumerator.items.clone(), index);
+
+    S_OK
+}
+
+/// Enable the system autocompletion (file system or urls) on an edit control
+pub unsafe fn shell_autocomplete(hwnd: HWND, flags: DWORD) -> Result<(), NwgError> {
+    match SHAutoComplete(hwnd, flags) {
+        S_OK => Ok(()),
+        _ => Err(NwgError::control_create("Failed to enable the autocompletion on the edit control"))
+    }
+}
+
+/// Create an autocomplete object bound to the edit control `hwnd` that suggests the values in `items`
+pub unsafe fn create_autocomplete(hwnd: HWND, items: SuggestionList, options: DWORD) -> Result<*mut IAutoComplete2, NwgError> {
+    use winapi::um::combaseapi::CoCreateInstance;
+    use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+
+    let mut auto_complete: *mut IAutoComplete2 = ptr::null_mut();
+    let result = CoCreateInstance(
+        &CLSID_AUTOCOMPLETE,
+        ptr::null_mut(),
+        CLSCTX_INPROC_SERVER,
+        &IAutoComplete2::uuidof(),
+        (&mut auto_complete as *mut *mut IAutoComplete2) as *mut *mut c_void
+    );
+
+    if result != S_OK {
+        return Err(NwgError::control_create("Failed to create the autocomplete object"));
+    }
+
+    let source = new_suggestion_enum(items, 0);
+    let result = (&*auto_complete).Init(hwnd, source as *mut IUnknown, ptr::null(), ptr::null());
+
+    // The autocomplete object keeps its own reference to the source
+    (&*source).Release();
+
+    if result != S_OK {
+        (&*auto_complete).Release();
+        return Err(NwgError::control_create("Failed to initialize the autocomplete object"));
+    }
+
+    (&*auto_complete).SetOptions(options);
+
+    Ok(auto_complete)
+}
+
+/// Ask the autocomplete object to reload its suggestions the next time the dropdown is shown
+pub unsafe fn reset_autocomplete(auto_complete: &IAutoComplete2) {
+    let mut dropdown: *mut IAutoCompleteDropDown = ptr::null_mut();
+    let result = auto_complete.QueryInterface(&IAutoCompleteDropDown::uuidof(), (&mut dropdown as *mut *mut IAutoCompleteDropDown) as *mut *mut c_void);
+    if result == S_OK {
+        (&*dropdown).ResetEnumerator();
+        (&*dropdown).Release();
+    }
+}
+
+/// Check if the dropdown of the autocomplete object is visible
+pub unsafe fn autocomplete_dropdown_visible(auto_complete: &IAutoComplete2) -> bool {
+    use winapi::um::combaseapi::CoTaskMemFree;
+
+    let mut dropdown: *mut IAutoCompleteDropDown = ptr::null_mut();
+    let result = auto_complete.QueryInterface(&IAutoCompleteDropDown::uuidof(), (&mut dropdown as *mut *mut IAutoCompleteDropDown) as *mut *mut c_void);
+    if result != S_OK {
+        return false;
+    }
+
+    let mut flags = 0;
+    let mut text: LPOLESTR = ptr::null_mut();
+    (&*dropdown).GetDropDownStatus(&mut flags, &mut text);
+    (&*dropdown).Release();
+
+    if !text.is_null() {
+        CoTaskMemFree(text as _);
+    }
+
+    flags & ACDD_VISIBLE == ACDD_VISIBLE
+}
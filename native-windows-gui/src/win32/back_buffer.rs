@@ -0,0 +1,128 @@
+/*!
+    Back buffers of the double buffered windows.
+
+    A double buffered window is painted into a memory device context that has the size of its client area.
+    The memory device context is copied to the window when the painting ends.
+*/
+use winapi::shared::windef::{HWND, HDC, HBITMAP};
+use winapi::um::wingdi::{DeleteDC, DeleteObject, SelectObject};
+use winapi::um::winnt::HANDLE;
+use std::cell::RefCell;
+use std::ptr;
+
+/// Raw event handler id used to release the back buffer of a destroyed window
+const BACK_BUFFER_HANDLER_ID: usize = 0xFF02;
+
+struct BackBuffer {
+    hwnd: HWND,
+    dc: HDC,
+    bitmap: HBITMAP,
+    old_bitmap: HANDLE,
+    size: (i32, i32),
+}
+
+impl BackBuffer {
+
+    /// Releases the memory device context and its bitmap
+    unsafe fn free(&mut self) {
+        if !self.dc.is_null() {
+            SelectObject(self.dc, self.old_bitmap as _);
+            DeleteObject(self.bitmap as _);
+            DeleteDC(self.dc);
+        }
+
+        self.dc = ptr::null_mut();
+        self.bitmap = ptr::null_mut();
+        self.size = (0, 0);
+    }
+
+}
+
+thread_local! {
+    /// The back buffers of the double buffered windows of the thread. The device contexts are created on the first paint.
+    static BACK_BUFFERS: RefCell<Vec<BackBuffer>> = RefCell::new(Vec::new());
+}
+
+/// Returns true if `hwnd` is double buffered
+pub fn enabled(hwnd: HWND) -> bool {
+    BACK_BUFFERS.with(|buffers| buffers.borrow().iter().any(|b| b.hwnd == hwnd))
+}
+
+/// Enables or disables the double buffering of `hwnd`. Disabling the double buffering releases the back buffer.
+/// The back buffer is also released when the window is destroyed.
+pub fn set_enabled(hwnd: HWND, enable: bool) {
+    use winapi::um::winuser::WM_NCDESTROY;
+    use crate::win32::window::bind_raw_event_handler_inner;
+    use crate::ControlHandle;
+
+    let added = BACK_BUFFERS.with(|buffers| {
+        let mut buffers = buffers.borrow_mut();
+        let index = buffers.iter().position(|b| b.hwnd == hwnd);
+
+        match (index, enable) {
+            (None, true) => {
+                buffers.push(BackBuffer { hwnd, dc: ptr::null_mut(), bitmap: ptr::null_mut(), old_bitmap: ptr::null_mut(), size: (0, 0) });
+                true
+            },
+            (Some(i), false) => {
+                unsafe { buffers.swap_remove(i).free(); }
+                false
+            },
+            _ => false
+        }
+    });
+
+    // The handler is freed with the window. It is already bound if the double buffering was enabled before.
+    if added {
+        bind_raw_event_handler_inner(&ControlHandle::Hwnd(hwnd), BACK_BUFFER_HANDLER_ID, |hwnd, msg, _w, _l| {
+            if msg == WM_NCDESTROY {
+                set_enabled(hwnd, false);
+            }
+            None
+        }).ok();
+    }
+}
+
+/// Returns the memory device context of `hwnd`, resized to its client area if needed.
+/// Returns `None` if the window is not double buffered or if the back buffer could not be created.
+pub unsafe fn begin(hwnd: HWND, window_dc: HDC) -> Option<HDC> {
+    use winapi::um::wingdi::{CreateCompatibleDC, CreateCompatibleBitmap};
+    use winapi::um::winuser::GetClientRect;
+    use winapi::shared::windef::RECT;
+
+    BACK_BUFFERS.with(|buffers| {
+        let mut buffers = buffers.borrow_mut();
+        let buffer = buffers.iter_mut().find(|b| b.hwnd == hwnd)?;
+
+        let mut r: RECT = ::std::mem::zeroed();
+        GetClientRect(hwnd, &mut r);
+        let size = ((r.right - r.left).max(1), (r.bottom - r.top).max(1));
+
+        if buffer.dc.is_null() || buffer.size != size {
+            buffer.free();
+
+            let dc = CreateCompatibleDC(window_dc);
+            let bitmap = CreateCompatibleBitmap(window_dc, size.0, size.1);
+            if dc.is_null() || bitmap.is_null() {
+                if !dc.is_null() { DeleteDC(dc); }
+                if !bitmap.is_null() { DeleteObject(bitmap as _); }
+                return None;
+            }
+
+            buffer.dc = dc;
+            buffer.bitmap = bitmap;
+            buffer.old_bitmap = SelectObject(dc, bitmap as _) as _;
+            buffer.size = size;
+        }
+
+        Some(buffer.dc)
+    })
+}
+
+/// Copies the region `[left, top, right, bottom]` of the back buffer to the window device context
+pub unsafe fn end(back_dc: HDC, window_dc: HDC, r: [i32; 4]) {
+    use winapi::um::wingdi::{BitBlt, SRCCOPY};
+
+    let [left, top, right, bottom] = r;
+    BitBlt(window_dc, left, top, right - left, bottom - top, back_dc, left, top, SRCCOPY);
+}
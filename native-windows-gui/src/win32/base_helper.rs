@@ -1,22 +1,48 @@
 use std::ptr;
 use winapi::shared::windef::HWND;
 use winapi::shared::minwindef::DWORD;
-use crate::ControlHandle;
+use crate::{ControlHandle, NwgError};
 use std::ffi::OsString;
+use std::cell::RefCell;
 
 pub const CUSTOM_ID_BEGIN: u32 = 10000;
 
 
 pub fn check_hwnd(handle: &ControlHandle, not_bound: &str, bad_handle: &str) -> HWND {
+    try_check_hwnd(handle, not_bound, bad_handle).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Same as `check_hwnd`, but returns an `InvalidHandle` error instead of panicking.
+/// In debug builds, using a control from a thread other than the one that created it is also an error.
+pub fn try_check_hwnd(handle: &ControlHandle, not_bound: &str, bad_handle: &str) -> Result<HWND, NwgError> {
     use winapi::um::winuser::IsWindow;
 
-    if handle.blank() { panic!("{}", not_bound); }
+    if handle.blank() { return Err(NwgError::invalid_handle(not_bound)); }
     match handle.hwnd() {
         Some(hwnd) => match unsafe { IsWindow(hwnd) } {
-            0 => { panic!("The window handle is no longer valid. This usually means the control was freed by the OS"); },
-            _ => hwnd
+            0 => Err(NwgError::invalid_handle("The window handle is no longer valid. This usually means the control was freed by the OS")),
+            _ => {
+                #[cfg(debug_assertions)]
+                check_thread(hwnd)?;
+
+                Ok(hwnd)
+            }
         },
-        None => { panic!("{}", bad_handle); }
+        None => Err(NwgError::invalid_handle(bad_handle))
+    }
+}
+
+/// Returns an error if `hwnd` was not created by the calling thread. Only enabled in debug builds.
+/// Sending messages to a control of another thread blocks until that thread processes them, or fails silently.
+#[cfg(debug_assertions)]
+fn check_thread(hwnd: HWND) -> Result<(), NwgError> {
+    use winapi::um::winuser::GetWindowThreadProcessId;
+    use winapi::um::processthreadsapi::GetCurrentThreadId;
+
+    let (owner, current) = unsafe { (GetWindowThreadProcessId(hwnd, ptr::null_mut()), GetCurrentThreadId()) };
+    match owner == current {
+        true => Ok(()),
+        false => Err(NwgError::invalid_handle(format!("A control created by the thread {} was used from the thread {}. Controls can only be used from the thread that created them", owner, current)))
     }
 }
 
@@ -30,6 +56,58 @@ pub fn to_utf16<'a>(s: &'a str) -> Vec<u16> {
       .collect()
 }
 
+/// Capacity kept by the thread-local conversion buffers between calls
+const UTF16_BUFFER_CAPACITY: usize = 1024;
+
+thread_local! {
+    /// Buffer reused by `with_utf16`
+    static ENCODE_BUFFER: RefCell<Vec<u16>> = RefCell::new(Vec::with_capacity(UTF16_BUFFER_CAPACITY));
+
+    /// Buffer reused by `with_wide_buffer`
+    static DECODE_BUFFER: RefCell<Vec<u16>> = RefCell::new(Vec::with_capacity(UTF16_BUFFER_CAPACITY));
+}
+
+/**
+    Encode `s` into a null terminated utf16 string and pass it to `f`.
+    Unlike `to_utf16`, this reuses a thread-local buffer instead of allocating a new one on each call.
+
+    If `f` converts another string (ex: because it sends a message handled by an event handler),
+    the nested conversion allocates a new buffer.
+*/
+pub fn with_utf16<R, F: FnOnce(&[u16]) -> R>(s: &str, f: F) -> R {
+    ENCODE_BUFFER.with(|buffer| match buffer.try_borrow_mut() {
+        Ok(mut buffer) => {
+            buffer.clear();
+            buffer.extend(s.encode_utf16());
+            buffer.push(0);
+
+            let result = f(&buffer);
+            buffer.shrink_to(UTF16_BUFFER_CAPACITY);
+
+            result
+        },
+        Err(_) => f(&to_utf16(s))
+    })
+}
+
+/**
+    Pass a zeroed buffer of `size` wide chars to `f`. The buffer is reused between calls in the same way as `with_utf16`.
+*/
+pub fn with_wide_buffer<R, F: FnOnce(&mut [u16]) -> R>(size: usize, f: F) -> R {
+    DECODE_BUFFER.with(|buffer| match buffer.try_borrow_mut() {
+        Ok(mut buffer) => {
+            buffer.clear();
+            buffer.resize(size, 0);
+
+            let result = f(&mut buffer);
+            buffer.shrink_to(UTF16_BUFFER_CAPACITY);
+
+            result
+        },
+        Err(_) => f(&mut vec![0; size])
+    })
+}
+
 /**
     Decode a raw utf16 string. Should be null terminated.
 */
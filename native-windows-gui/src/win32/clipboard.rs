@@ -1,7 +1,25 @@
 use crate::controls::ControlHandle;
-use super::base_helper::{to_utf16};
-use winapi::um::winuser::{CF_BITMAP, CF_TEXT, CF_UNICODETEXT};
+use crate::{Bitmap, NwgError};
+use super::base_helper::{to_utf16, from_utf16};
+use winapi::um::winuser::{CF_BITMAP, CF_DIB, CF_HDROP, CF_TEXT, CF_UNICODETEXT};
 use winapi::um::winnt::HANDLE;
+use winapi::shared::minwindef::{DWORD, BOOL};
+use winapi::shared::windef::{HWND, POINT};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Number of times the high level functions try to open the clipboard if another application is using it
+const OPEN_RETRIES: usize = 10;
+
+/// The header of the `CF_HDROP` data. Not defined by winapi-rs.
+#[repr(C)]
+#[allow(non_snake_case)]
+struct DROPFILES {
+    pFiles: DWORD,
+    pt: POINT,
+    fNC: BOOL,
+    fWide: BOOL,
+}
 
 
 #[derive(Copy, Clone)]
@@ -15,15 +33,25 @@ pub enum ClipboardFormat {
     /// A bitmap file
     Bitmap,
 
+    /// A device independent bitmap (`BITMAPINFO` followed by the bitmap bits).
+    /// The system converts the `Bitmap` format to this format when an application requests it.
+    Dib,
+
+    /// A list of files (`HDROP`). Used when files are copied in the explorer.
+    Files,
+
     /// Global clipboard format to share data between applications
     /// The format name comparison is case-insensitive.
-    Global(&'static str)
+    Global(&'static str),
+
+    /// A clipboard format identifier. Returned by `Clipboard::register_format` and `Clipboard::formats`.
+    Raw(u32),
 }
 
 
 impl ClipboardFormat {
 
-    fn into_raw(&self) -> u32 {
+    pub(crate) fn into_raw(&self) -> u32 {
         use ClipboardFormat::*;
         use winapi::um::winuser::RegisterClipboardFormatW;
 
@@ -31,10 +59,13 @@ impl ClipboardFormat {
             Text => CF_TEXT,
             UnicodeText => CF_UNICODETEXT,
             Bitmap => CF_BITMAP,
+            Dib => CF_DIB,
+            Files => CF_HDROP,
             Global(v) => unsafe {
                 let v = to_utf16(v);
                 RegisterClipboardFormatW(v.as_ptr())
-            }
+            },
+            Raw(id) => *id,
         }
     }
 
@@ -56,6 +87,38 @@ impl Drop for ClipboardData {
 }
 
 
+/// Keeps the clipboard open until it is dropped
+struct OpenedClipboard;
+
+impl OpenedClipboard {
+
+    fn open(handle: ControlHandle) -> Result<OpenedClipboard, NwgError> {
+        use winapi::um::winuser::OpenClipboard;
+        use std::{thread, time::Duration};
+
+        let hwnd = handle.hwnd().ok_or_else(|| NwgError::clipboard("The clipboard owner must be a window"))?;
+
+        // Another application may be using the clipboard
+        for _ in 0..OPEN_RETRIES {
+            if unsafe { OpenClipboard(hwnd) } != 0 {
+                return Ok(OpenedClipboard);
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        Err(NwgError::clipboard("Failed to open the clipboard"))
+    }
+
+}
+
+impl Drop for OpenedClipboard {
+    fn drop(&mut self) {
+        Clipboard::close();
+    }
+}
+
+
 /**
 A global object that wraps the system clipboard. It can be used to set or get the system cliboard content.
 
@@ -81,6 +144,30 @@ fn clipboard_text(window: &nwg::Window) {
 ```
 
 
+Writing / Reading bitmaps, files and custom formats
+
+```rust
+use native_windows_gui as nwg;
+
+fn clipboard_data(window: &nwg::Window, bitmap: &nwg::Bitmap) -> Result<(), nwg::NwgError> {
+    nwg::Clipboard::set_data_bitmap(window, bitmap)?;
+    let copy = nwg::Clipboard::data_bitmap(window);
+    assert!(copy.is_some());
+
+    nwg::Clipboard::set_data_files(window, &["C:\\Windows\\notepad.exe"])?;
+    let files = nwg::Clipboard::data_files(window);
+    assert_eq!(files, Some(vec!["C:\\Windows\\notepad.exe".to_string()]));
+
+    let format = nwg::Clipboard::register_format("MyApp.Data");
+    nwg::Clipboard::set_data_bytes(window, format, &[1, 2, 3])?;
+    let data = nwg::Clipboard::data_bytes(window, format).unwrap();
+    assert!(data.starts_with(&[1, 2, 3]));
+
+    Ok(())
+}
+```
+
+
 Writing / Reading custom data
 
 ```rust
@@ -220,6 +307,270 @@ impl Clipboard {
         data
     }
 
+    /**
+        Fill the clipboard with a copy of `bitmap`. The data use the `ClipboardFormat::Bitmap` format.
+        Applications that request the `ClipboardFormat::Dib` format receive a copy converted by the system.
+
+        This is a high level function that handles `open` and `close`
+    */
+    pub fn set_data_bitmap<C: Into<ControlHandle>>(handle: C, bitmap: &Bitmap) -> Result<(), NwgError> {
+        use winapi::um::winuser::{SetClipboardData, CopyImage, IMAGE_BITMAP};
+        use winapi::um::wingdi::DeleteObject;
+
+        let _clipboard = OpenedClipboard::open(handle.into())?;
+
+        unsafe {
+            // The clipboard owns the bitmap
+            let copy = CopyImage(bitmap.handle, IMAGE_BITMAP, 0, 0, 0);
+            if copy.is_null() {
+                return Err(NwgError::clipboard("Failed to copy the bitmap"));
+            }
+
+            Clipboard::empty();
+            if SetClipboardData(CF_BITMAP, copy).is_null() {
+                DeleteObject(copy as _);
+                return Err(NwgError::clipboard("Failed to set the clipboard data"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+        Return a copy of the bitmap in the clipboard (if there is one).
+        The bitmap is read from either the `Bitmap` format or the `Dib` format.
+
+        This is a high level function that handles `open` and `close`
+    */
+    pub fn data_bitmap<C: Into<ControlHandle>>(handle: C) -> Option<Bitmap> {
+        use winapi::um::winuser::{GetClipboardData, CopyImage, IMAGE_BITMAP};
+
+        let _clipboard = OpenedClipboard::open(handle.into()).ok()?;
+
+        unsafe {
+            let bitmap = GetClipboardData(CF_BITMAP);
+            if bitmap.is_null() {
+                return None;
+            }
+
+            match CopyImage(bitmap, IMAGE_BITMAP, 0, 0, 0) {
+                copy if copy.is_null() => None,
+                copy => Some(Bitmap { handle: copy, owned: true })
+            }
+        }
+    }
+
+    /**
+        Fill the clipboard with a list of files. The data use the `ClipboardFormat::Files` format,
+        so the files can be pasted in the explorer.
+
+        This is a high level function that handles `open` and `close`
+    */
+    pub fn set_data_files<'a, C: Into<ControlHandle>>(handle: C, files: &[&'a str]) -> Result<(), NwgError> {
+        let data = drop_files_data(files);
+        Clipboard::set_data_bytes(handle, ClipboardFormat::Files, &data)
+    }
+
+    /**
+        Return the list of files in the clipboard (if there is one).
+
+        This is a high level function that handles `open` and `close`
+    */
+    pub fn data_files<C: Into<ControlHandle>>(handle: C) -> Option<Vec<String>> {
+        use winapi::um::winuser::GetClipboardData;
+        use winapi::um::shellapi::{DragQueryFileW, HDROP};
+        use std::ptr;
+
+        let _clipboard = OpenedClipboard::open(handle.into()).ok()?;
+
+        unsafe {
+            // The HDROP is owned by the clipboard and must not be released with `DragFinish`
+            let drop = GetClipboardData(CF_HDROP) as HDROP;
+            if drop.is_null() {
+                return None;
+            }
+
+            let count = DragQueryFileW(drop, 0xFFFFFFFF, ptr::null_mut(), 0);
+            let mut files = Vec::with_capacity(count as usize);
+            for i in 0..count {
+                let buffer_size = DragQueryFileW(drop, i, ptr::null_mut(), 0) + 1;
+                let mut buffer: Vec<u16> = vec![0; buffer_size as usize];
+                DragQueryFileW(drop, i, buffer.as_mut_ptr(), buffer_size);
+                files.push(from_utf16(&buffer));
+            }
+
+            Some(files)
+        }
+    }
+
+    /**
+        Fill the clipboard with a copy of `data` in the selected format.
+        Use `register_format` to share data in a custom format between applications.
+
+        This is a high level function that handles `open` and `close`
+    */
+    pub fn set_data_bytes<C: Into<ControlHandle>>(handle: C, fmt: ClipboardFormat, data: &[u8]) -> Result<(), NwgError> {
+        use winapi::um::winuser::SetClipboardData;
+        use winapi::um::winbase::GlobalFree;
+
+        let _clipboard = OpenedClipboard::open(handle.into())?;
+
+        unsafe {
+            let alloc = global_copy(data)?;
+
+            Clipboard::empty();
+            if SetClipboardData(fmt.into_raw(), alloc as HANDLE).is_null() {
+                GlobalFree(alloc);
+                return Err(NwgError::clipboard("Failed to set the clipboard data"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+        Fill the clipboard with formats that are only rendered when another application reads them (delayed rendering).
+        Use this to offer data that is expensive to produce (ex: large images or generated documents).
+
+        `render` is called on the GUI thread with the requested format and must return the data of the format
+        (see `set_data_bytes` for the layout of the data). Return `None` if the data cannot be rendered.
+        If the owner window is destroyed while it owns the clipboard, every format is rendered before the window is destroyed.
+        The render callback is dropped when another application fills the clipboard.
+
+        The `Bitmap` format cannot be rendered from bytes. Use `Dib` instead.
+
+        `handle` must be a window-like control with an event handler bound (for example a window built by native-windows-derive)
+        because the render requests are sent to the owner window.
+
+        This is a high level function that handles `open` and `close`
+
+        ```rust
+        use native_windows_gui as nwg;
+
+        fn copy_report(window: &nwg::Window) -> Result<(), nwg::NwgError> {
+            nwg::Clipboard::set_data_delayed(window, &[nwg::ClipboardFormat::UnicodeText], |_fmt| {
+                let report = "A very large report";
+                let text: Vec<u8> = report.encode_utf16().chain(Some(0)).flat_map(|c| c.to_le_bytes().to_vec()).collect();
+                Some(text)
+            })
+        }
+        ```
+    */
+    pub fn set_data_delayed<C, F>(handle: C, formats: &[ClipboardFormat], render: F) -> Result<(), NwgError>
+        where C: Into<ControlHandle>,
+              F: Fn(ClipboardFormat) -> Option<Vec<u8>> + 'static
+    {
+        use winapi::um::winuser::SetClipboardData;
+        use std::ptr;
+
+        let handle = handle.into();
+        let owner = handle.hwnd().ok_or_else(|| NwgError::clipboard("The clipboard owner must be a window"))?;
+        if formats.is_empty() {
+            return Err(NwgError::clipboard("No clipboard format to render"));
+        }
+
+        let _clipboard = OpenedClipboard::open(handle)?;
+
+        // Emptying the clipboard drops the render callback of the previous owner
+        Clipboard::empty();
+
+        let formats: Vec<(u32, ClipboardFormat)> = formats.iter().map(|f| (f.into_raw(), *f)).collect();
+        for &(raw, _) in formats.iter() {
+            if unsafe { SetClipboardData(raw, ptr::null_mut()) }.is_null() {
+                return Err(NwgError::clipboard("Failed to set the clipboard data"));
+            }
+        }
+
+        release_delayed_render(owner);
+        DELAYED_RENDERS.with(|renders| {
+            renders.borrow_mut().push(DelayedRender { owner, formats, render: Rc::new(render) });
+        });
+
+        Ok(())
+    }
+
+    /**
+        Return a copy of the clipboard data in the selected format (if there is one).
+        The size of a global allocation may be rounded up by the system, so the data can end with padding bytes.
+
+        This function cannot read the `Bitmap` format because it is not stored in a global allocation. Use `data_bitmap`.
+
+        This is a high level function that handles `open` and `close`
+    */
+    pub fn data_bytes<C: Into<ControlHandle>>(handle: C, fmt: ClipboardFormat) -> Option<Vec<u8>> {
+        use winapi::um::winuser::GetClipboardData;
+        use winapi::um::winbase::{GlobalLock, GlobalUnlock, GlobalSize};
+        use std::slice;
+
+        let _clipboard = OpenedClipboard::open(handle.into()).ok()?;
+
+        unsafe {
+            let data = GetClipboardData(fmt.into_raw());
+            if data.is_null() {
+                return None;
+            }
+
+            let size = GlobalSize(data);
+            let data_ptr = GlobalLock(data) as *const u8;
+            if data_ptr.is_null() {
+                return None;
+            }
+
+            let bytes = slice::from_raw_parts(data_ptr, size).to_vec();
+            GlobalUnlock(data);
+
+            Some(bytes)
+        }
+    }
+
+    /**
+        Register a custom clipboard format and return it. Registering the same name (case-insensitive) twice returns the same format,
+        even in different applications.
+    */
+    pub fn register_format(name: &str) -> ClipboardFormat {
+        use winapi::um::winuser::RegisterClipboardFormatW;
+
+        let name = to_utf16(name);
+        ClipboardFormat::Raw(unsafe { RegisterClipboardFormatW(name.as_ptr()) })
+    }
+
+    /**
+        Return the name of a registered clipboard format. Return `None` for the predefined formats (ex: `UnicodeText`).
+    */
+    pub fn format_name(fmt: ClipboardFormat) -> Option<String> {
+        use winapi::um::winuser::GetClipboardFormatNameW;
+
+        let mut buffer = [0u16; 256];
+        let length = unsafe { GetClipboardFormatNameW(fmt.into_raw(), buffer.as_mut_ptr(), buffer.len() as _) };
+        match length {
+            0 => None,
+            _ => Some(from_utf16(&buffer))
+        }
+    }
+
+    /**
+        Return the formats of the data currently in the clipboard, in the order they were placed in the clipboard.
+
+        This is a high level function that handles `open` and `close`
+    */
+    pub fn formats<C: Into<ControlHandle>>(handle: C) -> Vec<ClipboardFormat> {
+        use winapi::um::winuser::EnumClipboardFormats;
+
+        let mut formats = Vec::new();
+        if let Ok(_clipboard) = OpenedClipboard::open(handle.into()) {
+            let mut format = 0;
+            loop {
+                format = unsafe { EnumClipboardFormats(format) };
+                match format {
+                    0 => break,
+                    f => formats.push(ClipboardFormat::Raw(f))
+                }
+            }
+        }
+
+        formats
+    }
+
     /**
         Remove the current data in the clipboard
     */
@@ -373,6 +724,129 @@ impl Clipboard {
 }
 
 
+/// Copies `data` into a new global allocation. The allocation is freed if it cannot be locked.
+unsafe fn global_copy(data: &[u8]) -> Result<HANDLE, NwgError> {
+    use winapi::um::winbase::{GlobalAlloc, GlobalLock, GlobalFree, GlobalUnlock, GMEM_MOVEABLE};
+    use std::ptr;
+
+    let alloc = GlobalAlloc(GMEM_MOVEABLE, data.len().max(1));
+    if alloc.is_null() {
+        return Err(NwgError::clipboard("Failed to allocate the clipboard data"));
+    }
+
+    let alloc_ptr = GlobalLock(alloc) as *mut u8;
+    if alloc_ptr.is_null() {
+        GlobalFree(alloc);
+        return Err(NwgError::clipboard("Failed to lock the clipboard data"));
+    }
+
+    ptr::copy_nonoverlapping(data.as_ptr(), alloc_ptr, data.len());
+    GlobalUnlock(alloc);
+
+    Ok(alloc as HANDLE)
+}
+
+/// The formats of a clipboard owner that are rendered on request
+struct DelayedRender {
+    owner: HWND,
+    formats: Vec<(u32, ClipboardFormat)>,
+    render: Rc<dyn Fn(ClipboardFormat) -> Option<Vec<u8>>>,
+}
+
+thread_local! {
+    /// The delayed renders of the clipboard owners of the thread. Only one owner at a time owns the clipboard,
+    /// but the render requests may come after the owner lost the clipboard.
+    static DELAYED_RENDERS: RefCell<Vec<DelayedRender>> = RefCell::new(Vec::new());
+}
+
+/// Renders `format` and puts it in the clipboard. Sent to the owner (`WM_RENDERFORMAT`) when an application reads a delayed format.
+/// The clipboard is already open when this is called.
+pub(crate) fn render_format(owner: HWND, format: u32) {
+    use winapi::um::winuser::SetClipboardData;
+    use winapi::um::winbase::GlobalFree;
+
+    // The render callback is called without borrowing the renders, so that it can use the clipboard functions
+    let render = DELAYED_RENDERS.with(|renders| {
+        renders.borrow().iter()
+            .find(|r| r.owner == owner)
+            .and_then(|r| r.formats.iter().find(|(raw, _)| *raw == format).map(|(_, fmt)| (*fmt, r.render.clone())))
+    });
+
+    let data = match render {
+        Some((fmt, render)) => render(fmt),
+        None => None
+    };
+
+    if let Some(data) = data {
+        unsafe {
+            if let Ok(alloc) = global_copy(&data) {
+                if SetClipboardData(format, alloc).is_null() {
+                    GlobalFree(alloc);
+                }
+            }
+        }
+    }
+}
+
+/// Renders every delayed format of `owner`. Sent to the owner (`WM_RENDERALLFORMATS`) before it is destroyed.
+pub(crate) fn render_all_formats(owner: HWND) {
+    use winapi::um::winuser::{OpenClipboard, CloseClipboard, GetClipboardOwner};
+
+    let formats: Vec<u32> = DELAYED_RENDERS.with(|renders| {
+        renders.borrow().iter()
+            .find(|r| r.owner == owner)
+            .map(|r| r.formats.iter().map(|(raw, _)| *raw).collect())
+            .unwrap_or_default()
+    });
+
+    unsafe {
+        if !formats.is_empty() && OpenClipboard(owner) != 0 {
+            // Another application may have filled the clipboard in the meantime
+            if GetClipboardOwner() == owner {
+                for format in formats {
+                    render_format(owner, format);
+                }
+            }
+
+            CloseClipboard();
+        }
+    }
+
+    release_delayed_render(owner);
+}
+
+/// Drops the render callback of `owner`. Sent to the owner (`WM_DESTROYCLIPBOARD`) when the clipboard is emptied.
+pub(crate) fn release_delayed_render(owner: HWND) {
+    // The callback is dropped after the renders are released, in case it owns a control
+    let removed: Vec<DelayedRender> = DELAYED_RENDERS.with(|renders| {
+        let mut renders = renders.borrow_mut();
+        let (removed, kept) = renders.drain(..).partition(|r| r.owner == owner);
+        *renders = kept;
+        removed
+    });
+
+    drop(removed);
+}
+
+/// Returns the `ClipboardFormat::Files` data of a list of files: the header, followed by the null terminated paths, followed by an empty path
+pub(crate) fn drop_files_data(files: &[&str]) -> Vec<u8> {
+    use std::{mem, slice};
+
+    let header = DROPFILES {
+        pFiles: mem::size_of::<DROPFILES>() as DWORD,
+        pt: POINT { x: 0, y: 0 },
+        fNC: 0,
+        fWide: 1,
+    };
+
+    let mut data: Vec<u8> = unsafe { slice::from_raw_parts(&header as *const DROPFILES as *const u8, mem::size_of::<DROPFILES>()).to_vec() };
+    for path in files.iter().map(|f| to_utf16(f)).chain(Some(vec![0u16])) {
+        data.extend(path.iter().flat_map(|c| c.to_le_bytes().to_vec()));
+    }
+
+    data
+}
+
 unsafe fn from_wide_ptr(ptr: *const u16) -> Option<String> {
     use std::slice::from_raw_parts;
     use std::ffi::OsString;
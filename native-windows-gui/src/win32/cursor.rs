@@ -207,4 +207,83 @@ impl GlobalCursor {
         unsafe { DragDetect(handle, c_point) == 1 }
     }
 
+    /**
+        Show the cursor. The cursor is displayed if the display counter returned by the method is greater or equal to 0.
+        Each call to `GlobalCursor::hide` must be balanced by a call to `GlobalCursor::show`.
+    */
+    pub fn show() -> i32 {
+        use winapi::um::winuser::ShowCursor;
+        unsafe { ShowCursor(1) as i32 }
+    }
+
+    /**
+        Hide the cursor. The cursor is hidden if the display counter returned by the method is lesser than 0.
+        Each call to `GlobalCursor::hide` must be balanced by a call to `GlobalCursor::show`.
+    */
+    pub fn hide() -> i32 {
+        use winapi::um::winuser::ShowCursor;
+        unsafe { ShowCursor(0) as i32 }
+    }
+
+    /**
+        Confine the cursor to the client area of a window-like control. The cursor is confined until
+        `GlobalCursor::unclip` is called or until another window confines the cursor.
+
+        Will panic if the control handle passed to the method is not a window or if the control is not yet initialized.
+    */
+    pub fn clip(control: &ControlHandle) {
+        use winapi::shared::windef::{RECT, POINT};
+        use winapi::um::winuser::{GetClientRect, ClientToScreen};
+        const MSG: &'static str = "The cursor can only be clipped to a window control";
+
+        if control.blank() { panic!("{}", MSG); }
+        let handle = control.hwnd().expect(MSG);
+
+        unsafe {
+            let mut r: RECT = ::std::mem::zeroed();
+            GetClientRect(handle, &mut r);
+
+            let mut top_left = POINT { x: r.left, y: r.top };
+            let mut bottom_right = POINT { x: r.right, y: r.bottom };
+            ClientToScreen(handle, &mut top_left);
+            ClientToScreen(handle, &mut bottom_right);
+
+            GlobalCursor::clip_rect([top_left.x, top_left.y, bottom_right.x, bottom_right.y]);
+        }
+    }
+
+    /**
+        Confine the cursor to a [left, top, right, bottom] rectangle in screen coordinates.
+    */
+    pub fn clip_rect(rect: [i32; 4]) {
+        use winapi::shared::windef::RECT;
+        use winapi::um::winuser::ClipCursor;
+
+        let r = RECT { left: rect[0], top: rect[1], right: rect[2], bottom: rect[3] };
+        unsafe { ClipCursor(&r); }
+    }
+
+    /**
+        Let the cursor move anywhere on the screen
+    */
+    pub fn unclip() {
+        use winapi::um::winuser::ClipCursor;
+        unsafe { ClipCursor(::std::ptr::null()); }
+    }
+
+    /**
+        Return the [left, top, right, bottom] rectangle, in screen coordinates, the cursor is confined to.
+        If the cursor is not confined, the rectangle is the virtual screen.
+    */
+    pub fn clip_area() -> [i32; 4] {
+        use winapi::shared::windef::RECT;
+        use winapi::um::winuser::GetClipCursor;
+
+        unsafe {
+            let mut r: RECT = ::std::mem::zeroed();
+            GetClipCursor(&mut r);
+            [r.left, r.top, r.right, r.bottom]
+        }
+    }
+
 }
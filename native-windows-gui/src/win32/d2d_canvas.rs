@@ -0,0 +1,266 @@
+/*!
+    Direct2D render target of the D2DCanvas control
+*/
+use winapi::shared::windef::HWND;
+use winapi::shared::winerror::{S_OK, D2DERR_RECREATE_TARGET};
+use winapi::um::d2d1::*;
+use super::window_helper;
+use crate::NwgError;
+use std::{cell::RefCell, collections::HashMap, ptr};
+
+
+fn color(c: [u8; 4]) -> D2D1_COLOR_F {
+    D2D1_COLOR_F {
+        r: c[0] as f32 / 255.0,
+        g: c[1] as f32 / 255.0,
+        b: c[2] as f32 / 255.0,
+        a: c[3] as f32 / 255.0,
+    }
+}
+
+fn rect(r: [f32; 4]) -> D2D1_RECT_F {
+    D2D1_RECT_F { left: r[0], top: r[1], right: r[2], bottom: r[3] }
+}
+
+fn point(p: [f32; 2]) -> D2D1_POINT_2F {
+    D2D1_POINT_2F { x: p[0], y: p[1] }
+}
+
+
+/// A render target and the resources that were created with it.
+/// The resources must be created again when the render target is recreated.
+struct Target {
+    render_target: *mut ID2D1HwndRenderTarget,
+    brushes: RefCell<HashMap<[u8; 4], *mut ID2D1SolidColorBrush>>,
+    size: (u32, u32),
+}
+
+impl Drop for Target {
+
+    fn drop(&mut self) {
+        unsafe {
+            for &brush in self.brushes.borrow().values() {
+                (&*brush).Release();
+            }
+
+            (&*self.render_target).Release();
+        }
+    }
+
+}
+
+
+/**
+    The drawing functions of a `D2DCanvas`. Only available in the closure passed to `D2DCanvas::draw`.
+
+    Coordinates are in device independent pixels (1/96 of an inch), rectangles are `[left, top, right, bottom]`,
+    points are `[x, y]` and colors are `[r, g, b, a]`.
+
+    The render target is available with `render_target` for the drawing functions that are not wrapped (ex: text, bitmaps, geometries).
+*/
+pub struct D2DContext<'a> {
+    target: &'a Target,
+}
+
+impl<'a> D2DContext<'a> {
+
+    /// Returns the render target. Resources created with the render target must be released by the application
+    /// and created again after the device was lost (`D2DCanvas::draw` is called with a new render target).
+    pub fn render_target(&self) -> &ID2D1HwndRenderTarget {
+        unsafe { &*self.target.render_target }
+    }
+
+    /// Returns the size of the render target in device independent pixels
+    pub fn size(&self) -> [f32; 2] {
+        let (mut dpi_x, mut dpi_y) = (0.0, 0.0);
+        unsafe { self.render_target().GetDpi(&mut dpi_x, &mut dpi_y); }
+
+        let (width, height) = self.target.size;
+        [width as f32 * 96.0 / dpi_x.max(1.0), height as f32 * 96.0 / dpi_y.max(1.0)]
+    }
+
+    /// Returns a solid color brush. Brushes are cached by the canvas, do not release them.
+    pub fn solid_brush(&self, c: [u8; 4]) -> *mut ID2D1Brush {
+        let mut brushes = self.target.brushes.borrow_mut();
+        let brush = brushes.entry(c).or_insert_with(|| {
+            let mut brush = ptr::null_mut();
+            unsafe { self.render_target().CreateSolidColorBrush(&color(c), ptr::null(), &mut brush); }
+            brush
+        });
+
+        *brush as *mut ID2D1Brush
+    }
+
+    /// Fills the whole render target with `c`
+    pub fn clear(&self, c: [u8; 4]) {
+        unsafe { self.render_target().Clear(&color(c)); }
+    }
+
+    /// Sets the transform applied to the drawing. `[[m11, m12], [m21, m22], [dx, dy]]`
+    pub fn set_transform(&self, matrix: [[f32; 2]; 3]) {
+        unsafe { self.render_target().SetTransform(&D2D1_MATRIX_3X2_F { matrix }); }
+    }
+
+    /// Removes the transform applied to the drawing
+    pub fn reset_transform(&self) {
+        self.set_transform([[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]);
+    }
+
+    /// Draws a line of `width` from `from` to `to`
+    pub fn draw_line(&self, from: [f32; 2], to: [f32; 2], c: [u8; 4], width: f32) {
+        let brush = self.solid_brush(c);
+        unsafe { self.render_target().DrawLine(point(from), point(to), brush, width, ptr::null_mut()); }
+    }
+
+    /// Draws the outline of a rectangle
+    pub fn draw_rect(&self, r: [f32; 4], c: [u8; 4], width: f32) {
+        let brush = self.solid_brush(c);
+        unsafe { self.render_target().DrawRectangle(&rect(r), brush, width, ptr::null_mut()); }
+    }
+
+    /// Fills a rectangle
+    pub fn fill_rect(&self, r: [f32; 4], c: [u8; 4]) {
+        let brush = self.solid_brush(c);
+        unsafe { self.render_target().FillRectangle(&rect(r), brush); }
+    }
+
+    /// Fills a rectangle with rounded corners. `radius` is the horizontal and vertical radius of the corners.
+    pub fn fill_rounded_rect(&self, r: [f32; 4], radius: [f32; 2], c: [u8; 4]) {
+        let brush = self.solid_brush(c);
+        let rounded = D2D1_ROUNDED_RECT { rect: rect(r), radiusX: radius[0], radiusY: radius[1] };
+        unsafe { self.render_target().FillRoundedRectangle(&rounded, brush); }
+    }
+
+    /// Draws the outline of an ellipse
+    pub fn draw_ellipse(&self, center: [f32; 2], radius: [f32; 2], c: [u8; 4], width: f32) {
+        let brush = self.solid_brush(c);
+        let ellipse = D2D1_ELLIPSE { point: point(center), radiusX: radius[0], radiusY: radius[1] };
+        unsafe { self.render_target().DrawEllipse(&ellipse, brush, width, ptr::null_mut()); }
+    }
+
+    /// Fills an ellipse
+    pub fn fill_ellipse(&self, center: [f32; 2], radius: [f32; 2], c: [u8; 4]) {
+        let brush = self.solid_brush(c);
+        let ellipse = D2D1_ELLIPSE { point: point(center), radiusX: radius[0], radiusY: radius[1] };
+        unsafe { self.render_target().FillEllipse(&ellipse, brush); }
+    }
+
+}
+
+
+/**
+    Owns the direct2D factory of a canvas and creates its render target when needed
+*/
+pub(crate) struct D2DRenderer {
+    factory: *mut ID2D1Factory,
+    target: RefCell<Option<Target>>,
+}
+
+impl D2DRenderer {
+
+    pub(crate) fn init() -> Result<D2DRenderer, NwgError> {
+        use winapi::ctypes::c_void;
+        use winapi::Interface;
+
+        let mut factory: *mut ID2D1Factory = ptr::null_mut();
+        let result = unsafe {
+            D2D1CreateFactory(
+                D2D1_FACTORY_TYPE_SINGLE_THREADED,
+                &ID2D1Factory::uuidof(),
+                ptr::null(),
+                (&mut factory as *mut *mut ID2D1Factory) as *mut *mut c_void
+            )
+        };
+
+        match result {
+            S_OK => Ok(D2DRenderer { factory, target: RefCell::new(None) }),
+            e => Err(NwgError::direct2d(e, "Failed to create the direct2D factory"))
+        }
+    }
+
+    pub(crate) fn factory(&self) -> *mut ID2D1Factory {
+        self.factory
+    }
+
+    /// Creates or resizes the render target, then calls `draw` between `BeginDraw` and `EndDraw`.
+    /// If the device was lost, the render target is released and the window is invalidated to draw it again with a new render target.
+    pub(crate) fn draw<F: FnOnce(&D2DContext)>(&self, hwnd: HWND, draw: F) -> Result<(), NwgError> {
+        use winapi::um::winuser::{ValidateRect, InvalidateRect};
+
+        let mut target = self.target.borrow_mut();
+        let size = unsafe { window_helper::get_window_physical_size(hwnd) };
+
+        let resized = match target.as_mut() {
+            Some(t) if t.size != size => {
+                t.size = size;
+                let pixel_size = D2D1_SIZE_U { width: size.0, height: size.1 };
+                unsafe { (&*t.render_target).Resize(&pixel_size) == S_OK }
+            },
+            _ => true
+        };
+
+        if !resized || target.is_none() {
+            *target = None;
+            *target = Some(unsafe { build_render_target(&*self.factory, hwnd, size)? });
+        }
+
+        let t = target.as_ref().unwrap();
+        let result = unsafe {
+            let render_target = &*t.render_target;
+            render_target.BeginDraw();
+            draw(&D2DContext { target: t });
+            render_target.EndDraw(ptr::null_mut(), ptr::null_mut())
+        };
+
+        unsafe { ValidateRect(hwnd, ptr::null()); }
+
+        match result {
+            S_OK => Ok(()),
+            D2DERR_RECREATE_TARGET => {
+                *target = None;
+                unsafe { InvalidateRect(hwnd, ptr::null(), 0); }
+                Ok(())
+            },
+            e => Err(NwgError::direct2d(e, "Failed to draw the direct2D render target"))
+        }
+    }
+
+}
+
+impl Drop for D2DRenderer {
+
+    fn drop(&mut self) {
+        // The render target must be released before its factory
+        *self.target.borrow_mut() = None;
+
+        if !self.factory.is_null() {
+            unsafe { (&*self.factory).Release(); }
+        }
+    }
+
+}
+
+unsafe fn build_render_target(factory: &ID2D1Factory, hwnd: HWND, size: (u32, u32)) -> Result<Target, NwgError> {
+    use winapi::shared::dxgiformat::DXGI_FORMAT_B8G8R8A8_UNORM;
+    use winapi::um::dcommon::{D2D1_PIXEL_FORMAT, D2D1_ALPHA_MODE_PREMULTIPLIED};
+
+    let render_props = D2D1_RENDER_TARGET_PROPERTIES {
+        _type: D2D1_RENDER_TARGET_TYPE_DEFAULT,
+        pixelFormat: D2D1_PIXEL_FORMAT { format: DXGI_FORMAT_B8G8R8A8_UNORM, alphaMode: D2D1_ALPHA_MODE_PREMULTIPLIED },
+        dpiX: 0.0, dpiY: 0.0,
+        usage: D2D1_RENDER_TARGET_USAGE_NONE,
+        minLevel: D2D1_FEATURE_LEVEL_DEFAULT
+    };
+
+    let hwnd_render_props = D2D1_HWND_RENDER_TARGET_PROPERTIES {
+        hwnd,
+        pixelSize: D2D1_SIZE_U { width: size.0, height: size.1 },
+        presentOptions: D2D1_PRESENT_OPTIONS_NONE
+    };
+
+    let mut render_target: *mut ID2D1HwndRenderTarget = ptr::null_mut();
+    match factory.CreateHwndRenderTarget(&render_props, &hwnd_render_props, &mut render_target) {
+        S_OK => Ok(Target { render_target, brushes: RefCell::new(HashMap::new()), size }),
+        e => Err(NwgError::direct2d(e, "Failed to create the direct2D render target"))
+    }
+}
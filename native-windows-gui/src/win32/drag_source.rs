@@ -0,0 +1,406 @@
+//! OLE drag source. winapi-rs does not implements IDropSource nor DoDragDrop, so here's the low level stuff.
+//! Also includes a rust implementation of `IDataObject` holding the dragged data in global allocations.
+#![allow(non_snake_case)]
+
+use winapi::um::objidl::{IDataObject, IDataObjectVtbl, IAdviseSink, IEnumFORMATETC, IEnumSTATDATA, FORMATETC, STGMEDIUM, TYMED_HGLOBAL, DATADIR_GET};
+use winapi::um::unknwnbase::{IUnknown, IUnknownVtbl};
+use winapi::shared::minwindef::{BOOL, DWORD, UINT, ULONG};
+use winapi::shared::guiddef::{REFIID, IsEqualGUID};
+use winapi::shared::ntdef::HRESULT;
+use winapi::shared::winerror::{S_OK, S_FALSE, E_NOINTERFACE, E_NOTIMPL, E_POINTER, E_OUTOFMEMORY, DV_E_FORMATETC, OLE_E_ADVISENOTSUPPORTED};
+use winapi::ctypes::c_void;
+use winapi::{Interface, RIDL};
+use super::base_helper::to_utf16;
+use super::clipboard::drop_files_data;
+use crate::{ClipboardFormat, DropEffect, NwgError};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::ptr;
+
+
+RIDL!{#[uuid(0x00000121, 0x0000, 0x0000, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46)]
+interface IDropSource(IDropSourceVtbl): IUnknown(IUnknownVtbl) {
+    fn QueryContinueDrag(
+        fEscapePressed: BOOL,
+        grfKeyState: DWORD,
+    ) -> HRESULT,
+    fn GiveFeedback(
+        dwEffect: DWORD,
+    ) -> HRESULT,
+}}
+
+// DoDragDrop is exported by ole32 and SHCreateStdEnumFmtEtc by shell32. winapi have import libraries
+// for both (with the ole2 and shellapi features), so we can use them if we declare them ourselves.
+extern "system" {
+    fn DoDragDrop(pDataObj: *mut IDataObject, pDropSource: *mut IDropSource, dwOKEffects: DWORD, pdwEffect: *mut DWORD) -> HRESULT;
+    fn SHCreateStdEnumFmtEtc(cfmt: UINT, afmt: *const FORMATETC, ppenumFormatEtc: *mut *mut IEnumFORMATETC) -> HRESULT;
+}
+
+
+/**
+    The data dragged out of the application with `begin_drag`. The data can hold many formats at once,
+    the drop target picks the one it understands.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn drag_files() -> Result<nwg::DropEffect, nwg::NwgError> {
+        let data = nwg::DragData::new()
+            .text("C:\\Users\\Hello\\hello.txt")
+            .files(&["C:\\Users\\Hello\\hello.txt"]);
+
+        nwg::begin_drag(&data, nwg::DropEffect::COPY)
+    }
+    ```
+*/
+#[derive(Default, Clone, Debug)]
+pub struct DragData {
+    pub(crate) formats: Vec<(u32, Vec<u8>)>,
+}
+
+impl DragData {
+
+    pub fn new() -> DragData {
+        DragData::default()
+    }
+
+    /// Adds text to the dragged data. The text use the `ClipboardFormat::UnicodeText` format.
+    pub fn text(self, text: &str) -> DragData {
+        let data: Vec<u8> = to_utf16(text).iter().flat_map(|c| c.to_le_bytes().to_vec()).collect();
+        self.bytes(ClipboardFormat::UnicodeText, &data)
+    }
+
+    /// Adds a list of files to the dragged data. The files use the `ClipboardFormat::Files` format,
+    /// so they can be dropped in the explorer.
+    pub fn files<'a>(self, files: &[&'a str]) -> DragData {
+        let data = drop_files_data(files);
+        self.bytes(ClipboardFormat::Files, &data)
+    }
+
+    /// Adds raw data in `fmt` to the dragged data. Replaces the data already set in the same format.
+    pub fn bytes(mut self, fmt: ClipboardFormat, data: &[u8]) -> DragData {
+        let fmt = fmt.into_raw();
+        self.formats.retain(|(f, _)| *f != fmt);
+        self.formats.push((fmt, data.to_vec()));
+        self
+    }
+
+    /// Returns true if the dragged data contains the format `fmt`
+    pub fn has_format(&self, fmt: ClipboardFormat) -> bool {
+        let fmt = fmt.into_raw();
+        self.formats.iter().any(|(f, _)| *f == fmt)
+    }
+
+}
+
+/**
+    Drags `data` out of the application. `allowed` is the list of effects the drop target can choose from.
+
+    The function must be called while the left (or right) mouse button is pressed, for example from a `OnMousePress`
+    event. It returns when the data is dropped or when the drag is cancelled (with the escape key).
+
+    Returns the effect selected by the drop target or `DropEffect::NONE` if the drag was cancelled.
+    If the effect is `DropEffect::MOVE`, the application should remove the dragged items.
+
+    Requires the `drag-drop` feature.
+*/
+pub fn begin_drag(data: &DragData, allowed: DropEffect) -> Result<DropEffect, NwgError> {
+    let effect = unsafe { do_drag_drop(data.formats.clone(), allowed.bits())? };
+    Ok(DropEffect::from_bits_truncate(effect))
+}
+
+
+/// Starts a drag and drop operation and returns the effect of the drop. OLE is initialized on the current thread if it was not already.
+unsafe fn do_drag_drop(formats: Vec<(u32, Vec<u8>)>, allowed: DWORD) -> Result<DWORD, NwgError> {
+    use winapi::um::ole2::OleInitialize;
+    use winapi::um::oleidl::DROPEFFECT_NONE;
+    use winapi::shared::winerror::{DRAGDROP_S_DROP, DRAGDROP_S_CANCEL};
+
+    if formats.is_empty() {
+        return Err(NwgError::drag_drop("The dragged data is empty"));
+    }
+
+    match OleInitialize(ptr::null_mut()) {
+        S_OK | S_FALSE => {},
+        _ => { return Err(NwgError::drag_drop("OleInitialize failed")); }
+    }
+
+    let data = new_data_object(formats);
+    let source = new_drop_source();
+
+    let mut effect = DROPEFFECT_NONE;
+    let result = DoDragDrop(data, source, allowed, &mut effect);
+
+    (&*data).Release();
+    (&*source).Release();
+
+    match result {
+        DRAGDROP_S_DROP => Ok(effect),
+        DRAGDROP_S_CANCEL => Ok(DROPEFFECT_NONE),
+        _ => Err(NwgError::drag_drop("DoDragDrop failed"))
+    }
+}
+
+
+/// A rust implementation of `IDataObject`. Each format is copied to a new global allocation when the drop target reads it.
+#[repr(C)]
+struct DataObject {
+    vtbl: *const IDataObjectVtbl,
+    ref_count: AtomicU32,
+    formats: Vec<(u32, Vec<u8>)>,
+}
+
+static DATA_OBJECT_VTBL: IDataObjectVtbl = IDataObjectVtbl {
+    parent: IUnknownVtbl {
+        QueryInterface: data_query_interface,
+        AddRef: data_add_ref,
+        Release: data_release,
+    },
+    GetData: data_get_data,
+    GetDataHere: data_get_data_here,
+    QueryGetData: data_query_get_data,
+    GetCanonicalFormatEtc: data_get_canonical_format_etc,
+    SetData: data_set_data,
+    EnumFormatEtc: data_enum_format_etc,
+    DAdvise: data_dadvise,
+    DUnadvise: data_dunadvise,
+    EnumDAdvise: data_enum_dadvise,
+};
+
+pub(crate) fn new_data_object(formats: Vec<(u32, Vec<u8>)>) -> *mut IDataObject {
+    let data = Box::new(DataObject {
+        vtbl: &DATA_OBJECT_VTBL,
+        ref_count: AtomicU32::new(1),
+        formats,
+    });
+
+    Box::into_raw(data) as *mut IDataObject
+}
+
+/// Returns the data of `format` if the format is supported by the data object
+unsafe fn find_format<'a>(this: *mut IDataObject, format: *const FORMATETC) -> Option<&'a [u8]> {
+    use winapi::shared::wtypes::DVASPECT_CONTENT;
+
+    if format.is_null() {
+        return None;
+    }
+
+    let data = &*(this as *mut DataObject);
+    let format = &*format;
+    if format.tymed & TYMED_HGLOBAL == 0 || format.dwAspect != DVASPECT_CONTENT {
+        return None;
+    }
+
+    data.formats.iter()
+        .find(|(f, _)| *f == format.cfFormat as u32)
+        .map(|(_, bytes)| bytes.as_slice())
+}
+
+unsafe extern "system" fn data_query_interface(this: *mut IUnknown, riid: REFIID, out: *mut *mut c_void) -> HRESULT {
+    if out.is_null() {
+        return E_POINTER;
+    }
+
+    let riid = &*riid;
+    if IsEqualGUID(riid, &IUnknown::uuidof()) || IsEqualGUID(riid, &IDataObject::uuidof()) {
+        data_add_ref(this);
+        *out = this as *mut c_void;
+        S_OK
+    } else {
+        *out = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+unsafe extern "system" fn data_add_ref(this: *mut IUnknown) -> ULONG {
+    let data = &*(this as *mut DataObject);
+    data.ref_count.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+unsafe extern "system" fn data_release(this: *mut IUnknown) -> ULONG {
+    let count = {
+        let data = &*(this as *mut DataObject);
+        data.ref_count.fetch_sub(1, Ordering::SeqCst) - 1
+    };
+
+    if count == 0 {
+        drop(Box::from_raw(this as *mut DataObject));
+    }
+
+    count
+}
+
+unsafe extern "system" fn data_get_data(this: *mut IDataObject, format: *const FORMATETC, medium: *mut STGMEDIUM) -> HRESULT {
+    use winapi::um::winbase::{GlobalAlloc, GlobalLock, GlobalUnlock, GlobalFree, GMEM_MOVEABLE};
+
+    if medium.is_null() {
+        return E_POINTER;
+    }
+
+    let bytes = match find_format(this, format) {
+        Some(bytes) => bytes,
+        None => { return DV_E_FORMATETC; }
+    };
+
+    let handle = GlobalAlloc(GMEM_MOVEABLE, bytes.len().max(1));
+    if handle.is_null() {
+        return E_OUTOFMEMORY;
+    }
+
+    let out = GlobalLock(handle) as *mut u8;
+    if out.is_null() {
+        GlobalFree(handle);
+        return E_OUTOFMEMORY;
+    }
+
+    ptr::copy_nonoverlapping(bytes.as_ptr(), out, bytes.len());
+    GlobalUnlock(handle);
+
+    // winapi-rs declares the union of STGMEDIUM as a pointer, the pointer value is the handle.
+    // The drop target owns the allocation and frees it with ReleaseStgMedium.
+    let medium = &mut *medium;
+    medium.tymed = TYMED_HGLOBAL;
+    medium.u = handle as _;
+    medium.pUnkForRelease = ptr::null_mut();
+
+    S_OK
+}
+
+unsafe extern "system" fn data_get_data_here(_this: *mut IDataObject, _format: *const FORMATETC, _medium: *mut STGMEDIUM) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn data_query_get_data(this: *mut IDataObject, format: *const FORMATETC) -> HRESULT {
+    match find_format(this, format).is_some() {
+        true => S_OK,
+        false => DV_E_FORMATETC
+    }
+}
+
+unsafe extern "system" fn data_get_canonical_format_etc(_this: *mut IDataObject, _format: *const FORMATETC, out: *mut FORMATETC) -> HRESULT {
+    if !out.is_null() {
+        (*out).ptd = ptr::null();
+    }
+
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn data_set_data(_this: *mut IDataObject, _format: *const FORMATETC, _medium: *const FORMATETC, _release: BOOL) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn data_enum_format_etc(this: *mut IDataObject, direction: DWORD, out: *mut *mut IEnumFORMATETC) -> HRESULT {
+    use winapi::shared::wtypes::DVASPECT_CONTENT;
+
+    if out.is_null() {
+        return E_POINTER;
+    }
+
+    if direction != DATADIR_GET {
+        *out = ptr::null_mut();
+        return E_NOTIMPL;
+    }
+
+    let data = &*(this as *mut DataObject);
+    let formats: Vec<FORMATETC> = data.formats.iter()
+        .map(|(f, _)| FORMATETC {
+            cfFormat: *f as _,
+            ptd: ptr::null(),
+            dwAspect: DVASPECT_CONTENT,
+            lindex: -1,
+            tymed: TYMED_HGLOBAL,
+        })
+        .collect();
+
+    SHCreateStdEnumFmtEtc(formats.len() as UINT, formats.as_ptr(), out)
+}
+
+unsafe extern "system" fn data_dadvise(_this: *mut IDataObject, _format: *const FORMATETC, _advf: DWORD, _sink: *const IAdviseSink, _connection: *mut DWORD) -> HRESULT {
+    OLE_E_ADVISENOTSUPPORTED
+}
+
+unsafe extern "system" fn data_dunadvise(_this: *mut IDataObject, _connection: DWORD) -> HRESULT {
+    OLE_E_ADVISENOTSUPPORTED
+}
+
+unsafe extern "system" fn data_enum_dadvise(_this: *mut IDataObject, _out: *const *const IEnumSTATDATA) -> HRESULT {
+    OLE_E_ADVISENOTSUPPORTED
+}
+
+
+/// A rust implementation of `IDropSource`. The data is dropped when the mouse buttons are released.
+#[repr(C)]
+struct DropSource {
+    vtbl: *const IDropSourceVtbl,
+    ref_count: AtomicU32,
+}
+
+static DROP_SOURCE_VTBL: IDropSourceVtbl = IDropSourceVtbl {
+    parent: IUnknownVtbl {
+        QueryInterface: source_query_interface,
+        AddRef: source_add_ref,
+        Release: source_release,
+    },
+    QueryContinueDrag: source_query_continue_drag,
+    GiveFeedback: source_give_feedback,
+};
+
+fn new_drop_source() -> *mut IDropSource {
+    let source = Box::new(DropSource {
+        vtbl: &DROP_SOURCE_VTBL,
+        ref_count: AtomicU32::new(1),
+    });
+
+    Box::into_raw(source) as *mut IDropSource
+}
+
+unsafe extern "system" fn source_query_interface(this: *mut IUnknown, riid: REFIID, out: *mut *mut c_void) -> HRESULT {
+    if out.is_null() {
+        return E_POINTER;
+    }
+
+    let riid = &*riid;
+    if IsEqualGUID(riid, &IUnknown::uuidof()) || IsEqualGUID(riid, &IDropSource::uuidof()) {
+        source_add_ref(this);
+        *out = this as *mut c_void;
+        S_OK
+    } else {
+        *out = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+unsafe extern "system" fn source_add_ref(this: *mut IUnknown) -> ULONG {
+    let source = &*(this as *mut DropSource);
+    source.ref_count.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+unsafe extern "system" fn source_release(this: *mut IUnknown) -> ULONG {
+    let count = {
+        let source = &*(this as *mut DropSource);
+        source.ref_count.fetch_sub(1, Ordering::SeqCst) - 1
+    };
+
+    if count == 0 {
+        drop(Box::from_raw(this as *mut DropSource));
+    }
+
+    count
+}
+
+unsafe extern "system" fn source_query_continue_drag(_this: *mut IDropSource, escape: BOOL, keys: DWORD) -> HRESULT {
+    use winapi::um::winuser::{MK_LBUTTON, MK_RBUTTON};
+    use winapi::shared::winerror::{DRAGDROP_S_DROP, DRAGDROP_S_CANCEL};
+
+    if escape != 0 {
+        DRAGDROP_S_CANCEL
+    } else if keys & (MK_LBUTTON | MK_RBUTTON) as DWORD == 0 {
+        DRAGDROP_S_DROP
+    } else {
+        S_OK
+    }
+}
+
+unsafe extern "system" fn source_give_feedback(_this: *mut IDropSource, _effect: DWORD) -> HRESULT {
+    use winapi::shared::winerror::DRAGDROP_S_USEDEFAULTCURSORS;
+    DRAGDROP_S_USEDEFAULTCURSORS
+}
@@ -0,0 +1,304 @@
+//! OLE drag and drop target. winapi-rs declares the `POINTL` parameters of `IDropTarget` as pointers
+//! (they are passed by value), so the vtable of the drop target is declared here.
+#![allow(non_snake_case)]
+
+use winapi::um::objidl::{IDataObject, FORMATETC, STGMEDIUM, TYMED_HGLOBAL};
+use winapi::um::oleidl::IDropTarget;
+use winapi::um::unknwnbase::IUnknown;
+use winapi::um::winnt::HANDLE;
+use winapi::shared::guiddef::{REFIID, IsEqualGUID};
+use winapi::shared::windef::{HWND, POINTL};
+use winapi::shared::minwindef::{UINT, DWORD, ULONG, WPARAM, LPARAM};
+use winapi::shared::ntdef::HRESULT;
+use winapi::shared::winerror::{S_OK, S_FALSE, E_NOINTERFACE};
+use winapi::ctypes::c_void;
+use winapi::Interface;
+use super::base_helper::{to_utf16, from_utf16};
+use super::window_helper as wh;
+use crate::NwgError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::cell::Cell;
+use std::{ptr, slice};
+
+pub const DRAG_ENTER: WPARAM = 0;
+pub const DRAG_OVER: WPARAM = 1;
+pub const DRAG_LEAVE: WPARAM = 2;
+pub const DROP: WPARAM = 3;
+
+lazy_static! {
+    /// Sent to the target window for each drag and drop notification. `w` is the notification and `l` points to a `DropTargetData`
+    /// (null for `DRAG_LEAVE`). The message is registered so that it cannot collide with the messages of the system controls.
+    pub static ref NWG_DRAG_DROP: UINT = unsafe {
+        use winapi::um::winuser::RegisterWindowMessageW;
+
+        let name = to_utf16("NWG_DRAG_DROP");
+        RegisterWindowMessageW(name.as_ptr())
+    };
+}
+
+
+/// The data of a drag and drop notification
+pub struct DropTargetData {
+    pub data: *mut IDataObject,
+    pub keys: DWORD,
+    pub point: [i32; 2],
+    pub allowed: DWORD,
+    pub effect: DWORD,
+}
+
+impl DropTargetData {
+
+    /// Returns true if the dragged data can be read in `format` from a global allocation
+    pub fn has_format(&self, format: u32) -> bool {
+        let format = format_etc(format);
+        unsafe { (&*self.data).QueryGetData(&format) == S_OK }
+    }
+
+    /// Calls `read` with the global allocation holding the dragged data in `format`, then releases the allocation
+    pub fn with_data<T, F: FnOnce(HANDLE) -> T>(&self, format: u32, read: F) -> Option<T> {
+        use winapi::um::winbase::GlobalFree;
+
+        let format = format_etc(format);
+        unsafe {
+            let mut medium: STGMEDIUM = ::std::mem::zeroed();
+            if (&*self.data).GetData(&format, &mut medium) != S_OK {
+                return None;
+            }
+
+            // winapi-rs declares the union of STGMEDIUM as a pointer, the pointer value is the handle
+            let handle = medium.u as HANDLE;
+            let value = match medium.tymed == TYMED_HGLOBAL && !handle.is_null() {
+                true => Some(read(handle)),
+                false => None
+            };
+
+            // Equivalent of ReleaseStgMedium for a global allocation
+            match medium.pUnkForRelease.is_null() {
+                true => { GlobalFree(handle); },
+                false => { (&*medium.pUnkForRelease).Release(); }
+            }
+
+            value
+        }
+    }
+
+    /// Returns a copy of the dragged data in `format`
+    pub fn bytes(&self, format: u32) -> Option<Vec<u8>> {
+        use winapi::um::winbase::{GlobalLock, GlobalUnlock, GlobalSize};
+
+        self.with_data(format, |handle| unsafe {
+            let data = GlobalLock(handle) as *const u8;
+            if data.is_null() {
+                return Vec::new();
+            }
+
+            let bytes = slice::from_raw_parts(data, GlobalSize(handle)).to_vec();
+            GlobalUnlock(handle);
+            bytes
+        })
+    }
+
+    /// Returns the dragged text
+    pub fn text(&self) -> Option<String> {
+        use winapi::um::winuser::CF_UNICODETEXT;
+
+        let bytes = self.bytes(CF_UNICODETEXT)?;
+        let text: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+        Some(from_utf16(&text))
+    }
+
+    /// Returns the dragged files
+    pub fn files(&self) -> Vec<String> {
+        use winapi::um::winuser::CF_HDROP;
+        use winapi::um::shellapi::{DragQueryFileW, HDROP};
+
+        let files = self.with_data(CF_HDROP, |handle| unsafe {
+            let drop = handle as HDROP;
+            let count = DragQueryFileW(drop, 0xFFFFFFFF, ptr::null_mut(), 0);
+            let mut files = Vec::with_capacity(count as usize);
+            for i in 0..count {
+                let buffer_size = DragQueryFileW(drop, i, ptr::null_mut(), 0) + 1;
+                let mut buffer: Vec<u16> = vec![0; buffer_size as usize];
+                DragQueryFileW(drop, i, buffer.as_mut_ptr(), buffer_size);
+                files.push(from_utf16(&buffer));
+            }
+
+            files
+        });
+
+        files.unwrap_or_default()
+    }
+
+}
+
+fn format_etc(format: u32) -> FORMATETC {
+    use winapi::shared::wtypes::DVASPECT_CONTENT;
+
+    FORMATETC {
+        cfFormat: format as _,
+        ptd: ptr::null(),
+        dwAspect: DVASPECT_CONTENT,
+        lindex: -1,
+        tymed: TYMED_HGLOBAL,
+    }
+}
+
+
+/// The `IDropTarget` registered on a window. The drag and drop notifications are sent to the window with the `NWG_DRAG_DROP` message.
+#[repr(C)]
+struct DropTargetObject {
+    vtbl: *const DropTargetVtbl,
+    refs: AtomicUsize,
+    hwnd: HWND,
+    data: Cell<*mut IDataObject>,
+    effect: Cell<DWORD>,
+}
+
+#[repr(C)]
+struct DropTargetVtbl {
+    QueryInterface: unsafe extern "system" fn(*mut DropTargetObject, REFIID, *mut *mut c_void) -> HRESULT,
+    AddRef: unsafe extern "system" fn(*mut DropTargetObject) -> ULONG,
+    Release: unsafe extern "system" fn(*mut DropTargetObject) -> ULONG,
+    DragEnter: unsafe extern "system" fn(*mut DropTargetObject, *mut IDataObject, DWORD, POINTL, *mut DWORD) -> HRESULT,
+    DragOver: unsafe extern "system" fn(*mut DropTargetObject, DWORD, POINTL, *mut DWORD) -> HRESULT,
+    DragLeave: unsafe extern "system" fn(*mut DropTargetObject) -> HRESULT,
+    Drop: unsafe extern "system" fn(*mut DropTargetObject, *mut IDataObject, DWORD, POINTL, *mut DWORD) -> HRESULT,
+}
+
+static DROP_TARGET_VTBL: DropTargetVtbl = DropTargetVtbl {
+    QueryInterface: target_query_interface,
+    AddRef: target_add_ref,
+    Release: target_release,
+    DragEnter: target_drag_enter,
+    DragOver: target_drag_over,
+    DragLeave: target_drag_leave,
+    Drop: target_drop,
+};
+
+unsafe extern "system" fn target_query_interface(this: *mut DropTargetObject, riid: REFIID, out: *mut *mut c_void) -> HRESULT {
+    let riid = &*riid;
+    let supported = [IUnknown::uuidof(), IDropTarget::uuidof()];
+    if supported.iter().any(|iid| IsEqualGUID(iid, riid)) {
+        target_add_ref(this);
+        *out = this as *mut c_void;
+        S_OK
+    } else {
+        *out = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+unsafe extern "system" fn target_add_ref(this: *mut DropTargetObject) -> ULONG {
+    ((*this).refs.fetch_add(1, Ordering::SeqCst) + 1) as ULONG
+}
+
+unsafe extern "system" fn target_release(this: *mut DropTargetObject) -> ULONG {
+    let refs = (*this).refs.fetch_sub(1, Ordering::SeqCst) - 1;
+    if refs == 0 {
+        set_data(&*this, ptr::null_mut());
+        drop(Box::from_raw(this));
+    }
+
+    refs as ULONG
+}
+
+unsafe extern "system" fn target_drag_enter(this: *mut DropTargetObject, data: *mut IDataObject, keys: DWORD, pt: POINTL, effect: *mut DWORD) -> HRESULT {
+    use winapi::um::oleidl::DROPEFFECT_NONE;
+
+    let target = &*this;
+    set_data(target, data);
+    target.effect.set(DROPEFFECT_NONE);
+    notify(target, DRAG_ENTER, keys, pt, effect);
+    S_OK
+}
+
+unsafe extern "system" fn target_drag_over(this: *mut DropTargetObject, keys: DWORD, pt: POINTL, effect: *mut DWORD) -> HRESULT {
+    notify(&*this, DRAG_OVER, keys, pt, effect);
+    S_OK
+}
+
+unsafe extern "system" fn target_drag_leave(this: *mut DropTargetObject) -> HRESULT {
+    let target = &*this;
+    set_data(target, ptr::null_mut());
+    wh::send_message(target.hwnd, *NWG_DRAG_DROP, DRAG_LEAVE, 0);
+    S_OK
+}
+
+unsafe extern "system" fn target_drop(this: *mut DropTargetObject, data: *mut IDataObject, keys: DWORD, pt: POINTL, effect: *mut DWORD) -> HRESULT {
+    let target = &*this;
+    set_data(target, data);
+    notify(target, DROP, keys, pt, effect);
+    set_data(target, ptr::null_mut());
+    S_OK
+}
+
+/// Keeps a reference to the dragged data until the drag leaves the target or the data is dropped
+unsafe fn set_data(target: &DropTargetObject, data: *mut IDataObject) {
+    if !data.is_null() {
+        (&*data).AddRef();
+    }
+
+    let old = target.data.replace(data);
+    if !old.is_null() {
+        (&*old).Release();
+    }
+}
+
+/// Sends the notification to the target window. The effect selected by the event handlers is kept for the next notifications.
+unsafe fn notify(target: &DropTargetObject, notification: WPARAM, keys: DWORD, pt: POINTL, effect: *mut DWORD) {
+    use winapi::um::winuser::ScreenToClient;
+    use winapi::shared::windef::POINT;
+
+    let mut point = POINT { x: pt.x, y: pt.y };
+    ScreenToClient(target.hwnd, &mut point);
+
+    let allowed = *effect;
+    let mut data = DropTargetData {
+        data: target.data.get(),
+        keys,
+        point: [point.x, point.y],
+        allowed,
+        effect: target.effect.get() & allowed,
+    };
+
+    wh::send_message(target.hwnd, *NWG_DRAG_DROP, notification, &mut data as *mut DropTargetData as LPARAM);
+
+    target.effect.set(data.effect);
+    *effect = data.effect & allowed;
+}
+
+
+/// Registers a drop target on `hwnd`. OLE is initialized on the current thread if it was not already.
+pub unsafe fn register(hwnd: HWND) -> Result<(), NwgError> {
+    use winapi::um::ole2::{OleInitialize, RegisterDragDrop};
+    use winapi::shared::winerror::DRAGDROP_E_ALREADYREGISTERED;
+
+    match OleInitialize(ptr::null_mut()) {
+        S_OK | S_FALSE => {},
+        _ => { return Err(NwgError::drag_drop("OleInitialize failed")); }
+    }
+
+    let target = Box::into_raw(Box::new(DropTargetObject {
+        vtbl: &DROP_TARGET_VTBL,
+        refs: AtomicUsize::new(1),
+        hwnd,
+        data: Cell::new(ptr::null_mut()),
+        effect: Cell::new(0),
+    }));
+
+    // RegisterDragDrop keeps its own reference
+    let result = RegisterDragDrop(hwnd, target as *mut IDropTarget);
+    target_release(target);
+
+    match result {
+        S_OK => Ok(()),
+        DRAGDROP_E_ALREADYREGISTERED => Err(NwgError::drag_drop("The control is already a drop target")),
+        _ => Err(NwgError::drag_drop("RegisterDragDrop failed"))
+    }
+}
+
+/// Removes the drop target of `hwnd`
+pub unsafe fn revoke(hwnd: HWND) {
+    use winapi::um::ole2::RevokeDragDrop;
+    RevokeDragDrop(hwnd);
+}
@@ -0,0 +1,85 @@
+/*!
+    Keyboard focus navigation between the controls of a window.
+
+    The tab order of the controls follows their z-order: a control created before another one is selected first.
+    Only the controls with the `WS_TABSTOP` style (the `TAB_STOP` flag of the controls) can be selected with the tab key.
+*/
+use winapi::shared::windef::HWND;
+use crate::ControlHandle;
+
+
+/**
+    Changes the tab order of `controls`. The controls are selected in the order of the slice when the user presses the tab key.
+    The first control keeps its place in the tab order and the other controls are moved right after it.
+
+    Panics if one of the controls is not a window-like control or if the controls do not have the same parent.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn form_order(name: &nwg::TextInput, email: &nwg::TextInput, submit: &nwg::Button) {
+        nwg::set_tab_order(&[name.handle, email.handle, submit.handle]);
+    }
+    ```
+*/
+pub fn set_tab_order(controls: &[ControlHandle]) {
+    use winapi::um::winuser::{GetParent, SetWindowPos, SWP_NOMOVE, SWP_NOSIZE, SWP_NOACTIVATE};
+
+    let handles: Vec<HWND> = controls.iter()
+        .map(|c| c.hwnd().expect("Tab order controls must be window-like controls"))
+        .collect();
+
+    let first = match handles.first() {
+        Some(&first) => first,
+        None => { return; }
+    };
+
+    unsafe {
+        let parent = GetParent(first);
+        if handles.iter().any(|&h| GetParent(h) != parent) {
+            panic!("Tab order controls must have the same parent");
+        }
+
+        // Each control is placed right after the previous one in the z-order
+        let mut previous = first;
+        for &handle in handles.iter().skip(1) {
+            SetWindowPos(handle, previous, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE);
+            previous = handle;
+        }
+    }
+}
+
+/**
+    Moves the keyboard focus to the next control of the window that has the focus, as if the user pressed the tab key.
+    Returns the control that received the focus or `None` if no control of the application has the focus.
+*/
+pub fn focus_next() -> Option<ControlHandle> {
+    unsafe { move_focus(false) }
+}
+
+/**
+    Moves the keyboard focus to the previous control of the window that has the focus, as if the user pressed shift+tab.
+    Returns the control that received the focus or `None` if no control of the application has the focus.
+*/
+pub fn focus_previous() -> Option<ControlHandle> {
+    unsafe { move_focus(true) }
+}
+
+unsafe fn move_focus(previous: bool) -> Option<ControlHandle> {
+    use winapi::um::winuser::{GetFocus, GetAncestor, GetNextDlgTabItem, SetFocus, GA_ROOT};
+
+    let focus = GetFocus();
+    if focus.is_null() {
+        return None;
+    }
+
+    // GetNextDlgTabItem walks the children of the containers with `WS_EX_CONTROLPARENT`
+    let root = GetAncestor(focus, GA_ROOT);
+    let next = GetNextDlgTabItem(root, focus, previous as _);
+    if next.is_null() {
+        return None;
+    }
+
+    SetFocus(next);
+    Some(ControlHandle::Hwnd(next))
+}
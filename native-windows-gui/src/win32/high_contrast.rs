@@ -0,0 +1,162 @@
+/*!
+    High contrast mode detection and system colors.
+
+    When the high contrast mode is enabled, the applications should draw with the system colors instead of their own colors.
+    The controls of native-windows-gui ignore their custom background color in high contrast mode.
+*/
+use winapi::shared::windef::HBRUSH;
+use winapi::ctypes::c_int;
+use super::base_helper::from_utf16;
+use std::mem;
+
+
+/**
+    A color of the system color scheme. In high contrast mode, these are the colors selected by the user.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SystemColor {
+    /// Background of the windows and of the text fields
+    Window,
+    /// Text of the windows
+    WindowText,
+    /// Background of the selected items
+    Highlight,
+    /// Text of the selected items
+    HighlightText,
+    /// Background of the buttons and of the dialogs
+    ButtonFace,
+    /// Text of the buttons
+    ButtonText,
+    /// Text of the disabled controls
+    GrayText,
+    /// Text of the hyperlinks
+    HotLight,
+    /// Background of the tooltips
+    InfoBackground,
+    /// Text of the tooltips
+    InfoText,
+    /// Background of the menus
+    Menu,
+    /// Text of the menus
+    MenuText,
+    /// Title bar of the active window
+    ActiveCaption,
+    /// Title bar text of the active window
+    CaptionText,
+    /// Title bar of the inactive windows
+    InactiveCaption,
+    /// Title bar text of the inactive windows
+    InactiveCaptionText,
+}
+
+impl SystemColor {
+
+    fn into_raw(self) -> c_int {
+        use winapi::um::winuser::*;
+        use SystemColor::*;
+
+        match self {
+            Window => COLOR_WINDOW,
+            WindowText => COLOR_WINDOWTEXT,
+            Highlight => COLOR_HIGHLIGHT,
+            HighlightText => COLOR_HIGHLIGHTTEXT,
+            ButtonFace => COLOR_BTNFACE,
+            ButtonText => COLOR_BTNTEXT,
+            GrayText => COLOR_GRAYTEXT,
+            HotLight => COLOR_HOTLIGHT,
+            InfoBackground => COLOR_INFOBK,
+            InfoText => COLOR_INFOTEXT,
+            Menu => COLOR_MENU,
+            MenuText => COLOR_MENUTEXT,
+            ActiveCaption => COLOR_ACTIVECAPTION,
+            CaptionText => COLOR_CAPTIONTEXT,
+            InactiveCaption => COLOR_INACTIVECAPTION,
+            InactiveCaptionText => COLOR_INACTIVECAPTIONTEXT,
+        }
+    }
+
+}
+
+
+/**
+    Returns true if the high contrast mode is enabled.
+    The `OnSystemColorsChanged` event is sent to the top level windows when the mode is toggled.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn chart_colors() -> ([u8; 3], [u8; 3]) {
+        match nwg::high_contrast() {
+            true => (nwg::system_color(nwg::SystemColor::Window), nwg::system_color(nwg::SystemColor::WindowText)),
+            false => ([255, 255, 255], [30, 100, 200])
+        }
+    }
+    ```
+*/
+pub fn high_contrast() -> bool {
+    use winapi::um::winuser::HCF_HIGHCONTRASTON;
+
+    match unsafe { high_contrast_info() } {
+        Some((flags, _)) => flags & HCF_HIGHCONTRASTON == HCF_HIGHCONTRASTON,
+        None => false
+    }
+}
+
+/**
+    Returns the name of the high contrast color scheme selected by the user (ex: "High Contrast Black").
+    Returns `None` if the high contrast mode is disabled.
+*/
+pub fn high_contrast_scheme() -> Option<String> {
+    use winapi::um::winuser::HCF_HIGHCONTRASTON;
+
+    match unsafe { high_contrast_info() } {
+        Some((flags, scheme)) if flags & HCF_HIGHCONTRASTON == HCF_HIGHCONTRASTON => scheme,
+        _ => None
+    }
+}
+
+/**
+    Returns the current value of a system color as `[r, g, b]`
+*/
+pub fn system_color(color: SystemColor) -> [u8; 3] {
+    use winapi::um::winuser::GetSysColor;
+    use winapi::um::wingdi::{GetRValue, GetGValue, GetBValue};
+
+    let c = unsafe { GetSysColor(color.into_raw()) };
+    [GetRValue(c), GetGValue(c), GetBValue(c)]
+}
+
+/// Returns the brush of the system color `sys_color` if the high contrast mode is enabled, otherwise returns `brush`.
+/// Used by the controls that paint their own background.
+pub(crate) fn background_brush(brush: HBRUSH, sys_color: c_int) -> HBRUSH {
+    use winapi::um::winuser::GetSysColorBrush;
+
+    match high_contrast() {
+        true => unsafe { GetSysColorBrush(sys_color) },
+        false => brush
+    }
+}
+
+/// Returns the high contrast flags and the name of the color scheme
+unsafe fn high_contrast_info() -> Option<(u32, Option<String>)> {
+    use winapi::um::winuser::{SystemParametersInfoW, HIGHCONTRASTW, SPI_GETHIGHCONTRAST};
+
+    let mut info: HIGHCONTRASTW = mem::zeroed();
+    info.cbSize = mem::size_of::<HIGHCONTRASTW>() as _;
+
+    let size = info.cbSize;
+    if SystemParametersInfoW(SPI_GETHIGHCONTRAST, size, &mut info as *mut HIGHCONTRASTW as _, 0) == 0 {
+        return None;
+    }
+
+    let scheme = match info.lpszDefaultScheme.is_null() {
+        true => None,
+        false => {
+            let mut len = 0;
+            while *info.lpszDefaultScheme.offset(len) != 0 { len += 1; }
+            Some(from_utf16(::std::slice::from_raw_parts(info.lpszDefaultScheme, len as usize)))
+        }
+    };
+
+    Some((info.dwFlags, scheme))
+}
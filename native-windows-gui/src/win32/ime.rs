@@ -0,0 +1,100 @@
+//! winapi-rs only implements a small part of imm.h, so here's the low level stuff.
+//! The input method editor (IME) of the East Asian languages composes the text in a composition string before sending it to the focused window.
+#![allow(non_snake_case)]
+
+use winapi::shared::minwindef::{BOOL, DWORD, LPVOID};
+use winapi::shared::windef::{HWND, POINT, RECT};
+use winapi::shared::ntdef::LONG;
+use winapi::um::imm::HIMC;
+use std::ptr;
+
+
+pub const GCS_COMPSTR: u32 = 0x0008;
+pub const GCS_CURSORPOS: u32 = 0x0080;
+pub const GCS_RESULTSTR: u32 = 0x0800;
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct CANDIDATEFORM {
+    dwIndex: DWORD,
+    dwStyle: DWORD,
+    ptCurrentPos: POINT,
+    rcArea: RECT,
+}
+
+extern "system" {
+    fn ImmGetCompositionStringW(himc: HIMC, index: DWORD, buf: LPVOID, buf_len: DWORD) -> LONG;
+    fn ImmSetCandidateWindow(himc: HIMC, candidate: *mut CANDIDATEFORM) -> BOOL;
+}
+
+/// Calls `f` with the input context of `hwnd`. Returns `None` if the window does not have an input context.
+unsafe fn with_context<T, F: FnOnce(HIMC) -> T>(hwnd: HWND, f: F) -> Option<T> {
+    use winapi::um::imm::{ImmGetContext, ImmReleaseContext};
+
+    let himc = ImmGetContext(hwnd);
+    if himc.is_null() {
+        return None;
+    }
+
+    let value = f(himc);
+    ImmReleaseContext(hwnd, himc);
+
+    Some(value)
+}
+
+/// Returns the composition string at `index` (`GCS_COMPSTR` or `GCS_RESULTSTR`).
+/// Returns `None` if the window does not have an input context or if the string is not available.
+pub unsafe fn composition_string(hwnd: HWND, index: u32) -> Option<String> {
+    with_context(hwnd, |himc| {
+        let size = ImmGetCompositionStringW(himc, index, ptr::null_mut(), 0);
+        if size < 0 {
+            return None;
+        }
+
+        // The size is in bytes
+        let mut buffer: Vec<u16> = vec![0; (size as usize) / 2];
+        ImmGetCompositionStringW(himc, index, buffer.as_mut_ptr() as LPVOID, size as DWORD);
+
+        Some(String::from_utf16_lossy(&buffer))
+    }).flatten()
+}
+
+/// Returns the position of the cursor in the composition string, in utf16 units
+pub unsafe fn composition_cursor(hwnd: HWND) -> Option<usize> {
+    with_context(hwnd, |himc| {
+        match ImmGetCompositionStringW(himc, GCS_CURSORPOS, ptr::null_mut(), 0) {
+            c if c < 0 => None,
+            c => Some(c as usize)
+        }
+    }).flatten()
+}
+
+/// Moves the composition window and the candidate window of `hwnd` to `(x, y)` in physical client coordinates.
+/// The candidate window is displayed under the position.
+pub unsafe fn set_position(hwnd: HWND, x: i32, y: i32) -> bool {
+    use winapi::um::imm::{ImmSetCompositionWindow, COMPOSITIONFORM, CFS_POINT, CFS_CANDIDATEPOS};
+
+    with_context(hwnd, |himc| {
+        let pos = POINT { x, y };
+        let area = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+
+        let mut composition = COMPOSITIONFORM { dwStyle: CFS_POINT, ptCurrentPos: pos, rcArea: area };
+        let mut candidate = CANDIDATEFORM { dwIndex: 0, dwStyle: CFS_CANDIDATEPOS, ptCurrentPos: pos, rcArea: area };
+
+        ImmSetCompositionWindow(himc, &mut composition) != 0 && ImmSetCandidateWindow(himc, &mut candidate) != 0
+    }).unwrap_or(false)
+}
+
+/// Returns true if the IME of `hwnd` is open
+pub unsafe fn open_status(hwnd: HWND) -> bool {
+    use winapi::um::imm::ImmGetOpenStatus;
+
+    with_context(hwnd, |himc| ImmGetOpenStatus(himc) != 0).unwrap_or(false)
+}
+
+/// Opens or closes the IME of `hwnd`. Returns false if the window does not have an input context.
+pub unsafe fn set_open_status(hwnd: HWND, open: bool) -> bool {
+    use winapi::um::imm::ImmSetOpenStatus;
+
+    with_context(hwnd, |himc| ImmSetOpenStatus(himc, open as BOOL) != 0).unwrap_or(false)
+}
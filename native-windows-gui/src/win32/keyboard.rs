@@ -0,0 +1,229 @@
+use winapi::um::winuser::{GetKeyState, GetAsyncKeyState};
+use winapi::ctypes::c_int;
+use crate::keys;
+
+bitflags! {
+    /**
+        The modifier keys held down
+
+        * SHIFT:   One of the shift keys
+        * CONTROL: One of the control keys
+        * ALT:     One of the alt keys
+        * WIN:     One of the windows keys
+    */
+    pub struct KeyModifiers: u32 {
+        const NONE = 0;
+        const SHIFT = 0b0001;
+        const CONTROL = 0b0010;
+        const ALT = 0b0100;
+        const WIN = 0b1000;
+    }
+}
+
+macro_rules! keys_enum {
+    ($($name:ident = $code:ident,)*) => {
+        /**
+            A keyboard key. Converts to and from the virtual key codes of the `nwg::keys` module.
+            `EventData::on_key_typed` returns the key of the `OnKeyPress` and `OnKeyRelease` events as a `Key`.
+
+            Keys that do not have a variant are wrapped in `Key::Other`. A code that has a variant is never converted to `Key::Other`.
+        */
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum Key {
+            $($name,)*
+            Other(u32),
+        }
+
+        impl From<u32> for Key {
+            fn from(code: u32) -> Key {
+                match code {
+                    $(keys::$code => Key::$name,)*
+                    code => Key::Other(code),
+                }
+            }
+        }
+
+        impl From<Key> for u32 {
+            fn from(key: Key) -> u32 {
+                match key {
+                    $(Key::$name => keys::$code,)*
+                    Key::Other(code) => code,
+                }
+            }
+        }
+    };
+}
+
+keys_enum! {
+    Back = BACK,
+    Tab = TAB,
+    Return = RETURN,
+    Shift = SHIFT,
+    Control = CONTROL,
+    Alt = ALT,
+    Pause = PAUSE,
+    CapsLock = CAPITAL,
+    Escape = ESCAPE,
+    Space = SPACE,
+    PageUp = PRIOR,
+    PageDown = NEXT,
+    End = END,
+    Home = HOME,
+    Left = LEFT,
+    Up = UP,
+    Right = RIGHT,
+    Down = DOWN,
+    PrintScreen = SNAPSHOT,
+    Insert = INSERT,
+    Delete = DELETE,
+    Digit0 = _0,
+    Digit1 = _1,
+    Digit2 = _2,
+    Digit3 = _3,
+    Digit4 = _4,
+    Digit5 = _5,
+    Digit6 = _6,
+    Digit7 = _7,
+    Digit8 = _8,
+    Digit9 = _9,
+    A = _A,
+    B = _B,
+    C = _C,
+    D = _D,
+    E = _E,
+    F = _F,
+    G = _G,
+    H = _H,
+    I = _I,
+    J = _J,
+    K = _K,
+    L = _L,
+    M = _M,
+    N = _N,
+    O = _O,
+    P = _P,
+    Q = _Q,
+    R = _R,
+    S = _S,
+    T = _T,
+    U = _U,
+    V = _V,
+    W = _W,
+    X = _X,
+    Y = _Y,
+    Z = _Z,
+    LeftWin = LWIN,
+    RightWin = RWIN,
+    Apps = APPS,
+    Numpad0 = NUMPAD0,
+    Numpad1 = NUMPAD1,
+    Numpad2 = NUMPAD2,
+    Numpad3 = NUMPAD3,
+    Numpad4 = NUMPAD4,
+    Numpad5 = NUMPAD5,
+    Numpad6 = NUMPAD6,
+    Numpad7 = NUMPAD7,
+    Numpad8 = NUMPAD8,
+    Numpad9 = NUMPAD9,
+    Multiply = MULTIPLY,
+    Add = ADD,
+    Separator = SEPARATOR,
+    Subtract = SUBTRACT,
+    Decimal = DECIMAL,
+    Divide = DIVIDE,
+    F1 = F1,
+    F2 = F2,
+    F3 = F3,
+    F4 = F4,
+    F5 = F5,
+    F6 = F6,
+    F7 = F7,
+    F8 = F8,
+    F9 = F9,
+    F10 = F10,
+    F11 = F11,
+    F12 = F12,
+    F13 = F13,
+    F14 = F14,
+    F15 = F15,
+    F16 = F16,
+    F17 = F17,
+    F18 = F18,
+    F19 = F19,
+    F20 = F20,
+    F21 = F21,
+    F22 = F22,
+    F23 = F23,
+    F24 = F24,
+    NumLock = NUMLOCK,
+    ScrollLock = SCROLL,
+    LeftShift = LSHIFT,
+    RightShift = RSHIFT,
+    LeftControl = LCONTROL,
+    RightControl = RCONTROL,
+    LeftAlt = LMENU,
+    RightAlt = RMENU,
+}
+
+/**
+    Expose the state of the keyboard. The keys are passed as a `Key`, the same type as the one returned by `EventData::on_key_typed`.
+
+    `is_key_down`, `modifiers` and the lock methods return the keyboard state of the last message read by the thread,
+    which is the state when the event being handled was sent. `is_key_down_now` returns the physical state of a key.
+
+    This object cannot be instanced. The methods should be used this way:
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn on_key(key: nwg::Key) {
+        if key == nwg::Key::S && nwg::Keyboard::modifiers() == nwg::KeyModifiers::CONTROL {
+            println!("Save");
+        }
+    }
+    ```
+*/
+pub struct Keyboard;
+
+impl Keyboard {
+
+    /// Returns `true` if `key` was down when the current message was sent
+    pub fn is_key_down(key: Key) -> bool {
+        unsafe { GetKeyState(u32::from(key) as c_int) < 0 }
+    }
+
+    /// Returns `true` if `key` is down at the time of the call. Useful for games that poll the keyboard.
+    pub fn is_key_down_now(key: Key) -> bool {
+        unsafe { GetAsyncKeyState(u32::from(key) as c_int) < 0 }
+    }
+
+    /// Returns the modifier keys that were down when the current message was sent
+    pub fn modifiers() -> KeyModifiers {
+        let mut modifiers = KeyModifiers::NONE;
+        modifiers.set(KeyModifiers::SHIFT, Keyboard::is_key_down(Key::Shift));
+        modifiers.set(KeyModifiers::CONTROL, Keyboard::is_key_down(Key::Control));
+        modifiers.set(KeyModifiers::ALT, Keyboard::is_key_down(Key::Alt));
+        modifiers.set(KeyModifiers::WIN, Keyboard::is_key_down(Key::LeftWin) || Keyboard::is_key_down(Key::RightWin));
+        modifiers
+    }
+
+    /// Returns `true` if caps lock is on
+    pub fn caps_lock() -> bool {
+        Keyboard::toggled(Key::CapsLock)
+    }
+
+    /// Returns `true` if num lock is on
+    pub fn num_lock() -> bool {
+        Keyboard::toggled(Key::NumLock)
+    }
+
+    /// Returns `true` if scroll lock is on
+    pub fn scroll_lock() -> bool {
+        Keyboard::toggled(Key::ScrollLock)
+    }
+
+    fn toggled(key: Key) -> bool {
+        unsafe { GetKeyState(u32::from(key) as c_int) & 1 == 1 }
+    }
+
+}
@@ -0,0 +1,140 @@
+//! winapi-rs does not implements Video for Windows, so here's the low level stuff used by the MediaPlayer control.
+//! The MCIWnd window class is registered by loading msvfw32.dll at runtime. The MCIWnd macros of `vfw.h` are messages sent to the window.
+#![allow(non_snake_case)]
+
+use winapi::shared::windef::HWND;
+use winapi::shared::minwindef::{UINT, BOOL};
+use winapi::um::winuser::WM_USER;
+use super::base_helper::{to_utf16, from_utf16};
+use super::window_helper as wh;
+use crate::NwgError;
+use std::{mem, ptr};
+
+
+pub const MCIWND_CLASS: &'static str = "MCIWndClass";
+
+// MCIWnd window styles
+pub const MCIWNDF_NOAUTOSIZEWINDOW: u32 = 0x0001;
+pub const MCIWNDF_NOPLAYBAR: u32 = 0x0002;
+pub const MCIWNDF_NOMENU: u32 = 0x0008;
+pub const MCIWNDF_NOTIFYMODE: u32 = 0x0100;
+pub const MCIWNDF_NOERRORDLG: u32 = 0x4000;
+pub const MCIWNDF_NOOPEN: u32 = 0x8000;
+
+// MCI commands
+pub const MCI_CLOSE: UINT = 0x0804;
+pub const MCI_PLAY: UINT = 0x0806;
+pub const MCI_SEEK: UINT = 0x0807;
+pub const MCI_STOP: UINT = 0x0808;
+pub const MCI_PAUSE: UINT = 0x0809;
+
+// MCIWnd messages
+pub const MCIWNDM_GETPOSITION: UINT = WM_USER + 102;
+pub const MCIWNDM_GETLENGTH: UINT = WM_USER + 104;
+pub const MCIWNDM_GETEND: UINT = WM_USER + 105;
+pub const MCIWNDM_GETMODE: UINT = WM_USER + 106;
+pub const MCIWNDM_SETVOLUME: UINT = WM_USER + 110;
+pub const MCIWNDM_GETVOLUME: UINT = WM_USER + 111;
+pub const MCIWNDM_CHANGESTYLES: UINT = WM_USER + 135;
+pub const MCIWNDM_GETSTYLES: UINT = WM_USER + 136;
+pub const MCIWNDM_SETTIMEFORMATW: UINT = WM_USER + 219;
+pub const MCIWNDM_GETERRORW: UINT = WM_USER + 228;
+pub const MCIWNDM_OPENW: UINT = WM_USER + 252;
+
+/// Sent by the MCIWnd window to its parent when the mode of the device changes
+pub const MCIWNDM_NOTIFYMODE: UINT = WM_USER + 200;
+
+// MCI device modes
+pub const MCI_MODE_NOT_READY: UINT = 524;
+pub const MCI_MODE_STOP: UINT = 525;
+pub const MCI_MODE_PLAY: UINT = 526;
+pub const MCI_MODE_SEEK: UINT = 528;
+pub const MCI_MODE_PAUSE: UINT = 529;
+
+
+lazy_static! {
+    static ref CLASS_REGISTERED: bool = unsafe { register_class() };
+}
+
+/// Calls `MCIWndRegisterClass`. Returns false if msvfw32.dll is not available.
+unsafe fn register_class() -> bool {
+    use winapi::um::libloaderapi::{LoadLibraryW, GetProcAddress};
+
+    let lib = to_utf16("msvfw32.dll");
+    let module = LoadLibraryW(lib.as_ptr());
+    if module.is_null() {
+        return false;
+    }
+
+    let register = GetProcAddress(module, "MCIWndRegisterClass\0".as_ptr() as _);
+    if register.is_null() {
+        return false;
+    }
+
+    let register: unsafe extern "C" fn() -> BOOL = mem::transmute(register);
+    register() != 0
+}
+
+/// Creates a MCIWnd window filling the client area of `parent`. The mode changes are sent to `parent`.
+pub unsafe fn create_player(parent: HWND, playbar: bool) -> Result<HWND, NwgError> {
+    use winapi::um::winuser::{CreateWindowExW, WS_CHILD, WS_VISIBLE};
+    use winapi::um::libloaderapi::GetModuleHandleW;
+
+    if !*CLASS_REGISTERED {
+        return Err(NwgError::control_create("Failed to register the MCIWnd window class (msvfw32.dll)"));
+    }
+
+    let mut style = WS_CHILD | WS_VISIBLE | MCIWNDF_NOMENU | MCIWNDF_NOAUTOSIZEWINDOW | MCIWNDF_NOTIFYMODE | MCIWNDF_NOERRORDLG | MCIWNDF_NOOPEN;
+    if !playbar {
+        style |= MCIWNDF_NOPLAYBAR;
+    }
+
+    let (width, height) = wh::get_window_physical_size(parent);
+    let class_name = to_utf16(MCIWND_CLASS);
+    let hwnd = CreateWindowExW(
+        0,
+        class_name.as_ptr(),
+        ptr::null(),
+        style,
+        0, 0, width as i32, height as i32,
+        parent,
+        ptr::null_mut(),
+        GetModuleHandleW(ptr::null_mut()),
+        ptr::null_mut()
+    );
+
+    match hwnd.is_null() {
+        true => Err(NwgError::control_create("Failed to create the MCIWnd window")),
+        false => Ok(hwnd)
+    }
+}
+
+/// Opens a media file and sets the time format to milliseconds
+pub unsafe fn open(player: HWND, path: &str) -> Result<(), NwgError> {
+    let path = to_utf16(path);
+    if wh::send_message(player, MCIWNDM_OPENW, 0, path.as_ptr() as _) != 0 {
+        return Err(NwgError::media_player(error_message(player)));
+    }
+
+    let format = to_utf16("ms");
+    wh::send_message(player, MCIWNDM_SETTIMEFORMATW, 0, format.as_ptr() as _);
+
+    Ok(())
+}
+
+/// Returns the description of the last MCI error of the player
+pub unsafe fn error_message(player: HWND) -> String {
+    let mut buffer = [0u16; 256];
+    wh::send_message(player, MCIWNDM_GETERRORW, buffer.len(), buffer.as_mut_ptr() as _);
+
+    match from_utf16(&buffer) {
+        message if message.is_empty() => "Failed to open the media file".to_string(),
+        message => message
+    }
+}
+
+/// Returns true if the player stopped at the end of the media
+pub unsafe fn at_end(player: HWND) -> bool {
+    let end = wh::send_message(player, MCIWNDM_GETEND, 0, 0);
+    end > 0 && wh::send_message(player, MCIWNDM_GETPOSITION, 0, 0) >= end
+}
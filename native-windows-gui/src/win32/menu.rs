@@ -1,28 +1,67 @@
 /*!
 Native Windows GUI menu base.
 */
-use winapi::shared::windef::{HMENU, HWND};
+use winapi::shared::windef::{HMENU, HWND, HBITMAP};
 use winapi::shared::minwindef::UINT;
-use super::base_helper::{CUSTOM_ID_BEGIN, to_utf16};
+use super::base_helper::{CUSTOM_ID_BEGIN, to_utf16, from_utf16};
 use crate::controls::ControlHandle;
 use crate::{NwgError};
+use winapi::um::winuser::{ACCEL, MSG};
+use winapi::shared::windef::HACCEL;
 use std::{mem, ptr};
+use std::cell::RefCell;
 use std::sync::atomic::{AtomicU32, Ordering};
 
 
 static MENU_ITEMS_ID: AtomicU32 = AtomicU32::new(CUSTOM_ID_BEGIN); 
 
+thread_local! {
+    /// Keyboard accelerators registered from the menu items text. They are shared by every window of the thread.
+    static ACCELERATORS: RefCell<AcceleratorTable> = RefCell::new(AcceleratorTable::default());
+
+    /// Menu bars detached from their window by `set_menu_bar_visible`
+    static HIDDEN_MENU_BARS: RefCell<Vec<(HWND, HMENU)>> = RefCell::new(Vec::new());
+}
+
+#[derive(Default)]
+struct AcceleratorTable {
+    entries: Vec<(HMENU, ACCEL)>,
+    handle: Option<HACCEL>,
+}
+
+impl AcceleratorTable {
+
+    /// Recreate the winapi accelerator table from the entries
+    unsafe fn rebuild(&mut self) {
+        use winapi::um::winuser::{CreateAcceleratorTableW, DestroyAcceleratorTable};
+
+        if let Some(handle) = self.handle.take() {
+            DestroyAcceleratorTable(handle);
+        }
+
+        if !self.entries.is_empty() {
+            let mut accels: Vec<ACCEL> = self.entries.iter().map(|(_, a)| *a).collect();
+            let handle = CreateAcceleratorTableW(accels.as_mut_ptr(), accels.len() as i32);
+            if !handle.is_null() {
+                self.handle = Some(handle);
+            }
+        }
+    }
+
+}
+
 
 /// Build a system menu
-pub unsafe fn build_hmenu_control(text: Option<String>, item: bool, separator: bool, popup: bool, hmenu: Option<HMENU>, hwnd: Option<HWND>) -> Result<ControlHandle, NwgError> {
-    use winapi::um::winuser::{CreateMenu, CreatePopupMenu, GetMenu, SetMenu, DrawMenuBar, AppendMenuW};
+/// If `position` is set, the menu is inserted at this position in its parent instead of being appended.
+pub unsafe fn build_hmenu_control(text: Option<String>, item: bool, separator: bool, popup: bool, position: Option<UINT>, hmenu: Option<HMENU>, hwnd: Option<HWND>) -> Result<ControlHandle, NwgError> {
+    use winapi::um::winuser::{CreateMenu, CreatePopupMenu, DrawMenuBar};
     use winapi::um::winuser::{MF_STRING, MF_POPUP};
 
     if separator {
         if hmenu.is_none() {
             return Err(NwgError::menu_create("Separator without parent"));
         }
-        return Ok(build_hmenu_separator(hmenu.unwrap()));
+        return Ok(build_hmenu_separator(hmenu.unwrap(), position));
     }
 
     if popup {
@@ -51,18 +90,18 @@ pub unsafe fn build_hmenu_control(text: Option<String>, item: bool, separator: b
 
     if hwnd.is_some() {
         let hwnd = hwnd.unwrap();
-        let mut menubar = GetMenu(hwnd);
+        let mut menubar = menu_bar(hwnd);
         if menubar.is_null() {
             // If the window do not have a menu bar, create one
             menubar = CreateMenu();
             use_menu_command(menubar);
-            SetMenu(hwnd, menubar);
+            set_menu_bar(hwnd, menubar);
         }
 
         if item {
             menu = menubar;
             item_id = MENU_ITEMS_ID.fetch_add(1, Ordering::SeqCst);
-            AppendMenuW(menubar, flags, item_id as usize, text.as_ptr());
+            insert_menu(menubar, position, flags, item_id as usize, text.as_ptr());
         } else {
             parent_menu = menubar;
             menu = CreateMenu();
@@ -70,7 +109,7 @@ pub unsafe fn build_hmenu_control(text: Option<String>, item: bool, separator: b
                 return Err(NwgError::menu_create("Menu without parent"));
             }
             use_menu_command(menu);
-            AppendMenuW(menubar, flags, mem::transmute(menu), text.as_ptr());
+            insert_menu(menubar, position, flags, mem::transmute(menu), text.as_ptr());
         }
 
         // Draw the menu bar to make sure the changes are visible
@@ -81,7 +120,7 @@ pub unsafe fn build_hmenu_control(text: Option<String>, item: bool, separator: b
         if item {
             menu = parent;
             item_id = MENU_ITEMS_ID.fetch_add(1, Ordering::SeqCst);
-            AppendMenuW(parent, flags, item_id as usize, text.as_ptr());
+            insert_menu(parent, position, flags, item_id as usize, text.as_ptr());
         } else {
             parent_menu = parent;
             menu = CreateMenu();
@@ -89,7 +128,7 @@ pub unsafe fn build_hmenu_control(text: Option<String>, item: bool, separator: b
                 return Err(NwgError::menu_create("Menu without parent"));
             }
             use_menu_command(menu);
-            AppendMenuW(parent, flags, mem::transmute(menu), text.as_ptr());
+            insert_menu(parent, position, flags, mem::transmute(menu), text.as_ptr());
         }
     }
 
@@ -190,19 +229,125 @@ pub unsafe fn menu_item_checked(parent_menu: HMENU, id: u32) -> bool {
     GetMenuState(parent_menu, id, MF_BYCOMMAND) & MF_CHECKED == MF_CHECKED
 }
 
+/// Search the menu and its submenus for the parent of the menu item with the id `id`
+pub unsafe fn find_menu_item_parent(menu: HMENU, id: u32) -> Option<HMENU> {
+    use winapi::um::winuser::{GetMenuItemCount, GetMenuItemID, GetSubMenu};
+
+    let children_count = GetMenuItemCount(menu);
+    for i in 0..children_count {
+        let sub_menu = GetSubMenu(menu, i);
+        if sub_menu.is_null() {
+            if GetMenuItemID(menu, i) == id { return Some(menu); }
+        } else if let Some(parent) = find_menu_item_parent(sub_menu, id) {
+            return Some(parent);
+        }
+    }
+
+    None
+}
+
+/// Toggle the radio check mark style of a menu item. The other type flags of the item are preserved.
+pub unsafe fn set_menu_item_radio(parent_menu: HMENU, id: u32, radio: bool) {
+    use winapi::um::winuser::{GetMenuItemInfoW, SetMenuItemInfoW, MENUITEMINFOW, MIIM_FTYPE, MFT_RADIOCHECK};
+    use winapi::shared::minwindef::BOOL;
+
+    let mut info = MENUITEMINFOW { 
+        cbSize: mem::size_of::<MENUITEMINFOW>() as UINT,
+        fMask: MIIM_FTYPE, fType: 0, fState: 0,
+        wID: 0,
+        hSubMenu: ptr::null_mut(), hbmpChecked: ptr::null_mut(),
+        hbmpUnchecked: ptr::null_mut(), dwItemData: 0, dwTypeData: ptr::null_mut(),
+        cch: 0, hbmpItem: ptr::null_mut()
+    };
+
+    GetMenuItemInfoW(parent_menu, id, false as BOOL, &mut info);
+
+    info.fType = match radio {
+        true => info.fType | MFT_RADIOCHECK,
+        false => info.fType & !MFT_RADIOCHECK
+    };
+
+    SetMenuItemInfoW(parent_menu, id, false as BOOL, &mut info);
+}
+
+/// Toggle the owner draw style of a menu item. Owner drawn items store their parent menu in their item data,
+/// because `WM_MEASUREITEM` does not include the menu handle.
+pub unsafe fn set_menu_item_owner_draw(parent_menu: HMENU, id: u32, owner_draw: bool) {
+    use winapi::um::winuser::{GetMenuItemInfoW, SetMenuItemInfoW, MENUITEMINFOW, MIIM_FTYPE, MIIM_DATA, MFT_OWNERDRAW};
+    use winapi::shared::minwindef::BOOL;
+
+    let mut info = MENUITEMINFOW { 
+        cbSize: mem::size_of::<MENUITEMINFOW>() as UINT,
+        fMask: MIIM_FTYPE, fType: 0, fState: 0,
+        wID: 0,
+        hSubMenu: ptr::null_mut(), hbmpChecked: ptr::null_mut(),
+        hbmpUnchecked: ptr::null_mut(), dwItemData: 0, dwTypeData: ptr::null_mut(),
+        cch: 0, hbmpItem: ptr::null_mut()
+    };
+
+    GetMenuItemInfoW(parent_menu, id, false as BOOL, &mut info);
+
+    info.fMask = MIIM_FTYPE | MIIM_DATA;
+    info.dwItemData = parent_menu as usize;
+    info.fType = match owner_draw {
+        true => info.fType | MFT_OWNERDRAW,
+        false => info.fType & !MFT_OWNERDRAW
+    };
+
+    SetMenuItemInfoW(parent_menu, id, false as BOOL, &mut info);
+}
+
+pub unsafe fn menu_item_owner_draw(parent_menu: HMENU, id: u32) -> bool {
+    use winapi::um::winuser::{GetMenuItemInfoW, MENUITEMINFOW, MIIM_FTYPE, MFT_OWNERDRAW};
+    use winapi::shared::minwindef::BOOL;
+
+    let mut info = MENUITEMINFOW { 
+        cbSize: mem::size_of::<MENUITEMINFOW>() as UINT,
+        fMask: MIIM_FTYPE, fType: 0, fState: 0,
+        wID: 0,
+        hSubMenu: ptr::null_mut(), hbmpChecked: ptr::null_mut(),
+        hbmpUnchecked: ptr::null_mut(), dwItemData: 0, dwTypeData: ptr::null_mut(),
+        cch: 0, hbmpItem: ptr::null_mut()
+    };
+
+    GetMenuItemInfoW(parent_menu, id, false as BOOL, &mut info);
+
+    info.fType & MFT_OWNERDRAW == MFT_OWNERDRAW
+}
+
+pub unsafe fn check_menu_radio_item(parent_menu: HMENU, id: u32) {
+    use winapi::um::winuser::{CheckMenuRadioItem, MF_BYCOMMAND};
+    CheckMenuRadioItem(parent_menu, id, id, id, MF_BYCOMMAND);
+}
+
+pub unsafe fn set_menu_item_bitmap(parent_menu: HMENU, id: u32, bitmap: HBITMAP) {
+    use winapi::um::winuser::{SetMenuItemInfoW, MENUITEMINFOW, MIIM_BITMAP};
+    use winapi::shared::minwindef::BOOL;
+
+    let mut info = MENUITEMINFOW { 
+        cbSize: mem::size_of::<MENUITEMINFOW>() as UINT,
+        fMask: MIIM_BITMAP, fType: 0, fState: 0,
+        wID: 0,
+        hSubMenu: ptr::null_mut(), hbmpChecked: ptr::null_mut(),
+        hbmpUnchecked: ptr::null_mut(), dwItemData: 0, dwTypeData: ptr::null_mut(),
+        cch: 0, hbmpItem: bitmap
+    };
+
+    SetMenuItemInfoW(parent_menu, id, false as BOOL, &mut info);
+}
+
 
-unsafe fn build_hmenu_separator(menu: HMENU) -> ControlHandle {
-    use winapi::um::winuser::{GetMenuItemCount, SetMenuItemInfoW, AppendMenuW};
+unsafe fn build_hmenu_separator(menu: HMENU, position: Option<UINT>) -> ControlHandle {
+    use winapi::um::winuser::{SetMenuItemInfoW};
     use winapi::um::winuser::{MENUITEMINFOW, MF_SEPARATOR, MIIM_ID};
     use winapi::shared::minwindef::{BOOL};
 
     let item_id = MENU_ITEMS_ID.fetch_add(1, Ordering::SeqCst);
 
     // MF_SEPARATOR ignore the lpNewItem and uIDNewItem parameters, so they must be set using SetMenuItemInfo
-    AppendMenuW(menu, MF_SEPARATOR, 0, ptr::null());
+    let pos = insert_menu(menu, position, MF_SEPARATOR, 0, ptr::null());
 
     // Set the unique id of the separator
-    let pos = GetMenuItemCount(menu) - 1;
     let mut info = MENUITEMINFOW { 
         cbSize: mem::size_of::<MENUITEMINFOW>() as UINT,
         fMask: MIIM_ID, fType: 0, fState: 0,
@@ -217,6 +362,346 @@ unsafe fn build_hmenu_separator(menu: HMENU) -> ControlHandle {
     ControlHandle::MenuItem(menu, item_id)
 }
 
+/**
+    Insert a menu item at `position` in `menu`. If `position` is `None` or out of bounds, the item is appended.
+    Return the position of the new item.
+*/
+unsafe fn insert_menu(menu: HMENU, position: Option<UINT>, flags: UINT, id: usize, text: *const u16) -> UINT {
+    use winapi::um::winuser::{GetMenuItemCount, AppendMenuW, InsertMenuW, MF_BYPOSITION};
+
+    let count = GetMenuItemCount(menu).max(0) as UINT;
+    match position {
+        Some(pos) if pos < count => {
+            InsertMenuW(menu, pos, flags | MF_BYPOSITION, id, text);
+            pos
+        },
+        _ => {
+            AppendMenuW(menu, flags, id, text);
+            count
+        }
+    }
+}
+
+/// Redraw the menu bar of every top level window of the current thread that uses `menu` as its menu bar.
+/// Changes to a menu bar are not visible until the menu bar is redrawn.
+pub unsafe fn redraw_menu_bar(menu: HMENU) {
+    use winapi::um::winuser::{EnumThreadWindows, GetMenu, DrawMenuBar};
+    use winapi::um::processthreadsapi::GetCurrentThreadId;
+    use winapi::shared::minwindef::{BOOL, LPARAM};
+
+    unsafe extern "system" fn redraw(hwnd: HWND, menu: LPARAM) -> BOOL {
+        if GetMenu(hwnd) == menu as HMENU {
+            DrawMenuBar(hwnd);
+        }
+        1
+    }
+
+    EnumThreadWindows(GetCurrentThreadId(), Some(redraw), menu as LPARAM);
+}
+
+/// Create a menu bar that is not attached to any window
+pub unsafe fn create_menu_bar() -> Result<ControlHandle, NwgError> {
+    use winapi::um::winuser::CreateMenu;
+
+    let menu = CreateMenu();
+    if menu.is_null() {
+        return Err(NwgError::menu_create("Menu bar creation failed"));
+    }
+
+    use_menu_command(menu);
+
+    Ok(ControlHandle::Menu(ptr::null_mut(), menu))
+}
+
+/// Return the menu bar stored for a window while its menu bar is hidden. Also forget the windows that were destroyed.
+unsafe fn hidden_menu_bar(hwnd: HWND) -> Option<HMENU> {
+    use winapi::um::winuser::IsWindow;
+
+    HIDDEN_MENU_BARS.with(|bars| {
+        let mut bars = bars.borrow_mut();
+        bars.retain(|(h, _)| IsWindow(*h) != 0);
+        bars.iter().find(|(h, _)| *h == hwnd).map(|(_, m)| *m)
+    })
+}
+
+/// Return the menu bar of a window, even if it is hidden. Return a null pointer if the window has no menu bar.
+pub unsafe fn menu_bar(hwnd: HWND) -> HMENU {
+    use winapi::um::winuser::GetMenu;
+
+    match hidden_menu_bar(hwnd) {
+        Some(menu) => menu,
+        None => GetMenu(hwnd)
+    }
+}
+
+/**
+    Replace the menu bar of a window. The previous menu bar is not destroyed. Use a null pointer to remove the menu bar.
+    If the menu bar of the window is hidden, the new menu bar will be displayed when the menu bar is shown again.
+*/
+pub unsafe fn set_menu_bar(hwnd: HWND, menu: HMENU) {
+    use winapi::um::winuser::{SetMenu, DrawMenuBar};
+
+    if hidden_menu_bar(hwnd).is_some() {
+        HIDDEN_MENU_BARS.with(|bars| {
+            let mut bars = bars.borrow_mut();
+            bars.retain(|(h, _)| *h != hwnd);
+            if !menu.is_null() {
+                bars.push((hwnd, menu));
+            }
+        });
+        return;
+    }
+
+    SetMenu(hwnd, menu);
+    DrawMenuBar(hwnd);
+}
+
+/// Return `true` if the window has a menu bar and the menu bar is not hidden
+pub unsafe fn menu_bar_visible(hwnd: HWND) -> bool {
+    use winapi::um::winuser::GetMenu;
+    hidden_menu_bar(hwnd).is_none() && !GetMenu(hwnd).is_null()
+}
+
+/// Detach the menu bar from a window without destroying it, or attach it back.
+pub unsafe fn set_menu_bar_visible(hwnd: HWND, visible: bool) {
+    use winapi::um::winuser::{GetMenu, SetMenu, DrawMenuBar};
+
+    match (visible, hidden_menu_bar(hwnd)) {
+        (true, Some(menu)) => {
+            HIDDEN_MENU_BARS.with(|bars| bars.borrow_mut().retain(|(h, _)| *h != hwnd));
+            SetMenu(hwnd, menu);
+        },
+        (false, None) => {
+            let menu = GetMenu(hwnd);
+            if menu.is_null() {
+                return;
+            }
+
+            HIDDEN_MENU_BARS.with(|bars| bars.borrow_mut().push((hwnd, menu)));
+            SetMenu(hwnd, ptr::null_mut());
+        },
+        _ => { return; }
+    }
+
+    DrawMenuBar(hwnd);
+}
+
+/// Remove a menu bar from every window of the current thread that uses it, including the windows where it is hidden.
+pub unsafe fn detach_menu_bar(menu: HMENU) {
+    use winapi::um::winuser::{EnumThreadWindows, GetMenu, SetMenu, DrawMenuBar};
+    use winapi::um::processthreadsapi::GetCurrentThreadId;
+    use winapi::shared::minwindef::{BOOL, LPARAM};
+
+    unsafe extern "system" fn detach(hwnd: HWND, menu: LPARAM) -> BOOL {
+        if GetMenu(hwnd) == menu as HMENU {
+            SetMenu(hwnd, ptr::null_mut());
+            DrawMenuBar(hwnd);
+        }
+        1
+    }
+
+    HIDDEN_MENU_BARS.with(|bars| bars.borrow_mut().retain(|(_, m)| *m != menu));
+    EnumThreadWindows(GetCurrentThreadId(), Some(detach), menu as LPARAM);
+}
+
+/// Return the text of a menu item at the selected position or using the selected ID.
+pub unsafe fn menu_item_text(h: HMENU, value: UINT, by_position: bool) -> String {
+    use winapi::um::winuser::{GetMenuItemInfoW, MENUITEMINFOW, MIIM_STRING};
+    use winapi::shared::minwindef::BOOL;
+
+    let mut info = MENUITEMINFOW { 
+        cbSize: mem::size_of::<MENUITEMINFOW>() as UINT,
+        fMask: MIIM_STRING, fType: 0, fState: 0,
+        wID: 0, hSubMenu: ptr::null_mut(), hbmpChecked: ptr::null_mut(),
+        hbmpUnchecked: ptr::null_mut(), dwItemData: 0, dwTypeData: ptr::null_mut(),
+        cch: 0, hbmpItem: ptr::null_mut()
+    };
+
+    // The first call returns the text length, without the terminating null character
+    if GetMenuItemInfoW(h, value, by_position as BOOL, &mut info) == 0 || info.cch == 0 {
+        return String::new();
+    }
+
+    let mut buffer: Vec<u16> = vec![0; (info.cch + 1) as usize];
+    info.cch += 1;
+    info.dwTypeData = buffer.as_mut_ptr();
+    GetMenuItemInfoW(h, value, by_position as BOOL, &mut info);
+
+    from_utf16(&buffer)
+}
+
+/// Set the text of a menu item at the selected position or using the selected ID.
+pub unsafe fn set_menu_item_text(h: HMENU, value: UINT, by_position: bool, text: &str) {
+    use winapi::um::winuser::{SetMenuItemInfoW, MENUITEMINFOW, MIIM_STRING};
+    use winapi::shared::minwindef::BOOL;
+
+    let mut text = to_utf16(text);
+    let mut info = MENUITEMINFOW { 
+        cbSize: mem::size_of::<MENUITEMINFOW>() as UINT,
+        fMask: MIIM_STRING, fType: 0, fState: 0,
+        wID: 0, hSubMenu: ptr::null_mut(), hbmpChecked: ptr::null_mut(),
+        hbmpUnchecked: ptr::null_mut(), dwItemData: 0, dwTypeData: text.as_mut_ptr(),
+        cch: 0, hbmpItem: ptr::null_mut()
+    };
+
+    SetMenuItemInfoW(h, value, by_position as BOOL, &mut info);
+}
+
+/// Remove a sub menu from its parent without destroying it.
+pub unsafe fn remove_menu(parent_menu: HMENU, menu: HMENU) {
+    use winapi::um::winuser::{RemoveMenu, MF_BYPOSITION};
+
+    let menu_index = menu_index_in_parent(parent_menu, menu);
+    RemoveMenu(parent_menu, menu_index, MF_BYPOSITION);
+}
+
+/**
+    Register the shortcut written after a tab in the menu item text (ex: "&Save\tCtrl+S") as a keyboard accelerator.
+    Replace the previous accelerator of the item. Return `false` if the text does not contain a valid shortcut.
+*/
+pub unsafe fn register_accelerator(parent_menu: HMENU, id: u32) -> bool {
+    unregister_accelerator(id);
+
+    // The command of an accelerator is a WORD
+    if id > 0xFFFF {
+        return false;
+    }
+
+    let text = menu_item_text(parent_menu, id, false);
+    let (virt, key) = match parse_accelerator(&text) {
+        Some(accel) => accel,
+        None => { return false; }
+    };
+
+    ACCELERATORS.with(|accels| {
+        let mut accels = accels.borrow_mut();
+        accels.entries.push((parent_menu, ACCEL { fVirt: virt, key, cmd: id as u16 }));
+        accels.rebuild();
+    });
+
+    true
+}
+
+/// Remove the keyboard accelerator of a menu item. Does nothing if the item has no accelerator.
+pub unsafe fn unregister_accelerator(id: u32) {
+    ACCELERATORS.with(|accels| {
+        let mut accels = accels.borrow_mut();
+        let count = accels.entries.len();
+        accels.entries.retain(|(_, a)| a.cmd as u32 != id);
+        if accels.entries.len() != count {
+            accels.rebuild();
+        }
+    });
+}
+
+/// Return `true` if the menu item has a registered keyboard accelerator
+pub fn accelerator_registered(id: u32) -> bool {
+    ACCELERATORS.with(|accels| accels.borrow().entries.iter().any(|(_, a)| a.cmd as u32 == id))
+}
+
+/// Return the handle of the enabled menu item that registered the accelerator command `cmd`
+pub unsafe fn accelerator_item(cmd: u32) -> Option<ControlHandle> {
+    let parent = ACCELERATORS.with(|accels| {
+        accels.borrow().entries.iter()
+            .find(|(_, a)| a.cmd as u32 == cmd)
+            .map(|(parent, _)| *parent)
+    })?;
+
+    match is_menuitem_enabled(parent, None, Some(cmd)) {
+        true => Some(ControlHandle::MenuItem(parent, cmd)),
+        false => None
+    }
+}
+
+/**
+    Translate a key press into a menu command if it matches a registered accelerator.
+    The command is sent to the top level window that has the keyboard focus.
+    Return `true` if the message was consumed.
+*/
+pub unsafe fn translate_accelerator(msg: &mut MSG) -> bool {
+    use winapi::um::winuser::{TranslateAcceleratorW, GetAncestor, WM_KEYDOWN, WM_SYSKEYDOWN, GA_ROOT};
+
+    if msg.message != WM_KEYDOWN && msg.message != WM_SYSKEYDOWN {
+        return false;
+    }
+
+    // The table is copied out because TranslateAcceleratorW dispatches the command synchronously
+    let table = match ACCELERATORS.with(|accels| accels.borrow().handle) {
+        Some(table) => table,
+        None => { return false; }
+    };
+
+    let root = GetAncestor(msg.hwnd, GA_ROOT);
+    if root.is_null() {
+        return false;
+    }
+
+    TranslateAcceleratorW(root, table, msg) != 0
+}
+
+/**
+    Parse a shortcut such as "Ctrl+Shift+S", "Alt+F4" or "Del" from the text after the first tab of a menu item text.
+    Return the accelerator virtual flags and the virtual key code.
+*/
+pub fn parse_accelerator(text: &str) -> Option<(u8, u16)> {
+    use winapi::um::winuser::{FVIRTKEY, FCONTROL, FSHIFT, FALT};
+    use winapi::um::winuser::{VK_OEM_PLUS, VK_OEM_MINUS, VK_DELETE, VK_INSERT, VK_HOME, VK_END, VK_PRIOR, VK_NEXT, VK_RETURN,
+      VK_ESCAPE, VK_TAB, VK_SPACE, VK_BACK, VK_LEFT, VK_RIGHT, VK_UP, VK_DOWN, VK_F1};
+
+    let shortcut = text.split_once('\t')?.1.trim();
+
+    // "Ctrl++" uses the plus key
+    let (modifiers, key) = match shortcut.strip_suffix("++") {
+        Some(modifiers) => (modifiers, "+"),
+        None => match shortcut.rsplit_once('+') {
+            Some((modifiers, key)) => (modifiers, key.trim()),
+            None => ("", shortcut)
+        }
+    };
+
+    let mut virt = FVIRTKEY;
+    for modifier in modifiers.split('+').map(|m| m.trim()).filter(|m| !m.is_empty()) {
+        match modifier.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => { virt |= FCONTROL; },
+            "shift" => { virt |= FSHIFT; },
+            "alt" => { virt |= FALT; },
+            _ => { return None; }
+        }
+    }
+
+    let lower = key.to_ascii_lowercase();
+    let mut chars = key.chars();
+    let vk = match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphanumeric() => c.to_ascii_uppercase() as i32,
+        _ => match lower.as_str() {
+            "+" | "plus" => VK_OEM_PLUS,
+            "-" | "minus" => VK_OEM_MINUS,
+            "del" | "delete" => VK_DELETE,
+            "ins" | "insert" => VK_INSERT,
+            "home" => VK_HOME,
+            "end" => VK_END,
+            "pgup" | "pageup" => VK_PRIOR,
+            "pgdn" | "pagedown" => VK_NEXT,
+            "enter" | "return" => VK_RETURN,
+            "esc" | "escape" => VK_ESCAPE,
+            "tab" => VK_TAB,
+            "space" => VK_SPACE,
+            "back" | "backspace" => VK_BACK,
+            "left" => VK_LEFT,
+            "right" => VK_RIGHT,
+            "up" => VK_UP,
+            "down" => VK_DOWN,
+            f if f.starts_with('f') => match f[1..].parse::<i32>() {
+                Ok(n) if (1..=24).contains(&n) => VK_F1 + (n - 1),
+                _ => { return None; }
+            },
+            _ => { return None; }
+        }
+    };
+
+    Some((virt, vk as u16))
+}
+
 /**
     Configure the menu to use a WM_MENUCOMMAND instead of a WM_COMMAND when its action are triggered.
     Required in order to allow nwg to dispatch the events correctly
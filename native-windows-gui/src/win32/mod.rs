@@ -5,6 +5,12 @@ pub(crate) mod window;
 pub(crate) mod message_box;
 pub(crate) mod high_dpi;
 pub(crate) mod monitor;
+pub(crate) mod back_buffer;
+pub(crate) mod focus;
+pub(crate) mod high_contrast;
+pub(crate) mod system_metrics;
+pub(crate) mod keyboard;
+pub(crate) mod os_info;
 
 #[cfg(feature = "menu")]
 pub(crate) mod menu;
@@ -30,7 +36,53 @@ pub(crate) mod richedit;
 #[cfg(feature = "plotting")]
 pub(crate) mod plotters_d2d;
 
+#[cfg(feature = "autocomplete")]
+pub(crate) mod autocomplete;
+
+#[cfg(feature = "spell-check")]
+pub(crate) mod spellcheck;
+
+#[cfg(feature = "toast-notification")]
+pub(crate) mod toast;
+
+#[cfg(feature = "d2d-canvas")]
+pub(crate) mod d2d_canvas;
+
+#[cfg(feature = "media-player")]
+pub(crate) mod media_player;
+
+#[cfg(feature = "drag-drop")]
+pub(crate) mod drop_target;
+
+#[cfg(feature = "drag-drop")]
+pub(crate) mod drag_source;
+
+#[cfg(feature = "shell")]
+pub(crate) mod shell;
+
+#[cfg(feature = "accessibility")]
+pub(crate) mod accessibility;
+
+#[cfg(feature = "accessibility")]
+pub(crate) mod announce;
+
+#[cfg(feature = "ime")]
+pub(crate) mod ime;
+
+#[cfg(feature = "pointer-input")]
+pub(crate) mod pointer;
+
+#[cfg(feature = "single-instance")]
+pub(crate) mod single_instance;
+
+#[cfg(feature = "recovery")]
+pub(crate) mod recovery;
+
+#[cfg(feature = "settings")]
+pub(crate) mod settings;
+
 use std::{fs, mem, ptr};
+use std::sync::atomic::{AtomicU32, Ordering};
 use crate::errors::NwgError;
 
 
@@ -46,6 +98,10 @@ pub fn dispatch_thread_events() {
     unsafe {
         let mut msg: MSG = mem::zeroed();
         while GetMessageW(&mut msg, ptr::null_mut(), 0, 0) != 0 {
+            if translate_accelerator(&mut msg) {
+                continue;
+            }
+
             if IsDialogMessageW(GetAncestor(msg.hwnd, GA_ROOT), &mut msg) == 0 {
                 TranslateMessage(&msg); 
                 DispatchMessageW(&msg); 
@@ -69,7 +125,7 @@ pub fn dispatch_thread_events_with_callback<F>(mut cb: F)
         let mut msg: MSG = mem::zeroed();
         while msg.message != WM_QUIT {
             let has_message = PeekMessageW(&mut msg, ptr::null_mut(), 0, 0, PM_REMOVE) != 0;
-            if has_message {
+            if has_message && !translate_accelerator(&mut msg) {
                 if IsDialogMessageW(GetAncestor(msg.hwnd, GA_ROOT), &mut msg) == 0 {
                     TranslateMessage(&msg); 
                     DispatchMessageW(&msg); 
@@ -81,6 +137,19 @@ pub fn dispatch_thread_events_with_callback<F>(mut cb: F)
     }
 }
 
+/**
+    Translate the keyboard accelerators registered from the menu items text.
+    Return `true` if the message was consumed.
+*/
+#[inline(always)]
+unsafe fn translate_accelerator(msg: &mut winapi::um::winuser::MSG) -> bool {
+    #[cfg(feature = "menu")]
+    { menu::translate_accelerator(msg) }
+
+    #[cfg(not(feature = "menu"))]
+    { let _ = msg; false }
+}
+
 /**
     Break the events loop running on the current thread
 */
@@ -93,9 +162,43 @@ pub fn stop_thread_dispatch() {
 
 
 /**
-  Enable the Windows visual style in the application without having to use a manifest
+  Set the default layout of the windows created by the process. If `rtl` is true, the new top level windows are mirrored
+  for right-to-left languages (Arabic, Hebrew). The windows created before the call are not changed.
+
+  Use `ControlHandle::set_rtl_layout` to mirror a single window.
+*/
+pub fn set_process_default_layout(rtl: bool) {
+    use winapi::um::winuser::SetProcessDefaultLayout;
+    use winapi::um::wingdi::LAYOUT_RTL;
+
+    unsafe { SetProcessDefaultLayout(if rtl { LAYOUT_RTL } else { 0 }); }
+}
+
+/**
+  Return true if the new top level windows of the process are mirrored for right-to-left languages
+*/
+pub fn process_default_layout() -> bool {
+    use winapi::um::winuser::GetProcessDefaultLayout;
+    use winapi::um::wingdi::LAYOUT_RTL;
+
+    let mut layout = 0;
+    unsafe { GetProcessDefaultLayout(&mut layout); }
+    layout & LAYOUT_RTL == LAYOUT_RTL
+}
+
+
+/**
+  Enable the Windows visual style in the application without having to use a manifest.
+  The visual style is enabled for the whole process, so only the first call has an effect.
 */
 pub fn enable_visual_styles() {
+    use std::sync::Once;
+
+    static VISUAL_STYLES: Once = Once::new();
+    VISUAL_STYLES.call_once(activate_visual_styles);
+}
+
+fn activate_visual_styles() {
     use winapi::shared::minwindef::{ULONG, DWORD, MAX_PATH};
     use winapi::shared::basetsd::ULONG_PTR;
     use winapi::um::fileapi::{GetTempFileNameW, GetTempPathW};
@@ -149,53 +252,146 @@ pub fn enable_visual_styles() {
     let _ = fs::remove_file(&manifest_path);
 }
 
+bitflags! {
+    /**
+        The groups of common controls that can be registered by `init_with`.
+        The groups that are not registered at initialization are registered when the first control of the group is created.
+
+        Control classes:
+        * STANDARD: Buttons, edits, list boxes, combo boxes, static controls and scroll bars
+        * BAR: Status bars, track bars and tooltips
+        * DATE: Date pickers
+        * PROGRESS: Progress bars
+        * TABS: Tabs
+        * TREE_VIEW: Tree views
+        * LIST_VIEW: List views
+        * RICH_EDIT: Rich text boxes (loads Msftedit.dll)
+    */
+    pub struct ControlClasses: u32 {
+        const STANDARD = 0x001;
+        const BAR = 0x002;
+        const DATE = 0x004;
+        const PROGRESS = 0x008;
+        const TABS = 0x010;
+        const TREE_VIEW = 0x020;
+        const LIST_VIEW = 0x040;
+        const RICH_EDIT = 0x080;
+    }
+}
+
+impl ControlClasses {
+
+    /// Return the control classes of the controls enabled by the crate features
+    pub fn enabled() -> ControlClasses {
+        let mut classes = ControlClasses::STANDARD | ControlClasses::BAR;
+        classes.set(ControlClasses::DATE, cfg!(feature = "datetime-picker"));
+        classes.set(ControlClasses::PROGRESS, cfg!(feature = "progress-bar"));
+        classes.set(ControlClasses::TABS, cfg!(feature = "tabs"));
+        classes.set(ControlClasses::TREE_VIEW, cfg!(feature = "tree-view"));
+        classes.set(ControlClasses::LIST_VIEW, cfg!(feature = "list-view"));
+        classes.set(ControlClasses::RICH_EDIT, cfg!(feature = "rich-textbox"));
+        classes
+    }
+
+    /// Return the control classes required to create a window of the class `class_name`
+    pub(crate) fn from_class_name(class_name: &str) -> ControlClasses {
+        use winapi::um::commctrl::{WC_TREEVIEW, WC_LISTVIEW, WC_TABCONTROL, TRACKBAR_CLASS, TOOLTIPS_CLASS};
+
+        let groups: [(&str, ControlClasses); 8] = [
+            ("msctls_statusbar32", ControlClasses::BAR),
+            (TRACKBAR_CLASS, ControlClasses::BAR),
+            (TOOLTIPS_CLASS, ControlClasses::BAR),
+            ("SysDateTimePick32", ControlClasses::DATE),
+            ("msctls_progress32", ControlClasses::PROGRESS),
+            (WC_TABCONTROL, ControlClasses::TABS),
+            (WC_TREEVIEW, ControlClasses::TREE_VIEW),
+            (WC_LISTVIEW, ControlClasses::LIST_VIEW),
+        ];
+
+        if class_name.eq_ignore_ascii_case("RICHEDIT50W") {
+            return ControlClasses::RICH_EDIT;
+        }
+
+        groups.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(class_name))
+            .map(|(_, classes)| *classes)
+            .unwrap_or(ControlClasses::empty())
+    }
+
+}
+
+/// The control classes already registered in the process
+static LOADED_CONTROL_CLASSES: AtomicU32 = AtomicU32::new(0);
+
 /**
-    Ensure that the dll containing the winapi controls is loaded.
-    Also register the custom classes used by NWG
+    Register the control classes in `classes` that are not already registered.
+    The common controls are shared by the whole process, so each group is only registered once.
 */
-pub fn init_common_controls() -> Result<(), NwgError> {
-    use winapi::um::objbase::CoInitialize;
+pub(crate) fn load_control_classes(classes: ControlClasses) {
     use winapi::um::libloaderapi::LoadLibraryW;
     use winapi::um::commctrl::{InitCommonControlsEx, INITCOMMONCONTROLSEX};
     use winapi::um::commctrl::{ICC_BAR_CLASSES, ICC_STANDARD_CLASSES, ICC_DATE_CLASSES, ICC_PROGRESS_CLASS,
      ICC_TAB_CLASSES, ICC_TREEVIEW_CLASSES, ICC_LISTVIEW_CLASSES};
-    use winapi::shared::winerror::{S_OK, S_FALSE};
 
-    unsafe {
-        let mut classes = ICC_BAR_CLASSES | ICC_STANDARD_CLASSES;
+    // Registering a group twice is harmless, so threads racing here may both register it
+    let loaded = ControlClasses::from_bits_truncate(LOADED_CONTROL_CLASSES.load(Ordering::SeqCst));
+    let missing = classes - loaded;
+    if missing.is_empty() {
+        return;
+    }
 
-        if cfg!(feature = "datetime-picker") {
-            classes |= ICC_DATE_CLASSES;
-        }
+    let icc = [
+        (ControlClasses::STANDARD, ICC_STANDARD_CLASSES),
+        (ControlClasses::BAR, ICC_BAR_CLASSES),
+        (ControlClasses::DATE, ICC_DATE_CLASSES),
+        (ControlClasses::PROGRESS, ICC_PROGRESS_CLASS),
+        (ControlClasses::TABS, ICC_TAB_CLASSES),
+        (ControlClasses::TREE_VIEW, ICC_TREEVIEW_CLASSES),
+        (ControlClasses::LIST_VIEW, ICC_LISTVIEW_CLASSES),
+    ];
 
-        if cfg!(feature = "progress-bar") {
-            classes |= ICC_PROGRESS_CLASS;
-        }
+    let icc_classes = icc.iter()
+        .filter(|(group, _)| missing.contains(*group))
+        .fold(0, |acc, (_, icc)| acc | icc);
 
-        if cfg!(feature = "tabs") {
-            classes |= ICC_TAB_CLASSES;
+    unsafe {
+        if missing.contains(ControlClasses::RICH_EDIT) {
+            base_helper::with_utf16("Msftedit.dll", |lib| LoadLibraryW(lib.as_ptr()));
         }
 
-        if cfg!(feature = "tree-view") {
-            classes |= ICC_TREEVIEW_CLASSES;
-        }
+        if icc_classes != 0 {
+            let data = INITCOMMONCONTROLSEX {
+                dwSize: mem::size_of::<INITCOMMONCONTROLSEX>() as u32,
+                dwICC: icc_classes
+            };
 
-        if cfg!(feature = "list-view") {
-            classes |= ICC_LISTVIEW_CLASSES;
+            InitCommonControlsEx(&data);
         }
+    }
 
-        if cfg!(feature = "rich-textbox") {
-            let lib = base_helper::to_utf16("Msftedit.dll");
-            LoadLibraryW(lib.as_ptr());
-        }
+    LOADED_CONTROL_CLASSES.fetch_or(missing.bits(), Ordering::SeqCst);
+}
 
-        let data = INITCOMMONCONTROLSEX {
-            dwSize: mem::size_of::<INITCOMMONCONTROLSEX>() as u32,
-            dwICC: classes
-        };
+/**
+    Ensure that the dll containing the winapi controls is loaded.
+    Also register the custom classes used by NWG
 
-        InitCommonControlsEx(&data);
-    }
+    All the control classes enabled by the crate features are registered. See `init_common_controls_with`
+    to only register some of them.
+*/
+pub fn init_common_controls() -> Result<(), NwgError> {
+    init_common_controls_with(ControlClasses::enabled())
+}
+
+/**
+    Same as `init_common_controls`, but only the control classes in `classes` are registered.
+    The other control classes are registered when the first control using them is created.
+*/
+pub fn init_common_controls_with(classes: ControlClasses) -> Result<(), NwgError> {
+    use winapi::um::objbase::CoInitialize;
+    use winapi::shared::winerror::{S_OK, S_FALSE};
+
+    load_control_classes(classes);
 
     window::init_window_class()?;
     tabs_init()?;
@@ -1,6 +1,6 @@
-use winapi::shared::windef::HWND;
-use winapi::um::winuser::{GetSystemMetrics, MonitorFromWindow, GetMonitorInfoW, MONITORINFO,
-    SM_CXSCREEN, SM_CYSCREEN, SM_CXVIRTUALSCREEN, SM_CYVIRTUALSCREEN, MONITOR_DEFAULTTONEAREST};
+use winapi::shared::windef::{HWND, HMONITOR, RECT, POINT};
+use winapi::um::winuser::{GetSystemMetrics, MonitorFromWindow, MonitorFromPoint, GetMonitorInfoW, GetCursorPos, MONITORINFO,
+    SM_CXSCREEN, SM_CYSCREEN, SM_XVIRTUALSCREEN, SM_YVIRTUALSCREEN, SM_CXVIRTUALSCREEN, SM_CYVIRTUALSCREEN, MONITOR_DEFAULTTONEAREST};
 use crate::ControlHandle;
 use std::mem;
 
@@ -34,18 +34,33 @@ use std::mem;
 */
 pub struct Monitor;
 
+/// Another name for `Monitor`
+pub type Screen = Monitor;
+
 impl Monitor {
 
     fn monitor_info_from_window(handle: HWND) -> MONITORINFO {
+        unsafe { Self::monitor_info(MonitorFromWindow(handle, MONITOR_DEFAULTTONEAREST)) }
+    }
+
+    fn monitor_info_from_cursor() -> MONITORINFO {
         unsafe {
-            let m = MonitorFromWindow(handle, MONITOR_DEFAULTTONEAREST);
+            let mut point = POINT { x: 0, y: 0 };
+            GetCursorPos(&mut point);
+            Self::monitor_info(MonitorFromPoint(point, MONITOR_DEFAULTTONEAREST))
+        }
+    }
 
-            let mut info: MONITORINFO = mem::zeroed();
-            info.cbSize = mem::size_of::<MONITORINFO>() as _;
-            GetMonitorInfoW(m, &mut info);
+    unsafe fn monitor_info(m: HMONITOR) -> MONITORINFO {
+        let mut info: MONITORINFO = mem::zeroed();
+        info.cbSize = mem::size_of::<MONITORINFO>() as _;
+        GetMonitorInfoW(m, &mut info);
 
-            info
-        }
+        info
+    }
+
+    fn rect(r: RECT) -> [i32; 4] {
+        [r.left, r.top, r.right, r.bottom]
     }
 
     /// Returns the width in pixel of the monitor that has the largest area of intersection with the bounding rectangle of a specified window
@@ -82,6 +97,54 @@ impl Monitor {
         ]
     }
 
+    /// Returns a [left, top, right, bottom] rectangle that specifies the work area of the monitor of a window, expressed in virtual-screen coordinates.
+    /// The work area is the monitor rectangle without the taskbar and the docked application bars.
+    /// Panics if `window` is not a window like control.
+    pub fn work_area_from_window<H: Into<ControlHandle>>(window: H) -> [i32; 4] {
+        let handle = window.into().hwnd().expect("Window to be a window-like control");
+        Self::rect(Self::monitor_info_from_window(handle).rcWork)
+    }
+
+    /// Returns a [left, top, right, bottom] rectangle that specifies the monitor under the mouse cursor, expressed in virtual-screen coordinates.
+    pub fn monitor_rect_from_cursor() -> [i32; 4] {
+        Self::rect(Self::monitor_info_from_cursor().rcMonitor)
+    }
+
+    /// Returns a [left, top, right, bottom] rectangle that specifies the work area of the monitor under the mouse cursor, expressed in virtual-screen coordinates.
+    pub fn work_area_from_cursor() -> [i32; 4] {
+        Self::rect(Self::monitor_info_from_cursor().rcWork)
+    }
+
+    /// Returns a [left, top, right, bottom] rectangle that specifies the work area of the primary monitor.
+    /// The work area is the monitor rectangle without the taskbar and the docked application bars.
+    pub fn work_area() -> [i32; 4] {
+        use winapi::um::winuser::{SystemParametersInfoW, SPI_GETWORKAREA};
+
+        unsafe {
+            let mut area: RECT = mem::zeroed();
+            SystemParametersInfoW(SPI_GETWORKAREA, 0, &mut area as *mut RECT as _, 0);
+            Self::rect(area)
+        }
+    }
+
+    /// Returns a [left, top, right, bottom] rectangle that specifies the virtual screen, the bounding rectangle of all the monitors.
+    /// Note that the left and top coordinates may be negative values.
+    pub fn virtual_rect() -> [i32; 4] {
+        unsafe {
+            let [x, y] = [GetSystemMetrics(SM_XVIRTUALSCREEN), GetSystemMetrics(SM_YVIRTUALSCREEN)];
+            [x, y, x + GetSystemMetrics(SM_CXVIRTUALSCREEN), y + GetSystemMetrics(SM_CYVIRTUALSCREEN)]
+        }
+    }
+
+    /// Returns the position of a window of `size` centered in a [left, top, right, bottom] `area`, such as the value returned by `Monitor::work_area`.
+    /// If the window is larger than the area, it is aligned on the top left corner of the area.
+    pub fn centered_position(size: (i32, i32), area: [i32; 4]) -> (i32, i32) {
+        let [left, top, right, bottom] = area;
+        let x = left + i32::max((right - left - size.0) / 2, 0);
+        let y = top + i32::max((bottom - top - size.1) / 2, 0);
+        (x, y)
+    }
+
     /// Returns the primary monitor width in pixel
     /// Use `Monitor::virtual_width` to get the dimensions of the virtual screen
     pub fn width() -> i32 {
@@ -0,0 +1,175 @@
+use winapi::shared::minwindef::{DWORD, HMODULE};
+use winapi::shared::ntdef::NTSTATUS;
+use winapi::shared::winerror::HRESULT;
+use winapi::um::winnt::RTL_OSVERSIONINFOW;
+use super::base_helper::to_utf16;
+use std::mem;
+
+/// The first build of Windows 10
+const WINDOWS_10_BUILD: u32 = 10240;
+
+/// Windows 10 1703, the first build with the per-monitor v2 DPI awareness
+const PER_MONITOR_DPI_V2_BUILD: u32 = 15063;
+
+/// Windows 10 1809, the first build with a dark mode for the applications
+const DARK_MODE_BUILD: u32 = 17763;
+
+/// The first build of Windows 11
+const WINDOWS_11_BUILD: u32 = 22000;
+
+/// Windows 11 22H2, the first build with the documented system backdrops (ex: Mica)
+const MICA_BUILD: u32 = 22621;
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct DLLVERSIONINFO {
+    cbSize: DWORD,
+    dwMajorVersion: DWORD,
+    dwMinorVersion: DWORD,
+    dwBuildNumber: DWORD,
+    dwPlatformID: DWORD,
+}
+
+lazy_static! {
+    static ref OS_INFO: OsInfo = unsafe { load_os_info() };
+}
+
+/**
+    The version of Windows and of the common controls library used by the application. Returned by `nwg::os_info`.
+
+    The `supports_*` methods tell if a system feature is available so that an application can fall back to another
+    behavior on the older versions of Windows.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OsInfo {
+    /// The major version of Windows. Windows 10 and Windows 11 are both 10.
+    pub major: u32,
+    /// The minor version of Windows
+    pub minor: u32,
+    /// The build number of Windows. Windows 11 starts at build 22000.
+    pub build: u32,
+    /// The major and minor version of the common controls library (comctl32.dll).
+    /// Version 6 is only loaded when the application enables the visual styles. The version is read the first time `os_info` is called.
+    pub common_controls: (u32, u32),
+}
+
+impl OsInfo {
+
+    /// Returns `true` if the system is Windows 10 or a later version
+    pub fn is_windows_10(&self) -> bool {
+        self.major >= 10 && self.build >= WINDOWS_10_BUILD
+    }
+
+    /// Returns `true` if the system is Windows 11 or a later version
+    pub fn is_windows_11(&self) -> bool {
+        self.major >= 10 && self.build >= WINDOWS_11_BUILD
+    }
+
+    /// Returns `true` if the applications can follow the dark mode of the system
+    pub fn supports_dark_mode(&self) -> bool {
+        self.major >= 10 && self.build >= DARK_MODE_BUILD
+    }
+
+    /// Returns `true` if the windows can use the Mica backdrop
+    pub fn supports_mica(&self) -> bool {
+        self.major >= 10 && self.build >= MICA_BUILD
+    }
+
+    /// Returns `true` if the process can use the per-monitor v2 DPI awareness
+    pub fn supports_per_monitor_dpi_v2(&self) -> bool {
+        self.major >= 10 && self.build >= PER_MONITOR_DPI_V2_BUILD
+    }
+
+    /// Returns `true` if the common controls library has the task dialogs and the visual styles (version 6 or later)
+    pub fn supports_task_dialog(&self) -> bool {
+        self.common_controls.0 >= 6
+    }
+
+}
+
+/**
+    Return the version of Windows and of the common controls library.
+
+    Unlike `GetVersionEx`, the real version of Windows is returned even if the application does not declare
+    the supported versions of Windows in its manifest.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn backdrop_available() -> bool {
+        nwg::os_info().supports_mica()
+    }
+    ```
+*/
+pub fn os_info() -> OsInfo {
+    *OS_INFO
+}
+
+unsafe fn load_os_info() -> OsInfo {
+    let mut info = OsInfo { major: 0, minor: 0, build: 0, common_controls: (0, 0) };
+
+    if let Some(version) = os_version() {
+        info.major = version.dwMajorVersion;
+        info.minor = version.dwMinorVersion;
+        info.build = version.dwBuildNumber;
+    }
+
+    if let Some(version) = common_controls_version() {
+        info.common_controls = (version.dwMajorVersion, version.dwMinorVersion);
+    }
+
+    info
+}
+
+/// `RtlGetVersion` is not affected by the compatibility settings of the application manifest
+unsafe fn os_version() -> Option<RTL_OSVERSIONINFOW> {
+    use winapi::um::libloaderapi::{GetModuleHandleW, GetProcAddress};
+
+    let lib = to_utf16("ntdll.dll");
+    let module = GetModuleHandleW(lib.as_ptr());
+    if module.is_null() {
+        return None;
+    }
+
+    let rtl_get_version = GetProcAddress(module, "RtlGetVersion\0".as_ptr() as _);
+    if rtl_get_version.is_null() {
+        return None;
+    }
+
+    let rtl_get_version: unsafe extern "system" fn(*mut RTL_OSVERSIONINFOW) -> NTSTATUS = mem::transmute(rtl_get_version);
+
+    let mut version: RTL_OSVERSIONINFOW = mem::zeroed();
+    version.dwOSVersionInfoSize = mem::size_of::<RTL_OSVERSIONINFOW>() as _;
+    match rtl_get_version(&mut version) {
+        0 => Some(version),
+        _ => None
+    }
+}
+
+/// The version of the common controls library loaded by the application, which depends on its activation context
+unsafe fn common_controls_version() -> Option<DLLVERSIONINFO> {
+    use winapi::um::libloaderapi::{GetModuleHandleW, LoadLibraryW, GetProcAddress};
+
+    let lib = to_utf16("comctl32.dll");
+    let mut module: HMODULE = GetModuleHandleW(lib.as_ptr());
+    if module.is_null() {
+        module = LoadLibraryW(lib.as_ptr());
+        if module.is_null() {
+            return None;
+        }
+    }
+
+    let dll_get_version = GetProcAddress(module, "DllGetVersion\0".as_ptr() as _);
+    if dll_get_version.is_null() {
+        return None;
+    }
+
+    let dll_get_version: unsafe extern "system" fn(*mut DLLVERSIONINFO) -> HRESULT = mem::transmute(dll_get_version);
+
+    let mut version: DLLVERSIONINFO = mem::zeroed();
+    version.cbSize = mem::size_of::<DLLVERSIONINFO>() as _;
+    match dll_get_version(&mut version) {
+        0 => Some(version),
+        _ => None
+    }
+}
@@ -0,0 +1,143 @@
+//! Pointer input (pen and touch) sent with the WM_POINTER messages.
+//! The pointer functions are loaded at runtime, this way applications using NWG can still start on Windows 7.
+use winapi::shared::windef::HWND;
+use winapi::shared::minwindef::BOOL;
+use winapi::um::winuser::{POINTER_INPUT_TYPE, POINTER_INFO, POINTER_PEN_INFO, POINTER_TOUCH_INFO};
+use super::base_helper::to_utf16;
+use crate::{PointerData, PointerType};
+use std::mem;
+
+
+/// The pointer functions used by the pointer events. Exported by user32.dll since Windows 8.
+struct PointerApi {
+    get_pointer_type: unsafe extern "system" fn(u32, *mut POINTER_INPUT_TYPE) -> BOOL,
+    get_pointer_info: unsafe extern "system" fn(u32, *mut POINTER_INFO) -> BOOL,
+    get_pointer_pen_info: unsafe extern "system" fn(u32, *mut POINTER_PEN_INFO) -> BOOL,
+    get_pointer_touch_info: unsafe extern "system" fn(u32, *mut POINTER_TOUCH_INFO) -> BOOL,
+}
+
+lazy_static! {
+    static ref POINTER_API: Option<PointerApi> = unsafe { load_pointer_api() };
+}
+
+unsafe fn load_pointer_api() -> Option<PointerApi> {
+    use winapi::um::libloaderapi::{GetModuleHandleW, GetProcAddress};
+
+    let lib = to_utf16("user32.dll");
+    let module = GetModuleHandleW(lib.as_ptr());
+    if module.is_null() {
+        return None;
+    }
+
+    let get_pointer_type = GetProcAddress(module, "GetPointerType\0".as_ptr() as _);
+    let get_pointer_info = GetProcAddress(module, "GetPointerInfo\0".as_ptr() as _);
+    let get_pointer_pen_info = GetProcAddress(module, "GetPointerPenInfo\0".as_ptr() as _);
+    let get_pointer_touch_info = GetProcAddress(module, "GetPointerTouchInfo\0".as_ptr() as _);
+
+    let functions = [get_pointer_type, get_pointer_info, get_pointer_pen_info, get_pointer_touch_info];
+    if functions.iter().any(|f| f.is_null()) {
+        return None;
+    }
+
+    Some(PointerApi {
+        get_pointer_type: mem::transmute(get_pointer_type),
+        get_pointer_info: mem::transmute(get_pointer_info),
+        get_pointer_pen_info: mem::transmute(get_pointer_pen_info),
+        get_pointer_touch_info: mem::transmute(get_pointer_touch_info),
+    })
+}
+
+/// Reads the state of the pointer `id`. The position is converted to the logical client coordinates of `hwnd`.
+/// Returns `None` if the pointer functions are not available or if `id` is not a valid pointer.
+/// Only valid while the WM_POINTER message of the pointer is processed.
+pub unsafe fn pointer_data(hwnd: HWND, id: u32) -> Option<PointerData> {
+    use winapi::um::winuser::{PT_TOUCH, PT_PEN, PT_MOUSE, PT_TOUCHPAD, POINTER_FLAG_INCONTACT, POINTER_FLAG_PRIMARY,
+        PEN_FLAG_BARREL, PEN_FLAG_INVERTED, PEN_FLAG_ERASER, PEN_MASK_PRESSURE, PEN_MASK_ROTATION, PEN_MASK_TILT_X, PEN_MASK_TILT_Y,
+        TOUCH_MASK_PRESSURE, ScreenToClient};
+    use super::high_dpi::physical_to_logical;
+
+    let api = POINTER_API.as_ref()?;
+
+    let mut raw_type: POINTER_INPUT_TYPE = 0;
+    if (api.get_pointer_type)(id, &mut raw_type) == 0 {
+        return None;
+    }
+
+    let pointer_type = match raw_type {
+        PT_TOUCH => PointerType::Touch,
+        PT_PEN => PointerType::Pen,
+        PT_MOUSE => PointerType::Mouse,
+        PT_TOUCHPAD => PointerType::Touchpad,
+        _ => PointerType::Generic,
+    };
+
+    let mut data = PointerData {
+        id,
+        pointer_type,
+        position: [0, 0],
+        in_contact: false,
+        primary: false,
+        pressure: None,
+        tilt: None,
+        rotation: None,
+        barrel: false,
+        inverted: false,
+        eraser: false,
+    };
+
+    // The pen info and the touch info begin with the pointer info
+    let info: POINTER_INFO = match pointer_type {
+        PointerType::Pen => {
+            let mut pen: POINTER_PEN_INFO = mem::zeroed();
+            if (api.get_pointer_pen_info)(id, &mut pen) == 0 {
+                return None;
+            }
+
+            if pen.penMask & PEN_MASK_PRESSURE != 0 {
+                data.pressure = Some(pen.pressure as f32 / 1024.0);
+            }
+            if pen.penMask & PEN_MASK_ROTATION != 0 {
+                data.rotation = Some(pen.rotation);
+            }
+            if pen.penMask & (PEN_MASK_TILT_X | PEN_MASK_TILT_Y) != 0 {
+                data.tilt = Some([pen.tiltX, pen.tiltY]);
+            }
+
+            data.barrel = pen.penFlags & PEN_FLAG_BARREL != 0;
+            data.inverted = pen.penFlags & PEN_FLAG_INVERTED != 0;
+            data.eraser = pen.penFlags & PEN_FLAG_ERASER != 0;
+
+            pen.pointerInfo
+        },
+        PointerType::Touch => {
+            let mut touch: POINTER_TOUCH_INFO = mem::zeroed();
+            if (api.get_pointer_touch_info)(id, &mut touch) == 0 {
+                return None;
+            }
+
+            if touch.touchMask & TOUCH_MASK_PRESSURE != 0 {
+                data.pressure = Some(touch.pressure as f32 / 1024.0);
+            }
+
+            touch.pointerInfo
+        },
+        _ => {
+            let mut info: POINTER_INFO = mem::zeroed();
+            if (api.get_pointer_info)(id, &mut info) == 0 {
+                return None;
+            }
+
+            info
+        }
+    };
+
+    let mut point = info.ptPixelLocation;
+    ScreenToClient(hwnd, &mut point);
+    let (x, y) = physical_to_logical(point.x, point.y);
+
+    data.position = [x, y];
+    data.in_contact = info.pointerFlags & POINTER_FLAG_INCONTACT != 0;
+    data.primary = info.pointerFlags & POINTER_FLAG_PRIMARY != 0;
+
+    Some(data)
+}
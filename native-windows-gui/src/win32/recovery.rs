@@ -0,0 +1,174 @@
+/*!
+    Wrappers over the application restart and recovery functions of Windows Error Reporting.
+*/
+use winapi::shared::windef::HWND;
+use winapi::shared::minwindef::{UINT, DWORD};
+use winapi::shared::basetsd::UINT_PTR;
+use winapi::shared::winerror::{HRESULT, S_OK};
+use winapi::um::winnt::PVOID;
+use super::base_helper::to_utf16;
+use crate::{ControlHandle, RawEventHandler, NwgError, unbind_raw_event_handler};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::cell::RefCell;
+
+/// Id of the raw event handler that signals the end of the recovery
+const RECOVERY_HANDLER_ID: UINT_PTR = 0xFF06;
+
+/// The maximum length of the restart command line, in characters
+const RESTART_MAX_CMD_LINE: usize = 1024;
+
+/// Windows Error Reporting waits this long (in ms) between two calls to `ApplicationRecoveryInProgress`
+const RECOVERY_DEFAULT_PING_INTERVAL: DWORD = 5000;
+
+/// Set when the GUI thread handled the recovery message
+static RECOVERY_DONE: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static RECOVERY_HANDLER: RefCell<Option<RawEventHandler>> = RefCell::new(None);
+}
+
+lazy_static! {
+    /// Posted to the recovery window by the recovery callback. The window dispatches a `OnRecovery` event.
+    pub static ref NWG_RECOVERY: UINT = unsafe {
+        use winapi::um::winuser::RegisterWindowMessageW;
+
+        let name = to_utf16("NWG_RECOVERY");
+        RegisterWindowMessageW(name.as_ptr())
+    };
+}
+
+bitflags! {
+    /**
+        The situations where the application is not restarted by `register_application_restart`
+
+        * NO_CRASH:  Do not restart the application if it crashed
+        * NO_HANG:   Do not restart the application if it stopped responding
+        * NO_PATCH:  Do not restart the application if it was closed by a software update
+        * NO_REBOOT: Do not restart the application if the system was restarted by a software update
+    */
+    pub struct RestartFlags: u32 {
+        const NONE = 0;
+        const NO_CRASH = 1;
+        const NO_HANG = 2;
+        const NO_PATCH = 4;
+        const NO_REBOOT = 8;
+    }
+}
+
+/**
+    Ask Windows to restart the application with `args` as command line arguments if it crashes, stops responding,
+    or is closed by a software update. The application must run for at least 60 seconds before it can be restarted.
+
+    The command line is limited to 1024 characters. Requires the `recovery` feature.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn init() {
+        // Restore the documents of the user when the application is restarted
+        nwg::register_application_restart("--restore", nwg::RestartFlags::NONE).ok();
+    }
+    ```
+*/
+pub fn register_application_restart(args: &str, flags: RestartFlags) -> Result<(), NwgError> {
+    use winapi::um::winbase::RegisterApplicationRestart;
+
+    if args.encode_utf16().count() >= RESTART_MAX_CMD_LINE {
+        return Err(NwgError::recovery(format!("The restart command line is longer than {} characters", RESTART_MAX_CMD_LINE - 1)));
+    }
+
+    let args = to_utf16(args);
+    check("RegisterApplicationRestart", unsafe { RegisterApplicationRestart(args.as_ptr(), flags.bits()) })
+}
+
+/// Stop restarting the application. See `register_application_restart`.
+pub fn unregister_application_restart() -> Result<(), NwgError> {
+    use winapi::um::winbase::UnregisterApplicationRestart;
+
+    check("UnregisterApplicationRestart", unsafe { UnregisterApplicationRestart() })
+}
+
+/**
+    Send a `OnRecovery` event to `window` if the application crashes or stops responding, so that the data
+    of the user can be saved before the process ends. The event is dispatched by the GUI thread; if the GUI thread
+    is not responding, the recovery ends when the user cancels it.
+
+    The event handlers of `window` are called from the thread that called this function. `window` must be a top level window.
+    Requires the `recovery` feature.
+*/
+pub fn register_application_recovery<C: Into<ControlHandle>>(window: C) -> Result<(), NwgError> {
+    use winapi::um::winbase::RegisterApplicationRecoveryCallback;
+    use winapi::um::commctrl::DefSubclassProc;
+    use crate::bind_raw_event_handler_inner;
+
+    let control = window.into();
+    let handle = control.hwnd().expect("The recovery window must be a window-like control");
+
+    unregister_recovery_handler();
+
+    // The other handlers of the window are called by `DefSubclassProc`, so the end of the recovery
+    // is only signaled once all the event handlers received the `OnRecovery` event.
+    let handler = bind_raw_event_handler_inner(&control, RECOVERY_HANDLER_ID, |hwnd, msg, w, l| {
+        if msg != *NWG_RECOVERY {
+            return None;
+        }
+
+        unsafe { DefSubclassProc(hwnd, msg, w, l); }
+        RECOVERY_DONE.store(true, Ordering::SeqCst);
+
+        Some(0)
+    })?;
+
+    RECOVERY_HANDLER.with(|h| *h.borrow_mut() = Some(handler));
+
+    check("RegisterApplicationRecoveryCallback", unsafe {
+        RegisterApplicationRecoveryCallback(Some(recovery_callback), handle as PVOID, RECOVERY_DEFAULT_PING_INTERVAL, 0)
+    })
+}
+
+/// Stop sending the `OnRecovery` event. See `register_application_recovery`.
+pub fn unregister_application_recovery() -> Result<(), NwgError> {
+    use winapi::um::winbase::UnregisterApplicationRecoveryCallback;
+
+    unregister_recovery_handler();
+    check("UnregisterApplicationRecoveryCallback", unsafe { UnregisterApplicationRecoveryCallback() })
+}
+
+fn unregister_recovery_handler() {
+    if let Some(handler) = RECOVERY_HANDLER.with(|h| h.borrow_mut().take()) {
+        // The window might already be destroyed
+        drop(unbind_raw_event_handler(&handler));
+    }
+}
+
+/// Called by Windows Error Reporting on its own thread. Waits for the GUI thread to handle the recovery message.
+unsafe extern "system" fn recovery_callback(param: PVOID) -> DWORD {
+    use winapi::um::winbase::{ApplicationRecoveryInProgress, ApplicationRecoveryFinished};
+    use winapi::um::winuser::PostMessageW;
+    use winapi::um::synchapi::Sleep;
+
+    RECOVERY_DONE.store(false, Ordering::SeqCst);
+    PostMessageW(param as HWND, *NWG_RECOVERY, 0, 0);
+
+    loop {
+        if RECOVERY_DONE.load(Ordering::SeqCst) {
+            ApplicationRecoveryFinished(1);
+            return 0;
+        }
+
+        let mut cancelled = 0;
+        ApplicationRecoveryInProgress(&mut cancelled);
+        if cancelled != 0 {
+            return 0;
+        }
+
+        Sleep(100);
+    }
+}
+
+fn check(function: &str, result: HRESULT) -> Result<(), NwgError> {
+    match result {
+        S_OK => Ok(()),
+        r => Err(NwgError::recovery(format!("{} failed (0x{:08X})", function, r)))
+    }
+}
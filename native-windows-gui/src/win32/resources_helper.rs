@@ -82,6 +82,27 @@ pub unsafe fn build_font(
     }
 }
 
+/// Create a copy of the message font of the system (the font used in the message boxes)
+pub unsafe fn build_message_font() -> Result<HFONT, NwgError> {
+    use winapi::um::winuser::{SystemParametersInfoW, NONCLIENTMETRICSW, SPI_GETNONCLIENTMETRICS};
+    use winapi::um::wingdi::CreateFontIndirectW;
+
+    let mut metrics: NONCLIENTMETRICSW = mem::zeroed();
+    metrics.cbSize = mem::size_of::<NONCLIENTMETRICSW>() as _;
+
+    let size = metrics.cbSize;
+    if SystemParametersInfoW(SPI_GETNONCLIENTMETRICS, size, &mut metrics as *mut NONCLIENTMETRICSW as _, 0) == 0 {
+        return Err( NwgError::resource_create("Failed to read the system message font") );
+    }
+
+    let handle = CreateFontIndirectW(&metrics.lfMessageFont);
+    if handle.is_null() {
+        Err( NwgError::resource_create("Failed to create font") )
+    } else {
+        Ok( handle )
+    }
+}
+
 pub unsafe fn build_image<'a>(
     source: &'a str,
     size: Option<(u32, u32)>,
@@ -218,6 +239,108 @@ pub unsafe fn build_oem_image(
 }
 
 
+/**
+    Create a 32 bits ARGB bitmap from an icon. The icon alpha channel is kept.
+    If the icon do not have an alpha channel, the transparency is read from the icon mask instead.
+*/
+pub unsafe fn icon_to_argb_bitmap(icon: HANDLE) -> Result<HANDLE, NwgError> {
+    use winapi::um::winuser::{GetIconInfo, DrawIconEx, ICONINFO};
+    use winapi::um::wingdi::{GetObjectW, CreateCompatibleDC, SelectObject, DeleteDC, DeleteObject, GdiFlush, BITMAP};
+    use winapi::shared::windef::HICON;
+    use winapi::shared::minwindef::UINT;
+    use std::slice;
+
+    // Missing from winapi
+    const DI_MASK: UINT = 0x0001;
+    const DI_NORMAL: UINT = 0x0003;
+
+    let icon = icon as HICON;
+
+    let mut info: ICONINFO = mem::zeroed();
+    if GetIconInfo(icon, &mut info) == 0 {
+        return Err(NwgError::resource_create("Failed to read the icon information"));
+    }
+
+    // Monochrome icons store both the AND and the XOR mask in `hbmMask`, so the image is twice as high
+    let mut bm: BITMAP = mem::zeroed();
+    let has_color = !info.hbmColor.is_null();
+    let source = if has_color { info.hbmColor } else { info.hbmMask };
+    GetObjectW(source as _, mem::size_of::<BITMAP>() as c_int, &mut bm as *mut BITMAP as _);
+
+    let width = bm.bmWidth;
+    let height = if has_color { bm.bmHeight } else { bm.bmHeight / 2 };
+
+    if has_color { DeleteObject(info.hbmColor as _); }
+    if !info.hbmMask.is_null() { DeleteObject(info.hbmMask as _); }
+
+    let dc = CreateCompatibleDC(ptr::null_mut());
+    let (bitmap, bits) = match argb_dib_section(dc, width, height) {
+        Ok(section) => section,
+        Err(e) => { DeleteDC(dc); return Err(e); }
+    };
+
+    let old = SelectObject(dc, bitmap as _);
+    DrawIconEx(dc, 0, 0, icon, width, height, 0, ptr::null_mut(), DI_NORMAL);
+    GdiFlush();
+
+    let count = (width * height) as usize;
+    let pixels = slice::from_raw_parts_mut(bits, count);
+    if pixels.iter().all(|p| p & 0xFF000000 == 0) {
+        if let Ok((mask_bitmap, mask_bits)) = argb_dib_section(dc, width, height) {
+            SelectObject(dc, mask_bitmap as _);
+            DrawIconEx(dc, 0, 0, icon, width, height, 0, ptr::null_mut(), DI_MASK);
+            GdiFlush();
+
+            // Black pixels in the mask are opaque, white pixels are transparent
+            let mask = slice::from_raw_parts(mask_bits, count);
+            for (pixel, mask) in pixels.iter_mut().zip(mask.iter()) {
+                match mask & 0x00FFFFFF == 0 {
+                    true => { *pixel |= 0xFF000000; },
+                    false => { *pixel = 0; }
+                }
+            }
+
+            SelectObject(dc, bitmap as _);
+            DeleteObject(mask_bitmap as _);
+        }
+    }
+
+    SelectObject(dc, old);
+    DeleteDC(dc);
+
+    Ok(bitmap as HANDLE)
+}
+
+/// Create a top-down 32 bits DIB section. Return the bitmap and a pointer to its pixels.
+unsafe fn argb_dib_section(dc: winapi::shared::windef::HDC, width: c_int, height: c_int) -> Result<(HBITMAP, *mut u32), NwgError> {
+    use winapi::um::wingdi::{CreateDIBSection, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS, RGBQUAD};
+    use winapi::shared::minwindef::DWORD;
+    use winapi::ctypes::c_void;
+
+    let header = BITMAPINFOHEADER {
+        biSize: mem::size_of::<BITMAPINFOHEADER>() as DWORD,
+        biWidth: width, biHeight: -height,
+        biPlanes: 1, biBitCount: 32, biCompression: BI_RGB,
+        biSizeImage: 0,
+        biXPelsPerMeter: 0, biYPelsPerMeter: 0,
+        biClrUsed: 0, biClrImportant: 0
+    };
+
+    let quad = RGBQUAD { rgbBlue: 0, rgbGreen: 0, rgbRed: 0, rgbReserved: 0 };
+    let info = BITMAPINFO {
+        bmiHeader: header,
+        bmiColors: [quad],
+    };
+
+    let mut bits: *mut c_void = ptr::null_mut();
+    let bitmap = CreateDIBSection(dc, &info, DIB_RGB_COLORS, &mut bits, ptr::null_mut(), 0);
+    if bitmap.is_null() || bits.is_null() {
+        return Err(NwgError::resource_create("Failed to create a 32 bits bitmap"));
+    }
+
+    Ok((bitmap, bits as *mut u32))
+}
+
 /** 
     Create a bitmap from memory. Only supports bitmap. Enable the `image-decoder` to load more image type from memory
     The memory must contain the whole file (including the bitmap header).
@@ -391,7 +514,7 @@ pub unsafe fn create_file_dialog<'a, 'b>(
     match &filters {
         &Some(ref f) => match file_dialog_set_filters(file_dialog, f) {
             Ok(_) => (),
-            Err(e) => { println!("set filters"); file_dialog.Release(); return Err(e); }
+            Err(e) => { file_dialog.Release(); return Err(e); }
         },
         &None => ()
     }
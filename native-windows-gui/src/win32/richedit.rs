@@ -3,7 +3,8 @@
 use winapi::um::winuser::WM_USER;
 use winapi::um::wingdi::{LF_FACESIZE, RGB};
 use winapi::shared::{
-    minwindef::{UINT, DWORD, WORD, BYTE},
+    minwindef::{UINT, DWORD, WORD, BYTE, LPBYTE},
+    basetsd::DWORD_PTR,
     ntdef::{LONG, SHORT, LCID},
     windef::{HWND, COLORREF}
 };
@@ -13,15 +14,36 @@ use crate::controls::{CharFormat, ParaFormat, CharEffects, UnderlineType, ParaNu
 ParaNumberingStyle, ParaAlignment, ParaLineSpacing};
 use std::{mem, ptr};
 use std::convert::TryFrom;
+use std::ops::Range;
 
 pub const EM_SETBKGNDCOLOR: u32 = WM_USER + 67;
+pub const EM_SETUNDOLIMIT: u32 = WM_USER + 82;
+pub const EM_REDO: u32 = WM_USER + 84;
+pub const EM_CANREDO: u32 = WM_USER + 85;
+pub const EM_STOPGROUPTYPING: u32 = WM_USER + 88;
 
 const EM_GETCHARFORMAT: u32 = WM_USER + 58;
 const EM_GETPARAFORMAT: u32 = WM_USER + 61;
 const EM_SETCHARFORMAT: u32 = WM_USER + 68;
 const EM_SETPARAFORMAT: u32 = WM_USER + 71;
+const EM_STREAMIN: u32 = WM_USER + 73;
+const EM_STREAMOUT: u32 = WM_USER + 74;
+const EM_GETTEXTRANGE: u32 = WM_USER + 75;
+const EM_GETTEXTLENGTHEX: u32 = WM_USER + 95;
+const EM_SETLANGOPTIONS: u32 = WM_USER + 120;
+const EM_GETLANGOPTIONS: u32 = WM_USER + 121;
+const EM_SETEDITSTYLE: u32 = WM_USER + 204;
 const SCF_SELECTION: u32 = 1;
 
+const IMF_SPELLCHECKING: u32 = 0x0800;
+const SES_USECTF: u32 = 0x00010000;
+const GTL_PRECISE: u32 = 2;
+const GTL_NUMCHARS: u32 = 8;
+const CP_UNICODE: u32 = 1200;
+
+const SF_RTF: u32 = 0x0002;
+const SFF_SELECTION: u32 = 0x8000;
+
 const MAX_TAB_STOPS: usize = 32;
 
 const CFM_EFFECTS: u32 = 0x001 | 0x002 | 0x004 | 0x008 | 0x010 | 0x020 | 0x40000000;
@@ -30,6 +52,8 @@ const CFM_OFFSET: u32 = 0x10000000;
 const CFM_COLOR: u32 = 0x40000000;
 const CFM_FACE: u32 = 0x20000000;
 const CFM_UNDERLINETYPE: u32 = 0x00800000;
+const CFM_BACKCOLOR: u32 = 0x04000000;
+const CFE_AUTOBACKCOLOR: u32 = CFM_BACKCOLOR;
 
 const PFM_STARTINDENT: u32 = 0x00000001;
 const PFM_RIGHTINDENT: u32 = 0x00000002;
@@ -93,6 +117,37 @@ struct CHARFORMATW {
     bUnderlineColor: BYTE
 }
 
+type EDITSTREAMCALLBACK = Option<unsafe extern "system" fn(DWORD_PTR, LPBYTE, LONG, *mut LONG) -> DWORD>;
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct EDITSTREAM {
+    dwCookie: DWORD_PTR,
+    dwError: DWORD,
+    pfnCallback: EDITSTREAMCALLBACK,
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct CHARRANGE {
+    cpMin: LONG,
+    cpMax: LONG,
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct TEXTRANGEW {
+    chrg: CHARRANGE,
+    lpstrText: *mut u16,
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct GETTEXTLENGTHEX {
+    flags: DWORD,
+    codepage: UINT,
+}
+
 #[repr(C)]
 #[allow(non_snake_case)]
 #[derive(Default)]
@@ -133,11 +188,18 @@ pub(crate) fn set_char_format(handle: HWND, fmt: &CharFormat) {
     if fmt.text_color.is_some() { mask |= CFM_COLOR; }
     if fmt.font_face_name.is_some() { mask |= CFM_FACE; }
     if fmt.underline_type.is_some() { mask |= CFM_UNDERLINETYPE }
+    if fmt.background_color.is_some() { mask |= CFM_BACKCOLOR; }
+
     let mut color = 0;
     if let Some([r, g, b]) = fmt.text_color {
         color = RGB(r, g, b);
     }
 
+    let mut back_color = 0;
+    if let Some([r, g, b]) = fmt.background_color {
+        back_color = RGB(r, g, b);
+    }
+
     let mut face: [u16; LF_FACESIZE] = [0; LF_FACESIZE];
     if let Some(face_name) = fmt.font_face_name.as_ref() {
         let face_name = to_utf16(&face_name);
@@ -171,6 +233,7 @@ pub(crate) fn set_char_format(handle: HWND, fmt: &CharFormat) {
         yHeight: fmt.height.unwrap_or(0),
         yOffset: fmt.y_offset.unwrap_or(0),
         crTextColor: color,
+        crBackColor: back_color,
         bUnderlineType: underline_type,
         szFaceName: face,
         .. Default::default()
@@ -210,6 +273,15 @@ pub(crate) fn char_format(handle: HWND) -> CharFormat {
         ]);
     }
 
+    let mut background_color = None;
+    if fmt.dwMask & CFM_BACKCOLOR == CFM_BACKCOLOR && fmt.dwEffects & CFE_AUTOBACKCOLOR == 0 {
+        background_color = Some([
+            GetRValue(fmt.crBackColor),
+            GetGValue(fmt.crBackColor),
+            GetBValue(fmt.crBackColor),
+        ]);
+    }
+
     let underline_type = match fmt.bUnderlineType {
         1 => Some(UnderlineType::Solid),
         5 => Some(UnderlineType::Dash),
@@ -233,6 +305,7 @@ pub(crate) fn char_format(handle: HWND) -> CharFormat {
         text_color,
         font_face_name,
         underline_type,
+        background_color,
     }
 }
 
@@ -453,3 +526,149 @@ pub(crate) fn para_format(handle: HWND) -> ParaFormat {
     }
 }
 
+
+/// Replace the content of the control (or the current selection if `selection` is true) with RTF data
+pub(crate) fn stream_in_rtf(handle: HWND, data: &[u8], selection: bool) {
+    struct StreamIn<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+
+    unsafe extern "system" fn read(cookie: DWORD_PTR, buffer: LPBYTE, cb: LONG, pcb: *mut LONG) -> DWORD {
+        let stream = &mut *(cookie as *mut StreamIn);
+        let remaining = &stream.data[stream.pos..];
+        let count = remaining.len().min(cb as usize);
+
+        ptr::copy_nonoverlapping(remaining.as_ptr(), buffer, count);
+        stream.pos += count;
+        *pcb = count as LONG;
+
+        0
+    }
+
+    let mut stream = StreamIn { data, pos: 0 };
+    let mut info = EDITSTREAM {
+        dwCookie: &mut stream as *mut StreamIn as DWORD_PTR,
+        dwError: 0,
+        pfnCallback: Some(read),
+    };
+
+    let mut flags = SF_RTF;
+    if selection { flags |= SFF_SELECTION; }
+
+    wh::send_message(handle, EM_STREAMIN, flags as _, &mut info as *mut EDITSTREAM as _);
+}
+
+/// Return the content of the control (or the current selection if `selection` is true) as RTF data
+pub(crate) fn stream_out_rtf(handle: HWND, selection: bool) -> Vec<u8> {
+    unsafe extern "system" fn write(cookie: DWORD_PTR, buffer: LPBYTE, cb: LONG, pcb: *mut LONG) -> DWORD {
+        let out = &mut *(cookie as *mut Vec<u8>);
+        out.extend_from_slice(::std::slice::from_raw_parts(buffer, cb as usize));
+        *pcb = cb;
+
+        0
+    }
+
+    let mut out: Vec<u8> = Vec::new();
+    let mut info = EDITSTREAM {
+        dwCookie: &mut out as *mut Vec<u8> as DWORD_PTR,
+        dwError: 0,
+        pfnCallback: Some(write),
+    };
+
+    let mut flags = SF_RTF;
+    if selection { flags |= SFF_SELECTION; }
+
+    wh::send_message(handle, EM_STREAMOUT, flags as _, &mut info as *mut EDITSTREAM as _);
+
+    out
+}
+
+/// Enable or disable the spell checking of the control. Misspelled words are underlined by the control.
+pub(crate) fn set_spell_checking(handle: HWND, enabled: bool) {
+    let options = wh::send_message(handle, EM_GETLANGOPTIONS, 0, 0) as u32;
+    let options = match enabled {
+        true => options | IMF_SPELLCHECKING,
+        false => options & !IMF_SPELLCHECKING,
+    };
+
+    // Spell checking goes through the text services framework
+    if enabled {
+        wh::send_message(handle, EM_SETEDITSTYLE, SES_USECTF as _, SES_USECTF as _);
+    }
+
+    wh::send_message(handle, EM_SETLANGOPTIONS, 0, options as _);
+}
+
+/// Check if the spell checking of the control is enabled
+pub(crate) fn spell_checking(handle: HWND) -> bool {
+    let options = wh::send_message(handle, EM_GETLANGOPTIONS, 0, 0) as u32;
+    options & IMF_SPELLCHECKING == IMF_SPELLCHECKING
+}
+
+/// Return the number of characters in the control. Unlike `GetWindowTextLength`, line breaks count as a single character.
+pub(crate) fn text_length(handle: HWND) -> usize {
+    let mut info = GETTEXTLENGTHEX { flags: GTL_PRECISE | GTL_NUMCHARS, codepage: CP_UNICODE };
+    wh::send_message(handle, EM_GETTEXTLENGTHEX, &mut info as *mut GETTEXTLENGTHEX as _, 0) as usize
+}
+
+/// Return the text between the character indices `start` and `end`
+pub(crate) fn text_range(handle: HWND, start: usize, end: usize) -> String {
+    let end = end.min(text_length(handle));
+    if start >= end {
+        return String::new();
+    }
+
+    let mut buffer: Vec<u16> = vec![0; (end - start) + 1];
+    let mut range = TEXTRANGEW {
+        chrg: CHARRANGE { cpMin: start as LONG, cpMax: end as LONG },
+        lpstrText: buffer.as_mut_ptr(),
+    };
+
+    let count = wh::send_message(handle, EM_GETTEXTRANGE, 0, &mut range as *mut TEXTRANGEW as _) as usize;
+    from_utf16(&buffer[..count])
+}
+
+/// Return the character index nearest to a point in the client area of the control
+pub(crate) fn char_from_pos(handle: HWND, x: i32, y: i32) -> usize {
+    use winapi::um::winuser::EM_CHARFROMPOS;
+    use winapi::shared::windef::POINTL;
+
+    let mut point = POINTL { x, y };
+    wh::send_message(handle, EM_CHARFROMPOS as u32, 0, &mut point as *mut POINTL as _) as usize
+}
+
+/// Return the word at the character index `index` and its range in the control text
+pub(crate) fn word_at(handle: HWND, index: usize) -> Option<(String, Range<usize>)> {
+    const MAX_WORD: usize = 64;
+
+    let start = index.saturating_sub(MAX_WORD);
+    let text: Vec<char> = text_range(handle, start, index + MAX_WORD).chars().collect();
+
+    let is_word = |c: &char| c.is_alphanumeric() || *c == '\'';
+
+    // Map the character index back into the chars of the text chunk
+    let mut offset = start;
+    let mut pos = None;
+    for (i, c) in text.iter().enumerate() {
+        if offset >= index { pos = Some(i); break; }
+        offset += c.len_utf16();
+    }
+
+    let pos = pos?;
+    if !text.get(pos).map(is_word).unwrap_or(false) {
+        return None;
+    }
+
+    let mut first = pos;
+    while first > 0 && is_word(&text[first-1]) { first -= 1; }
+
+    let mut last = pos;
+    while last + 1 < text.len() && is_word(&text[last+1]) { last += 1; }
+
+    let word: String = text[first..=last].iter().collect();
+    let word_start = start + text[..first].iter().map(|c| c.len_utf16()).sum::<usize>();
+    let word_end = word_start + word.encode_utf16().count();
+
+    Some((word, word_start..word_end))
+}
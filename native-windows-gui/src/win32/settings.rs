@@ -0,0 +1,258 @@
+/*!
+    Save and restore the settings of an application in the registry of the current user.
+*/
+use winapi::shared::minwindef::{HKEY, DWORD};
+use winapi::shared::winerror::ERROR_SUCCESS;
+use winapi::um::winnt::LONG;
+use super::base_helper::{to_utf16, from_utf16};
+use crate::{ControlHandle, NwgError};
+use std::{mem, ptr};
+
+/**
+    The settings of an application, stored in the `HKEY_CURRENT_USER\Software\{application}` registry key.
+    The values are strings. The `save_*` and `restore_*` methods store the state of the controls so that
+    the layout of the application can be restored the next time it starts.
+
+    Requires the `settings` feature.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn restore(window: &nwg::Window) -> nwg::Settings {
+        let settings = nwg::Settings::open("MyCompany\\MyApp").unwrap();
+        if !settings.restore_window("main", window) {
+            window.set_visible(true);
+        }
+
+        settings
+    }
+
+    fn save(settings: &nwg::Settings, window: &nwg::Window) {
+        settings.save_window("main", window).ok();
+    }
+    ```
+*/
+pub struct Settings {
+    key: HKEY,
+}
+
+impl Settings {
+
+    /// Open the settings of `application`. The registry key is created if it does not exist.
+    pub fn open(application: &str) -> Result<Settings, NwgError> {
+        use winapi::um::winreg::{RegCreateKeyExW, HKEY_CURRENT_USER};
+        use winapi::um::winnt::{KEY_READ, KEY_WRITE, REG_OPTION_NON_VOLATILE};
+
+        let path = to_utf16(&format!("Software\\{}", application));
+        let mut key: HKEY = ptr::null_mut();
+
+        let result = unsafe {
+            RegCreateKeyExW(HKEY_CURRENT_USER, path.as_ptr(), 0, ptr::null_mut(), REG_OPTION_NON_VOLATILE,
+                KEY_READ | KEY_WRITE, ptr::null_mut(), &mut key, ptr::null_mut())
+        };
+
+        check("RegCreateKeyExW", result)?;
+
+        Ok(Settings { key })
+    }
+
+    /// Delete all the settings of `application`
+    pub fn delete(application: &str) -> Result<(), NwgError> {
+        use winapi::um::winreg::{RegDeleteTreeW, HKEY_CURRENT_USER};
+        use winapi::shared::winerror::ERROR_FILE_NOT_FOUND;
+
+        let path = to_utf16(&format!("Software\\{}", application));
+        match unsafe { RegDeleteTreeW(HKEY_CURRENT_USER, path.as_ptr()) } as DWORD {
+            ERROR_FILE_NOT_FOUND => Ok(()),
+            result => check("RegDeleteTreeW", result as LONG)
+        }
+    }
+
+    /// Return the value `name`, or `None` if the value does not exist
+    pub fn value(&self, name: &str) -> Option<String> {
+        use winapi::um::winreg::{RegGetValueW, RRF_RT_REG_SZ};
+
+        let name = to_utf16(name);
+        let mut size: DWORD = 0;
+
+        unsafe {
+            let result = RegGetValueW(self.key, ptr::null(), name.as_ptr(), RRF_RT_REG_SZ, ptr::null_mut(), ptr::null_mut(), &mut size);
+            if result as DWORD != ERROR_SUCCESS {
+                return None;
+            }
+
+            let mut buffer: Vec<u16> = vec![0; size as usize / mem::size_of::<u16>()];
+            let result = RegGetValueW(self.key, ptr::null(), name.as_ptr(), RRF_RT_REG_SZ, ptr::null_mut(), buffer.as_mut_ptr() as _, &mut size);
+            if result as DWORD != ERROR_SUCCESS {
+                return None;
+            }
+
+            Some(from_utf16(&buffer))
+        }
+    }
+
+    /// Set the value `name`
+    pub fn set_value(&self, name: &str, value: &str) -> Result<(), NwgError> {
+        use winapi::um::winreg::RegSetValueExW;
+        use winapi::um::winnt::REG_SZ;
+
+        let name = to_utf16(name);
+        let value = to_utf16(value);
+        let size = (value.len() * mem::size_of::<u16>()) as DWORD;
+
+        let result = unsafe { RegSetValueExW(self.key, name.as_ptr(), 0, REG_SZ, value.as_ptr() as _, size) };
+        check("RegSetValueExW", result)
+    }
+
+    /// Remove the value `name`. Does nothing if the value does not exist.
+    pub fn remove_value(&self, name: &str) -> Result<(), NwgError> {
+        use winapi::um::winreg::RegDeleteValueW;
+        use winapi::shared::winerror::ERROR_FILE_NOT_FOUND;
+
+        let name = to_utf16(name);
+        match unsafe { RegDeleteValueW(self.key, name.as_ptr()) } as DWORD {
+            ERROR_FILE_NOT_FOUND => Ok(()),
+            result => check("RegDeleteValueW", result as LONG)
+        }
+    }
+
+    /**
+        Save the position, the size, and the maximized state of a top level `window` in the value `name`.
+        Panics if `window` is not a window-like control.
+    */
+    pub fn save_window<C: Into<ControlHandle>>(&self, name: &str, window: C) -> Result<(), NwgError> {
+        use winapi::um::winuser::{GetWindowPlacement, WINDOWPLACEMENT, SW_SHOWMAXIMIZED, SW_SHOWNORMAL};
+
+        let handle = window.into().hwnd().expect("Saved windows must be window-like controls");
+
+        let placement = unsafe {
+            let mut placement: WINDOWPLACEMENT = mem::zeroed();
+            placement.length = mem::size_of::<WINDOWPLACEMENT>() as _;
+            if GetWindowPlacement(handle, &mut placement) == 0 {
+                return Err(NwgError::system("GetWindowPlacement"));
+            }
+
+            placement
+        };
+
+        // A minimized window is restored in its normal state
+        let show = match placement.showCmd as i32 {
+            SW_SHOWMAXIMIZED => SW_SHOWMAXIMIZED,
+            _ => SW_SHOWNORMAL
+        };
+
+        let r = placement.rcNormalPosition;
+        self.set_value(name, &format_values(&[show, r.left, r.top, r.right, r.bottom]))
+    }
+
+    /**
+        Restore the position, the size, and the maximized state of a top level `window` saved with `save_window`, and show the window.
+        Returns `false` if the value does not exist or if the window would be outside of the monitors (ex: the monitor was removed).
+        Panics if `window` is not a window-like control.
+    */
+    pub fn restore_window<C: Into<ControlHandle>>(&self, name: &str, window: C) -> bool {
+        use winapi::um::winuser::{SetWindowPlacement, MonitorFromRect, WINDOWPLACEMENT, MONITOR_DEFAULTTONULL};
+        use winapi::shared::windef::RECT;
+
+        let handle = window.into().hwnd().expect("Restored windows must be window-like controls");
+
+        let values = match self.value(name).and_then(|v| parse_values(&v)) {
+            Some(values) if values.len() == 5 => values,
+            _ => { return false; }
+        };
+
+        let rect = RECT { left: values[1], top: values[2], right: values[3], bottom: values[4] };
+
+        unsafe {
+            if MonitorFromRect(&rect, MONITOR_DEFAULTTONULL).is_null() {
+                return false;
+            }
+
+            let mut placement: WINDOWPLACEMENT = mem::zeroed();
+            placement.length = mem::size_of::<WINDOWPLACEMENT>() as _;
+            placement.showCmd = values[0] as _;
+            placement.rcNormalPosition = rect;
+
+            SetWindowPlacement(handle, &placement) != 0
+        }
+    }
+
+    /// Save the width and the order of the columns of a list view in the value `name`
+    #[cfg(feature = "list-view")]
+    pub fn save_list_view_columns(&self, name: &str, list_view: &crate::ListView) -> Result<(), NwgError> {
+        use winapi::um::commctrl::{LVM_GETCOLUMNWIDTH, LVM_GETCOLUMNORDERARRAY};
+        use super::window_helper as wh;
+
+        let handle = list_view.handle.hwnd().expect("List view is not initialized");
+        let count = list_view.column_len();
+
+        let mut values: Vec<i32> = (0..count)
+            .map(|i| wh::send_message(handle, LVM_GETCOLUMNWIDTH, i as _, 0) as i32)
+            .collect();
+
+        let mut order: Vec<i32> = vec![0; count];
+        wh::send_message(handle, LVM_GETCOLUMNORDERARRAY, count as _, order.as_mut_ptr() as _);
+        values.append(&mut order);
+
+        self.set_value(name, &format_values(&values))
+    }
+
+    /**
+        Restore the width and the order of the columns of a list view saved with `save_list_view_columns`.
+        Returns `false` if the value does not exist or if it was saved with a different number of columns.
+    */
+    #[cfg(feature = "list-view")]
+    pub fn restore_list_view_columns(&self, name: &str, list_view: &crate::ListView) -> bool {
+        use winapi::um::commctrl::{LVM_SETCOLUMNWIDTH, LVM_SETCOLUMNORDERARRAY};
+        use winapi::shared::minwindef::MAKELONG;
+        use super::window_helper as wh;
+
+        let handle = list_view.handle.hwnd().expect("List view is not initialized");
+        let count = list_view.column_len();
+
+        let values = match self.value(name).and_then(|v| parse_values(&v)) {
+            Some(values) if values.len() == count * 2 => values,
+            _ => { return false; }
+        };
+
+        let (widths, order) = values.split_at(count);
+        if (0..count as i32).any(|i| !order.contains(&i)) {
+            return false;
+        }
+
+        for (i, &width) in widths.iter().enumerate() {
+            wh::send_message(handle, LVM_SETCOLUMNWIDTH, i as _, MAKELONG(width as u16, 0) as _);
+        }
+
+        wh::send_message(handle, LVM_SETCOLUMNORDERARRAY, count as _, order.as_ptr() as _) != 0
+    }
+
+}
+
+impl Drop for Settings {
+    fn drop(&mut self) {
+        use winapi::um::winreg::RegCloseKey;
+        unsafe { RegCloseKey(self.key); }
+    }
+}
+
+/// The registry functions return their error code instead of setting the last error of the thread
+fn check(operation: &str, result: LONG) -> Result<(), NwgError> {
+    use winapi::um::errhandlingapi::SetLastError;
+
+    match result as DWORD {
+        ERROR_SUCCESS => Ok(()),
+        code => {
+            unsafe { SetLastError(code); }
+            Err(NwgError::system(operation))
+        }
+    }
+}
+
+fn format_values(values: &[i32]) -> String {
+    values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" ")
+}
+
+fn parse_values(value: &str) -> Option<Vec<i32>> {
+    value.split_whitespace().map(|v| v.parse().ok()).collect()
+}
@@ -0,0 +1,113 @@
+/*!
+    Small wrappers over `ShellExecuteW` to open urls and files with the default application of the user.
+*/
+use super::base_helper::to_utf16;
+use crate::NwgError;
+use std::path::Path;
+use std::ptr;
+
+
+/**
+    Opens `url` in the default web browser (or in the default application of the url scheme, ex: `mailto:`).
+
+    The url must start with a scheme (ex: `https://`) so that a local path cannot be executed by mistake.
+    Use `open_file_with_default_app` to open files.
+
+    Requires the `shell` feature.
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn open_homepage() {
+        nwg::open_url("https://github.com/gabdube/native-windows-gui").ok();
+    }
+    ```
+*/
+pub fn open_url(url: &str) -> Result<(), NwgError> {
+    let scheme = url.split(':').next().unwrap_or("");
+    let valid_scheme = url.contains(':')
+        && scheme.len() > 1
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.');
+
+    if !valid_scheme {
+        return Err(NwgError::shell(format!("{:?} is not an url", url)));
+    }
+
+    unsafe { shell_execute(url, None) }
+}
+
+/**
+    Opens the file at `path` with the application associated with its extension. If `path` is a folder, it is opened in the explorer.
+
+    Requires the `shell` feature.
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn open_readme() -> Result<(), nwg::NwgError> {
+        nwg::open_file_with_default_app("C:\\Users\\Hello\\README.txt")
+    }
+    ```
+*/
+pub fn open_file_with_default_app<P: AsRef<Path>>(path: P) -> Result<(), NwgError> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Err(NwgError::shell(format!("{:?} does not exist", path)));
+    }
+
+    unsafe { shell_execute(&path.to_string_lossy(), None) }
+}
+
+/**
+    Opens the folder containing `path` in the explorer and selects `path`.
+
+    Requires the `shell` feature.
+
+    ```rust
+    use native_windows_gui as nwg;
+    fn show_export(path: &str) -> Result<(), nwg::NwgError> {
+        nwg::reveal_in_explorer(path)
+    }
+    ```
+*/
+pub fn reveal_in_explorer<P: AsRef<Path>>(path: P) -> Result<(), NwgError> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Err(NwgError::shell(format!("{:?} does not exist", path)));
+    }
+
+    // explorer does not understand the `\\?\` prefix of canonicalized paths
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let path = path.to_string_lossy();
+    let path = path.trim_start_matches("\\\\?\\");
+
+    let params = format!("/select,\"{}\"", path);
+    unsafe { shell_execute("explorer.exe", Some(&params)) }
+}
+
+
+/// Calls ShellExecuteW with the "open" verb and maps the error codes to a readable message
+unsafe fn shell_execute(file: &str, params: Option<&str>) -> Result<(), NwgError> {
+    use winapi::um::shellapi::{ShellExecuteW, SE_ERR_ACCESSDENIED, SE_ERR_NOASSOC, SE_ERR_ASSOCINCOMPLETE, SE_ERR_OOM};
+    use winapi::um::winuser::SW_SHOWNORMAL;
+    use winapi::shared::winerror::{ERROR_FILE_NOT_FOUND, ERROR_PATH_NOT_FOUND};
+
+    let verb = to_utf16("open");
+    let file = to_utf16(file);
+    let params = params.map(to_utf16);
+    let params_ptr = params.as_ref().map(|p| p.as_ptr()).unwrap_or(ptr::null());
+
+    let result = ShellExecuteW(ptr::null_mut(), verb.as_ptr(), file.as_ptr(), params_ptr, ptr::null(), SW_SHOWNORMAL) as usize;
+    if result > 32 {
+        return Ok(());
+    }
+
+    let reason = match result as u32 {
+        0 | SE_ERR_OOM => "The system is out of memory or resources",
+        ERROR_FILE_NOT_FOUND => "The file was not found",
+        ERROR_PATH_NOT_FOUND => "The path was not found",
+        SE_ERR_ACCESSDENIED => "Access denied",
+        SE_ERR_NOASSOC | SE_ERR_ASSOCINCOMPLETE => "No application is associated with this file",
+        _ => "ShellExecuteW failed"
+    };
+
+    Err(NwgError::shell(reason))
+}
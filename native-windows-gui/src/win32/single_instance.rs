@@ -0,0 +1,210 @@
+/*!
+    Allow a single instance of an application to run. The next launches forward their command line to the running instance.
+*/
+use winapi::shared::windef::HWND;
+use winapi::shared::minwindef::{UINT, BOOL, LPARAM};
+use winapi::um::winnt::HANDLE;
+use super::base_helper::{to_utf16, from_utf16};
+use crate::{ControlHandle, NwgError};
+use std::{mem, ptr, slice};
+
+lazy_static! {
+    /// Tag of the `WM_COPYDATA` messages that carry the command line of another instance
+    pub static ref NWG_SINGLE_INSTANCE: UINT = unsafe {
+        use winapi::um::winuser::RegisterWindowMessageW;
+
+        let name = to_utf16("NWG_SINGLE_INSTANCE");
+        RegisterWindowMessageW(name.as_ptr())
+    };
+}
+
+/**
+    A named mutex that tells if another instance of the application is running.
+
+    The first instance calls `listen` with its main window. The window then receives a `OnInstanceArgs` event with
+    the command line of each new instance. The new instances call `forward` to send their command line and then exit.
+
+    Requires the `single-instance` feature.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn start(window: &nwg::Window) -> Option<nwg::SingleInstance> {
+        let instance = nwg::SingleInstance::acquire("MyCompany.MyApp").unwrap();
+        if !instance.is_first() {
+            let args: Vec<String> = std::env::args().collect();
+            instance.forward(&args).ok();
+            return None;
+        }
+
+        instance.listen(window);
+
+        nwg::bind_event_handler(&window.handle, &window.handle, |evt, evt_data, handle| {
+            if evt == nwg::Event::OnInstanceArgs {
+                println!("{:?}", evt_data.on_instance_args());
+                nwg::SingleInstance::raise(handle);
+            }
+        });
+
+        Some(instance)
+    }
+    ```
+*/
+pub struct SingleInstance {
+    name: String,
+    mutex: HANDLE,
+    first: bool,
+}
+
+impl SingleInstance {
+
+    /**
+        Create or open the mutex `name`. The name should be unique to the application (ex: "MyCompany.MyApp").
+        The mutex is shared by all the sessions of the user, but not with the other users.
+    */
+    pub fn acquire(name: &str) -> Result<SingleInstance, NwgError> {
+        use winapi::um::synchapi::CreateMutexW;
+        use winapi::um::errhandlingapi::GetLastError;
+        use winapi::shared::winerror::ERROR_ALREADY_EXISTS;
+
+        let name = format!("NWG_SINGLE_INSTANCE_{}", name);
+        let mutex_name = to_utf16(&name);
+
+        unsafe {
+            let mutex = CreateMutexW(ptr::null_mut(), 0, mutex_name.as_ptr());
+            if mutex.is_null() {
+                return Err(NwgError::system("CreateMutexW"));
+            }
+
+            let first = GetLastError() != ERROR_ALREADY_EXISTS;
+
+            Ok(SingleInstance { name, mutex, first })
+        }
+    }
+
+    /// Returns `true` if no other instance of the application was running when the mutex was acquired
+    pub fn is_first(&self) -> bool {
+        self.first
+    }
+
+    /**
+        Send the command line of the next instances to `window`, as a `OnInstanceArgs` event.
+        `window` must be a top level window. Panics if `window` is not a window-like control.
+    */
+    pub fn listen<C: Into<ControlHandle>>(&self, window: C) {
+        use winapi::um::winuser::SetPropW;
+
+        let handle = window.into().hwnd().expect("The instance window must be a window-like control");
+        let prop = to_utf16(&self.name);
+
+        unsafe { SetPropW(handle, prop.as_ptr(), 1 as HANDLE); }
+    }
+
+    /**
+        Send `args` to the window of the running instance. The running instance is also allowed to bring its window to the foreground.
+        Returns an error if no running instance listens to the command lines.
+    */
+    pub fn forward<S: AsRef<str>>(&self, args: &[S]) -> Result<(), NwgError> {
+        use winapi::um::winuser::{SendMessageW, AllowSetForegroundWindow, GetWindowThreadProcessId, WM_COPYDATA, COPYDATASTRUCT};
+
+        let handle = self.find_window()
+            .ok_or_else(|| NwgError::initialization(format!("No running instance listens to {:?}", self.name)))?;
+
+        let mut data: Vec<u16> = Vec::new();
+        for arg in args {
+            data.extend(arg.as_ref().encode_utf16());
+            data.push(0);
+        }
+
+        unsafe {
+            let mut process_id = 0;
+            GetWindowThreadProcessId(handle, &mut process_id);
+            AllowSetForegroundWindow(process_id);
+
+            let copy_data = COPYDATASTRUCT {
+                dwData: *NWG_SINGLE_INSTANCE as _,
+                cbData: (data.len() * mem::size_of::<u16>()) as _,
+                lpData: data.as_mut_ptr() as _,
+            };
+
+            SendMessageW(handle, WM_COPYDATA, 0, &copy_data as *const COPYDATASTRUCT as _);
+        }
+
+        Ok(())
+    }
+
+    /// Restore `window` if it is minimized and bring it to the foreground
+    pub fn raise<C: Into<ControlHandle>>(window: C) {
+        use winapi::um::winuser::{IsIconic, ShowWindow, SetForegroundWindow, SW_RESTORE};
+
+        let handle = window.into().hwnd().expect("The instance window must be a window-like control");
+
+        unsafe {
+            if IsIconic(handle) != 0 {
+                ShowWindow(handle, SW_RESTORE);
+            }
+
+            SetForegroundWindow(handle);
+        }
+    }
+
+    /// Find the top level window that listens to the instance name
+    fn find_window(&self) -> Option<HWND> {
+        use winapi::um::winuser::{EnumWindows, GetPropW};
+
+        struct FindData {
+            prop: Vec<u16>,
+            handle: HWND,
+        }
+
+        unsafe extern "system" fn find_window(hwnd: HWND, p: LPARAM) -> BOOL {
+            let data = &mut *(p as *mut FindData);
+            if !GetPropW(hwnd, data.prop.as_ptr()).is_null() {
+                data.handle = hwnd;
+                return 0;
+            }
+
+            1
+        }
+
+        let mut data = FindData { prop: to_utf16(&self.name), handle: ptr::null_mut() };
+        unsafe { EnumWindows(Some(find_window), &mut data as *mut FindData as LPARAM); }
+
+        match data.handle.is_null() {
+            true => None,
+            false => Some(data.handle)
+        }
+    }
+
+}
+
+impl Drop for SingleInstance {
+    fn drop(&mut self) {
+        use winapi::um::handleapi::CloseHandle;
+        unsafe { CloseHandle(self.mutex); }
+    }
+}
+
+/// Read the command line sent by `SingleInstance::forward`. `l` is the `COPYDATASTRUCT` of a `WM_COPYDATA` message.
+/// Returns `None` if the message was not sent by `SingleInstance::forward`.
+pub unsafe fn instance_args(l: LPARAM) -> Option<Vec<String>> {
+    use winapi::um::winuser::COPYDATASTRUCT;
+
+    let copy_data = &*(l as *const COPYDATASTRUCT);
+    if copy_data.dwData != *NWG_SINGLE_INSTANCE as usize {
+        return None;
+    }
+
+    let data: &[u16] = match copy_data.cbData {
+        0 => &[],
+        size => slice::from_raw_parts(copy_data.lpData as *const u16, size as usize / mem::size_of::<u16>())
+    };
+
+    // Each argument ends with a null character
+    let args = match data.split_last() {
+        Some((0, args)) => args.split(|&c| c == 0).map(from_utf16).collect(),
+        _ => Vec::new()
+    };
+
+    Some(args)
+}
@@ -0,0 +1,262 @@
+//! winapi-rs does not implements spellcheck.h, so here's the low level stuff
+#![allow(non_snake_case)]
+
+use winapi::um::objidlbase::IEnumString;
+use winapi::um::unknwnbase::{IUnknown, IUnknownVtbl};
+use winapi::um::combaseapi::CoTaskMemFree;
+use winapi::shared::minwindef::{BOOL, ULONG};
+use winapi::shared::guiddef::GUID;
+use winapi::shared::ntdef::{HRESULT, LPCWSTR, LPWSTR};
+use winapi::shared::winerror::S_OK;
+use winapi::ctypes::c_void;
+use winapi::{Interface, RIDL};
+use crate::win32::base_helper::{to_utf16, from_utf16};
+use crate::{NwgError, SpellingError, SpellingAction};
+use std::ptr;
+
+
+const CLSID_SPELL_CHECKER_FACTORY: GUID = GUID {
+    Data1: 0x7AB36653, Data2: 0x1796, Data3: 0x484B,
+    Data4: [0xBD, 0xFA, 0xE7, 0x4F, 0x1D, 0xB7, 0xC1, 0xDC]
+};
+
+RIDL!{#[uuid(0x8e018a9d, 0x2415, 0x4677, 0xbf, 0x08, 0x79, 0x4e, 0xa6, 0x1f, 0x94, 0xbb)]
+interface ISpellCheckerFactory(ISpellCheckerFactoryVtbl): IUnknown(IUnknownVtbl) {
+    fn get_SupportedLanguages(
+        value: *mut *mut IEnumString,
+    ) -> HRESULT,
+    fn IsSupported(
+        languageTag: LPCWSTR,
+        value: *mut BOOL,
+    ) -> HRESULT,
+    fn CreateSpellChecker(
+        languageTag: LPCWSTR,
+        value: *mut *mut ISpellChecker,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0xb6fd0b71, 0xe2bc, 0x4653, 0x8d, 0x05, 0xf1, 0x97, 0xe4, 0x12, 0x77, 0x0b)]
+interface ISpellChecker(ISpellCheckerVtbl): IUnknown(IUnknownVtbl) {
+    fn get_LanguageTag(
+        value: *mut LPWSTR,
+    ) -> HRESULT,
+    fn Check(
+        text: LPCWSTR,
+        value: *mut *mut IEnumSpellingError,
+    ) -> HRESULT,
+    fn Suggest(
+        word: LPCWSTR,
+        value: *mut *mut IEnumString,
+    ) -> HRESULT,
+    fn Add(
+        word: LPCWSTR,
+    ) -> HRESULT,
+    fn Ignore(
+        word: LPCWSTR,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0x803e3bd4, 0x2828, 0x4410, 0x82, 0x90, 0x41, 0x8d, 0x1d, 0x73, 0xc7, 0x62)]
+interface IEnumSpellingError(IEnumSpellingErrorVtbl): IUnknown(IUnknownVtbl) {
+    fn Next(
+        value: *mut *mut ISpellingError,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0xb7c82d61, 0xfbe8, 0x4b47, 0x9b, 0x27, 0x6c, 0x0d, 0x2e, 0x0d, 0xe0, 0xa3)]
+interface ISpellingError(ISpellingErrorVtbl): IUnknown(IUnknownVtbl) {
+    fn get_StartIndex(
+        value: *mut ULONG,
+    ) -> HRESULT,
+    fn get_Length(
+        value: *mut ULONG,
+    ) -> HRESULT,
+    fn get_CorrectiveAction(
+        value: *mut u32,
+    ) -> HRESULT,
+    fn get_Replacement(
+        value: *mut LPWSTR,
+    ) -> HRESULT,
+}}
+
+
+unsafe fn create_factory() -> Result<*mut ISpellCheckerFactory, NwgError> {
+    use winapi::um::combaseapi::CoCreateInstance;
+    use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+
+    let mut factory: *mut ISpellCheckerFactory = ptr::null_mut();
+    let result = CoCreateInstance(
+        &CLSID_SPELL_CHECKER_FACTORY,
+        ptr::null_mut(),
+        CLSCTX_INPROC_SERVER,
+        &ISpellCheckerFactory::uuidof(),
+        (&mut factory as *mut *mut ISpellCheckerFactory) as *mut *mut c_void
+    );
+
+    match result {
+        S_OK => Ok(factory),
+        _ => Err(NwgError::resource_create("Failed to create the spell checker factory. Spell checking requires Windows 8 or later."))
+    }
+}
+
+/// Take ownership of a string allocated by COM
+unsafe fn take_com_string(text: LPWSTR) -> String {
+    use winapi::um::winbase::lstrlenW;
+
+    if text.is_null() {
+        return String::new();
+    }
+
+    let len = lstrlenW(text) as usize;
+    let out = from_utf16(::std::slice::from_raw_parts(text, len));
+    CoTaskMemFree(text as _);
+    out
+}
+
+/// Collect all the strings of a string enumerator and release it
+unsafe fn collect_strings(strings: *mut IEnumString) -> Vec<String> {
+    let mut out = Vec::new();
+    if strings.is_null() {
+        return out;
+    }
+
+    let strings = &*strings;
+    loop {
+        let mut value: LPWSTR = ptr::null_mut();
+        let mut fetched = 0;
+        if strings.Next(1, &mut value, &mut fetched) != S_OK || fetched == 0 {
+            break;
+        }
+
+        out.push(take_com_string(value));
+    }
+
+    strings.Release();
+
+    out
+}
+
+/// Return the language tag of the current user (ex: "en-US")
+pub fn user_language() -> String {
+    use winapi::um::winnls::GetUserDefaultLocaleName;
+    use winapi::um::winnt::LOCALE_NAME_MAX_LENGTH;
+
+    let mut buffer = [0u16; LOCALE_NAME_MAX_LENGTH];
+    let len = unsafe { GetUserDefaultLocaleName(buffer.as_mut_ptr(), LOCALE_NAME_MAX_LENGTH as _) };
+    match len > 0 {
+        true => from_utf16(&buffer[..(len as usize - 1)]),
+        false => "en-US".to_string()
+    }
+}
+
+/// Return the languages that have a spell checker on the system
+pub unsafe fn supported_languages() -> Vec<String> {
+    let factory = match create_factory() {
+        Ok(f) => &*f,
+        Err(_) => { return Vec::new(); }
+    };
+
+    let mut languages = ptr::null_mut();
+    factory.get_SupportedLanguages(&mut languages);
+    factory.Release();
+
+    collect_strings(languages)
+}
+
+/// Create a spell checker for the language `language`
+pub unsafe fn create_spell_checker(language: &str) -> Result<*mut ISpellChecker, NwgError> {
+    let factory = &*create_factory()?;
+    let language = to_utf16(language);
+
+    let mut supported = 0;
+    factory.IsSupported(language.as_ptr(), &mut supported);
+    if supported == 0 {
+        factory.Release();
+        return Err(NwgError::resource_create("The spell checker language is not supported on this system"));
+    }
+
+    let mut checker: *mut ISpellChecker = ptr::null_mut();
+    let result = factory.CreateSpellChecker(language.as_ptr(), &mut checker);
+    factory.Release();
+
+    match result {
+        S_OK => Ok(checker),
+        _ => Err(NwgError::resource_create("Failed to create the spell checker"))
+    }
+}
+
+/// Return the language of a spell checker
+pub unsafe fn language(checker: &ISpellChecker) -> String {
+    let mut tag = ptr::null_mut();
+    checker.get_LanguageTag(&mut tag);
+    take_com_string(tag)
+}
+
+/// Check the spelling of `text`. The ranges of the errors are in UTF-16 code units, like the edit controls indices.
+pub unsafe fn check(checker: &ISpellChecker, text: &str) -> Vec<SpellingError> {
+    let text = to_utf16(text);
+
+    let mut errors: *mut IEnumSpellingError = ptr::null_mut();
+    if checker.Check(text.as_ptr(), &mut errors) != S_OK || errors.is_null() {
+        return Vec::new();
+    }
+
+    let errors = &*errors;
+    let mut out = Vec::new();
+    loop {
+        let mut error: *mut ISpellingError = ptr::null_mut();
+        if errors.Next(&mut error) != S_OK || error.is_null() {
+            break;
+        }
+
+        let error = &*error;
+        let (mut start, mut length, mut action) = (0, 0, 0);
+        let mut replacement = ptr::null_mut();
+        error.get_StartIndex(&mut start);
+        error.get_Length(&mut length);
+        error.get_CorrectiveAction(&mut action);
+        error.get_Replacement(&mut replacement);
+        error.Release();
+
+        let start = start as usize;
+        let replacement = take_com_string(replacement);
+        let action = match action {
+            1 => SpellingAction::Suggest,
+            2 => SpellingAction::Replace(replacement),
+            3 => SpellingAction::Delete,
+            _ => SpellingAction::None,
+        };
+
+        out.push(SpellingError { range: start..(start+length as usize), action });
+    }
+
+    errors.Release();
+
+    out
+}
+
+/// Return the suggestions for a misspelled word
+pub unsafe fn suggest(checker: &ISpellChecker, word: &str) -> Vec<String> {
+    let word = to_utf16(word);
+    let mut suggestions = ptr::null_mut();
+    let result = checker.Suggest(word.as_ptr(), &mut suggestions);
+
+    // If the word is correctly spelled, the enumerator only contains the word itself
+    let suggestions = collect_strings(suggestions);
+    match result {
+        S_OK => suggestions,
+        _ => Vec::new()
+    }
+}
+
+/// Add a word to the user dictionary
+pub unsafe fn add(checker: &ISpellChecker, word: &str) {
+    let word = to_utf16(word);
+    checker.Add(word.as_ptr());
+}
+
+/// Ignore a word for the lifetime of the spell checker
+pub unsafe fn ignore(checker: &ISpellChecker, word: &str) {
+    let word = to_utf16(word);
+    checker.Ignore(word.as_ptr());
+}
@@ -0,0 +1,112 @@
+use winapi::um::winuser::GetSystemMetrics;
+use winapi::ctypes::c_int;
+use super::high_dpi::physical_to_logical;
+
+/**
+    Expose the dimensions of the elements drawn by the system (scroll bars, title bars, borders, icons).
+    The values are in logical pixels, like the size and the position of the controls.
+
+    This object cannot be instanced. The methods should be used this way:
+
+    ```rust
+    // Leave some room for the vertical scroll bar of a list
+    use native_windows_gui as nwg;
+
+    fn list_content_width(list_width: i32) -> i32 {
+        list_width - nwg::SystemMetrics::vertical_scrollbar_width()
+    }
+    ```
+*/
+pub struct SystemMetrics;
+
+impl SystemMetrics {
+
+    fn metric(index: c_int) -> i32 {
+        unsafe {
+            let value = GetSystemMetrics(index);
+            physical_to_logical(value, 0).0
+        }
+    }
+
+    fn metrics(x: c_int, y: c_int) -> [i32; 2] {
+        unsafe {
+            let (x, y) = physical_to_logical(GetSystemMetrics(x), GetSystemMetrics(y));
+            [x, y]
+        }
+    }
+
+    /// Returns the width of a vertical scroll bar
+    pub fn vertical_scrollbar_width() -> i32 {
+        use winapi::um::winuser::SM_CXVSCROLL;
+        Self::metric(SM_CXVSCROLL)
+    }
+
+    /// Returns the height of a horizontal scroll bar
+    pub fn horizontal_scrollbar_height() -> i32 {
+        use winapi::um::winuser::SM_CYHSCROLL;
+        Self::metric(SM_CYHSCROLL)
+    }
+
+    /// Returns the height of the title bar of a window
+    pub fn caption_height() -> i32 {
+        use winapi::um::winuser::SM_CYCAPTION;
+        Self::metric(SM_CYCAPTION)
+    }
+
+    /// Returns the height of the menu bar of a window
+    pub fn menu_height() -> i32 {
+        use winapi::um::winuser::SM_CYMENU;
+        Self::metric(SM_CYMENU)
+    }
+
+    /// Returns the `[width, height]` of a thin window border
+    pub fn border_size() -> [i32; 2] {
+        use winapi::um::winuser::{SM_CXBORDER, SM_CYBORDER};
+        Self::metrics(SM_CXBORDER, SM_CYBORDER)
+    }
+
+    /// Returns the `[width, height]` of a 3D border (ex: the border of a text input)
+    pub fn edge_size() -> [i32; 2] {
+        use winapi::um::winuser::{SM_CXEDGE, SM_CYEDGE};
+        Self::metrics(SM_CXEDGE, SM_CYEDGE)
+    }
+
+    /// Returns the `[width, height]` of the resizable border of a window, including the padding of the border
+    pub fn frame_size() -> [i32; 2] {
+        use winapi::um::winuser::{SM_CXSIZEFRAME, SM_CYSIZEFRAME, SM_CXPADDEDBORDER};
+        let [x, y] = Self::metrics(SM_CXSIZEFRAME, SM_CYSIZEFRAME);
+        let padding = Self::metric(SM_CXPADDEDBORDER);
+        [x + padding, y + padding]
+    }
+
+    /// Returns the `[width, height]` of the border of a fixed size window
+    pub fn fixed_frame_size() -> [i32; 2] {
+        use winapi::um::winuser::{SM_CXFIXEDFRAME, SM_CYFIXEDFRAME};
+        Self::metrics(SM_CXFIXEDFRAME, SM_CYFIXEDFRAME)
+    }
+
+    /// Returns the default `[width, height]` of an icon
+    pub fn icon_size() -> [i32; 2] {
+        use winapi::um::winuser::{SM_CXICON, SM_CYICON};
+        Self::metrics(SM_CXICON, SM_CYICON)
+    }
+
+    /// Returns the `[width, height]` of a small icon, as displayed in the title bars
+    pub fn small_icon_size() -> [i32; 2] {
+        use winapi::um::winuser::{SM_CXSMICON, SM_CYSMICON};
+        Self::metrics(SM_CXSMICON, SM_CYSMICON)
+    }
+
+    /// Returns the `[width, height]` of the area where the second click of a double click must happen
+    pub fn double_click_size() -> [i32; 2] {
+        use winapi::um::winuser::{SM_CXDOUBLECLK, SM_CYDOUBLECLK};
+        Self::metrics(SM_CXDOUBLECLK, SM_CYDOUBLECLK)
+    }
+
+    /// Returns the `[width, height]` that the mouse must move before a drag operation begins
+    pub fn drag_size() -> [i32; 2] {
+        use winapi::um::winuser::{SM_CXDRAG, SM_CYDRAG};
+        Self::metrics(SM_CXDRAG, SM_CYDRAG)
+    }
+
+}
@@ -0,0 +1,488 @@
+//! winapi-rs does not implements the WinRT toast notifications, so here's the low level stuff.
+//! The WinRT functions are loaded at runtime, this way applications using NWG can still start on Windows 7.
+#![allow(non_snake_case)]
+
+use winapi::winrt::hstring::HSTRING;
+use winapi::winrt::inspectable::{IInspectable, IInspectableVtbl};
+use winapi::um::unknwnbase::IUnknown;
+use winapi::shared::guiddef::{GUID, REFIID, IsEqualGUID};
+use winapi::shared::windef::HWND;
+use winapi::shared::minwindef::{ULONG, UINT, WPARAM, LPARAM};
+use winapi::shared::ntdef::{HRESULT, PCWSTR};
+use winapi::shared::winerror::{S_OK, E_NOINTERFACE};
+use winapi::ctypes::c_void;
+use winapi::{Interface, RIDL};
+use super::base_helper::{to_utf16, from_utf16};
+use super::window_helper::NWG_TOAST;
+use crate::NwgError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::collections::VecDeque;
+use std::{mem, ptr};
+
+/// Number of activation arguments kept for the event handlers
+const MAX_ACTIVATIONS: usize = 32;
+
+/// Number of toasts that can raise an activation event. When more toasts are shown, the oldest toast is forgotten.
+const MAX_SHOWN_TOASTS: usize = 32;
+
+
+/// The WinRT functions used by the toast notifications. Exported by combase.dll since Windows 8.
+struct WinRt {
+    get_activation_factory: unsafe extern "system" fn(HSTRING, REFIID, *mut *mut c_void) -> HRESULT,
+    activate_instance: unsafe extern "system" fn(HSTRING, *mut *mut IInspectable) -> HRESULT,
+    create_string: unsafe extern "system" fn(PCWSTR, UINT, *mut HSTRING) -> HRESULT,
+    delete_string: unsafe extern "system" fn(HSTRING) -> HRESULT,
+    get_string_raw_buffer: unsafe extern "system" fn(HSTRING, *mut UINT) -> PCWSTR,
+}
+
+lazy_static! {
+    static ref WINRT: Option<WinRt> = unsafe { load_winrt() };
+
+    /// The arguments of the last toast activations, with their sequence number and the toast control (window, id).
+    /// A window can have many event handlers, so the arguments are copied instead of being moved into the message.
+    static ref ACTIVATIONS: Mutex<VecDeque<(usize, usize, u32, String)>> = Mutex::new(VecDeque::new());
+
+    /// The toasts that can still be activated, oldest first. A toast is removed when it is activated.
+    static ref SHOWN_TOASTS: Mutex<VecDeque<ShownToast>> = Mutex::new(VecDeque::new());
+}
+
+static ACTIVATION_SEQ: AtomicUsize = AtomicUsize::new(1);
+
+static TOAST_SEQ: AtomicUsize = AtomicUsize::new(1);
+
+/// Return the arguments of the toast activation `seq` sent with a `NWG_TOAST` message
+pub fn activation_arguments(seq: usize) -> String {
+    let activations = ACTIVATIONS.lock().unwrap();
+    activations.iter()
+        .find(|(s, _, _, _)| *s == seq)
+        .map(|(_, _, _, arguments)| arguments.clone())
+        .unwrap_or_default()
+}
+
+/**
+    Forget the toasts shown by the toast control (`hwnd`, `id`) and their activations.
+    The toasts stay in the notification center, but activating them no longer sends a `NWG_TOAST` message.
+*/
+pub fn forget_toasts(hwnd: HWND, id: u32) {
+    let forgotten: VecDeque<ShownToast> = {
+        let mut shown = SHOWN_TOASTS.lock().unwrap();
+        let (forgotten, kept) = shown.drain(..).partition(|t| t.hwnd == hwnd as usize && t.id == id);
+        *shown = kept;
+        forgotten
+    };
+
+    // The toasts are released after the lock, because releasing a toast can wait for its event handlers
+    drop(forgotten);
+
+    ACTIVATIONS.lock().unwrap().retain(|&(_, h, i, _)| !(h == hwnd as usize && i == id));
+}
+
+/// Forget the toast `key` once it was activated
+fn forget_toast(key: usize) {
+    let forgotten = {
+        let mut shown = SHOWN_TOASTS.lock().unwrap();
+        shown.iter().position(|t| t.key == key).and_then(|i| shown.remove(i))
+    };
+
+    drop(forgotten);
+}
+
+unsafe fn load_winrt() -> Option<WinRt> {
+    use winapi::um::libloaderapi::{LoadLibraryW, GetProcAddress};
+
+    let lib = to_utf16("combase.dll");
+    let module = LoadLibraryW(lib.as_ptr());
+    if module.is_null() {
+        return None;
+    }
+
+    let get_activation_factory = GetProcAddress(module, "RoGetActivationFactory\0".as_ptr() as _);
+    let activate_instance = GetProcAddress(module, "RoActivateInstance\0".as_ptr() as _);
+    let create_string = GetProcAddress(module, "WindowsCreateString\0".as_ptr() as _);
+    let delete_string = GetProcAddress(module, "WindowsDeleteString\0".as_ptr() as _);
+    let get_string_raw_buffer = GetProcAddress(module, "WindowsGetStringRawBuffer\0".as_ptr() as _);
+
+    let functions = [get_activation_factory, activate_instance, create_string, delete_string, get_string_raw_buffer];
+    if functions.iter().any(|f| f.is_null()) {
+        return None;
+    }
+
+    Some(WinRt {
+        get_activation_factory: mem::transmute(get_activation_factory),
+        activate_instance: mem::transmute(activate_instance),
+        create_string: mem::transmute(create_string),
+        delete_string: mem::transmute(delete_string),
+        get_string_raw_buffer: mem::transmute(get_string_raw_buffer),
+    })
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct EventRegistrationToken {
+    value: i64
+}
+
+const IID_IXML_DOCUMENT: GUID = GUID {
+    Data1: 0xf7f3a506, Data2: 0x1e87, Data3: 0x42d6,
+    Data4: [0xbc, 0xfb, 0xb8, 0xc8, 0x09, 0xfa, 0x54, 0x94]
+};
+
+/// TypedEventHandler<ToastNotification, IInspectable>
+const IID_ACTIVATED_HANDLER: GUID = GUID {
+    Data1: 0xab54de2d, Data2: 0x97d9, Data3: 0x5528,
+    Data4: [0xb6, 0xad, 0x10, 0x5a, 0xfe, 0x15, 0x65, 0x30]
+};
+
+const IID_IAGILE_OBJECT: GUID = GUID {
+    Data1: 0x94ea2b94, Data2: 0xe9cc, Data3: 0x49e0,
+    Data4: [0xc0, 0xff, 0xee, 0x64, 0xca, 0x8f, 0x5b, 0x90]
+};
+
+RIDL!{#[uuid(0x50ac103f, 0xd235, 0x4598, 0xbb, 0xef, 0x98, 0xfe, 0x4d, 0x1a, 0x3a, 0xd4)]
+interface IToastNotificationManagerStatics(IToastNotificationManagerStaticsVtbl): IInspectable(IInspectableVtbl) {
+    fn CreateToastNotifier(
+        result: *mut *mut IToastNotifier,
+    ) -> HRESULT,
+    fn CreateToastNotifierWithId(
+        applicationId: HSTRING,
+        result: *mut *mut IToastNotifier,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0x75927b93, 0x03f3, 0x41ec, 0x91, 0xd3, 0x6e, 0x5b, 0xac, 0x1b, 0x38, 0xe7)]
+interface IToastNotifier(IToastNotifierVtbl): IInspectable(IInspectableVtbl) {
+    fn Show(
+        notification: *mut IToastNotification,
+    ) -> HRESULT,
+    fn Hide(
+        notification: *mut IToastNotification,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0x04124b20, 0x82c6, 0x4229, 0xb1, 0x09, 0xfd, 0x9e, 0xd4, 0x66, 0x2b, 0x53)]
+interface IToastNotificationFactory(IToastNotificationFactoryVtbl): IInspectable(IInspectableVtbl) {
+    fn CreateToastNotification(
+        content: *mut IInspectable,
+        value: *mut *mut IToastNotification,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0x997e2675, 0x059e, 0x4e60, 0x8b, 0x06, 0x17, 0x60, 0x91, 0x7c, 0x8b, 0x80)]
+interface IToastNotification(IToastNotificationVtbl): IInspectable(IInspectableVtbl) {
+    fn get_Content(
+        value: *mut *mut IInspectable,
+    ) -> HRESULT,
+    fn put_ExpirationTime(
+        value: *mut IInspectable,
+    ) -> HRESULT,
+    fn get_ExpirationTime(
+        value: *mut *mut IInspectable,
+    ) -> HRESULT,
+    fn add_Dismissed(
+        handler: *mut IUnknown,
+        token: *mut EventRegistrationToken,
+    ) -> HRESULT,
+    fn remove_Dismissed(
+        token: EventRegistrationToken,
+    ) -> HRESULT,
+    fn add_Activated(
+        handler: *mut IUnknown,
+        token: *mut EventRegistrationToken,
+    ) -> HRESULT,
+    fn remove_Activated(
+        token: EventRegistrationToken,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0xe3bf92f3, 0xc197, 0x436f, 0x82, 0x65, 0x06, 0x25, 0x82, 0x4f, 0x8d, 0xac)]
+interface IToastActivatedEventArgs(IToastActivatedEventArgsVtbl): IInspectable(IInspectableVtbl) {
+    fn get_Arguments(
+        value: *mut HSTRING,
+    ) -> HRESULT,
+}}
+
+RIDL!{#[uuid(0x6cd0e74e, 0xee65, 0x4489, 0x9e, 0xbf, 0xca, 0x43, 0xe8, 0x7b, 0xa6, 0x37)]
+interface IXmlDocumentIO(IXmlDocumentIOVtbl): IInspectable(IInspectableVtbl) {
+    fn LoadXml(
+        xml: HSTRING,
+    ) -> HRESULT,
+}}
+
+
+/// An owned HSTRING
+struct HString(HSTRING);
+
+impl HString {
+
+    unsafe fn new(rt: &WinRt, text: &str) -> HString {
+        let text = to_utf16(text);
+        let mut value = ptr::null_mut();
+        (rt.create_string)(text.as_ptr(), (text.len() - 1) as UINT, &mut value);
+        HString(value)
+    }
+
+    unsafe fn to_string(&self, rt: &WinRt) -> String {
+        if self.0.is_null() {
+            return String::new();
+        }
+
+        let mut len = 0;
+        let buffer = (rt.get_string_raw_buffer)(self.0, &mut len);
+        from_utf16(::std::slice::from_raw_parts(buffer, len as usize))
+    }
+
+}
+
+impl Drop for HString {
+    fn drop(&mut self) {
+        if let Some(rt) = WINRT.as_ref() {
+            unsafe { (rt.delete_string)(self.0); }
+        }
+    }
+}
+
+/// A COM pointer released when dropped
+struct Com<T: Interface>(*mut T);
+
+impl<T: Interface> Com<T> {
+
+    fn null() -> Com<T> {
+        Com(ptr::null_mut())
+    }
+
+    fn out(&mut self) -> *mut *mut T {
+        &mut self.0
+    }
+
+    /// Query another interface of the object
+    unsafe fn cast<U: Interface>(&self) -> Option<Com<U>> {
+        let mut out: Com<U> = Com::null();
+        let unknown = &*(self.0 as *mut IUnknown);
+        match unknown.QueryInterface(&U::uuidof(), out.out() as *mut *mut c_void) {
+            S_OK => Some(out),
+            _ => None
+        }
+    }
+
+}
+
+impl<T: Interface> Drop for Com<T> {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { (&*(self.0 as *mut IUnknown)).Release(); }
+        }
+    }
+}
+
+
+/// The handler of the `Activated` event of a toast. The event is received in a thread pool,
+/// so the arguments are sent to the parent window of the control with the `NWG_TOAST` message.
+#[repr(C)]
+struct ActivatedHandler {
+    vtbl: *const ActivatedHandlerVtbl,
+    refs: AtomicUsize,
+    hwnd: usize,
+    id: u32,
+    toast: usize,
+}
+
+#[repr(C)]
+struct ActivatedHandlerVtbl {
+    QueryInterface: unsafe extern "system" fn(*mut ActivatedHandler, REFIID, *mut *mut c_void) -> HRESULT,
+    AddRef: unsafe extern "system" fn(*mut ActivatedHandler) -> ULONG,
+    Release: unsafe extern "system" fn(*mut ActivatedHandler) -> ULONG,
+    Invoke: unsafe extern "system" fn(*mut ActivatedHandler, *mut IInspectable, *mut IInspectable) -> HRESULT,
+}
+
+static ACTIVATED_HANDLER_VTBL: ActivatedHandlerVtbl = ActivatedHandlerVtbl {
+    QueryInterface: handler_query_interface,
+    AddRef: handler_add_ref,
+    Release: handler_release,
+    Invoke: handler_invoke,
+};
+
+unsafe extern "system" fn handler_query_interface(this: *mut ActivatedHandler, riid: REFIID, out: *mut *mut c_void) -> HRESULT {
+    let riid = &*riid;
+    let supported = [IUnknown::uuidof(), IID_IAGILE_OBJECT, IID_ACTIVATED_HANDLER];
+    if supported.iter().any(|iid| IsEqualGUID(iid, riid)) {
+        handler_add_ref(this);
+        *out = this as *mut c_void;
+        S_OK
+    } else {
+        *out = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+unsafe extern "system" fn handler_add_ref(this: *mut ActivatedHandler) -> ULONG {
+    ((*this).refs.fetch_add(1, Ordering::SeqCst) + 1) as ULONG
+}
+
+unsafe extern "system" fn handler_release(this: *mut ActivatedHandler) -> ULONG {
+    let refs = (*this).refs.fetch_sub(1, Ordering::SeqCst) - 1;
+    if refs == 0 {
+        drop(Box::from_raw(this));
+    }
+
+    refs as ULONG
+}
+
+unsafe extern "system" fn handler_invoke(this: *mut ActivatedHandler, _sender: *mut IInspectable, args: *mut IInspectable) -> HRESULT {
+    use winapi::um::winuser::PostMessageW;
+
+    let rt = match WINRT.as_ref() {
+        Some(rt) => rt,
+        None => { return S_OK; }
+    };
+
+    let mut arguments = String::new();
+    if !args.is_null() {
+        let mut activated: Com<IToastActivatedEventArgs> = Com::null();
+        if (&*args).QueryInterface(&IToastActivatedEventArgs::uuidof(), activated.out() as *mut *mut c_void) == S_OK {
+            let mut value = HString(ptr::null_mut());
+            (&*activated.0).get_Arguments(&mut value.0);
+            arguments = value.to_string(rt);
+        }
+    }
+
+    let handler = &*this;
+    let seq = ACTIVATION_SEQ.fetch_add(1, Ordering::SeqCst);
+    if let Ok(mut activations) = ACTIVATIONS.lock() {
+        activations.push_back((seq, handler.hwnd, handler.id, arguments));
+        if activations.len() > MAX_ACTIVATIONS {
+            activations.pop_front();
+        }
+    }
+
+    PostMessageW(handler.hwnd as HWND, NWG_TOAST, handler.id as WPARAM, seq as LPARAM);
+
+    // A toast is closed when it is activated
+    forget_toast(handler.toast);
+
+    S_OK
+}
+
+
+/// A toast that was shown. The toast must be kept alive to receive its `Activated` event.
+struct ShownToast {
+    key: usize,
+    hwnd: usize,
+    id: u32,
+    toast: Com<IToastNotification>,
+    token: EventRegistrationToken,
+}
+
+// The toast notifications are agile objects, so they can be released from the thread pool that receives their events
+unsafe impl Send for ShownToast {}
+
+impl Drop for ShownToast {
+    fn drop(&mut self) {
+        unsafe { (&*self.toast.0).remove_Activated(self.token); }
+    }
+}
+
+/// Return `true` if the system supports toast notifications (Windows 8 or later)
+pub fn toasts_supported() -> bool {
+    WINRT.is_some()
+}
+
+/**
+    Show a toast described by the xml document `xml` using the application ID `app_id`.
+    When the toast is activated, a `NWG_TOAST` message is posted to `hwnd` with `id` as WPARAM
+    and the sequence number of the activation arguments as LPARAM (see `activation_arguments`).
+
+    Only the last 32 toasts can be activated. See `forget_toasts`.
+*/
+pub unsafe fn show_toast(app_id: &str, xml: &str, hwnd: HWND, id: u32) -> Result<(), NwgError> {
+    use winapi::shared::winerror::SUCCEEDED;
+
+    let rt = match WINRT.as_ref() {
+        Some(rt) => rt,
+        None => { return Err(NwgError::resource_create("Toast notifications require Windows 8 or later")); }
+    };
+
+    // Toast notifier
+    let class = HString::new(rt, "Windows.UI.Notifications.ToastNotificationManager");
+    let mut manager: Com<IToastNotificationManagerStatics> = Com::null();
+    let result = (rt.get_activation_factory)(class.0, &IToastNotificationManagerStatics::uuidof(), manager.out() as *mut *mut c_void);
+    if !SUCCEEDED(result) {
+        return Err(NwgError::resource_create("Toast notifications are not supported on this system"));
+    }
+
+    let app_id = HString::new(rt, app_id);
+    let mut notifier: Com<IToastNotifier> = Com::null();
+    if !SUCCEEDED((&*manager.0).CreateToastNotifierWithId(app_id.0, notifier.out())) {
+        return Err(NwgError::resource_create("Failed to create the toast notifier. Is the application ID registered?"));
+    }
+
+    // Toast content
+    let class = HString::new(rt, "Windows.Data.Xml.Dom.XmlDocument");
+    let mut document: Com<IInspectable> = Com::null();
+    if !SUCCEEDED((rt.activate_instance)(class.0, document.out())) {
+        return Err(NwgError::resource_create("Failed to create the toast xml document"));
+    }
+
+    let document_io: Com<IXmlDocumentIO> = document.cast()
+        .ok_or_else(|| NwgError::resource_create("Failed to create the toast xml document"))?;
+
+    let xml = HString::new(rt, xml);
+    if !SUCCEEDED((&*document_io.0).LoadXml(xml.0)) {
+        return Err(NwgError::resource_create("The toast xml document is not valid"));
+    }
+
+    let mut content: Com<IInspectable> = Com::null();
+    let unknown = &*(document.0 as *mut IUnknown);
+    if unknown.QueryInterface(&IID_IXML_DOCUMENT, content.out() as *mut *mut c_void) != S_OK {
+        return Err(NwgError::resource_create("Failed to create the toast xml document"));
+    }
+
+    // Toast
+    let class = HString::new(rt, "Windows.UI.Notifications.ToastNotification");
+    let mut factory: Com<IToastNotificationFactory> = Com::null();
+    if !SUCCEEDED((rt.get_activation_factory)(class.0, &IToastNotificationFactory::uuidof(), factory.out() as *mut *mut c_void)) {
+        return Err(NwgError::resource_create("Toast notifications are not supported on this system"));
+    }
+
+    let mut toast: Com<IToastNotification> = Com::null();
+    if !SUCCEEDED((&*factory.0).CreateToastNotification(content.0, toast.out())) {
+        return Err(NwgError::resource_create("Failed to create the toast notification"));
+    }
+
+    // Events
+    let key = TOAST_SEQ.fetch_add(1, Ordering::SeqCst);
+    let handler = Box::into_raw(Box::new(ActivatedHandler {
+        vtbl: &ACTIVATED_HANDLER_VTBL,
+        refs: AtomicUsize::new(1),
+        hwnd: hwnd as usize,
+        id,
+        toast: key,
+    }));
+
+    let mut token = EventRegistrationToken { value: 0 };
+    let result = (&*toast.0).add_Activated(handler as *mut IUnknown, &mut token);
+    handler_release(handler);
+
+    if !SUCCEEDED(result) {
+        return Err(NwgError::resource_create("Failed to register the toast activation handler"));
+    }
+
+    let shown = ShownToast { key, hwnd: hwnd as usize, id, toast, token };
+    if !SUCCEEDED((&*notifier.0).Show(shown.toast.0)) {
+        return Err(NwgError::resource_create("Failed to show the toast notification"));
+    }
+
+    let oldest = {
+        let mut toasts = SHOWN_TOASTS.lock().unwrap();
+        toasts.push_back(shown);
+        match toasts.len() > MAX_SHOWN_TOASTS {
+            true => toasts.pop_front(),
+            false => None
+        }
+    };
+
+    drop(oldest);
+
+    Ok(())
+}
@@ -8,13 +8,19 @@ use winapi::shared::windef::{HWND, HMENU, HBRUSH};
 use winapi::shared::basetsd::{DWORD_PTR, UINT_PTR};
 use winapi::um::winuser::{WNDPROC, NMHDR, IDCANCEL, IDOK};
 use winapi::um::commctrl::{NMTTDISPINFOW, SUBCLASSPROC};
-use super::base_helper::{CUSTOM_ID_BEGIN, to_utf16};
+use super::base_helper::to_utf16;
 use super::window_helper::{NOTICE_MESSAGE, NWG_INIT, NWG_TRAY, NWG_TIMER_TICK, NWG_TIMER_STOP};
+#[cfg(feature = "toast-notification")] use super::window_helper::NWG_TOAST;
+#[cfg(feature = "plot-canvas")] use super::window_helper::NWG_PLOT_HOVER;
+#[cfg(feature = "media-player")] use super::window_helper::NWG_MEDIA_END;
+#[cfg(feature = "tabs")] use super::window_helper::NWG_TAB_CLOSE;
+#[cfg(feature = "number-select")] use super::window_helper::NWG_NUMBER_CHANGED;
 use super::high_dpi;
 use crate::controls::ControlHandle;
 use crate::{Event, EventData, NwgError};
-use std::{ptr, mem};
+use std::{ptr, mem, panic};
 use std::rc::Rc;
+use std::cell::{Cell, RefCell};
 use std::ffi::OsString;
 use std::os::windows::prelude::OsStringExt;
 use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
@@ -22,12 +28,34 @@ use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 
 static TIMER_ID: AtomicU32 = AtomicU32::new(1); 
 static NOTICE_ID: AtomicU32 = AtomicU32::new(1); 
+static TRAY_ID: AtomicU32 = AtomicU32::new(1); 
+#[cfg(feature = "toast-notification")]
+static TOAST_ID: AtomicU32 = AtomicU32::new(1); 
+static OWNER_DRAW_ID: AtomicU32 = AtomicU32::new(1); 
 static EVENT_HANDLER_ID: AtomicUsize = AtomicUsize::new(1);
 
 const NO_DATA: EventData = EventData::NoData;
 
 type RawCallback = dyn Fn(HWND, UINT, WPARAM, LPARAM) -> Option<LRESULT>;
 type Callback = dyn Fn(Event, EventData, ControlHandle) -> ();
+type PanicCallback = dyn Fn(&str) -> ();
+
+thread_local! {
+    /// Called when an event handler of the thread panics. See `set_event_panic_handler`.
+    static PANIC_HANDLER: RefCell<Option<Rc<PanicCallback>>> = RefCell::new(None);
+
+    /// The innermost message dispatch running on the thread. See `DispatchGuard`.
+    static RUNNING_DISPATCH: Cell<*const DispatchGuard> = Cell::new(ptr::null());
+
+    /// The callback references released while an event callback was running. See `release_callback`.
+    static RELEASED_CALLBACKS: RefCell<Vec<Rc<Callback>>> = RefCell::new(Vec::new());
+
+    /// The owner drawn controls built by NWG. See `register_owner_draw`.
+    static OWNER_DRAWN: RefCell<Vec<HWND>> = RefCell::new(Vec::new());
+}
+
+/// Raw event handler id used to forget a destroyed owner drawn control
+const OWNER_DRAW_HANDLER_ID: UINT_PTR = 0xFF04;
 
 /**
     An opaque structure that represent a window subclass hook. 
@@ -35,7 +63,8 @@ type Callback = dyn Fn(Event, EventData, ControlHandle) -> ();
 pub struct EventHandler {
     handles: Vec<HWND>,
     id: SUBCLASSPROC,
-    subclass_id: UINT_PTR
+    subclass_id: UINT_PTR,
+    full: bool,
 }
 
 /**
@@ -58,6 +87,49 @@ pub fn build_notice(parent: HWND) -> ControlHandle {
     ControlHandle::Notice(parent, id)
 }
 
+/// Tray notifications are identified by their parent window and an ID, so a window can have many tray icons.
+pub fn build_tray(parent: HWND) -> ControlHandle {
+    let id = TRAY_ID.fetch_add(1, Ordering::SeqCst);
+    ControlHandle::SystemTray(parent, id)
+}
+
+#[cfg(feature = "toast-notification")]
+pub fn build_toast(parent: HWND) -> ControlHandle {
+    let id = TOAST_ID.fetch_add(1, Ordering::SeqCst);
+    ControlHandle::Toast(parent, id)
+}
+
+/**
+    Register an owner drawn control built by NWG. `OnDrawItem` and `OnMeasureItem` are only raised for these controls,
+    the owner drawn items of the other controls are left to the default window procedure.
+
+    `WM_MEASUREITEM` only identifies the control by its id, so a control without an id receives one that is unique in its parent.
+    A control that already has an id keeps it.
+*/
+pub(crate) fn register_owner_draw(hwnd: HWND) {
+    use winapi::um::winuser::{GWLP_ID, WM_NCDESTROY};
+    use super::window_helper::{get_window_long, set_window_long};
+
+    if get_window_long(hwnd, GWLP_ID) == 0 {
+        let id = OWNER_DRAW_ID.fetch_add(1, Ordering::SeqCst);
+        set_window_long(hwnd, GWLP_ID, id as usize);
+    }
+
+    OWNER_DRAWN.with(|drawn| drawn.borrow_mut().push(hwnd));
+
+    bind_raw_event_handler_inner(&ControlHandle::Hwnd(hwnd), OWNER_DRAW_HANDLER_ID, |hwnd, msg, _w, _l| {
+        if msg == WM_NCDESTROY {
+            OWNER_DRAWN.with(|drawn| drawn.borrow_mut().retain(|&h| h != hwnd));
+        }
+        None
+    }).ok();
+}
+
+/// Returns true if `hwnd` was registered with `register_owner_draw`
+fn owner_drawn(hwnd: HWND) -> bool {
+    OWNER_DRAWN.with(|drawn| drawn.borrow().contains(&hwnd))
+}
+
 pub unsafe fn build_timer(parent: HWND, interval: u32, stopped: bool) -> ControlHandle {
     use winapi::um::winuser::SetTimer;
     
@@ -92,6 +164,10 @@ pub fn full_bind_event_handler<F>(handle: &ControlHandle, f: F) -> EventHandler
         Function that iters over a top level window and bind the events dispatch callback
     */
     unsafe extern "system" fn set_children_subclass(h: HWND, p: LPARAM) -> i32 {
+        if !dispatch_allowed(h) {
+            return 1;
+        }
+
         let params_ptr = p as *mut SetSubclassParam;
         let params = &*params_ptr;
         
@@ -108,16 +184,6 @@ pub fn full_bind_event_handler<F>(handle: &ControlHandle, f: F) -> EventHandler
         1
     }
 
-    /**
-        Push the children window handle into the EventHandler
-    */
-    unsafe extern "system" fn handler_children(h: HWND, p: LPARAM) -> i32 {
-        let handles_ptr: *mut Vec<HWND> = p as *mut Vec<HWND>;
-        let handles = &mut *handles_ptr;
-        handles.push(h);
-        1
-    }
-
     let hwnd = handle.hwnd().expect("Cannot bind control with an handle of type");
 
     // The callback function must be passed to each children of the control
@@ -128,18 +194,17 @@ pub fn full_bind_event_handler<F>(handle: &ControlHandle, f: F) -> EventHandler
     
     let callback_fn: SUBCLASSPROC = Some(process_events);
     let subclass_id = EVENT_HANDLER_ID.fetch_add(1, Ordering::SeqCst);
-    let mut handler = EventHandler {
+    let handler = EventHandler {
         handles: vec![hwnd],
         id: callback_fn,
         subclass_id,
+        full: true,
     };
 
-
     let params = Box::new(SetSubclassParam { callback_ptr, subclass_id });
     let params_ptr: *mut SetSubclassParam = Box::into_raw(params);
 
     unsafe {
-        EnumChildWindows(hwnd, Some(handler_children), (&mut handler.handles as *mut Vec<HWND>) as LPARAM);
         EnumChildWindows(hwnd, Some(set_children_subclass), params_ptr as LPARAM);
         SetWindowSubclass(hwnd, callback_fn, subclass_id, callback_ptr as UINT_PTR);
         Box::from_raw(params_ptr);
@@ -182,6 +247,7 @@ pub fn bind_event_handler<F>(handle: &ControlHandle, parent_handle: &ControlHand
         handles: vec![hwnd, parent_hwnd],
         id: callback_fn,
         subclass_id,
+        full: false,
     };
 
     unsafe {
@@ -193,8 +259,147 @@ pub fn bind_event_handler<F>(handle: &ControlHandle, parent_handle: &ControlHand
 }
 
 
+lazy_static! {
+    /// Window handles replaced by `recreate_control`. Event handlers still reference the old handles.
+    /// The entries are removed when the new window is destroyed.
+    static ref REPLACED_HANDLES: std::sync::Mutex<std::collections::HashMap<usize, usize>> = Default::default();
+
+    /// The ids of the bound raw event handlers, and the number of windows that use each id
+    static ref RAW_HANDLER_IDS: std::sync::Mutex<std::collections::HashMap<UINT_PTR, usize>> = Default::default();
+}
+
+/**
+    Return the current handle of the window that has the subclass `proc`/`id`. The window might have been recreated by `recreate_control`.
+    Windows reuses the handles of the destroyed windows, so the old handle is only replaced if it does not have the subclass.
+*/
+unsafe fn current_handle(mut handle: HWND, proc: SUBCLASSPROC, id: UINT_PTR) -> HWND {
+    let replaced = REPLACED_HANDLES.lock().unwrap();
+    let mut data = 0;
+
+    for _ in 0..replaced.len() {
+        if GetWindowSubclass(handle, proc, id, &mut data) != 0 {
+            break;
+        }
+
+        match replaced.get(&(handle as usize)) {
+            Some(&new_handle) => { handle = new_handle as HWND; },
+            None => break
+        }
+    }
+
+    handle
+}
+
+/// Count the windows that use the raw event handler id `id`
+fn track_raw_handler_id(id: UINT_PTR, bound: bool) {
+    let mut ids = RAW_HANDLER_IDS.lock().unwrap();
+    let count = ids.entry(id).or_insert(0);
+    match bound {
+        true => { *count += 1; },
+        false => { *count = count.saturating_sub(1); }
+    }
+
+    if ids[&id] == 0 {
+        ids.remove(&id);
+    }
+}
+
+/// Remove the handles replaced by a window created by `recreate_control` when the window is destroyed
+#[cfg(feature = "textbox")]
+unsafe extern "system" fn clear_replaced_handles(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM, id: UINT_PTR, _data: DWORD_PTR) -> LRESULT {
+    use winapi::um::commctrl::DefSubclassProc;
+    use winapi::um::winuser::WM_NCDESTROY;
+
+    if msg == WM_NCDESTROY {
+        REPLACED_HANDLES.lock().unwrap().retain(|_, new_handle| *new_handle != hwnd as usize);
+        RemoveWindowSubclass(hwnd, Some(clear_replaced_handles), id);
+    }
+
+    DefSubclassProc(hwnd, msg, w, l)
+}
+
+/**
+    Create a copy of a control using a new window style. Some styles (ex: word wrap in edit controls) cannot be changed after creation.
+
+    The new control has the same class, parent, id, geometry, extended style, font and tab order as the old one.
+    The NWG event handlers (including the raw event handlers reserved by NWG) are moved to the new control.
+    The content of the control is not copied and the old control is not destroyed.
+*/
+#[cfg(feature = "textbox")]
+pub(crate) unsafe fn recreate_control(hwnd: HWND, style: u32) -> HWND {
+    use winapi::um::winuser::{CreateWindowExW, GetClassNameW, GetParent, GetDlgCtrlID, GetWindowRect, MapWindowPoints, SetWindowPos,
+      GetFocus, SetFocus, GWL_EXSTYLE, GWLP_HINSTANCE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOACTIVATE};
+    use winapi::shared::windef::{RECT, POINT};
+    use super::window_helper as wh;
+
+    let mut class_name = [0u16; 256];
+    GetClassNameW(hwnd, class_name.as_mut_ptr(), 256);
+
+    let parent = GetParent(hwnd);
+    let mut rect: RECT = mem::zeroed();
+    GetWindowRect(hwnd, &mut rect);
+    MapWindowPoints(ptr::null_mut(), parent, &mut rect as *mut RECT as *mut POINT, 2);
+
+    let new_hwnd = CreateWindowExW(
+        wh::get_window_long(hwnd, GWL_EXSTYLE) as DWORD,
+        class_name.as_ptr(),
+        ptr::null(),
+        style,
+        rect.left, rect.top,
+        rect.right - rect.left, rect.bottom - rect.top,
+        parent,
+        GetDlgCtrlID(hwnd) as usize as HMENU,
+        wh::get_window_long(hwnd, GWLP_HINSTANCE) as HMODULE,
+        ptr::null_mut()
+    );
+
+    // Keep the tab order
+    SetWindowPos(new_hwnd, hwnd, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE);
+    wh::set_window_font(new_hwnd, Some(wh::get_window_font(hwnd)), false);
+
+    // Move the event handlers
+    let events_proc: SUBCLASSPROC = Some(process_events);
+    let raw_proc: SUBCLASSPROC = Some(process_raw_events);
+    let max_id = EVENT_HANDLER_ID.load(Ordering::SeqCst);
+    let mut data = 0;
+
+    for id in 1..max_id {
+        if GetWindowSubclass(hwnd, events_proc, id, &mut data) != 0 {
+            RemoveWindowSubclass(hwnd, events_proc, id);
+            SetWindowSubclass(new_hwnd, events_proc, id, data);
+        }
+    }
+
+    let raw_ids: Vec<UINT_PTR> = RAW_HANDLER_IDS.lock().unwrap().keys().cloned().collect();
+    for id in raw_ids {
+        if GetWindowSubclass(hwnd, raw_proc, id, &mut data) != 0 {
+            RemoveWindowSubclass(hwnd, raw_proc, id);
+            SetWindowSubclass(new_hwnd, raw_proc, id, data);
+        }
+    }
+
+    // The handles that were replaced by the old window are now replaced by the new window
+    {
+        let mut replaced = REPLACED_HANDLES.lock().unwrap();
+        for new_handle in replaced.values_mut().filter(|h| **h == hwnd as usize) {
+            *new_handle = new_hwnd as usize;
+        }
+
+        replaced.insert(hwnd as usize, new_hwnd as usize);
+    }
+
+    SetWindowSubclass(new_hwnd, Some(clear_replaced_handles), 0, 0);
+
+    if GetFocus() == hwnd {
+        SetFocus(new_hwnd);
+    }
+
+    new_hwnd
+}
+
 /**
     Free all associated callbacks with the event handler.
+    The callbacks bound to a window are also freed when the window is destroyed.
 
     This function will panic if the handler was already freed.
 */
@@ -204,7 +409,13 @@ pub fn unbind_event_handler(handler: &EventHandler)
     let subclass_id = handler.subclass_id;
     let mut callback_ptr: *mut *const Callback = ptr::null_mut();
 
-    for &handle in handler.handles.iter() {
+    // The children of a full bind can be destroyed after the binding, so they are searched again
+    let handles = match handler.full {
+        true => unsafe { bound_windows(current_handle(handler.handles[0], id, subclass_id), id, subclass_id) },
+        false => handler.handles.iter().map(|&h| unsafe { current_handle(h, id, subclass_id) }).collect()
+    };
+
+    for handle in handles {
         unsafe { 
             let mut callback_value: UINT_PTR = 0;
             let result = GetWindowSubclass(handle, id, subclass_id, &mut callback_value);
@@ -213,8 +424,7 @@ pub fn unbind_event_handler(handler: &EventHandler)
             }
 
             callback_ptr = callback_value as *mut *const Callback;
-            let callback: Rc<Callback> = Rc::from_raw(*callback_ptr);
-            mem::drop(callback);
+            release_callback(Rc::from_raw(*callback_ptr));
 
             RemoveWindowSubclass(handle, id, subclass_id);
         };
@@ -226,6 +436,43 @@ pub fn unbind_event_handler(handler: &EventHandler)
     }
 }
 
+/// Return `root` and its children hooked with the subclass `subclass_id`
+unsafe fn bound_windows(root: HWND, id: SUBCLASSPROC, subclass_id: UINT_PTR) -> Vec<HWND> {
+    use winapi::um::winuser::EnumChildWindows;
+
+    struct Params {
+        handles: Vec<HWND>,
+        id: SUBCLASSPROC,
+        subclass_id: UINT_PTR,
+    }
+
+    unsafe extern "system" fn bound_child(h: HWND, p: LPARAM) -> i32 {
+        let params = &mut *(p as *mut Params);
+        let mut data = 0;
+        if GetWindowSubclass(h, params.id, params.subclass_id, &mut data) != 0 {
+            params.handles.push(h);
+        }
+        1
+    }
+
+    let mut params = Params { handles: vec![root], id, subclass_id };
+    EnumChildWindows(root, Some(bound_child), &mut params as *mut Params as LPARAM);
+
+    params.handles
+}
+
+/// Returns false for the children that must not be subclassed by `full_bind_event_handler`.
+/// The messages of a MCIWnd window use the same values as the NWG custom messages (`WM_USER+100` and up).
+#[cfg(feature = "media-player")]
+unsafe fn dispatch_allowed(h: HWND) -> bool {
+    super::window_helper::get_window_class_name(h) != super::media_player::MCIWND_CLASS
+}
+
+#[cfg(not(feature = "media-player"))]
+unsafe fn dispatch_allowed(_h: HWND) -> bool {
+    true
+}
+
 pub(crate) fn bind_raw_event_handler_inner<F>(handle: &ControlHandle, handler_id: UINT_PTR, f: F) -> Result<RawEventHandler, NwgError>
     where F: Fn(HWND, UINT, WPARAM, LPARAM) -> Option<LRESULT> + 'static
 {
@@ -242,10 +489,16 @@ pub(crate) fn bind_raw_event_handler_inner<F>(handle: &ControlHandle, handler_id
             }
 
             // Bind the callback
-            let boxed_proc: Box<RawCallback> = Box::new(f);
-            let boxed_proc_wrapper: Box<*mut RawCallback> = Box::new(Box::into_raw(boxed_proc));
-            let proc_data: *mut *mut RawCallback = Box::into_raw(boxed_proc_wrapper);
-            SetWindowSubclass(h, subclass_proc, handler_id, proc_data as UINT_PTR);
+            let boxed_proc: Rc<RawCallback> = Rc::new(f);
+            let boxed_proc_wrapper: Box<*const RawCallback> = Box::new(Rc::into_raw(boxed_proc));
+            let proc_data: *mut *const RawCallback = Box::into_raw(boxed_proc_wrapper);
+            if SetWindowSubclass(h, subclass_proc, handler_id, proc_data as UINT_PTR) == 0 {
+                RemoveWindowSubclass(h, subclass_proc, handler_id);
+                free_raw_callback(proc_data as UINT_PTR);
+                return Err(NwgError::events_binding(format!("Failed to bind the events id {} to the control", handler_id)));
+            }
+
+            track_raw_handler_id(handler_id, true);
 
             h
         },
@@ -269,6 +522,7 @@ or otherwise, the old callback will be replaced by the new one. See `Label::hook
 
 Error:
 - If the event handler with the same ID is already bound, this function will return an Error. The `has_raw_handler` method can be used to check this.
+- If the subclass could not be set (ex: the window was destroyed). The callback is freed.
 
 Panic:
 - If the `handle` parameter is not a window-like control
@@ -316,13 +570,14 @@ pub fn has_raw_handler(handle: &ControlHandle, handler_id: UINT_PTR) -> bool {
 
 /**
     Remove the raw event handler from the associated window.
+    The raw event handlers are also freed when their window is destroyed.
     Calling unbind twice or trying to unbind an handler after destroying its parent will cause the function to panic.
 */
 pub fn unbind_raw_event_handler(handler: &RawEventHandler) -> Result<(), NwgError>
 {
     let subclass_proc = handler.subclass_proc;
     let handler_id = handler.handler_id;
-    let handle = handler.handle;
+    let handle = unsafe { current_handle(handler.handle, subclass_proc, handler_id) };
 
     unsafe {
         let mut callback_value: UINT_PTR = 0;
@@ -336,12 +591,9 @@ pub fn unbind_raw_event_handler(handler: &RawEventHandler) -> Result<(), NwgErro
             return Err(NwgError::EventsBinding(err));
         }
 
-        let callback_wrapper_ptr = callback_value as *mut *mut RawCallback;
-        let callback_wrapper: Box<*mut RawCallback> = Box::from_raw(callback_wrapper_ptr);
-        let callback: Box<RawCallback> = Box::from_raw(*callback_wrapper);
-        mem::drop(callback);
-
         RemoveWindowSubclass(handle, subclass_proc, handler_id);
+        free_raw_callback(callback_value);
+        track_raw_handler_id(handler_id, false);
         Ok(())
     }
 }
@@ -366,8 +618,11 @@ pub(crate) unsafe fn build_hwnd_control<'a>(
     use winapi::um::libloaderapi::GetModuleHandleW;
 
     let hmod = GetModuleHandleW(ptr::null_mut());
-    if hmod.is_null() { return Err(NwgError::initialization("GetModuleHandleW failed")); }
+    if hmod.is_null() { return Err(NwgError::system("GetModuleHandleW")); }
+
+    super::load_control_classes(super::ControlClasses::from_class_name(class_name));
 
+    let control = class_name;
     let class_name = to_utf16(class_name);
     let window_title = to_utf16(window_title.unwrap_or("New Window"));
     let ex_flags = ex_flags.unwrap_or(0);
@@ -403,7 +658,12 @@ pub(crate) unsafe fn build_hwnd_control<'a>(
 
     
     if handle.is_null() {
-        Err(NwgError::initialization("Window creation failed"))
+        let error = NwgError::window_creation(control, flags, ex_flags, parent);
+
+        #[cfg(feature = "log")]
+        log::error!("{}", error);
+
+        Err(error)
     } else {
         Ok(ControlHandle::Hwnd(handle))
     }
@@ -444,7 +704,12 @@ pub(crate) unsafe fn build_sysclass<'a>(
 
     let class_token = RegisterClassExW(&class);
     if class_token == 0 && GetLastError() != ERROR_CLASS_ALREADY_EXISTS { 
-        Err(NwgError::initialization("System class creation failed"))
+        let error = NwgError::system("RegisterClassExW");
+
+        #[cfg(feature = "log")]
+        log::error!("{}", error);
+
+        Err(error)
     } else {
         Ok(())
     }
@@ -552,17 +817,32 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
     use crate::events::*;
 
     use winapi::um::commctrl::{DefSubclassProc, TTN_GETDISPINFOW};
-    use winapi::um::winuser::{GetClassNameW, GetMenuItemID, GetSubMenu};
+    use winapi::um::winuser::{GetMenuItemID, GetSubMenu};
     use winapi::um::winuser::{WM_CLOSE, WM_COMMAND, WM_MENUCOMMAND, WM_TIMER, WM_NOTIFY, WM_HSCROLL, WM_VSCROLL, WM_LBUTTONDOWN, WM_LBUTTONUP,
       WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SIZE, WM_MOVE, WM_PAINT, WM_MOUSEMOVE, WM_CONTEXTMENU, WM_INITMENUPOPUP, WM_MENUSELECT, WM_EXITSIZEMOVE,
       WM_ENTERSIZEMOVE, SIZE_MAXIMIZED, SIZE_MINIMIZED, WM_KEYDOWN, WM_KEYUP, WM_CHAR, WM_MOUSEWHEEL, WM_DROPFILES, GET_WHEEL_DELTA_WPARAM,
-      WM_GETMINMAXINFO, WM_ENTERMENULOOP, WM_EXITMENULOOP, WM_SYSKEYDOWN, WM_SYSKEYUP};
-    use winapi::um::shellapi::{NIN_BALLOONSHOW, NIN_BALLOONHIDE, NIN_BALLOONTIMEOUT, NIN_BALLOONUSERCLICK};
-    use winapi::um::winnt::WCHAR;
+      WM_GETMINMAXINFO, WM_ENTERMENULOOP, WM_EXITMENULOOP, WM_SYSKEYDOWN, WM_SYSKEYUP,
+      WM_UNINITMENUPOPUP, MF_POPUP, MF_SYSMENU, WM_LBUTTONDBLCLK, WM_ERASEBKGND, WM_SYSCOLORCHANGE, WM_NCDESTROY};
+    use winapi::um::winuser::{WM_MEASUREITEM, WM_DRAWITEM};
+    #[cfg(feature="clipboard")] use winapi::um::winuser::{WM_RENDERFORMAT, WM_RENDERALLFORMATS, WM_DESTROYCLIPBOARD};
+    #[cfg(feature="ime")] use winapi::um::winuser::{WM_IME_STARTCOMPOSITION, WM_IME_COMPOSITION, WM_IME_ENDCOMPOSITION};
+    #[cfg(feature="pointer-input")] use winapi::um::winuser::{WM_POINTERDOWN, WM_POINTERUPDATE, WM_POINTERUP};
+    #[cfg(feature="single-instance")] use winapi::um::winuser::WM_COPYDATA;
+    use winapi::um::shellapi::{NIN_BALLOONSHOW, NIN_BALLOONHIDE, NIN_BALLOONTIMEOUT, NIN_BALLOONUSERCLICK, NIN_SELECT, NIN_KEYSELECT};
     use winapi::shared::minwindef::{HIWORD, LOWORD};
 
     let callback_ptr = data as *mut *const Callback;
-    let callback: &Callback = &**callback_ptr;
+
+    // The callback is borrowed instead of cloned. If the window is destroyed or unbound by the callback,
+    // the callback is kept alive until the dispatch ends. See `release_callback`.
+    let dispatch = DispatchGuard { callback: *callback_ptr, previous: Cell::new(ptr::null()) };
+    dispatch.start();
+
+    let callback_ref: &Callback = &**callback_ptr;
+    let guarded_callback = move |evt: Event, data: EventData, handle: ControlHandle| {
+        catch_panic(|| callback_ref(evt, data, handle));
+    };
+    let callback: &Callback = &guarded_callback;
     let base_handle = ControlHandle::Hwnd(hwnd);
 
     match msg {
@@ -601,9 +881,26 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
             let item_id = GetMenuItemID(parent_handle, w as i32);
             let handle = ControlHandle::MenuItem(parent_handle, item_id);
             callback(Event::OnMenuItemSelected, NO_DATA, handle);
+
+            #[cfg(feature="menu")]
+            if let Some((menu, path)) = crate::controls::recent_file(item_id) {
+                let data = EventData::OnRecentFileSelected(path);
+                callback(Event::OnRecentFileSelected, data, ControlHandle::Menu(ptr::null_mut(), menu));
+            }
         },
-        WM_INITMENUPOPUP => {
-            callback(Event::OnMenuOpen, NO_DATA, ControlHandle::Menu(ptr::null_mut(), w as HMENU));
+        WM_INITMENUPOPUP | WM_UNINITMENUPOPUP => {
+            let menu = ControlHandle::Menu(ptr::null_mut(), w as HMENU);
+            let system_menu = match msg {
+                WM_INITMENUPOPUP => HIWORD(l as u32) != 0,
+                _ => HIWORD(l as u32) as u32 & MF_SYSMENU == MF_SYSMENU
+            };
+
+            let evt = match msg {
+                WM_INITMENUPOPUP => Event::OnMenuOpen,
+                _ => Event::OnMenuClose
+            };
+
+            callback(evt, EventData::OnMenuPopup { menu, system_menu }, menu);
         },
         WM_ENTERMENULOOP => {
             callback(Event::OnMenuEnter, NO_DATA, ControlHandle::Menu(ptr::null_mut(), w as HMENU));
@@ -616,34 +913,54 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
         },
         WM_MENUSELECT => {
             let index = LOWORD(w as u32) as u32;
+            let flags = HIWORD(w as u32) as u32;
             let parent = l as HMENU;
-            if index < CUSTOM_ID_BEGIN {
-                // Item is a sub menu
-                callback(Event::OnMenuHover, NO_DATA, ControlHandle::Menu(parent, GetSubMenu(parent, index as i32)));
+            let menu = ControlHandle::Menu(ptr::null_mut(), parent);
+
+            if flags == 0xFFFF && parent.is_null() {
+                // The menu was closed
+                callback(Event::OnMenuHover, EventData::OnMenuHover { menu, item: None }, base_handle);
             } else {
-                // Item is a menu item
-                callback(Event::OnMenuHover, NO_DATA, ControlHandle::MenuItem(parent, index));
+                let item = if flags & MF_POPUP == MF_POPUP {
+                    // Item is a sub menu
+                    ControlHandle::Menu(parent, GetSubMenu(parent, index as i32))
+                } else {
+                    // Item is a menu item
+                    ControlHandle::MenuItem(parent, index)
+                };
+
+                callback(Event::OnMenuHover, EventData::OnMenuHover { menu, item: Some(item) }, item);
             }
         },
         WM_COMMAND => {
             let child_handle: HWND = l as HWND;
             let message = HIWORD(w as u32) as u16;
             let handle = ControlHandle::Hwnd(child_handle);
-            
-            // Converting the class name into rust string might not be the most efficient way to do this
-            // It might be a good idea to just compare the class_name_raw
-            let mut class_name_raw: [WCHAR; 100] = [0; 100];
-            let count = GetClassNameW(child_handle, class_name_raw.as_mut_ptr(), 100) as usize;
-            let class_name = OsString::from_wide(&class_name_raw[..count]).into_string().unwrap_or("".to_string());
-
-            match &class_name as &str {
-                "Button" => callback(button_commands(message), NO_DATA, handle),
-                "Edit" => callback(edit_commands(message), NO_DATA, handle),
-                "ComboBox" => callback(combo_commands(message), NO_DATA, handle),
-                "Static" => callback(static_commands(child_handle, message), NO_DATA, handle),
-                "ListBox" => callback(listbox_commands(message), NO_DATA, handle),
+
+            match control_class(child_handle) {
+                ControlClass::Button => callback(button_commands(message), NO_DATA, handle),
+                ControlClass::Edit | ControlClass::RichEdit => callback(edit_commands(message), NO_DATA, handle),
+                ControlClass::ComboBox => callback(combo_commands(message), NO_DATA, handle),
+                ControlClass::Static => callback(static_commands(child_handle, message), NO_DATA, handle),
+                ControlClass::ListBox => {
+                    callback(listbox_commands(message), NO_DATA, handle);
+
+                    #[cfg(feature="listbox")]
+                    {
+                        if message == winapi::um::winuser::LBN_SELCHANGE {
+                            callback(Event::OnListBoxSelectionChanged, list_box_selection_data(child_handle), handle);
+                        }
+                    }
+                },
                 _ => match w as i32 {
                     IDOK | IDCANCEL => callback(no_class_name_commands(w), NO_DATA, base_handle),
+                    #[cfg(feature="menu")]
+                    _ if child_handle.is_null() && message == 1 => {
+                        // Keyboard accelerator registered by a menu item
+                        if let Some(item) = super::menu::accelerator_item(LOWORD(w as u32) as u32) {
+                            callback(Event::OnMenuItemSelected, NO_DATA, item);
+                        }
+                    },
                     _ => {}
                 },
             }
@@ -652,10 +969,17 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
             let target_handle = w as HWND;
             let handle = ControlHandle::Hwnd(target_handle);
             callback(Event::OnContextMenu, NO_DATA, handle);
+
+            #[cfg(all(feature = "spell-check", feature = "rich-textbox"))]
+            {
+                if let Some(data) = misspelled_word_data(target_handle, l) {
+                    callback(Event::OnMisspelledWord, data, handle);
+                }
+            }
         },
         NWG_TRAY => {
             let msg = LOWORD(l as u32) as u32;
-            let handle = ControlHandle::SystemTray(hwnd);
+            let handle = ControlHandle::SystemTray(hwnd, w as u32);
 
             match msg {
                 NIN_BALLOONSHOW => callback(Event::OnTrayNotificationShow, NO_DATA, handle),
@@ -664,10 +988,11 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
                 NIN_BALLOONUSERCLICK => callback(Event::OnTrayNotificationUserClose, NO_DATA, handle),
                 WM_LBUTTONUP => callback(Event::OnMousePress(MousePressEvent::MousePressLeftUp), NO_DATA,  handle), 
                 WM_LBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressLeftDown), NO_DATA, handle), 
-                WM_RBUTTONUP => {
-                    callback(Event::OnMousePress(MousePressEvent::MousePressRightUp), NO_DATA, handle);
-                    callback(Event::OnContextMenu, NO_DATA, handle);
-                }, 
+                WM_LBUTTONDBLCLK => callback(Event::OnTrayNotificationDoubleClick, NO_DATA, handle),
+                NIN_SELECT => callback(Event::OnTrayNotificationSelect, NO_DATA, handle),
+                NIN_KEYSELECT => callback(Event::OnTrayNotificationKeySelect, NO_DATA, handle),
+                WM_CONTEXTMENU => callback(Event::OnContextMenu, NO_DATA, handle),
+                WM_RBUTTONUP => callback(Event::OnMousePress(MousePressEvent::MousePressRightUp), NO_DATA, handle),
                 WM_RBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressRightDown), NO_DATA, handle),
                 WM_MOUSEMOVE => callback(Event::OnMouseMove, NO_DATA, handle),
                 _ => {}
@@ -684,10 +1009,85 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
             let data = EventData::OnPaint(PaintData { hwnd } );
             callback(Event::OnPaint, data, base_handle)
         },
+        WM_ERASEBKGND => {
+            // Double buffered controls paint their whole client area
+            if super::back_buffer::enabled(hwnd) {
+                return 1;
+            }
+        },
+        #[cfg(feature="clipboard")]
+        WM_RENDERFORMAT => {
+            super::clipboard::render_format(hwnd, w as u32);
+            return 0;
+        },
+        #[cfg(feature="clipboard")]
+        WM_RENDERALLFORMATS => {
+            super::clipboard::render_all_formats(hwnd);
+            return 0;
+        },
+        #[cfg(feature="clipboard")]
+        WM_DESTROYCLIPBOARD => {
+            super::clipboard::release_delayed_render(hwnd);
+        },
         WM_DROPFILES => {
             let data = EventData::OnFileDrop(DropFiles { drop: w as _ });
             callback(Event::OnFileDrop, data, base_handle)
         },
+        WM_MEASUREITEM => {
+            use winapi::um::winuser::{MEASUREITEMSTRUCT, GetDlgItem};
+
+            let measure = &mut *(l as *mut MEASUREITEMSTRUCT);
+
+            #[cfg(feature="menu")]
+            {
+                use winapi::um::winuser::{ODT_MENU, SM_CYMENU, GetSystemMetrics};
+
+                if measure.CtlType == ODT_MENU {
+                    // Owner drawn menu items store their parent menu in the item data
+                    let handle = ControlHandle::MenuItem(measure.itemData as HMENU, measure.itemID);
+                    measure.itemHeight = GetSystemMetrics(SM_CYMENU) as u32;
+
+                    let data = EventData::OnMenuItemMeasure(MenuItemMeasureData { inner: measure });
+                    callback(Event::OnMenuItemMeasure, data, handle);
+                    return 1;
+                }
+            }
+
+            // Controls without an id cannot be found. See `register_owner_draw`
+            let control = match measure.CtlID {
+                0 => ptr::null_mut(),
+                id => GetDlgItem(hwnd, id as _),
+            };
+
+            if !control.is_null() && owner_drawn(control) {
+                let data = EventData::OnMeasureItem(MeasureItemData { inner: measure });
+                callback(Event::OnMeasureItem, data, ControlHandle::Hwnd(control));
+                return 1;
+            }
+        },
+        WM_DRAWITEM => {
+            use winapi::um::winuser::DRAWITEMSTRUCT;
+
+            let draw = &*(l as *const DRAWITEMSTRUCT);
+
+            #[cfg(feature="menu")]
+            {
+                use winapi::um::winuser::ODT_MENU;
+
+                if draw.CtlType == ODT_MENU {
+                    let handle = ControlHandle::MenuItem(draw.hwndItem as HMENU, draw.itemID);
+                    let data = EventData::OnMenuItemDraw(MenuItemDrawData { inner: draw });
+                    callback(Event::OnMenuItemDraw, data, handle);
+                    return 1;
+                }
+            }
+
+            if owner_drawn(draw.hwndItem) {
+                let data = EventData::OnDrawItem(DrawItemData { inner: draw });
+                callback(Event::OnDrawItem, data, ControlHandle::Hwnd(draw.hwndItem));
+                return 1;
+            }
+        },
         WM_GETMINMAXINFO => {
             let data = EventData::OnMinMaxInfo(MinMaxInfo { inner: l as _ });
             callback(Event::OnMinMaxInfo, data, base_handle)
@@ -697,8 +1097,20 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
         WM_ENTERSIZEMOVE => callback(Event::OnResizeBegin, NO_DATA, base_handle),
         WM_TIMER => callback(Event::OnTimerTick, NO_DATA, ControlHandle::Timer(hwnd, w as u32)),
         WM_MOVE => callback(Event::OnMove, NO_DATA, base_handle),
-        WM_HSCROLL => callback(Event::OnHorizontalScroll, NO_DATA, ControlHandle::Hwnd(l as HWND)),
-        WM_VSCROLL => callback(Event::OnVerticalScroll, NO_DATA, ControlHandle::Hwnd(l as HWND)),
+        WM_HSCROLL | WM_VSCROLL => {
+            let handle = ControlHandle::Hwnd(l as HWND);
+            match msg == WM_HSCROLL {
+                true => callback(Event::OnHorizontalScroll, NO_DATA, handle),
+                false => callback(Event::OnVerticalScroll, NO_DATA, handle)
+            }
+
+            #[cfg(feature = "trackbar")]
+            {
+                if let Some(data) = track_bar_value_data(l as HWND, w) {
+                    callback(Event::OnTrackBarValueChanged, data, handle);
+                }
+            }
+        },
         WM_MOUSEMOVE => callback(Event::OnMouseMove, NO_DATA, base_handle), 
         WM_LBUTTONUP => callback(Event::OnMousePress(MousePressEvent::MousePressLeftUp), NO_DATA,  base_handle), 
         WM_LBUTTONDOWN => callback(Event::OnMousePress(MousePressEvent::MousePressLeftDown), NO_DATA, base_handle), 
@@ -708,6 +1120,31 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
         NWG_TIMER_STOP => callback(Event::OnTimerStop, NO_DATA, ControlHandle::Timer(hwnd, w as u32)),
         NWG_TIMER_TICK => callback(Event::OnTimerTick, NO_DATA, ControlHandle::Timer(hwnd, w as u32)),
         NWG_INIT => callback(Event::OnInit, NO_DATA, base_handle),
+        #[cfg(feature="toast-notification")]
+        NWG_TOAST => {
+            let data = EventData::OnToastActivated(super::toast::activation_arguments(l as usize));
+            callback(Event::OnToastActivated, data, ControlHandle::Toast(hwnd, w as u32));
+        },
+        #[cfg(feature="plot-canvas")]
+        NWG_PLOT_HOVER => {
+            // The point is owned by the plot canvas and is only valid during the message
+            let point = match l {
+                0 => None,
+                l => Some(*(l as *const crate::PlotPoint))
+            };
+
+            callback(Event::OnPlotHover, EventData::OnPlotHover(point), base_handle);
+        },
+        #[cfg(feature="media-player")]
+        NWG_MEDIA_END => callback(Event::OnMediaEnd, NO_DATA, base_handle),
+        #[cfg(feature="tabs")]
+        NWG_TAB_CLOSE => callback(Event::OnTabCloseRequest, EventData::OnTabCloseRequest(w as usize), base_handle),
+        #[cfg(feature="number-select")]
+        NWG_NUMBER_CHANGED => {
+            // The data is owned by the number select and is only valid during the message
+            let data = *(l as *const crate::NumberSelectData);
+            callback(Event::OnNumberChanged, EventData::OnNumberChanged(data), base_handle);
+        },
         WM_CLOSE => {
             let mut should_exit = true;
             let data = EventData::OnWindowClose(WindowCloseData { data: &mut should_exit as *mut bool });
@@ -717,10 +1154,77 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
                 return 0;
             }
         },
+        WM_SYSCOLORCHANGE => callback(Event::OnSystemColorsChanged, NO_DATA, base_handle),
+        #[cfg(feature="pointer-input")]
+        WM_POINTERDOWN | WM_POINTERUPDATE | WM_POINTERUP => {
+            // The default processing must still happen so that the pointer also sends the mouse messages
+            if let Some(pointer) = super::pointer::pointer_data(hwnd, LOWORD(w as u32) as u32) {
+                let evt = match msg {
+                    WM_POINTERDOWN => Event::OnPointerDown,
+                    WM_POINTERUP => Event::OnPointerUp,
+                    _ => Event::OnPointerUpdate,
+                };
+
+                callback(evt, EventData::OnPointer(pointer), base_handle);
+            }
+        },
+        #[cfg(feature="ime")]
+        WM_IME_STARTCOMPOSITION => callback(Event::OnImeStartComposition, NO_DATA, base_handle),
+        #[cfg(feature="ime")]
+        WM_IME_COMPOSITION => {
+            // The default processing must still happen so that the committed text is sent with WM_CHAR
+            let data = EventData::OnImeComposition(ImeCompositionData { hwnd, flags: l as u32 });
+            callback(Event::OnImeComposition, data, base_handle);
+        },
+        #[cfg(feature="single-instance")]
+        WM_COPYDATA => {
+            if let Some(args) = super::single_instance::instance_args(l) {
+                callback(Event::OnInstanceArgs, EventData::OnInstanceArgs(args), base_handle);
+                return 1;
+            }
+        },
+        #[cfg(feature="ime")]
+        WM_IME_ENDCOMPOSITION => callback(Event::OnImeEndComposition, NO_DATA, base_handle),
+        #[cfg(feature="recovery")]
+        m if m == *super::recovery::NWG_RECOVERY => callback(Event::OnRecovery, NO_DATA, base_handle),
+        #[cfg(feature="drag-drop")]
+        m if m == *super::drop_target::NWG_DRAG_DROP => {
+            use super::drop_target::{DRAG_ENTER, DRAG_OVER, DROP};
+
+            let evt = match w {
+                DRAG_ENTER => Event::OnDragEnter,
+                DRAG_OVER => Event::OnDragOver,
+                DROP => Event::OnDrop,
+                _ => Event::OnDragLeave,
+            };
+
+            let data = match l {
+                0 => NO_DATA,
+                l => EventData::OnDragDrop(DragDropData { inner: l as _ })
+            };
+
+            callback(evt, data, base_handle);
+            return 0;
+        },
+        #[cfg(feature="tray-notification")]
+        m if m != 0 && m == crate::controls::taskbar_created_message() => {
+            for handle in crate::controls::recreate_tray_icons(hwnd) {
+                callback(Event::OnTrayRecreated, NO_DATA, handle);
+            }
+        },
         _ => {}
     }
 
-    DefSubclassProc(hwnd, msg, w, l)
+    let result = DefSubclassProc(hwnd, msg, w, l);
+
+    // The window is being destroyed. Release its reference to the callback if the handler was not unbound.
+    // The pointer to the callback can be shared by many windows, it is only freed by `unbind_event_handler`.
+    if msg == WM_NCDESTROY {
+        RemoveWindowSubclass(hwnd, Some(process_events), id);
+        release_callback(Rc::from_raw(*callback_ptr));
+    }
+
+    result
 }
 
 /**
@@ -728,16 +1232,154 @@ unsafe extern "system" fn process_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LP
 */
 #[allow(unused_variables)]
 unsafe extern "system" fn process_raw_events(hwnd: HWND, msg: UINT, w: WPARAM, l: LPARAM, id: UINT_PTR, data: DWORD_PTR) -> LRESULT {
-    let callback_wrapper_ptr = data as *mut *mut RawCallback;
-    let callback: Box<RawCallback> = Box::from_raw(*callback_wrapper_ptr);
+    use winapi::um::commctrl::DefSubclassProc;
+    use winapi::um::winuser::WM_NCDESTROY;
 
-    let result = callback(hwnd, msg, w, l);
-    Box::into_raw(callback);
+    // Keep the callback alive until it returns, even if the window is destroyed by the callback
+    let callback = clone_callback(*(data as *mut *const RawCallback));
 
-    match result {
+    let result = match catch_panic(|| callback(hwnd, msg, w, l)).flatten() {
         Some(r) => r,
-        None => ::winapi::um::commctrl::DefSubclassProc(hwnd, msg, w, l)
+        None => DefSubclassProc(hwnd, msg, w, l)
+    };
+
+    // The window is being destroyed. Free the callback if it was not unbound.
+    if msg == WM_NCDESTROY {
+        RemoveWindowSubclass(hwnd, Some(process_raw_events), id);
+        free_raw_callback(data);
+        track_raw_handler_id(id, false);
+    }
+
+    result
+}
+
+/**
+    Set the function called when an event handler of the calling thread panics. The function receives the panic message.
+
+    The event handlers are called by the system, and a panic cannot unwind through the system code.
+    Without a panic handler, the process is aborted after the panic message is printed.
+    With a panic handler, the event that caused the panic is ignored and the application keeps running.
+
+    ```rust
+    use native_windows_gui as nwg;
+
+    fn log_panics() {
+        nwg::set_event_panic_handler(|message| {
+            nwg::error_message("Internal error", message);
+        });
+    }
+    ```
+*/
+pub fn set_event_panic_handler<F>(handler: F)
+    where F: Fn(&str) -> () + 'static
+{
+    let handler: Rc<PanicCallback> = Rc::new(handler);
+    PANIC_HANDLER.with(|h| *h.borrow_mut() = Some(handler));
+}
+
+/// Remove the panic handler of the calling thread. See `set_event_panic_handler`.
+pub fn clear_event_panic_handler() {
+    PANIC_HANDLER.with(|h| *h.borrow_mut() = None);
+}
+
+/// Call `f` and stop the panics before they unwind into the system code that called the event handler.
+/// Return `None` if `f` panicked.
+fn catch_panic<R, F: FnOnce() -> R>(f: F) -> Option<R> {
+    let payload = match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+        Ok(r) => { return Some(r); },
+        Err(payload) => payload
+    };
+
+    let message = match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "Unknown panic".to_string()
+        }
+    };
+
+    // Clone the handler, it might replace itself
+    let handler = PANIC_HANDLER.with(|h| h.borrow().clone());
+    match handler {
+        Some(handler) => { handler(&message); },
+        None => {
+            // The message was already printed by the panic hook
+            ::std::process::abort();
+        }
+    }
+
+    None
+}
+
+/// Drop a reference to an event callback. If the callback is running, the reference is
+/// kept alive until the callback returns.
+fn release_callback(callback: Rc<Callback>) {
+    match callback_running(&callback) {
+        true => RELEASED_CALLBACKS.with(|released| released.borrow_mut().push(callback)),
+        false => mem::drop(callback)
+    }
+}
+
+/// Check if a message dispatch of the thread is running `callback`
+fn callback_running(callback: &Rc<Callback>) -> bool {
+    let callback_ptr = Rc::as_ptr(callback) as *const u8;
+    let mut dispatch = RUNNING_DISPATCH.with(|running| running.get());
+
+    while !dispatch.is_null() {
+        let guard = unsafe { &*dispatch };
+        if guard.callback as *const u8 == callback_ptr {
+            return true;
+        }
+
+        dispatch = guard.previous.get();
     }
+
+    false
+}
+
+/**
+    A message dispatch by `process_events`. The running dispatches form a linked list stored on the stack,
+    so the hot path of the event dispatch does not touch the heap or the callbacks reference count.
+*/
+struct DispatchGuard {
+    callback: *const Callback,
+    previous: Cell<*const DispatchGuard>,
+}
+
+impl DispatchGuard {
+    /// Register the dispatch as running. The guard must not be moved after this call.
+    fn start(&self) {
+        let previous = RUNNING_DISPATCH.with(|running| running.replace(self as *const DispatchGuard));
+        self.previous.set(previous);
+    }
+}
+
+impl Drop for DispatchGuard {
+    fn drop(&mut self) {
+        RUNNING_DISPATCH.with(|running| running.set(self.previous.get()));
+
+        // Drop the references released by `release_callback` that are no longer running
+        let released = RELEASED_CALLBACKS.with(|released| match released.borrow().is_empty() {
+            true => Vec::new(),
+            false => mem::take(&mut *released.borrow_mut())
+        });
+
+        for callback in released {
+            release_callback(callback);
+        }
+    }
+}
+
+/// Return a new reference to a callback stored in a window subclass
+unsafe fn clone_callback<T: ?Sized>(callback: *const T) -> Rc<T> {
+    let callback = mem::ManuallyDrop::new(Rc::from_raw(callback));
+    Rc::clone(&callback)
+}
+
+/// Free the data of a raw event handler subclass
+unsafe fn free_raw_callback(data: UINT_PTR) {
+    let callback_wrapper: Box<*const RawCallback> = Box::from_raw(data as *mut *const RawCallback);
+    mem::drop(Rc::from_raw(*callback_wrapper));
 }
 
 fn button_commands(m: u16) -> Event {
@@ -749,19 +1391,29 @@ fn button_commands(m: u16) -> Event {
     }
 }
 
+fn button_notify_commands(m: u32) -> Event {
+    use winapi::um::commctrl::BCN_DROPDOWN;
+    match m {
+        BCN_DROPDOWN => Event::OnButtonDropDown,
+        _ => Event::Unknown
+    }
+}
+
 fn edit_commands(m: u16) -> Event {
-    use winapi::um::winuser::{EN_CHANGE};
+    use winapi::um::winuser::{EN_CHANGE, EN_MAXTEXT};
 
     match m {
         EN_CHANGE => Event::OnTextInput,
+        EN_MAXTEXT => Event::OnTextLimitReached,
         _ => Event::Unknown
     }
 }
 
 fn combo_commands(m: u16) -> Event {
-    use winapi::um::winuser::{CBN_CLOSEUP, CBN_DROPDOWN, CBN_SELCHANGE};
+    use winapi::um::winuser::{CBN_CLOSEUP, CBN_DROPDOWN, CBN_SELCHANGE, CBN_EDITCHANGE};
     match m {
         CBN_CLOSEUP => Event::OnComboBoxClosed,
+        CBN_EDITCHANGE => Event::OnComboBoxTextChanged,
         CBN_DROPDOWN => Event::OnComboBoxDropdown,
         CBN_SELCHANGE => Event::OnComboxBoxSelection,
         _ => Event::Unknown
@@ -1001,6 +1653,32 @@ unsafe fn listbox_commands(m: u16) -> Event {
     }
 }
 
+#[cfg(feature="listbox")]
+unsafe fn list_box_selection_data(hwnd: HWND) -> EventData {
+    use winapi::um::winuser::{SendMessageW, GetWindowLongW, GWL_STYLE};
+    use winapi::um::winuser::{LBS_MULTIPLESEL, LBS_EXTENDEDSEL, LB_GETCURSEL, LB_GETSELCOUNT, LB_GETSELITEMS, LB_ERR};
+
+    let style = GetWindowLongW(hwnd, GWL_STYLE) as u32;
+    let indices = if style & (LBS_MULTIPLESEL | LBS_EXTENDEDSEL) != 0 {
+        let count = match SendMessageW(hwnd, LB_GETSELCOUNT, 0, 0) {
+            LB_ERR => 0,
+            c => c as usize
+        };
+
+        let mut buffer: Vec<u32> = vec![0; count];
+        let read = SendMessageW(hwnd, LB_GETSELITEMS, count as WPARAM, buffer.as_mut_ptr() as LPARAM);
+        buffer.truncate(read.max(0) as usize);
+        buffer.into_iter().map(|i| i as usize).collect()
+    } else {
+        match SendMessageW(hwnd, LB_GETCURSEL, 0, 0) {
+            LB_ERR => Vec::new(),
+            i => vec![i as usize]
+        }
+    };
+
+    EventData::OnListBoxSelectionChanged(indices)
+}
+
 unsafe fn handle_tooltip_callback<'a>(notif: *mut NMTTDISPINFOW, callback: &Callback) {
     use crate::events::ToolTipTextData;
 
@@ -1011,37 +1689,126 @@ unsafe fn handle_tooltip_callback<'a>(notif: *mut NMTTDISPINFOW, callback: &Call
 }
 
 unsafe fn handle_default_notify_callback<'a>(notif_raw: *const NMHDR, callback: &Callback){
-    use winapi::um::winnt::WCHAR;
-    use winapi::um::winuser::GetClassNameW;
-
     let notif = &*notif_raw;
     let handle = ControlHandle::Hwnd(notif.hwndFrom);
-
-    let mut class_name_raw: [WCHAR; 100] = mem::zeroed();
-    let count = GetClassNameW(notif.hwndFrom, class_name_raw.as_mut_ptr(), 100) as usize;
-    let class_name = OsString::from_wide(&class_name_raw[..count]).into_string().unwrap_or("".to_string());
-
     let code = notif.code;
 
-    match &class_name as &str {
-        "SysDateTimePick32" => callback(datetimepick_commands(code), NO_DATA, handle),
-        "SysTabControl32" => callback(tabs_commands(code), NO_DATA, handle),
-        "msctls_trackbar32" => callback(track_commands(code), NO_DATA, handle),
-        winapi::um::commctrl::WC_TREEVIEW => callback(tree_commands(code), tree_data(code, notif_raw), handle),
-        winapi::um::commctrl::WC_LISTVIEW => callback(list_view_commands(code), list_view_data(code, notif_raw), handle),
+    match control_class(notif.hwndFrom) {
+        ControlClass::Button => callback(button_notify_commands(code), NO_DATA, handle),
+        ControlClass::DateTimePicker => callback(datetimepick_commands(code), NO_DATA, handle),
+        ControlClass::Tabs => callback(tabs_commands(code), NO_DATA, handle),
+        ControlClass::TrackBar => callback(track_commands(code), NO_DATA, handle),
+        ControlClass::TreeView => callback(tree_commands(code), tree_data(code, notif_raw), handle),
+        ControlClass::ListView => callback(list_view_commands(code), list_view_data(code, notif_raw), handle),
         _ => {}
     }
 }
 
-unsafe fn is_textbox_control(hwnd: HWND) -> bool {
+/// The window classes of the controls that send their events to their parent
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ControlClass {
+    Button,
+    Edit,
+    RichEdit,
+    ComboBox,
+    Static,
+    ListBox,
+    DateTimePicker,
+    Tabs,
+    TrackBar,
+    TreeView,
+    ListView,
+    Other,
+}
+
+const CONTROL_CLASSES: &[(&str, ControlClass)] = &[
+    ("Button", ControlClass::Button),
+    ("Edit", ControlClass::Edit),
+    ("RICHEDIT50W", ControlClass::RichEdit),
+    ("ComboBox", ControlClass::ComboBox),
+    ("Static", ControlClass::Static),
+    ("ListBox", ControlClass::ListBox),
+    ("SysDateTimePick32", ControlClass::DateTimePicker),
+    ("SysTabControl32", ControlClass::Tabs),
+    ("msctls_trackbar32", ControlClass::TrackBar),
+    (winapi::um::commctrl::WC_TREEVIEW, ControlClass::TreeView),
+    (winapi::um::commctrl::WC_LISTVIEW, ControlClass::ListView),
+];
+
+/// Return the class of a control. The class name is compared in UTF-16 without allocating.
+unsafe fn control_class(hwnd: HWND) -> ControlClass {
     use winapi::um::winnt::WCHAR;
     use winapi::um::winuser::GetClassNameW;
 
     let mut class_name_raw: [WCHAR; 100] = [0; 100];
     let count = GetClassNameW(hwnd, class_name_raw.as_mut_ptr(), 100) as usize;
-    let class_name = OsString::from_wide(&class_name_raw[..count]).into_string().unwrap_or("".to_string());
-    
-    class_name == "Edit" || class_name == "RICHEDIT50W"
+    let class_name = &class_name_raw[..count];
+
+    // The class names are ascii
+    let same_name = |name: &str| name.len() == count && name.bytes().zip(class_name).all(|(a, &b)| a as WCHAR == b);
+
+    CONTROL_CLASSES.iter()
+        .find(|(name, _)| same_name(name))
+        .map(|&(_, class)| class)
+        .unwrap_or(ControlClass::Other)
+}
+
+/// Read the new position of a trackbar that sent a scroll message. Returns `None` if the message was not sent by a trackbar
+/// or if the message only signals the end of a change.
+#[cfg(feature = "trackbar")]
+unsafe fn track_bar_value_data(hwnd: HWND, w: WPARAM) -> Option<EventData> {
+    use winapi::um::commctrl::{TB_ENDTRACK, TBM_GETPOS};
+    use winapi::shared::minwindef::LOWORD;
+
+    if hwnd.is_null() || LOWORD(w as u32) as WPARAM == TB_ENDTRACK {
+        return None;
+    }
+
+    if control_class(hwnd) != ControlClass::TrackBar {
+        return None;
+    }
+
+    let pos = super::window_helper::send_message(hwnd, TBM_GETPOS, 0, 0) as usize;
+    Some(EventData::OnTrackBarValueChanged(pos))
+}
+
+/// Check if the word under the context menu of a spell checked rich text box is misspelled
+#[cfg(all(feature = "spell-check", feature = "rich-textbox"))]
+unsafe fn misspelled_word_data(hwnd: HWND, l: LPARAM) -> Option<EventData> {
+    use crate::win32::richedit as rich;
+    use crate::SpellChecker;
+    use winapi::um::winuser::{ScreenToClient, EM_GETSEL};
+    use winapi::shared::windef::POINT;
+    use winapi::shared::minwindef::{LOWORD, HIWORD};
+
+    if !is_textbox_control(hwnd) || !rich::spell_checking(hwnd) {
+        return None;
+    }
+
+    // The context menu was opened with the keyboard. Use the caret position instead.
+    let index = if l == -1 {
+        (super::window_helper::send_message(hwnd, EM_GETSEL as u32, 0, 0) as u32 & 0xFFFF) as usize
+    } else {
+        let mut point = POINT { x: LOWORD(l as u32) as i16 as i32, y: HIWORD(l as u32) as i16 as i32 };
+        ScreenToClient(hwnd, &mut point);
+        rich::char_from_pos(hwnd, point.x, point.y)
+    };
+
+    let (word, range) = rich::word_at(hwnd, index)?;
+    let checker = SpellChecker::new().ok()?;
+    if checker.is_correct(&word) {
+        return None;
+    }
+
+    let suggestions = checker.suggest(&word);
+    Some(EventData::OnMisspelledWord { word, range, suggestions })
+}
+
+unsafe fn is_textbox_control(hwnd: HWND) -> bool {
+    match control_class(hwnd) {
+        ControlClass::Edit | ControlClass::RichEdit => true,
+        _ => false
+    }
 }
 
 //
@@ -1054,26 +1821,19 @@ unsafe fn is_textbox_control(hwnd: HWND) -> bool {
 type SubclassId = (usize, usize, UINT_PTR);
 
 #[cfg(target_env="gnu")]
-static mut SUBCLASS_COLLECTION: Option<Mutex<HashMap<SubclassId, DWORD_PTR>>> = None;
+lazy_static! {
+    /// Shared by all the GUI threads
+    static ref SUBCLASS_COLLECTION: Mutex<HashMap<SubclassId, DWORD_PTR>> = Mutex::new(HashMap::new());
+}
 
 
 #[cfg(target_env="gnu")]
 #[allow(non_snake_case)]
 unsafe fn GetWindowSubclass(hwnd: HWND, proc: SUBCLASSPROC, uid: UINT_PTR, data: *mut DWORD_PTR) -> BOOL {
-    if SUBCLASS_COLLECTION.is_none() {
-        SUBCLASS_COLLECTION = Some(Mutex::new(HashMap::new()));
-    }
-
     let id = (hwnd as usize, mem::transmute(proc), uid);
-    match SUBCLASS_COLLECTION.as_ref() {
-        Some(collection_mutex) => {
-            let collection = collection_mutex.lock().unwrap();
-            match collection.get(&id) {
-                Some(v) => { *data = *v; 1 },
-                None => { 0 }
-            }
-        },
-        None => unreachable!()
+    match SUBCLASS_COLLECTION.lock().unwrap().get(&id) {
+        Some(v) => { *data = *v; 1 },
+        None => { 0 }
     }
 }
 
@@ -1082,19 +1842,8 @@ unsafe fn GetWindowSubclass(hwnd: HWND, proc: SUBCLASSPROC, uid: UINT_PTR, data:
 unsafe fn SetWindowSubclass(hwnd: HWND, proc: SUBCLASSPROC, uid: UINT_PTR, data: DWORD_PTR) -> BOOL {
     use winapi::um::commctrl::SetWindowSubclass;
 
-    if SUBCLASS_COLLECTION.is_none() {
-        SUBCLASS_COLLECTION = Some(Mutex::new(HashMap::new()));
-    }
-
     let id = (hwnd as usize, mem::transmute(proc), uid);
-    match SUBCLASS_COLLECTION.as_ref() {
-        Some(collection_mutex) => {
-            let mut collection = collection_mutex.lock().unwrap();
-            collection.insert(id, data);
-        },
-        None => unreachable!()
-    }
-
+    SUBCLASS_COLLECTION.lock().unwrap().insert(id, data);
 
     SetWindowSubclass(hwnd, proc, uid, data)
 }
@@ -1105,18 +1854,8 @@ unsafe fn SetWindowSubclass(hwnd: HWND, proc: SUBCLASSPROC, uid: UINT_PTR, data:
 unsafe fn RemoveWindowSubclass(hwnd: HWND, proc: SUBCLASSPROC, uid: UINT_PTR) -> BOOL {
     use winapi::um::commctrl::RemoveWindowSubclass;
 
-    if SUBCLASS_COLLECTION.is_none() {
-        SUBCLASS_COLLECTION = Some(Mutex::new(HashMap::new()));
-    }
-
     let id = (hwnd as usize, mem::transmute(proc), uid);
-    match SUBCLASS_COLLECTION.as_ref() {
-        Some(collection_mutex) => {
-            let mut collection = collection_mutex.lock().unwrap();
-            collection.remove(&id);
-        },
-        None => unreachable!()
-    }
+    SUBCLASS_COLLECTION.lock().unwrap().remove(&id);
 
     RemoveWindowSubclass(hwnd, proc, uid)
 }
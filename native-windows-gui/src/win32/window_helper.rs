@@ -1,4 +1,4 @@
-use super::base_helper::{to_utf16, from_utf16};
+use super::base_helper::{with_utf16, with_wide_buffer, from_utf16};
 use super::high_dpi;
 use winapi::shared::windef::{HFONT, HWND, HMENU};
 use winapi::shared::minwindef::{UINT, WPARAM, LPARAM, LRESULT};
@@ -14,6 +14,16 @@ pub const NWG_INIT: UINT = WM_USER + 101;
 pub const NWG_TRAY: UINT = WM_USER + 102;
 pub const NWG_TIMER_TICK: UINT = WM_USER + 103;
 pub const NWG_TIMER_STOP: UINT = WM_USER + 104;
+#[cfg(feature = "toast-notification")]
+pub const NWG_TOAST: UINT = WM_USER + 105;
+#[cfg(feature = "plot-canvas")]
+pub const NWG_PLOT_HOVER: UINT = WM_USER + 106;
+#[cfg(feature = "media-player")]
+pub const NWG_MEDIA_END: UINT = WM_USER + 107;
+#[cfg(feature = "tabs")]
+pub const NWG_TAB_CLOSE: UINT = WM_USER + 108;
+#[cfg(feature = "number-select")]
+pub const NWG_NUMBER_CHANGED: UINT = WM_USER + 109;
 
 
 /// Returns the class info of a hwnd handle
@@ -88,6 +98,11 @@ pub fn destroy_window(hwnd: HWND) {
         }
     });
 
+    super::back_buffer::set_enabled(hwnd, false);
+
+    #[cfg(feature = "accessibility")]
+    super::accessibility::clear(hwnd);
+
     unsafe { DestroyWindow(hwnd); }
 }
 
@@ -153,7 +168,6 @@ pub fn iterate_window_children<F>(hwnd_parent: HWND, cb: F)
     }
 }
 
-#[cfg(any(feature="timer", feature="animation-timer", feature="notice"))]
 pub fn window_valid(hwnd: HWND) -> bool {
     use winapi::um::winuser::IsWindow;
 
@@ -232,7 +246,6 @@ pub fn get_style(handle: HWND) -> UINT {
     get_window_long(handle, GWL_STYLE) as UINT
 }
 
-#[cfg(any(feature = "list-view", feature = "progress-bar"))]
 pub fn set_style(handle: HWND, style: u32) {
     use ::winapi::um::winuser::GWL_STYLE;
     set_window_long(handle, GWL_STYLE, style as usize);
@@ -260,20 +273,19 @@ pub unsafe fn get_window_text(handle: HWND) -> String {
     let buffer_size = GetWindowTextLengthW(handle) as usize + 1;
     if buffer_size == 0 { return String::new(); }
 
-    let mut buffer: Vec<u16> = vec![0; buffer_size];
-
-    if GetWindowTextW(handle, buffer.as_mut_ptr(), buffer_size as c_int) == 0 {
-        String::new()
-    } else {
-        from_utf16(&buffer[..])
-    }
+    with_wide_buffer(buffer_size, |buffer| {
+        if GetWindowTextW(handle, buffer.as_mut_ptr(), buffer_size as c_int) == 0 {
+            String::new()
+        } else {
+            from_utf16(buffer)
+        }
+    })
 }
 
 pub unsafe fn set_window_text<'a>(handle: HWND, text: &'a str) {
     use winapi::um::winuser::SetWindowTextW;
 
-    let text = to_utf16(text);
-    SetWindowTextW(handle, text.as_ptr());
+    with_utf16(text, |text| SetWindowTextW(handle, text.as_ptr()));
 }
 
 pub unsafe fn set_window_position(handle: HWND, x: i32, y: i32) {
@@ -304,9 +316,15 @@ pub unsafe fn get_window_position(handle: HWND) -> (i32, i32) {
     let mut r: RECT = mem::zeroed();
     GetWindowRect(handle, &mut r);
 
+    // In a mirrored parent, the origin of the child is its right edge
     let parent = GetParent(handle);
     let (x, y) = if !parent.is_null() {
-        let mut pt = POINT{x: r.left, y: r.top};
+        let left = match is_rtl_layout(parent) {
+            true => r.right,
+            false => r.left
+        };
+
+        let mut pt = POINT{x: left, y: r.top};
         ScreenToClient(parent, &mut pt);
         (pt.x as i32, pt.y as i32)
     } else {
@@ -316,6 +334,12 @@ pub unsafe fn get_window_position(handle: HWND) -> (i32, i32) {
     high_dpi::physical_to_logical(x, y)
 }
 
+/// Return true if the window is mirrored for right-to-left languages
+pub fn is_rtl_layout(handle: HWND) -> bool {
+    use winapi::um::winuser::{GWL_EXSTYLE, WS_EX_LAYOUTRTL};
+    get_window_long(handle, GWL_EXSTYLE) as u32 & WS_EX_LAYOUTRTL == WS_EX_LAYOUTRTL
+}
+
 pub unsafe fn set_window_size(handle: HWND, w: u32, h: u32, fix: bool) {
     use winapi::um::winuser::{SetWindowPos, AdjustWindowRectEx, GetWindowLongW};
     use winapi::um::winuser::{SWP_NOZORDER, SWP_NOMOVE, SWP_NOACTIVATE, SWP_NOCOPYBITS, GWL_STYLE, GWL_EXSTYLE, SWP_NOOWNERZORDER};
@@ -397,7 +421,7 @@ pub unsafe fn set_window_enabled(handle: HWND, enabled: bool) {
     UpdateWindow(handle);
 }
 
-#[cfg(feature = "tabs")]
+#[cfg(any(feature = "tabs", feature = "accessibility", feature = "style", feature = "media-player"))]
 pub unsafe fn get_window_class_name(handle: HWND) -> String {
     use std::ffi::OsString;
     use std::os::windows::ffi::OsStringExt;